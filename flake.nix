@@ -41,6 +41,7 @@
             xorriso
             dvdplusrwtools  # provides growisofs
             qrencode
+            zbar
             rsync
             util-linux      # provides mount/umount
             coreutils       # provides sha256sum and other utilities
@@ -54,6 +55,7 @@
                 pkgs.xorriso
                 pkgs.dvdplusrwtools
                 pkgs.qrencode
+                pkgs.zbar
                 pkgs.rsync
                 pkgs.util-linux
                 pkgs.coreutils
@@ -88,6 +90,7 @@
             xorriso
             dvdplusrwtools
             qrencode
+            zbar
             rsync
             util-linux
             coreutils
@@ -122,6 +125,7 @@
             xorriso
             dvdplusrwtools
             qrencode
+            zbar
             rsync
             util-linux
             coreutils
@@ -0,0 +1,3637 @@
+//! Archive pipeline: stage, checksum, build the ISO, burn and index.
+//!
+//! This is the library entry point for creating discs without the TUI. Progress
+//! is reported through an optional callback; nothing here reads the terminal.
+//!
+//! ```no_run
+//! use bdarchive::archive::{self, ArchiveRequest};
+//! use bdarchive::{init_database, Config};
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let config = Config::load()?;
+//! let mut conn = init_database(&config.database_path()?)?;
+//! let request = ArchiveRequest::new("2024-BD-001", vec!["/home/me/Photos".into()], config);
+//! let outcome = archive::create_disc(&mut conn, &request, None)?;
+//! println!("{} files on {}", outcome.file_count, outcome.disc_id);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::burn::{self, BurnStats};
+use crate::config::{BurnConfig, Compression, Config, DiscLayout};
+use crate::database::{self, BurnSession};
+use crate::disc::{self, format_timestamp_now};
+use crate::search::{format_bytes_gb, format_size};
+use crate::size_cache::SizeCache;
+use crate::staging::WalkOptions;
+use crate::{bagit, compression, dedup, incremental, interrupt, iso, iso_library, manifest, paths, plan_report, qrcode, staging};
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+/// Pipeline phase reported through [`ArchiveEvent::Stage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveStage {
+    Staging,
+    GeneratingManifest,
+    CreatingIso,
+    Burning,
+    Indexing,
+    GeneratingQr,
+}
+
+/// Progress reported while a disc is being created.
+#[derive(Debug, Clone)]
+pub enum ArchiveEvent {
+    /// A new phase started (or the current phase has a new headline)
+    Stage(ArchiveStage, String),
+    /// Headline status for the current step
+    Status(String),
+    /// Fine-grained progress (file being copied, burn speed, ...)
+    Progress(String),
+    /// Share of the current phase done by bytes, 0.0-1.0
+    PhaseProgress(f64),
+    /// Files and bytes checksummed so far while the manifest is generated
+    Hashing(manifest::HashProgress),
+    /// Position within a multi-disc set, sent as each disc starts
+    MultiDiscProgress(MultiDiscProgress),
+}
+
+/// One line of the JSON progress stream (`--progress-json`): an
+/// [`ArchiveEvent`], or the outcome of the run, flattened into fields an
+/// external UI can read without knowing the Rust types.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ProgressRecord {
+    /// `stage`, `status`, `progress`, `phase_progress`, `hashing`,
+    /// `multi_disc`, or what the command adds (`insert_disc`, `complete`, `error`)
+    pub event: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<ArchiveStage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disc_current: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disc_total: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_done: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes_total: Option<u64>,
+    /// Share done, 0.0-1.0: of the phase, the checksumming or the whole set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fraction: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eta_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ProgressRecord {
+    pub fn new(event: &str) -> Self {
+        Self {
+            event: event.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// The record as a single line of JSON
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| format!("{{\"event\":\"{}\"}}", self.event))
+    }
+}
+
+impl From<&ArchiveEvent> for ProgressRecord {
+    fn from(event: &ArchiveEvent) -> Self {
+        match event {
+            ArchiveEvent::Stage(stage, message) => Self {
+                phase: Some(*stage),
+                ..Self::new("stage").with_message(message.as_str())
+            },
+            ArchiveEvent::Status(message) => Self::new("status").with_message(message.as_str()),
+            ArchiveEvent::Progress(message) => Self::new("progress").with_message(message.as_str()),
+            ArchiveEvent::PhaseProgress(fraction) => Self {
+                fraction: Some(*fraction),
+                ..Self::new("phase_progress")
+            },
+            ArchiveEvent::Hashing(progress) => Self {
+                bytes_done: Some(progress.bytes_done),
+                bytes_total: Some(progress.bytes_total),
+                fraction: Some(progress.fraction()),
+                ..Self::new("hashing").with_message(progress.current_path.to_string_lossy())
+            },
+            ArchiveEvent::MultiDiscProgress(progress) => Self {
+                disc_current: Some(progress.current),
+                disc_total: Some(progress.total),
+                bytes_done: Some(progress.bytes_done),
+                bytes_total: Some(progress.bytes_total),
+                fraction: Some(progress.fraction()),
+                eta_secs: progress.eta_secs,
+                ..Self::new("multi_disc")
+            },
+        }
+    }
+}
+
+/// Where a multi-disc run is, with an ETA for the rest of the set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MultiDiscProgress {
+    /// Disc being processed (1-based); earlier discs are finished
+    pub current: usize,
+    pub total: usize,
+    /// Planned bytes of the finished discs
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+    /// Time left for the whole set at the throughput measured so far;
+    /// None until the first disc of this run has finished
+    pub eta_secs: Option<u64>,
+}
+
+impl MultiDiscProgress {
+    pub fn discs_completed(&self) -> usize {
+        self.current.saturating_sub(1).min(self.total)
+    }
+
+    pub fn discs_remaining(&self) -> usize {
+        self.total - self.discs_completed()
+    }
+
+    /// Overall progress by bytes, 0.0-1.0
+    pub fn fraction(&self) -> f64 {
+        if self.bytes_total == 0 {
+            return 0.0;
+        }
+        (self.bytes_done as f64 / self.bytes_total as f64).clamp(0.0, 1.0)
+    }
+}
+
+/// Measures throughput across a set run to estimate the remaining time.
+/// Includes disc swaps and ISO creation, so the ETA reflects wall-clock time.
+struct SetProgressTracker {
+    started: std::time::Instant,
+    total: usize,
+    bytes_total: u64,
+    /// Bytes already burned before this run started (resumed sessions)
+    bytes_before_run: u64,
+    bytes_done: u64,
+}
+
+impl SetProgressTracker {
+    fn new(plans: &[staging::DiscPlan], already_done: &[usize]) -> Self {
+        let bytes_before_run = plans
+            .iter()
+            .filter(|p| already_done.contains(&p.disc_number))
+            .map(|p| p.used_bytes)
+            .sum();
+        Self {
+            started: std::time::Instant::now(),
+            total: plans.len(),
+            bytes_total: plans.iter().map(|p| p.used_bytes).sum(),
+            bytes_before_run,
+            bytes_done: bytes_before_run,
+        }
+    }
+
+    fn disc_done(&mut self, plan: &staging::DiscPlan) {
+        self.bytes_done += plan.used_bytes;
+    }
+
+    fn progress(&self, current: usize) -> MultiDiscProgress {
+        MultiDiscProgress {
+            current,
+            total: self.total,
+            bytes_done: self.bytes_done,
+            bytes_total: self.bytes_total,
+            eta_secs: estimate_eta_secs(
+                self.bytes_done - self.bytes_before_run,
+                self.started.elapsed(),
+                self.bytes_total.saturating_sub(self.bytes_done),
+            ),
+        }
+    }
+}
+
+/// Seconds needed for `bytes_remaining` at the rate `bytes_measured` took over `elapsed`
+fn estimate_eta_secs(bytes_measured: u64, elapsed: Duration, bytes_remaining: u64) -> Option<u64> {
+    if bytes_measured == 0 || elapsed.is_zero() {
+        return None;
+    }
+    let bytes_per_sec = bytes_measured as f64 / elapsed.as_secs_f64();
+    Some((bytes_remaining as f64 / bytes_per_sec).ceil() as u64)
+}
+
+/// Receives [`ArchiveEvent`]s. Shared with helper threads, hence `Fn + Sync`.
+pub type ProgressCallback = Arc<dyn Fn(ArchiveEvent) + Send + Sync>;
+
+/// Inputs for [`create_disc`] and [`create_multi_disc`].
+#[derive(Debug, Clone)]
+pub struct ArchiveRequest {
+    /// Disc ID, or the base ID for a multi-disc set (discs get `-1`, `-2`, ...,
+    /// or replace a `{set}` placeholder)
+    pub disc_id: String,
+    pub notes: String,
+    pub source_folders: Vec<PathBuf>,
+    /// Disc-side folder names for renamed sources; the others keep their own name
+    pub destination_names: BTreeMap<PathBuf, String>,
+    pub dry_run: bool,
+    /// Only archive files not already on an earlier disc (single discs only)
+    pub incremental: bool,
+    pub config: Config,
+}
+
+impl ArchiveRequest {
+    pub fn new(disc_id: impl Into<String>, source_folders: Vec<PathBuf>, config: Config) -> Self {
+        Self {
+            disc_id: disc_id.into(),
+            notes: String::new(),
+            source_folders,
+            destination_names: BTreeMap::new(),
+            dry_run: false,
+            incremental: false,
+            config,
+        }
+    }
+
+    /// Each source folder with its destination label, if renamed
+    pub fn sources(&self) -> Vec<(PathBuf, Option<String>)> {
+        self.source_folders
+            .iter()
+            .map(|folder| (folder.clone(), self.destination_names.get(folder).cloned()))
+            .collect()
+    }
+
+    fn notes(&self) -> Option<&str> {
+        if self.notes.is_empty() {
+            None
+        } else {
+            Some(&self.notes)
+        }
+    }
+}
+
+/// Result of creating one disc.
+#[derive(Debug, Clone)]
+pub struct ArchiveOutcome {
+    pub disc_id: String,
+    pub volume_label: String,
+    /// ISO image in the staging directory (None for direct burns)
+    pub iso_path: Option<PathBuf>,
+    pub size_bytes: u64,
+    /// Files listed in the disc's manifest (0 when no manifest was generated)
+    pub file_count: usize,
+    pub manifest_hash: Option<String>,
+    /// None for dry runs
+    pub burn_stats: Option<BurnStats>,
+    /// Dry-run plan reports written to the staging directory
+    pub report_paths: Vec<PathBuf>,
+}
+
+/// Result of creating (or resuming) a multi-disc set.
+#[derive(Debug, Clone)]
+pub struct MultiDiscOutcome {
+    pub set_id: String,
+    pub session_id: String,
+    /// Discs created by this run, in sequence order
+    pub discs: Vec<ArchiveOutcome>,
+    pub total_size: u64,
+}
+
+/// Why a disc could not be created, classified so callers can offer the right recovery.
+#[derive(Debug, Clone)]
+pub enum ArchiveError {
+    /// The request itself is unusable (empty ID, missing sources, nothing new to archive)
+    InvalidRequest(String),
+    CapacityExceeded { required: u64, capacity: u64 },
+    /// The optical drive is missing, not a block device or busy
+    DeviceUnavailable(String),
+    /// Staging, checksumming or writing disc metadata failed
+    StagingIo(String),
+    /// The volume holding the catalog or QR codes has less than `min_data_free_mb` free
+    CatalogSpaceLow(String),
+    IsoFailed(String),
+    BurnFailed(String),
+    DatabaseError(String),
+    Cancelled,
+}
+
+impl ArchiveError {
+    /// What the user can do about it, for display under the error message
+    pub fn recovery_hint(&self) -> Option<&'static str> {
+        match self {
+            Self::InvalidRequest(_) | Self::Cancelled => None,
+            Self::CapacityExceeded { .. } => {
+                Some("Deselect some folders, or archive them as a multi-disc set.")
+            }
+            Self::DeviceUnavailable(_) => Some(
+                "Check that the drive is connected and not mounted, and that `device` in the config points to it.",
+            ),
+            Self::StagingIo(_) => {
+                Some("Check free space and permissions in the staging directory and that the sources are readable.")
+            }
+            Self::CatalogSpaceLow(_) => Some(
+                "Free up space on the volume holding the catalog, or lower `min_data_free_mb` in the config.",
+            ),
+            Self::IsoFailed(_) => Some("Check that xorriso is installed and the staging directory has room for the image."),
+            Self::BurnFailed(_) => {
+                Some("Insert a fresh blank disc before retrying; a BD-R that failed mid-burn cannot be reused.")
+            }
+            Self::DatabaseError(_) => {
+                Some("Check the database path and permissions; the disc itself may already have been written.")
+            }
+        }
+    }
+}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRequest(msg)
+            | Self::DeviceUnavailable(msg)
+            | Self::StagingIo(msg)
+            | Self::CatalogSpaceLow(msg)
+            | Self::IsoFailed(msg)
+            | Self::BurnFailed(msg)
+            | Self::DatabaseError(msg) => write!(f, "{}", msg),
+            Self::CapacityExceeded { required, capacity } => write!(
+                f,
+                "Total size {} exceeds disc capacity {}",
+                format_bytes_gb(*required),
+                format_bytes_gb(*capacity)
+            ),
+            Self::Cancelled => write!(f, "Operation cancelled by user"),
+        }
+    }
+}
+
+impl std::error::Error for ArchiveError {}
+
+/// Attach an [`ArchiveError`] category and a short context to a lower-level error.
+trait Classify<T> {
+    fn classify(self, kind: fn(String) -> ArchiveError, context: &str) -> Result<T, ArchiveError>;
+}
+
+impl<T, E: fmt::Display> Classify<T> for std::result::Result<T, E> {
+    fn classify(self, kind: fn(String) -> ArchiveError, context: &str) -> Result<T, ArchiveError> {
+        self.map_err(|e| kind(format!("{}: {:#}", context, e)))
+    }
+}
+
+/// Multi-disc operation error types for better error handling
+#[derive(Debug, Clone)]
+pub enum MultiDiscError {
+    PlanningFailed(String),
+    HardwareFailure(String),
+    /// The first disc of the run failed; its set's burn session is kept so
+    /// the disc can be retried with [`retry_failed_disc`], or re-planned with
+    /// [`replan_overflowing_disc`] when its image was too big
+    BurnFailed { session_id: String, disc_number: usize, error: ArchiveError },
+    UserCancelled,
+    /// Some discs of the set were burned; the set's burn session is kept so it
+    /// can be resumed, or abandoned with [`abort_multi_disc`]
+    PartialSuccess { session_id: String, completed_discs: Vec<usize>, failed_disc: usize, error: ArchiveError },
+    StagingFailed { disc_number: usize, error: ArchiveError },
+    DatabaseInconsistency(String),
+}
+
+impl MultiDiscError {
+    /// Classify the failure of `failed_disc`. Once earlier discs of the set are
+    /// burned the run is a partial success whatever the cause.
+    fn from_disc_failure(session_id: &str, completed_discs: Vec<usize>, failed_disc: usize, error: ArchiveError) -> Self {
+        match error {
+            ArchiveError::Cancelled => Self::UserCancelled,
+            error if !completed_discs.is_empty() => Self::PartialSuccess {
+                session_id: session_id.to_string(),
+                completed_discs,
+                failed_disc,
+                error,
+            },
+            ArchiveError::DeviceUnavailable(msg) => Self::HardwareFailure(msg),
+            ArchiveError::DatabaseError(msg) => Self::DatabaseInconsistency(msg),
+            error @ (ArchiveError::IsoFailed(_) | ArchiveError::BurnFailed(_) | ArchiveError::CapacityExceeded { .. }) => {
+                Self::BurnFailed { session_id: session_id.to_string(), disc_number: failed_disc, error }
+            }
+            error => Self::StagingFailed { disc_number: failed_disc, error },
+        }
+    }
+
+    /// What the user can do about it, for display under the error message
+    pub fn recovery_hint(&self) -> Option<&'static str> {
+        match self {
+            Self::PlanningFailed(_) => Some("Check that the source folders exist and are readable."),
+            Self::HardwareFailure(msg) => ArchiveError::DeviceUnavailable(msg.clone()).recovery_hint(),
+            Self::BurnFailed { error, .. } | Self::StagingFailed { error, .. } => error.recovery_hint(),
+            Self::PartialSuccess { .. } => {
+                Some("Completed discs are indexed. Fix the problem, then continue the set from Resume Burn.")
+            }
+            Self::DatabaseInconsistency(msg) => ArchiveError::DatabaseError(msg.clone()).recovery_hint(),
+            Self::UserCancelled => None,
+        }
+    }
+}
+
+impl fmt::Display for MultiDiscError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlanningFailed(msg) => write!(f, "Planning failed: {}", msg),
+            Self::HardwareFailure(msg) => write!(f, "Hardware error: {}", msg),
+            Self::BurnFailed { disc_number, error, .. } => write!(f, "Disc {} failed to burn: {}", disc_number, error),
+            Self::UserCancelled => write!(f, "Operation cancelled by user"),
+            Self::PartialSuccess { completed_discs, failed_disc, error, .. } => write!(
+                f,
+                "Partial success: {} discs completed, disc {} failed: {}",
+                completed_discs.len(),
+                failed_disc,
+                error
+            ),
+            Self::StagingFailed { disc_number, error } => write!(f, "Disc {} staging failed: {}", disc_number, error),
+            Self::DatabaseInconsistency(msg) => write!(f, "Database error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for MultiDiscError {}
+
+/// Cloneable handle around the optional progress callback.
+#[derive(Clone, Default)]
+struct Reporter(Option<ProgressCallback>);
+
+impl Reporter {
+    fn emit(&self, event: ArchiveEvent) {
+        if let Some(callback) = &self.0 {
+            callback(event);
+        }
+    }
+
+    fn stage(&self, stage: ArchiveStage, msg: impl Into<String>) {
+        self.emit(ArchiveEvent::Stage(stage, msg.into()));
+    }
+
+    fn status(&self, msg: impl Into<String>) {
+        self.emit(ArchiveEvent::Status(msg.into()));
+    }
+
+    fn progress(&self, msg: impl Into<String>) {
+        self.emit(ArchiveEvent::Progress(msg.into()));
+    }
+
+    fn phase_progress(&self, done: u64, total: u64) {
+        if total > 0 {
+            self.emit(ArchiveEvent::PhaseProgress((done as f64 / total as f64).clamp(0.0, 1.0)));
+        }
+    }
+
+    /// Report `bytes_done()` against `total` about twice a second until the
+    /// returned guard is dropped.
+    fn watch_bytes(&self, total: u64, bytes_done: impl Fn() -> u64 + Send + 'static) -> PhaseWatch {
+        let stop = Arc::new(AtomicBool::new(false));
+        let reporter = self.clone();
+        let stopped = stop.clone();
+        let handle = thread::spawn(move || {
+            let mut last = None;
+            while !stopped.load(Ordering::Relaxed) {
+                let done = bytes_done();
+                if last != Some(done) {
+                    reporter.phase_progress(done, total);
+                    last = Some(done);
+                }
+                thread::sleep(Duration::from_millis(500));
+            }
+        });
+        PhaseWatch { stop, handle: Some(handle) }
+    }
+
+    /// Forwards checksum progress, which also drives the phase gauge
+    fn hash_callback(&self) -> manifest::HashProgressCallback {
+        let reporter = self.clone();
+        Box::new(move |progress: &manifest::HashProgress| {
+            reporter.emit(ArchiveEvent::PhaseProgress(progress.fraction()));
+            reporter.emit(ArchiveEvent::Hashing(progress.clone()));
+        })
+    }
+
+    /// Adapter for the `&str` callbacks used by staging
+    fn text_callback(&self) -> Box<dyn FnMut(&str) + Send> {
+        let reporter = self.clone();
+        Box::new(move |msg: &str| reporter.progress(msg))
+    }
+}
+
+/// Background byte reporting for one phase; stops when dropped.
+struct PhaseWatch {
+    stop: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl Drop for PhaseWatch {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Create a single disc: stage, checksum, build the ISO, burn, index and label.
+pub fn create_disc(
+    conn: &mut Connection,
+    request: &ArchiveRequest,
+    progress: Option<ProgressCallback>,
+) -> Result<ArchiveOutcome, ArchiveError> {
+    let reporter = Reporter(progress);
+    // A `{set}` placeholder only means something for multi-disc sets
+    let request = &ArchiveRequest {
+        disc_id: disc::expand_set_placeholder(&request.disc_id, None),
+        ..request.clone()
+    };
+    let config = &request.config;
+    let disc_id = request.disc_id.as_str();
+    let dry_run = request.dry_run;
+    let source_folders = &request.source_folders;
+
+    reporter.status(format!(
+        "Starting disc creation (mode: {})...",
+        if dry_run { "DRY RUN" } else { "ACTUAL" }
+    ));
+
+    // Validate inputs
+    if disc_id.is_empty() {
+        return Err(ArchiveError::InvalidRequest("Disc ID cannot be empty".to_string()));
+    }
+    if source_folders.is_empty() {
+        return Err(ArchiveError::InvalidRequest("No source folders selected".to_string()));
+    }
+    // Refuse a reused ID now, not when indexing fails after the burn
+    let existing = disc::find_existing_disc_ids(conn, &[disc_id.to_string()])
+        .classify(ArchiveError::DatabaseError, "Failed to check disc ID")?;
+    if !existing.is_empty() {
+        return Err(ArchiveError::InvalidRequest(format!(
+            "Disc ID already exists in catalog: {}",
+            existing.join(", ")
+        )));
+    }
+    for folder in source_folders {
+        if !folder.exists() {
+            return Err(ArchiveError::InvalidRequest(format!(
+                "Source folder does not exist: {}",
+                folder.display()
+            )));
+        }
+    }
+    staging::preflight_source_folders(source_folders)
+        .map_err(|e| ArchiveError::InvalidRequest(format!("{:#}", e)))?;
+    let sources = request.sources();
+    staging::validate_destination_names(&sources).map_err(|e| ArchiveError::InvalidRequest(format!("{:#}", e)))?;
+    if !dry_run {
+        burn::check_device(&config.device).map_err(|e| ArchiveError::DeviceUnavailable(format!("{:#}", e)))?;
+        // Reject non-M-DISC media before staging rather than at burn time
+        if config.burn.mdisc {
+            burn::check_mdisc_media(&config.device)
+                .map_err(|e| ArchiveError::DeviceUnavailable(format!("{:#}", e)))?;
+        }
+        check_catalog_space(config)?;
+    }
+
+    let staging_dir = config
+        .staging_dir()
+        .classify(ArchiveError::StagingIo, "Failed to get staging directory")?;
+    std::fs::create_dir_all(&staging_dir).classify(ArchiveError::StagingIo, "Failed to create staging directory")?;
+
+    // Step 1: Create disc layout
+    reporter.stage(ArchiveStage::Staging, "Creating disc layout...");
+    let disc_root = disc::create_disc_layout(&staging_dir, disc_id, source_folders, request.notes())
+        .classify(ArchiveError::StagingIo, "Failed to create disc layout")?;
+    reporter.status("Disc layout created");
+
+    // Step 2: Stage files
+    reporter.stage(ArchiveStage::Staging, "Staging files...");
+    // Staging looks rsync up itself and falls back to copying without it
+    let use_rsync = config.optional_tools.use_rsync;
+
+    let bytes_staged = Arc::new(AtomicU64::new(0));
+    let failed = staging::FailedFiles::default();
+    let skip_failed = config.staging.skip_unreadable.then_some(&failed);
+    let incremental_selection = if request.incremental {
+        reporter.status("Comparing source files with the archive index...");
+        let selection = incremental::select_changed_files(conn, &sources, &config.staging)
+            .classify(ArchiveError::StagingIo, "Failed to compare sources with the archive index")?;
+        if selection.changed.is_empty() {
+            return Err(ArchiveError::InvalidRequest(format!(
+                "Nothing to archive: all {} files are already on discs {}",
+                selection.unchanged_count,
+                selection.baseline_disc_ids.join(", ")
+            )));
+        }
+        reporter.status(format!(
+            "Incremental: {} new or changed files ({}), {} unchanged files skipped",
+            selection.changed.len(),
+            format_size(selection.changed_bytes()),
+            selection.unchanged_count
+        ));
+        let counter = bytes_staged.clone();
+        let _watch = reporter.watch_bytes(selection.changed_bytes(), move || counter.load(Ordering::Relaxed));
+        if config.staging.compression == Compression::Zstd {
+            compress_sources(&reporter, &disc_root, &selection.changed, request, &bytes_staged)?;
+        } else {
+            staging::stage_file_list(
+                &disc_root,
+                &selection.changed,
+                dry_run,
+                &config.staging,
+                Some(reporter.text_callback()),
+                Some(&bytes_staged),
+                skip_failed,
+            )
+            .classify(ArchiveError::StagingIo, "Staging failed")?;
+        }
+        Some(selection)
+    } else {
+        let source_bytes = source_folders
+            .iter()
+            .map(|folder| staging::calculate_directory_size(folder).unwrap_or(0))
+            .sum();
+        let counter = bytes_staged.clone();
+        let _watch = reporter.watch_bytes(source_bytes, move || counter.load(Ordering::Relaxed));
+        if config.staging.compression == Compression::Zstd {
+            let files = staging::collect_named_source_files(&sources, WalkOptions::from(&config.staging))
+                .classify(ArchiveError::StagingIo, "Failed to list source files")?;
+            compress_sources(&reporter, &disc_root, &files, request, &bytes_staged)?;
+        } else {
+            staging::stage_files_with_options(
+                &disc_root,
+                &sources,
+                use_rsync,
+                dry_run,
+                &config.staging,
+                Some(reporter.text_callback()),
+                Some(&bytes_staged),
+                skip_failed,
+            )
+            .classify(ArchiveError::StagingIo, "Staging failed")?;
+        }
+        None
+    };
+    reporter.stage(ArchiveStage::Staging, "Files staged successfully");
+    stop_if_interrupted()?;
+
+    let failed_files = failed.take();
+    if !failed_files.is_empty() {
+        report_failed_files(&reporter, &disc_root, &failed_files, dry_run)?;
+    }
+
+    if !dry_run {
+        let renamed = staging::normalize_staged_names(&disc_root.join("ARCHIVE"))
+            .classify(ArchiveError::StagingIo, "Failed to normalize file names")?;
+        if renamed > 0 {
+            reporter.status(format!("Normalized {} file and folder names to Unicode NFC", renamed));
+        }
+    }
+
+    let duplicates = if config.staging.dedup && !dry_run {
+        let duplicates = dedup::remove_staged_duplicates(&disc_root.join("ARCHIVE"), &disc_root)
+            .classify(ArchiveError::StagingIo, "Deduplication failed")?;
+        if !duplicates.is_empty() {
+            reporter.status(format!(
+                "Deduplicated {} identical files, saving {}",
+                duplicates.len(),
+                format_size(dedup::saved_bytes(&duplicates))
+            ));
+        }
+        duplicates
+    } else {
+        Vec::new()
+    };
+
+    disc::write_disc_info(
+        &disc_root,
+        disc_id,
+        request.notes(),
+        source_folders,
+        &disc::get_tool_version(),
+        None,
+        None,
+        None,
+    )
+    .classify(ArchiveError::StagingIo, "Failed to write disc info")?;
+    disc::write_checksum_info(&disc_root, config.staging.checksum)
+        .classify(ArchiveError::StagingIo, "Failed to write disc info")?;
+    disc::write_iso_info(&disc_root, &iso::IsoMetadata::from(&config.iso))
+        .classify(ArchiveError::StagingIo, "Failed to write disc info")?;
+    if let Some(ref selection) = incremental_selection {
+        disc::write_incremental_info(&disc_root, &selection.baseline_disc_ids, selection.unchanged_count)
+            .classify(ArchiveError::StagingIo, "Failed to write incremental info")?;
+    }
+
+    let volume_label = disc::generate_volume_label(disc_id);
+    reporter.status(format!("💿 Volume label: {}", volume_label));
+
+    // Step 3: Generate manifest and SHA256SUMS
+    reporter.stage(ArchiveStage::GeneratingManifest, "Generating manifest and checksums...");
+    reporter.progress(format!("🔐 Checksums: {}", config.staging.checksum.description()));
+    let mut files = manifest::generate_manifest_and_sums_with_progress(
+        &disc_root,
+        None,
+        Some(reporter.hash_callback()),
+        config.staging.checksum,
+        config.staging.max_retries,
+    )
+    .classify(ArchiveError::StagingIo, "Manifest generation failed")?;
+    reporter.progress(format!("✅ Checksum generation complete: {} files processed", files.len()));
+    manifest::add_duplicate_entries(&mut files, &duplicates);
+
+    let manifest_path = disc_root.join("MANIFEST.txt");
+    manifest::write_manifest_file(&manifest_path, &files)
+        .classify(ArchiveError::StagingIo, "Failed to write manifest file")?;
+    info!("Manifest file written successfully");
+
+    let sums_path = manifest::write_checksum_list(&disc_root, &files, config.staging.checksum)
+        .classify(ArchiveError::StagingIo, "Failed to write checksum list")?;
+    info!("Checksum list written: {}", sums_path.display());
+
+    // One timestamp for manifest.json, the catalog record and the label
+    let created_at = format_timestamp_now();
+    let disc_manifest = manifest::DiscManifest::new(
+        manifest::DiscManifestInfo {
+            disc_id: disc_id.to_string(),
+            volume_label: volume_label.clone(),
+            created_at: created_at.clone(),
+            tool_version: disc::get_tool_version(),
+            notes: request.notes().map(str::to_string),
+            source_roots: source_folders.iter().map(|p| p.to_string_lossy().into_owned()).collect(),
+            set_id: None,
+            sequence_number: None,
+            total_discs: None,
+        },
+        files,
+    );
+    manifest::write_manifest_json(&disc_root.join(manifest::MANIFEST_JSON_FILE), &disc_manifest)
+        .classify(ArchiveError::StagingIo, "Failed to write manifest.json")?;
+    let files = disc_manifest.files;
+
+    let manifest_hash = match manifest::calculate_manifest_hash(&sums_path) {
+        Ok(hash) => Some(hash),
+        Err(e) => {
+            warn!("Failed to hash checksum list: {}", e);
+            None
+        }
+    };
+
+    if config.staging.layout == DiscLayout::Bagit {
+        reporter.status("Packaging disc as a BagIt bag...");
+        let info = bagit::BagInfo {
+            external_identifier: disc_id.to_string(),
+            description: request.notes().map(str::to_string),
+        };
+        let oxum = bagit::make_bag(&disc_root, &files, &info)
+            .classify(ArchiveError::StagingIo, "Failed to package BagIt bag")?;
+        reporter.status(format!("📦 BagIt bag written: {} payload files, {}", oxum.files, format_size(oxum.bytes)));
+    }
+
+    // Check capacity against the image the staged files will make
+    let estimate = iso::IsoSizeEstimate::from_files(
+        files
+            .iter()
+            .filter(|f| f.duplicate_of.is_none())
+            .map(|f| (f.rel_path.as_path(), if f.link_target.is_some() { 0 } else { f.size })),
+    );
+    let capacity = config.default_capacity_bytes();
+    if estimate.iso_bytes > capacity {
+        let err = ArchiveError::CapacityExceeded { required: estimate.iso_bytes, capacity };
+        error!("Capacity check failed: {}", err);
+        return Err(err);
+    }
+    info!(
+        "Capacity check passed: {} of content, estimated ISO {} / {}",
+        format_bytes_gb(estimate.content_bytes),
+        format_bytes_gb(estimate.iso_bytes),
+        format_bytes_gb(capacity)
+    );
+
+    // Step 4: Create ISO (skip if using direct burn and not dry run)
+    let iso_path = staging_dir.join(disc::iso_file_name(disc_id));
+    // The ISO library needs an image to keep, so it overrides the direct method
+    let direct_burn = config.burn.method == "direct" && !config.iso_library.enabled;
+
+    // Files of 4 GiB or more need ISO 9660 level 3; tell the user which level the disc uses
+    let mut iso_metadata = iso::IsoMetadata::from(&config.iso);
+    if !dry_run {
+        let choice = iso::choose_iso_level(iso_metadata.level, &disc_root)
+            .classify(ArchiveError::StagingIo, "Failed to check staged file sizes")?;
+        if let Some(notice) = choice.notice() {
+            reporter.status(notice);
+        }
+        iso_metadata.level = choice.level;
+    }
+
+    let iso_size = if direct_burn && !dry_run {
+        info!("Skipping ISO creation (using direct burn method)");
+        reporter.stage(
+            ArchiveStage::CreatingIso,
+            format!("Direct burn - skipping ISO creation ({})", format_bytes_gb(estimate.iso_bytes)),
+        );
+        estimate.iso_bytes
+    } else {
+        reporter.stage(ArchiveStage::CreatingIso, "Creating ISO image...");
+        info!("Creating ISO at: {}", iso_path.display());
+        let iso_watch = {
+            let iso_path = iso_path.clone();
+            reporter.watch_bytes(estimate.iso_bytes, move || std::fs::metadata(&iso_path).map(|m| m.len()).unwrap_or(0))
+        };
+        iso::create_iso(&disc_root, &iso_path, &volume_label, &iso_metadata, false)
+            .classify(ArchiveError::IsoFailed, "ISO creation failed")?;
+        drop(iso_watch);
+        let size = iso::get_iso_size(&iso_path).classify(ArchiveError::IsoFailed, "Failed to get ISO size")?;
+        info!("ISO created successfully: {} bytes", size);
+        reporter.stage(ArchiveStage::CreatingIso, format!("ISO created: {}", format_bytes_gb(size)));
+        size
+    };
+
+    // Step 5: Burn to disc (or keep the ISO for a dry run)
+    stop_if_interrupted()?;
+    reporter.stage(
+        ArchiveStage::Burning,
+        if dry_run {
+            "Creating ISO for dry run...".to_string()
+        } else {
+            format!("Burning to {}...", config.device)
+        },
+    );
+
+    let mut burn_stats = None;
+    let mut report_paths = Vec::new();
+    if dry_run {
+        // The ISO was built above for both burn methods so it can be archived manually
+        reporter.stage(
+            ArchiveStage::Burning,
+            format!("DRY RUN COMPLETE - ISO saved at: {}", iso_path.display()),
+        );
+        info!("Dry run ISO available at: {}", iso_path.display());
+
+        let plans = staging::plan_disc_layout(source_folders, config.default_capacity_bytes(), staging::WalkOptions::from(&config.staging))
+            .and_then(|mut plans| {
+                if config.staging.dedup {
+                    staging::dedup_plans(&mut plans)?;
+                }
+                Ok(plans)
+            });
+        match plans {
+            Ok(plans) => report_paths = write_dry_run_report(disc_id, &plans, source_folders, files.len(), config, &reporter),
+            Err(e) => warn!("Could not plan layout for dry-run report: {}", e),
+        }
+    } else {
+        burn_stats = if direct_burn {
+            // Burn the staging directory directly (no ISO needed)
+            burn_direct_with_progress(&disc_root, &config.device, &config.burn, &reporter)
+        } else {
+            burn_iso_with_progress(&iso_path, &config.device, &config.burn, &reporter)
+        }
+        .map_err(|e| ArchiveError::BurnFailed(format!("{:#}", e)))?;
+        reporter.stage(ArchiveStage::Burning, "Disc burned successfully");
+        eject_after_burn(config, &config.device, &reporter);
+    }
+
+    // Step 6: Index in database (dry runs as a planned disc, if enabled)
+    if !dry_run || config.burn.index_dry_runs {
+        reporter.stage(ArchiveStage::Indexing, "Updating index...");
+        index_disc_in_database(conn, request, &volume_label, &created_at, iso_size, manifest_hash.as_deref())
+            .classify(ArchiveError::DatabaseError, "Database indexing failed")?;
+        if let Some(stats) = burn_stats {
+            if let Err(e) = database::Disc::record_burn_stats(conn, disc_id, stats.duration_secs, stats.avg_write_speed_mbps) {
+                warn!("Failed to record burn stats for {}: {}", disc_id, e);
+            }
+        }
+        if let Err(e) = database::record_failed_files(conn, disc_id, &failed_files) {
+            warn!("Failed to record skipped files for {}: {}", disc_id, e);
+        }
+        reporter.stage(
+            ArchiveStage::Indexing,
+            if dry_run { "Recorded as a planned disc" } else { "Database updated successfully" },
+        );
+
+        index_files_in_database(conn, disc_id, &files).classify(ArchiveError::DatabaseError, "File indexing failed")?;
+        reporter.progress("Files indexed in database");
+        backup_catalog(conn, config, &reporter);
+    } else {
+        reporter.stage(ArchiveStage::Indexing, "Dry run not recorded in the catalog");
+    }
+
+    // Step 7: Generate QR code
+    reporter.stage(ArchiveStage::GeneratingQr, "Generating QR code...");
+    if config.optional_tools.use_qrencode {
+        let label_info = qrcode::DiscLabelInfo {
+            volume_label: volume_label.clone(),
+            created_at: created_at.clone(),
+            file_count: files.len(),
+            manifest_hash: manifest_hash.clone(),
+            storage_location: None,
+            set_id: None,
+            sequence_number: None,
+        };
+        match generate_qr_code(config, disc_id, &label_info, dry_run) {
+            Ok(_) => reporter.status("QR code generated"),
+            Err(e) => {
+                warn!("QR code generation failed: {:#}", e);
+                reporter.status(format!("QR code generation skipped: {:#}", e));
+            }
+        }
+        if !dry_run {
+            match generate_label(config, disc_id, &label_info) {
+                Ok(path) => reporter.status(format!("Label written: {}", path.display())),
+                Err(e) => {
+                    warn!("Label generation failed: {:#}", e);
+                    reporter.status(format!("Label generation skipped: {:#}", e));
+                }
+            }
+        }
+    } else {
+        reporter.status("QR code generation disabled");
+    }
+
+    // Step 8: Move the ISO into the library, then clean up staging according to the cleanup policy
+    let mut iso_path = iso_path;
+    if config.iso_library.enabled && !dry_run {
+        match iso_library::retain_iso(conn, config, disc_id, &iso_path) {
+            Ok(retained) => {
+                reporter.status(format!("ISO kept in library: {}", retained.display()));
+                iso_path = retained;
+            }
+            Err(e) => {
+                warn!("Failed to retain ISO for {}: {}", disc_id, e);
+                reporter.status(format!("ISO not kept in library: {}", e));
+            }
+        }
+    }
+    if let Err(e) = staging::cleanup_staging_dir(&staging_dir, &config.cleanup, dry_run) {
+        warn!("Failed to cleanup staging directory: {}", e);
+    }
+
+    Ok(ArchiveOutcome {
+        disc_id: disc_id.to_string(),
+        volume_label,
+        iso_path: if direct_burn && !dry_run { None } else { Some(iso_path) },
+        size_bytes: iso_size,
+        file_count: files.len(),
+        manifest_hash,
+        burn_stats,
+        report_paths,
+    })
+}
+
+/// Burn a pre-built ISO and record it in the catalog.
+///
+/// The disc ID is `disc_id` if given, else the one in the image's
+/// `manifest.json`, else its volume label. Files are indexed from the embedded
+/// manifest; images without one are recorded with their size and label only
+/// and cannot be verified against checksums later.
+pub fn burn_existing_iso(
+    conn: &mut Connection,
+    config: &Config,
+    iso_path: &Path,
+    disc_id: Option<&str>,
+    dry_run: bool,
+    progress: Option<ProgressCallback>,
+) -> Result<ArchiveOutcome, ArchiveError> {
+    let reporter = Reporter(progress);
+    if !iso_path.is_file() {
+        return Err(ArchiveError::InvalidRequest(format!("ISO not found: {}", iso_path.display())));
+    }
+    let volume_label = iso::read_volume_label(iso_path).map_err(|e| ArchiveError::InvalidRequest(format!("{:#}", e)))?;
+    let iso_size = iso::get_iso_size(iso_path).classify(ArchiveError::IsoFailed, "Failed to get ISO size")?;
+
+    reporter.stage(ArchiveStage::GeneratingManifest, "Reading embedded manifest...");
+    let (disc_manifest, manifest_hash) = read_embedded_manifest(config, iso_path);
+    if disc_manifest.is_none() {
+        warn!("{} has no BlueVault manifest; verification won't be available", iso_path.display());
+        reporter.progress("⚠️ No BlueVault manifest in this ISO: files won't be indexed and verification won't be available");
+    }
+
+    let disc_id = disc_id
+        .map(str::to_string)
+        .or_else(|| disc_manifest.as_ref().map(|m| m.disc.disc_id.clone()))
+        .unwrap_or_else(|| volume_label.clone());
+    disc::validate_disc_id(&disc_id).map_err(ArchiveError::InvalidRequest)?;
+    let existing = disc::find_existing_disc_ids(conn, std::slice::from_ref(&disc_id))
+        .classify(ArchiveError::DatabaseError, "Failed to check disc ID")?;
+    if !existing.is_empty() {
+        return Err(ArchiveError::InvalidRequest(format!(
+            "Disc ID already exists in catalog: {}",
+            existing.join(", ")
+        )));
+    }
+    if !dry_run {
+        burn::check_device(&config.device).map_err(|e| ArchiveError::DeviceUnavailable(format!("{:#}", e)))?;
+        if config.burn.mdisc {
+            burn::check_mdisc_media(&config.device)
+                .map_err(|e| ArchiveError::DeviceUnavailable(format!("{:#}", e)))?;
+        }
+        check_catalog_space(config)?;
+    }
+
+    stop_if_interrupted()?;
+    let mut burn_stats = None;
+    if dry_run {
+        reporter.stage(ArchiveStage::Burning, format!("DRY RUN - skipping burn of {}", iso_path.display()));
+    } else {
+        reporter.stage(ArchiveStage::Burning, format!("Burning to {}...", config.device));
+        burn_stats = burn_iso_with_progress(iso_path, &config.device, &config.burn, &reporter)
+            .map_err(|e| ArchiveError::BurnFailed(format!("{:#}", e)))?;
+        reporter.stage(ArchiveStage::Burning, "Disc burned successfully");
+        eject_after_burn(config, &config.device, &reporter);
+    }
+
+    if dry_run && !config.burn.index_dry_runs {
+        reporter.stage(ArchiveStage::Indexing, "Dry run not recorded in the catalog");
+        return Ok(ArchiveOutcome {
+            disc_id,
+            volume_label,
+            iso_path: Some(iso_path.to_path_buf()),
+            size_bytes: iso_size,
+            file_count: disc_manifest.map_or(0, |m| m.files.len()),
+            manifest_hash,
+            burn_stats,
+            report_paths: Vec::new(),
+        });
+    }
+
+    reporter.stage(ArchiveStage::Indexing, "Updating index...");
+    let info = disc_manifest.as_ref().map(|m| &m.disc);
+    let disc_record = database::Disc {
+        disc_id: disc_id.clone(),
+        volume_label: volume_label.clone(),
+        created_at: format_timestamp_now(),
+        notes: Some(format!("Burned from external ISO {}", iso_path.display())),
+        iso_size: Some(iso_size),
+        burn_device: if dry_run { None } else { Some(config.device.clone()) },
+        checksum_manifest_hash: manifest_hash.clone(),
+        qr_path: None,
+        source_roots: info
+            .filter(|info| !info.source_roots.is_empty())
+            .and_then(|info| serde_json::to_string(&info.source_roots).ok()),
+        tool_version: info.map(|info| info.tool_version.clone()),
+        set_id: None,
+        sequence_number: None,
+        burn_duration_secs: burn_stats.map(|s| s.duration_secs),
+        avg_write_speed_mbps: burn_stats.map(|s| s.avg_write_speed_mbps),
+        is_mdisc: config.burn.mdisc && !dry_run,
+        storage_location: None,
+        planned: dry_run,
+    };
+    database::Disc::insert(conn, &disc_record).classify(ArchiveError::DatabaseError, "Failed to insert disc record")?;
+
+    let files = disc_manifest.map(|m| m.files).unwrap_or_default();
+    index_files_in_database(conn, &disc_id, &files).classify(ArchiveError::DatabaseError, "File indexing failed")?;
+    reporter.stage(ArchiveStage::Indexing, format!("Indexed {} files", files.len()));
+    backup_catalog(conn, config, &reporter);
+
+    Ok(ArchiveOutcome {
+        disc_id,
+        volume_label,
+        iso_path: Some(iso_path.to_path_buf()),
+        size_bytes: iso_size,
+        file_count: files.len(),
+        manifest_hash,
+        burn_stats,
+        report_paths: Vec::new(),
+    })
+}
+
+/// Add a BlueVault disc that is missing from the catalog, e.g. one burned on
+/// another machine, from its mounted contents. The disc's manifest.json
+/// supplies the disc record and the file index; set membership is kept when
+/// the set is already catalogued.
+pub fn import_mounted_disc(conn: &mut Connection, config: &Config, root: &Path) -> Result<ArchiveOutcome, ArchiveError> {
+    let content_root = bagit::content_root(root);
+    let manifest_path = content_root.join(manifest::MANIFEST_JSON_FILE);
+    if !manifest_path.is_file() {
+        return Err(ArchiveError::InvalidRequest(format!(
+            "No {} on the disc at {}; only discs with a BlueVault manifest can be imported",
+            manifest::MANIFEST_JSON_FILE,
+            root.display()
+        )));
+    }
+    let disc_manifest = manifest::read_manifest_json(&manifest_path)
+        .map_err(|e| ArchiveError::InvalidRequest(format!("{:#}", e)))?;
+    let info = disc_manifest.disc;
+    disc::validate_disc_id(&info.disc_id).map_err(ArchiveError::InvalidRequest)?;
+    let existing = disc::find_existing_disc_ids(conn, std::slice::from_ref(&info.disc_id))
+        .classify(ArchiveError::DatabaseError, "Failed to check disc ID")?;
+    if !existing.is_empty() {
+        return Err(ArchiveError::InvalidRequest(format!(
+            "Disc ID already exists in catalog: {}",
+            existing.join(", ")
+        )));
+    }
+    let manifest_hash = manifest::calculate_manifest_hash(&content_root.join("SHA256SUMS.txt")).ok();
+
+    // Discs can't point at a set the catalog doesn't know
+    let set_id = match &info.set_id {
+        Some(set_id) => database::DiscSet::get(conn, set_id)
+            .classify(ArchiveError::DatabaseError, "Failed to look up disc set")?
+            .map(|set| set.set_id),
+        None => None,
+    };
+    let mut notes = info.notes.clone().unwrap_or_default();
+    if let (Some(missing), None) = (&info.set_id, &set_id) {
+        warn!("Set {} of disc {} is not in the catalog; importing the disc on its own", missing, info.disc_id);
+        if !notes.is_empty() {
+            notes.push('\n');
+        }
+        notes.push_str(&format!(
+            "Disc {} of {} in set {} (set not in catalog)",
+            info.sequence_number.map_or("?".to_string(), |n| n.to_string()),
+            info.total_discs.map_or("?".to_string(), |n| n.to_string()),
+            missing
+        ));
+    }
+
+    let disc_record = database::Disc {
+        disc_id: info.disc_id.clone(),
+        volume_label: info.volume_label.clone(),
+        created_at: info.created_at.clone(),
+        notes: (!notes.is_empty()).then_some(notes),
+        iso_size: None,
+        burn_device: None,
+        checksum_manifest_hash: manifest_hash.clone(),
+        qr_path: None,
+        source_roots: (!info.source_roots.is_empty())
+            .then(|| serde_json::to_string(&info.source_roots).ok())
+            .flatten(),
+        tool_version: Some(info.tool_version.clone()),
+        sequence_number: set_id.as_ref().and(info.sequence_number),
+        set_id: set_id.clone(),
+        burn_duration_secs: None,
+        avg_write_speed_mbps: None,
+        is_mdisc: false,
+        storage_location: None,
+        planned: false,
+    };
+    database::Disc::insert(conn, &disc_record).classify(ArchiveError::DatabaseError, "Failed to insert disc record")?;
+    if let Some(set_id) = &set_id {
+        database::DiscSet::record_progress(conn, set_id).classify(ArchiveError::DatabaseError, "Failed to update disc set")?;
+    }
+    let files = disc_manifest.files;
+    index_files_in_database(conn, &info.disc_id, &files).classify(ArchiveError::DatabaseError, "File indexing failed")?;
+    info!("Imported disc {} ({} files) from {}", info.disc_id, files.len(), root.display());
+    backup_catalog(conn, config, &Reporter(None));
+
+    Ok(ArchiveOutcome {
+        disc_id: info.disc_id,
+        volume_label: info.volume_label,
+        iso_path: None,
+        size_bytes: files.iter().map(|f| f.size).sum(),
+        file_count: files.len(),
+        manifest_hash,
+        burn_stats: None,
+        report_paths: Vec::new(),
+    })
+}
+
+/// Extract `manifest.json` and `SHA256SUMS.txt` from an ISO into the staging
+/// directory. Returns None for images without a readable BlueVault manifest.
+fn read_embedded_manifest(config: &Config, iso_path: &Path) -> (Option<manifest::DiscManifest>, Option<String>) {
+    let result = config.staging_dir().and_then(|staging_dir| {
+        let dir = staging_dir.join(".external-iso");
+        std::fs::create_dir_all(&dir).context("Failed to create extraction directory")?;
+        let manifest_path = dir.join(manifest::MANIFEST_JSON_FILE);
+        let sums_path = dir.join("SHA256SUMS.txt");
+        let _ = std::fs::remove_file(&manifest_path);
+        let _ = std::fs::remove_file(&sums_path);
+
+        let mut found = (None, None);
+        // Bagged discs keep the BlueVault files under data/
+        for root in ["", "/data"] {
+            if iso::extract_file(iso_path, &format!("{}/{}", root, manifest::MANIFEST_JSON_FILE), &manifest_path)? {
+                found.0 = Some(manifest::read_manifest_json(&manifest_path)?);
+                if iso::extract_file(iso_path, &format!("{}/SHA256SUMS.txt", root), &sums_path)? {
+                    found.1 = manifest::calculate_manifest_hash(&sums_path).ok();
+                }
+                break;
+            }
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+        Ok(found)
+    });
+    result.unwrap_or_else(|e| {
+        warn!("Could not read manifest from {}: {:#}", iso_path.display(), e);
+        (None, None)
+    })
+}
+
+/// Split the sources across as many discs as needed and burn them.
+///
+/// Discs are burned one after another, or in parallel when several burners are
+/// configured (see [`Config::burn_devices`]). `insert_disc(sequence, total, device)`
+/// is called before each real burn; returning false cancels the run with
+/// [`MultiDiscError::UserCancelled`]. Calls are never concurrent, and it is not
+/// called for dry runs.
+pub fn create_multi_disc<F>(
+    conn: &mut Connection,
+    request: &ArchiveRequest,
+    progress: Option<ProgressCallback>,
+    mut insert_disc: F,
+) -> Result<MultiDiscOutcome, MultiDiscError>
+where
+    F: FnMut(usize, usize, &str) -> bool + Send,
+{
+    let reporter = Reporter(progress);
+    let config = &request.config;
+
+    if request.incremental {
+        return Err(MultiDiscError::PlanningFailed(
+            "Incremental archiving is only supported for single discs".to_string(),
+        ));
+    }
+
+    reporter.status("🔍 Starting multi-disc archive creation with enhanced error handling...");
+    if config.staging.compression != Compression::None {
+        warn!("Compression is not applied to multi-disc sets; staging raw copies");
+        reporter.progress("⚠️ Compression applies to single discs only; this set is staged uncompressed");
+    }
+    if config.staging.layout != DiscLayout::Standard {
+        warn!("BagIt layout is not applied to multi-disc sets");
+        reporter.progress("⚠️ The BagIt layout applies to single discs only; this set uses the standard layout");
+    }
+
+    // Catch unreadable or stale sources before planning walks them
+    staging::preflight_source_folders(&request.source_folders)
+        .map_err(|e| MultiDiscError::PlanningFailed(format!("{:#}", e)))?;
+    staging::validate_destination_names(&request.sources())
+        .map_err(|e| MultiDiscError::PlanningFailed(format!("{:#}", e)))?;
+
+    // Phase 1: Planning
+    let plans = plan_multi_disc_archive(
+        &request.source_folders,
+        config.default_capacity_bytes(),
+        &config.staging,
+        &reporter,
+    )?;
+    let total_discs = plans.len();
+    let total_size: u64 = plans.iter().map(|p| p.used_bytes).sum();
+
+    let existing = disc::find_existing_disc_ids(conn, &disc::planned_disc_ids(&request.disc_id, total_discs))
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(e.to_string()))?;
+    if !existing.is_empty() {
+        return Err(MultiDiscError::PlanningFailed(format!(
+            "Disc ID already exists in catalog: {}",
+            existing.join(", ")
+        )));
+    }
+
+    // Phase 2: Database set and burn session, committed together so a crash
+    // can't leave a set without the plan needed to resume it
+    reporter.status("💾 Setting up database records...");
+    let source_folders_json = serde_json::to_string(&request.source_folders)
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(e.to_string()))?;
+    let disc_set = database::DiscSet {
+        set_id: database::generate_set_id(),
+        name: format!("Multi-disc archive: {}", request.disc_id),
+        description: request.notes().map(str::to_string),
+        total_size,
+        disc_count: total_discs as u32,
+        created_at: format_timestamp_now(),
+        source_roots: Some(source_folders_json),
+        status: database::SetStatus::InProgress,
+        completed_count: 0,
+    };
+    let set_id = disc_set.set_id.clone();
+
+    let mut session = BurnSession::new(
+        set_id.clone(),
+        request.disc_id.clone(),
+        total_discs,
+        request.source_folders.clone(),
+        serde_json::to_string(config).unwrap_or_default(),
+    );
+    session
+        .set_plans(&plans)
+        .and_then(|_| database::MultiDiscOps::create_disc_set_with_session(conn, &disc_set, &session))
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to create disc set: {}", e)))?;
+    reporter.progress(format!("✅ Database set '{}' created", set_id));
+
+    // Phase 3: Burn discs
+    let set = SetContext {
+        disc_id_base: &request.disc_id,
+        notes: &request.notes,
+        set_id: &set_id,
+        source_folders: &request.source_folders,
+        destination_names: &request.destination_names,
+        plans: &plans,
+        total_discs,
+        dry_run: request.dry_run,
+        config,
+    };
+    let mut discs = burn_set_discs(conn, &set, &plans, &[], &session.session_id, &reporter, &mut insert_disc)?;
+
+    // Phase 4: Final cleanup and reporting
+    if request.dry_run && !config.burn.index_dry_runs {
+        // The set and session only existed to drive the run
+        if let Err(e) = database::MultiDiscOps::delete_set(conn, &set_id) {
+            warn!("Failed to remove dry-run set {}: {}", set_id, e);
+        }
+    }
+    if request.dry_run {
+        let checksums_computed = discs.iter().map(|d| d.file_count).sum();
+        let report_paths =
+            write_dry_run_report(&request.disc_id, &plans, &request.source_folders, checksums_computed, config, &reporter);
+        if let Some(first) = discs.first_mut() {
+            first.report_paths = report_paths;
+        }
+    }
+    finalize_multi_disc_archive(&discs, total_size, request.dry_run, config, &reporter);
+
+    Ok(MultiDiscOutcome {
+        set_id,
+        session_id: session.session_id,
+        discs,
+        total_size,
+    })
+}
+
+/// Extend a finished set with discs for new source content.
+///
+/// Only `request.source_folders` are planned. The new discs continue the
+/// set's sequence numbering and disc ID pattern (`request.disc_id` is not
+/// used), and their DISC_INFO.txt names the existing set. The set's disc count
+/// and size grow before burning, with a burn session for the new discs so an
+/// interrupted append can be continued from Resume Burn. Labels of the earlier
+/// discs keep the old disc count. Dry runs leave the set untouched.
+pub fn append_to_disc_set<F>(
+    conn: &mut Connection,
+    set_id: &str,
+    request: &ArchiveRequest,
+    progress: Option<ProgressCallback>,
+    mut insert_disc: F,
+) -> Result<MultiDiscOutcome, MultiDiscError>
+where
+    F: FnMut(usize, usize, &str) -> bool + Send,
+{
+    let reporter = Reporter(progress);
+    let mut config = request.config.clone();
+    // Planned discs of a dry run must not join the set
+    config.burn.index_dry_runs = false;
+
+    let mut disc_set = database::DiscSet::get(conn, set_id)
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to load disc set: {}", e)))?
+        .ok_or_else(|| MultiDiscError::PlanningFailed(format!("Disc set not found: {}", set_id)))?;
+    if disc_set.status != database::SetStatus::Complete {
+        return Err(MultiDiscError::PlanningFailed(format!(
+            "Set {} is {}; finish it from Resume Burn before adding discs",
+            set_id,
+            disc_set.status.label()
+        )));
+    }
+    let existing_discs = database::DiscSet::get_discs(conn, set_id)
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to load discs of set: {}", e)))?;
+    let disc_id_base = set_disc_id_base(conn, set_id, &existing_discs)?;
+    let last_disc = existing_discs
+        .iter()
+        .filter_map(|d| d.sequence_number)
+        .max()
+        .unwrap_or(0)
+        .max(disc_set.disc_count) as usize;
+
+    reporter.status(format!("➕ Adding discs to set '{}' after disc {}", disc_set.name, last_disc));
+    staging::preflight_source_folders(&request.source_folders)
+        .map_err(|e| MultiDiscError::PlanningFailed(format!("{:#}", e)))?;
+    staging::validate_destination_names(&request.sources())
+        .map_err(|e| MultiDiscError::PlanningFailed(format!("{:#}", e)))?;
+
+    let mut plans = plan_multi_disc_archive(
+        &request.source_folders,
+        config.default_capacity_bytes(),
+        &config.staging,
+        &reporter,
+    )?;
+    for plan in &mut plans {
+        plan.disc_number += last_disc;
+    }
+    let total_discs = last_disc + plans.len();
+    let added_size: u64 = plans.iter().map(|p| p.used_bytes).sum();
+
+    let new_ids: Vec<String> = plans
+        .iter()
+        .map(|p| disc::generate_multi_disc_id(&disc_id_base, p.disc_number as u32))
+        .collect();
+    let existing = disc::find_existing_disc_ids(conn, &new_ids)
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(e.to_string()))?;
+    if !existing.is_empty() {
+        return Err(MultiDiscError::PlanningFailed(format!(
+            "Disc ID already exists in catalog: {}",
+            existing.join(", ")
+        )));
+    }
+
+    let mut session = BurnSession::new(
+        set_id.to_string(),
+        disc_id_base.clone(),
+        total_discs,
+        request.source_folders.clone(),
+        serde_json::to_string(&config).unwrap_or_default(),
+    );
+    for done in 1..=last_disc {
+        session.update_progress(done);
+    }
+    if !request.dry_run {
+        let mut roots: Vec<PathBuf> = disc_set
+            .source_roots
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        for folder in &request.source_folders {
+            if !roots.contains(folder) {
+                roots.push(folder.clone());
+            }
+        }
+        disc_set.source_roots = serde_json::to_string(&roots).ok();
+        disc_set.disc_count = total_discs as u32;
+        disc_set.total_size += added_size;
+        disc_set.status = database::SetStatus::InProgress;
+        session
+            .set_plans(&plans)
+            .and_then(|_| database::MultiDiscOps::extend_set_with_session(conn, &disc_set, &session))
+            .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to extend disc set: {}", e)))?;
+        reporter.progress(format!("✅ Set '{}' now has {} discs", set_id, total_discs));
+    }
+
+    let notes = match request.notes() {
+        Some(notes) => notes.to_string(),
+        None => disc_set.description.clone().unwrap_or_default(),
+    };
+    let set = SetContext {
+        disc_id_base: &disc_id_base,
+        notes: &notes,
+        set_id,
+        source_folders: &request.source_folders,
+        destination_names: &request.destination_names,
+        plans: &plans,
+        total_discs,
+        dry_run: request.dry_run,
+        config: &config,
+    };
+    let already_done: Vec<usize> = (1..=last_disc).collect();
+    let mut discs = burn_set_discs(conn, &set, &plans, &already_done, &session.session_id, &reporter, &mut insert_disc)?;
+
+    if let Ok(Some(mut saved)) = BurnSession::load(conn, &session.session_id) {
+        saved.complete();
+        let _ = saved.save(conn);
+    }
+    if request.dry_run {
+        let checksums_computed = discs.iter().map(|d| d.file_count).sum();
+        let report_paths =
+            write_dry_run_report(&disc_id_base, &plans, &request.source_folders, checksums_computed, &config, &reporter);
+        if let Some(first) = discs.first_mut() {
+            first.report_paths = report_paths;
+        }
+    }
+    finalize_multi_disc_archive(&discs, added_size, request.dry_run, &config, &reporter);
+
+    Ok(MultiDiscOutcome {
+        set_id: set_id.to_string(),
+        session_id: session.session_id,
+        discs,
+        total_size: added_size,
+    })
+}
+
+/// Base ID the discs of a set were named from: the name of its latest burn
+/// session, or for imported sets the disc IDs without their `-<n>` suffix.
+fn set_disc_id_base(conn: &Connection, set_id: &str, discs: &[database::Disc]) -> Result<String, MultiDiscError> {
+    let session = database::BurnSessionOps::latest_for_set(conn, set_id)
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to load burn sessions: {}", e)))?;
+    if let Some(session) = session {
+        return Ok(session.session_name);
+    }
+    discs
+        .iter()
+        .find_map(|d| {
+            let suffix = format!("-{}", d.sequence_number?);
+            d.disc_id.strip_suffix(&suffix).map(str::to_string)
+        })
+        .ok_or_else(|| MultiDiscError::PlanningFailed(format!("Cannot tell how the discs of set {} are named", set_id)))
+}
+
+/// Continue a paused or interrupted multi-disc burn from its next disc.
+///
+/// Uses the disc layout stored with the session; sessions saved before plans
+/// were stored are re-planned from the source folders, with a warning.
+pub fn resume_multi_disc<F>(
+    conn: &mut Connection,
+    session: BurnSession,
+    config: &Config,
+    progress: Option<ProgressCallback>,
+    insert_disc: F,
+) -> Result<MultiDiscOutcome, MultiDiscError>
+where
+    F: FnMut(usize, usize, &str) -> bool + Send,
+{
+    // Sessions don't store renames, so resumed discs use the folder names
+    continue_session(conn, session, config, &BTreeMap::new(), &Reporter(progress), insert_disc)
+}
+
+/// Burn the failed disc of a set again on a fresh blank, then carry on with
+/// the rest of the set.
+///
+/// The lowest failed disc that isn't completed is taken off the session's
+/// failed list and burned first; discs already burned are left alone.
+/// `destination_names` should be the renames the set was started with.
+pub fn retry_failed_disc<F>(
+    conn: &mut Connection,
+    session_id: &str,
+    config: &Config,
+    destination_names: &BTreeMap<PathBuf, String>,
+    progress: Option<ProgressCallback>,
+    insert_disc: F,
+) -> Result<MultiDiscOutcome, MultiDiscError>
+where
+    F: FnMut(usize, usize, &str) -> bool + Send,
+{
+    let reporter = Reporter(progress);
+    let mut session = BurnSession::load(conn, session_id)
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to load burn session: {}", e)))?
+        .ok_or_else(|| MultiDiscError::DatabaseInconsistency(format!("Burn session not found: {}", session_id)))?;
+    let failed_disc = session
+        .failed_discs
+        .iter()
+        .copied()
+        .filter(|n| !session.completed_discs.contains(n))
+        .min()
+        .ok_or_else(|| MultiDiscError::DatabaseInconsistency(format!("Session {} has no failed disc to retry", session_id)))?;
+
+    session.failed_discs.retain(|&n| n != failed_disc);
+    session
+        .save(conn)
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to update burn session: {}", e)))?;
+    info!("Retrying disc {} of set {} on a new blank", failed_disc, session.set_id);
+    reporter.status(format!("🔁 Retrying disc {} of {} on a new blank", failed_disc, session.total_discs));
+
+    continue_session(conn, session, config, destination_names, &reporter, insert_disc)
+}
+
+/// Move the largest files off the failed disc of a set whose image came out
+/// bigger than the disc, onto a new disc appended to the set, then carry on
+/// burning from the failed disc.
+///
+/// `iso_bytes` is the size of the oversized image (the `required` of
+/// [`ArchiveError::CapacityExceeded`]); enough files are moved to make up the
+/// difference to the disc capacity. The set and its session grow by one disc.
+/// Labels of discs burned before the re-plan keep the old disc count.
+pub fn replan_overflowing_disc<F>(
+    conn: &mut Connection,
+    session_id: &str,
+    iso_bytes: u64,
+    config: &Config,
+    destination_names: &BTreeMap<PathBuf, String>,
+    progress: Option<ProgressCallback>,
+    insert_disc: F,
+) -> Result<MultiDiscOutcome, MultiDiscError>
+where
+    F: FnMut(usize, usize, &str) -> bool + Send,
+{
+    let reporter = Reporter(progress);
+    let mut session = BurnSession::load(conn, session_id)
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to load burn session: {}", e)))?
+        .ok_or_else(|| MultiDiscError::DatabaseInconsistency(format!("Burn session not found: {}", session_id)))?;
+    let failed_disc = session
+        .failed_discs
+        .iter()
+        .copied()
+        .filter(|n| !session.completed_discs.contains(n))
+        .min()
+        .ok_or_else(|| MultiDiscError::DatabaseInconsistency(format!("Session {} has no failed disc to re-plan", session_id)))?;
+    let mut plans = session
+        .stored_plans()
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Stored disc layout is unreadable: {}", e)))?
+        .ok_or_else(|| MultiDiscError::PlanningFailed(format!("Session {} has no stored disc layout to re-plan", session_id)))?;
+
+    let capacity = config.default_capacity_bytes();
+    let overflow = iso_bytes.saturating_sub(capacity);
+    let plan = plans
+        .iter_mut()
+        .find(|p| p.disc_number == failed_disc)
+        .ok_or_else(|| MultiDiscError::PlanningFailed(format!("Disc {} is not in the stored layout", failed_disc)))?;
+    let moved = plan.take_largest_files(overflow.max(1));
+    if moved.is_empty() {
+        return Err(MultiDiscError::PlanningFailed(format!("Disc {} has no files that could be moved", failed_disc)));
+    }
+
+    let mut new_plan = staging::DiscPlan::new(plans.len() + 1, capacity);
+    for file in &moved {
+        new_plan.add_entry(file.clone());
+    }
+    info!(
+        "Re-planned disc {} of set {}: moved {} files ({}) to new disc {}",
+        failed_disc,
+        session.set_id,
+        moved.len(),
+        format_size(new_plan.used_bytes),
+        new_plan.disc_number
+    );
+    reporter.status(format!(
+        "✂️ Moved {} largest files ({}) from disc {} to a new disc {}",
+        moved.len(),
+        format_size(new_plan.used_bytes),
+        failed_disc,
+        new_plan.disc_number
+    ));
+    plans.push(new_plan);
+
+    session.total_discs = plans.len();
+    session.failed_discs.retain(|&n| n != failed_disc);
+    session
+        .set_plans(&plans)
+        .and_then(|_| database::MultiDiscOps::resize_set_with_session(conn, &session.set_id, plans.len() as u32, &session))
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to update disc set: {}", e)))?;
+
+    continue_session(conn, session, config, destination_names, &reporter, insert_disc)
+}
+
+/// Burn every disc of `session` that isn't completed yet, then finish the set.
+fn continue_session<F>(
+    conn: &mut Connection,
+    session: BurnSession,
+    config: &Config,
+    destination_names: &BTreeMap<PathBuf, String>,
+    reporter: &Reporter,
+    mut insert_disc: F,
+) -> Result<MultiDiscOutcome, MultiDiscError>
+where
+    F: FnMut(usize, usize, &str) -> bool + Send,
+{
+    let disc_set = database::DiscSet::get(conn, &session.set_id)
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Failed to load disc set: {}", e)))?
+        .ok_or_else(|| MultiDiscError::DatabaseInconsistency(format!("Disc set not found: {}", session.set_id)))?;
+
+    // Burn exactly the layout that was planned; recomputing only matches if
+    // the source folders are unchanged and present on this machine
+    let stored_plans = session
+        .stored_plans()
+        .map_err(|e| MultiDiscError::DatabaseInconsistency(format!("Stored disc layout is unreadable: {}", e)))?;
+    let plans = match stored_plans {
+        Some(plans) => plans,
+        None => {
+            warn!("Session {} has no stored disc plan; recomputing from source folders", session.session_id);
+            reporter.status(
+                "⚠️  WARNING: no stored disc layout for this session. Re-planning from the source folders; \
+                 if they changed since the first discs were burned, the remaining discs may not match the set.",
+            );
+            recreate_plans_from_disc_set(&disc_set, config)
+                .map_err(|e| MultiDiscError::PlanningFailed(e.to_string()))?
+        }
+    };
+
+    let notes = disc_set.description.clone().unwrap_or_default();
+    let (disc_id_base, set_id, source_folders) =
+        (session.session_name.clone(), session.set_id.clone(), session.source_folders.clone());
+    staging::preflight_source_folders(&source_folders)
+        .map_err(|e| MultiDiscError::PlanningFailed(format!("{:#}", e)))?;
+    let set = SetContext {
+        disc_id_base: &disc_id_base,
+        notes: &notes,
+        set_id: &set_id,
+        source_folders: &source_folders,
+        destination_names,
+        plans: &plans,
+        total_discs: session.total_discs,
+        dry_run: false, // Resumed sessions are always real burns
+        config,
+    };
+
+    if let Err(e) = database::DiscSet::set_status(conn, &set_id, database::SetStatus::InProgress) {
+        warn!("Failed to mark disc set {} as in progress: {}", set_id, e);
+    }
+
+    // Older sessions only tracked the next disc to burn
+    let mut already_done = session.completed_discs.clone();
+    already_done.extend((1..session.current_disc).filter(|n| !session.completed_discs.contains(n)));
+    let discs = burn_set_discs(conn, &set, &plans, &already_done, &session.session_id, reporter, &mut insert_disc)?;
+
+    if let Ok(Some(mut saved)) = BurnSession::load(conn, &session.session_id) {
+        saved.complete();
+        let _ = saved.save(conn);
+    }
+
+    finalize_multi_disc_archive(&discs, disc_set.total_size, false, config, reporter);
+
+    Ok(MultiDiscOutcome {
+        set_id,
+        session_id: session.session_id,
+        discs,
+        total_size: disc_set.total_size,
+    })
+}
+
+/// What [`abort_multi_disc`] removed, and which discs had already been burned.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AbortCleanup {
+    pub set_id: String,
+    /// Discs burned before the abort. They exist physically but are no longer catalogued.
+    pub burned_disc_ids: Vec<String>,
+    pub file_records_removed: usize,
+    /// Staging directories, staged ISOs and retained ISOs deleted
+    pub paths_removed: Vec<PathBuf>,
+}
+
+impl AbortCleanup {
+    /// What was cleaned up, and what to do with discs already burned.
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "Aborted set {}: removed {} disc record(s), {} file record(s), the burn session and {} staging item(s).",
+            self.set_id,
+            self.burned_disc_ids.len(),
+            self.file_records_removed,
+            self.paths_removed.len()
+        );
+        if self.burned_disc_ids.is_empty() {
+            summary.push_str("\nNo discs of the set had been burned.");
+        } else {
+            summary.push_str(&format!(
+                "\nAlready burned and no longer catalogued: {}. Set these discs aside or discard them.",
+                self.burned_disc_ids.join(", ")
+            ));
+        }
+        summary
+    }
+}
+
+/// Abandon a multi-disc set whose burn failed partway: delete the set, the
+/// catalog rows of the discs already burned and the burn session, then remove
+/// the set's staging directories, staged ISOs and retained ISOs.
+///
+/// Paths that cannot be removed are logged and skipped; the catalog is
+/// cleaned in one transaction either way.
+pub fn abort_multi_disc(conn: &mut Connection, session_id: &str, config: &Config) -> Result<AbortCleanup> {
+    let session = BurnSession::load(conn, session_id)?
+        .with_context(|| format!("Burn session {} not found", session_id))?;
+    let set_discs: Vec<String> = database::DiscSet::get_discs(conn, &session.set_id)?
+        .into_iter()
+        .map(|disc| disc.disc_id)
+        .collect();
+    let retained_isos: Vec<PathBuf> = database::Disc::list_retained_isos(conn)?
+        .into_iter()
+        .filter(|iso| set_discs.contains(&iso.disc_id))
+        .map(|iso| iso.iso_path)
+        .collect();
+
+    let (burned_disc_ids, file_records_removed) = database::MultiDiscOps::delete_set(conn, &session.set_id)?;
+
+    let mut candidates = retained_isos;
+    if let Ok(staging_dir) = config.staging_dir() {
+        for sequence_num in 1..=session.total_discs {
+            let disc_id = disc::generate_multi_disc_id(&session.session_name, sequence_num as u32);
+            candidates.push(staging_dir.join(format!("disc_{}", sequence_num)));
+            candidates.push(staging_dir.join(disc::iso_file_name(&disc_id)));
+        }
+    }
+    if let Some(dirs) = session.staging_state.as_deref().and_then(|state| serde_json::from_str::<Vec<PathBuf>>(state).ok()) {
+        candidates.extend(dirs);
+    }
+
+    let mut paths_removed = Vec::new();
+    for path in candidates {
+        let removed = if path.is_dir() {
+            std::fs::remove_dir_all(&path)
+        } else if path.exists() {
+            std::fs::remove_file(&path)
+        } else {
+            continue;
+        };
+        match removed {
+            Ok(()) => paths_removed.push(path),
+            Err(e) => warn!("Could not remove {}: {}", path.display(), e),
+        }
+    }
+
+    let cleanup = AbortCleanup {
+        set_id: session.set_id,
+        burned_disc_ids,
+        file_records_removed,
+        paths_removed,
+    };
+    info!("{}", cleanup.summary());
+    Ok(cleanup)
+}
+
+/// Per-set values shared by every disc in a multi-disc burn
+struct SetContext<'a> {
+    disc_id_base: &'a str,
+    notes: &'a str,
+    set_id: &'a str,
+    source_folders: &'a [PathBuf],
+    /// Disc-side names of renamed sources
+    destination_names: &'a BTreeMap<PathBuf, String>,
+    /// Layout of every disc in the set, for the split directory records
+    plans: &'a [staging::DiscPlan],
+    total_discs: usize,
+    dry_run: bool,
+    config: &'a Config,
+}
+
+/// Burn the discs of a set that are not in `already_done`, recording each
+/// result in the burn session. Uses every configured burner for real burns of
+/// more than one disc; otherwise burns sequentially on the first.
+fn burn_set_discs<F>(
+    conn: &mut Connection,
+    set: &SetContext,
+    plans: &[staging::DiscPlan],
+    already_done: &[usize],
+    session_id: &str,
+    reporter: &Reporter,
+    insert_disc: &mut F,
+) -> Result<Vec<ArchiveOutcome>, MultiDiscError>
+where
+    F: FnMut(usize, usize, &str) -> bool + Send,
+{
+    let remaining: Vec<&staging::DiscPlan> =
+        plans.iter().filter(|p| !already_done.contains(&p.disc_number)).collect();
+    let devices = set.config.burn_devices();
+    let db = Mutex::new(conn);
+    let tracker = SetProgressTracker::new(plans, already_done);
+
+    let (mut burned, failure) = if set.dry_run || devices.len() < 2 || remaining.len() < 2 {
+        burn_set_discs_sequentially(&db, set, &remaining, &devices[0], tracker, session_id, reporter, insert_disc)
+    } else {
+        reporter.status(format!("🔀 Burning on {} drives in parallel: {}", devices.len(), devices.join(", ")));
+        burn_set_discs_in_parallel(&db, set, &remaining, &devices, tracker, session_id, reporter, insert_disc)
+    };
+    burned.sort_by_key(|(sequence_num, _)| *sequence_num);
+
+    match failure {
+        None => Ok(burned.into_iter().map(|(_, outcome)| outcome).collect()),
+        Some((failed_disc, error)) => {
+            // A cancelled set can be resumed from the Resume Burn screen
+            let status = if matches!(error, ArchiveError::Cancelled) {
+                pause_session(&db, session_id);
+                database::SetStatus::Cancelled
+            } else {
+                database::SetStatus::Failed
+            };
+            // Discs burned so far stay in the catalog; the status tells them apart from a full set
+            if let Err(e) = database::DiscSet::set_status(&lock(&db), set.set_id, status) {
+                warn!("Failed to mark disc set {} as {}: {}", set.set_id, status, e);
+            }
+            let mut completed: Vec<usize> = already_done.to_vec();
+            completed.extend(burned.iter().map(|(sequence_num, _)| *sequence_num));
+            completed.sort_unstable();
+            Err(MultiDiscError::from_disc_failure(session_id, completed, failed_disc, error))
+        }
+    }
+}
+
+/// Finished discs by sequence number and, if the run stopped early, the disc that failed and why
+type SetBurnResult = (Vec<(usize, ArchiveOutcome)>, Option<(usize, ArchiveError)>);
+
+#[allow(clippy::too_many_arguments)]
+fn burn_set_discs_sequentially<F>(
+    db: &Mutex<&mut Connection>,
+    set: &SetContext,
+    plans: &[&staging::DiscPlan],
+    device: &str,
+    mut tracker: SetProgressTracker,
+    session_id: &str,
+    reporter: &Reporter,
+    insert_disc: &mut F,
+) -> SetBurnResult
+where
+    F: FnMut(usize, usize, &str) -> bool,
+{
+    let mut discs = Vec::new();
+    for plan in plans {
+        let sequence_num = plan.disc_number;
+        if let Err(e) = stop_if_interrupted() {
+            return (discs, Some((sequence_num, e)));
+        }
+        reporter.emit(ArchiveEvent::MultiDiscProgress(tracker.progress(sequence_num)));
+        match burn_set_disc(db, set, plan, device, reporter, insert_disc) {
+            Ok(outcome) => {
+                discs.push((sequence_num, outcome));
+                tracker.disc_done(plan);
+                record_session_disc(db, session_id, sequence_num, true);
+            }
+            Err(e) => {
+                if !matches!(e, ArchiveError::Cancelled) {
+                    record_session_disc(db, session_id, sequence_num, false);
+                }
+                return (discs, Some((sequence_num, e)));
+            }
+        }
+    }
+    (discs, None)
+}
+
+/// One worker thread per burner, each taking the next unburned disc from a
+/// shared queue. After a failure no new discs are started, but burns already
+/// in progress on other drives are allowed to finish.
+#[allow(clippy::too_many_arguments)]
+fn burn_set_discs_in_parallel<F>(
+    db: &Mutex<&mut Connection>,
+    set: &SetContext,
+    plans: &[&staging::DiscPlan],
+    devices: &[String],
+    tracker: SetProgressTracker,
+    session_id: &str,
+    reporter: &Reporter,
+    insert_disc: &mut F,
+) -> SetBurnResult
+where
+    F: FnMut(usize, usize, &str) -> bool + Send,
+{
+    let queue = Mutex::new(plans.iter().copied());
+    // Held while the user is prompted, so only one insertion prompt shows at a time
+    let insert_disc = Mutex::new(insert_disc);
+    let tracker = Mutex::new(tracker);
+    let results: Mutex<SetBurnResult> = Mutex::new((Vec::new(), None));
+
+    thread::scope(|scope| {
+        for device in devices {
+            let (queue, insert_disc, tracker, results) = (&queue, &insert_disc, &tracker, &results);
+            scope.spawn(move || loop {
+                if lock(results).1.is_some() {
+                    break;
+                }
+                let Some(plan) = lock(queue).next() else {
+                    break;
+                };
+                let sequence_num = plan.disc_number;
+                if let Err(e) = stop_if_interrupted() {
+                    lock(results).1.get_or_insert((sequence_num, e));
+                    break;
+                }
+                reporter.emit(ArchiveEvent::MultiDiscProgress(lock(tracker).progress(sequence_num)));
+
+                let mut prompt = |sequence: usize, total: usize, device: &str| (*lock(insert_disc))(sequence, total, device);
+                match burn_set_disc(db, set, plan, device, reporter, &mut prompt) {
+                    Ok(outcome) => {
+                        lock(tracker).disc_done(plan);
+                        record_session_disc(db, session_id, sequence_num, true);
+                        lock(results).0.push((sequence_num, outcome));
+                    }
+                    Err(e) => {
+                        if !matches!(e, ArchiveError::Cancelled) {
+                            record_session_disc(db, session_id, sequence_num, false);
+                        }
+                        let mut results = lock(results);
+                        // Keep the first failure; later ones are usually consequences of it
+                        if results.1.is_none() {
+                            results.1 = Some((sequence_num, e));
+                        }
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    results.into_inner().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Lock a mutex, recovering the data if another worker panicked while holding it
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Mark the stored burn session as paused
+fn pause_session(db: &Mutex<&mut Connection>, session_id: &str) {
+    let conn = lock(db);
+    if let Ok(Some(mut saved)) = BurnSession::load(&conn, session_id) {
+        saved.pause(None);
+        match saved.save(&conn) {
+            Ok(()) => info!("Burn session {} paused", session_id),
+            Err(e) => warn!("Failed to pause burn session {}: {}", session_id, e),
+        }
+    }
+}
+
+/// Stop at a safe point (between phases or discs) after Ctrl-C.
+/// Burns already in progress are never interrupted.
+fn stop_if_interrupted() -> Result<(), ArchiveError> {
+    if interrupt::is_requested() {
+        warn!("Stopping at a safe point after an interrupt");
+        return Err(ArchiveError::Cancelled);
+    }
+    Ok(())
+}
+
+/// Mark a disc as completed or failed in the stored burn session
+fn record_session_disc(db: &Mutex<&mut Connection>, session_id: &str, sequence_num: usize, succeeded: bool) {
+    let conn = lock(db);
+    if let Ok(Some(mut saved)) = BurnSession::load(&conn, session_id) {
+        if succeeded {
+            saved.update_progress(sequence_num);
+        } else if !saved.failed_discs.contains(&sequence_num) {
+            saved.failed_discs.push(sequence_num);
+        }
+        let _ = saved.save(&conn);
+    }
+}
+
+/// Plan multi-disc archive with error handling
+fn plan_multi_disc_archive(
+    source_folders: &[PathBuf],
+    disc_capacity: u64,
+    staging_options: &crate::config::StagingConfig,
+    reporter: &Reporter,
+) -> Result<Vec<staging::DiscPlan>, MultiDiscError> {
+    reporter.status("📊 Planning multi-disc layout with error recovery...");
+
+    // Guard against planner panics so the caller gets an error instead of a dead thread
+    let plans_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let walk_options = staging::WalkOptions::from(staging_options);
+        let mut plans = staging::plan_disc_layout_with_progress(
+            source_folders,
+            disc_capacity,
+            walk_options,
+            Some(&SizeCache::open_default(walk_options)),
+            |progress| reporter.progress(progress),
+        )?;
+        if staging_options.dedup {
+            let saved = staging::dedup_plans(&mut plans)?;
+            reporter.progress(format!("🧬 Deduplication saves {}", format_size(saved)));
+        }
+        Ok::<_, anyhow::Error>(plans)
+    }));
+
+    match plans_result {
+        Ok(Ok(plans)) if plans.is_empty() => Err(MultiDiscError::PlanningFailed("No disc plans generated".to_string())),
+        Ok(Ok(plans)) => Ok(plans),
+        Ok(Err(_)) if interrupt::is_requested() => Err(MultiDiscError::UserCancelled),
+        Ok(Err(e)) => Err(MultiDiscError::PlanningFailed(format!("Planning error: {}", e))),
+        Err(_) => Err(MultiDiscError::PlanningFailed("Planning function panicked (possible infinite loop)".to_string())),
+    }
+}
+
+/// Stage, burn on `device` and record one disc of a set.
+/// The database is only locked for the final writes.
+fn burn_set_disc<F>(
+    db: &Mutex<&mut Connection>,
+    set: &SetContext,
+    plan: &staging::DiscPlan,
+    device: &str,
+    reporter: &Reporter,
+    insert_disc: &mut F,
+) -> Result<ArchiveOutcome, ArchiveError>
+where
+    F: FnMut(usize, usize, &str) -> bool,
+{
+    let config = set.config;
+    let sequence_num = plan.disc_number;
+    let disc_id = disc::generate_multi_disc_id(set.disc_id_base, sequence_num as u32);
+
+    reporter.status(format!("🔥 Processing disc {}/{}: {}", sequence_num, set.total_discs, disc_id));
+
+    if !set.dry_run {
+        // Checked again for every disc: a long set can fill the data volume
+        check_catalog_space(config)?;
+        if !insert_disc(sequence_num, set.total_discs, device) {
+            return Err(ArchiveError::Cancelled);
+        }
+        burn::check_device(device).map_err(|e| ArchiveError::DeviceUnavailable(format!("{:#}", e)))?;
+    }
+
+    let staging_dir = config
+        .staging_dir()
+        .classify(ArchiveError::StagingIo, "Cannot access staging directory")?;
+    let disc_staging_dir = staging_dir.join(format!("disc_{}", sequence_num));
+
+    let failed = staging::FailedFiles::default();
+    stage_disc_content(
+        plan,
+        set.source_folders,
+        set.destination_names,
+        &disc_staging_dir,
+        &config.staging,
+        config.staging.skip_unreadable.then_some(&failed),
+        set.dry_run,
+        reporter,
+    )
+    .classify(ArchiveError::StagingIo, "Staging failed")?;
+    let failed_files = failed.take();
+    if !failed_files.is_empty() {
+        if let Err(e) = report_failed_files(reporter, &disc_staging_dir, &failed_files, set.dry_run) {
+            let _ = std::fs::remove_dir_all(&disc_staging_dir);
+            return Err(e);
+        }
+    }
+    let duplicates = if config.staging.dedup && !set.dry_run {
+        match dedup::remove_staged_duplicates(&disc_staging_dir, &disc_staging_dir) {
+            Ok(duplicates) => duplicates,
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&disc_staging_dir);
+                return Err(ArchiveError::StagingIo(format!("Deduplication failed: {:#}", e)));
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    let (files, manifest_hash) =
+        match write_set_disc_metadata(set, &disc_staging_dir, &disc_id, sequence_num, &duplicates, reporter) {
+            Ok(written) => written,
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&disc_staging_dir);
+                return Err(ArchiveError::StagingIo(format!("Failed to write disc info: {:#}", e)));
+            }
+        };
+
+    let (iso_path, iso_size, burn_stats) =
+        match create_iso_and_burn_disc(&disc_id, &disc_staging_dir, device, set.dry_run, config, reporter) {
+            Ok(result) => result,
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&disc_staging_dir);
+                return Err(e);
+            }
+        };
+
+    // Database errors don't fail a disc that is already burned, but are logged
+    let mut iso_path = iso_path;
+    if !set.dry_run || config.burn.index_dry_runs {
+        let mut conn = lock(db);
+        if let Err(e) =
+            record_set_disc_in_database(&mut conn, set, &disc_id, sequence_num, plan, device, manifest_hash.clone())
+        {
+            warn!("Failed to record disc {} in database: {}", sequence_num, e);
+        } else {
+            if let Err(e) = index_files_in_database(&mut conn, &disc_id, &files) {
+                warn!("Failed to index files for disc {}: {}", sequence_num, e);
+            }
+            if let Err(e) = database::record_failed_files(&conn, &disc_id, &failed_files) {
+                warn!("Failed to record skipped files for disc {}: {}", sequence_num, e);
+            }
+            if let Some(stats) = burn_stats {
+                if let Err(e) = database::Disc::record_burn_stats(&conn, &disc_id, stats.duration_secs, stats.avg_write_speed_mbps) {
+                    warn!("Failed to record burn stats for disc {}: {}", sequence_num, e);
+                }
+            }
+            if config.iso_library.enabled && !set.dry_run {
+                match iso_library::retain_iso(&conn, config, &disc_id, &iso_path) {
+                    Ok(retained) => iso_path = retained,
+                    Err(e) => warn!("Failed to retain ISO for disc {}: {}", sequence_num, e),
+                }
+            }
+            backup_catalog(&conn, config, reporter);
+        }
+    }
+
+    if config.cleanup.after_burn && !(set.dry_run && config.cleanup.keep_on_dry_run) && disc_staging_dir.exists() {
+        let _ = std::fs::remove_dir_all(&disc_staging_dir);
+    }
+
+    reporter.status(format!("✅ Disc {} of {} completed successfully", sequence_num, set.total_discs));
+
+    Ok(ArchiveOutcome {
+        volume_label: disc::generate_volume_label(&disc_id),
+        disc_id,
+        iso_path: Some(iso_path),
+        size_bytes: iso_size,
+        file_count: files.len(),
+        manifest_hash,
+        burn_stats,
+        report_paths: Vec::new(),
+    })
+}
+
+/// Write the metadata of a staged set disc. DISC_INFO.txt and the split and
+/// dedup records go in `disc_info/`; MANIFEST.txt, the checksum list and
+/// manifest.json cover the whole disc from its root, as on single discs.
+/// Returns the manifest entries and the hash of the checksum list.
+fn write_set_disc_metadata(
+    set: &SetContext,
+    disc_staging_dir: &Path,
+    disc_id: &str,
+    sequence_num: usize,
+    duplicates: &[dedup::DuplicateFile],
+    reporter: &Reporter,
+) -> Result<(Vec<manifest::FileMetadata>, Option<String>)> {
+    let config = set.config;
+    let info_dir = disc_staging_dir.join("disc_info");
+    std::fs::create_dir_all(&info_dir)?;
+    disc::write_disc_info(
+        &info_dir,
+        disc_id,
+        if set.notes.is_empty() { None } else { Some(set.notes) },
+        set.source_folders,
+        &disc::get_tool_version(),
+        Some(set.set_id),
+        Some(sequence_num as u32),
+        Some(set.total_discs as u32),
+    )?;
+    disc::write_checksum_info(&info_dir, config.staging.checksum)?;
+    disc::write_iso_info(&info_dir, &iso::IsoMetadata::from(&config.iso))?;
+    // Restore reads the dedup map where it finds DISC_INFO.txt
+    if !duplicates.is_empty() {
+        dedup::write_dedup_map(&info_dir.join(dedup::DEDUP_MAP_FILE), duplicates)?;
+    }
+    let split_directories = staging::split_directories_on_disc(set.plans, sequence_num);
+    if !split_directories.is_empty() {
+        let info = staging::SplitInfo {
+            set_id: set.set_id.to_string(),
+            disc_number: sequence_num,
+            total_discs: set.total_discs,
+            directories: split_directories,
+        };
+        staging::write_split_info(&info_dir.join(staging::SPLIT_INFO_FILE), &info)?;
+    }
+
+    reporter.progress(format!("🔐 Checksums: {}", config.staging.checksum.description()));
+    let mut files = manifest::generate_manifest_and_sums_with_progress(
+        disc_staging_dir,
+        None,
+        Some(reporter.hash_callback()),
+        config.staging.checksum,
+        config.staging.max_retries,
+    )?;
+    manifest::add_duplicate_entries(&mut files, duplicates);
+
+    manifest::write_manifest_file(&disc_staging_dir.join("MANIFEST.txt"), &files)?;
+    let sums_path = manifest::write_checksum_list(disc_staging_dir, &files, config.staging.checksum)?;
+
+    let disc_manifest = manifest::DiscManifest::new(
+        manifest::DiscManifestInfo {
+            disc_id: disc_id.to_string(),
+            volume_label: disc::generate_volume_label(disc_id),
+            created_at: format_timestamp_now(),
+            tool_version: disc::get_tool_version(),
+            notes: if set.notes.is_empty() { None } else { Some(set.notes.to_string()) },
+            source_roots: set.source_folders.iter().map(|p| p.to_string_lossy().into_owned()).collect(),
+            set_id: Some(set.set_id.to_string()),
+            sequence_number: Some(sequence_num as u32),
+            total_discs: Some(set.total_discs as u32),
+        },
+        files,
+    );
+    manifest::write_manifest_json(&disc_staging_dir.join(manifest::MANIFEST_JSON_FILE), &disc_manifest)?;
+
+    let manifest_hash = match manifest::calculate_manifest_hash(&sums_path) {
+        Ok(hash) => Some(hash),
+        Err(e) => {
+            warn!("Failed to hash checksum list: {}", e);
+            None
+        }
+    };
+    Ok((disc_manifest.files, manifest_hash))
+}
+
+/// Take a rolling backup of the catalog after a disc was indexed. A failed
+/// backup is reported but never fails the disc.
+fn backup_catalog(conn: &Connection, config: &Config, reporter: &Reporter) {
+    if !config.backup.enabled {
+        return;
+    }
+    let Some(dir) = database::backup_dir(conn) else {
+        return;
+    };
+    match database::rolling_backup(conn, &dir, config.backup.keep) {
+        Ok(path) => reporter.progress(format!("💾 Catalog backed up to {}", path.display())),
+        Err(e) => {
+            warn!("Catalog backup failed: {:#}", e);
+            reporter.progress(format!("⚠️ Catalog backup failed: {:#}", e));
+        }
+    }
+}
+
+/// Record a completed set disc in the database
+fn record_set_disc_in_database(
+    conn: &mut Connection,
+    set: &SetContext,
+    disc_id: &str,
+    sequence_num: usize,
+    plan: &staging::DiscPlan,
+    device: &str,
+    manifest_hash: Option<String>,
+) -> Result<()> {
+    let volume_label =
+        disc::generate_multi_disc_volume_label(set.disc_id_base, sequence_num as u32, set.total_discs as u32);
+
+    let mut disc_record = database::Disc {
+        disc_id: disc_id.to_string(),
+        volume_label,
+        created_at: format_timestamp_now(),
+        notes: Some(format!("Disc {} of {} in multi-disc set {}", sequence_num, set.total_discs, set.set_id)),
+        iso_size: Some(plan.used_bytes),
+        burn_device: if set.dry_run { None } else { Some(device.to_string()) },
+        checksum_manifest_hash: manifest_hash,
+        qr_path: None,
+        source_roots: Some(serde_json::to_string(set.source_folders)?),
+        tool_version: Some(disc::get_tool_version()),
+        set_id: Some(set.set_id.to_string()),
+        sequence_number: Some(sequence_num as u32),
+        burn_duration_secs: None,
+        avg_write_speed_mbps: None,
+        is_mdisc: set.config.burn.mdisc && !set.dry_run,
+        storage_location: None,
+        planned: set.dry_run,
+    };
+
+    database::MultiDiscOps::add_disc_to_set(conn, &mut disc_record, set.set_id, sequence_num as u32)?;
+    Ok(())
+}
+
+/// Clean up staging and report the finished set
+fn finalize_multi_disc_archive(
+    discs: &[ArchiveOutcome],
+    total_size: u64,
+    dry_run: bool,
+    config: &Config,
+    reporter: &Reporter,
+) {
+    if let Ok(staging_dir) = config.staging_dir() {
+        if let Err(e) = staging::cleanup_staging_dir(&staging_dir, &config.cleanup, dry_run) {
+            warn!("Failed to cleanup staging directory: {}", e);
+        }
+    }
+
+    reporter.status(format!(
+        "🎊 Multi-disc archive complete! {} discs, {} MB total",
+        discs.len(),
+        total_size / (1024 * 1024)
+    ));
+
+    if !discs.is_empty() {
+        reporter.progress("📂 ISO files created:");
+        for (i, disc) in discs.iter().enumerate() {
+            if let Some(ref iso_path) = disc.iso_path {
+                reporter.progress(format!("  💿 Disc {}: {}", i + 1, iso_path.display()));
+            }
+        }
+    }
+}
+
+/// Build the ISO for a set disc and burn it.
+/// Returns the ISO path, its size and the burn timing (None for dry runs).
+fn create_iso_and_burn_disc(
+    disc_id: &str,
+    disc_staging_dir: &Path,
+    device: &str,
+    dry_run: bool,
+    config: &Config,
+    reporter: &Reporter,
+) -> Result<(PathBuf, u64, Option<BurnStats>), ArchiveError> {
+    reporter.status("🎨 Creating ISO image...");
+    reporter.progress("🔄 Analyzing files and building filesystem...");
+
+    let volume_label = disc::generate_volume_label(disc_id);
+    reporter.progress(format!("💿 Volume label: {}", volume_label));
+    let staging_dir = config
+        .staging_dir()
+        .classify(ArchiveError::StagingIo, "Cannot access staging directory")?;
+    let iso_path = staging_dir.join(disc::iso_file_name(disc_id));
+
+    // Animated progress while xorriso runs
+    let iso_reporter = reporter.clone();
+    thread::spawn(move || {
+        let spinners = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+        for i in 0..20 {
+            iso_reporter.progress(format!("🎨 Building ISO... {}", spinners[i % spinners.len()]));
+            thread::sleep(Duration::from_millis(200));
+        }
+    });
+
+    let mut iso_metadata = iso::IsoMetadata::from(&config.iso);
+    if !dry_run {
+        let choice = iso::choose_iso_level(iso_metadata.level, disc_staging_dir)
+            .classify(ArchiveError::StagingIo, "Failed to check staged file sizes")?;
+        if let Some(notice) = choice.notice() {
+            reporter.status(notice);
+        }
+        iso_metadata.level = choice.level;
+    }
+    iso::create_iso(disc_staging_dir, &iso_path, &volume_label, &iso_metadata, dry_run)
+        .classify(ArchiveError::IsoFailed, "ISO creation failed")?;
+
+    // No ISO file is written for a dry run, so estimate from the staging directory
+    let iso_size = if dry_run {
+        staging::calculate_directory_size(disc_staging_dir).classify(ArchiveError::StagingIo, "Failed to size staging")?
+    } else {
+        iso::get_iso_size(&iso_path).classify(ArchiveError::IsoFailed, "Failed to get ISO size")?
+    };
+
+    reporter.progress(format!(
+        "✅ ISO created: {} ({})",
+        format_bytes_gb(iso_size),
+        volume_label
+    ));
+
+    // Filesystem overhead can still push a full disc over; stop before burning
+    let capacity = config.default_capacity_bytes();
+    if !dry_run && iso_size > capacity {
+        let _ = std::fs::remove_file(&iso_path);
+        let err = ArchiveError::CapacityExceeded { required: iso_size, capacity };
+        error!("ISO for {} is too big for the disc: {}", disc_id, err);
+        return Err(err);
+    }
+
+    stop_if_interrupted()?;
+    let mut burn_stats = None;
+    if dry_run {
+        reporter.status("🔍 Skipping burn (dry run mode)");
+        reporter.progress("📋 Dry run complete - no disc written");
+    } else {
+        reporter.status(format!("🔥 Burning to {}...", device));
+        reporter.progress("⚡ Initializing Blu-ray burner...");
+
+        let start_time = std::time::Instant::now();
+        burn::burn_iso(&iso_path, device, dry_run, config.burn.mdisc, config.burn.stall_timeout()).classify(ArchiveError::BurnFailed, "Burn failed")?;
+        burn_stats = Some(BurnStats::new(iso_size, start_time.elapsed()));
+
+        reporter.progress("🎉 Disc burned successfully!");
+        eject_after_burn(config, device, reporter);
+    }
+
+    Ok((iso_path, iso_size, burn_stats))
+}
+
+/// Refuse to burn when the volume holding the catalog or the QR codes has
+/// less than `min_data_free_mb` free: the disc would be written but its
+/// indexing or label could fail afterwards.
+fn check_catalog_space(config: &Config) -> Result<(), ArchiveError> {
+    let min_bytes = config.min_data_free_bytes();
+    if min_bytes == 0 {
+        return Ok(());
+    }
+    let db_path = config.database_path().classify(ArchiveError::DatabaseError, "Cannot locate the database")?;
+    let qrcodes_dir = paths::qrcodes_dir().classify(ArchiveError::StagingIo, "Cannot locate the QR code directory")?;
+    for path in [db_path.as_path(), qrcodes_dir.as_path()] {
+        if let Err(e) = paths::check_min_free_space(path, min_bytes) {
+            error!("Catalog volume check failed: {:#}", e);
+            return Err(ArchiveError::CatalogSpaceLow(format!("{:#}", e)));
+        }
+    }
+    Ok(())
+}
+
+/// Open the tray after a successful burn when `burn.eject_after_disc` is set.
+/// A drive that can't eject is only logged; the disc is already written.
+fn eject_after_burn(config: &Config, device: &str, reporter: &Reporter) {
+    if !config.burn.eject_after_disc {
+        return;
+    }
+    match burn::eject(device) {
+        Ok(()) => reporter.progress("⏏️ Disc ejected"),
+        Err(e) => warn!("Could not eject {} after the burn: {:#}", device, e),
+    }
+}
+
+/// Write the report of files skipped with `staging.skip_unreadable` to the
+/// disc root and warn about them.
+fn report_failed_files(
+    reporter: &Reporter,
+    disc_root: &Path,
+    failed_files: &[(PathBuf, String)],
+    dry_run: bool,
+) -> Result<(), ArchiveError> {
+    reporter.status(format!(
+        "⚠️ Skipped {} unreadable files; see {}",
+        failed_files.len(),
+        staging::FAILED_FILES_FILE
+    ));
+    if !dry_run {
+        staging::write_failed_files(&disc_root.join(staging::FAILED_FILES_FILE), failed_files)
+            .classify(ArchiveError::StagingIo, "Failed to write failed files report")?;
+    }
+    Ok(())
+}
+
+/// Stage the files `plan` puts on this disc, each source folder at the disc
+/// root, with names normalized to NFC.
+/// Files that no longer exist are left out; files added since planning wait
+/// for a later disc or run.
+#[allow(clippy::too_many_arguments)]
+fn stage_disc_content(
+    plan: &staging::DiscPlan,
+    source_folders: &[PathBuf],
+    destination_names: &BTreeMap<PathBuf, String>,
+    disc_staging_dir: &Path,
+    staging_options: &crate::config::StagingConfig,
+    failed: Option<&staging::FailedFiles>,
+    dry_run: bool,
+    reporter: &Reporter,
+) -> Result<()> {
+    reporter.progress(format!("🔄 Starting content staging for disc {}...", plan.disc_number));
+
+    let planned: HashSet<&Path> = plan.files().into_iter().collect();
+    let sources: Vec<(PathBuf, Option<String>)> = source_folders
+        .iter()
+        .map(|folder| (folder.clone(), destination_names.get(folder).cloned()))
+        .collect();
+    let files: Vec<staging::SourceFile> = staging::collect_named_source_files(&sources, staging_options.into())?
+        .into_iter()
+        .filter(|file| planned.contains(file.source.as_path()))
+        .collect();
+    if files.len() < planned.len() {
+        warn!(
+            "{} planned files for disc {} no longer exist in the source folders",
+            planned.len() - files.len(),
+            plan.disc_number
+        );
+    }
+
+    reporter.progress(format!("📂 Staging {} planned files", files.len()));
+    staging::stage_file_list_into(
+        disc_staging_dir,
+        &files,
+        dry_run,
+        staging_options,
+        Some(reporter.text_callback()),
+        None,
+        failed,
+    )?;
+
+    // Names on the disc must match the NFC paths its manifest and the catalog record
+    if !dry_run {
+        let renamed = staging::normalize_staged_names(disc_staging_dir).context("Failed to normalize file names")?;
+        if renamed > 0 {
+            reporter.status(format!("Normalized {} file and folder names to Unicode NFC", renamed));
+        }
+    }
+
+    reporter.progress(format!("🎯 Disc {} staging complete!", plan.disc_number));
+    Ok(())
+}
+
+/// Stage `files` as one `<folder>.tar.zst` per source folder.
+fn compress_sources(
+    reporter: &Reporter,
+    disc_root: &Path,
+    files: &[staging::SourceFile],
+    request: &ArchiveRequest,
+    bytes_staged: &AtomicU64,
+) -> Result<(), ArchiveError> {
+    if request.dry_run {
+        info!("[DRY RUN] Would compress {} files", files.len());
+        return Ok(());
+    }
+    reporter.progress(format!("🗜️ Compressing {} files with zstd...", files.len()));
+    let archives = compression::compress_files(&disc_root.join("ARCHIVE"), files, &request.config.staging, Some(bytes_staged))
+        .classify(ArchiveError::StagingIo, "Compression failed")?;
+    for archive in &archives {
+        let size = std::fs::metadata(archive).map(|m| m.len()).unwrap_or(0);
+        reporter.progress(format!(
+            "✅ Compressed: {} ({})",
+            archive.file_name().unwrap_or_default().to_string_lossy(),
+            format_size(size)
+        ));
+    }
+    Ok(())
+}
+
+/// Burn `source` with xorriso, reporting the share written with speed and ETA.
+/// Speed and ETA come from the speed xorriso reports; the average since the
+/// start is only used when its output carries no speed.
+fn burn_reporting_progress(
+    source: &Path,
+    device: &str,
+    method: &str,
+    burn_config: &BurnConfig,
+    total_bytes: u64,
+    reporter: &Reporter,
+) -> Result<Duration> {
+    let start_time = std::time::Instant::now();
+    let mut last_percent = None;
+    let stall_timeout = burn_config.stall_timeout();
+    burn::burn_with_progress(source, device, false, method, burn_config.mdisc, stall_timeout, &mut |progress| {
+        reporter.emit(ArchiveEvent::PhaseProgress(progress.fraction));
+        let percent = (progress.fraction * 100.0) as u32;
+        if last_percent == Some(percent) {
+            return;
+        }
+        last_percent = Some(percent);
+        reporter.progress(describe_burn_progress(&progress, total_bytes, start_time.elapsed()));
+    })?;
+    Ok(start_time.elapsed())
+}
+
+/// Progress line for a burn, e.g. "🔥 Burning... 42% written | 18MB/s (4.0x) | fifo 100% | 57min remaining".
+fn describe_burn_progress(progress: &burn::BurnProgress, total_bytes: u64, elapsed: Duration) -> String {
+    let elapsed = elapsed.as_secs_f64();
+    let remaining_bytes = (1.0 - progress.fraction) * total_bytes as f64;
+    let (speed, eta_secs) = match (progress.speed_mbs(), progress.speed_x) {
+        (Some(mbs), Some(x)) if mbs > 0.0 => {
+            (format!("{}MB/s ({:.1}x)", mbs as u32, x), remaining_bytes / (mbs * 1_000_000.0))
+        }
+        _ => {
+            let written = progress.fraction * total_bytes as f64;
+            let mbs = if elapsed > 0.0 { written / elapsed / 1_000_000.0 } else { 0.0 };
+            let eta = if progress.fraction > 0.0 { elapsed / progress.fraction - elapsed } else { 0.0 };
+            (format!("~{}MB/s avg", mbs as u32), eta)
+        }
+    };
+    let fifo = progress.fifo_percent.map(|fifo| format!(" | fifo {}%", fifo)).unwrap_or_default();
+    format!(
+        "🔥 Burning... {}% written | {}{} | {}min remaining",
+        (progress.fraction * 100.0) as u32,
+        speed,
+        fifo,
+        (eta_secs / 60.0) as u32
+    )
+}
+
+/// Burn an ISO with progress updates and return the measured timing
+fn burn_iso_with_progress(iso_path: &Path, device: &str, burn_config: &BurnConfig, reporter: &Reporter) -> Result<Option<BurnStats>> {
+    let iso_size = std::fs::metadata(iso_path).map(|m| m.len()).unwrap_or(0);
+
+    reporter.progress("🔥 Initializing Blu-ray burner...");
+    reporter.progress(format!("💿 Starting data transfer ({}) to disc...", format_bytes_gb(iso_size)));
+
+    match burn_reporting_progress(iso_path, device, "iso", burn_config, iso_size, reporter) {
+        Ok(elapsed) => {
+            let stats = BurnStats::new(iso_size, elapsed);
+            reporter.progress(format!(
+                "✅ Burn completed! {:.1}s | {}MB/s average speed",
+                stats.duration_secs, stats.avg_write_speed_mbps as u32
+            ));
+            Ok(Some(stats))
+        }
+        Err(e) => {
+            error!("ISO burn failed: {}", e);
+            Err(anyhow::anyhow!("ISO burn failed: {}", e))
+        }
+    }
+}
+
+/// Burn the staging directory directly with progress updates and return the measured timing
+fn burn_direct_with_progress(dir_path: &Path, device: &str, burn_config: &BurnConfig, reporter: &Reporter) -> Result<Option<BurnStats>> {
+    let dir_size = staging::calculate_directory_size(dir_path).unwrap_or(0);
+
+    reporter.progress("🔥 Initializing Blu-ray burner...");
+    reporter.progress(format!("💿 Starting direct data transfer ({}) to disc...", format_bytes_gb(dir_size)));
+
+    match burn_reporting_progress(dir_path, device, "direct", burn_config, dir_size, reporter) {
+        Ok(elapsed) => {
+            let stats = BurnStats::new(dir_size, elapsed);
+            reporter.progress(format!(
+                "✅ Direct burn completed! {:.1}s | {}MB/s average speed",
+                stats.duration_secs, stats.avg_write_speed_mbps as u32
+            ));
+            Ok(Some(stats))
+        }
+        Err(e) => {
+            error!("Direct burn failed: {}", e);
+            Err(anyhow::anyhow!("Direct burn failed: {}", e))
+        }
+    }
+}
+
+/// Index the disc record in the database
+fn index_disc_in_database(
+    conn: &mut Connection,
+    request: &ArchiveRequest,
+    volume_label: &str,
+    created_at: &str,
+    iso_size: u64,
+    manifest_hash: Option<&str>,
+) -> Result<()> {
+    let source_roots_json = serde_json::to_string(&request.source_folders)
+        .context("Failed to serialize source roots")?;
+
+    let disc_record = database::Disc {
+        disc_id: request.disc_id.clone(),
+        volume_label: volume_label.to_string(),
+        created_at: created_at.to_string(),
+        notes: request.notes().map(str::to_string),
+        iso_size: Some(iso_size),
+        burn_device: if request.dry_run { None } else { Some(request.config.device.clone()) },
+        checksum_manifest_hash: manifest_hash.map(|h| h.to_string()),
+        qr_path: None,
+        source_roots: Some(source_roots_json),
+        tool_version: Some(disc::get_tool_version()),
+        set_id: None, // Single disc, not part of a set
+        sequence_number: None,
+        burn_duration_secs: None,
+        avg_write_speed_mbps: None,
+        is_mdisc: request.config.burn.mdisc && !request.dry_run,
+        storage_location: None,
+        planned: request.dry_run,
+    };
+
+    database::Disc::insert(conn, &disc_record).context("Failed to insert disc record")?;
+    Ok(())
+}
+
+/// Index file records in the database
+fn index_files_in_database(conn: &mut Connection, disc_id: &str, files: &[manifest::FileMetadata]) -> Result<()> {
+    let created_at = format_timestamp_now();
+
+    let file_records: Vec<database::FileRecord> = files
+        .iter()
+        .map(|f| database::FileRecord {
+            id: None,
+            disc_id: disc_id.to_string(),
+            rel_path: f.rel_path.to_string_lossy().to_string(),
+            sha256: f.sha256.clone(),
+            crc32: f.crc32.clone(),
+            size: f.size,
+            mtime: f.mtime.clone(),
+            added_at: created_at.clone(),
+        })
+        .collect();
+
+    database::FileRecord::insert_batch(conn, &file_records).context("Failed to insert file records")?;
+    Ok(())
+}
+
+/// Write the dry-run plan report into the staging directory.
+/// Failures are reported but never abort the dry run.
+fn write_dry_run_report(
+    disc_id: &str,
+    plans: &[staging::DiscPlan],
+    source_folders: &[PathBuf],
+    checksums_computed: usize,
+    config: &Config,
+    reporter: &Reporter,
+) -> Vec<PathBuf> {
+    let result = staging::find_excluded_paths(source_folders, staging::WalkOptions::from(&config.staging))
+        .and_then(|excluded| {
+            let report = plan_report::PlanReport::new(
+                disc_id,
+                config.default_capacity_bytes(),
+                plans,
+                checksums_computed,
+                excluded,
+            );
+            report.write(&config.staging_dir()?, config.burn.dry_run_json_report)
+        });
+
+    match result {
+        Ok(paths) => {
+            for path in &paths {
+                reporter.progress(format!("📝 Plan report: {}", path.display()));
+            }
+            paths
+        }
+        Err(e) => {
+            warn!("Failed to write dry-run report: {}", e);
+            reporter.progress(format!("⚠️ Plan report not written: {}", e));
+            Vec::new()
+        }
+    }
+}
+
+/// Generate the QR code image
+fn generate_qr_code(
+    config: &Config,
+    disc_id: &str,
+    label_info: &qrcode::DiscLabelInfo,
+    dry_run: bool,
+) -> Result<()> {
+    let qrcodes_dir = paths::qrcodes_dir()
+        .context("Failed to get QR codes directory")?;
+
+    let payload = label_info.qr_payload(disc_id, &config.qr);
+    qrcode::generate_qrcode_with_payload(
+        disc_id,
+        &payload,
+        config.qr.error_correction,
+        &qrcodes_dir,
+        qrcode::QrCodeFormat::from_extension(&config.optional_tools.qr_format),
+        dry_run,
+    ).context("QR code generation failed")?;
+
+    Ok(())
+}
+
+/// Render the printable PDF label next to the QR codes. Returns the label path.
+fn generate_label(config: &Config, disc_id: &str, label_info: &qrcode::DiscLabelInfo) -> Result<PathBuf> {
+    let label_path = paths::qrcodes_dir()
+        .context("Failed to get QR codes directory")?
+        .join(format!("{}-label.pdf", disc::sanitize_for_filesystem(disc_id)));
+    qrcode::generate_label_pdf(disc_id, label_info, &config.qr, &label_path).context("Label generation failed")
+}
+
+/// Regenerate the printable label of an indexed disc from its database record,
+/// e.g. after its storage location changed. Returns the label path.
+pub fn regenerate_label(conn: &Connection, config: &Config, disc_id: &str) -> Result<PathBuf> {
+    let disc = database::Disc::get(conn, disc_id)?
+        .with_context(|| format!("Disc not found: {}", disc_id))?;
+    let label_info = qrcode::DiscLabelInfo {
+        volume_label: disc.volume_label,
+        created_at: disc.created_at,
+        file_count: database::FileRecord::list_for_disc(conn, disc_id)?.len(),
+        manifest_hash: disc.checksum_manifest_hash,
+        storage_location: disc.storage_location,
+        set_id: disc.set_id,
+        sequence_number: disc.sequence_number,
+    };
+    generate_label(config, disc_id, &label_info)
+}
+
+/// Recreate disc plans from an existing disc set's source folders
+fn recreate_plans_from_disc_set(disc_set: &database::DiscSet, config: &Config) -> Result<Vec<staging::DiscPlan>> {
+    let source_folders: Vec<PathBuf> = serde_json::from_str(
+        disc_set.source_roots.as_deref().unwrap_or("[]")
+    ).unwrap_or_default();
+
+    if source_folders.is_empty() {
+        anyhow::bail!("Cannot recreate plans: no source folders stored");
+    }
+
+    let walk_options = staging::WalkOptions::from(&config.staging);
+    let mut plans = staging::plan_disc_layout_with_progress(
+        &source_folders,
+        config.default_capacity_bytes(),
+        walk_options,
+        Some(&SizeCache::open_default(walk_options)),
+        |_| {},
+    )?;
+    if config.staging.dedup {
+        staging::dedup_plans(&mut plans)?;
+    }
+    Ok(plans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::init_database;
+    use std::fs;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_create_disc_rejects_missing_source() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+
+        let request = ArchiveRequest::new("TEST-001", vec![temp_dir.path().join("missing")], Config::default());
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let callback: ProgressCallback = Arc::new(move |event| sink.lock().unwrap().push(event));
+
+        let err = create_disc(&mut conn, &request, Some(callback)).unwrap_err();
+        assert!(matches!(err, ArchiveError::InvalidRequest(_)));
+        assert!(err.to_string().contains("Source folder does not exist"));
+        assert!(matches!(events.lock().unwrap().first(), Some(ArchiveEvent::Status(_))));
+        assert!(database::Disc::get(&conn, "TEST-001")?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_disc_rejects_existing_disc_id() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('TEST-001', 'TEST_001', '2024-01-01T00:00:00Z')",
+            [],
+        )?;
+
+        let request = ArchiveRequest::new("test-001", vec![temp_dir.path().to_path_buf()], Config::default());
+        let err = create_disc(&mut conn, &request, None).unwrap_err();
+        assert!(matches!(err, ArchiveError::InvalidRequest(_)));
+        assert!(err.to_string().contains("already exists in catalog: TEST-001"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_burn_existing_iso_without_manifest() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let config = Config {
+            staging_dir: Some(temp_dir.path().join("staging").to_string_lossy().into_owned()),
+            ..Config::default()
+        };
+
+        // Just a primary volume descriptor: a valid label but no files
+        let iso_path = temp_dir.path().join("external.iso");
+        let mut image = vec![0u8; 17 * 2048];
+        image[16 * 2048] = 1;
+        image[16 * 2048 + 1..16 * 2048 + 6].copy_from_slice(b"CD001");
+        image[16 * 2048 + 40..16 * 2048 + 72].copy_from_slice(format!("{:<32}", "PHOTOS_2019").as_bytes());
+        fs::write(&iso_path, &image)?;
+
+        let outcome = burn_existing_iso(&mut conn, &config, &iso_path, None, true, None)?;
+        assert_eq!(outcome.disc_id, "PHOTOS_2019");
+        assert_eq!(outcome.file_count, 0);
+        let disc = database::Disc::get(&conn, "PHOTOS_2019")?.expect("disc recorded");
+        assert_eq!(disc.volume_label, "PHOTOS_2019");
+        assert_eq!(disc.iso_size, Some(image.len() as u64));
+        assert!(disc.planned, "a dry run records a planned disc");
+        assert_eq!(disc.burn_device, None);
+
+        // The planned disc doesn't hold the ID: running again replaces it
+        burn_existing_iso(&mut conn, &config, &iso_path, None, true, None)?;
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM discs WHERE disc_id = 'PHOTOS_2019'", [], |r| r.get(0))?;
+        assert_eq!(count, 1);
+
+        // With indexing of dry runs off, nothing is recorded
+        let mut config = config;
+        config.burn.index_dry_runs = false;
+        let outcome = burn_existing_iso(&mut conn, &config, &iso_path, Some("PHOTOS-DRY"), true, None)?;
+        assert_eq!(outcome.disc_id, "PHOTOS-DRY");
+        assert!(database::Disc::get(&conn, "PHOTOS-DRY")?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_mounted_disc() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let config = Config::default();
+        let disc_root = temp_dir.path().join("disc");
+        fs::create_dir_all(disc_root.join("ARCHIVE/docs"))?;
+        fs::write(disc_root.join("ARCHIVE/docs/a.txt"), "alpha")?;
+
+        let err = import_mounted_disc(&mut conn, &config, &disc_root).unwrap_err();
+        assert!(err.to_string().contains("BlueVault manifest"));
+
+        let files = manifest::generate_manifest_and_sums(&disc_root, None)?;
+        manifest::write_sha256sums_file(&disc_root.join("SHA256SUMS.txt"), &files)?;
+        let info = manifest::DiscManifestInfo {
+            disc_id: "OLD-2".to_string(),
+            volume_label: "OLD_2".to_string(),
+            created_at: "2019-05-01T10:00:00Z".to_string(),
+            tool_version: "0.1.0".to_string(),
+            notes: Some("Scans".to_string()),
+            source_roots: vec!["/home/alex/scans".to_string()],
+            set_id: Some("SET-OLD".to_string()),
+            sequence_number: Some(2),
+            total_discs: Some(3),
+        };
+        manifest::write_manifest_json(
+            &disc_root.join(manifest::MANIFEST_JSON_FILE),
+            &manifest::DiscManifest::new(info, files),
+        )?;
+
+        let outcome = import_mounted_disc(&mut conn, &config, &disc_root)?;
+        assert_eq!(outcome.disc_id, "OLD-2");
+        assert_eq!(outcome.file_count, 1);
+        let disc = database::Disc::get(&conn, "OLD-2")?.expect("disc imported");
+        assert_eq!(disc.created_at, "2019-05-01T10:00:00Z");
+        assert!(disc.checksum_manifest_hash.is_some());
+        // The set isn't catalogued, so the disc stands alone and says where it belonged
+        assert_eq!(disc.set_id, None);
+        assert_eq!(disc.notes.as_deref(), Some("Scans\nDisc 2 of 3 in set SET-OLD (set not in catalog)"));
+        assert_eq!(database::FileRecord::list_for_disc(&conn, "OLD-2")?.len(), 1);
+
+        let err = import_mounted_disc(&mut conn, &config, &disc_root).unwrap_err();
+        assert!(err.to_string().contains("already exists in catalog"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_disc_failure_classification() {
+        let burn = ArchiveError::BurnFailed("No writable medium found".to_string());
+
+        // First disc of the run: the cause decides the variant
+        assert!(matches!(
+            MultiDiscError::from_disc_failure("session_1", vec![], 1, burn.clone()),
+            MultiDiscError::BurnFailed { disc_number: 1, error: ArchiveError::BurnFailed(_), .. }
+        ));
+        assert!(matches!(
+            MultiDiscError::from_disc_failure("session_1", vec![], 1, ArchiveError::DeviceUnavailable("gone".to_string())),
+            MultiDiscError::HardwareFailure(_)
+        ));
+        assert!(matches!(
+            MultiDiscError::from_disc_failure("session_1", vec![], 1, ArchiveError::StagingIo("disk full".to_string())),
+            MultiDiscError::StagingFailed { disc_number: 1, .. }
+        ));
+
+        // Later discs keep the typed cause inside the partial success
+        let err = MultiDiscError::from_disc_failure("session_1", vec![1, 2], 3, burn);
+        assert!(matches!(
+            &err,
+            MultiDiscError::PartialSuccess { failed_disc: 3, error: ArchiveError::BurnFailed(_), .. }
+        ));
+        assert!(err.recovery_hint().is_some());
+
+        // Cancelling is never reported as a failure
+        assert!(matches!(
+            MultiDiscError::from_disc_failure("session_1", vec![1], 2, ArchiveError::Cancelled),
+            MultiDiscError::UserCancelled
+        ));
+    }
+
+    #[test]
+    fn test_create_disc_reports_capacity_exceeded() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let source = temp_dir.path().join("src");
+        fs::create_dir_all(&source)?;
+        fs::write(source.join("file.txt"), "content")?;
+
+        // Zero capacity: even the disc's own metadata files don't fit
+        let config = Config {
+            staging_dir: Some(temp_dir.path().join("staging").to_string_lossy().into_owned()),
+            default_capacity_gb: 0,
+            ..Config::default()
+        };
+        let mut request = ArchiveRequest::new("TEST-CAP", vec![source], config);
+        request.dry_run = true;
+
+        let err = create_disc(&mut conn, &request, None).unwrap_err();
+        assert!(matches!(err, ArchiveError::CapacityExceeded { required, capacity: 0 } if required > 0));
+        assert!(err.recovery_hint().is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_dispatch_burns_each_disc_once() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let source = temp_dir.path().join("src");
+        fs::create_dir_all(&source)?;
+        fs::write(source.join("file.txt"), "content")?;
+        let source_folders = vec![source];
+
+        let config = Config {
+            staging_dir: Some(temp_dir.path().join("staging").to_string_lossy().into_owned()),
+            devices: vec!["/dev/sr0".to_string(), "/dev/sr1".to_string()],
+            ..Config::default()
+        };
+        let plans: Vec<staging::DiscPlan> = (1..=3).map(|n| staging::DiscPlan::new(n, 1_000_000)).collect();
+
+        let disc_set = database::DiscSet {
+            set_id: "SET-PARALLEL".to_string(),
+            name: "parallel".to_string(),
+            description: None,
+            total_size: 0,
+            disc_count: 3,
+            created_at: format_timestamp_now(),
+            source_roots: None,
+            status: database::SetStatus::InProgress,
+            completed_count: 0,
+        };
+        let mut session = BurnSession::new(disc_set.set_id.clone(), "PAR".to_string(), 3, source_folders.clone(), "{}".to_string());
+        session.set_plans(&plans)?;
+        database::MultiDiscOps::create_disc_set_with_session(&mut conn, &disc_set, &session)?;
+
+        let set = SetContext {
+            disc_id_base: "PAR",
+            notes: "",
+            set_id: &disc_set.set_id,
+            source_folders: &source_folders,
+            destination_names: &BTreeMap::new(),
+            plans: &plans,
+            total_discs: 3,
+            dry_run: true,
+            config: &config,
+        };
+        let remaining: Vec<&staging::DiscPlan> = plans.iter().collect();
+        let db = Mutex::new(&mut conn);
+        let (burned, failure) = burn_set_discs_in_parallel(
+            &db,
+            &set,
+            &remaining,
+            &config.burn_devices(),
+            SetProgressTracker::new(&plans, &[]),
+            &session.session_id,
+            &Reporter::default(),
+            &mut |_, _, _| true,
+        );
+
+        assert!(failure.is_none(), "{:?}", failure);
+        let mut numbers: Vec<usize> = burned.iter().map(|(n, _)| *n).collect();
+        numbers.sort_unstable();
+        assert_eq!(numbers, vec![1, 2, 3]);
+
+        let saved = BurnSession::load(&conn, &session.session_id)?.unwrap();
+        assert_eq!(saved.completed_discs, vec![1, 2, 3]);
+        assert_eq!(saved.current_disc, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_disc_layout_verifies() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("photos");
+        fs::create_dir_all(source.join("2024"))?;
+        fs::write(source.join("2024/a.jpg"), "jpeg a")?;
+        fs::write(source.join("b.jpg"), "jpeg b")?;
+        let source_folders = vec![source];
+
+        let config = Config::default();
+        let plans = staging::plan_disc_layout(&source_folders, 1_000_000, (&config.staging).into())?;
+        let set = SetContext {
+            disc_id_base: "LAYOUT",
+            notes: "",
+            set_id: "SET-LAYOUT",
+            source_folders: &source_folders,
+            destination_names: &BTreeMap::new(),
+            plans: &plans,
+            total_discs: 1,
+            dry_run: false,
+            config: &config,
+        };
+        let disc_dir = temp_dir.path().join("disc_1");
+        let reporter = Reporter::default();
+        stage_disc_content(&plans[0], &source_folders, &BTreeMap::new(), &disc_dir, &config.staging, None, false, &reporter)?;
+        let (files, manifest_hash) = write_set_disc_metadata(&set, &disc_dir, "LAYOUT-1", 1, &[], &reporter)?;
+
+        assert!(disc_dir.join("disc_info/DISC_INFO.txt").is_file());
+        assert!(files.iter().any(|f| f.rel_path == Path::new("photos/2024/a.jpg")));
+        assert!(manifest_hash.is_some());
+
+        // What set verification looks for once the disc is mounted
+        assert!(crate::verify::is_readable_archive(&disc_dir));
+        assert_eq!(crate::verify::read_disc_id(&disc_dir), Some("LAYOUT-1".to_string()));
+        let result = crate::verify::verify_disc(&disc_dir, false, false)?;
+        assert!(result.success, "{:?}", result.error_message);
+        assert_eq!(result.files_checked, files.len() as u32);
+
+        let disc_manifest = manifest::read_manifest_json(&disc_dir.join(manifest::MANIFEST_JSON_FILE))?;
+        assert_eq!(disc_manifest.disc.set_id.as_deref(), Some("SET-LAYOUT"));
+        assert_eq!(disc_manifest.disc.sequence_number, Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_disc_normalizes_names() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("docs");
+        fs::create_dir_all(source.join("Cafe\u{301}"))?;
+        fs::write(source.join("Cafe\u{301}/re\u{301}sume\u{301}.txt"), "cv")?;
+        let source_folders = vec![source];
+
+        let config = Config::default();
+        let plans = staging::plan_disc_layout(&source_folders, 1_000_000, (&config.staging).into())?;
+        let set = SetContext {
+            disc_id_base: "NFC",
+            notes: "",
+            set_id: "SET-NFC",
+            source_folders: &source_folders,
+            destination_names: &BTreeMap::new(),
+            plans: &plans,
+            total_discs: 1,
+            dry_run: false,
+            config: &config,
+        };
+        let disc_dir = temp_dir.path().join("disc_1");
+        let reporter = Reporter::default();
+        stage_disc_content(&plans[0], &source_folders, &BTreeMap::new(), &disc_dir, &config.staging, None, false, &reporter)?;
+        let (files, _) = write_set_disc_metadata(&set, &disc_dir, "NFC-1", 1, &[], &reporter)?;
+
+        // The manifest path is NFC and so is the staged file it points at
+        let nfc = Path::new("docs/Caf\u{e9}/r\u{e9}sum\u{e9}.txt");
+        assert!(files.iter().any(|f| f.rel_path == nfc));
+        assert_eq!(fs::read_to_string(disc_dir.join(nfc))?, "cv");
+        let result = crate::verify::verify_disc(&disc_dir, false, false)?;
+        assert!(result.success, "{:?}", result.error_message);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_set_staged_by_burn() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let source = temp_dir.path().join("docs");
+        fs::create_dir_all(&source)?;
+        fs::write(source.join("a.txt"), "alpha")?;
+        fs::write(source.join("b.txt"), "bravo")?;
+        let source_folders = vec![source];
+
+        // One file per disc
+        let config = Config::default();
+        let plans = staging::plan_disc_layout(&source_folders, 6, (&config.staging).into())?;
+        assert_eq!(plans.len(), 2);
+        let set_id = database::MultiDiscOps::create_disc_set(&mut conn, "VSET", None, 0, 2, None)?;
+        let set = SetContext {
+            disc_id_base: "VSET",
+            notes: "",
+            set_id: &set_id,
+            source_folders: &source_folders,
+            destination_names: &BTreeMap::new(),
+            plans: &plans,
+            total_discs: 2,
+            dry_run: false,
+            config: &config,
+        };
+
+        // Stage and record each disc the way burn_set_disc does, minus the ISO and burn
+        let mounts = temp_dir.path().join("mounts");
+        let reporter = Reporter::default();
+        for plan in &plans {
+            let disc_id = disc::generate_multi_disc_id(set.disc_id_base, plan.disc_number as u32);
+            let disc_dir = mounts.join(format!("disc_{}", plan.disc_number));
+            stage_disc_content(plan, &source_folders, &BTreeMap::new(), &disc_dir, &config.staging, None, false, &reporter)?;
+            let (files, manifest_hash) = write_set_disc_metadata(&set, &disc_dir, &disc_id, plan.disc_number, &[], &reporter)?;
+            record_set_disc_in_database(&mut conn, &set, &disc_id, plan.disc_number, plan, "/dev/null", manifest_hash)?;
+            index_files_in_database(&mut conn, &disc_id, &files)?;
+        }
+
+        let result = crate::verify::verify_multi_disc_set(
+            &conn,
+            &set_id,
+            "/dev/null",
+            Some(&mounts),
+            false,
+            |_, _, _, _| panic!("both discs are mounted"),
+            |_, _| {},
+        )?;
+        assert!(result.overall_success(), "{:?}", result.per_disc);
+        assert_eq!(result.discs_verified(), 2);
+        assert!(result.per_disc.iter().all(|(_, _, r)| r.files_checked > 0));
+
+        let mut indexed: Vec<String> = ["VSET-1", "VSET-2"]
+            .iter()
+            .map(|disc_id| database::FileRecord::list_for_disc(&conn, disc_id))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .map(|f| f.rel_path)
+            .filter(|path| path.starts_with("docs/"))
+            .collect();
+        indexed.sort();
+        assert_eq!(indexed, ["docs/a.txt", "docs/b.txt"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_info_matches_staged_discs() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("photos");
+        fs::create_dir_all(source.join("album"))?;
+        for name in ["a.jpg", "b.jpg", "c.jpg"] {
+            fs::write(source.join("album").join(name), vec![0u8; 40])?;
+        }
+        let source_folders = vec![source.clone()];
+
+        // The album split over two discs: a.jpg and b.jpg, then c.jpg
+        let config = Config::default();
+        let album = staging::analyze_directory_structure(&source.join("album"), (&config.staging).into())?;
+        let plans: Vec<staging::DiscPlan> = [vec!["a.jpg", "b.jpg"], vec!["c.jpg"]]
+            .into_iter()
+            .enumerate()
+            .map(|(i, names)| {
+                let children: Vec<staging::DirectoryEntry> =
+                    album.children.iter().filter(|c| names.iter().any(|n| c.path.ends_with(n))).cloned().collect();
+                let mut plan = staging::DiscPlan::new(i + 1, 100);
+                plan.add_entry(staging::DirectoryEntry {
+                    path: album.path.clone(),
+                    size_bytes: children.iter().map(|c| c.size_bytes).sum(),
+                    is_file: false,
+                    children,
+                });
+                plan.split_directories.push(album.path.display().to_string());
+                plan
+            })
+            .collect();
+        let set = SetContext {
+            disc_id_base: "SPLIT",
+            notes: "",
+            set_id: "SET-SPLIT",
+            source_folders: &source_folders,
+            destination_names: &BTreeMap::new(),
+            plans: &plans,
+            total_discs: plans.len(),
+            dry_run: false,
+            config: &config,
+        };
+
+        let reporter = Reporter::default();
+        let mut disc_dirs = BTreeMap::new();
+        for plan in &plans {
+            let disc_dir = temp_dir.path().join(format!("disc_{}", plan.disc_number));
+            stage_disc_content(plan, &source_folders, &BTreeMap::new(), &disc_dir, &config.staging, None, false, &reporter)?;
+            let disc_id = disc::generate_multi_disc_id(set.disc_id_base, plan.disc_number as u32);
+            write_set_disc_metadata(&set, &disc_dir, &disc_id, plan.disc_number, &[], &reporter)?;
+            disc_dirs.insert(plan.disc_number, disc_dir);
+        }
+
+        // Every disc's record lists exactly the album files staged on each disc
+        for (number, disc_dir) in &disc_dirs {
+            let json = fs::read_to_string(disc_dir.join("disc_info").join(staging::SPLIT_INFO_FILE))?;
+            let info: staging::SplitInfo = serde_json::from_str(&json)?;
+            assert_eq!(info.disc_number, *number);
+            assert_eq!(info.directories.len(), 1);
+            let split = &info.directories[0];
+            let on_disc = Path::new(&split.directory).strip_prefix(&source)?;
+            assert_eq!(on_disc, Path::new("album"));
+            for part in &split.parts {
+                let mut staged: Vec<String> = fs::read_dir(disc_dirs[&part.disc_number].join("photos").join(on_disc))?
+                    .map(|entry| entry.map(|e| e.file_name().to_string_lossy().into_owned()))
+                    .collect::<std::io::Result<_>>()?;
+                staged.sort();
+                assert_eq!(staged, part.files);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_disc_progress_eta() {
+        let plans: Vec<staging::DiscPlan> = (1..=4)
+            .map(|n| {
+                let mut plan = staging::DiscPlan::new(n, 100);
+                plan.used_bytes = 100;
+                plan
+            })
+            .collect();
+
+        // Resumed at disc 3: discs 1-2 were burned by an earlier run
+        let mut tracker = SetProgressTracker::new(&plans, &[1, 2]);
+        let progress = tracker.progress(3);
+        assert_eq!(progress.bytes_done, 200);
+        assert_eq!(progress.discs_completed(), 2);
+        assert_eq!(progress.discs_remaining(), 2);
+        assert_eq!(progress.eta_secs, None);
+        assert!((progress.fraction() - 0.5).abs() < f64::EPSILON);
+
+        tracker.disc_done(&plans[2]);
+        assert_eq!(tracker.progress(4).discs_remaining(), 1);
+
+        // Only bytes from this run count towards the rate: 100 bytes in 60s
+        assert_eq!(estimate_eta_secs(100, Duration::from_secs(60), 100), Some(60));
+        assert_eq!(estimate_eta_secs(100, Duration::from_secs(60), 250), Some(150));
+        assert_eq!(estimate_eta_secs(0, Duration::from_secs(60), 250), None);
+    }
+
+    #[test]
+    fn test_progress_record_json() {
+        let stage = ProgressRecord::from(&ArchiveEvent::Stage(ArchiveStage::CreatingIso, "Creating ISO".to_string()));
+        assert_eq!(stage.to_json_line(), r#"{"event":"stage","phase":"creating_iso","message":"Creating ISO"}"#);
+
+        let set = ProgressRecord::from(&ArchiveEvent::MultiDiscProgress(MultiDiscProgress {
+            current: 2,
+            total: 3,
+            bytes_done: 100,
+            bytes_total: 400,
+            eta_secs: None,
+        }));
+        assert_eq!(
+            set.to_json_line(),
+            r#"{"event":"multi_disc","disc_current":2,"disc_total":3,"bytes_done":100,"bytes_total":400,"fraction":0.25}"#
+        );
+
+        let error = ProgressRecord::new("error").with_message("Burn failed");
+        assert_eq!(error.to_json_line(), r#"{"event":"error","message":"Burn failed"}"#);
+    }
+
+    #[test]
+    fn test_describe_burn_progress() {
+        let total = 25_000_000_000;
+        // Reported speed wins: 4x BD is ~18 MB/s, so 12.5 GB left take ~11 minutes
+        let reported = burn::BurnProgress { fraction: 0.5, speed_x: Some(4.0), fifo_percent: Some(98) };
+        assert_eq!(
+            describe_burn_progress(&reported, total, Duration::from_secs(60)),
+            "🔥 Burning... 50% written | 17MB/s (4.0x) | fifo 98% | 11min remaining"
+        );
+        // Without a reported speed, fall back to the average since the start
+        let bare = burn::BurnProgress { fraction: 0.5, speed_x: None, fifo_percent: None };
+        assert_eq!(
+            describe_burn_progress(&bare, total, Duration::from_secs(1250)),
+            "🔥 Burning... 50% written | ~10MB/s avg | 20min remaining"
+        );
+    }
+
+    #[test]
+    fn test_abort_multi_disc_removes_set() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let staging = temp_dir.path().join("staging");
+        let config = Config {
+            staging_dir: Some(staging.to_string_lossy().into_owned()),
+            ..Config::default()
+        };
+
+        let set_id = database::MultiDiscOps::create_disc_set(&mut conn, "Set", None, 100, 2, None)?;
+        let burned_id = disc::generate_multi_disc_id("SET", 1);
+        let mut burned = database::Disc {
+            disc_id: burned_id.clone(),
+            volume_label: "SET_1".to_string(),
+            created_at: format_timestamp_now(),
+            notes: None,
+            iso_size: None,
+            burn_device: None,
+            checksum_manifest_hash: None,
+            qr_path: None,
+            source_roots: None,
+            tool_version: None,
+            set_id: None,
+            sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
+        };
+        database::MultiDiscOps::add_disc_to_set(&mut conn, &mut burned, &set_id, 1)?;
+        let session = BurnSession::new(set_id.clone(), "SET".to_string(), 2, vec![], "{}".to_string());
+        session.save(&conn)?;
+
+        fs::create_dir_all(staging.join("disc_2").join("ARCHIVE"))?;
+        fs::write(staging.join(disc::iso_file_name(&disc::generate_multi_disc_id("SET", 2))), "partial")?;
+        fs::write(staging.join("unrelated.txt"), "keep")?;
+
+        let cleanup = abort_multi_disc(&mut conn, &session.session_id, &config)?;
+        assert_eq!(cleanup.set_id, set_id);
+        assert_eq!(cleanup.burned_disc_ids, vec![burned_id.clone()]);
+        assert_eq!(cleanup.paths_removed.len(), 2);
+        assert!(cleanup.summary().contains(&burned_id));
+        assert!(!staging.join("disc_2").exists());
+        assert!(staging.join("unrelated.txt").exists());
+        assert!(database::DiscSet::get(&conn, &set_id)?.is_none());
+        assert!(database::Disc::get(&conn, &burned_id)?.is_none());
+        assert!(BurnSession::load(&conn, &session.session_id)?.is_none());
+
+        // The session is gone, so a second abort has nothing to clean
+        assert!(abort_multi_disc(&mut conn, &session.session_id, &config).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_retry_failed_disc_burns_it_first() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let source = temp_dir.path().join("src");
+        fs::create_dir_all(&source)?;
+        fs::write(source.join("file.txt"), "content")?;
+        let config = Config {
+            staging_dir: Some(temp_dir.path().join("staging").to_string_lossy().into_owned()),
+            ..Config::default()
+        };
+        let plans: Vec<staging::DiscPlan> = (1..=3).map(|n| staging::DiscPlan::new(n, 1_000_000)).collect();
+
+        let set_id = database::MultiDiscOps::create_disc_set(&mut conn, "Set", None, 3_000_000, 3, None)?;
+        let mut session = BurnSession::new(set_id, "RETRY".to_string(), 3, vec![source], "{}".to_string());
+        session.set_plans(&plans)?;
+        session.update_progress(1);
+        session.failed_discs.push(2);
+        session.save(&conn)?;
+
+        // Declining the insertion prompt stops the retry before anything is burned
+        let mut prompted = Vec::new();
+        let err = retry_failed_disc(&mut conn, &session.session_id, &config, &BTreeMap::new(), None, |sequence, _, _| {
+            prompted.push(sequence);
+            false
+        })
+        .unwrap_err();
+        assert!(matches!(err, MultiDiscError::UserCancelled));
+        assert_eq!(prompted, vec![2]);
+
+        let saved = BurnSession::load(&conn, &session.session_id)?.unwrap();
+        assert!(saved.failed_discs.is_empty());
+        assert_eq!(saved.completed_discs, vec![1]);
+
+        // With no failed disc left there is nothing to retry
+        let err = retry_failed_disc(&mut conn, &session.session_id, &config, &BTreeMap::new(), None, |_, _, _| true)
+            .unwrap_err();
+        assert!(matches!(err, MultiDiscError::DatabaseInconsistency(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_replan_overflowing_disc_moves_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let source = temp_dir.path().join("media");
+        fs::create_dir_all(source.join("videos"))?;
+        fs::write(source.join("videos/big.mkv"), vec![0u8; 600])?;
+        fs::write(source.join("videos/small.mkv"), vec![0u8; 100])?;
+        fs::write(source.join("notes.txt"), vec![0u8; 50])?;
+        let source_folders = vec![source];
+        let config = Config {
+            staging_dir: Some(temp_dir.path().join("staging").to_string_lossy().into_owned()),
+            ..Config::default()
+        };
+        let plans = staging::plan_disc_layout(&source_folders, 1_000_000, (&config.staging).into())?;
+        assert_eq!(plans.len(), 1);
+
+        let set_id = database::MultiDiscOps::create_disc_set(&mut conn, "Set", None, 750, 1, None)?;
+        let mut session = BurnSession::new(set_id.clone(), "REPLAN".to_string(), 1, source_folders.clone(), "{}".to_string());
+        session.set_plans(&plans)?;
+        session.failed_discs.push(1);
+        session.save(&conn)?;
+
+        // One byte over: the largest file moves to a new disc 2, then disc 1 is burned again
+        let mut prompted = Vec::new();
+        let iso_bytes = config.default_capacity_bytes() + 1;
+        let err = replan_overflowing_disc(&mut conn, &session.session_id, iso_bytes, &config, &BTreeMap::new(), None, |sequence, total, _| {
+            prompted.push((sequence, total));
+            false
+        })
+        .unwrap_err();
+        assert!(matches!(err, MultiDiscError::UserCancelled));
+        assert_eq!(prompted, vec![(1, 2)]);
+
+        let saved = BurnSession::load(&conn, &session.session_id)?.unwrap();
+        assert_eq!(saved.total_discs, 2);
+        assert!(saved.failed_discs.is_empty());
+        assert_eq!(database::DiscSet::get(&conn, &set_id)?.unwrap().disc_count, 2);
+        let plans = saved.stored_plans()?.unwrap();
+
+        // Each disc is staged from its own plan, so the moved file leaves disc 1
+        let reporter = Reporter::default();
+        let staged = |plan: &staging::DiscPlan| -> Result<PathBuf> {
+            let disc_dir = temp_dir.path().join(format!("disc_{}", plan.disc_number));
+            stage_disc_content(plan, &source_folders, &BTreeMap::new(), &disc_dir, &config.staging, None, false, &reporter)?;
+            Ok(disc_dir)
+        };
+        let first = staged(&plans[0])?;
+        assert!(!first.join("media/videos/big.mkv").exists());
+        assert!(first.join("media/videos/small.mkv").is_file());
+        assert!(first.join("media/notes.txt").is_file());
+        assert_eq!(staging::calculate_directory_size(&first)?, 150);
+        let second = staged(&plans[1])?;
+        assert!(second.join("media/videos/big.mkv").is_file());
+        assert_eq!(staging::calculate_directory_size(&second)?, 600);
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_to_disc_set_continues_numbering() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let source = temp_dir.path().join("more");
+        fs::create_dir_all(&source)?;
+        fs::write(source.join("new.txt"), "new content")?;
+        let config = Config {
+            staging_dir: Some(temp_dir.path().join("staging").to_string_lossy().into_owned()),
+            ..Config::default()
+        };
+
+        let set_id = database::MultiDiscOps::create_disc_set(&mut conn, "Set", None, 200, 2, None)?;
+        for sequence in 1..=2 {
+            let mut disc = database::Disc {
+                disc_id: disc::generate_multi_disc_id("APP", sequence),
+                volume_label: format!("APP_{}", sequence),
+                created_at: format_timestamp_now(),
+                notes: None,
+                iso_size: Some(100),
+                burn_device: None,
+                checksum_manifest_hash: None,
+                qr_path: None,
+                source_roots: None,
+                tool_version: None,
+                set_id: None,
+                sequence_number: None,
+                burn_duration_secs: None,
+                avg_write_speed_mbps: None,
+                is_mdisc: false,
+                storage_location: None,
+                planned: false,
+            };
+            database::MultiDiscOps::add_disc_to_set(&mut conn, &mut disc, &set_id, sequence)?;
+        }
+        assert_eq!(database::DiscSet::get(&conn, &set_id)?.unwrap().status, database::SetStatus::Complete);
+
+        // Declining the insertion prompt leaves the grown set resumable
+        let request = ArchiveRequest::new("", vec![source], config);
+        let mut prompted = Vec::new();
+        let err = append_to_disc_set(&mut conn, &set_id, &request, None, |sequence, total, _| {
+            prompted.push((sequence, total));
+            false
+        })
+        .unwrap_err();
+        assert!(matches!(err, MultiDiscError::UserCancelled));
+        assert_eq!(prompted, vec![(3, 3)]);
+
+        let disc_set = database::DiscSet::get(&conn, &set_id)?.unwrap();
+        assert_eq!(disc_set.disc_count, 3);
+        assert!(disc_set.total_size > 200);
+        assert_eq!(disc_set.status, database::SetStatus::Cancelled);
+        let session = database::BurnSessionOps::latest_for_set(&conn, &set_id)?.unwrap();
+        assert_eq!(session.session_name, "APP");
+        assert_eq!(session.status, database::BurnSessionStatus::Paused);
+        assert_eq!(session.completed_discs, vec![1, 2]);
+        assert_eq!(session.stored_plans()?.unwrap()[0].disc_number, 3);
+
+        // Only finished sets can be extended
+        let err = append_to_disc_set(&mut conn, &set_id, &request, None, |_, _, _| true).unwrap_err();
+        assert!(matches!(err, MultiDiscError::PlanningFailed(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_multi_disc_rejects_incremental() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let source = temp_dir.path().join("src");
+        fs::create_dir_all(&source)?;
+
+        let mut request = ArchiveRequest::new("SET", vec![source], Config::default());
+        request.incremental = true;
+
+        let err = create_multi_disc(&mut conn, &request, None, |_, _, _| true).unwrap_err();
+        assert!(matches!(err, MultiDiscError::PlanningFailed(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_eject_after_burn() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let reporter = Reporter(Some(Arc::new(move |event| sink.lock().unwrap().push(event))));
+
+        // Off by default: the drive is never touched
+        let mut config = Config::default();
+        assert!(!config.burn.eject_after_disc);
+        eject_after_burn(&config, "/dev/nonexistent-sr9", &reporter);
+        assert!(events.lock().unwrap().is_empty());
+
+        // A drive that can't eject doesn't fail the finished burn
+        config.burn.eject_after_disc = true;
+        eject_after_burn(&config, "/dev/nonexistent-sr9", &reporter);
+        assert!(events.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,332 @@
+use crate::manifest::{self, FileMetadata};
+use crate::search::format_size;
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+use walkdir::WalkDir;
+
+/// Declaration file marking a directory as a bag
+pub const BAGIT_FILE: &str = "bagit.txt";
+pub const BAG_INFO_FILE: &str = "bag-info.txt";
+pub const PAYLOAD_MANIFEST: &str = "manifest-sha256.txt";
+pub const TAG_MANIFEST: &str = "tagmanifest-sha256.txt";
+/// Directory holding the payload, i.e. the whole BlueVault disc layout
+pub const PAYLOAD_DIR: &str = "data";
+
+const BAGIT_VERSION: &str = "1.0";
+
+/// Metadata written to bag-info.txt.
+#[derive(Debug, Clone, Default)]
+pub struct BagInfo {
+    /// Disc ID, recorded as External-Identifier
+    pub external_identifier: String,
+    /// Disc notes, recorded as External-Description
+    pub description: Option<String>,
+}
+
+/// Payload totals of a bag (the Payload-Oxum).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PayloadOxum {
+    pub bytes: u64,
+    pub files: u64,
+}
+
+impl std::fmt::Display for PayloadOxum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.bytes, self.files)
+    }
+}
+
+/// Whether `root` is a bag (has a bagit.txt).
+pub fn is_bag(root: &Path) -> bool {
+    root.join(BAGIT_FILE).is_file()
+}
+
+/// Where the BlueVault layout (ARCHIVE/, SHA256SUMS.txt, ...) of a disc starts:
+/// `data/` for bagged discs, the disc root otherwise.
+pub fn content_root(root: &Path) -> PathBuf {
+    if is_bag(root) {
+        root.join(PAYLOAD_DIR)
+    } else {
+        root.to_path_buf()
+    }
+}
+
+/// Turn a staged disc into a BagIt bag (RFC 8493) in place.
+///
+/// Everything already in `disc_root` moves under `data/`, so the BlueVault
+/// files keep their relative paths and `sha256sum -c` still works from
+/// there. SHA-256 hashes already computed for the manifest (`known`, with
+/// paths relative to the old root) are reused; everything else is hashed.
+/// DISC_INFO.txt is also copied next to bagit.txt so the disc stays
+/// self-describing at the top level.
+pub fn make_bag(disc_root: &Path, known: &[FileMetadata], info: &BagInfo) -> Result<PayloadOxum> {
+    let payload_dir = disc_root.join(PAYLOAD_DIR);
+    let entries: Vec<PathBuf> = fs::read_dir(disc_root)
+        .with_context(|| format!("Failed to read disc root: {}", disc_root.display()))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<std::io::Result<_>>()?;
+    fs::create_dir(&payload_dir)
+        .with_context(|| format!("Failed to create payload directory: {}", payload_dir.display()))?;
+    for entry in entries {
+        let dest = payload_dir.join(entry.file_name().unwrap_or_default());
+        fs::rename(&entry, &dest).with_context(|| format!("Failed to move {} into data/", entry.display()))?;
+    }
+
+    let known: BTreeMap<&Path, &str> = known
+        .iter()
+        .filter(|f| !f.sha256.is_empty() && f.link_target.is_none() && f.duplicate_of.is_none())
+        .map(|f| (f.rel_path.as_path(), f.sha256.as_str()))
+        .collect();
+
+    let mut lines = Vec::new();
+    let mut oxum = PayloadOxum::default();
+    for (path, size) in payload_files(&payload_dir)? {
+        let rel = path.strip_prefix(&payload_dir).unwrap_or(&path);
+        let sha256 = match known.get(rel) {
+            Some(sha256) => sha256.to_string(),
+            None => manifest::calculate_sha256(&path)?,
+        };
+        lines.push(manifest_line(&sha256, &Path::new(PAYLOAD_DIR).join(rel)));
+        oxum.bytes += size;
+        oxum.files += 1;
+    }
+    fs::write(disc_root.join(PAYLOAD_MANIFEST), lines.concat()).context("Failed to write manifest-sha256.txt")?;
+
+    fs::write(
+        disc_root.join(BAGIT_FILE),
+        format!("BagIt-Version: {}\nTag-File-Character-Encoding: UTF-8\n", BAGIT_VERSION),
+    )
+    .context("Failed to write bagit.txt")?;
+
+    let mut bag_info = format!(
+        "Bagging-Date: {}\nBag-Software-Agent: BlueVault {}\nExternal-Identifier: {}\n",
+        &crate::disc::format_timestamp_now()[..10],
+        env!("CARGO_PKG_VERSION"),
+        info.external_identifier
+    );
+    if let Some(description) = info.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        // Continuation lines of a tag value are indented
+        bag_info.push_str(&format!("External-Description: {}\n", description.trim().replace('\n', "\n  ")));
+    }
+    bag_info.push_str(&format!("Bag-Size: {}\nPayload-Oxum: {}\n", format_size(oxum.bytes), oxum));
+    fs::write(disc_root.join(BAG_INFO_FILE), bag_info).context("Failed to write bag-info.txt")?;
+
+    let disc_info = payload_dir.join("DISC_INFO.txt");
+    let mut tag_files = vec![BAGIT_FILE, BAG_INFO_FILE, PAYLOAD_MANIFEST];
+    if disc_info.is_file() {
+        fs::copy(&disc_info, disc_root.join("DISC_INFO.txt")).context("Failed to copy DISC_INFO.txt")?;
+        tag_files.push("DISC_INFO.txt");
+    }
+    let mut tag_lines = Vec::new();
+    for name in tag_files {
+        tag_lines.push(manifest_line(&manifest::calculate_sha256(&disc_root.join(name))?, Path::new(name)));
+    }
+    fs::write(disc_root.join(TAG_MANIFEST), tag_lines.concat()).context("Failed to write tagmanifest-sha256.txt")?;
+
+    info!("Packaged disc as a BagIt bag: {} files, {}", oxum.files, format_size(oxum.bytes));
+    Ok(oxum)
+}
+
+/// Outcome of validating a bag.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BagValidation {
+    /// Payload files whose checksum was checked
+    pub files_checked: u32,
+    /// Missing files, checksum mismatches, unlisted payload files and bad tags
+    pub failures: Vec<String>,
+}
+
+impl BagValidation {
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Validate the bag at `root`: the declaration must be present, every file in
+/// manifest-sha256.txt must exist with that checksum, every payload file must
+/// be listed, Payload-Oxum (if given) must match and tag files listed in
+/// tagmanifest-sha256.txt must be intact.
+pub fn validate_bag(root: &Path) -> Result<BagValidation> {
+    let declaration = fs::read_to_string(root.join(BAGIT_FILE))
+        .with_context(|| format!("{} not found in {}", BAGIT_FILE, root.display()))?;
+    let declaration = parse_tags(&declaration);
+    if !declaration.contains_key("BagIt-Version") || !declaration.contains_key("Tag-File-Character-Encoding") {
+        anyhow::bail!("{} lacks BagIt-Version or Tag-File-Character-Encoding", BAGIT_FILE);
+    }
+
+    let mut validation = BagValidation::default();
+    let manifest_text = fs::read_to_string(root.join(PAYLOAD_MANIFEST))
+        .with_context(|| format!("{} not found in {}", PAYLOAD_MANIFEST, root.display()))?;
+    let mut listed = BTreeSet::new();
+    for (sha256, rel) in parse_manifest(&manifest_text) {
+        if !rel.starts_with(PAYLOAD_DIR) || rel.components().any(|c| c == std::path::Component::ParentDir) {
+            validation.failures.push(format!("{}: not a payload path", rel.display()));
+            continue;
+        }
+        check_file(root, &rel, &sha256, &mut validation);
+        validation.files_checked += 1;
+        listed.insert(rel);
+    }
+
+    let mut oxum = PayloadOxum::default();
+    for (path, size) in payload_files(&root.join(PAYLOAD_DIR))? {
+        let rel = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+        if !listed.contains(&rel) {
+            validation.failures.push(format!("{}: not listed in {}", rel.display(), PAYLOAD_MANIFEST));
+        }
+        oxum.bytes += size;
+        oxum.files += 1;
+    }
+
+    if let Ok(bag_info) = fs::read_to_string(root.join(BAG_INFO_FILE)) {
+        if let Some(expected) = parse_tags(&bag_info).get("Payload-Oxum") {
+            if *expected != oxum.to_string() {
+                validation
+                    .failures
+                    .push(format!("Payload-Oxum is {} but the payload holds {}", expected, oxum));
+            }
+        }
+    }
+
+    if let Ok(tag_manifest) = fs::read_to_string(root.join(TAG_MANIFEST)) {
+        for (sha256, rel) in parse_manifest(&tag_manifest) {
+            check_file(root, &rel, &sha256, &mut validation);
+        }
+    }
+
+    if validation.is_valid() {
+        info!("Bag at {} is valid: {} files", root.display(), validation.files_checked);
+    } else {
+        warn!("Bag at {} is invalid: {} problems", root.display(), validation.failures.len());
+    }
+    Ok(validation)
+}
+
+fn check_file(root: &Path, rel: &Path, expected: &str, validation: &mut BagValidation) {
+    let path = root.join(rel);
+    if !path.is_file() {
+        validation.failures.push(format!("{}: missing", rel.display()));
+        return;
+    }
+    match manifest::calculate_sha256(&path) {
+        Ok(actual) if actual.eq_ignore_ascii_case(expected) => {}
+        Ok(_) => validation.failures.push(format!("{}: checksum mismatch", rel.display())),
+        Err(e) => validation.failures.push(format!("{}: {:#}", rel.display(), e)),
+    }
+}
+
+/// Files under the payload directory with their sizes, sorted by path.
+/// Symlinks are followed the way a BagIt tool reading the disc sees them;
+/// dangling ones are skipped.
+fn payload_files(payload_dir: &Path) -> Result<Vec<(PathBuf, u64)>> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(payload_dir).sort_by_file_name() {
+        let entry = entry.context("Failed to walk the bag payload")?;
+        if entry.file_type().is_dir() {
+            continue;
+        }
+        match fs::metadata(entry.path()) {
+            Ok(metadata) if metadata.is_file() => files.push((entry.path().to_path_buf(), metadata.len())),
+            Ok(_) => {}
+            Err(_) => warn!("Skipping dangling symlink in bag payload: {}", entry.path().display()),
+        }
+    }
+    Ok(files)
+}
+
+/// One manifest line. BagIt paths use `/` and percent-encode CR, LF and `%`.
+fn manifest_line(sha256: &str, rel: &Path) -> String {
+    let path = rel
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A");
+    format!("{}  {}\n", sha256, path)
+}
+
+/// Parse manifest lines into (checksum, path), decoding the path.
+fn parse_manifest(text: &str) -> Vec<(String, PathBuf)> {
+    text.lines()
+        .filter_map(|line| {
+            let (sha256, path) = line.trim_end_matches('\r').split_once(char::is_whitespace)?;
+            let path = path
+                .trim_start()
+                .replace("%0A", "\n")
+                .replace("%0a", "\n")
+                .replace("%0D", "\r")
+                .replace("%0d", "\r")
+                .replace("%25", "%");
+            Some((sha256.to_string(), PathBuf::from(path)))
+        })
+        .collect()
+}
+
+/// Parse `Label: value` tag lines, joining indented continuation lines.
+fn parse_tags(text: &str) -> BTreeMap<String, String> {
+    let mut tags: BTreeMap<String, String> = BTreeMap::new();
+    let mut last: Option<String> = None;
+    for line in text.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some(value) = last.as_ref().and_then(|label| tags.get_mut(label)) {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((label, value)) = line.split_once(':') {
+            tags.insert(label.trim().to_string(), value.trim().to_string());
+            last = Some(label.trim().to_string());
+        }
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_make_and_validate_bag() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("ARCHIVE/photos"))?;
+        fs::write(root.join("ARCHIVE/photos/a.jpg"), "jpeg")?;
+        fs::write(root.join("ARCHIVE/photos/100%\nodd.txt"), "odd")?;
+        fs::write(root.join("DISC_INFO.txt"), "Disc-ID: 2026-BD-1\n")?;
+        let files = manifest::generate_manifest_and_sums(root, None)?;
+
+        let info = BagInfo { external_identifier: "2026-BD-1".to_string(), description: Some("Photos".to_string()) };
+        let oxum = make_bag(root, &files, &info)?;
+        assert_eq!(oxum, PayloadOxum { bytes: 4 + 3 + 19, files: 3 });
+        assert!(is_bag(root));
+        assert_eq!(content_root(root), root.join("data"));
+        assert!(root.join("data/ARCHIVE/photos/a.jpg").is_file());
+        assert!(root.join("DISC_INFO.txt").is_file());
+
+        let manifest_text = fs::read_to_string(root.join(PAYLOAD_MANIFEST))?;
+        assert!(manifest_text.contains("  data/ARCHIVE/photos/100%25%0Aodd.txt\n"));
+        let bag_info = parse_tags(&fs::read_to_string(root.join(BAG_INFO_FILE))?);
+        assert_eq!(bag_info["Payload-Oxum"], "26.3");
+        assert_eq!(bag_info["External-Identifier"], "2026-BD-1");
+
+        let validation = validate_bag(root)?;
+        assert!(validation.is_valid(), "{:?}", validation.failures);
+        assert_eq!(validation.files_checked, 3);
+
+        // Corruption, an unlisted file and a changed tag file are all caught
+        fs::write(root.join("data/ARCHIVE/photos/a.jpg"), "JPEG")?;
+        fs::write(root.join("data/extra.txt"), "x")?;
+        fs::write(root.join("DISC_INFO.txt"), "edited")?;
+        let validation = validate_bag(root)?;
+        assert!(validation.failures.iter().any(|f| f.contains("a.jpg: checksum mismatch")));
+        assert!(validation.failures.iter().any(|f| f.contains("extra.txt: not listed")));
+        assert!(validation.failures.iter().any(|f| f.starts_with("Payload-Oxum")));
+        assert!(validation.failures.iter().any(|f| f.starts_with("DISC_INFO.txt")));
+        Ok(())
+    }
+}
@@ -1,15 +1,87 @@
 use crate::commands;
 use anyhow::{Context, Result};
 use std::path::Path;
+use std::time::Duration;
 use tracing::{error, info, warn};
 
+/// Timing measured for a completed burn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BurnStats {
+    pub duration_secs: f64,
+    /// Average write speed in megabytes (10^6 bytes) per second
+    pub avg_write_speed_mbps: f64,
+}
+
+impl BurnStats {
+    pub fn new(bytes_written: u64, duration: Duration) -> Self {
+        let duration_secs = duration.as_secs_f64();
+        let avg_write_speed_mbps = if duration_secs > 0.0 {
+            bytes_written as f64 / duration_secs / 1_000_000.0
+        } else {
+            0.0
+        };
+        Self {
+            duration_secs,
+            avg_write_speed_mbps,
+        }
+    }
+}
+
+/// A progress report parsed from the burner's output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BurnProgress {
+    /// Share of the image written, 0.0-1.0
+    pub fraction: f64,
+    /// Current write speed as a multiple of 1x Blu-ray (4.495 MB/s), if reported
+    pub speed_x: Option<f64>,
+    /// Fill level of the burner's FIFO buffer in percent, if reported
+    pub fifo_percent: Option<u32>,
+}
+
+/// Bytes per second written at 1x Blu-ray speed.
+const BD_1X_BYTES_PER_SEC: f64 = 4_495_000.0;
+
+impl BurnProgress {
+    /// Reported write speed in megabytes (10^6 bytes) per second.
+    pub fn speed_mbs(&self) -> Option<f64> {
+        self.speed_x.map(|x| x * BD_1X_BYTES_PER_SEC / 1_000_000.0)
+    }
+}
+
+/// Write speed passed to xorriso for M-DISC media. M-DISC BD-R is rated for
+/// 4x; burning it faster risks a weaker recording layer.
+const MDISC_WRITE_SPEED: &str = "speed=4b";
+
 /// Burn an ISO image or directory to a Blu-ray disc using xorriso.
-pub fn burn_iso(iso_path: &Path, device: &str, dry_run: bool) -> Result<()> {
-    burn_with_method(iso_path, device, dry_run, "iso")
+pub fn burn_iso(iso_path: &Path, device: &str, dry_run: bool, mdisc: bool, stall_timeout: Option<Duration>) -> Result<()> {
+    burn_with_method(iso_path, device, dry_run, "iso", mdisc, stall_timeout)
+}
+
+/// Burn using specified method: "iso" (burn ISO file) or "direct" (burn directory).
+/// With `mdisc`, the inserted medium must be M-DISC and is written at its rated speed.
+/// With `stall_timeout`, xorriso is killed once it prints nothing for that long.
+pub fn burn_with_method(
+    source_path: &Path,
+    device: &str,
+    dry_run: bool,
+    method: &str,
+    mdisc: bool,
+    stall_timeout: Option<Duration>,
+) -> Result<()> {
+    burn_with_progress(source_path, device, dry_run, method, mdisc, stall_timeout, &mut |_| {})
 }
 
-/// Burn using specified method: "iso" (burn ISO file) or "direct" (burn directory)
-pub fn burn_with_method(source_path: &Path, device: &str, dry_run: bool, method: &str) -> Result<()> {
+/// Like [`burn_with_method`], calling `on_progress` for every progress line
+/// xorriso prints, with the share written and the speed it reports.
+pub fn burn_with_progress(
+    source_path: &Path,
+    device: &str,
+    dry_run: bool,
+    method: &str,
+    mdisc: bool,
+    stall_timeout: Option<Duration>,
+    on_progress: &mut dyn FnMut(BurnProgress),
+) -> Result<()> {
     match method {
         "iso" => {
             info!("Burning ISO to device: {} -> {} (dry_run: {})", source_path.display(), device, dry_run);
@@ -47,6 +119,9 @@ pub fn burn_with_method(source_path: &Path, device: &str, dry_run: bool, method:
 
         // Check what type of media is in the drive
         check_media_type(device)?;
+        if mdisc {
+            check_mdisc_media(device)?;
+        }
     } else {
         info!("Skipping device validation (dry run)");
     }
@@ -62,8 +137,11 @@ pub fn burn_with_method(source_path: &Path, device: &str, dry_run: bool, method:
         let temp_iso = temp_dir.join("bluevault_direct.iso");
         temp_iso_str_storage = temp_iso.to_string_lossy().to_string();
 
-        // Create the ISO
-        let mkisofs_args = vec!["-as", "mkisofs", "-r", "-J", "-o", &temp_iso_str_storage, &source_path_str];
+        // Create the ISO. Level 3 so files of 4 GiB or more fit; [iso] settings
+        // only apply to images made by iso::create_iso
+        let mkisofs_args = vec![
+            "-as", "mkisofs", "-r", "-J", "-iso-level", "3", "-o", &temp_iso_str_storage, &source_path_str,
+        ];
         info!("Creating temporary ISO for direct burn: xorriso {}", mkisofs_args.join(" "));
         let iso_output = commands::execute_command("xorriso", &mkisofs_args, dry_run)?;
         if !iso_output.success {
@@ -77,19 +155,43 @@ pub fn burn_with_method(source_path: &Path, device: &str, dry_run: bool, method:
     };
 
     // Now build the args
-    let args = if method == "iso" {
-        vec!["-as", "cdrecord", "-v", &dev_arg, "-data", &source_path_str]
+    let mut args = vec!["-as", "cdrecord", "-v", &dev_arg];
+    if mdisc {
+        args.push(MDISC_WRITE_SPEED);
+    }
+    if method == "iso" {
+        args.extend(["-data", source_path_str.as_str()]);
     } else {
         // For direct, use the temp ISO path
-        vec!["-as", "cdrecord", "-v", &dev_arg, "-data", &temp_iso_str_storage]
-    };
+        args.extend(["-data", temp_iso_str_storage.as_str()]);
+    }
 
     info!(
         "About to execute xorriso command (dry_run: {}): xorriso {}",
         dry_run,
         args.join(" ")
     );
-    let output = commands::execute_command("xorriso", &args, dry_run)?;
+    let output = commands::execute_command_watched("xorriso", &args, dry_run, stall_timeout, &mut |line| {
+        if let Some(progress) = parse_burn_progress(line) {
+            on_progress(progress);
+        }
+    });
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => {
+            if let Some(temp_iso_path) = &temp_iso_path {
+                let _ = std::fs::remove_file(temp_iso_path);
+            }
+            if let Some(stalled) = e.downcast_ref::<commands::CommandStalled>() {
+                error!("Burn stalled: {}", stalled);
+                anyhow::bail!(
+                    "❌ BURNER STOPPED RESPONDING\n\nxorriso reported no progress for {}s and was stopped.\n\nSOLUTION:\n• Eject the disc; it is probably unusable if writing had started\n• Power-cycle or reconnect the drive if it stays busy\n• Raise burn.stall_timeout_secs if this drive pauses for long stretches",
+                    stalled.idle.as_secs()
+                );
+            }
+            return Err(e);
+        }
+    };
     info!(
         "xorriso command completed with exit code: {:?}",
         output.exit_code
@@ -132,6 +234,104 @@ pub fn burn_with_method(source_path: &Path, device: &str, dry_run: bool, method:
     Ok(())
 }
 
+/// Verify that `device` is usable before any staging work starts: the node must
+/// exist, be a block device and not be mounted.
+/// Progress from a line of `xorriso -as cdrecord -v` output, such as
+/// "Track 01:  812 of 23842 MB written (fifo 100%) [buf  99%]   4.0x." or
+/// xorriso's own "xorriso : UPDATE : Writing:  41536s   8.7%  fifo 100%  buf  50%   4.0xB".
+fn parse_burn_progress(line: &str) -> Option<BurnProgress> {
+    let fraction = if let Some(rest) = line.trim_start().strip_prefix("Track ") {
+        let (_, counts) = rest.split_once(':')?;
+        let (written, rest) = counts.split_once(" of ")?;
+        let total = rest.split_whitespace().next()?;
+        let written: f64 = written.trim().parse().ok()?;
+        let total: f64 = total.parse().ok()?;
+        if total <= 0.0 {
+            return None;
+        }
+        written / total
+    } else {
+        let (_, rest) = line.split_once("UPDATE : Writing:")?;
+        let percent = rest.split_whitespace().find_map(|token| token.strip_suffix('%'))?;
+        percent.parse::<f64>().ok()? / 100.0
+    };
+
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let fifo_percent = tokens
+        .windows(2)
+        .find(|pair| pair[0].trim_start_matches('(') == "fifo")
+        .and_then(|pair| pair[1].trim_end_matches(')').strip_suffix('%')?.parse().ok());
+    // "4.0x." (cdrecord style) or "4.0xB" (xorriso, B = Blu-ray)
+    let speed_x = tokens.iter().rev().find_map(|token| {
+        let (speed, unit) = token.rsplit_once('x')?;
+        matches!(unit, "" | "." | "B").then(|| speed.parse::<f64>().ok())?
+    });
+
+    Some(BurnProgress {
+        fraction: fraction.clamp(0.0, 1.0),
+        speed_x,
+        fifo_percent,
+    })
+}
+
+pub fn check_device(device: &str) -> Result<()> {
+    let device_path = Path::new(device);
+    let metadata = match std::fs::metadata(device_path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            anyhow::bail!(
+                "Optical device not found: {} (check that the drive is connected, or set `device` in the config)",
+                device
+            );
+        }
+        Err(e) => {
+            return Err(e).with_context(|| format!("Cannot access optical device {}", device));
+        }
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+        if !metadata.file_type().is_block_device() {
+            anyhow::bail!("{} is not a block device; set `device` to the optical drive (e.g. /dev/sr0)", device);
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = metadata;
+
+    if let Ok(mounts) = std::fs::read_to_string("/proc/mounts") {
+        if let Some(mount_point) = find_mount_point(&mounts, device_path) {
+            anyhow::bail!(
+                "Optical device {} is busy: mounted at {} (unmount it before burning)",
+                device,
+                mount_point
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Find where `device` is mounted in /proc/mounts-formatted text.
+/// Device symlinks such as /dev/cdrom are resolved before comparing.
+pub fn find_mount_point(mounts: &str, device: &Path) -> Option<String> {
+    let resolved = std::fs::canonicalize(device).unwrap_or_else(|_| device.to_path_buf());
+
+    mounts.lines().find_map(|line| {
+        let mut fields = line.split_whitespace();
+        let source = fields.next()?;
+        let target = fields.next()?;
+        let source_path = Path::new(source);
+        let source_resolved = std::fs::canonicalize(source_path).unwrap_or_else(|_| source_path.to_path_buf());
+        if source_path == device || source_resolved == resolved {
+            // /proc/mounts escapes spaces and tabs as octal
+            Some(target.replace("\\040", " ").replace("\\011", "\t"))
+        } else {
+            None
+        }
+    })
+}
+
 /// Check if device is ready for burning.
 pub fn check_device_ready(device: &str, dry_run: bool) -> Result<bool> {
     if dry_run {
@@ -149,6 +349,22 @@ pub fn check_device_ready(device: &str, dry_run: bool) -> Result<bool> {
     Ok(device_path.exists())
 }
 
+/// Open the tray of `device` with `eject`, falling back to xorriso's eject.
+/// Fails when neither can, e.g. on slot-loading drives or without either tool.
+pub fn eject(device: &str) -> Result<()> {
+    match commands::execute_command("eject", &[device], false) {
+        Ok(output) if output.success => return Ok(()),
+        Ok(output) => warn!("eject {} failed: {}", device, output.stderr.trim()),
+        Err(e) => warn!("eject is not available: {:#}", e),
+    }
+
+    let output = commands::execute_command("xorriso", &["-outdev", device, "-eject", "all"], false)?;
+    if !output.success {
+        anyhow::bail!("Cannot eject {}: {}", device, output.stderr.trim());
+    }
+    Ok(())
+}
+
 /// Check the type of media currently in the drive and warn about issues.
 pub fn check_media_type(device: &str) -> Result<()> {
     info!("Checking media type in drive: {}", device);
@@ -283,6 +499,185 @@ pub fn check_media_type(device: &str) -> Result<()> {
     Ok(())
 }
 
+/// What xorriso reports about the drive and the inserted medium.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MediaInfo {
+    /// Current medium profile, e.g. "BD-R sequential recording"
+    pub media_type: Option<String>,
+    /// Media ID burned into the disc by its maker, e.g. "VERBAT/IMe/000"
+    pub product_id: Option<String>,
+    /// Manufacturer name xorriso derives from the media ID
+    pub manufacturer: Option<String>,
+    /// Drive lists a BD-R profile
+    pub drive_supports_bdr: bool,
+}
+
+impl MediaInfo {
+    /// Whether the media ID belongs to known M-DISC stock. M-DISC BD-R is made
+    /// by Millenniata and sold by Verbatim, whose M-DISC IDs use the "IM" type code.
+    pub fn is_mdisc(&self) -> bool {
+        let product = self.product_id.as_deref().unwrap_or("").to_uppercase();
+        let manufacturer = self.manufacturer.as_deref().unwrap_or("").to_uppercase();
+        let mut parts = product.split(['/', '-']);
+        let maker = parts.next().unwrap_or("");
+        let media_type = parts.next().unwrap_or("");
+
+        maker.starts_with("MILLEN")
+            || manufacturer.contains("MILLENNIATA")
+            || (maker == "VERBAT" && media_type.starts_with("IM"))
+    }
+}
+
+/// Query the drive profiles and the inserted medium with xorriso.
+pub fn probe_media(device: &str) -> Result<MediaInfo> {
+    let profiles = commands::execute_command("xorriso", &["-outdev", device, "-list_profiles"], false)
+        .context("Failed to query drive profiles")?;
+    let toc = commands::execute_command("xorriso", &["-outdev", device, "-toc"], false)
+        .context("Failed to query inserted medium")?;
+    // xorriso writes its drive and media reports to stderr
+    Ok(parse_media_info(&profiles.stderr, &toc.stderr))
+}
+
+fn parse_media_info(profiles: &str, toc: &str) -> MediaInfo {
+    let field = |name: &str| {
+        toc.lines()
+            .find_map(|line| line.trim().strip_prefix(name))
+            .map(|rest| rest.trim_start_matches([' ', ':']).trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    let (product_id, manufacturer) = match field("Media product") {
+        // "VERBAT/IMe/000 , Verbatim Corporation"
+        Some(product) => match product.split_once(',') {
+            Some((id, maker)) => (Some(id.trim().to_string()), Some(maker.trim().to_string())),
+            None => (Some(product), None),
+        },
+        None => (None, None),
+    };
+
+    MediaInfo {
+        media_type: field("Media current"),
+        product_id,
+        manufacturer,
+        drive_supports_bdr: profiles.to_lowercase().contains("bd-r"),
+    }
+}
+
+/// Make sure the drive can write BD-R and the inserted medium is M-DISC.
+/// M-DISC BD-R needs no special drive feature beyond BD-R support.
+pub fn check_mdisc_media(device: &str) -> Result<MediaInfo> {
+    let media = probe_media(device)?;
+    if !media.drive_supports_bdr {
+        anyhow::bail!("Drive {} does not support BD-R, so it cannot write M-DISC media", device);
+    }
+    if !media.is_mdisc() {
+        anyhow::bail!(
+            "Medium in {} is not M-DISC (media ID: {}). Insert an M-DISC BD-R or turn off burn.mdisc",
+            device,
+            media.product_id.as_deref().unwrap_or("unknown")
+        );
+    }
+    info!("M-DISC medium detected: {}", media.product_id.as_deref().unwrap_or("unknown"));
+    Ok(media)
+}
+
+/// What a drive reports it can write.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriveCaps {
+    /// Vendor and model, e.g. "HL-DT-ST BD-RE WH16NS60"
+    pub model: Option<String>,
+    /// Media profiles the drive supports, e.g. "BD-R sequential recording"
+    pub media_types: Vec<String>,
+    /// Write speeds offered for the inserted medium, slowest first, e.g. "4.0xB".
+    /// Drives only report these with a medium loaded.
+    pub write_speeds: Vec<String>,
+}
+
+impl DriveCaps {
+    pub fn supports_bdr(&self) -> bool {
+        self.media_types.iter().any(|t| {
+            let t = t.to_uppercase();
+            t == "BD-R" || t.starts_with("BD-R ")
+        })
+    }
+
+    pub fn supports_bdre(&self) -> bool {
+        self.media_types.iter().any(|t| t.eq_ignore_ascii_case("BD-RE"))
+    }
+
+    /// M-DISC BD-R needs no drive feature beyond BD-R support (see [`check_mdisc_media`]).
+    pub fn supports_mdisc(&self) -> bool {
+        self.supports_bdr()
+    }
+
+    /// Fastest write speed offered for the inserted medium
+    pub fn max_write_speed(&self) -> Option<&str> {
+        self.write_speeds.last().map(String::as_str)
+    }
+}
+
+/// Ask the drive which media profiles it supports and which write speeds it
+/// offers for the inserted medium.
+pub fn drive_capabilities(device: &str) -> Result<DriveCaps> {
+    let profiles = commands::execute_command("xorriso", &["-outdev", device, "-list_profiles"], false)
+        .context("Failed to query drive profiles")?;
+    if !profiles.success {
+        anyhow::bail!("Cannot query {}: {}", device, profiles.stderr.trim());
+    }
+    // Without a medium the speed query fails; the profiles are still worth showing
+    let speeds = commands::execute_command("xorriso", &["-outdev", device, "-list_speeds"], false)
+        .map(|output| output.stderr)
+        .unwrap_or_default();
+    Ok(parse_drive_caps(&profiles.stderr, &speeds))
+}
+
+fn parse_drive_caps(profiles: &str, speeds: &str) -> DriveCaps {
+    let mut caps = DriveCaps::default();
+
+    // "Drive type   : vendor 'HL-DT-ST' product 'BD-RE  WH16NS60' revision '1.02'"
+    if let Some(line) = profiles.lines().find_map(|line| line.trim().strip_prefix("Drive type")) {
+        let quoted: Vec<&str> = line.split('\'').skip(1).step_by(2).take(2).collect();
+        let model = quoted.iter().flat_map(|part| part.split_whitespace()).collect::<Vec<_>>().join(" ");
+        if !model.is_empty() {
+            caps.model = Some(model);
+        }
+    }
+
+    // "Profile      : 0x0041 (BD-R sequential recording) (current)"
+    for line in profiles.lines() {
+        let Some(rest) = line.trim().strip_prefix("Profile") else {
+            continue;
+        };
+        let name = rest.split_once('(').and_then(|(_, name)| name.split_once(')')).map(|(name, _)| name.trim());
+        if let Some(name) = name.filter(|name| !name.is_empty()) {
+            if !caps.media_types.iter().any(|t| t == name) {
+                caps.media_types.push(name.to_string());
+            }
+        }
+    }
+
+    // "Write speed L  :   4495k ,   1.0xB"
+    let mut write_speeds: Vec<(f64, String)> = Vec::new();
+    for line in speeds.lines() {
+        if !line.trim().starts_with("Write speed") {
+            continue;
+        }
+        let Some(speed) = line.rsplit_once(',').map(|(_, speed)| speed.trim()) else {
+            continue;
+        };
+        let factor = speed.trim_end_matches(|c: char| c.is_ascii_alphabetic()).parse::<f64>();
+        if let Ok(factor) = factor {
+            if factor > 0.0 && !write_speeds.iter().any(|(_, s)| s == speed) {
+                write_speeds.push((factor, speed.to_string()));
+            }
+        }
+    }
+    write_speeds.sort_by(|a, b| a.0.total_cmp(&b.0));
+    caps.write_speeds = write_speeds.into_iter().map(|(_, speed)| speed).collect();
+
+    caps
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,7 +686,125 @@ mod tests {
     fn test_burn_iso_dry_run() -> Result<()> {
         let iso_path = Path::new("/tmp/test.iso");
         // Should not fail in dry run mode even if file doesn't exist
-        burn_iso(iso_path, "/dev/sr0", true)?;
+        burn_iso(iso_path, "/dev/sr0", true, false, None)?;
+        burn_iso(iso_path, "/dev/sr0", true, true, Some(Duration::from_secs(1)))?;
         Ok(())
     }
+
+    #[test]
+    fn test_check_device_missing() {
+        let err = check_device("/dev/does-not-exist-bdarchive").unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_find_mount_point() {
+        let mounts = "/dev/sda1 / ext4 rw 0 0\n/dev/sr0 /media/My\\040Disc udf ro 0 0\n";
+        assert_eq!(
+            find_mount_point(mounts, Path::new("/dev/sr0")),
+            Some("/media/My Disc".to_string())
+        );
+        assert_eq!(find_mount_point(mounts, Path::new("/dev/sr1")), None);
+    }
+
+    #[test]
+    fn test_parse_burn_progress() {
+        assert_eq!(
+            parse_burn_progress("Track 01:  250 of 1000 MB written (fifo 100%) [buf  99%]   4.0x."),
+            Some(BurnProgress { fraction: 0.25, speed_x: Some(4.0), fifo_percent: Some(100) })
+        );
+        let native = parse_burn_progress("xorriso : UPDATE : Writing:      41536s    8.7%   fifo  87%  buf  50%    2.0xB").unwrap();
+        assert!((native.fraction - 0.087).abs() < 1e-9);
+        assert_eq!(native.speed_x, Some(2.0));
+        assert_eq!(native.fifo_percent, Some(87));
+        assert!((native.speed_mbs().unwrap() - 8.99).abs() < 1e-9);
+        // Older xorriso builds omit the speed; the share written is still usable
+        let bare = parse_burn_progress("xorriso : UPDATE : Writing:      41536s    8.7%   fifo 100%  buf  50%").unwrap();
+        assert_eq!(bare.speed_x, None);
+        assert_eq!(parse_burn_progress("Track 01: Total bytes read/written: 1000/1000"), None);
+        assert_eq!(parse_burn_progress("Starting to write CD/DVD at speed 4.0"), None);
+    }
+
+    #[test]
+    fn test_burn_stats() {
+        let stats = BurnStats::new(50_000_000, Duration::from_secs(10));
+        assert_eq!(stats.duration_secs, 10.0);
+        assert_eq!(stats.avg_write_speed_mbps, 5.0);
+
+        let instant = BurnStats::new(1_000, Duration::ZERO);
+        assert_eq!(instant.avg_write_speed_mbps, 0.0);
+    }
+
+    #[test]
+    fn test_parse_media_info_mdisc() {
+        let profiles = "Profile      : 0x0041 (BD-R sequential recording)\nProfile      : 0x0043 (BD-RE)\n";
+        let toc = "Drive current: -outdev '/dev/sr0'\n\
+                   Media current: BD-R sequential recording\n\
+                   Media status : is blank\n\
+                   Media product: VERBAT/IMe/000 , Verbatim Corporation\n";
+        let media = parse_media_info(profiles, toc);
+        assert_eq!(media.media_type.as_deref(), Some("BD-R sequential recording"));
+        assert_eq!(media.product_id.as_deref(), Some("VERBAT/IMe/000"));
+        assert_eq!(media.manufacturer.as_deref(), Some("Verbatim Corporation"));
+        assert!(media.drive_supports_bdr);
+        assert!(media.is_mdisc());
+
+        let toc = "Media product: VERBAT/IMf/000 , Verbatim Corporation\n";
+        assert!(parse_media_info("", toc).is_mdisc());
+    }
+
+    #[test]
+    fn test_parse_media_info_regular_bdr() {
+        let toc = "Media current: BD-R sequential recording\n\
+                   Media product: CMCMAG/BA5/000 , CMC Magnetics Corporation\n";
+        let media = parse_media_info("Profile      : 0x0010 (DVD-ROM)\n", toc);
+        assert!(!media.is_mdisc());
+        assert!(!media.drive_supports_bdr);
+
+        let media = parse_media_info("", "libburn : SORRY : No readable medium found\n");
+        assert_eq!(media, MediaInfo::default());
+        assert!(!media.is_mdisc());
+    }
+
+    #[test]
+    fn test_parse_drive_caps() {
+        let profiles = "Drive current: -outdev '/dev/sr0'\n\
+                        Drive type   : vendor 'HL-DT-ST' product 'BD-RE  WH16NS60' revision '1.02'\n\
+                        Profile      : 0x0043 (BD-RE)\n\
+                        Profile      : 0x0041 (BD-R sequential recording) (current)\n\
+                        Profile      : 0x0042 (BD-R random recording)\n\
+                        Profile      : 0x0040 (BD-ROM)\n\
+                        Profile      : 0x0041 (BD-R sequential recording)\n";
+        let speeds = "Write speed L  :   4495k ,   1.0xB\n\
+                      Write speed H  :  53940k ,  12.0xB\n\
+                      Write speed    :  26970k ,   6.0xB\n\
+                      Write speed    :  53940k ,  12.0xB\n\
+                      Read speed     :  53940k ,  12.0xB\n";
+        let caps = parse_drive_caps(profiles, speeds);
+        assert_eq!(caps.model.as_deref(), Some("HL-DT-ST BD-RE WH16NS60"));
+        assert_eq!(
+            caps.media_types,
+            vec!["BD-RE", "BD-R sequential recording", "BD-R random recording", "BD-ROM"]
+        );
+        assert_eq!(caps.write_speeds, vec!["1.0xB", "6.0xB", "12.0xB"]);
+        assert_eq!(caps.max_write_speed(), Some("12.0xB"));
+        assert!(caps.supports_bdr());
+        assert!(caps.supports_bdre());
+        assert!(caps.supports_mdisc());
+
+        // A DVD writer without a medium: no Blu-ray, no speeds
+        let caps = parse_drive_caps("Profile      : 0x0011 (DVD-R sequential recording)\n", "");
+        assert!(!caps.supports_bdr());
+        assert!(!caps.supports_mdisc());
+        assert_eq!(caps.max_write_speed(), None);
+    }
+
+    #[test]
+    fn test_millenniata_media_is_mdisc() {
+        let media = MediaInfo {
+            product_id: Some("MILLEN/MR1/000".to_string()),
+            ..Default::default()
+        };
+        assert!(media.is_mdisc());
+    }
 }
@@ -1,7 +1,11 @@
 use anyhow::{Context, Result};
 use std::ffi::OsStr;
+use std::io::{BufReader, Read};
 use std::path::Path;
 use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 use tracing::{debug, warn};
 
 /// Execute a command safely without shell injection.
@@ -165,6 +169,158 @@ pub fn execute_command_with_stdin<S: AsRef<OsStr>>(
     })
 }
 
+/// A watched command printed nothing for longer than its idle timeout and was killed.
+#[derive(Debug, Clone)]
+pub struct CommandStalled {
+    pub program: String,
+    pub idle: Duration,
+}
+
+impl std::fmt::Display for CommandStalled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} printed nothing for {}s and was stopped", self.program, self.idle.as_secs())
+    }
+}
+
+impl std::error::Error for CommandStalled {}
+
+/// Execute a command, passing each line it prints (stdout or stderr) to
+/// `on_line` as it arrives. A carriage return also ends a line, so progress
+/// meters that redraw in place are seen one update at a time.
+pub fn execute_command_streaming<S: AsRef<OsStr>>(
+    program: S,
+    args: &[S],
+    dry_run: bool,
+    on_line: &mut dyn FnMut(&str),
+) -> Result<CommandOutput> {
+    execute_command_watched(program, args, dry_run, None, on_line)
+}
+
+/// Like [`execute_command_streaming`], but with an `idle_timeout` the command
+/// is killed once it goes that long without printing a line, and the call
+/// fails with [`CommandStalled`].
+pub fn execute_command_watched<S: AsRef<OsStr>>(
+    program: S,
+    args: &[S],
+    dry_run: bool,
+    idle_timeout: Option<Duration>,
+    on_line: &mut dyn FnMut(&str),
+) -> Result<CommandOutput> {
+    let program_str = program.as_ref().to_string_lossy().to_string();
+    let args_str: Vec<String> = args
+        .iter()
+        .map(|a| a.as_ref().to_string_lossy().to_string())
+        .collect();
+
+    debug!("Executing command (streaming): {} {}", program_str, args_str.join(" "));
+
+    if dry_run {
+        debug!(
+            "[DRY RUN] Would execute: {} {}",
+            program_str,
+            args_str.join(" ")
+        );
+        return Ok(CommandOutput {
+            success: true,
+            stdout: String::new(),
+            stderr: String::new(),
+            exit_code: Some(0),
+        });
+    }
+
+    let mut child = Command::new(&program)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to spawn command: {}", program_str))?;
+
+    let (tx, rx) = mpsc::channel();
+    let readers = [
+        child.stdout.take().map(|pipe| spawn_line_reader(pipe, false, tx.clone())),
+        child.stderr.take().map(|pipe| spawn_line_reader(pipe, true, tx)),
+    ];
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    loop {
+        let received = match idle_timeout {
+            Some(timeout) => rx.recv_timeout(timeout),
+            None => rx.recv().map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+        };
+        let (is_stderr, line) = match received {
+            Ok(received) => received,
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                let idle = idle_timeout.unwrap_or_default();
+                warn!("{} printed nothing for {}s, killing it", program_str, idle.as_secs());
+                if let Err(e) = child.kill() {
+                    warn!("Failed to kill {}: {}", program_str, e);
+                }
+                // A process stuck on a hung device may not exit straight away;
+                // reap it in the background instead of blocking the caller.
+                thread::spawn(move || child.wait());
+                return Err(CommandStalled { program: program_str, idle }.into());
+            }
+        };
+        on_line(&line);
+        let buffer = if is_stderr { &mut stderr } else { &mut stdout };
+        buffer.push_str(&line);
+        buffer.push('\n');
+    }
+    for reader in readers.into_iter().flatten() {
+        let _ = reader.join();
+    }
+
+    let status = child
+        .wait()
+        .with_context(|| format!("Failed to wait for command: {}", program_str))?;
+    let success = status.success();
+    let exit_code = status.code();
+
+    if !success {
+        warn!(
+            "Command failed: {} {} (exit code: {:?})",
+            program_str,
+            args_str.join(" "),
+            exit_code
+        );
+        warn!("stderr: {}", stderr);
+    }
+
+    Ok(CommandOutput {
+        success,
+        stdout,
+        stderr,
+        exit_code,
+    })
+}
+
+/// Forward the lines of `pipe` to `tx`, split on `\n` or `\r`.
+fn spawn_line_reader(
+    pipe: impl Read + Send + 'static,
+    is_stderr: bool,
+    tx: mpsc::Sender<(bool, String)>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut line = Vec::new();
+        for byte in BufReader::new(pipe).bytes() {
+            let Ok(byte) = byte else { break };
+            if byte == b'\n' || byte == b'\r' {
+                if !line.is_empty() {
+                    let _ = tx.send((is_stderr, String::from_utf8_lossy(&line).into_owned()));
+                    line.clear();
+                }
+            } else {
+                line.push(byte);
+            }
+        }
+        if !line.is_empty() {
+            let _ = tx.send((is_stderr, String::from_utf8_lossy(&line).into_owned()));
+        }
+    })
+}
+
 /// Validate that a path is safe to use in commands (no shell injection).
 /// This checks for basic path traversal and shell metacharacters.
 pub fn validate_safe_path(path: &Path) -> Result<()> {
@@ -231,4 +387,31 @@ mod tests {
         assert!(!output.success);
         assert_eq!(output.exit_code, Some(1));
     }
+
+    #[test]
+    fn test_execute_command_streaming_splits_carriage_returns() {
+        let mut lines = Vec::new();
+        let output = execute_command_streaming("printf", &["10%%\r20%%\rdone\n"], false, &mut |line| {
+            lines.push(line.to_string())
+        })
+        .unwrap();
+        assert!(output.success);
+        assert_eq!(lines, vec!["10%", "20%", "done"]);
+    }
+
+    #[test]
+    fn test_execute_command_watched_kills_silent_command() {
+        let mut lines = Vec::new();
+        let started = std::time::Instant::now();
+        let err = execute_command_watched("sh", &["-c", "echo started; sleep 30"], false, Some(Duration::from_millis(300)), &mut |line| {
+            lines.push(line.to_string())
+        })
+        .unwrap_err();
+        assert!(err.downcast_ref::<CommandStalled>().is_some());
+        assert_eq!(lines, vec!["started"]);
+        assert!(started.elapsed() < Duration::from_secs(10));
+
+        let output = execute_command_watched("echo", &["done"], false, Some(Duration::from_secs(5)), &mut |_| {}).unwrap();
+        assert!(output.success);
+    }
 }
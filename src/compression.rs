@@ -0,0 +1,302 @@
+//! Optional on-disc compression.
+//!
+//! With `staging.compression = "zstd"` each source folder is stored as
+//! `ARCHIVE/<folder>.tar.zst` instead of a plain copy. Next to each tarball,
+//! `<folder>.tar.zst.sha256` lists the SHA-256 of every original file in
+//! `sha256sum` format, so the originals can be checked after extraction
+//! (`tar --zstd -xf` then `sha256sum -c`) or by [`verify_archive`].
+
+use crate::config::{Compression, StagingConfig};
+use crate::iso::{estimate_iso_overhead, IsoSizeEstimate};
+use crate::staging::{self, SourceFile, WalkOptions};
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::{info, warn};
+
+/// Extension of a compressed source folder.
+pub const ARCHIVE_EXTENSION: &str = ".tar.zst";
+/// Appended to the tarball name for its list of original file hashes.
+pub const SIDECAR_EXTENSION: &str = ".sha256";
+
+/// zstd's default level: good ratios on text at close to disk speed.
+const ZSTD_LEVEL: i32 = 3;
+/// Files compressed to estimate the ratio of a whole selection.
+const SAMPLE_FILES: usize = 32;
+/// Bytes read from the start of each sampled file.
+const SAMPLE_BYTES: u64 = 1024 * 1024;
+/// Tar header per entry, counted in size estimates.
+const TAR_HEADER_BYTES: u64 = 512;
+
+/// Sidecar path for a compressed folder.
+pub fn sidecar_path(archive: &Path) -> PathBuf {
+    let mut name = archive.as_os_str().to_owned();
+    name.push(SIDECAR_EXTENSION);
+    PathBuf::from(name)
+}
+
+/// Compress `files` into one `<folder>.tar.zst` per source folder in
+/// `archive_dir`, grouping by the first component of each archive path.
+/// Original bytes read are added to `bytes_staged`. Returns the tarballs written.
+pub fn compress_files(
+    archive_dir: &Path,
+    files: &[SourceFile],
+    options: &StagingConfig,
+    bytes_staged: Option<&AtomicU64>,
+) -> Result<Vec<PathBuf>> {
+    let mut folders: BTreeMap<String, Vec<&SourceFile>> = BTreeMap::new();
+    for file in files {
+        let folder = file
+            .archive_path
+            .components()
+            .next()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .unwrap_or_default();
+        folders.entry(folder).or_default().push(file);
+    }
+
+    fs::create_dir_all(archive_dir)
+        .with_context(|| format!("Failed to create directory: {}", archive_dir.display()))?;
+    let mut archives = Vec::new();
+    for (folder, files) in folders {
+        let archive = archive_dir.join(format!("{}{}", folder, ARCHIVE_EXTENSION));
+        compress_folder(&archive, &files, options, bytes_staged)?;
+        archives.push(archive);
+    }
+    Ok(archives)
+}
+
+fn compress_folder(archive: &Path, files: &[&SourceFile], options: &StagingConfig, bytes_staged: Option<&AtomicU64>) -> Result<()> {
+    let output = File::create(archive).with_context(|| format!("Failed to create {}", archive.display()))?;
+    let encoder = zstd::Encoder::new(BufWriter::new(output), ZSTD_LEVEL).context("Failed to start zstd")?;
+    let mut builder = tar::Builder::new(encoder);
+    let mut sidecar = String::new();
+
+    for file in files {
+        match &file.link_target {
+            Some(target) => {
+                let mut header = tar::Header::new_gnu();
+                header.set_metadata(&fs::symlink_metadata(&file.source)?);
+                header.set_entry_type(tar::EntryType::Symlink);
+                header.set_size(0);
+                builder
+                    .append_link(&mut header, &file.archive_path, target)
+                    .with_context(|| format!("Failed to add link {}", file.source.display()))?;
+            }
+            None => {
+                let source = File::open(&file.source)
+                    .with_context(|| format!("Failed to open {}", file.source.display()))?;
+                let mut header = tar::Header::new_gnu();
+                header.set_metadata(&source.metadata()?);
+                if !options.preserve_permissions {
+                    header.set_mode(0o644);
+                }
+                let mut reader = HashingReader::new(source);
+                builder
+                    .append_data(&mut header, &file.archive_path, &mut reader)
+                    .with_context(|| format!("Failed to compress {}", file.source.display()))?;
+                if let Some(counter) = bytes_staged {
+                    counter.fetch_add(reader.bytes, Ordering::Relaxed);
+                }
+                sidecar.push_str(&format!("{}  {}\n", reader.finish(), file.archive_path.display()));
+            }
+        }
+    }
+
+    builder
+        .into_inner()
+        .and_then(|encoder| encoder.finish())
+        .with_context(|| format!("Failed to finish {}", archive.display()))?;
+    fs::write(sidecar_path(archive), sidecar)
+        .with_context(|| format!("Failed to write hashes for {}", archive.display()))?;
+    info!("Compressed {} files into {}", files.len(), archive.display());
+    Ok(())
+}
+
+/// Passes reads through while hashing them.
+struct HashingReader<R> {
+    inner: R,
+    hasher: Sha256,
+    bytes: u64,
+}
+
+impl<R: Read> HashingReader<R> {
+    fn new(inner: R) -> Self {
+        Self { inner, hasher: Sha256::new(), bytes: 0 }
+    }
+
+    fn finish(self) -> String {
+        hex::encode(self.hasher.finalize())
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        self.bytes += n as u64;
+        Ok(n)
+    }
+}
+
+/// Estimate the compressed size of `files`. Up to `SAMPLE_FILES` files spread
+/// across the list are compressed (first megabyte of each) and the ratio they
+/// reach is applied to the total.
+pub fn estimate_compressed_size(files: &[SourceFile]) -> Result<u64> {
+    let regular: Vec<&SourceFile> = files.iter().filter(|f| f.link_target.is_none() && f.size_bytes > 0).collect();
+    let total: u64 = regular.iter().map(|f| f.size_bytes).sum();
+    let overhead = files.len() as u64 * TAR_HEADER_BYTES;
+
+    let step = (regular.len() / SAMPLE_FILES).max(1);
+    let (mut sampled, mut compressed) = (0u64, 0u64);
+    for file in regular.iter().step_by(step).take(SAMPLE_FILES) {
+        let mut buffer = Vec::new();
+        match File::open(&file.source).and_then(|f| f.take(SAMPLE_BYTES).read_to_end(&mut buffer)) {
+            Ok(_) => {
+                sampled += buffer.len() as u64;
+                compressed += zstd::bulk::compress(&buffer, ZSTD_LEVEL)?.len() as u64;
+            }
+            Err(e) => warn!("Could not sample {} for the compression estimate: {}", file.source.display(), e),
+        }
+    }
+
+    let ratio = if sampled > 0 { compressed as f64 / sampled as f64 } else { 1.0 };
+    Ok((total as f64 * ratio) as u64 + overhead)
+}
+
+/// Size the selection will take on the disc and whether its ISO exceeds
+/// `capacity_bytes`, using the compressed estimate when compression is on.
+pub fn check_capacity(
+    source_folders: &[PathBuf],
+    capacity_bytes: u64,
+    options: &StagingConfig,
+) -> Result<(IsoSizeEstimate, bool)> {
+    match options.compression {
+        Compression::None => staging::check_capacity(source_folders, capacity_bytes, WalkOptions::from(options)),
+        Compression::Zstd => {
+            let files = staging::collect_source_files(source_folders, WalkOptions::from(options))?;
+            let size = estimate_compressed_size(&files)?;
+            // One archive and one checksum sidecar per source folder
+            let estimate = IsoSizeEstimate {
+                content_bytes: size,
+                iso_bytes: size + estimate_iso_overhead(2 * source_folders.len() as u64, 1),
+            };
+            Ok((estimate, estimate.iso_bytes > capacity_bytes))
+        }
+    }
+}
+
+/// Compressed folders directly under `archive_dir`, sorted by name.
+pub fn find_archives(archive_dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut archives = Vec::new();
+    if !archive_dir.is_dir() {
+        return Ok(archives);
+    }
+    for entry in fs::read_dir(archive_dir).with_context(|| format!("Failed to read {}", archive_dir.display()))? {
+        let path = entry?.path();
+        if path.is_file() && path.to_string_lossy().ends_with(ARCHIVE_EXTENSION) {
+            archives.push(path);
+        }
+    }
+    archives.sort();
+    Ok(archives)
+}
+
+/// Result of checking one compressed folder against its sidecar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchiveCheck {
+    pub archive: PathBuf,
+    pub files_checked: u32,
+    /// One line per original that failed, e.g. "Photos/a.jpg: checksum mismatch"
+    pub failures: Vec<String>,
+}
+
+/// Decompress `archive` and check every file in it against the SHA-256
+/// recorded in its sidecar. Files missing from either side are failures.
+pub fn verify_archive(archive: &Path) -> Result<ArchiveCheck> {
+    let sidecar = sidecar_path(archive);
+    let listing = fs::read_to_string(&sidecar).with_context(|| format!("Failed to read {}", sidecar.display()))?;
+    let mut expected: HashMap<String, String> = listing
+        .lines()
+        .filter_map(|line| line.split_once("  "))
+        .map(|(hash, path)| (path.to_string(), hash.to_string()))
+        .collect();
+
+    let decoder = zstd::Decoder::new(File::open(archive).with_context(|| format!("Failed to open {}", archive.display()))?)?;
+    let mut tarball = tar::Archive::new(decoder);
+    let mut check = ArchiveCheck {
+        archive: archive.to_path_buf(),
+        files_checked: 0,
+        failures: Vec::new(),
+    };
+
+    for entry in tarball.entries().with_context(|| format!("Failed to read {}", archive.display()))? {
+        let entry = entry.with_context(|| format!("Corrupt entry in {}", archive.display()))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.to_string_lossy().into_owned();
+        let mut reader = HashingReader::new(entry);
+        io::copy(&mut reader, &mut io::sink()).with_context(|| format!("Failed to decompress {}", path))?;
+        let actual = reader.finish();
+        check.files_checked += 1;
+        match expected.remove(&path) {
+            Some(hash) if hash == actual => {}
+            Some(_) => check.failures.push(format!("{}: checksum mismatch", path)),
+            None => check.failures.push(format!("{}: not listed in {}", path, sidecar.display())),
+        }
+    }
+
+    let mut missing: Vec<String> = expected.into_keys().collect();
+    missing.sort();
+    check
+        .failures
+        .extend(missing.into_iter().map(|path| format!("{}: missing from archive", path)));
+    Ok(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SymlinkPolicy;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_compress_and_verify() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("Logs");
+        fs::create_dir_all(source.join("2024"))?;
+        fs::write(source.join("2024/app.log"), "GET /index.html 200\n".repeat(5000))?;
+        fs::write(source.join("notes.txt"), "rotated weekly")?;
+
+        let files = staging::collect_source_files(std::slice::from_ref(&source), SymlinkPolicy::Follow.into())?;
+        let raw: u64 = files.iter().map(|f| f.size_bytes).sum();
+        let estimate = estimate_compressed_size(&files)?;
+        assert!(estimate < raw / 10, "estimate {} for {} raw bytes", estimate, raw);
+
+        let archive_dir = temp_dir.path().join("disc/ARCHIVE");
+        let bytes_staged = AtomicU64::new(0);
+        let archives = compress_files(&archive_dir, &files, &StagingConfig::default(), Some(&bytes_staged))?;
+        assert_eq!(archives, vec![archive_dir.join("Logs.tar.zst")]);
+        assert_eq!(bytes_staged.load(Ordering::Relaxed), raw);
+        assert_eq!(find_archives(&archive_dir)?, archives);
+        assert!(fs::read_to_string(sidecar_path(&archives[0]))?.contains("  Logs/notes.txt"));
+
+        let check = verify_archive(&archives[0])?;
+        assert_eq!(check.files_checked, 2);
+        assert!(check.failures.is_empty(), "{:?}", check.failures);
+
+        // A sidecar entry that no longer matches is reported
+        let sidecar = sidecar_path(&archives[0]);
+        let tampered = fs::read_to_string(&sidecar)?.replacen(char::is_alphanumeric, "0", 1);
+        fs::write(&sidecar, tampered)?;
+        let check = verify_archive(&archives[0])?;
+        assert_eq!(check.failures.len(), 1);
+
+        Ok(())
+    }
+}
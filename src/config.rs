@@ -11,16 +11,31 @@ pub struct Config {
     #[serde(default = "default_device")]
     pub device: String,
 
+    /// Burners used for multi-disc sets. With two or more, the discs of a set
+    /// are burned in parallel; when empty, `device` is used on its own.
+    #[serde(default)]
+    pub devices: Vec<String>,
+
     /// Staging directory for building ISO
     pub staging_dir: Option<String>,
 
     /// Database path (defaults to data_dir/archive.db)
     pub database_path: Option<String>,
 
+    /// Free space in MB the volumes holding the database and QR codes must
+    /// keep before a burn starts (0 = no check)
+    #[serde(default = "default_min_data_free_mb")]
+    pub min_data_free_mb: u64,
+
     /// Default disc capacity in GB (25, 50, or 100)
     #[serde(default = "default_capacity_gb")]
     pub default_capacity_gb: u64,
 
+    /// Template for the disc ID suggested on the New Disc screen.
+    /// Placeholders: {year}, {month}, {seq} (next free number) and {set}
+    #[serde(default = "default_disc_id_template")]
+    pub disc_id_template: String,
+
     /// Verification settings
     #[serde(default)]
     pub verification: VerificationConfig,
@@ -32,6 +47,34 @@ pub struct Config {
     /// Optional tools configuration
     #[serde(default)]
     pub optional_tools: OptionalToolsConfig,
+
+    /// Staging configuration
+    #[serde(default)]
+    pub staging: StagingConfig,
+
+    /// Post-burn cleanup policy
+    #[serde(default)]
+    pub cleanup: CleanupConfig,
+
+    /// Keep burned ISOs for re-burning
+    #[serde(default)]
+    pub iso_library: IsoLibraryConfig,
+
+    /// ISO 9660 volume descriptor fields
+    #[serde(default)]
+    pub iso: IsoConfig,
+
+    /// Rolling catalog backups
+    #[serde(default)]
+    pub backup: BackupConfig,
+
+    /// What the QR code on each disc's label encodes
+    #[serde(default)]
+    pub qr: QrConfig,
+
+    /// Log file rotation and retention
+    #[serde(default)]
+    pub logging: LoggingConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,6 +86,23 @@ pub struct VerificationConfig {
     /// Automatically mount disc when verifying
     #[serde(default)]
     pub auto_mount: bool,
+
+    /// Discs not verified within this many days appear in the scrub report
+    #[serde(default = "default_scrub_after_days")]
+    pub scrub_after_days: u64,
+
+    /// Decompress `.tar.zst` archives on the disc and check every original
+    /// file against its recorded SHA-256
+    #[serde(default = "default_true")]
+    pub decompress_archives: bool,
+
+    /// Files hashed by a sample check: a count (`50`) or a percentage (`5%`)
+    #[serde(default = "default_sample_size")]
+    pub sample_size: String,
+
+    /// Leave a disc that verification mounted mounted afterwards, to browse it
+    #[serde(default)]
+    pub keep_mounted_after_verify: bool,
 }
 
 impl Default for VerificationConfig {
@@ -50,6 +110,10 @@ impl Default for VerificationConfig {
         Self {
             auto_verify_after_burn: false,
             auto_mount: false,
+            scrub_after_days: default_scrub_after_days(),
+            decompress_archives: true,
+            sample_size: default_sample_size(),
+            keep_mounted_after_verify: false,
         }
     }
 }
@@ -59,12 +123,426 @@ pub struct BurnConfig {
     /// Burn method: "iso" (create ISO first) or "direct" (burn directory directly)
     #[serde(default = "default_burn_method")]
     pub method: String,
+
+    /// Also write a JSON copy of the dry-run plan report
+    #[serde(default)]
+    pub dry_run_json_report: bool,
+
+    /// Record dry-run discs in the catalog as planned, so search, lists and
+    /// verify-from-ISO can be tried without media
+    #[serde(default = "default_true")]
+    pub index_dry_runs: bool,
+
+    /// Require M-DISC media, burn it at its rated speed and mark discs as M-DISC
+    #[serde(default)]
+    pub mdisc: bool,
+
+    /// Kill a burn whose xorriso output has been silent this long, in seconds (0 = never)
+    #[serde(default = "default_stall_timeout_secs")]
+    pub stall_timeout_secs: u64,
+
+    /// Open the tray after every disc written, so a set's next blank can go in
+    #[serde(default)]
+    pub eject_after_disc: bool,
 }
 
 impl Default for BurnConfig {
     fn default() -> Self {
         Self {
             method: default_burn_method(),
+            dry_run_json_report: false,
+            index_dry_runs: true,
+            mdisc: false,
+            stall_timeout_secs: default_stall_timeout_secs(),
+            eject_after_disc: false,
+        }
+    }
+}
+
+impl BurnConfig {
+    /// The burn watchdog's idle timeout, or `None` when it is disabled.
+    pub fn stall_timeout(&self) -> Option<std::time::Duration> {
+        (self.stall_timeout_secs > 0).then(|| std::time::Duration::from_secs(self.stall_timeout_secs))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StagingConfig {
+    /// Copy Unix permission bits from the source files. When disabled, staged
+    /// files get 0644 and directories 0755. Modification times are always kept.
+    #[serde(default = "default_true")]
+    pub preserve_permissions: bool,
+
+    /// How symbolic links in source folders are handled when planning and staging
+    #[serde(default)]
+    pub symlink_policy: SymlinkPolicy,
+
+    /// Archive hidden files and directories (names starting with `.`). On by
+    /// default so nothing is silently left behind
+    #[serde(default = "default_true")]
+    pub include_hidden: bool,
+
+    /// Retries for a file copy or read that fails with a transient I/O error
+    /// (flaky USB drive, network mount hiccup) before the run is aborted
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Cap on how fast source files are read while staging, in megabits per
+    /// second, so staging from a NAS doesn't saturate the network. Unset means
+    /// full speed
+    #[serde(default)]
+    pub max_read_mbps: Option<f64>,
+
+    /// Store byte-identical files once per disc and record the copies in the
+    /// manifest's dedup map
+    #[serde(default)]
+    pub dedup: bool,
+
+    /// Default checksum for the disc manifest; can be changed per burn in the
+    /// Review step
+    #[serde(default)]
+    pub checksum: ChecksumAlgorithm,
+
+    /// Store each source folder as `<folder>.tar.zst` instead of a plain copy
+    /// (single-disc burns only)
+    #[serde(default)]
+    pub compression: Compression,
+
+    /// Package single discs as BagIt bags for institutional archives
+    #[serde(default)]
+    pub layout: DiscLayout,
+
+    /// Hash files while they are copied into staging and compare with a fresh
+    /// read of the staged copies before the ISO is built
+    #[serde(default)]
+    pub verify_after_copy: bool,
+
+    /// Keep files already staged by an interrupted run when their size and
+    /// modification time match the source, copying only missing or changed ones
+    #[serde(default)]
+    pub resume: bool,
+
+    /// Skip source files that can't be read (permission denied, I/O error)
+    /// instead of aborting, listing them in FAILED_FILES.txt on the disc and
+    /// in the catalog. Not applied to compressed discs
+    #[serde(default)]
+    pub skip_unreadable: bool,
+
+    /// Files copied at the same time while staging. Values above 1 help on
+    /// fast storage (NVMe, RAID) and hurt on a single spinning disk; ignored
+    /// when rsync is used or `max_read_mbps` is set
+    #[serde(default = "default_copy_jobs")]
+    pub copy_jobs: usize,
+}
+
+impl Default for StagingConfig {
+    fn default() -> Self {
+        Self {
+            preserve_permissions: true,
+            symlink_policy: SymlinkPolicy::default(),
+            include_hidden: true,
+            max_retries: default_max_retries(),
+            max_read_mbps: None,
+            dedup: false,
+            checksum: ChecksumAlgorithm::default(),
+            compression: Compression::default(),
+            layout: DiscLayout::default(),
+            verify_after_copy: false,
+            resume: false,
+            skip_unreadable: false,
+            copy_jobs: default_copy_jobs(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanupConfig {
+    /// Remove staged content after a successful burn (opt-in)
+    #[serde(default)]
+    pub after_burn: bool,
+
+    /// Keep `<disc_id>.iso` images in the staging directory for re-burning
+    #[serde(default)]
+    pub keep_iso: bool,
+
+    /// Leave dry-run output in the staging directory for inspection
+    #[serde(default = "default_true")]
+    pub keep_on_dry_run: bool,
+}
+
+impl Default for CleanupConfig {
+    fn default() -> Self {
+        Self {
+            after_burn: false,
+            keep_iso: false,
+            keep_on_dry_run: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IsoLibraryConfig {
+    /// Move the ISO of every successful burn into the library (forces ISO
+    /// creation even with the direct burn method)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Library directory (defaults to data_dir/isos)
+    #[serde(default)]
+    pub dir: Option<String>,
+
+    /// Size cap in GB; the least recently used ISOs are removed beyond it
+    #[serde(default = "default_iso_library_max_gb")]
+    pub max_size_gb: u64,
+}
+
+impl Default for IsoLibraryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: None,
+            max_size_gb: default_iso_library_max_gb(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupConfig {
+    /// Back up the database to `backups/` next to it after every disc is indexed
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Number of backups kept; older ones are deleted
+    #[serde(default = "default_backup_keep")]
+    pub keep: usize,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            keep: default_backup_keep(),
+        }
+    }
+}
+
+/// QR error-correction level: higher levels survive more damage to the
+/// label but hold less data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum QrErrorCorrection {
+    /// About 7% of the code can be restored
+    #[default]
+    L,
+    /// About 15%
+    M,
+    /// About 25%
+    Q,
+    /// About 30%
+    H,
+}
+
+impl QrErrorCorrection {
+    /// qrencode's `-l` value
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::L => "L",
+            Self::M => "M",
+            Self::Q => "Q",
+            Self::H => "H",
+        }
+    }
+
+    /// Most bytes the largest QR code (version 40) holds at this level
+    pub fn capacity_bytes(self) -> usize {
+        match self {
+            Self::L => 2953,
+            Self::M => 2331,
+            Self::Q => 1663,
+            Self::H => 1273,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QrConfig {
+    /// Payload template with `{id}`, `{set}`, `{seq}`, `{created}` and
+    /// `{hash}` placeholders; a URI, JSON or plain text
+    #[serde(default = "default_qr_payload_template")]
+    pub payload_template: String,
+
+    #[serde(default)]
+    pub error_correction: QrErrorCorrection,
+}
+
+impl Default for QrConfig {
+    fn default() -> Self {
+        Self {
+            payload_template: default_qr_payload_template(),
+            error_correction: QrErrorCorrection::default(),
+        }
+    }
+}
+
+/// How often a new log file is started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogRotation {
+    Hourly,
+    #[default]
+    Daily,
+    /// Always write to the same file
+    Never,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggingConfig {
+    /// When to start a new log file
+    #[serde(default)]
+    pub rotation: LogRotation,
+
+    /// Number of log files kept; older ones are deleted
+    #[serde(default = "default_log_keep_files")]
+    pub keep_files: usize,
+
+    /// Upper bound for the whole logs directory in MB (0 = no limit).
+    /// The oldest files are deleted first; the current file is never removed.
+    #[serde(default = "default_log_max_total_mb")]
+    pub max_total_mb: u64,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            rotation: LogRotation::default(),
+            keep_files: default_log_keep_files(),
+            max_total_mb: default_log_max_total_mb(),
+        }
+    }
+}
+
+/// Volume descriptor fields written into every ISO image. Unset fields are
+/// left empty, except the application ID, which defaults to "BlueVault <version>".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IsoConfig {
+    #[serde(default)]
+    pub publisher: Option<String>,
+
+    #[serde(default)]
+    pub preparer: Option<String>,
+
+    #[serde(default)]
+    pub application_id: Option<String>,
+
+    #[serde(default)]
+    pub system_id: Option<String>,
+
+    /// ISO 9660 interchange level (1-3). Only level 3 holds files of 4 GiB
+    /// or more; a lower level is raised to 3 when the disc has such files
+    #[serde(default = "default_iso_level")]
+    pub level: u8,
+
+    /// Longest file or directory name allowed on the disc. Defaults to what
+    /// the ISO level keeps intact: 64 (Joliet) at level 3, 31 at level 2 and
+    /// 12 (8.3 names) at level 1
+    #[serde(default)]
+    pub max_name_length: Option<usize>,
+
+    /// Longest path allowed on the disc, counted from the disc root.
+    /// Defaults to Joliet's 240 characters
+    #[serde(default)]
+    pub max_path_length: Option<usize>,
+}
+
+impl Default for IsoConfig {
+    fn default() -> Self {
+        Self {
+            publisher: None,
+            preparer: None,
+            application_id: None,
+            system_id: None,
+            level: default_iso_level(),
+            max_name_length: None,
+            max_path_length: None,
+        }
+    }
+}
+
+/// Symbolic link handling during staging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SymlinkPolicy {
+    /// Leave symlinks out of the archive
+    Skip,
+    /// Copy the link target (directory loops are detected and skipped)
+    #[default]
+    Follow,
+    /// Recreate the link itself; it is recorded as a link in the manifest
+    Preserve,
+}
+
+/// Checksum computed for every file in the disc manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChecksumAlgorithm {
+    /// Fast; catches accidental corruption but not deliberate changes
+    Crc32,
+    /// Slower; a changed file can't be made to match its recorded hash
+    #[default]
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "CRC32",
+            ChecksumAlgorithm::Sha256 => "SHA-256",
+        }
+    }
+
+    /// Name with what the checksum does and doesn't protect against.
+    pub fn description(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "CRC32 (fast; integrity check only, not tamper-evident)",
+            ChecksumAlgorithm::Sha256 => "SHA-256 (full; tamper-evident)",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            ChecksumAlgorithm::Crc32 => ChecksumAlgorithm::Sha256,
+            ChecksumAlgorithm::Sha256 => ChecksumAlgorithm::Crc32,
+        }
+    }
+}
+
+/// How source folders are stored on the disc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    /// Plain file tree under ARCHIVE/, readable without any tools
+    #[default]
+    None,
+    /// One zstd-compressed tarball per source folder
+    Zstd,
+}
+
+/// How the staged disc is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiscLayout {
+    /// ARCHIVE/ and the BlueVault manifests at the disc root
+    #[default]
+    Standard,
+    /// A BagIt bag: the standard layout moved under data/, with bagit.txt,
+    /// bag-info.txt and manifest-sha256.txt at the root
+    Bagit,
+}
+
+impl Compression {
+    pub fn name(self) -> &'static str {
+        match self {
+            Compression::None => "none",
+            Compression::Zstd => "zstd",
         }
     }
 }
@@ -82,6 +560,10 @@ pub struct OptionalToolsConfig {
     /// Use Midnight Commander for folder selection
     #[serde(default = "default_true")]
     pub use_mc: bool,
+
+    /// QR code image format: "png" or "svg"
+    #[serde(default = "default_qr_format")]
+    pub qr_format: String,
 }
 
 impl Default for OptionalToolsConfig {
@@ -90,6 +572,7 @@ impl Default for OptionalToolsConfig {
             use_qrencode: true,
             use_rsync: true,
             use_mc: true,
+            qr_format: default_qr_format(),
         }
     }
 }
@@ -99,14 +582,74 @@ fn default_device() -> String {
     crate::paths::detect_optical_drive().unwrap_or_else(|| "/dev/sr0".to_string())
 }
 
+/// Bytes in one GB of disc capacity. Media are specified in decimal units:
+/// a "25 GB" BD-R holds 25,025,314,816 bytes, less than 25 GiB.
+pub const BYTES_PER_GB: u64 = 1_000_000_000;
+
 fn default_capacity_gb() -> u64 {
     25
 }
 
+fn default_disc_id_template() -> String {
+    crate::disc::DEFAULT_DISC_ID_TEMPLATE.to_string()
+}
+
+fn default_scrub_after_days() -> u64 {
+    365
+}
+
+fn default_sample_size() -> String {
+    "5%".to_string()
+}
+
+fn default_copy_jobs() -> usize {
+    1
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_iso_library_max_gb() -> u64 {
+    200
+}
+
+fn default_backup_keep() -> usize {
+    10
+}
+
+fn default_qr_payload_template() -> String {
+    crate::qrcode::DEFAULT_PAYLOAD_TEMPLATE.to_string()
+}
+
+fn default_iso_level() -> u8 {
+    3
+}
+
+fn default_log_keep_files() -> usize {
+    14
+}
+
+fn default_log_max_total_mb() -> u64 {
+    200
+}
+
+fn default_min_data_free_mb() -> u64 {
+    512
+}
+
 fn default_true() -> bool {
     true
 }
 
+fn default_qr_format() -> String {
+    "png".to_string()
+}
+
+fn default_stall_timeout_secs() -> u64 {
+    300
+}
+
 fn default_burn_method() -> String {
     "direct".to_string()  // Default to direct method for space efficiency
 }
@@ -115,12 +658,22 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             device: default_device(),
+            devices: Vec::new(),
             staging_dir: None,
             database_path: None,
+            min_data_free_mb: default_min_data_free_mb(),
             default_capacity_gb: default_capacity_gb(),
+            disc_id_template: default_disc_id_template(),
             verification: VerificationConfig::default(),
             burn: BurnConfig::default(),
             optional_tools: OptionalToolsConfig::default(),
+            staging: StagingConfig::default(),
+            cleanup: CleanupConfig::default(),
+            iso_library: IsoLibraryConfig::default(),
+            iso: IsoConfig::default(),
+            backup: BackupConfig::default(),
+            qr: QrConfig::default(),
+            logging: LoggingConfig::default(),
         }
     }
 }
@@ -181,9 +734,36 @@ impl Config {
         }
     }
 
-    /// Get the default disc capacity in bytes.
+    /// Free space the catalog volumes must keep, in bytes.
+    pub fn min_data_free_bytes(&self) -> u64 {
+        self.min_data_free_mb.saturating_mul(1024 * 1024)
+    }
+
+    /// Get the ISO library directory.
+    pub fn iso_library_dir(&self) -> Result<PathBuf> {
+        match &self.iso_library.dir {
+            Some(dir) => Ok(paths::expand_tilde(dir)),
+            None => Ok(paths::data_dir()?.join("isos")),
+        }
+    }
+
+    /// Burners available for multi-disc sets, without duplicates; `device` when none are listed.
+    pub fn burn_devices(&self) -> Vec<String> {
+        let mut devices: Vec<String> = Vec::new();
+        for device in &self.devices {
+            if !device.is_empty() && !devices.contains(device) {
+                devices.push(device.clone());
+            }
+        }
+        if devices.is_empty() {
+            devices.push(self.device.clone());
+        }
+        devices
+    }
+
+    /// Get the default disc capacity in bytes (decimal GB).
     pub fn default_capacity_bytes(&self) -> u64 {
-        self.default_capacity_gb * 1024 * 1024 * 1024
+        self.default_capacity_gb * BYTES_PER_GB
     }
 
     /// Validate the configuration.
@@ -246,7 +826,8 @@ mod tests {
         let config = Config::default();
         assert_eq!(config.device, "/dev/sr0");
         assert_eq!(config.default_capacity_gb, 25);
-        assert_eq!(config.default_capacity_bytes(), 25 * 1024 * 1024 * 1024);
+        assert_eq!(config.default_capacity_bytes(), 25_000_000_000);
+        assert!(!config.cleanup.after_burn);
     }
 
     #[test]
@@ -257,6 +838,16 @@ mod tests {
         assert!(toml_str.contains("/dev/sr0"));
     }
 
+    #[test]
+    fn test_checksum_config() {
+        assert_eq!(Config::default().staging.checksum, ChecksumAlgorithm::Sha256);
+
+        let config: Config = toml::from_str("[staging]\nchecksum = \"crc32\"\n").unwrap();
+        assert_eq!(config.staging.checksum, ChecksumAlgorithm::Crc32);
+        let config: Config = toml::from_str("[staging]\nchecksum = \"sha256\"\n").unwrap();
+        assert_eq!(config.staging.checksum, ChecksumAlgorithm::Sha256);
+    }
+
     #[test]
     fn test_config_deserialization() {
         let toml_str = r#"
@@ -271,6 +862,39 @@ auto_verify_after_burn = true
         assert!(config.verification.auto_verify_after_burn);
     }
 
+    #[test]
+    fn test_compression_config() {
+        let config = Config::default();
+        assert_eq!(config.staging.compression, Compression::None);
+        assert!(config.verification.decompress_archives);
+
+        let config: Config = toml::from_str("[staging]\ncompression = \"zstd\"\n").unwrap();
+        assert_eq!(config.staging.compression, Compression::Zstd);
+    }
+
+    #[test]
+    fn test_eject_after_disc_config() {
+        assert!(!Config::default().burn.eject_after_disc);
+
+        let config: Config = toml::from_str("[burn]\neject_after_disc = true\n").unwrap();
+        assert!(config.burn.eject_after_disc);
+    }
+
+    #[test]
+    fn test_burn_devices() {
+        let config = Config::default();
+        assert_eq!(config.burn_devices(), vec!["/dev/sr0".to_string()]);
+
+        let config: Config = toml::from_str(
+            r#"
+device = "/dev/sr0"
+devices = ["/dev/sr1", "/dev/sr2", "/dev/sr1"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.burn_devices(), vec!["/dev/sr1".to_string(), "/dev/sr2".to_string()]);
+    }
+
     #[test]
     fn test_config_100gb_capacity() {
         let toml_str = r#"
@@ -279,7 +903,7 @@ default_capacity_gb = 100
 "#;
         let config: Config = toml::from_str(toml_str).unwrap();
         assert_eq!(config.default_capacity_gb, 100);
-        assert_eq!(config.default_capacity_bytes(), 100 * 1024 * 1024 * 1024);
+        assert_eq!(config.default_capacity_bytes(), 100_000_000_000);
     }
 
     #[test]
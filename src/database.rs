@@ -1,11 +1,13 @@
 use anyhow::{Context, Result};
 use rusqlite::{params, Connection, Transaction};
-use std::path::Path;
-use tracing::{debug, info};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+use crate::config::ChecksumAlgorithm;
 use crate::disc;
 
 /// Database schema version
-const SCHEMA_VERSION: u32 = 3;
+pub const SCHEMA_VERSION: u32 = 17;
 
 /// Initialize the database and run migrations if needed.
 pub fn init_database(db_path: &Path) -> Result<Connection> {
@@ -46,13 +48,56 @@ fn migrate_database(conn: &mut Connection) -> Result<()> {
         if current_version == 1 {
             migrate_v1_to_v2(&tx)?;
         }
-        if current_version == 2 {
+        // Later migrations run for every older version. The v3 step is
+        // idempotent and also runs for v3 catalogs: create_schema only covers
+        // the v2 tables, yet fresh catalogs used to be stamped as v3 without
+        // burn_sessions
+        if current_version <= 3 {
             migrate_v2_to_v3(&tx)?;
         }
+        if current_version <= 3 {
+            migrate_v3_to_v4(&tx)?;
+        }
+        if current_version <= 4 {
+            migrate_v4_to_v5(&tx)?;
+        }
+        if current_version <= 5 {
+            migrate_v5_to_v6(&tx)?;
+        }
+        if current_version <= 6 {
+            migrate_v6_to_v7(&tx)?;
+        }
+        if current_version <= 7 {
+            migrate_v7_to_v8(&tx)?;
+        }
+        if current_version <= 8 {
+            migrate_v8_to_v9(&tx)?;
+        }
+        if current_version <= 9 {
+            migrate_v9_to_v10(&tx)?;
+        }
+        if current_version <= 10 {
+            migrate_v10_to_v11(&tx)?;
+        }
+        if current_version <= 11 {
+            migrate_v11_to_v12(&tx)?;
+        }
+        if current_version <= 12 {
+            migrate_v12_to_v13(&tx)?;
+        }
+        if current_version <= 13 {
+            migrate_v13_to_v14(&tx)?;
+        }
+        if current_version <= 14 {
+            migrate_v14_to_v15(&tx)?;
+        }
+        if current_version <= 15 {
+            migrate_v15_to_v16(&tx)?;
+        }
+        if current_version <= 16 {
+            migrate_v16_to_v17(&tx)?;
+        }
         // Future migrations would go here:
-        // if current_version == 3 {
-        //     migrate_v3_to_v4(&tx)?;
-        // }
         set_schema_version(&tx, SCHEMA_VERSION)?;
         tx.commit()?;
 
@@ -90,6 +135,35 @@ fn get_schema_version(conn: &Connection) -> Result<u32> {
     Ok(version)
 }
 
+/// Schema version of the catalog at `db_path` before it is opened for use,
+/// or `None` if it doesn't exist or can't be read. Used to tell at startup
+/// whether [`init_database`] is about to migrate it.
+pub fn stored_schema_version(db_path: &Path) -> Option<u32> {
+    if !db_path.exists() {
+        return None;
+    }
+    let conn = Connection::open_with_flags(db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY).ok()?;
+    get_schema_version(&conn).ok()
+}
+
+/// Lightweight check that an open catalog really works: the connection
+/// answers a query, the schema is the one this build expects and the discs
+/// table can be read. Returns the number of discs.
+pub fn check_connection(conn: &Connection) -> Result<usize> {
+    conn.query_row("SELECT 1", [], |row| row.get::<_, i64>(0))
+        .context("Database does not answer queries")?;
+    let version: u32 = conn
+        .query_row("SELECT version FROM schema_version", [], |row| row.get(0))
+        .context("Cannot read the schema version")?;
+    if version != SCHEMA_VERSION {
+        anyhow::bail!("Schema version {} does not match the supported version {}", version, SCHEMA_VERSION);
+    }
+    let discs: i64 = conn
+        .query_row("SELECT COUNT(*) FROM discs", [], |row| row.get(0))
+        .context("Cannot read the discs table")?;
+    Ok(discs as usize)
+}
+
 /// Set the schema version.
 fn set_schema_version(tx: &Transaction, version: u32) -> Result<()> {
     tx.execute(
@@ -196,6 +270,276 @@ fn migrate_v2_to_v3(tx: &Transaction) -> Result<()> {
     Ok(())
 }
 
+/// Migrate from schema version 3 to version 4 (add FTS5 index over file paths).
+/// If the linked SQLite lacks FTS5 the index is skipped and search falls back to LIKE.
+fn migrate_v3_to_v4(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 4: adding full-text path index");
+
+    // External-content FTS5 table mirroring files.rel_path
+    if let Err(e) = tx.execute(
+        "CREATE VIRTUAL TABLE IF NOT EXISTS files_fts USING fts5(
+            rel_path,
+            content='files',
+            content_rowid='id'
+        )",
+        [],
+    ) {
+        warn!("FTS5 not available, full-text search disabled: {}", e);
+        return Ok(());
+    }
+
+    // Triggers keep the index in sync with inserts, upserts and deletes
+    // (including cascaded deletes when a disc is removed)
+    tx.execute(
+        "CREATE TRIGGER IF NOT EXISTS files_fts_insert AFTER INSERT ON files BEGIN
+            INSERT INTO files_fts(rowid, rel_path) VALUES (new.id, new.rel_path);
+        END",
+        [],
+    )?;
+
+    tx.execute(
+        "CREATE TRIGGER IF NOT EXISTS files_fts_delete AFTER DELETE ON files BEGIN
+            INSERT INTO files_fts(files_fts, rowid, rel_path) VALUES ('delete', old.id, old.rel_path);
+        END",
+        [],
+    )?;
+
+    tx.execute(
+        "CREATE TRIGGER IF NOT EXISTS files_fts_update AFTER UPDATE OF rel_path ON files BEGIN
+            INSERT INTO files_fts(files_fts, rowid, rel_path) VALUES ('delete', old.id, old.rel_path);
+            INSERT INTO files_fts(rowid, rel_path) VALUES (new.id, new.rel_path);
+        END",
+        [],
+    )?;
+
+    // Build the index for rows that already exist
+    tx.execute("INSERT INTO files_fts(files_fts) VALUES ('rebuild')", [])?;
+
+    info!("Migration to version 4 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 4 to version 5 (record burn duration and write speed).
+fn migrate_v4_to_v5(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 5: adding burn timing columns");
+
+    tx.execute("ALTER TABLE discs ADD COLUMN burn_duration_secs REAL", [])?;
+    tx.execute("ALTER TABLE discs ADD COLUMN avg_write_speed_mbps REAL", [])?;
+
+    info!("Migration to version 5 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 5 to version 6 (store the planned disc layout with burn sessions).
+fn migrate_v5_to_v6(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 6: storing planned layout with burn sessions");
+
+    tx.execute("ALTER TABLE burn_sessions ADD COLUMN plan_json TEXT", [])?;
+
+    info!("Migration to version 6 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 6 to version 7 (record whether a disc is M-DISC media).
+fn migrate_v6_to_v7(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 7: adding M-DISC flag to discs");
+
+    tx.execute("ALTER TABLE discs ADD COLUMN is_mdisc INTEGER NOT NULL DEFAULT 0", [])?;
+
+    info!("Migration to version 7 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 7 to version 8 (ISOs retained in the ISO library).
+fn migrate_v7_to_v8(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 8: adding retained ISO path and checksum to discs");
+
+    tx.execute("ALTER TABLE discs ADD COLUMN iso_path TEXT", [])?;
+    tx.execute("ALTER TABLE discs ADD COLUMN iso_sha256 TEXT", [])?;
+
+    info!("Migration to version 8 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 8 to version 9 (tags for grouping discs).
+fn migrate_v8_to_v9(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 9: adding disc tags");
+
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS tags (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE
+        )",
+        [],
+    )?;
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS disc_tags (
+            disc_id TEXT NOT NULL,
+            tag_id INTEGER NOT NULL,
+            PRIMARY KEY (disc_id, tag_id),
+            FOREIGN KEY (disc_id) REFERENCES discs(disc_id) ON DELETE CASCADE,
+            FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE
+        )",
+        [],
+    )?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_disc_tags_tag_id ON disc_tags(tag_id)",
+        [],
+    )?;
+
+    info!("Migration to version 9 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 9 to version 10 (where each disc is stored).
+fn migrate_v9_to_v10(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 10: adding storage location to discs");
+
+    tx.execute("ALTER TABLE discs ADD COLUMN storage_location TEXT", [])?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_discs_storage_location ON discs(storage_location COLLATE NOCASE)",
+        [],
+    )?;
+
+    info!("Migration to version 10 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 10 to version 11 (whole-set verification outcomes).
+fn migrate_v10_to_v11(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 11: adding set verification runs");
+
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS set_verification_runs (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            set_id TEXT NOT NULL,
+            verified_at TEXT NOT NULL,
+            success INTEGER NOT NULL,
+            total_discs INTEGER NOT NULL,
+            discs_verified INTEGER NOT NULL,
+            discs_failed INTEGER NOT NULL,
+            discs_missing INTEGER NOT NULL,
+            FOREIGN KEY (set_id) REFERENCES disc_sets(set_id) ON DELETE CASCADE
+        )",
+        [],
+    )?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_set_verification_set_id ON set_verification_runs(set_id)",
+        [],
+    )?;
+
+    info!("Migration to version 11 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 11 to version 12 (disc set status and progress).
+fn migrate_v11_to_v12(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 12: adding status and progress to disc sets");
+
+    tx.execute("ALTER TABLE disc_sets ADD COLUMN status TEXT NOT NULL DEFAULT 'in_progress'", [])?;
+    tx.execute("ALTER TABLE disc_sets ADD COLUMN completed_count INTEGER NOT NULL DEFAULT 0", [])?;
+    // Existing sets: count what was recorded and mark finished sets complete
+    tx.execute(
+        "UPDATE disc_sets SET completed_count =
+            (SELECT COUNT(*) FROM discs WHERE discs.set_id = disc_sets.set_id)",
+        [],
+    )?;
+    tx.execute("UPDATE disc_sets SET status = 'complete' WHERE completed_count >= disc_count", [])?;
+
+    info!("Migration to version 12 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 12 to version 13 (recently used source folders).
+fn migrate_v12_to_v13(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 13: adding recent source folders");
+
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS recent_sources (
+            path TEXT PRIMARY KEY,
+            last_used_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    info!("Migration to version 13 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 13 to version 14 (sample verification flag).
+fn migrate_v13_to_v14(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 14: flagging sample verification runs");
+
+    tx.execute("ALTER TABLE verification_runs ADD COLUMN sampled INTEGER NOT NULL DEFAULT 0", [])?;
+
+    info!("Migration to version 14 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 14 to version 15 (planned discs from dry runs).
+fn migrate_v14_to_v15(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 15: flagging planned (dry-run) discs");
+
+    tx.execute("ALTER TABLE discs ADD COLUMN planned INTEGER NOT NULL DEFAULT 0", [])?;
+
+    info!("Migration to version 15 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 15 to version 16 (source files that could not be read).
+fn migrate_v15_to_v16(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 16: adding unreadable source files");
+
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS failed_files (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            disc_id TEXT NOT NULL,
+            source_path TEXT NOT NULL,
+            error TEXT NOT NULL,
+            recorded_at TEXT NOT NULL,
+            FOREIGN KEY (disc_id) REFERENCES discs(disc_id) ON DELETE CASCADE
+        )",
+        [],
+    )?;
+    tx.execute("CREATE INDEX IF NOT EXISTS idx_failed_files_disc_id ON failed_files(disc_id)", [])?;
+
+    info!("Migration to version 16 completed");
+    Ok(())
+}
+
+/// Migrate from schema version 16 to version 17 (CRC32 checksums in a column of their own).
+fn migrate_v16_to_v17(tx: &Transaction) -> Result<()> {
+    info!("Migrating database to version 17: moving CRC32 checksums out of files.sha256");
+
+    tx.execute("ALTER TABLE files ADD COLUMN crc32 TEXT", [])?;
+    // CRC32 discs stored their 8-digit checksum where the SHA-256 goes
+    let moved = tx.execute("UPDATE files SET crc32 = sha256, sha256 = '' WHERE length(sha256) = 8", [])?;
+
+    info!("Migration to version 17 completed ({} CRC32 checksums moved)", moved);
+    Ok(())
+}
+
+/// Normalize a tag as typed (`#Photos`, `photos`) to its stored form (`photos`).
+/// Returns None for an empty tag or one containing whitespace.
+pub fn normalize_tag(tag: &str) -> Option<String> {
+    let tag = tag.trim();
+    let tag = tag.strip_prefix('#').unwrap_or(tag);
+    if tag.is_empty() || tag.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(tag.to_lowercase())
+}
+
+/// Check whether the full-text path index exists.
+pub fn fts_available(conn: &Connection) -> bool {
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type='table' AND name='files_fts')",
+        [],
+        |row| row.get(0),
+    )
+    .unwrap_or(false)
+}
+
 /// Create the initial database schema.
 fn create_schema(tx: &Transaction) -> Result<()> {
     // Disc sets table (for multi-disc archives)
@@ -309,8 +653,52 @@ fn create_schema(tx: &Transaction) -> Result<()> {
     Ok(())
 }
 
+/// Where a disc set stands: still being burned, stopped early, or finished.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SetStatus {
+    #[default]
+    InProgress,
+    Cancelled,
+    Failed,
+    Complete,
+}
+
+impl SetStatus {
+    /// Parse the stored form; unknown values are treated as in progress.
+    pub fn parse(s: &str) -> SetStatus {
+        match s {
+            "cancelled" => SetStatus::Cancelled,
+            "failed" => SetStatus::Failed,
+            "complete" => SetStatus::Complete,
+            _ => SetStatus::InProgress,
+        }
+    }
+
+    /// Short label for lists and reports.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SetStatus::InProgress => "in progress",
+            SetStatus::Cancelled => "cancelled",
+            SetStatus::Failed => "failed",
+            SetStatus::Complete => "complete",
+        }
+    }
+}
+
+impl std::fmt::Display for SetStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetStatus::InProgress => write!(f, "in_progress"),
+            SetStatus::Cancelled => write!(f, "cancelled"),
+            SetStatus::Failed => write!(f, "failed"),
+            SetStatus::Complete => write!(f, "complete"),
+        }
+    }
+}
+
 /// Disc set record structure (for multi-disc archives)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscSet {
     pub set_id: String,
     pub name: String,
@@ -319,15 +707,40 @@ pub struct DiscSet {
     pub disc_count: u32,
     pub created_at: String,
     pub source_roots: Option<String>,
+    /// Whether the set was finished, cancelled or failed part way
+    #[serde(default)]
+    pub status: SetStatus,
+    /// Number of discs of the set recorded in the catalog so far
+    #[serde(default)]
+    pub completed_count: u32,
 }
 
 impl DiscSet {
+    const COLUMNS: &'static str =
+        "set_id, name, description, total_size, disc_count, created_at, source_roots, status, completed_count";
+
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<DiscSet> {
+        let status: String = row.get(7)?;
+        Ok(DiscSet {
+            set_id: row.get(0)?,
+            name: row.get(1)?,
+            description: row.get(2)?,
+            total_size: row.get(3)?,
+            disc_count: row.get(4)?,
+            created_at: row.get(5)?,
+            source_roots: row.get(6)?,
+            status: SetStatus::parse(&status),
+            completed_count: row.get(8)?,
+        })
+    }
+
     /// Insert a new disc set record.
-    pub fn insert(conn: &mut Connection, disc_set: &DiscSet) -> Result<()> {
+    pub fn insert(conn: &Connection, disc_set: &DiscSet) -> Result<()> {
         conn.execute(
             "INSERT INTO disc_sets (
-                set_id, name, description, total_size, disc_count, created_at, source_roots
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                set_id, name, description, total_size, disc_count, created_at, source_roots,
+                status, completed_count
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
             params![
                 disc_set.set_id,
                 disc_set.name,
@@ -335,7 +748,9 @@ impl DiscSet {
                 disc_set.total_size,
                 disc_set.disc_count,
                 disc_set.created_at,
-                disc_set.source_roots
+                disc_set.source_roots,
+                disc_set.status.to_string(),
+                disc_set.completed_count
             ],
         )?;
         Ok(())
@@ -343,44 +758,35 @@ impl DiscSet {
 
     /// List all disc sets.
     pub fn list_all(conn: &Connection) -> Result<Vec<DiscSet>> {
-        let mut stmt = conn.prepare(
-            "SELECT set_id, name, description, total_size, disc_count, created_at, source_roots
-             FROM disc_sets ORDER BY created_at DESC",
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM disc_sets ORDER BY created_at DESC",
+            Self::COLUMNS
+        ))?;
 
-        let disc_sets = stmt.query_map(params![], |row| {
-            Ok(DiscSet {
-                set_id: row.get(0)?,
-                name: row.get(1)?,
-                description: row.get(2)?,
-                total_size: row.get(3)?,
-                disc_count: row.get(4)?,
-                created_at: row.get(5)?,
-                source_roots: row.get(6)?,
-            })
-        })?;
+        let disc_sets = stmt.query_map(params![], Self::from_row)?;
+
+        disc_sets.map(|r| r.map_err(anyhow::Error::from)).collect::<Result<Vec<_>>>()
+    }
+
+    /// List disc sets with physical discs, leaving out sets planned by a dry run.
+    pub fn list_burned(conn: &Connection) -> Result<Vec<DiscSet>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM disc_sets s WHERE NOT EXISTS
+                (SELECT 1 FROM discs d WHERE d.set_id = s.set_id AND d.planned = 1)
+             ORDER BY created_at DESC",
+            Self::COLUMNS
+        ))?;
+
+        let disc_sets = stmt.query_map(params![], Self::from_row)?;
 
         disc_sets.map(|r| r.map_err(anyhow::Error::from)).collect::<Result<Vec<_>>>()
     }
 
     /// Get a disc set by ID.
     pub fn get(conn: &Connection, set_id: &str) -> Result<Option<DiscSet>> {
-        let mut stmt = conn.prepare(
-            "SELECT set_id, name, description, total_size, disc_count, created_at, source_roots
-             FROM disc_sets WHERE set_id = ?1",
-        )?;
+        let mut stmt = conn.prepare(&format!("SELECT {} FROM disc_sets WHERE set_id = ?1", Self::COLUMNS))?;
 
-        let disc_set = stmt.query_row(params![set_id], |row| {
-            Ok(DiscSet {
-                set_id: row.get(0)?,
-                name: row.get(1)?,
-                description: row.get(2)?,
-                total_size: row.get(3)?,
-                disc_count: row.get(4)?,
-                created_at: row.get(5)?,
-                source_roots: row.get(6)?,
-            })
-        });
+        let disc_set = stmt.query_row(params![set_id], Self::from_row);
 
         match disc_set {
             Ok(ds) => Ok(Some(ds)),
@@ -392,27 +798,10 @@ impl DiscSet {
     /// Get all discs in this set, ordered by sequence number.
     pub fn get_discs(conn: &Connection, set_id: &str) -> Result<Vec<Disc>> {
         let mut stmt = conn.prepare(
-            "SELECT disc_id, volume_label, created_at, notes, iso_size, burn_device,
-                    checksum_manifest_hash, qr_path, source_roots, tool_version, set_id, sequence_number
-             FROM discs WHERE set_id = ?1 ORDER BY sequence_number",
+            &format!("SELECT {} FROM discs WHERE set_id = ?1 ORDER BY sequence_number", Disc::COLUMNS),
         )?;
 
-        let disc_iter = stmt.query_map(params![set_id], |row| {
-            Ok(Disc {
-                disc_id: row.get(0)?,
-                volume_label: row.get(1)?,
-                created_at: row.get(2)?,
-                notes: row.get(3)?,
-                iso_size: row.get(4)?,
-                burn_device: row.get(5)?,
-                checksum_manifest_hash: row.get(6)?,
-                qr_path: row.get(7)?,
-                source_roots: row.get(8)?,
-                tool_version: row.get(9)?,
-                set_id: row.get(10)?,
-                sequence_number: row.get(11)?,
-            })
-        })?;
+        let disc_iter = stmt.query_map(params![set_id], Disc::from_row)?;
 
         let mut discs = Vec::new();
         for disc in disc_iter {
@@ -421,62 +810,689 @@ impl DiscSet {
 
         Ok(discs)
     }
+
+    /// Set the status of a disc set (e.g. cancelled when a burn run is stopped).
+    pub fn set_status(conn: &Connection, set_id: &str, status: SetStatus) -> Result<()> {
+        conn.execute(
+            "UPDATE disc_sets SET status = ?1 WHERE set_id = ?2",
+            params![status.to_string(), set_id],
+        )?;
+        Ok(())
+    }
+
+    /// Recount the discs recorded for a set and mark it complete once all are present.
+    ///
+    /// A set that was complete but lost discs goes back to in progress;
+    /// cancelled and failed sets keep their status until all discs are in.
+    pub fn record_progress(conn: &Connection, set_id: &str) -> Result<()> {
+        conn.execute(
+            "UPDATE disc_sets SET completed_count =
+                (SELECT COUNT(*) FROM discs WHERE discs.set_id = disc_sets.set_id)
+             WHERE set_id = ?1",
+            params![set_id],
+        )?;
+        conn.execute(
+            "UPDATE disc_sets SET status = CASE
+                WHEN completed_count >= disc_count THEN 'complete'
+                WHEN status = 'complete' THEN 'in_progress'
+                ELSE status
+             END
+             WHERE set_id = ?1",
+            params![set_id],
+        )?;
+        Ok(())
+    }
 }
 
-/// Generate a unique set ID for a multi-disc archive
-pub fn generate_set_id() -> String {
-    use crate::disc::format_timestamp_now;
-    format!("SET-{}", format_timestamp_now().replace([':', '-'], ""))
+/// Version of the set export format written by `export_set_json`
+const SET_EXPORT_VERSION: u32 = 1;
+
+/// A disc set with its discs and their files, as written by `export_set_json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetExport {
+    pub format_version: u32,
+    pub exported_at: String,
+    pub tool_version: String,
+    pub set: DiscSet,
+    pub discs: Vec<DiscExport>,
 }
 
-/// Helper functions for multi-disc operations
-pub struct MultiDiscOps;
+/// One disc of an exported set with its file records.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscExport {
+    #[serde(flatten)]
+    pub disc: Disc,
+    pub files: Vec<FileRecord>,
+}
 
-impl MultiDiscOps {
-    /// Create a new disc set and get the set ID
-    pub fn create_disc_set(
-        conn: &mut Connection,
-        name: &str,
-        description: Option<&str>,
-        total_size: u64,
-        disc_count: u32,
-        source_roots: Option<&str>,
-    ) -> Result<String> {
-        let set_id = generate_set_id();
-        let created_at = crate::disc::format_timestamp_now();
+/// What an export or import covered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetTransferSummary {
+    pub set_id: String,
+    pub discs: usize,
+    pub files: usize,
+}
 
-        let disc_set = DiscSet {
-            set_id: set_id.clone(),
-            name: name.to_string(),
-            description: description.map(|s| s.to_string()),
-            total_size,
-            disc_count,
-            created_at,
-            source_roots: source_roots.map(|s| s.to_string()),
-        };
+/// Write a disc set, its discs and their file records to a JSON file that
+/// `import_set_json` can load into another catalog.
+pub fn export_set_json(conn: &Connection, set_id: &str, out: &Path) -> Result<SetTransferSummary> {
+    let set = DiscSet::get(conn, set_id)?.with_context(|| format!("Disc set {} not found", set_id))?;
+    let discs = DiscSet::get_discs(conn, set_id)?
+        .into_iter()
+        .map(|disc| {
+            let files = FileRecord::list_for_disc(conn, &disc.disc_id)?;
+            Ok(DiscExport { disc, files })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let export = SetExport {
+        format_version: SET_EXPORT_VERSION,
+        exported_at: disc::format_timestamp_now(),
+        tool_version: disc::get_tool_version(),
+        set,
+        discs,
+    };
+
+    let summary = SetTransferSummary {
+        set_id: set_id.to_string(),
+        discs: export.discs.len(),
+        files: export.discs.iter().map(|d| d.files.len()).sum(),
+    };
+    let json = serde_json::to_string_pretty(&export).context("Failed to serialize disc set")?;
+    std::fs::write(out, json).with_context(|| format!("Failed to write {}", out.display()))?;
+    info!("Exported set {} ({} discs, {} files) to {}", set_id, summary.discs, summary.files, out.display());
+    Ok(summary)
+}
 
-        DiscSet::insert(conn, &disc_set)?;
-        Ok(set_id)
+/// Load a set written by `export_set_json`. Rows are upserted on their keys
+/// (set ID, disc ID, and disc ID + path for files), so importing the same
+/// file again updates rows instead of duplicating them.
+pub fn import_set_json(conn: &mut Connection, path: &Path) -> Result<SetTransferSummary> {
+    let json = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let export: SetExport =
+        serde_json::from_str(&json).with_context(|| format!("Not a disc set export: {}", path.display()))?;
+    if export.format_version > SET_EXPORT_VERSION {
+        anyhow::bail!(
+            "{} uses set export format {}; this version reads up to {}",
+            path.display(),
+            export.format_version,
+            SET_EXPORT_VERSION
+        );
     }
-
-    /// Add a disc to an existing set
-    pub fn add_disc_to_set(
-        conn: &mut Connection,
-        disc: &mut Disc,
-        set_id: &str,
-        sequence_number: u32,
-    ) -> Result<()> {
-        disc.set_id = Some(set_id.to_string());
-        disc.sequence_number = Some(sequence_number);
-        Disc::insert(conn, disc)?;
-        Ok(())
+    if let Some(other) = export.discs.iter().find(|d| d.disc.set_id.as_deref() != Some(export.set.set_id.as_str())) {
+        anyhow::bail!("Disc {} does not belong to set {}", other.disc.disc_id, export.set.set_id);
     }
 
-    /// Check if a disc is part of a multi-disc set
-    pub fn is_part_of_set(conn: &Connection, disc_id: &str) -> Result<Option<String>> {
-        let disc = Disc::get(conn, disc_id)?;
-        Ok(disc.and_then(|d| d.set_id))
-    }
+    let tx = conn.transaction()?;
+    let set = &export.set;
+    tx.execute(
+        "INSERT INTO disc_sets (set_id, name, description, total_size, disc_count, created_at, source_roots, status)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+         ON CONFLICT(set_id) DO UPDATE SET
+            name = ?2, description = ?3, total_size = ?4, disc_count = ?5, created_at = ?6, source_roots = ?7,
+            status = ?8",
+        params![
+            set.set_id,
+            set.name,
+            set.description,
+            set.total_size,
+            set.disc_count,
+            set.created_at,
+            set.source_roots,
+            set.status.to_string()
+        ],
+    )?;
+    let mut files = 0;
+    for DiscExport { disc, files: records } in &export.discs {
+        tx.execute(
+            "INSERT INTO discs (
+                disc_id, volume_label, created_at, notes, iso_size, burn_device,
+                checksum_manifest_hash, qr_path, source_roots, tool_version, set_id, sequence_number,
+                burn_duration_secs, avg_write_speed_mbps, is_mdisc, storage_location, planned
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+            ON CONFLICT(disc_id) DO UPDATE SET
+                volume_label = ?2, created_at = ?3, notes = ?4, iso_size = ?5, burn_device = ?6,
+                checksum_manifest_hash = ?7, qr_path = ?8, source_roots = ?9, tool_version = ?10,
+                set_id = ?11, sequence_number = ?12, burn_duration_secs = ?13,
+                avg_write_speed_mbps = ?14, is_mdisc = ?15, storage_location = ?16, planned = ?17",
+            params![
+                disc.disc_id,
+                disc.volume_label,
+                disc.created_at,
+                disc.notes,
+                disc.iso_size,
+                disc.burn_device,
+                disc.checksum_manifest_hash,
+                disc.qr_path,
+                disc.source_roots,
+                disc.tool_version,
+                disc.set_id,
+                disc.sequence_number,
+                disc.burn_duration_secs,
+                disc.avg_write_speed_mbps,
+                disc.is_mdisc,
+                disc.storage_location,
+                disc.planned
+            ],
+        )?;
+        let mut stmt = tx.prepare(
+            "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at, crc32)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(disc_id, rel_path) DO UPDATE SET
+                sha256 = ?3, size = ?4, mtime = ?5, added_at = ?6, crc32 = ?7",
+        )?;
+        for file in records {
+            // Files are always filed under the disc they were exported with
+            stmt.execute(params![
+                disc.disc_id,
+                file.rel_path,
+                file.sha256,
+                file.size,
+                file.mtime,
+                file.added_at,
+                file.crc32
+            ])?;
+        }
+        files += records.len();
+    }
+    DiscSet::record_progress(&tx, &set.set_id)?;
+    tx.commit()?;
+
+    info!("Imported set {} ({} discs, {} files) from {}", set.set_id, export.discs.len(), files, path.display());
+    Ok(SetTransferSummary {
+        set_id: set.set_id.clone(),
+        discs: export.discs.len(),
+        files,
+    })
+}
+
+/// Generate a unique set ID for a multi-disc archive
+pub fn generate_set_id() -> String {
+    use crate::disc::format_timestamp_now;
+    format!("SET-{}", format_timestamp_now().replace([':', '-'], ""))
+}
+
+/// Delete disc sets that have no discs and cannot be resumed.
+///
+/// Such sets are left behind when a multi-disc run dies after creating the set
+/// but before recording its first disc. Sets whose active or paused burn
+/// session still holds a stored plan are kept, since they can be resumed.
+/// Returns the IDs of the deleted sets.
+pub fn repair_orphan_sets(conn: &mut Connection) -> Result<Vec<String>> {
+    let tx = conn.transaction()?;
+
+    let orphans: Vec<String> = {
+        let mut stmt = tx.prepare(
+            "SELECT set_id FROM disc_sets s
+             WHERE NOT EXISTS (SELECT 1 FROM discs d WHERE d.set_id = s.set_id)
+               AND NOT EXISTS (
+                   SELECT 1 FROM burn_sessions b
+                   WHERE b.set_id = s.set_id
+                     AND b.status IN ('active', 'paused')
+                     AND b.plan_json IS NOT NULL
+               )
+             ORDER BY set_id",
+        )?;
+        let rows = stmt.query_map([], |row| row.get(0))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+
+    for set_id in &orphans {
+        tx.execute("DELETE FROM burn_sessions WHERE set_id = ?1", params![set_id])?;
+        tx.execute("DELETE FROM disc_sets WHERE set_id = ?1", params![set_id])?;
+        warn!("Removed orphan disc set {} (no discs recorded)", set_id);
+    }
+
+    tx.commit()?;
+    Ok(orphans)
+}
+
+/// Catalog-wide totals for the main menu dashboard. Planned discs from dry
+/// runs (and sets made of them) are not counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ArchiveStats {
+    pub disc_count: u64,
+    pub set_count: u64,
+    pub file_count: u64,
+    /// Sum of indexed file sizes
+    pub total_bytes: u64,
+    /// Discs without a single successful verification run
+    pub unverified_discs: u64,
+}
+
+/// Gather [`ArchiveStats`] in a single query.
+pub fn archive_stats(conn: &Connection) -> Result<ArchiveStats> {
+    let stats = conn.query_row(
+        "SELECT
+            (SELECT COUNT(*) FROM discs WHERE planned = 0),
+            (SELECT COUNT(*) FROM disc_sets s WHERE NOT EXISTS
+                (SELECT 1 FROM discs d WHERE d.set_id = s.set_id AND d.planned = 1)),
+            (SELECT COUNT(*) FROM files f JOIN discs d ON d.disc_id = f.disc_id WHERE d.planned = 0),
+            (SELECT COALESCE(SUM(f.size), 0) FROM files f JOIN discs d ON d.disc_id = f.disc_id WHERE d.planned = 0),
+            (SELECT COUNT(*) FROM discs d WHERE d.planned = 0 AND NOT EXISTS
+                (SELECT 1 FROM verification_runs v WHERE v.disc_id = d.disc_id AND v.success = 1 AND v.sampled = 0))",
+        [],
+        |row| {
+            Ok(ArchiveStats {
+                disc_count: row.get::<_, i64>(0)? as u64,
+                set_count: row.get::<_, i64>(1)? as u64,
+                file_count: row.get::<_, i64>(2)? as u64,
+                total_bytes: row.get::<_, i64>(3)? as u64,
+                unverified_discs: row.get::<_, i64>(4)? as u64,
+            })
+        },
+    )?;
+    Ok(stats)
+}
+
+/// File count and bytes per extension, as `(extension, count, total_bytes)`,
+/// largest share first. Extensions are lowercased without the dot; files
+/// without one are grouped under an empty string. With `disc_id` the
+/// breakdown covers that disc, otherwise every burned (not planned) disc.
+pub fn extension_breakdown(conn: &Connection, disc_id: Option<&str>) -> Result<Vec<(String, u64, u64)>> {
+    let mut stmt = match disc_id {
+        Some(_) => conn.prepare("SELECT rel_path, size FROM files WHERE disc_id = ?1")?,
+        None => conn.prepare(
+            "SELECT f.rel_path, f.size FROM files f JOIN discs d ON d.disc_id = f.disc_id WHERE d.planned = 0",
+        )?,
+    };
+    let map_row = |row: &rusqlite::Row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as u64));
+    let rows = match disc_id {
+        Some(disc_id) => stmt.query_map(params![disc_id], map_row)?.collect::<rusqlite::Result<Vec<_>>>()?,
+        None => stmt.query_map([], map_row)?.collect::<rusqlite::Result<Vec<_>>>()?,
+    };
+    Ok(tally_extensions(rows.iter().map(|(path, size)| (path.as_str(), *size))))
+}
+
+/// Group `(rel_path, size)` pairs the way [`extension_breakdown`] does, for
+/// file lists that are already loaded.
+pub fn tally_extensions<'a>(files: impl IntoIterator<Item = (&'a str, u64)>) -> Vec<(String, u64, u64)> {
+    let mut totals: std::collections::HashMap<String, (u64, u64)> = std::collections::HashMap::new();
+    for (rel_path, size) in files {
+        let ext = Path::new(rel_path)
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let entry = totals.entry(ext).or_default();
+        entry.0 += 1;
+        entry.1 += size;
+    }
+    let mut breakdown: Vec<(String, u64, u64)> =
+        totals.into_iter().map(|(ext, (count, bytes))| (ext, count, bytes)).collect();
+    breakdown.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+    breakdown
+}
+
+/// Run SQLite's `PRAGMA integrity_check` and `PRAGMA foreign_key_check`.
+/// Returns one line per problem found; an empty list means the database is healthy.
+pub fn integrity_check(conn: &Connection) -> Result<Vec<String>> {
+    let mut problems: Vec<String> = {
+        let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()?
+    };
+    problems.retain(|line| line != "ok");
+
+    let mut stmt = conn.prepare("PRAGMA foreign_key_check")?;
+    let violations = stmt.query_map([], |row| {
+        Ok(format!(
+            "{} row {} references a missing {} row",
+            row.get::<_, String>(0)?,
+            row.get::<_, Option<i64>>(1)?.map_or("?".to_string(), |id| id.to_string()),
+            row.get::<_, String>(2)?
+        ))
+    })?;
+    for violation in violations {
+        problems.push(violation?);
+    }
+    for problem in &problems {
+        warn!("Database integrity: {}", problem);
+    }
+    Ok(problems)
+}
+
+/// Tables reported by [`table_row_counts`], in display order.
+const COUNTED_TABLES: [&str; 6] = ["discs", "disc_sets", "files", "verification_runs", "burn_sessions", "tags"];
+
+/// Row count of each main table.
+pub fn table_row_counts(conn: &Connection) -> Result<Vec<(&'static str, u64)>> {
+    COUNTED_TABLES
+        .iter()
+        .map(|&table| {
+            let count: i64 = conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0))?;
+            Ok((table, count as u64))
+        })
+        .collect()
+}
+
+/// File records whose disc no longer exists. Foreign keys cascade deletes, so
+/// these only appear in databases written with enforcement off.
+pub fn orphaned_file_count(conn: &Connection) -> Result<u64> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM files f WHERE NOT EXISTS (SELECT 1 FROM discs d WHERE d.disc_id = f.disc_id)",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(count as u64)
+}
+
+/// Database size before and after [`vacuum`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VacuumStats {
+    pub before_bytes: u64,
+    pub after_bytes: u64,
+}
+
+impl VacuumStats {
+    pub fn saved_bytes(&self) -> u64 {
+        self.before_bytes.saturating_sub(self.after_bytes)
+    }
+}
+
+/// Rebuild the database file to reclaim the space left by deleted rows.
+pub fn vacuum(conn: &Connection) -> Result<VacuumStats> {
+    let size = |conn: &Connection| -> Result<u64> {
+        let pages: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+        let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+        Ok((pages * page_size) as u64)
+    };
+    let before_bytes = size(conn)?;
+    conn.execute_batch("VACUUM").context("VACUUM failed")?;
+    let stats = VacuumStats { before_bytes, after_bytes: size(conn)? };
+    info!("Vacuumed database: {} -> {} bytes", stats.before_bytes, stats.after_bytes);
+    Ok(stats)
+}
+
+/// Directory of rolling backups, next to the database file
+pub const BACKUP_DIR: &str = "backups";
+const BACKUP_PREFIX: &str = "bdarchive-";
+const BACKUP_EXTENSION: &str = "db";
+
+/// Copy the database to `path` with SQLite's online backup API, which gives a
+/// consistent snapshot even while the database is open.
+pub fn backup_to(conn: &Connection, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create backup directory: {}", parent.display()))?;
+    }
+    conn.backup(rusqlite::DatabaseName::Main, path, None)
+        .with_context(|| format!("Failed to back up database to {}", path.display()))?;
+    debug!("Backed up database to {}", path.display());
+    Ok(())
+}
+
+/// Backup directory for the database `conn` has open; None for in-memory databases.
+pub fn backup_dir(conn: &Connection) -> Option<PathBuf> {
+    let db_path = Path::new(conn.path().filter(|p| !p.is_empty())?);
+    Some(db_path.parent()?.join(BACKUP_DIR))
+}
+
+/// Back up into `dir` as `bdarchive-<timestamp>.db`, then delete all but the newest `keep` backups.
+pub fn rolling_backup(conn: &Connection, dir: &Path, keep: usize) -> Result<PathBuf> {
+    let stamp: String = disc::format_timestamp_now()
+        .chars()
+        .map(|c| if c.is_ascii_digit() { c } else { '-' })
+        .collect();
+    let path = dir.join(format!("{}{}.{}", BACKUP_PREFIX, stamp.trim_end_matches('-'), BACKUP_EXTENSION));
+    backup_to(conn, &path)?;
+
+    for old in list_backups(dir)?.into_iter().skip(keep.max(1)) {
+        match std::fs::remove_file(&old) {
+            Ok(()) => debug!("Removed old backup {}", old.display()),
+            Err(e) => warn!("Failed to remove old backup {}: {}", old.display(), e),
+        }
+    }
+    info!("Database backed up to {}", path.display());
+    Ok(path)
+}
+
+/// Backups in `dir`, newest first.
+pub fn list_backups(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut backups: Vec<PathBuf> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read backup directory: {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == BACKUP_EXTENSION)
+                && path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .and_then(|n| n.strip_prefix(BACKUP_PREFIX))
+                    .is_some_and(|stamp| stamp.starts_with(|c: char| c.is_ascii_digit()))
+        })
+        .collect();
+    // Timestamps in the names sort chronologically
+    backups.sort();
+    backups.reverse();
+    Ok(backups)
+}
+
+/// Replace the open database's contents with the backup at `path`, then bring
+/// its schema up to date (the backup may predate recent migrations).
+pub fn restore_from(conn: &mut Connection, path: &Path) -> Result<()> {
+    // Check the file is a readable database before overwriting anything
+    let backup = Connection::open_with_flags(path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .with_context(|| format!("Failed to open backup: {}", path.display()))?;
+    let problems: String = backup
+        .query_row("PRAGMA quick_check", [], |row| row.get(0))
+        .with_context(|| format!("Not a usable database: {}", path.display()))?;
+    if problems != "ok" {
+        anyhow::bail!("Backup {} is damaged: {}", path.display(), problems);
+    }
+    drop(backup);
+
+    conn.restore(rusqlite::DatabaseName::Main, path, None::<fn(rusqlite::backup::Progress)>)
+        .with_context(|| format!("Failed to restore database from {}", path.display()))?;
+    migrate_database(conn)?;
+    info!("Restored database from {}", path.display());
+    Ok(())
+}
+
+/// Discs stored at `location`, by disc ID. Matching ignores case and
+/// surrounding whitespace in `location`, so "binder a, slot 12" finds
+/// "Binder A, Slot 12".
+pub fn discs_by_location(conn: &Connection, location: &str) -> Result<Vec<Disc>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM discs WHERE storage_location = ?1 COLLATE NOCASE ORDER BY disc_id",
+        Disc::COLUMNS
+    ))?;
+    let discs = stmt
+        .query_map(params![location.trim()], Disc::from_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(discs)
+}
+
+/// Number of source folders remembered for the New Disc quick-pick
+pub const RECENT_SOURCES_LIMIT: usize = 5;
+
+/// Remember the source folders of a run, most recent first, keeping the
+/// newest [`RECENT_SOURCES_LIMIT`].
+pub fn record_recent_sources(conn: &Connection, folders: &[PathBuf]) -> Result<()> {
+    let used_at = crate::disc::format_timestamp_now();
+    // REPLACE gives a reused path a fresh rowid, which orders the list;
+    // the first folder of the run is inserted last so it lists first
+    for folder in folders.iter().rev() {
+        conn.execute(
+            "INSERT OR REPLACE INTO recent_sources (path, last_used_at) VALUES (?1, ?2)",
+            params![folder.to_string_lossy(), used_at],
+        )?;
+    }
+    conn.execute(
+        "DELETE FROM recent_sources WHERE rowid NOT IN
+            (SELECT rowid FROM recent_sources ORDER BY rowid DESC LIMIT ?1)",
+        params![RECENT_SOURCES_LIMIT as i64],
+    )?;
+    Ok(())
+}
+
+/// Recently used source folders, most recent first.
+pub fn recent_sources(conn: &Connection) -> Result<Vec<PathBuf>> {
+    let mut stmt = conn.prepare("SELECT path FROM recent_sources ORDER BY rowid DESC LIMIT ?1")?;
+    let paths = stmt
+        .query_map(params![RECENT_SOURCES_LIMIT as i64], |row| row.get::<_, String>(0))?
+        .map(|path| path.map(PathBuf::from))
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(paths)
+}
+
+/// Record source files left off `disc_id` because they could not be read,
+/// as `(source path, error)` pairs.
+pub fn record_failed_files(conn: &Connection, disc_id: &str, files: &[(PathBuf, String)]) -> Result<()> {
+    let recorded_at = crate::disc::format_timestamp_now();
+    for (path, error) in files {
+        conn.execute(
+            "INSERT INTO failed_files (disc_id, source_path, error, recorded_at) VALUES (?1, ?2, ?3, ?4)",
+            params![disc_id, path.to_string_lossy(), error, recorded_at],
+        )?;
+    }
+    Ok(())
+}
+
+/// Source files that could not be read when `disc_id` was staged, as
+/// `(source path, error)` pairs.
+pub fn failed_files(conn: &Connection, disc_id: &str) -> Result<Vec<(String, String)>> {
+    let mut stmt = conn.prepare("SELECT source_path, error FROM failed_files WHERE disc_id = ?1 ORDER BY id")?;
+    let files = stmt
+        .query_map(params![disc_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(files)
+}
+
+/// Helper functions for multi-disc operations
+pub struct MultiDiscOps;
+
+impl MultiDiscOps {
+    /// Create a new disc set and get the set ID
+    pub fn create_disc_set(
+        conn: &mut Connection,
+        name: &str,
+        description: Option<&str>,
+        total_size: u64,
+        disc_count: u32,
+        source_roots: Option<&str>,
+    ) -> Result<String> {
+        let set_id = generate_set_id();
+        let created_at = crate::disc::format_timestamp_now();
+
+        let disc_set = DiscSet {
+            set_id: set_id.clone(),
+            name: name.to_string(),
+            description: description.map(|s| s.to_string()),
+            total_size,
+            disc_count,
+            created_at,
+            source_roots: source_roots.map(|s| s.to_string()),
+            status: SetStatus::InProgress,
+            completed_count: 0,
+        };
+
+        DiscSet::insert(conn, &disc_set)?;
+        Ok(set_id)
+    }
+
+    /// Insert a disc set together with the burn session that carries its plan.
+    ///
+    /// Both rows are written in one transaction, so a set never exists without
+    /// the layout needed to finish burning it.
+    pub fn create_disc_set_with_session(
+        conn: &mut Connection,
+        disc_set: &DiscSet,
+        session: &BurnSession,
+    ) -> Result<()> {
+        if session.set_id != disc_set.set_id {
+            anyhow::bail!(
+                "Burn session belongs to set {}, not {}",
+                session.set_id,
+                disc_set.set_id
+            );
+        }
+        if session.plan_json.is_none() {
+            anyhow::bail!("Burn session for set {} has no disc plan", disc_set.set_id);
+        }
+
+        let tx = conn.transaction()?;
+        DiscSet::insert(&tx, disc_set)?;
+        session.save(&tx)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Change the number of discs in a set together with the burn session that
+    /// carries its new layout, in one transaction.
+    pub fn resize_set_with_session(
+        conn: &mut Connection,
+        set_id: &str,
+        disc_count: u32,
+        session: &BurnSession,
+    ) -> Result<()> {
+        if session.set_id != set_id {
+            anyhow::bail!("Burn session belongs to set {}, not {}", session.set_id, set_id);
+        }
+
+        let tx = conn.transaction()?;
+        let updated = tx.execute(
+            "UPDATE disc_sets SET disc_count = ?1 WHERE set_id = ?2",
+            params![disc_count, set_id],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Disc set not found: {}", set_id);
+        }
+        session.save(&tx)?;
+        DiscSet::record_progress(&tx, set_id)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Store a set's new disc count, size and source roots together with the
+    /// burn session for the discs being appended, in one transaction.
+    pub fn extend_set_with_session(conn: &mut Connection, disc_set: &DiscSet, session: &BurnSession) -> Result<()> {
+        if session.set_id != disc_set.set_id {
+            anyhow::bail!("Burn session belongs to set {}, not {}", session.set_id, disc_set.set_id);
+        }
+        if session.plan_json.is_none() {
+            anyhow::bail!("Burn session for set {} has no disc plan", disc_set.set_id);
+        }
+
+        let tx = conn.transaction()?;
+        let updated = tx.execute(
+            "UPDATE disc_sets SET disc_count = ?1, total_size = ?2, source_roots = ?3, status = ?4 WHERE set_id = ?5",
+            params![
+                disc_set.disc_count,
+                disc_set.total_size,
+                disc_set.source_roots,
+                disc_set.status.to_string(),
+                disc_set.set_id
+            ],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Disc set not found: {}", disc_set.set_id);
+        }
+        session.save(&tx)?;
+        DiscSet::record_progress(&tx, &disc_set.set_id)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Add a disc to an existing set and update the set's progress
+    pub fn add_disc_to_set(
+        conn: &mut Connection,
+        disc: &mut Disc,
+        set_id: &str,
+        sequence_number: u32,
+    ) -> Result<()> {
+        disc.set_id = Some(set_id.to_string());
+        disc.sequence_number = Some(sequence_number);
+        Disc::insert(conn, disc)?;
+        DiscSet::record_progress(conn, set_id)?;
+        Ok(())
+    }
+
+    /// Check if a disc is part of a multi-disc set
+    pub fn is_part_of_set(conn: &Connection, disc_id: &str) -> Result<Option<String>> {
+        let disc = Disc::get(conn, disc_id)?;
+        Ok(disc.and_then(|d| d.set_id))
+    }
 
     /// Get all discs in the same set as the given disc
     pub fn get_related_discs(conn: &Connection, disc_id: &str) -> Result<Vec<Disc>> {
@@ -486,10 +1502,45 @@ impl MultiDiscOps {
             Ok(Vec::new())
         }
     }
+
+    /// Delete a set with its discs, their file records and the set's burn
+    /// sessions in one transaction. Returns the deleted disc IDs in sequence
+    /// order and the number of file records removed.
+    pub fn delete_set(conn: &mut Connection, set_id: &str) -> Result<(Vec<String>, usize)> {
+        let tx = conn.transaction()?;
+        let disc_ids: Vec<String> = {
+            let mut stmt = tx.prepare("SELECT disc_id FROM discs WHERE set_id = ?1 ORDER BY sequence_number")?;
+            let rows = stmt.query_map(params![set_id], |row| row.get(0))?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+
+        // Files first so the FTS delete trigger sees every row; the remaining
+        // per-disc rows (tags, verification runs) go with the disc
+        let mut files = 0;
+        for disc_id in &disc_ids {
+            files += tx.execute("DELETE FROM files WHERE disc_id = ?1", params![disc_id])?;
+            tx.execute("DELETE FROM discs WHERE disc_id = ?1", params![disc_id])?;
+        }
+        tx.execute("DELETE FROM burn_sessions WHERE set_id = ?1", params![set_id])?;
+        tx.execute("DELETE FROM disc_sets WHERE set_id = ?1", params![set_id])?;
+        tx.commit()?;
+
+        info!("Deleted disc set {} ({} discs, {} files)", set_id, disc_ids.len(), files);
+        Ok((disc_ids, files))
+    }
+}
+
+/// ISO image of a disc kept in the ISO library for re-burning.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetainedIso {
+    pub disc_id: String,
+    pub iso_path: std::path::PathBuf,
+    /// SHA-256 of the image when it was retained
+    pub sha256: Option<String>,
 }
 
 /// Disc record structure
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Disc {
     pub disc_id: String,
     pub volume_label: String,
@@ -503,16 +1554,58 @@ pub struct Disc {
     pub tool_version: Option<String>,
     pub set_id: Option<String>,
     pub sequence_number: Option<u32>,
+    /// Wall-clock time spent burning, in seconds
+    pub burn_duration_secs: Option<f64>,
+    /// Average write speed over the burn, in megabytes per second
+    pub avg_write_speed_mbps: Option<f64>,
+    /// Burned on M-DISC archival media rather than standard BD-R
+    pub is_mdisc: bool,
+    /// Where the physical disc is kept, e.g. "Binder A, Slot 12"
+    pub storage_location: Option<String>,
+    /// Recorded by a dry run: searchable, but no physical disc was burned
+    #[serde(default)]
+    pub planned: bool,
 }
 
 impl Disc {
-    /// Insert a new disc record.
+    /// Column list matching `from_row`.
+    const COLUMNS: &'static str = "disc_id, volume_label, created_at, notes, iso_size, burn_device,
+        checksum_manifest_hash, qr_path, source_roots, tool_version, set_id, sequence_number,
+        burn_duration_secs, avg_write_speed_mbps, is_mdisc, storage_location, planned";
+
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Disc> {
+        Ok(Disc {
+            disc_id: row.get(0)?,
+            volume_label: row.get(1)?,
+            created_at: row.get(2)?,
+            notes: row.get(3)?,
+            iso_size: row.get(4)?,
+            burn_device: row.get(5)?,
+            checksum_manifest_hash: row.get(6)?,
+            qr_path: row.get(7)?,
+            source_roots: row.get(8)?,
+            tool_version: row.get(9)?,
+            set_id: row.get(10)?,
+            sequence_number: row.get(11)?,
+            burn_duration_secs: row.get(12)?,
+            avg_write_speed_mbps: row.get(13)?,
+            is_mdisc: row.get(14)?,
+            storage_location: row.get(15)?,
+            planned: row.get(16)?,
+        })
+    }
+
+    /// Insert a new disc record, replacing a disc with the same ID that a dry
+    /// run planned (and its file records).
     pub fn insert(conn: &mut Connection, disc: &Disc) -> Result<()> {
-        conn.execute(
+        let tx = conn.transaction()?;
+        Self::remove_planned(&tx, &disc.disc_id)?;
+        tx.execute(
             "INSERT INTO discs (
                 disc_id, volume_label, created_at, notes, iso_size, burn_device,
-                checksum_manifest_hash, qr_path, source_roots, tool_version, set_id, sequence_number
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                checksum_manifest_hash, qr_path, source_roots, tool_version, set_id, sequence_number,
+                burn_duration_secs, avg_write_speed_mbps, is_mdisc, storage_location, planned
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
             params![
                 disc.disc_id,
                 disc.volume_label,
@@ -525,36 +1618,49 @@ impl Disc {
                 disc.source_roots,
                 disc.tool_version,
                 disc.set_id,
-                disc.sequence_number
+                disc.sequence_number,
+                disc.burn_duration_secs,
+                disc.avg_write_speed_mbps,
+                disc.is_mdisc,
+                disc.storage_location,
+                disc.planned
             ],
         )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Delete the dry-run disc planned under `disc_id` (in any case) with its
+    /// file records, and its set once no disc is left in it.
+    fn remove_planned(tx: &rusqlite::Transaction, disc_id: &str) -> Result<()> {
+        let planned: Vec<(String, Option<String>)> = {
+            let mut stmt = tx.prepare("SELECT disc_id, set_id FROM discs WHERE lower(disc_id) = lower(?1) AND planned = 1")?;
+            let rows = stmt.query_map(params![disc_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+        for (planned_id, set_id) in planned {
+            // Files first so the FTS delete trigger sees every row
+            let files = tx.execute("DELETE FROM files WHERE disc_id = ?1", params![planned_id])?;
+            tx.execute("DELETE FROM discs WHERE disc_id = ?1", params![planned_id])?;
+            info!("Replacing planned disc {} ({} files) from a dry run", planned_id, files);
+            if let Some(set_id) = set_id {
+                let remaining: i64 = tx.query_row("SELECT COUNT(*) FROM discs WHERE set_id = ?1", params![set_id], |row| row.get(0))?;
+                if remaining == 0 {
+                    tx.execute("DELETE FROM burn_sessions WHERE set_id = ?1", params![set_id])?;
+                    tx.execute("DELETE FROM disc_sets WHERE set_id = ?1", params![set_id])?;
+                }
+            }
+        }
         Ok(())
     }
 
     /// Get a disc by ID.
     pub fn get(conn: &Connection, disc_id: &str) -> Result<Option<Disc>> {
         let mut stmt = conn.prepare(
-            "SELECT disc_id, volume_label, created_at, notes, iso_size, burn_device,
-                    checksum_manifest_hash, qr_path, source_roots, tool_version, set_id, sequence_number
-             FROM discs WHERE disc_id = ?1",
+            &format!("SELECT {} FROM discs WHERE disc_id = ?1", Disc::COLUMNS),
         )?;
 
-        let disc = stmt.query_row(params![disc_id], |row| {
-            Ok(Disc {
-                disc_id: row.get(0)?,
-                volume_label: row.get(1)?,
-                created_at: row.get(2)?,
-                notes: row.get(3)?,
-                iso_size: row.get(4)?,
-                burn_device: row.get(5)?,
-                checksum_manifest_hash: row.get(6)?,
-                qr_path: row.get(7)?,
-                source_roots: row.get(8)?,
-                tool_version: row.get(9)?,
-                set_id: row.get(10)?,
-                sequence_number: row.get(11)?,
-            })
-        });
+        let disc = stmt.query_row(params![disc_id], Disc::from_row);
 
         match disc {
             Ok(d) => Ok(Some(d)),
@@ -563,30 +1669,147 @@ impl Disc {
         }
     }
 
+    /// Update the notes stored for a disc.
+    /// Only the database index changes; DISC_INFO.txt on the burned media is immutable.
+    pub fn update_notes(conn: &Connection, disc_id: &str, notes: Option<&str>) -> Result<()> {
+        let updated = conn.execute(
+            "UPDATE discs SET notes = ?1 WHERE disc_id = ?2",
+            params![notes, disc_id],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Disc not found: {}", disc_id);
+        }
+        Ok(())
+    }
+
+    /// Record (or with `None`, clear) where the physical disc is stored.
+    /// The location is trimmed; a blank one clears it.
+    pub fn set_storage_location(conn: &Connection, disc_id: &str, location: Option<&str>) -> Result<()> {
+        let location = location.map(str::trim).filter(|l| !l.is_empty());
+        let updated = conn.execute(
+            "UPDATE discs SET storage_location = ?1 WHERE disc_id = ?2",
+            params![location, disc_id],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Disc not found: {}", disc_id);
+        }
+        Ok(())
+    }
+
+    /// Record how long the burn took and the average write speed.
+    pub fn record_burn_stats(
+        conn: &Connection,
+        disc_id: &str,
+        duration_secs: f64,
+        avg_write_speed_mbps: f64,
+    ) -> Result<()> {
+        let updated = conn.execute(
+            "UPDATE discs SET burn_duration_secs = ?1, avg_write_speed_mbps = ?2 WHERE disc_id = ?3",
+            params![duration_secs, avg_write_speed_mbps, disc_id],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Disc not found: {}", disc_id);
+        }
+        Ok(())
+    }
+
+    /// Record (or with `None`, forget) the ISO kept in the ISO library for a disc.
+    pub fn set_retained_iso(conn: &Connection, disc_id: &str, iso: Option<(&str, &str)>) -> Result<()> {
+        let (path, sha256) = iso.unzip();
+        let updated = conn.execute(
+            "UPDATE discs SET iso_path = ?1, iso_sha256 = ?2 WHERE disc_id = ?3",
+            params![path, sha256, disc_id],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Disc not found: {}", disc_id);
+        }
+        Ok(())
+    }
+
+    /// Discs with an ISO in the ISO library, by disc ID.
+    pub fn list_retained_isos(conn: &Connection) -> Result<Vec<RetainedIso>> {
+        let mut stmt = conn.prepare(
+            "SELECT disc_id, iso_path, iso_sha256 FROM discs WHERE iso_path IS NOT NULL ORDER BY disc_id",
+        )?;
+        let isos = stmt
+            .query_map([], |row| {
+                Ok(RetainedIso {
+                    disc_id: row.get(0)?,
+                    iso_path: row.get::<_, String>(1)?.into(),
+                    sha256: row.get(2)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(isos)
+    }
+
+    /// Tag a disc. Tags are normalized with [`normalize_tag`]; adding a tag
+    /// the disc already has is a no-op.
+    pub fn add_tag(conn: &Connection, disc_id: &str, tag: &str) -> Result<()> {
+        let tag = normalize_tag(tag).with_context(|| format!("Invalid tag: {:?}", tag))?;
+        if Disc::get(conn, disc_id)?.is_none() {
+            anyhow::bail!("Disc not found: {}", disc_id);
+        }
+        conn.execute("INSERT OR IGNORE INTO tags (name) VALUES (?1)", params![tag])?;
+        conn.execute(
+            "INSERT OR IGNORE INTO disc_tags (disc_id, tag_id)
+             SELECT ?1, id FROM tags WHERE name = ?2",
+            params![disc_id, tag],
+        )?;
+        Ok(())
+    }
+
+    /// Remove a tag from a disc. Returns false if the disc didn't have it.
+    /// Tags no longer used by any disc are deleted.
+    pub fn remove_tag(conn: &Connection, disc_id: &str, tag: &str) -> Result<bool> {
+        let Some(tag) = normalize_tag(tag) else {
+            return Ok(false);
+        };
+        let removed = conn.execute(
+            "DELETE FROM disc_tags WHERE disc_id = ?1 AND tag_id = (SELECT id FROM tags WHERE name = ?2)",
+            params![disc_id, tag],
+        )?;
+        conn.execute(
+            "DELETE FROM tags WHERE id NOT IN (SELECT tag_id FROM disc_tags)",
+            [],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// Tags on a disc, sorted by name.
+    pub fn tags_for(conn: &Connection, disc_id: &str) -> Result<Vec<String>> {
+        let mut stmt = conn.prepare(
+            "SELECT t.name FROM disc_tags dt JOIN tags t ON t.id = dt.tag_id
+             WHERE dt.disc_id = ?1 ORDER BY t.name",
+        )?;
+        let tags = stmt
+            .query_map(params![disc_id], |row| row.get(0))?
+            .collect::<rusqlite::Result<Vec<String>>>()?;
+        Ok(tags)
+    }
+
+    /// Tags of every tagged disc, keyed by disc ID, for list views.
+    pub fn all_tags(conn: &Connection) -> Result<std::collections::HashMap<String, Vec<String>>> {
+        let mut stmt = conn.prepare(
+            "SELECT dt.disc_id, t.name FROM disc_tags dt JOIN tags t ON t.id = dt.tag_id
+             ORDER BY dt.disc_id, t.name",
+        )?;
+        let mut tags: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?;
+        for row in rows {
+            let (disc_id, tag) = row?;
+            tags.entry(disc_id).or_default().push(tag);
+        }
+        Ok(tags)
+    }
+
     /// List all discs.
     pub fn list_all(conn: &Connection) -> Result<Vec<Disc>> {
         let mut stmt = conn.prepare(
-            "SELECT disc_id, volume_label, created_at, notes, iso_size, burn_device,
-                    checksum_manifest_hash, qr_path, source_roots, tool_version, set_id, sequence_number
-             FROM discs ORDER BY created_at DESC",
+            &format!("SELECT {} FROM discs ORDER BY created_at DESC", Disc::COLUMNS),
         )?;
 
-        let discs = stmt.query_map([], |row| {
-            Ok(Disc {
-                disc_id: row.get(0)?,
-                volume_label: row.get(1)?,
-                created_at: row.get(2)?,
-                notes: row.get(3)?,
-                iso_size: row.get(4)?,
-                burn_device: row.get(5)?,
-                checksum_manifest_hash: row.get(6)?,
-                qr_path: row.get(7)?,
-                source_roots: row.get(8)?,
-                tool_version: row.get(9)?,
-                set_id: row.get(10)?,
-                sequence_number: row.get(11)?,
-            })
-        })?;
+        let discs = stmt.query_map([], Disc::from_row)?;
 
         let mut result = Vec::new();
         for disc in discs {
@@ -597,46 +1820,64 @@ impl Disc {
 }
 
 /// File record structure
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileRecord {
+    /// Row ID, local to one database and so left out of exports
+    #[serde(skip)]
     pub id: Option<i64>,
     pub disc_id: String,
     pub rel_path: String,
+    /// Empty for symlinks and for files burned with CRC32 checksums
     pub sha256: String,
     pub size: u64,
     pub mtime: String,
     pub added_at: String,
+    /// Checksum of files burned with CRC32 checksums
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crc32: Option<String>,
 }
 
 impl FileRecord {
+    /// The catalogued checksum and its algorithm: the SHA-256, else the
+    /// CRC32. None for symlinks, which have no checksum.
+    pub fn checksum(&self) -> Option<(ChecksumAlgorithm, &str)> {
+        if !self.sha256.is_empty() {
+            Some((ChecksumAlgorithm::Sha256, &self.sha256))
+        } else {
+            self.crc32.as_deref().map(|crc32| (ChecksumAlgorithm::Crc32, crc32))
+        }
+    }
+
     /// Insert a file record.
     pub fn insert(conn: &Connection, file: &FileRecord) -> Result<()> {
         conn.execute(
-            "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at, crc32)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
              ON CONFLICT(disc_id, rel_path) DO UPDATE SET
-                sha256 = ?3, size = ?4, mtime = ?5, added_at = ?6",
+                sha256 = ?3, size = ?4, mtime = ?5, added_at = ?6, crc32 = ?7",
             params![
                 file.disc_id,
                 file.rel_path,
                 file.sha256,
                 file.size,
                 file.mtime,
-                file.added_at
+                file.added_at,
+                file.crc32
             ],
         )?;
         Ok(())
     }
 
     /// Insert multiple file records in a transaction.
+    /// The full-text path index is populated by triggers on the files table.
     pub fn insert_batch(conn: &mut Connection, files: &[FileRecord]) -> Result<()> {
         let tx = conn.transaction()?;
         {
             let mut stmt = tx.prepare(
-                "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at, crc32)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
                  ON CONFLICT(disc_id, rel_path) DO UPDATE SET
-                    sha256 = ?3, size = ?4, mtime = ?5, added_at = ?6",
+                    sha256 = ?3, size = ?4, mtime = ?5, added_at = ?6, crc32 = ?7",
             )?;
 
             for file in files {
@@ -646,13 +1887,55 @@ impl FileRecord {
                     file.sha256,
                     file.size,
                     file.mtime,
-                    file.added_at
+                    file.added_at,
+                    file.crc32
                 ])?;
             }
         }
         tx.commit()?;
         Ok(())
     }
+
+    /// List all file records for a disc, ordered by path.
+    pub fn list_for_disc(conn: &Connection, disc_id: &str) -> Result<Vec<FileRecord>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, disc_id, rel_path, sha256, size, mtime, added_at, crc32
+             FROM files WHERE disc_id = ?1 ORDER BY rel_path",
+        )?;
+
+        let files = stmt.query_map(params![disc_id], |row| {
+            Ok(FileRecord {
+                id: row.get(0)?,
+                disc_id: row.get(1)?,
+                rel_path: row.get(2)?,
+                sha256: row.get(3)?,
+                size: row.get(4)?,
+                mtime: row.get(5)?,
+                added_at: row.get(6)?,
+                crc32: row.get(7)?,
+            })
+        })?;
+
+        let mut result = Vec::new();
+        for file in files {
+            result.push(file?);
+        }
+        Ok(result)
+    }
+
+    /// Burned discs already holding `rel_path` with this size and a real SHA-256: (disc_id, sha256).
+    /// Discs planned by a dry run hold nothing yet.
+    pub fn find_archived(conn: &Connection, rel_path: &str, size: u64) -> Result<Vec<(String, String)>> {
+        let mut stmt = conn.prepare_cached(
+            "SELECT f.disc_id, f.sha256 FROM files f JOIN discs d ON d.disc_id = f.disc_id
+             WHERE f.rel_path = ?1 AND f.size = ?2 AND f.sha256 != '' AND d.planned = 0
+             ORDER BY f.disc_id",
+        )?;
+        let rows = stmt
+            .query_map(params![rel_path, size], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(rows)
+    }
 }
 
 /// Verification run record
@@ -667,6 +1950,8 @@ pub struct VerificationRun {
     pub error_message: Option<String>,
     pub files_checked: Option<u32>,
     pub files_failed: Option<u32>,
+    /// Only a random sample of the files was hashed; not a full verification
+    pub sampled: bool,
 }
 
 impl VerificationRun {
@@ -675,8 +1960,8 @@ impl VerificationRun {
         conn.execute(
             "INSERT INTO verification_runs (
                 disc_id, verified_at, mountpoint, device, success,
-                error_message, files_checked, files_failed
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                error_message, files_checked, files_failed, sampled
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
             params![
                 run.disc_id,
                 run.verified_at,
@@ -685,13 +1970,76 @@ impl VerificationRun {
                 if run.success { 1 } else { 0 },
                 run.error_message,
                 run.files_checked,
-                run.files_failed
+                run.files_failed,
+                run.sampled
             ],
         )?;
         Ok(conn.last_insert_rowid())
     }
 }
 
+/// Outcome of verifying every disc of a set in one go
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetVerificationRun {
+    pub id: Option<i64>,
+    pub set_id: String,
+    pub verified_at: String,
+    /// Every disc was read and passed
+    pub success: bool,
+    pub total_discs: u32,
+    pub discs_verified: u32,
+    pub discs_failed: u32,
+    pub discs_missing: u32,
+}
+
+impl SetVerificationRun {
+    pub fn insert(conn: &Connection, run: &SetVerificationRun) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO set_verification_runs (
+                set_id, verified_at, success, total_discs, discs_verified, discs_failed, discs_missing
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                run.set_id,
+                run.verified_at,
+                run.success,
+                run.total_discs,
+                run.discs_verified,
+                run.discs_failed,
+                run.discs_missing
+            ],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// The most recent run for `set_id`, if it was ever verified as a set.
+    pub fn latest_for_set(conn: &Connection, set_id: &str) -> Result<Option<SetVerificationRun>> {
+        let run = conn.query_row(
+            "SELECT id, set_id, verified_at, success, total_discs, discs_verified, discs_failed, discs_missing
+             FROM set_verification_runs WHERE set_id = ?1
+             ORDER BY verified_at DESC, id DESC LIMIT 1",
+            params![set_id],
+            |row| {
+                Ok(SetVerificationRun {
+                    id: row.get(0)?,
+                    set_id: row.get(1)?,
+                    verified_at: row.get(2)?,
+                    success: row.get(3)?,
+                    total_discs: row.get(4)?,
+                    discs_verified: row.get(5)?,
+                    discs_failed: row.get(6)?,
+                    discs_missing: row.get(7)?,
+                })
+            },
+        );
+
+        match run {
+            Ok(run) => Ok(Some(run)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -709,41 +2057,395 @@ mod tests {
             .query_map([], |row| row.get(0))?
             .collect::<Result<_, _>>()?;
 
-        assert!(tables.contains(&"discs".to_string()));
-        assert!(tables.contains(&"files".to_string()));
-        assert!(tables.contains(&"verification_runs".to_string()));
+        assert!(tables.contains(&"discs".to_string()));
+        assert!(tables.contains(&"files".to_string()));
+        assert!(tables.contains(&"verification_runs".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fts_index_tracks_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let db_path = temp_dir.path().join("test.db");
+        let mut conn = init_database(&db_path)?;
+        assert!(fts_available(&conn));
+
+        let disc = Disc {
+            disc_id: "2024-BD-001".to_string(),
+            volume_label: "BDARCHIVE_2024_BD_001".to_string(),
+            created_at: "2024-01-15T10:30:00Z".to_string(),
+            notes: None,
+            iso_size: None,
+            burn_device: None,
+            checksum_manifest_hash: None,
+            qr_path: None,
+            source_roots: None,
+            tool_version: None,
+            set_id: None,
+            sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
+        };
+        Disc::insert(&mut conn, &disc)?;
+
+        let file = FileRecord {
+            id: None,
+            disc_id: "2024-BD-001".to_string(),
+            rel_path: "ARCHIVE/photos/holiday.jpg".to_string(),
+            sha256: "abc".to_string(),
+            size: 1,
+            mtime: "2024-01-01T00:00:00Z".to_string(),
+            added_at: "2024-01-01T00:00:00Z".to_string(),
+            crc32: None,
+        };
+        FileRecord::insert_batch(&mut conn, &[file])?;
+
+        let count = |conn: &Connection| -> Result<i64> {
+            Ok(conn.query_row(
+                "SELECT COUNT(*) FROM files_fts WHERE files_fts MATCH 'holiday'",
+                [],
+                |row| row.get(0),
+            )?)
+        };
+        assert_eq!(count(&conn)?, 1);
+
+        conn.execute("DELETE FROM files WHERE disc_id = '2024-BD-001'", [])?;
+        assert_eq!(count(&conn)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_files_for_disc() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        for disc_id in ["2024-BD-001", "2024-BD-002"] {
+            conn.execute(
+                "INSERT INTO discs (disc_id, volume_label, created_at) VALUES (?1, ?1, '2024-01-01T00:00:00Z')",
+                [disc_id],
+            )?;
+        }
+        let file = |disc_id: &str, rel_path: &str| FileRecord {
+            id: None,
+            disc_id: disc_id.to_string(),
+            rel_path: rel_path.to_string(),
+            sha256: "abc".to_string(),
+            size: 1,
+            mtime: "2024-01-01T00:00:00Z".to_string(),
+            added_at: "2024-01-01T00:00:00Z".to_string(),
+            crc32: None,
+        };
+        FileRecord::insert_batch(
+            &mut conn,
+            &[
+                file("2024-BD-001", "ARCHIVE/photos/holiday.jpg"),
+                file("2024-BD-002", "ARCHIVE/other.txt"),
+                file("2024-BD-001", "ARCHIVE/docs/tax.pdf"),
+            ],
+        )?;
+
+        // Only the requested disc, ordered by path
+        let listed: Vec<String> = FileRecord::list_for_disc(&conn, "2024-BD-001")?.into_iter().map(|f| f.rel_path).collect();
+        assert_eq!(listed, vec!["ARCHIVE/docs/tax.pdf", "ARCHIVE/photos/holiday.jpg"]);
+        assert!(FileRecord::list_for_disc(&conn, "2024-BD-999")?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_disc_insert_and_get() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let db_path = temp_dir.path().join("test.db");
+        let mut conn = init_database(&db_path)?;
+
+        let disc = Disc {
+            disc_id: "2024-BD-001".to_string(),
+            volume_label: "BDARCHIVE_2024_BD_001".to_string(),
+            created_at: "2024-01-15T10:30:00Z".to_string(),
+            notes: Some("Test disc".to_string()),
+            iso_size: Some(1024),
+            burn_device: Some("/dev/sr0".to_string()),
+            checksum_manifest_hash: None,
+            qr_path: None,
+            source_roots: None,
+            tool_version: None,
+            set_id: None,
+            sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
+        };
+
+        Disc::insert(&mut conn, &disc)?;
+        let retrieved = Disc::get(&conn, "2024-BD-001")?;
+
+        assert!(retrieved.is_some());
+        let d = retrieved.unwrap();
+        assert_eq!(d.disc_id, "2024-BD-001");
+        assert_eq!(d.notes, Some("Test disc".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_disc_update_notes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        Disc::insert(
+            &mut conn,
+            &Disc {
+                disc_id: "2024-BD-001".to_string(),
+                volume_label: "BDARCHIVE_2024_BD_001".to_string(),
+                created_at: "2024-01-15T10:30:00Z".to_string(),
+                notes: Some("Test disc".to_string()),
+                iso_size: None,
+                burn_device: None,
+                checksum_manifest_hash: None,
+                qr_path: None,
+                source_roots: None,
+                tool_version: None,
+                set_id: None,
+                sequence_number: None,
+                burn_duration_secs: None,
+                avg_write_speed_mbps: None,
+                is_mdisc: false,
+                storage_location: None,
+                planned: false,
+            },
+        )?;
+
+        Disc::update_notes(&conn, "2024-BD-001", Some("Tax records 2019-2021"))?;
+        let d = Disc::get(&conn, "2024-BD-001")?.unwrap();
+        assert_eq!(d.notes, Some("Tax records 2019-2021".to_string()));
+
+        Disc::update_notes(&conn, "2024-BD-001", None)?;
+        assert_eq!(Disc::get(&conn, "2024-BD-001")?.unwrap().notes, None);
+
+        // Unknown discs are reported rather than silently ignored
+        assert!(Disc::update_notes(&conn, "2024-BD-999", None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_disc_record_burn_stats() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        Disc::insert(
+            &mut conn,
+            &Disc {
+                disc_id: "2024-BD-001".to_string(),
+                volume_label: "BDARCHIVE_2024_BD_001".to_string(),
+                created_at: "2024-01-15T10:30:00Z".to_string(),
+                notes: None,
+                iso_size: None,
+                burn_device: None,
+                checksum_manifest_hash: None,
+                qr_path: None,
+                source_roots: None,
+                tool_version: None,
+                set_id: None,
+                sequence_number: None,
+                burn_duration_secs: None,
+                avg_write_speed_mbps: None,
+                is_mdisc: false,
+                storage_location: None,
+                planned: false,
+            },
+        )?;
+
+        let d = Disc::get(&conn, "2024-BD-001")?.unwrap();
+        assert_eq!(d.burn_duration_secs, None);
+        assert_eq!(d.avg_write_speed_mbps, None);
+
+        Disc::record_burn_stats(&conn, "2024-BD-001", 1500.0, 16.5)?;
+        let d = Disc::get(&conn, "2024-BD-001")?.unwrap();
+        assert_eq!(d.burn_duration_secs, Some(1500.0));
+        assert_eq!(d.avg_write_speed_mbps, Some(16.5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_disc_storage_location() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        Disc::insert(
+            &mut conn,
+            &Disc {
+                disc_id: "2024-BD-001".to_string(),
+                volume_label: "BDARCHIVE_2024_BD_001".to_string(),
+                created_at: "2024-01-15T10:30:00Z".to_string(),
+                notes: None,
+                iso_size: None,
+                burn_device: None,
+                checksum_manifest_hash: None,
+                qr_path: None,
+                source_roots: None,
+                tool_version: None,
+                set_id: None,
+                sequence_number: None,
+                burn_duration_secs: None,
+                avg_write_speed_mbps: None,
+                is_mdisc: false,
+                storage_location: None,
+                planned: false,
+            },
+        )?;
+
+        assert_eq!(Disc::get(&conn, "2024-BD-001")?.unwrap().storage_location, None);
+
+        Disc::set_storage_location(&conn, "2024-BD-001", Some("Binder A, Slot 12"))?;
+        let d = Disc::get(&conn, "2024-BD-001")?.unwrap();
+        assert_eq!(d.storage_location.as_deref(), Some("Binder A, Slot 12"));
+
+        // Lookup ignores case and surrounding whitespace
+        let shelf = discs_by_location(&conn, " binder a, slot 12 ")?;
+        assert_eq!(shelf.len(), 1);
+        assert_eq!(shelf[0].disc_id, "2024-BD-001");
+        assert!(discs_by_location(&conn, "Offsite box 3")?.is_empty());
+
+        assert!(Disc::set_storage_location(&conn, "2024-BD-999", None).is_err());
 
         Ok(())
     }
 
     #[test]
-    fn test_disc_insert_and_get() -> Result<()> {
+    fn test_disc_mdisc_flag() -> Result<()> {
         let temp_dir = TempDir::new()?;
-        let db_path = temp_dir.path().join("test.db");
-        let mut conn = init_database(&db_path)?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        Disc::insert(
+            &mut conn,
+            &Disc {
+                disc_id: "2024-BD-001".to_string(),
+                volume_label: "BDARCHIVE_2024_BD_001".to_string(),
+                created_at: "2024-01-15T10:30:00Z".to_string(),
+                notes: None,
+                iso_size: None,
+                burn_device: None,
+                checksum_manifest_hash: None,
+                qr_path: None,
+                source_roots: None,
+                tool_version: None,
+                set_id: None,
+                sequence_number: None,
+                burn_duration_secs: None,
+                avg_write_speed_mbps: None,
+                is_mdisc: true,
+                storage_location: None,
+                planned: false,
+            },
+        )?;
 
-        let disc = Disc {
+        assert!(Disc::get(&conn, "2024-BD-001")?.unwrap().is_mdisc);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_burn_replaces_planned_disc() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let file = |rel_path: &str| FileRecord {
+            id: None,
+            disc_id: "2024-BD-001".to_string(),
+            rel_path: rel_path.to_string(),
+            sha256: "a".repeat(64),
+            size: 1,
+            mtime: "2024-01-01T00:00:00Z".to_string(),
+            added_at: "2024-01-01T00:00:00Z".to_string(),
+            crc32: None,
+        };
+        let disc = |planned: bool| Disc {
             disc_id: "2024-BD-001".to_string(),
             volume_label: "BDARCHIVE_2024_BD_001".to_string(),
             created_at: "2024-01-15T10:30:00Z".to_string(),
-            notes: Some("Test disc".to_string()),
-            iso_size: Some(1024),
-            burn_device: Some("/dev/sr0".to_string()),
+            notes: None,
+            iso_size: None,
+            burn_device: None,
             checksum_manifest_hash: None,
             qr_path: None,
             source_roots: None,
             tool_version: None,
             set_id: None,
             sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned,
         };
 
-        Disc::insert(&mut conn, &disc)?;
-        let retrieved = Disc::get(&conn, "2024-BD-001")?;
+        // Dry run with the disc ID, then the real burn with the same ID
+        Disc::insert(&mut conn, &disc(true))?;
+        FileRecord::insert_batch(&mut conn, &[file("ARCHIVE/dropped.txt"), file("ARCHIVE/kept.txt")])?;
+        assert!(crate::disc::find_existing_disc_ids(&conn, &["2024-bd-001".to_string()])?.is_empty());
+        assert!(FileRecord::find_archived(&conn, "ARCHIVE/kept.txt", 1)?.is_empty());
+
+        Disc::insert(&mut conn, &disc(false))?;
+        FileRecord::insert_batch(&mut conn, &[file("ARCHIVE/kept.txt")])?;
+        assert!(!Disc::get(&conn, "2024-BD-001")?.expect("burned disc").planned);
+        let files: Vec<String> = FileRecord::list_for_disc(&conn, "2024-BD-001")?.into_iter().map(|f| f.rel_path).collect();
+        assert_eq!(files, vec!["ARCHIVE/kept.txt"]);
+        assert_eq!(crate::disc::find_existing_disc_ids(&conn, &["2024-BD-001".to_string()])?, vec!["2024-BD-001"]);
+        assert_eq!(FileRecord::find_archived(&conn, "ARCHIVE/kept.txt", 1)?.len(), 1);
+
+        // A burned disc is never replaced
+        assert!(Disc::insert(&mut conn, &disc(false)).is_err());
+        Ok(())
+    }
 
-        assert!(retrieved.is_some());
-        let d = retrieved.unwrap();
-        assert_eq!(d.disc_id, "2024-BD-001");
-        assert_eq!(d.notes, Some("Test disc".to_string()));
+    #[test]
+    fn test_disc_tags() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        for disc_id in ["2024-BD-001", "2024-BD-002"] {
+            Disc::insert(
+                &mut conn,
+                &Disc {
+                    disc_id: disc_id.to_string(),
+                    volume_label: disc_id.to_string(),
+                    created_at: "2024-01-15T10:30:00Z".to_string(),
+                    notes: None,
+                    iso_size: None,
+                    burn_device: None,
+                    checksum_manifest_hash: None,
+                    qr_path: None,
+                    source_roots: None,
+                    tool_version: None,
+                    set_id: None,
+                    sequence_number: None,
+                    burn_duration_secs: None,
+                    avg_write_speed_mbps: None,
+                    is_mdisc: false,
+                    storage_location: None,
+                    planned: false,
+                },
+            )?;
+        }
+
+        Disc::add_tag(&conn, "2024-BD-001", "#Photos")?;
+        Disc::add_tag(&conn, "2024-BD-001", "offsite")?;
+        Disc::add_tag(&conn, "2024-BD-001", "photos")?;
+        Disc::add_tag(&conn, "2024-BD-002", "#photos")?;
+        assert_eq!(Disc::tags_for(&conn, "2024-BD-001")?, vec!["offsite", "photos"]);
+        assert_eq!(Disc::all_tags(&conn)?.len(), 2);
+
+        assert!(Disc::add_tag(&conn, "2024-BD-001", "#").is_err());
+        assert!(Disc::add_tag(&conn, "2024-BD-999", "photos").is_err());
+
+        assert!(Disc::remove_tag(&conn, "2024-BD-001", "#offsite")?);
+        assert!(!Disc::remove_tag(&conn, "2024-BD-001", "offsite")?);
+        assert_eq!(Disc::tags_for(&conn, "2024-BD-001")?, vec!["photos"]);
+        // Unused tags are dropped
+        let tag_count: i64 = conn.query_row("SELECT COUNT(*) FROM tags", [], |row| row.get(0))?;
+        assert_eq!(tag_count, 1);
 
         Ok(())
     }
@@ -778,6 +2480,11 @@ mod tests {
             tool_version: None,
             set_id: None,
             sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
         };
 
         let mut disc2 = Disc {
@@ -793,6 +2500,11 @@ mod tests {
             tool_version: None,
             set_id: None,
             sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
         };
 
         // Add discs to the set
@@ -822,6 +2534,601 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_delete_set() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let set_id = MultiDiscOps::create_disc_set(&mut conn, "Aborted", None, 100, 3, None)?;
+        for sequence in 1..=2 {
+            let mut disc = Disc {
+                disc_id: format!("SET-{}", sequence),
+                volume_label: format!("BDARCHIVE_SET_{}", sequence),
+                created_at: "2024-01-15T10:30:00Z".to_string(),
+                notes: None,
+                iso_size: None,
+                burn_device: None,
+                checksum_manifest_hash: None,
+                qr_path: None,
+                source_roots: None,
+                tool_version: None,
+                set_id: None,
+                sequence_number: None,
+                burn_duration_secs: None,
+                avg_write_speed_mbps: None,
+                is_mdisc: false,
+                storage_location: None,
+                planned: false,
+            };
+            MultiDiscOps::add_disc_to_set(&mut conn, &mut disc, &set_id, sequence)?;
+            FileRecord::insert(
+                &conn,
+                &FileRecord {
+                    id: None,
+                    disc_id: disc.disc_id.clone(),
+                    rel_path: "ARCHIVE/a.txt".to_string(),
+                    sha256: "00".repeat(32),
+                    size: 1,
+                    mtime: "2024-01-01T00:00:00Z".to_string(),
+                    added_at: "2024-01-15T10:30:00Z".to_string(),
+                    crc32: None,
+                },
+            )?;
+        }
+        let session = BurnSession::new(set_id.clone(), "SET".to_string(), 3, vec![], "{}".to_string());
+        session.save(&conn)?;
+
+        let (disc_ids, files) = MultiDiscOps::delete_set(&mut conn, &set_id)?;
+        assert_eq!(disc_ids, vec!["SET-1", "SET-2"]);
+        assert_eq!(files, 2);
+        assert!(DiscSet::get(&conn, &set_id)?.is_none());
+        assert!(Disc::get(&conn, "SET-1")?.is_none());
+        assert!(BurnSession::load(&conn, &session.session_id)?.is_none());
+        let remaining: i64 = conn.query_row("SELECT COUNT(*) FROM files", [], |row| row.get(0))?;
+        assert_eq!(remaining, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recent_sources() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+        assert!(recent_sources(&conn)?.is_empty());
+
+        record_recent_sources(&conn, &[PathBuf::from("/photos"), PathBuf::from("/music")])?;
+        record_recent_sources(&conn, &[PathBuf::from("/docs")])?;
+        assert_eq!(recent_sources(&conn)?, ["/docs", "/photos", "/music"].map(PathBuf::from));
+
+        // Reusing a folder moves it to the top instead of listing it twice
+        record_recent_sources(&conn, &[PathBuf::from("/music")])?;
+        assert_eq!(recent_sources(&conn)?, ["/music", "/docs", "/photos"].map(PathBuf::from));
+
+        // Only the newest are kept
+        let many: Vec<PathBuf> = (0..RECENT_SOURCES_LIMIT + 2).map(|i| PathBuf::from(format!("/src{}", i))).collect();
+        record_recent_sources(&conn, &many)?;
+        assert_eq!(recent_sources(&conn)?, many[..RECENT_SOURCES_LIMIT]);
+        let stored: i64 = conn.query_row("SELECT COUNT(*) FROM recent_sources", [], |row| row.get(0))?;
+        assert_eq!(stored as usize, RECENT_SOURCES_LIMIT);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_progress() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let set_id = MultiDiscOps::create_disc_set(&mut conn, "Partial", None, 100, 2, None)?;
+        let add = |conn: &mut Connection, sequence: u32| {
+            let mut disc = Disc {
+                disc_id: format!("PART-{}", sequence),
+                volume_label: format!("BDARCHIVE_PART_{}", sequence),
+                created_at: "2024-01-15T10:30:00Z".to_string(),
+                notes: None,
+                iso_size: None,
+                burn_device: None,
+                checksum_manifest_hash: None,
+                qr_path: None,
+                source_roots: None,
+                tool_version: None,
+                set_id: None,
+                sequence_number: None,
+                burn_duration_secs: None,
+                avg_write_speed_mbps: None,
+                is_mdisc: false,
+                storage_location: None,
+                planned: false,
+            };
+            MultiDiscOps::add_disc_to_set(conn, &mut disc, &set_id, sequence)
+        };
+
+        add(&mut conn, 1)?;
+        let set = DiscSet::get(&conn, &set_id)?.expect("set exists");
+        assert_eq!((set.status, set.completed_count), (SetStatus::InProgress, 1));
+
+        // Cancelled after the first disc: still queryable, and not mistaken for a full set
+        DiscSet::set_status(&conn, &set_id, SetStatus::Cancelled)?;
+        let set = DiscSet::list_all(&conn)?.remove(0);
+        assert_eq!((set.status, set.completed_count), (SetStatus::Cancelled, 1));
+        assert_eq!(DiscSet::get_discs(&conn, &set_id)?.len(), 1);
+
+        // Resuming and burning the last disc completes it
+        DiscSet::set_status(&conn, &set_id, SetStatus::InProgress)?;
+        add(&mut conn, 2)?;
+        let set = DiscSet::get(&conn, &set_id)?.expect("set exists");
+        assert_eq!((set.status, set.completed_count), (SetStatus::Complete, 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_json_round_trip() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut source = init_database(&temp_dir.path().join("source.db"))?;
+        let set_id = MultiDiscOps::create_disc_set(&mut source, "Photos", None, 2048, 1, Some("/photos"))?;
+        let mut disc = Disc {
+            disc_id: "2024-BD-010".to_string(),
+            volume_label: "BDARCHIVE_2024_BD_010".to_string(),
+            created_at: "2024-03-01T09:00:00Z".to_string(),
+            notes: None,
+            iso_size: Some(2048),
+            burn_device: None,
+            checksum_manifest_hash: Some("abc".to_string()),
+            qr_path: None,
+            source_roots: None,
+            tool_version: None,
+            set_id: None,
+            sequence_number: None,
+            burn_duration_secs: Some(60.0),
+            avg_write_speed_mbps: None,
+            is_mdisc: true,
+            storage_location: Some("Shelf 2".to_string()),
+            planned: false,
+        };
+        MultiDiscOps::add_disc_to_set(&mut source, &mut disc, &set_id, 1)?;
+        for name in ["a.jpg", "b.jpg"] {
+            FileRecord::insert(
+                &source,
+                &FileRecord {
+                    id: None,
+                    disc_id: disc.disc_id.clone(),
+                    rel_path: name.to_string(),
+                    sha256: "00".repeat(32),
+                    size: 1024,
+                    mtime: "2024-02-01T00:00:00Z".to_string(),
+                    added_at: "2024-03-01T09:00:00Z".to_string(),
+                    crc32: None,
+                },
+            )?;
+        }
+
+        let export_path = temp_dir.path().join("set.json");
+        let exported = export_set_json(&source, &set_id, &export_path)?;
+        assert_eq!((exported.discs, exported.files), (1, 2));
+
+        let mut target = init_database(&temp_dir.path().join("target.db"))?;
+        let imported = import_set_json(&mut target, &export_path)?;
+        assert_eq!(imported, exported);
+        // A second import updates in place
+        import_set_json(&mut target, &export_path)?;
+
+        let set = DiscSet::get(&target, &set_id)?.expect("set imported");
+        assert_eq!(set.name, "Photos");
+        assert_eq!((set.status, set.completed_count), (SetStatus::Complete, 1));
+        let discs = DiscSet::get_discs(&target, &set_id)?;
+        assert_eq!(discs.len(), 1);
+        assert_eq!(discs[0].sequence_number, Some(1));
+        assert!(discs[0].is_mdisc);
+        assert_eq!(discs[0].storage_location.as_deref(), Some("Shelf 2"));
+        let files = FileRecord::list_for_disc(&target, "2024-BD-010")?;
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].sha256, "00".repeat(32));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_burn_session_stores_plans() -> Result<()> {
+        use crate::staging::{DirectoryEntry, DiscPlan};
+
+        let temp_dir = TempDir::new()?;
+        let db_path = temp_dir.path().join("test.db");
+        let mut conn = init_database(&db_path)?;
+
+        let set_id = MultiDiscOps::create_disc_set(&mut conn, "Set", None, 300, 2, None)?;
+
+        let mut plan1 = DiscPlan::new(1, 200);
+        plan1.add_entry(DirectoryEntry {
+            path: std::path::PathBuf::from("/src/a.bin"),
+            size_bytes: 150,
+            is_file: true,
+            children: Vec::new(),
+        });
+        let mut plan2 = DiscPlan::new(2, 200);
+        plan2.add_entry(DirectoryEntry {
+            path: std::path::PathBuf::from("/src/b.bin"),
+            size_bytes: 150,
+            is_file: true,
+            children: Vec::new(),
+        });
+
+        let mut session = BurnSession::new(set_id, "2026-BD".to_string(), 2, Vec::new(), "{}".to_string());
+        assert!(session.stored_plans()?.is_none());
+        session.notes = Some("first run".to_string());
+        session.set_plans(&[plan1, plan2])?;
+        session.save(&conn)?;
+
+        let loaded = BurnSession::load(&conn, &session.session_id)?.unwrap();
+        assert_eq!(loaded.notes.as_deref(), Some("first run"));
+        let plans = loaded.stored_plans()?.unwrap();
+        assert_eq!(plans.len(), 2);
+        assert_eq!(plans[1].entries[0].path, std::path::Path::new("/src/b.bin"));
+        assert_eq!(plans[1].used_bytes, 150);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pause_active_sessions() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let set_id = MultiDiscOps::create_disc_set(&mut conn, "Set", None, 300, 2, None)?;
+        let new_session = || BurnSession::new(set_id.clone(), "2026-BD".to_string(), 2, Vec::new(), "{}".to_string());
+
+        let active = new_session();
+        active.save(&conn)?;
+        let mut paused = new_session();
+        paused.pause(Some("staged".to_string()));
+        paused.save(&conn)?;
+        let mut completed = new_session();
+        completed.complete();
+        completed.save(&conn)?;
+
+        // Only the running session changes; an earlier pause keeps its staging state
+        assert_eq!(BurnSessionOps::pause_active_sessions(&conn)?, 1);
+        let status = |session: &BurnSession| -> Result<BurnSessionStatus> {
+            Ok(BurnSession::load(&conn, &session.session_id)?.unwrap().status)
+        };
+        assert_eq!(status(&active)?, BurnSessionStatus::Paused);
+        assert_eq!(status(&completed)?, BurnSessionStatus::Completed);
+        let loaded = BurnSession::load(&conn, &paused.session_id)?.unwrap();
+        assert_eq!(loaded.status, BurnSessionStatus::Paused);
+        assert_eq!(loaded.staging_state.as_deref(), Some("staged"));
+
+        assert_eq!(BurnSessionOps::pause_active_sessions(&conn)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_archive_stats() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+        assert_eq!(archive_stats(&conn)?, ArchiveStats::default());
+
+        for disc_id in ["A", "B", "C"] {
+            conn.execute(
+                "INSERT INTO discs (disc_id, volume_label, created_at) VALUES (?1, ?1, '2024-01-01T00:00:00Z')",
+                [disc_id],
+            )?;
+        }
+        for (disc_id, rel_path, size) in [("A", "ARCHIVE/a", 100), ("A", "ARCHIVE/b", 50), ("B", "ARCHIVE/c", 1000)] {
+            conn.execute(
+                "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at) VALUES (?1, ?2, 'x', ?3, '', '')",
+                params![disc_id, rel_path, size],
+            )?;
+        }
+        conn.execute(
+            "INSERT INTO disc_sets (set_id, name, total_size, disc_count, created_at) VALUES ('SET-1', 'Set', 0, 2, '')",
+            [],
+        )?;
+        // A verified OK, B only failed verification, C never verified
+        for (disc_id, success) in [("A", 1), ("B", 0)] {
+            conn.execute(
+                "INSERT INTO verification_runs (disc_id, verified_at, success) VALUES (?1, '2024-02-01T00:00:00Z', ?2)",
+                params![disc_id, success],
+            )?;
+        }
+
+        let stats = archive_stats(&conn)?;
+        assert_eq!(stats.disc_count, 3);
+        assert_eq!(stats.set_count, 1);
+        assert_eq!(stats.file_count, 3);
+        assert_eq!(stats.total_bytes, 1150);
+        assert_eq!(stats.unverified_discs, 2);
+
+        // A dry run's planned disc is never counted as burned media
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at, planned) VALUES ('D', 'D', '2024-01-01T00:00:00Z', 1)",
+            [],
+        )?;
+        conn.execute(
+            "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at) VALUES ('D', 'ARCHIVE/d', 'x', 5000, '', '')",
+            [],
+        )?;
+        assert_eq!(archive_stats(&conn)?, stats);
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+        conn.execute("INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('A', 'A', '')", [])?;
+        assert!(failed_files(&conn, "A")?.is_empty());
+
+        let failed = vec![
+            (PathBuf::from("/src/bad.mov"), "Input/output error".to_string()),
+            (PathBuf::from("/src/locked.txt"), "Permission denied".to_string()),
+        ];
+        record_failed_files(&conn, "A", &failed)?;
+        assert_eq!(
+            failed_files(&conn, "A")?,
+            vec![
+                ("/src/bad.mov".to_string(), "Input/output error".to_string()),
+                ("/src/locked.txt".to_string(), "Permission denied".to_string()),
+            ]
+        );
+
+        conn.execute("DELETE FROM discs WHERE disc_id = 'A'", [])?;
+        assert!(failed_files(&conn, "A")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_v3_catalog_without_burn_sessions() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let db_path = temp_dir.path().join("test.db");
+        {
+            // Earlier releases created fresh catalogs with the v2 tables and stamped them v3
+            let mut conn = Connection::open(&db_path)?;
+            let tx = conn.transaction()?;
+            create_schema(&tx)?;
+            set_schema_version(&tx, 3)?;
+            tx.commit()?;
+            let has_sessions: bool = conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type='table' AND name='burn_sessions')",
+                [],
+                |row| row.get(0),
+            )?;
+            assert!(!has_sessions);
+        }
+
+        let conn = init_database(&db_path)?;
+        assert_eq!(get_schema_version(&conn)?, SCHEMA_VERSION);
+        conn.query_row("SELECT COUNT(plan_json) FROM burn_sessions", [], |row| row.get::<_, i64>(0))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_crc32_column() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let db_path = temp_dir.path().join("test.db");
+        {
+            // A v16 catalog kept CRC32 checksums in files.sha256
+            let conn = init_database(&db_path)?;
+            conn.execute("ALTER TABLE files DROP COLUMN crc32", [])?;
+            conn.execute("UPDATE schema_version SET version = 16", [])?;
+            conn.execute("INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('A', 'A', '2024-01-01T00:00:00Z')", [])?;
+            for (rel_path, checksum) in [("crc.txt", "1234abcd".to_string()), ("sha.txt", "f".repeat(64))] {
+                conn.execute(
+                    "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at)
+                     VALUES ('A', ?1, ?2, 1, '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z')",
+                    params![rel_path, checksum],
+                )?;
+            }
+        }
+
+        let conn = init_database(&db_path)?;
+        let files = FileRecord::list_for_disc(&conn, "A")?;
+        assert_eq!(files[0].checksum(), Some((ChecksumAlgorithm::Crc32, "1234abcd")));
+        assert_eq!(files[0].sha256, "");
+        let sha = "f".repeat(64);
+        assert_eq!(files[1].checksum(), Some((ChecksumAlgorithm::Sha256, sha.as_str())));
+        assert_eq!(files[1].crc32, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_connection() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let db_path = temp_dir.path().join("test.db");
+        assert_eq!(stored_schema_version(&db_path), None);
+
+        let conn = init_database(&db_path)?;
+        assert_eq!(stored_schema_version(&db_path), Some(SCHEMA_VERSION));
+        assert_eq!(check_connection(&conn)?, 0);
+
+        conn.execute("UPDATE schema_version SET version = ?1", params![SCHEMA_VERSION + 1])?;
+        let err = check_connection(&conn).unwrap_err();
+        assert!(err.to_string().contains("does not match"), "{}", err);
+
+        conn.execute("DROP TABLE schema_version", [])?;
+        assert!(check_connection(&conn).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_extension_breakdown() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+        assert!(extension_breakdown(&conn, None)?.is_empty());
+
+        for (disc_id, planned) in [("A", 0), ("B", 0), ("P", 1)] {
+            conn.execute(
+                "INSERT INTO discs (disc_id, volume_label, created_at, planned) VALUES (?1, ?1, '', ?2)",
+                params![disc_id, planned],
+            )?;
+        }
+        for (disc_id, rel_path, size) in [
+            ("A", "ARCHIVE/film.mkv", 600),
+            ("A", "ARCHIVE/IMG_1.CR2", 200),
+            ("A", "ARCHIVE/README", 5),
+            ("B", "ARCHIVE/raw/IMG_2.cr2", 100),
+            ("B", "ARCHIVE/notes.txt", 10),
+            ("P", "ARCHIVE/planned.mkv", 9000),
+        ] {
+            conn.execute(
+                "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at) VALUES (?1, ?2, 'x', ?3, '', '')",
+                params![disc_id, rel_path, size],
+            )?;
+        }
+
+        assert_eq!(
+            extension_breakdown(&conn, None)?,
+            vec![
+                ("mkv".to_string(), 1, 600),
+                ("cr2".to_string(), 2, 300),
+                ("txt".to_string(), 1, 10),
+                (String::new(), 1, 5),
+            ]
+        );
+        assert_eq!(
+            extension_breakdown(&conn, Some("B"))?,
+            vec![("cr2".to_string(), 1, 100), ("txt".to_string(), 1, 10)]
+        );
+        // A planned disc's own breakdown is still available
+        assert_eq!(extension_breakdown(&conn, Some("P"))?, vec![("mkv".to_string(), 1, 9000)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_backup_and_restore() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let dir = backup_dir(&conn).expect("file-backed database");
+        assert_eq!(dir, temp_dir.path().join(BACKUP_DIR));
+
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('A', 'A', '2024-01-01T00:00:00Z')",
+            [],
+        )?;
+        let backup = rolling_backup(&conn, &dir, 2)?;
+        assert_eq!(list_backups(&dir)?, vec![backup.clone()]);
+
+        // Older backups beyond `keep` are pruned
+        for name in ["bdarchive-2020-01-01-00-00-00.db", "bdarchive-2021-01-01-00-00-00.db"] {
+            backup_to(&conn, &dir.join(name))?;
+        }
+        rolling_backup(&conn, &dir, 2)?;
+        let kept = list_backups(&dir)?;
+        assert_eq!(kept.len(), 2);
+        assert!(kept.iter().all(|p| !p.to_string_lossy().contains("2020")));
+
+        conn.execute("DELETE FROM discs", [])?;
+        restore_from(&mut conn, &backup)?;
+        assert!(Disc::get(&conn, "A")?.is_some());
+
+        std::fs::write(dir.join("bdarchive-junk.db"), "not a database")?;
+        assert!(restore_from(&mut conn, &dir.join("bdarchive-junk.db")).is_err());
+        assert!(Disc::get(&conn, "A")?.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_integrity_check_and_vacuum() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+        assert!(integrity_check(&conn)?.is_empty());
+
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('A', 'A', '2024-01-01T00:00:00Z')",
+            [],
+        )?;
+        let padding = "x".repeat(4096);
+        for i in 0..200 {
+            conn.execute(
+                "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at) VALUES ('A', ?1, ?2, 1, '', '')",
+                params![format!("ARCHIVE/{}", i), padding],
+            )?;
+        }
+        // Written as if by a connection without foreign key enforcement
+        conn.execute("PRAGMA foreign_keys = OFF", [])?;
+        conn.execute(
+            "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at) VALUES ('GONE', 'ARCHIVE/x', 'x', 1, '', '')",
+            [],
+        )?;
+        conn.execute("PRAGMA foreign_keys = ON", [])?;
+
+        assert_eq!(orphaned_file_count(&conn)?, 1);
+        let problems = integrity_check(&conn)?;
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("files row"), "{}", problems[0]);
+        assert!(table_row_counts(&conn)?.contains(&("files", 201)));
+
+        conn.execute("DELETE FROM files WHERE disc_id = 'A'", [])?;
+        let stats = vacuum(&conn)?;
+        assert!(stats.saved_bytes() > 200 * 4096 / 2, "{:?}", stats);
+        Ok(())
+    }
+
+    #[test]
+    fn test_repair_orphan_sets() -> Result<()> {
+        use crate::staging::DiscPlan;
+
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+
+        let set = |set_id: &str| DiscSet {
+            set_id: set_id.to_string(),
+            name: set_id.to_string(),
+            description: None,
+            total_size: 100,
+            disc_count: 2,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            source_roots: None,
+            status: SetStatus::InProgress,
+            completed_count: 0,
+        };
+
+        // Crashed before anything but the set row was written
+        DiscSet::insert(&conn, &set("SET-ORPHAN"))?;
+
+        // A session without a plan can't be resumed reliably
+        let unplanned = BurnSession::new("SET-NO-PLAN".to_string(), "NP".to_string(), 2, Vec::new(), "{}".to_string());
+        assert!(MultiDiscOps::create_disc_set_with_session(&mut conn, &set("SET-NO-PLAN"), &unplanned).is_err());
+        assert!(DiscSet::get(&conn, "SET-NO-PLAN")?.is_none());
+
+        // Planned but nothing burned yet: resumable, so kept
+        let mut planned = BurnSession::new("SET-PLANNED".to_string(), "P".to_string(), 2, Vec::new(), "{}".to_string());
+        planned.set_plans(&[DiscPlan::new(1, 100), DiscPlan::new(2, 100)])?;
+        MultiDiscOps::create_disc_set_with_session(&mut conn, &set("SET-PLANNED"), &planned)?;
+
+        // Has a disc: kept
+        DiscSet::insert(&conn, &set("SET-BURNED"))?;
+        let mut disc = Disc {
+            disc_id: "BURNED-01".to_string(),
+            volume_label: "BURNED_01".to_string(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            notes: None,
+            iso_size: None,
+            burn_device: None,
+            checksum_manifest_hash: None,
+            qr_path: None,
+            source_roots: None,
+            tool_version: None,
+            set_id: None,
+            sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
+        };
+        MultiDiscOps::add_disc_to_set(&mut conn, &mut disc, "SET-BURNED", 1)?;
+
+        assert_eq!(repair_orphan_sets(&mut conn)?, vec!["SET-ORPHAN".to_string()]);
+        assert!(DiscSet::get(&conn, "SET-ORPHAN")?.is_none());
+        assert!(DiscSet::get(&conn, "SET-PLANNED")?.is_some());
+        assert!(DiscSet::get(&conn, "SET-BURNED")?.is_some());
+
+        // Once the planned session is cancelled, the empty set is pruned too
+        planned.status = BurnSessionStatus::Cancelled;
+        planned.save(&conn)?;
+        assert_eq!(repair_orphan_sets(&mut conn)?, vec!["SET-PLANNED".to_string()]);
+        assert!(BurnSession::load(&conn, &planned.session_id)?.is_none());
+
+        Ok(())
+    }
 }
 
 /// Burn session states for pause/resume functionality
@@ -861,6 +3168,8 @@ pub struct BurnSession {
     pub updated_at: String,
     pub status: BurnSessionStatus,
     pub notes: Option<String>,
+    /// Serialized per-disc layout captured at plan time (JSON array of `DiscPlan`)
+    pub plan_json: Option<String>,
 }
 
 impl BurnSession {
@@ -888,6 +3197,57 @@ impl BurnSession {
             updated_at: now,
             status: BurnSessionStatus::Active,
             notes: None,
+            plan_json: None,
+        }
+    }
+
+    const COLUMNS: &'static str = "session_id, set_id, session_name, current_disc, total_discs,
+        completed_discs, failed_discs, source_folders, config_json,
+        staging_state, created_at, updated_at, status, notes, plan_json";
+
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<BurnSession> {
+        let status_str: String = row.get(12)?;
+        let status = match status_str.as_str() {
+            "active" => BurnSessionStatus::Active,
+            "paused" => BurnSessionStatus::Paused,
+            "completed" => BurnSessionStatus::Completed,
+            "cancelled" => BurnSessionStatus::Cancelled,
+            _ => BurnSessionStatus::Active,
+        };
+
+        Ok(BurnSession {
+            session_id: row.get(0)?,
+            set_id: row.get(1)?,
+            session_name: row.get(2)?,
+            current_disc: row.get::<_, u32>(3)? as usize,
+            total_discs: row.get::<_, u32>(4)? as usize,
+            completed_discs: serde_json::from_str(&row.get::<_, String>(5)?).unwrap_or_default(),
+            failed_discs: serde_json::from_str(&row.get::<_, String>(6)?).unwrap_or_default(),
+            source_folders: serde_json::from_str(&row.get::<_, String>(7)?).unwrap_or_default(),
+            config_json: row.get(8)?,
+            staging_state: row.get(9)?,
+            created_at: row.get(10)?,
+            updated_at: row.get(11)?,
+            status,
+            notes: row.get(13)?,
+            plan_json: row.get(14)?,
+        })
+    }
+
+    /// Store the exact per-disc layout so a resume burns the same content,
+    /// even if the source folders have changed since.
+    pub fn set_plans(&mut self, plans: &[crate::staging::DiscPlan]) -> Result<()> {
+        self.plan_json = Some(serde_json::to_string(plans).context("Failed to serialize disc plans")?);
+        Ok(())
+    }
+
+    /// The layout stored at plan time, if any.
+    pub fn stored_plans(&self) -> Result<Option<Vec<crate::staging::DiscPlan>>> {
+        match &self.plan_json {
+            Some(json) => Ok(Some(
+                serde_json::from_str(json).context("Failed to parse stored disc plans")?,
+            )),
+            None => Ok(None),
         }
     }
 
@@ -897,8 +3257,8 @@ impl BurnSession {
             "INSERT OR REPLACE INTO burn_sessions (
                 session_id, set_id, session_name, current_disc, total_discs,
                 completed_discs, failed_discs, source_folders, config_json,
-                staging_state, created_at, updated_at, status, notes
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                staging_state, created_at, updated_at, status, notes, plan_json
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             params![
                 &self.session_id,
                 &self.set_id,
@@ -914,6 +3274,7 @@ impl BurnSession {
                 &self.updated_at,
                 &self.status.to_string(),
                 &self.notes,
+                &self.plan_json,
             ],
         )?;
         Ok(())
@@ -921,40 +3282,12 @@ impl BurnSession {
 
     /// Load session from database
     pub fn load(conn: &Connection, session_id: &str) -> Result<Option<Self>> {
-        let mut stmt = conn.prepare(
-            "SELECT session_id, set_id, session_name, current_disc, total_discs,
-                    completed_discs, failed_discs, source_folders, config_json,
-                    staging_state, created_at, updated_at, status, notes
-             FROM burn_sessions WHERE session_id = ?"
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM burn_sessions WHERE session_id = ?",
+            Self::COLUMNS
+        ))?;
 
-        let mut rows = stmt.query_map(params![session_id], |row| {
-            let status_str: String = row.get(12)?;
-            let status = match status_str.as_str() {
-                "active" => BurnSessionStatus::Active,
-                "paused" => BurnSessionStatus::Paused,
-                "completed" => BurnSessionStatus::Completed,
-                "cancelled" => BurnSessionStatus::Cancelled,
-                _ => BurnSessionStatus::Active,
-            };
-
-            Ok(BurnSession {
-                session_id: row.get(0)?,
-                set_id: row.get(1)?,
-                session_name: row.get(2)?,
-                current_disc: row.get::<_, u32>(3)? as usize,
-                total_discs: row.get::<_, u32>(4)? as usize,
-                completed_discs: serde_json::from_str(&row.get::<_, String>(5)?).unwrap_or_default(),
-                failed_discs: serde_json::from_str(&row.get::<_, String>(6)?).unwrap_or_default(),
-                source_folders: serde_json::from_str(&row.get::<_, String>(7)?).unwrap_or_default(),
-                config_json: row.get(8)?,
-                staging_state: row.get(9)?,
-                created_at: row.get(10)?,
-                updated_at: row.get(11)?,
-                status,
-                notes: row.get(12)?,
-            })
-        })?;
+        let mut rows = stmt.query_map(params![session_id], Self::from_row)?;
 
         if let Some(session) = rows.next() {
             Ok(Some(session?))
@@ -967,8 +3300,12 @@ impl BurnSession {
     pub fn update_progress(&mut self, completed_disc: usize) {
         if !self.completed_discs.contains(&completed_disc) {
             self.completed_discs.push(completed_disc);
+            self.completed_discs.sort_unstable();
         }
-        self.current_disc = completed_disc + 1;
+        // Discs burned in parallel can finish out of order: resume from the first gap
+        self.current_disc = (1..)
+            .find(|n| !self.completed_discs.contains(n))
+            .unwrap_or(completed_disc + 1);
         self.updated_at = disc::format_timestamp_now();
     }
 
@@ -998,46 +3335,42 @@ pub struct BurnSessionOps;
 impl BurnSessionOps {
     /// Get all active/paused sessions
     pub fn get_active_sessions(conn: &Connection) -> Result<Vec<BurnSession>> {
-        let mut stmt = conn.prepare(
-            "SELECT session_id, set_id, session_name, current_disc, total_discs,
-                    completed_discs, failed_discs, source_folders, config_json,
-                    staging_state, created_at, updated_at, status, notes
-             FROM burn_sessions
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM burn_sessions
              WHERE status IN ('active', 'paused')
-             ORDER BY updated_at DESC"
-        )?;
-
-        let sessions = stmt.query_map(params![], |row| {
-            let status_str: String = row.get(12)?;
-            let status = match status_str.as_str() {
-                "active" => BurnSessionStatus::Active,
-                "paused" => BurnSessionStatus::Paused,
-                "completed" => BurnSessionStatus::Completed,
-                "cancelled" => BurnSessionStatus::Cancelled,
-                _ => BurnSessionStatus::Active,
-            };
+             ORDER BY updated_at DESC",
+            BurnSession::COLUMNS
+        ))?;
 
-            Ok(BurnSession {
-                session_id: row.get(0)?,
-                set_id: row.get(1)?,
-                session_name: row.get(2)?,
-                current_disc: row.get::<_, u32>(3)? as usize,
-                total_discs: row.get::<_, u32>(4)? as usize,
-                completed_discs: serde_json::from_str(&row.get::<_, String>(5)?).unwrap_or_default(),
-                failed_discs: serde_json::from_str(&row.get::<_, String>(6)?).unwrap_or_default(),
-                source_folders: serde_json::from_str(&row.get::<_, String>(7)?).unwrap_or_default(),
-                config_json: row.get(8)?,
-                staging_state: row.get(9)?,
-                created_at: row.get(10)?,
-                updated_at: row.get(11)?,
-                status,
-                notes: row.get(12)?,
-            })
-        })?;
+        let sessions = stmt.query_map(params![], BurnSession::from_row)?;
 
         sessions.map(|r| r.map_err(anyhow::Error::from)).collect::<Result<Vec<_>>>()
     }
 
+    /// The most recently started session of a set, whatever its status
+    pub fn latest_for_set(conn: &Connection, set_id: &str) -> Result<Option<BurnSession>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM burn_sessions WHERE set_id = ?1 ORDER BY created_at DESC LIMIT 1",
+            BurnSession::COLUMNS
+        ))?;
+        let mut rows = stmt.query_map(params![set_id], BurnSession::from_row)?;
+        rows.next().transpose().map_err(anyhow::Error::from)
+    }
+
+    /// Mark every active session as paused, so a set interrupted by quitting
+    /// can be continued from Resume Burn. Returns the number paused.
+    pub fn pause_active_sessions(conn: &Connection) -> Result<usize> {
+        let mut paused = 0;
+        for mut session in Self::get_active_sessions(conn)? {
+            if session.status == BurnSessionStatus::Active {
+                session.pause(None);
+                session.save(conn)?;
+                paused += 1;
+            }
+        }
+        Ok(paused)
+    }
+
     /// Delete a session and clean up associated data
     pub fn delete_session(conn: &Connection, session_id: &str) -> Result<()> {
         // Clean up associated staging directories if they exist
@@ -0,0 +1,253 @@
+use anyhow::{Context, Result};
+use crc32fast::Hasher;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+/// Bytes read from each end of a file for the quick hash.
+const QUICK_HASH_CHUNK: u64 = 64 * 1024;
+
+/// A file whose content is byte-identical to another file on the same disc.
+/// Only `original` is written to the disc; `path` is recorded in the dedup map.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DuplicateFile {
+    pub path: PathBuf,
+    pub original: PathBuf,
+    pub size: u64,
+}
+
+/// Bytes saved by storing each duplicate only once.
+pub fn saved_bytes(duplicates: &[DuplicateFile]) -> u64 {
+    duplicates.iter().map(|d| d.size).sum()
+}
+
+/// Find files in `files` whose content matches an earlier file.
+///
+/// Candidates are grouped by size and a quick hash of the first and last
+/// 64 KiB, then confirmed with a full byte comparison, so a quick-hash
+/// collision never drops data. Within each group the first path in sorted
+/// order is kept as the original. Empty files are never deduplicated.
+pub fn find_duplicates(files: &[PathBuf]) -> Result<Vec<DuplicateFile>> {
+    let mut by_size: BTreeMap<u64, Vec<&PathBuf>> = BTreeMap::new();
+    for path in files {
+        let size = fs::metadata(path)
+            .with_context(|| format!("Failed to read metadata for: {}", path.display()))?
+            .len();
+        if size > 0 {
+            by_size.entry(size).or_default().push(path);
+        }
+    }
+
+    let mut duplicates = Vec::new();
+    for (size, mut paths) in by_size.into_iter().filter(|(_, paths)| paths.len() > 1) {
+        paths.sort();
+
+        let mut by_quick_hash: BTreeMap<u32, Vec<&PathBuf>> = BTreeMap::new();
+        for path in paths {
+            by_quick_hash.entry(quick_hash(path, size)?).or_default().push(path);
+        }
+
+        for candidates in by_quick_hash.into_values().filter(|paths| paths.len() > 1) {
+            // Originals found so far in this group; each candidate joins the first it matches
+            let mut originals: Vec<&PathBuf> = Vec::new();
+            for path in candidates {
+                let mut matched = None;
+                for original in &originals {
+                    if files_identical(original, path)? {
+                        matched = Some(*original);
+                        break;
+                    }
+                }
+                match matched {
+                    Some(original) => duplicates.push(DuplicateFile {
+                        path: path.clone(),
+                        original: original.clone(),
+                        size,
+                    }),
+                    None => originals.push(path),
+                }
+            }
+        }
+    }
+
+    duplicates.sort_by(|a, b| a.path.cmp(&b.path));
+    debug!("Found {} duplicate files ({} bytes)", duplicates.len(), saved_bytes(&duplicates));
+    Ok(duplicates)
+}
+
+/// Remove duplicate files from a staged tree so only one copy goes on the disc.
+///
+/// Returned paths are relative to `base` (the disc root), matching the
+/// manifest. Symlinks are left alone.
+pub fn remove_staged_duplicates(dir: &Path, base: &Path) -> Result<Vec<DuplicateFile>> {
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(dir) {
+        let entry = entry.with_context(|| format!("Failed to walk {}", dir.display()))?;
+        if entry.file_type().is_file() {
+            files.push(entry.into_path());
+        }
+    }
+
+    let mut duplicates = find_duplicates(&files)?;
+    for duplicate in &mut duplicates {
+        fs::remove_file(&duplicate.path)
+            .with_context(|| format!("Failed to remove duplicate: {}", duplicate.path.display()))?;
+        duplicate.path = crate::paths::make_relative(&duplicate.path, base)?;
+        duplicate.original = crate::paths::make_relative(&duplicate.original, base)?;
+    }
+
+    if !duplicates.is_empty() {
+        info!(
+            "Deduplicated {} staged files, saving {} bytes",
+            duplicates.len(),
+            saved_bytes(&duplicates)
+        );
+    }
+    Ok(duplicates)
+}
+
+/// File name of the dedup map written to discs that have no manifest.json.
+pub const DEDUP_MAP_FILE: &str = "DEDUP_MAP.txt";
+
+/// Write a dedup map, one `path => stored copy` line per duplicate.
+pub fn write_dedup_map(map_path: &Path, duplicates: &[DuplicateFile]) -> Result<()> {
+    let mut map = String::new();
+    for duplicate in duplicates {
+        map.push_str(&format!(
+            "{} => {}\n",
+            duplicate.path.to_string_lossy(),
+            duplicate.original.to_string_lossy()
+        ));
+    }
+    fs::write(map_path, map).with_context(|| format!("Failed to write dedup map: {}", map_path.display()))
+}
+
+/// CRC32 of the file size plus its first and last 64 KiB.
+fn quick_hash(path: &Path, size: u64) -> Result<u32> {
+    let mut file = fs::File::open(path).with_context(|| format!("Failed to open file: {}", path.display()))?;
+    let mut hasher = Hasher::new();
+    hasher.update(&size.to_le_bytes());
+
+    let mut buffer = Vec::with_capacity(QUICK_HASH_CHUNK as usize);
+    file.by_ref().take(QUICK_HASH_CHUNK).read_to_end(&mut buffer)?;
+    hasher.update(&buffer);
+
+    if size > QUICK_HASH_CHUNK {
+        buffer.clear();
+        file.seek(SeekFrom::Start(size.saturating_sub(QUICK_HASH_CHUNK).max(QUICK_HASH_CHUNK)))?;
+        file.read_to_end(&mut buffer)?;
+        hasher.update(&buffer);
+    }
+
+    Ok(hasher.finalize())
+}
+
+/// Compare two files byte by byte.
+fn files_identical(a: &Path, b: &Path) -> Result<bool> {
+    let mut file_a = fs::File::open(a).with_context(|| format!("Failed to open file: {}", a.display()))?;
+    let mut file_b = fs::File::open(b).with_context(|| format!("Failed to open file: {}", b.display()))?;
+    let mut buf_a = vec![0u8; 256 * 1024];
+    let mut buf_b = vec![0u8; 256 * 1024];
+
+    loop {
+        let read_a = read_full(&mut file_a, &mut buf_a)?;
+        let read_b = read_full(&mut file_b, &mut buf_b)?;
+        if read_a != read_b || buf_a[..read_a] != buf_b[..read_b] {
+            return Ok(false);
+        }
+        if read_a == 0 {
+            return Ok(true);
+        }
+    }
+}
+
+/// Fill `buf` as far as the file allows; returns the bytes read.
+fn read_full(file: &mut fs::File, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match file.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_duplicates() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("b"))?;
+        fs::write(root.join("a.jpg"), "same content")?;
+        fs::write(root.join("b/copy.jpg"), "same content")?;
+        fs::write(root.join("b/other.jpg"), "diff content")?;
+        fs::write(root.join("empty1"), "")?;
+        fs::write(root.join("empty2"), "")?;
+
+        let files: Vec<PathBuf> = ["b/copy.jpg", "a.jpg", "b/other.jpg", "empty1", "empty2"]
+            .iter()
+            .map(|p| root.join(p))
+            .collect();
+        let duplicates = find_duplicates(&files)?;
+
+        assert_eq!(
+            duplicates,
+            vec![DuplicateFile {
+                path: root.join("b/copy.jpg"),
+                original: root.join("a.jpg"),
+                size: 12,
+            }]
+        );
+        assert_eq!(saved_bytes(&duplicates), 12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_quick_hash_collision_is_not_a_duplicate() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        // Same size, same first and last 64 KiB, different middle
+        let size = 3 * QUICK_HASH_CHUNK as usize;
+        let a = vec![7u8; size];
+        let mut b = a.clone();
+        b[size / 2] = 8;
+        fs::write(root.join("a.bin"), &a)?;
+        fs::write(root.join("b.bin"), &b)?;
+        fs::write(root.join("c.bin"), &a)?;
+
+        let files = vec![root.join("a.bin"), root.join("b.bin"), root.join("c.bin")];
+        assert_eq!(quick_hash(&files[0], size as u64)?, quick_hash(&files[1], size as u64)?);
+
+        let duplicates = find_duplicates(&files)?;
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].path, root.join("c.bin"));
+        assert_eq!(duplicates[0].original, root.join("a.bin"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_staged_duplicates() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let disc_root = temp_dir.path();
+        let archive = disc_root.join("ARCHIVE/photos");
+        fs::create_dir_all(&archive)?;
+        fs::write(archive.join("1.jpg"), "pixels")?;
+        fs::write(archive.join("2.jpg"), "pixels")?;
+
+        let duplicates = remove_staged_duplicates(&disc_root.join("ARCHIVE"), disc_root)?;
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].path, PathBuf::from("ARCHIVE/photos/2.jpg"));
+        assert_eq!(duplicates[0].original, PathBuf::from("ARCHIVE/photos/1.jpg"));
+        assert!(archive.join("1.jpg").exists());
+        assert!(!archive.join("2.jpg").exists());
+        Ok(())
+    }
+}
@@ -16,7 +16,7 @@ pub const REQUIRED_COMMANDS: &[&str] = &[
 ];
 
 /// Optional dependencies.
-pub const OPTIONAL_COMMANDS: &[&str] = &["qrencode", "rsync", "mc"];
+pub const OPTIONAL_COMMANDS: &[&str] = &["qrencode", "zbarimg", "rsync", "mc", "udisksctl", "xdg-open"];
 
 /// Check all dependencies and return missing required ones.
 pub fn check_dependencies() -> DependencyStatus {
@@ -92,8 +92,11 @@ fn installation_hint(command: &str) -> Option<&'static str> {
         "mount" => Some("Usually included in util-linux, try: sudo apt install util-linux"),
         "umount" => Some("Usually included in util-linux, try: sudo apt install util-linux"),
         "qrencode" => Some("sudo apt install qrencode (Debian/Ubuntu) or sudo dnf install qrencode (Fedora/RHEL)"),
+        "zbarimg" => Some("sudo apt install zbar-tools (Debian/Ubuntu) or sudo dnf install zbar (Fedora/RHEL)"),
         "rsync" => Some("sudo apt install rsync (Debian/Ubuntu) or sudo dnf install rsync (Fedora/RHEL)"),
         "mc" => Some("sudo apt install mc (Debian/Ubuntu) or sudo dnf install mc (Fedora/RHEL)"),
+        "xdg-open" => Some("sudo apt install xdg-utils (Debian/Ubuntu) or sudo dnf install xdg-utils (Fedora/RHEL)"),
+        "udisksctl" => Some("sudo apt install udisks2 (Debian/Ubuntu) or sudo dnf install udisks2 (Fedora/RHEL)"),
         _ => None,
     }
 }
@@ -103,6 +106,28 @@ pub fn get_optional_command(command: &str) -> Option<PathBuf> {
     check_command(command)
 }
 
+/// Resolve an optional tool right before a run uses it. The startup check can
+/// be stale by then (the tool may have been uninstalled since), so a missing
+/// tool is logged with `fallback`, what happens instead, and the caller
+/// carries on without it.
+pub fn resolve_optional(command: &str, fallback: &str) -> Option<PathBuf> {
+    let path = check_command(command);
+    if path.is_none() {
+        warn!("Optional tool {} is not available; {}", command, fallback);
+    }
+    path
+}
+
+/// Whether `err` means a command could not be started because its executable
+/// is gone, e.g. removed between [`resolve_optional`] and running it.
+pub fn is_missing_command(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        cause
+            .downcast_ref::<std::io::Error>()
+            .is_some_and(|e| e.kind() == std::io::ErrorKind::NotFound)
+    })
+}
+
 #[derive(Debug, Clone)]
 pub struct DependencyStatus {
     pub missing_required: Vec<String>,
@@ -158,4 +183,14 @@ mod tests {
         // This probably doesn't exist
         assert!(check_command("nonexistent_command_xyz123").is_none());
     }
+
+    #[test]
+    fn test_missing_optional_tool() {
+        assert!(resolve_optional("nonexistent_command_xyz123", "skipping").is_none());
+        assert!(resolve_optional("sh", "skipping").is_some());
+
+        let err = crate::commands::execute_command("nonexistent_command_xyz123", &[], false).unwrap_err();
+        assert!(is_missing_command(&err));
+        assert!(!is_missing_command(&anyhow::anyhow!("rsync failed")));
+    }
 }
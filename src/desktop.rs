@@ -0,0 +1,91 @@
+//! Desktop integration: opening folders in the file manager and copying text
+//! to the clipboard. Both need a graphical session, so headless servers get a
+//! clear error instead of a hanging or failing helper.
+
+use crate::dependencies;
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use tracing::{debug, info};
+
+/// Kept alive for the whole run: on X11 and Wayland the copied text is served
+/// by this handle, and it disappears when the handle is dropped.
+static CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+
+/// Whether a graphical session is running.
+fn has_display() -> bool {
+    ["DISPLAY", "WAYLAND_DISPLAY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
+/// Whether folders can be opened in the system file manager.
+pub fn file_manager_available() -> bool {
+    has_display() && dependencies::get_optional_command("xdg-open").is_some()
+}
+
+/// Directory holding `path`; a bare file name lives in the current directory.
+fn containing_folder(path: &Path) -> &Path {
+    path.parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+}
+
+/// Open the directory containing `path` in the system file manager.
+pub fn open_containing_folder(path: &Path) -> Result<()> {
+    let folder = containing_folder(path);
+    open_folder(folder)
+}
+
+/// Open `folder` in the system file manager.
+pub fn open_folder(folder: &Path) -> Result<()> {
+    if !has_display() {
+        anyhow::bail!("No graphical session; open {} manually", folder.display());
+    }
+    let xdg_open = dependencies::get_optional_command("xdg-open")
+        .context("xdg-open not found (install xdg-utils)")?;
+
+    info!("Opening {} in the file manager", folder.display());
+    // The file manager outlives the request, so reap xdg-open in the background
+    let mut child = Command::new(xdg_open)
+        .arg(folder)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("Failed to run xdg-open")?;
+    std::thread::spawn(move || {
+        if let Ok(status) = child.wait() {
+            debug!("xdg-open exited with {}", status);
+        }
+    });
+    Ok(())
+}
+
+/// Put `text` on the desktop clipboard.
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    let mut clipboard = CLIPBOARD.lock().unwrap_or_else(|e| e.into_inner());
+    if clipboard.is_none() {
+        *clipboard = Some(arboard::Clipboard::new().context("No clipboard available")?);
+    }
+    clipboard
+        .as_mut()
+        .expect("clipboard set above")
+        .set_text(text)
+        .context("Failed to copy to the clipboard")?;
+    debug!("Copied to clipboard: {}", text);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_containing_folder() {
+        assert_eq!(containing_folder(Path::new("/var/staging/2024-BD-001.iso")), Path::new("/var/staging"));
+        assert_eq!(containing_folder(Path::new("2024-BD-001.iso")), Path::new("."));
+        assert_eq!(containing_folder(Path::new("/")), Path::new("."));
+    }
+}
@@ -1,100 +1,331 @@
+use crate::config::ChecksumAlgorithm;
 use anyhow::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
-use tracing::debug;
+use tracing::{debug, warn};
 use rusqlite::params;
 
+/// Default `disc_id_template`: IDs like `2024-BD-7`.
+pub const DEFAULT_DISC_ID_TEMPLATE: &str = "{year}-BD-{seq}";
+
+/// Marks where a multi-disc set puts each disc's position in the set.
+const SET_PLACEHOLDER: &str = "{set}";
+
 /// Generate a disc ID in the format YYYY-BD-#.
 pub fn generate_disc_id() -> String {
-    let year = get_current_year();
-    let number = get_next_disc_number(&year).unwrap_or(1);
-    format!("{:04}-BD-{}", year, number)
+    // Query database for existing discs with this year prefix
+    let db_path = dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("bdarchive")
+        .join("archive.db");
+
+    let rendered = if db_path.exists() {
+        rusqlite::Connection::open(&db_path)
+            .map_err(anyhow::Error::from)
+            .and_then(|conn| render_disc_id_template(&conn, DEFAULT_DISC_ID_TEMPLATE))
+            .ok()
+    } else {
+        None
+    };
+
+    // No database yet (or it can't be read): start from 1
+    rendered.unwrap_or_else(|| format!("{:04}-BD-1", current_year_month().0))
 }
 
-/// Get current year (simplified).
-fn get_current_year() -> u32 {
-    // For now, use a simple approach
-    // In production, you might want to use a proper date library
-    use std::time::SystemTime;
-    match SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
-        Ok(duration) => {
-            let days = duration.as_secs() / 86400;
-            1970 + (days / 365) as u32
+/// Render a disc ID template for the next disc.
+///
+/// Placeholders: `{year}` (e.g. 2024), `{month}` (01-12), `{seq}` (one more than
+/// the highest number already used after the text before `{seq}`, see
+/// [`next_sequence_for_prefix`]) and `{set}`. `{set}` is kept as is and filled in
+/// with the disc's position when the ID is used for a multi-disc set; single
+/// discs drop it (see [`expand_set_placeholder`]).
+pub fn render_disc_id_template(conn: &rusqlite::Connection, template: &str) -> Result<String> {
+    let (year, month) = current_year_month();
+    let mut rendered = String::new();
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        rendered.push_str(&rest[..open]);
+        let close = rest[open..]
+            .find('}')
+            .map(|i| open + i)
+            .with_context(|| format!("Unclosed placeholder in disc ID template '{}'", template))?;
+        match &rest[open..=close] {
+            "{year}" => rendered.push_str(&format!("{:04}", year)),
+            "{month}" => rendered.push_str(&format!("{:02}", month)),
+            "{seq}" => {
+                let seq = next_sequence_for_prefix(conn, &rendered)?;
+                rendered.push_str(&seq.to_string());
+            }
+            SET_PLACEHOLDER => rendered.push_str(SET_PLACEHOLDER),
+            other => anyhow::bail!(
+                "Unknown placeholder {} in disc ID template '{}' (use {{year}}, {{month}}, {{seq}} or {{set}})",
+                other,
+                template
+            ),
         }
-        Err(_) => 2024,
+        rest = &rest[close + 1..];
     }
+    rendered.push_str(rest);
+
+    if let Err(e) = validate_disc_id(&expand_set_placeholder(&rendered, None)) {
+        anyhow::bail!("Disc ID template '{}' renders an invalid ID: {}", template, e);
+    }
+    Ok(rendered)
 }
 
-/// Get next disc number for a year (checks database if available).
-/// For now, just return None to always start from 1.
-fn get_next_disc_number(year: &u32) -> Option<u32> {
-    // Query database for existing discs with this year prefix
-    let db_path = dirs::data_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("bdarchive")
-        .join("archive.db");
+/// Next free sequence number for disc IDs starting with `prefix`.
+///
+/// Looks at the digits right after the prefix in every existing disc ID, so
+/// `2024-PHOTOS-12` and multi-disc members like `2024-PHOTOS-12-3` both count
+/// as 12. Returns 1 when no disc uses the prefix yet.
+pub fn next_sequence_for_prefix(conn: &rusqlite::Connection, prefix: &str) -> Result<u32> {
+    // substr() rather than LIKE: prefixes may contain % or _, and LIKE ignores case
+    let mut stmt = conn.prepare("SELECT disc_id FROM discs WHERE substr(disc_id, 1, length(?1)) = ?1")?;
+    let ids = stmt.query_map(params![prefix], |row| row.get::<_, String>(0))?;
+
+    let mut highest = 0u32;
+    for id in ids {
+        let id = id?;
+        let digits: String = id[prefix.len()..].chars().take_while(|c| c.is_ascii_digit()).collect();
+        if let Ok(number) = digits.parse::<u32>() {
+            highest = highest.max(number);
+        }
+    }
+    Ok(highest.saturating_add(1))
+}
+
+/// Disc IDs a run of `disc_count` discs creates from `base_id`.
+pub fn planned_disc_ids(base_id: &str, disc_count: usize) -> Vec<String> {
+    if disc_count <= 1 {
+        vec![expand_set_placeholder(base_id, None)]
+    } else {
+        (1..=disc_count as u32).map(|seq| generate_multi_disc_id(base_id, seq)).collect()
+    }
+}
+
+/// Catalogued disc IDs that clash with any of `disc_ids`. Volume labels are
+/// upper case, so IDs that differ only in case clash as well. Discs planned by
+/// a dry run don't clash: the burn replaces them.
+pub fn find_existing_disc_ids(conn: &rusqlite::Connection, disc_ids: &[String]) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare("SELECT disc_id FROM discs WHERE lower(disc_id) = lower(?1) AND planned = 0")?;
+    let mut existing = Vec::new();
+    for disc_id in disc_ids {
+        for id in stmt.query_map(params![disc_id], |row| row.get::<_, String>(0))? {
+            existing.push(id?);
+        }
+    }
+    Ok(existing)
+}
+
+/// First `<base_id>-<n>` (n from 2) whose discs are all free in the catalog.
+pub fn suggest_free_disc_id(conn: &rusqlite::Connection, base_id: &str, disc_count: usize) -> Result<String> {
+    for n in 2..1000 {
+        let candidate = format!("{}-{}", base_id, n);
+        if find_existing_disc_ids(conn, &planned_disc_ids(&candidate, disc_count))?.is_empty() {
+            return Ok(candidate);
+        }
+    }
+    anyhow::bail!("No free disc ID found for '{}'", base_id)
+}
 
-    if !db_path.exists() {
-        // Database doesn't exist yet, start from 1
-        return Some(1);
+/// Fill in or drop the `{set}` placeholder of a disc ID.
+///
+/// With a sequence number the placeholder becomes that number; without one it
+/// is removed together with the separator in front of it.
+pub fn expand_set_placeholder(disc_id: &str, sequence_num: Option<u32>) -> String {
+    match sequence_num {
+        Some(n) => disc_id.replace(SET_PLACEHOLDER, &n.to_string()),
+        None => {
+            let mut id = disc_id.to_string();
+            while let Some(pos) = id.find(SET_PLACEHOLDER) {
+                let start = if id[..pos].ends_with(['-', '_', '.']) { pos - 1 } else { pos };
+                id.replace_range(start..pos + SET_PLACEHOLDER.len(), "");
+            }
+            id.trim_start_matches(['-', '_', '.']).to_string()
+        }
     }
+}
 
-    let conn = match rusqlite::Connection::open(&db_path) {
-        Ok(conn) => conn,
-        Err(_) => return Some(1), // If we can't open DB, start from 1
+/// Current UTC year and month.
+fn current_year_month() -> (u32, u32) {
+    use std::time::SystemTime;
+    let days = match SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() / 86400) as i64,
+        Err(_) => 0,
     };
 
-    let year_prefix = format!("{:04}-BD-", year);
-    let query = "SELECT disc_id FROM discs WHERE disc_id LIKE ?1 ORDER BY disc_id DESC LIMIT 1";
+    // Civil-from-days conversion (proleptic Gregorian calendar)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as u32, month as u32)
+}
+
+/// Filesystem whose volume label rules a label must satisfy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeLabelTarget {
+    /// ISO9660 primary volume descriptor: up to 32 d-characters (`A-Z`, `0-9`, `_`).
+    Iso9660,
+    /// Joliet supplementary volume descriptor: up to 16 characters.
+    Joliet,
+}
 
-    match conn.query_row(query, params![format!("{}%", year_prefix)], |row| {
-        let disc_id: String = row.get(0)?;
-        // Extract the number from disc_id like "2026-BD-005"
-        if let Some(num_str) = disc_id.strip_prefix(&year_prefix) {
-            Ok(num_str.parse::<u32>().ok())
-        } else {
-            Ok(None)
+impl VolumeLabelTarget {
+    /// Maximum label length in characters.
+    pub fn max_len(self) -> usize {
+        match self {
+            VolumeLabelTarget::Iso9660 => 32,
+            VolumeLabelTarget::Joliet => 16,
         }
-    }) {
-        Ok(Some(last_num)) => Some(last_num + 1),
-        _ => Some(1), // No existing discs for this year, start from 1
     }
 }
 
+/// Label rules used for the images `iso::create_iso` writes.
+/// The ISO9660 label is what Linux mounts and `blkid` report; xorriso derives
+/// the shorter Joliet label from it.
+pub const VOLUME_LABEL_TARGET: VolumeLabelTarget = VolumeLabelTarget::Iso9660;
+
+/// Label used when nothing usable is left after sanitizing.
+const FALLBACK_VOLUME_LABEL: &str = "BDARCHIVE";
+
+/// Sanitize a label to the target filesystem's rules.
+///
+/// Letters are uppercased and every other character that is not `A-Z`/`0-9`
+/// becomes `_`, one for one, so short disc IDs map back to their label
+/// unambiguously apart from case and `-`/`_`. Labels that are too long keep
+/// their head and their last `_`-separated segment (usually the disc number).
+pub fn sanitize_volume_label(raw: &str, target: VolumeLabelTarget) -> String {
+    let max_len = target.max_len();
+    let mapped: String = raw
+        .trim()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+
+    if mapped.trim_matches('_').is_empty() {
+        return FALLBACK_VOLUME_LABEL.to_string();
+    }
+    if mapped.len() <= max_len {
+        return mapped;
+    }
+
+    // Everything is ASCII at this point, so byte offsets are char offsets
+    let tail = mapped.rsplit('_').next().unwrap_or("");
+    if !tail.is_empty() && tail.len() <= max_len / 4 {
+        let head = mapped[..max_len - tail.len() - 1].trim_end_matches('_');
+        format!("{}_{}", head, tail)
+    } else {
+        mapped[..max_len].to_string()
+    }
+}
+
+/// Check that a label already satisfies the target filesystem's rules.
+pub fn is_valid_volume_label(label: &str, target: VolumeLabelTarget) -> bool {
+    !label.is_empty()
+        && label.len() <= target.max_len()
+        && label.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Device names Windows reserves, with or without an extension.
+const RESERVED_NAMES: [&str; 22] = [
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8", "com9", "lpt1",
+    "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
+/// Characters no common filesystem accepts in a file name.
+const INVALID_FILENAME_CHARS: [char; 9] = ['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+/// Longest name `sanitize_for_filesystem` returns, in bytes. Leaves room
+/// within the usual 255-byte limit for extensions such as `.iso.tmp`.
+pub const MAX_FILESYSTEM_NAME_LEN: usize = 200;
+
+/// Name used when nothing usable is left after sanitizing.
+const FALLBACK_FILESYSTEM_NAME: &str = "disc";
+
+/// Make a disc ID safe to use as a file or directory name on Linux, macOS
+/// and Windows filesystems alike.
+///
+/// Path separators, characters Windows rejects and control characters become
+/// `_`; trailing dots and spaces are dropped; reserved device names (`CON`,
+/// `nul.txt`, ...) get a `_` appended to their first part; overlong names are
+/// cut at a character boundary. Ordinary disc IDs come back unchanged.
+pub fn sanitize_for_filesystem(name: &str) -> String {
+    let mapped: String = name
+        .chars()
+        .map(|c| if c.is_control() || INVALID_FILENAME_CHARS.contains(&c) { '_' } else { c })
+        .collect();
+    let mut safe = mapped.trim_end_matches(['.', ' ']).to_string();
+
+    let stem_len = safe.find('.').unwrap_or(safe.len());
+    if RESERVED_NAMES.contains(&safe[..stem_len].trim_end().to_lowercase().as_str()) {
+        safe.insert(stem_len, '_');
+    }
+
+    let mut end = safe.len().min(MAX_FILESYSTEM_NAME_LEN);
+    while !safe.is_char_boundary(end) {
+        end -= 1;
+    }
+    safe.truncate(end);
+    let safe = safe.trim_end_matches(['.', ' ']);
+    if safe.is_empty() {
+        FALLBACK_FILESYSTEM_NAME.to_string()
+    } else {
+        safe.to_string()
+    }
+}
+
+/// File name of the ISO image for a disc.
+pub fn iso_file_name(disc_id: &str) -> String {
+    format!("{}.iso", sanitize_for_filesystem(disc_id))
+}
+
 /// Generate volume label from disc ID.
 pub fn generate_volume_label(disc_id: &str) -> String {
-    // Convert to uppercase and replace hyphens with underscores
-    disc_id.to_uppercase().replace("-", "_")
+    let label = sanitize_volume_label(&sanitize_for_filesystem(disc_id), VOLUME_LABEL_TARGET);
+    if label != disc_id.to_uppercase().replace('-', "_") {
+        warn!("Volume label for disc {} adjusted to {}", disc_id, label);
+    }
+    label
 }
 
 /// Generate volume label for multi-disc sets.
-/// Ensures labels fit within filesystem constraints (typically 32 chars max).
+/// Ensures labels fit within filesystem constraints (32 chars max).
 pub fn generate_multi_disc_volume_label(base_id: &str, sequence_num: u32, total_discs: u32) -> String {
-    // For multi-disc sets, create labels like: "BDARCHIVE_2024_1_OF_3"
+    // For multi-disc sets, create labels like: "BDARCHIVE_2024D1_OF_3"
     // This clearly shows the disc position and total count
 
     // Extract year from base_id if it's in the format "YYYY-BD-XXX"
-    let year_part = if base_id.len() >= 4 && base_id.chars().take(4).all(|c| c.is_ascii_digit()) {
-        format!("_{}", &base_id[0..4])
-    } else {
-        String::new()
-    };
+    let year = base_id
+        .get(0..4)
+        .filter(|prefix| prefix.chars().all(|c| c.is_ascii_digit()));
+    let year_part = year.map(|y| format!("_{}", y)).unwrap_or_default();
 
     let label = format!("BDARCHIVE{}D{}_OF_{}", year_part, sequence_num, total_discs);
 
-    // Ensure it fits within typical filesystem limits (32 chars is common)
-    if label.len() > 32 {
-        // Fallback to shorter format if needed
-        format!("BD{}_{}_{}", &base_id[0..4], sequence_num, total_discs)
+    // Fallback to shorter format if needed
+    let label = if label.len() > VOLUME_LABEL_TARGET.max_len() {
+        format!("BD{}_{}_{}", year.unwrap_or(""), sequence_num, total_discs)
     } else {
         label
-    }
+    };
+    sanitize_volume_label(&label, VOLUME_LABEL_TARGET)
 }
 
 /// Generate disc ID for a specific sequence in a multi-disc set.
 /// For multi-disc sets, generates IDs like "2024-BD-ARCHIVE-1", "2024-BD-ARCHIVE-2", etc.
+/// A `{set}` placeholder in the base ID is replaced by the sequence number instead.
 pub fn generate_multi_disc_id(base_id: &str, sequence_num: u32) -> String {
-    format!("{}-{}", base_id, sequence_num)
+    if base_id.contains(SET_PLACEHOLDER) {
+        expand_set_placeholder(base_id, Some(sequence_num))
+    } else {
+        format!("{}-{}", base_id, sequence_num)
+    }
 }
 
 /// Validate that a disc ID is valid for use in filenames and volume labels.
@@ -115,8 +346,7 @@ pub fn validate_disc_id(disc_id: &str) -> Result<(), String> {
 
     // Check for reserved names (Windows system files, etc.)
     let lower_id = disc_id.to_lowercase();
-    let reserved_names = ["con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8", "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9"];
-    if reserved_names.contains(&lower_id.as_str()) {
+    if RESERVED_NAMES.contains(&lower_id.as_str()) {
         return Err(format!("'{}' is a reserved system name", disc_id));
     }
 
@@ -130,7 +360,7 @@ pub fn create_disc_layout(
     _source_folders: &[PathBuf],
     _notes: Option<&str>,
 ) -> Result<PathBuf> {
-    let disc_root = staging_dir.join(disc_id);
+    let disc_root = staging_dir.join(sanitize_for_filesystem(disc_id));
     fs::create_dir_all(&disc_root)?;
 
     // Create ARCHIVE directory
@@ -190,6 +420,70 @@ pub fn write_disc_info(
     Ok(())
 }
 
+/// Append incremental-archive details to an existing DISC_INFO.txt.
+pub fn write_incremental_info(
+    disc_root: &Path,
+    baseline_disc_ids: &[String],
+    unchanged_files: usize,
+) -> Result<()> {
+    use std::io::Write;
+
+    let disc_info_path = disc_root.join("DISC_INFO.txt");
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(&disc_info_path)
+        .with_context(|| format!("Failed to open DISC_INFO.txt: {}", disc_info_path.display()))?;
+
+    let baseline = if baseline_disc_ids.is_empty() {
+        "(none)".to_string()
+    } else {
+        baseline_disc_ids.join(", ")
+    };
+    write!(
+        file,
+        "\nIncremental: yes (only new or changed files)\nBaseline Discs: {}\nUnchanged Files (not on this disc): {}\n",
+        baseline, unchanged_files
+    )
+    .with_context(|| format!("Failed to write DISC_INFO.txt: {}", disc_info_path.display()))?;
+
+    debug!("Added incremental info to {}", disc_info_path.display());
+    Ok(())
+}
+
+/// Append the ISO volume descriptor fields to an existing DISC_INFO.txt.
+pub fn write_iso_info(disc_root: &Path, metadata: &crate::iso::IsoMetadata) -> Result<()> {
+    use std::io::Write;
+
+    let disc_info_path = disc_root.join("DISC_INFO.txt");
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(&disc_info_path)
+        .with_context(|| format!("Failed to open DISC_INFO.txt: {}", disc_info_path.display()))?;
+    for (name, value) in metadata.fields() {
+        writeln!(file, "{}: {}", name, value)
+            .with_context(|| format!("Failed to write DISC_INFO.txt: {}", disc_info_path.display()))?;
+    }
+
+    debug!("Added ISO metadata to {}", disc_info_path.display());
+    Ok(())
+}
+
+/// Append the manifest checksum algorithm to an existing DISC_INFO.txt.
+pub fn write_checksum_info(disc_root: &Path, algorithm: ChecksumAlgorithm) -> Result<()> {
+    use std::io::Write;
+
+    let disc_info_path = disc_root.join("DISC_INFO.txt");
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(&disc_info_path)
+        .with_context(|| format!("Failed to open DISC_INFO.txt: {}", disc_info_path.display()))?;
+    writeln!(file, "Checksums: {}", algorithm.description())
+        .with_context(|| format!("Failed to write DISC_INFO.txt: {}", disc_info_path.display()))?;
+
+    debug!("Added checksum info to {}", disc_info_path.display());
+    Ok(())
+}
+
 /// Format current timestamp as ISO 8601.
 pub fn format_timestamp_now() -> String {
     use std::time::SystemTime;
@@ -202,6 +496,67 @@ pub fn format_timestamp_now() -> String {
     }
 }
 
+/// Timestamp `days` days before now, in the same format as `format_timestamp_now`
+/// so the two compare correctly as strings.
+pub fn format_timestamp_days_ago(days: u64) -> String {
+    use std::time::SystemTime;
+    match SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+        Ok(duration) => format_timestamp_simple(duration.as_secs().saturating_sub(days * 86400)),
+        Err(_) => "1970-01-01T00:00:00Z".to_string(),
+    }
+}
+
+/// A stored timestamp as its date plus how long ago it was, e.g.
+/// "2025-01-10 (3 months ago)". Values that don't parse are returned as is.
+pub fn format_timestamp_relative(timestamp: &str) -> String {
+    match (parse_timestamp_simple(timestamp), now_secs()) {
+        (Some(then), Some(now)) => format!("{} ({})", &timestamp[..10], describe_age(then, now)),
+        _ => timestamp.to_string(),
+    }
+}
+
+fn now_secs() -> Option<u64> {
+    use std::time::SystemTime;
+    SystemTime::now().duration_since(std::time::UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+/// "just now", "5 minutes ago", "1 day ago", "3 months ago", "2 years ago".
+fn describe_age(then: u64, now: u64) -> String {
+    let Some(age) = now.checked_sub(then) else {
+        return "in the future".to_string();
+    };
+    let (count, unit) = match age {
+        0..=59 => return "just now".to_string(),
+        60..=3599 => (age / 60, "minute"),
+        3600..=86_399 => (age / 3600, "hour"),
+        _ if age < 30 * 86400 => (age / 86400, "day"),
+        _ if age < 365 * 86400 => (age / (30 * 86400), "month"),
+        _ => (age / (365 * 86400), "year"),
+    };
+    format!("{} {}{} ago", count, unit, if count == 1 { "" } else { "s" })
+}
+
+/// Seconds since the epoch for a timestamp written by `format_timestamp_simple`
+/// ("YYYY-MM-DD", optionally followed by "THH:MM:SS" or " HH:MM:SS"), using
+/// the same calendar approximation so ages come out right.
+fn parse_timestamp_simple(timestamp: &str) -> Option<u64> {
+    let field = |range: std::ops::Range<usize>| -> Option<u64> {
+        let s = timestamp.get(range)?;
+        if s.bytes().all(|b| b.is_ascii_digit()) { s.parse().ok() } else { None }
+    };
+    let (year, month, day) = (field(0..4)?, field(5..7)?, field(8..10)?);
+    if year < 1970 || !(1..=13).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let days = (year - 1970) * 365 + (month - 1) * 30 + (day - 1);
+    let secs_in_day = if timestamp.len() > 10 {
+        field(11..13)? * 3600 + field(14..16)? * 60 + field(17..19).unwrap_or(0)
+    } else {
+        0
+    };
+    Some(days * 86400 + secs_in_day)
+}
+
 /// Simple timestamp formatting (approximate UTC).
 fn format_timestamp_simple(secs: u64) -> String {
     let days = secs / 86400;
@@ -232,6 +587,25 @@ mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_format_timestamp_relative() {
+        let now = 1_700_000_000;
+        assert_eq!(parse_timestamp_simple(&format_timestamp_simple(now)), Some(now));
+        assert_eq!(parse_timestamp_simple("2024-01-01 10:00:00"), parse_timestamp_simple("2024-01-01T10:00:00Z"));
+        assert!(parse_timestamp_simple("2024-01-01").is_some());
+        assert_eq!(parse_timestamp_simple("not a date"), None);
+
+        assert_eq!(describe_age(now - 30, now), "just now");
+        assert_eq!(describe_age(now - 5 * 60, now), "5 minutes ago");
+        assert_eq!(describe_age(now - 86400, now), "1 day ago");
+        assert_eq!(describe_age(now - 95 * 86400, now), "3 months ago");
+        assert_eq!(describe_age(now - 800 * 86400, now), "2 years ago");
+        assert_eq!(describe_age(now + 3600, now), "in the future");
+
+        assert!(format_timestamp_relative(&format_timestamp_now()).ends_with(" (just now)"));
+        assert_eq!(format_timestamp_relative("unknown"), "unknown");
+    }
+
     #[test]
     fn test_generate_disc_id() {
         let disc_id = generate_disc_id();
@@ -239,12 +613,166 @@ mod tests {
         assert!(disc_id.contains("-BD-"));
     }
 
+    #[test]
+    fn test_existing_disc_ids_and_suffix() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = crate::database::init_database(&temp_dir.path().join("test.db"))?;
+        for disc_id in ["2024-PHOTOS", "2024-PHOTOS-2", "2024-SET-1"] {
+            conn.execute(
+                "INSERT INTO discs (disc_id, volume_label, created_at) VALUES (?1, ?1, '2024-01-01T00:00:00Z')",
+                [disc_id],
+            )?;
+        }
+
+        assert_eq!(find_existing_disc_ids(&conn, &planned_disc_ids("2024-photos", 1))?, vec!["2024-PHOTOS"]);
+        assert!(find_existing_disc_ids(&conn, &planned_disc_ids("2024-NEW", 1))?.is_empty());
+        assert_eq!(find_existing_disc_ids(&conn, &planned_disc_ids("2024-SET", 2))?, vec!["2024-SET-1"]);
+
+        assert_eq!(suggest_free_disc_id(&conn, "2024-PHOTOS", 1)?, "2024-PHOTOS-3");
+        assert_eq!(suggest_free_disc_id(&conn, "2024-SET", 2)?, "2024-SET-2");
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_sequence_for_prefix() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = crate::database::init_database(&temp_dir.path().join("test.db"))?;
+        assert_eq!(next_sequence_for_prefix(&conn, "2024-PHOTOS-")?, 1);
+
+        for disc_id in ["2024-PHOTOS-3", "2024-PHOTOS-12-2", "2024-PHOTOS-9", "2024-PHOTOS-X", "2024-photos-40", "2024-BD-99"] {
+            conn.execute(
+                "INSERT INTO discs (disc_id, volume_label, created_at) VALUES (?1, ?1, '2024-01-01T00:00:00Z')",
+                [disc_id],
+            )?;
+        }
+        // Numeric, not lexicographic; case-sensitive; other prefixes ignored
+        assert_eq!(next_sequence_for_prefix(&conn, "2024-PHOTOS-")?, 13);
+        assert_eq!(next_sequence_for_prefix(&conn, "2024-BD-")?, 100);
+        assert_eq!(next_sequence_for_prefix(&conn, "2024_PHOTOS_")?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_disc_id_template() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = crate::database::init_database(&temp_dir.path().join("test.db"))?;
+        let (year, month) = current_year_month();
+        assert!((1..=12).contains(&month));
+
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at) VALUES (?1, ?1, '2024-01-01T00:00:00Z')",
+            [format!("{}-PHOTOS-4", year)],
+        )?;
+
+        assert_eq!(render_disc_id_template(&conn, "{year}-PHOTOS-{seq}")?, format!("{}-PHOTOS-5", year));
+        assert_eq!(render_disc_id_template(&conn, DEFAULT_DISC_ID_TEMPLATE)?, format!("{}-BD-1", year));
+        assert_eq!(render_disc_id_template(&conn, "{year}{month}-{seq}")?, format!("{}{:02}-1", year, month));
+        assert_eq!(render_disc_id_template(&conn, "FAMILY-{seq}-{set}")?, "FAMILY-1-{set}");
+        assert_eq!(render_disc_id_template(&conn, "NO-PLACEHOLDERS")?, "NO-PLACEHOLDERS");
+
+        assert!(render_disc_id_template(&conn, "{year}-{week}").is_err());
+        assert!(render_disc_id_template(&conn, "{year}-{seq").is_err());
+        assert!(render_disc_id_template(&conn, "{year}/{seq}").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_expand_set_placeholder() {
+        assert_eq!(expand_set_placeholder("FAMILY-7-{set}", Some(2)), "FAMILY-7-2");
+        assert_eq!(expand_set_placeholder("FAMILY-7-{set}", None), "FAMILY-7");
+        assert_eq!(expand_set_placeholder("{set}_FAMILY-7", None), "FAMILY-7");
+        assert_eq!(expand_set_placeholder("2024-BD-3", None), "2024-BD-3");
+
+        assert_eq!(generate_multi_disc_id("FAMILY-{set}-OF-SET", 3), "FAMILY-3-OF-SET");
+        assert_eq!(generate_multi_disc_id("FAMILY", 3), "FAMILY-3");
+    }
+
     #[test]
     fn test_generate_volume_label() {
         let label = generate_volume_label("2024-BD-001");
         assert_eq!(label, "2024_BD_001");
     }
 
+    #[test]
+    fn test_sanitize_volume_label() {
+        use VolumeLabelTarget::{Iso9660, Joliet};
+
+        let cases = [
+            // (input, target, expected)
+            ("2024-BD-001", Iso9660, "2024_BD_001"),
+            ("my archive", Iso9660, "MY_ARCHIVE"),
+            ("  padded  ", Iso9660, "PADDED"),
+            ("photos.2024+raw!", Iso9660, "PHOTOS_2024_RAW_"),
+            ("Fotos-Müller-Ä", Iso9660, "FOTOS_M_LLER__"),
+            ("日本語-1", Iso9660, "____1"),
+            ("", Iso9660, "BDARCHIVE"),
+            ("---", Iso9660, "BDARCHIVE"),
+            ("ÄÖÜ", Joliet, "BDARCHIVE"),
+            // Long IDs keep the head and the trailing disc number
+            ("2024-BD-VERY-LONG-ARCHIVE-NAME-PROJECT-12", Iso9660, "2024_BD_VERY_LONG_ARCHIVE_NAM_12"),
+            ("2024-BD-VERY-LONG-ARCHIVE-NAME-PROJECT-12", Joliet, "2024_BD_VERY_12"),
+            // A trailing segment too long to keep is truncated like the rest
+            ("2024-BD-ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789", Iso9660, "2024_BD_ABCDEFGHIJKLMNOPQRSTUVWX"),
+            ("ABCDEFGHIJKLMNOPQRSTUVWXYZ", Joliet, "ABCDEFGHIJKLMNOP"),
+            // Exactly at the limit is left alone
+            (&"A".repeat(32), Iso9660, &"A".repeat(32)),
+        ];
+
+        for (input, target, expected) in cases {
+            let label = sanitize_volume_label(input, target);
+            assert_eq!(label, expected, "input {:?} ({:?})", input, target);
+            assert!(is_valid_volume_label(&label, target), "{:?} invalid for {:?}", label, target);
+        }
+    }
+
+    #[test]
+    fn test_sanitize_for_filesystem() {
+        let cases = [
+            // Ordinary IDs are left alone
+            ("2024-BD-001", "2024-BD-001"),
+            ("Photos 2024_raw", "Photos 2024_raw"),
+            // Separators and characters Windows rejects
+            ("a/b\\c:d*e?f\"g<h>i|j", "a_b_c_d_e_f_g_h_i_j"),
+            ("tab\tnewline\n", "tab_newline_"),
+            // Trailing dots and spaces
+            ("archive. . ", "archive"),
+            ("v1.0.", "v1.0"),
+            ("...", "disc"),
+            ("", "disc"),
+            // Reserved device names, with or without an extension
+            ("CON", "CON_"),
+            ("nul", "nul_"),
+            ("com1.iso", "com1_.iso"),
+            ("aux ", "aux_"),
+            ("lpt9.", "lpt9_"),
+            ("CONSOLE", "CONSOLE"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(sanitize_for_filesystem(input), expected, "input {:?}", input);
+        }
+
+        // Overlong names are cut at a character boundary, then trimmed again
+        let long = "é".repeat(150);
+        let safe = sanitize_for_filesystem(&long);
+        assert!(safe.len() <= MAX_FILESYSTEM_NAME_LEN);
+        assert_eq!(safe, "é".repeat(MAX_FILESYSTEM_NAME_LEN / 2));
+        let dotted = format!("{}.{}", "a".repeat(MAX_FILESYSTEM_NAME_LEN - 1), "b".repeat(50));
+        assert_eq!(sanitize_for_filesystem(&dotted), "a".repeat(MAX_FILESYSTEM_NAME_LEN - 1));
+
+        assert_eq!(iso_file_name("CON"), "CON_.iso");
+        assert_eq!(iso_file_name("2024-BD-001"), "2024-BD-001.iso");
+        assert_eq!(generate_volume_label("nul"), "NUL_");
+    }
+
+    #[test]
+    fn test_is_valid_volume_label() {
+        assert!(is_valid_volume_label("2024_BD_001", VolumeLabelTarget::Iso9660));
+        assert!(!is_valid_volume_label("2024-BD-001", VolumeLabelTarget::Iso9660));
+        assert!(!is_valid_volume_label("lowercase", VolumeLabelTarget::Iso9660));
+        assert!(!is_valid_volume_label("", VolumeLabelTarget::Iso9660));
+        assert!(!is_valid_volume_label(&"A".repeat(17), VolumeLabelTarget::Joliet));
+    }
+
     #[test]
     fn test_create_disc_layout() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -259,6 +787,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_checksum_info() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let disc_root = temp_dir.path();
+        write_disc_info(disc_root, "2024-BD-001", None, &[], "1.0.0", None, None, None)?;
+
+        write_checksum_info(disc_root, ChecksumAlgorithm::Crc32)?;
+        let content = fs::read_to_string(disc_root.join("DISC_INFO.txt"))?;
+        assert!(content.ends_with("Checksums: CRC32 (fast; integrity check only, not tamper-evident)\n"));
+
+        // Without DISC_INFO.txt there is nothing to append to
+        let empty = TempDir::new()?;
+        assert!(write_checksum_info(empty.path(), ChecksumAlgorithm::Sha256).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_iso_info() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let disc_root = temp_dir.path();
+        write_disc_info(disc_root, "2024-BD-001", None, &[], "1.0.0", None, None, None)?;
+
+        let metadata = crate::iso::IsoMetadata {
+            publisher: Some("City Archives".to_string()),
+            ..Default::default()
+        };
+        write_iso_info(disc_root, &metadata)?;
+        let content = fs::read_to_string(disc_root.join("DISC_INFO.txt"))?;
+        assert!(content.contains("Disc-ID: 2024-BD-001"));
+        assert!(content.contains("ISO Publisher: City Archives\n"));
+        assert!(content.contains(&format!("ISO Application ID: {}\n", metadata.application_id)));
+        assert!(!content.contains("ISO Preparer:"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_disc_info() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -338,6 +903,14 @@ mod tests {
         assert!(label.contains("12"));
     }
 
+    #[test]
+    fn test_multi_disc_volume_label_short_or_non_ascii() {
+        // Short or non-ASCII base IDs must not panic
+        assert_eq!(generate_multi_disc_volume_label("ab", 1, 2), "BDARCHIVED1_OF_2");
+        let label = generate_multi_disc_volume_label("Ärchiv-ü", 100, 10000000);
+        assert!(is_valid_volume_label(&label, VOLUME_LABEL_TARGET));
+    }
+
     #[test]
     fn test_validate_disc_id() {
         // Valid IDs
@@ -0,0 +1,168 @@
+use crate::config::StagingConfig;
+use crate::database::FileRecord;
+use crate::manifest;
+use crate::staging::{self, SourceFile};
+use anyhow::Result;
+use rusqlite::Connection;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+/// Outcome of comparing source folders against the archive index.
+#[derive(Debug, Clone, Default)]
+pub struct IncrementalSelection {
+    /// New or changed files that still need to be archived
+    pub changed: Vec<SourceFile>,
+    /// Files already on an earlier disc with the same path, size and SHA-256
+    pub unchanged_count: usize,
+    pub unchanged_bytes: u64,
+    /// Discs holding the unchanged files, sorted
+    pub baseline_disc_ids: Vec<String>,
+}
+
+impl IncrementalSelection {
+    pub fn changed_bytes(&self) -> u64 {
+        self.changed.iter().map(|f| f.size_bytes).sum()
+    }
+}
+
+/// Split the files under `sources` into already-archived and new/changed.
+///
+/// A file counts as archived when a disc holds the same `ARCHIVE/...` path with
+/// the same size and SHA-256. Only files whose path and size match are hashed.
+/// Discs indexed without SHA-256 values never match, so their files are archived again.
+pub fn select_changed_files(
+    conn: &Connection,
+    sources: &[(PathBuf, Option<String>)],
+    options: &StagingConfig,
+) -> Result<IncrementalSelection> {
+    let mut selection = IncrementalSelection::default();
+
+    for file in staging::collect_named_source_files(sources, staging::WalkOptions::from(options))? {
+        if file.link_target.is_some() {
+            selection.changed.push(file);
+            continue;
+        }
+
+        let rel_path = Path::new("ARCHIVE").join(&file.archive_path);
+        // The catalog holds normalized paths; names that cannot be normalized are refused at staging
+        let rel_path = crate::paths::normalize_rel_path(&rel_path).unwrap_or(rel_path);
+        let candidates = FileRecord::find_archived(conn, &rel_path.to_string_lossy(), file.size_bytes)?;
+        if candidates.is_empty() {
+            selection.changed.push(file);
+            continue;
+        }
+
+        let sha256 = staging::with_retries(options.max_retries, &format!("Reading {}", file.source.display()), || {
+            manifest::calculate_sha256(&file.source)
+        })?;
+        match candidates.into_iter().find(|(_, archived)| *archived == sha256) {
+            Some((disc_id, _)) => {
+                debug!("Already archived on {}: {}", disc_id, rel_path.display());
+                selection.unchanged_count += 1;
+                selection.unchanged_bytes += file.size_bytes;
+                if !selection.baseline_disc_ids.contains(&disc_id) {
+                    selection.baseline_disc_ids.push(disc_id);
+                }
+            }
+            None => selection.changed.push(file),
+        }
+    }
+
+    selection.baseline_disc_ids.sort();
+    info!(
+        "Incremental selection: {} new or changed files, {} unchanged on {} discs",
+        selection.changed.len(),
+        selection.unchanged_count,
+        selection.baseline_disc_ids.len()
+    );
+    Ok(selection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::init_database;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_select_changed_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+
+        let source = temp_dir.path().join("photos");
+        fs::create_dir_all(source.join("2023"))?;
+        fs::write(source.join("2023/same.jpg"), "unchanged")?;
+        fs::write(source.join("2023/edited.jpg"), "new content")?;
+        fs::write(source.join("new.jpg"), "brand new")?;
+
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('BASE-1', 'BASE_1', '2024-01-01T00:00:00Z')",
+            [],
+        )?;
+        let record = |rel_path: &str, sha256: String, size: usize| FileRecord {
+            id: None,
+            disc_id: "BASE-1".to_string(),
+            rel_path: rel_path.to_string(),
+            sha256,
+            size: size as u64,
+            mtime: "2024-01-01T00:00:00Z".to_string(),
+            added_at: "2024-01-01T00:00:00Z".to_string(),
+            crc32: None,
+        };
+        let same_sha = manifest::calculate_sha256(&source.join("2023/same.jpg"))?;
+        FileRecord::insert(&conn, &record("ARCHIVE/photos/2023/same.jpg", same_sha, "unchanged".len()))?;
+        // Same path and size, different content
+        FileRecord::insert(&conn, &record("ARCHIVE/photos/2023/edited.jpg", "0".repeat(64), "new content".len()))?;
+
+        let selection = select_changed_files(&conn, &[(source, None)], &StagingConfig::default())?;
+
+        let mut changed: Vec<PathBuf> = selection.changed.iter().map(|f| f.archive_path.clone()).collect();
+        changed.sort();
+        assert_eq!(
+            changed,
+            vec![PathBuf::from("photos/2023/edited.jpg"), PathBuf::from("photos/new.jpg")]
+        );
+        assert_eq!(selection.unchanged_count, 1);
+        assert_eq!(selection.unchanged_bytes, "unchanged".len() as u64);
+        assert_eq!(selection.baseline_disc_ids, vec!["BASE-1".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dry_run_is_no_baseline() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+
+        let source = temp_dir.path().join("photos");
+        fs::create_dir_all(&source)?;
+        fs::write(source.join("a.jpg"), "planned only")?;
+
+        // A dry run indexed the file on a planned disc that was never burned
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at, planned) VALUES ('DRY-1', 'DRY_1', '2024-01-01T00:00:00Z', 1)",
+            [],
+        )?;
+        FileRecord::insert(
+            &conn,
+            &FileRecord {
+                id: None,
+                disc_id: "DRY-1".to_string(),
+                rel_path: "ARCHIVE/photos/a.jpg".to_string(),
+                sha256: manifest::calculate_sha256(&source.join("a.jpg"))?,
+                size: "planned only".len() as u64,
+                mtime: "2024-01-01T00:00:00Z".to_string(),
+                added_at: "2024-01-01T00:00:00Z".to_string(),
+                crc32: None,
+            },
+        )?;
+
+        let selection = select_changed_files(&conn, &[(source, None)], &StagingConfig::default())?;
+        assert_eq!(selection.changed.len(), 1);
+        assert_eq!(selection.unchanged_count, 0);
+        assert!(selection.baseline_disc_ids.is_empty());
+
+        Ok(())
+    }
+}
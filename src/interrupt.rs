@@ -0,0 +1,89 @@
+//! Ctrl-C handling.
+//!
+//! The first Ctrl-C (a SIGINT, or the key in the TUI's raw mode) asks the
+//! archive pipeline to stop at its next safe point: before staging or burning
+//! the next disc, never in the middle of a burn. A second Ctrl-C within
+//! [`FORCE_QUIT_WINDOW`] force-quits.
+
+use anyhow::{Context, Result};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// A second Ctrl-C within this window force-quits.
+pub const FORCE_QUIT_WINDOW: Duration = Duration::from_secs(3);
+
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+/// Set by the signal handler until the UI picks the request up
+static SIGNAL_PENDING: AtomicBool = AtomicBool::new(false);
+
+/// What a Ctrl-C asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interrupt {
+    /// Stop at the next safe point
+    Graceful,
+    /// Second Ctrl-C in quick succession: exit now
+    Force,
+}
+
+/// Record a Ctrl-C and say whether it should force-quit.
+pub fn request() -> Interrupt {
+    let now = Instant::now();
+    let mut last = LAST_REQUEST.lock().unwrap_or_else(|e| e.into_inner());
+    let force = is_force(*last, now);
+    *last = Some(now);
+    REQUESTED.store(true, Ordering::SeqCst);
+    if force {
+        Interrupt::Force
+    } else {
+        Interrupt::Graceful
+    }
+}
+
+/// A Ctrl-C at `now` forces when the previous one was recent enough.
+fn is_force(last: Option<Instant>, now: Instant) -> bool {
+    last.is_some_and(|at| now.duration_since(at) <= FORCE_QUIT_WINDOW)
+}
+
+/// Whether a Ctrl-C has been received.
+pub fn is_requested() -> bool {
+    REQUESTED.load(Ordering::SeqCst)
+}
+
+/// Whether a SIGINT arrived that the UI has not handled yet. Returns true once per signal.
+pub fn take_signal() -> bool {
+    SIGNAL_PENDING.swap(false, Ordering::SeqCst)
+}
+
+/// Install the SIGINT handler. `on_force` runs on the handler thread when a
+/// second signal arrives in time, so it must not depend on the UI thread.
+pub fn install_handler(on_force: impl Fn() + Send + 'static) -> Result<()> {
+    ctrlc::set_handler(move || match request() {
+        Interrupt::Graceful => {
+            warn!("Interrupt received; stopping at the next safe point");
+            SIGNAL_PENDING.store(true, Ordering::SeqCst);
+        }
+        Interrupt::Force => {
+            warn!("Second interrupt received; forcing quit");
+            on_force();
+        }
+    })
+    .context("Failed to install Ctrl-C handler")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The flags are process-wide, so only the timing rule is tested here:
+    // setting them would cancel archive tests running in parallel.
+    #[test]
+    fn test_second_request_forces_within_window() {
+        let first = Instant::now();
+        assert!(!is_force(None, first));
+        assert!(is_force(Some(first), first + Duration::from_secs(1)));
+        assert!(!is_force(Some(first), first + FORCE_QUIT_WINDOW + Duration::from_millis(1)));
+    }
+}
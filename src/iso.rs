@@ -1,13 +1,273 @@
 use crate::commands;
+use crate::config::IsoConfig;
 use anyhow::{Context, Result};
-use std::path::Path;
-use tracing::{debug, info};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+use walkdir::WalkDir;
+
+/// Longest publisher, preparer or application ID ISO 9660 can hold
+const MAX_DESCRIPTOR_LEN: usize = 128;
+/// Longest system ID ISO 9660 can hold
+const MAX_SYSTEM_ID_LEN: usize = 32;
+/// Largest file a single ISO 9660 extent can hold (4 GiB - 1), and so the
+/// largest file levels 1 and 2 can store
+pub const MAX_SINGLE_EXTENT_SIZE: u64 = u32::MAX as u64;
+/// Lowest ISO 9660 level that stores bigger files, split into several extents
+pub const LARGE_FILE_LEVEL: u8 = 3;
+
+/// Longest name Joliet records, in UTF-16 code units
+pub const JOLIET_MAX_NAME_LEN: usize = 64;
+/// Longest path Joliet records, counted from the image root
+pub const JOLIET_MAX_PATH_LEN: usize = 240;
+
+/// Logical block size of ISO 9660 images; file data starts on block boundaries
+pub const SECTOR_SIZE: u64 = 2048;
+/// Present in every image: system area, volume descriptors, path tables,
+/// both root directories and the 300 KiB xorriso pads images with in mkisofs mode
+const BASE_OVERHEAD: u64 = 192 * SECTOR_SIZE;
+/// A file's directory records: ISO 9660 with Rock Ridge entries, plus Joliet
+const FILE_RECORD_BYTES: u64 = 320;
+/// A directory's own extent in both trees, its records in the parent and
+/// its entries in the four path tables
+const DIR_OVERHEAD_BYTES: u64 = 2 * SECTOR_SIZE + 512;
+
+/// Provenance fields written into the ISO 9660 primary volume descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsoMetadata {
+    pub publisher: Option<String>,
+    pub preparer: Option<String>,
+    pub application_id: String,
+    pub system_id: Option<String>,
+    /// ISO 9660 interchange level passed to xorriso
+    pub level: u8,
+}
+
+impl Default for IsoMetadata {
+    fn default() -> Self {
+        Self::from(&IsoConfig::default())
+    }
+}
+
+impl From<&IsoConfig> for IsoMetadata {
+    fn from(config: &IsoConfig) -> Self {
+        // Blank values in the config file count as unset
+        let field = |value: &Option<String>| {
+            value.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(str::to_string)
+        };
+        Self {
+            publisher: field(&config.publisher),
+            preparer: field(&config.preparer),
+            application_id: field(&config.application_id)
+                .unwrap_or_else(|| format!("BlueVault {}", crate::disc::get_tool_version())),
+            system_id: field(&config.system_id),
+            level: config.level,
+        }
+    }
+}
+
+impl IsoMetadata {
+    /// The set fields as (DISC_INFO.txt name, value) pairs.
+    pub fn fields(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("ISO Publisher", self.publisher.as_deref()),
+            ("ISO Preparer", self.preparer.as_deref()),
+            ("ISO Application ID", Some(self.application_id.as_str())),
+            ("ISO System ID", self.system_id.as_deref()),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|v| (name, v)))
+        .collect()
+    }
+
+    /// Refuse values longer than their ISO 9660 field rather than let xorriso
+    /// truncate them, and levels ISO 9660 doesn't define.
+    fn validate(&self) -> Result<()> {
+        if !(1..=3).contains(&self.level) {
+            anyhow::bail!("ISO level {} is not valid; use 1, 2 or 3", self.level);
+        }
+        let limited = [
+            ("publisher", self.publisher.as_deref(), MAX_DESCRIPTOR_LEN),
+            ("preparer", self.preparer.as_deref(), MAX_DESCRIPTOR_LEN),
+            ("application_id", Some(self.application_id.as_str()), MAX_DESCRIPTOR_LEN),
+            ("system_id", self.system_id.as_deref(), MAX_SYSTEM_ID_LEN),
+        ];
+        for (name, value, max_len) in limited {
+            if let Some(value) = value {
+                if value.len() > max_len {
+                    anyhow::bail!("ISO {} is {} characters; at most {} fit", name, value.len(), max_len);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// xorriso `-as mkisofs` options for the set fields.
+    fn xorriso_args(&self) -> Vec<&str> {
+        let level = match self.level {
+            1 => "1",
+            2 => "2",
+            _ => "3",
+        };
+        let mut args = vec!["-iso-level", level];
+        if let Some(publisher) = &self.publisher {
+            args.extend(["-publisher", publisher.as_str()]);
+        }
+        if let Some(preparer) = &self.preparer {
+            args.extend(["-preparer", preparer.as_str()]);
+        }
+        args.extend(["-appid", self.application_id.as_str()]);
+        if let Some(system_id) = &self.system_id {
+            args.extend(["-sysid", system_id.as_str()]);
+        }
+        args
+    }
+}
+
+/// ISO 9660 level chosen for an image, with the file that forced it up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsoLevelChoice {
+    /// Level from the config
+    pub configured: u8,
+    /// Level the image is built with
+    pub level: u8,
+    /// Largest file over 4 GiB in the image, with its size
+    pub largest_file: Option<(PathBuf, u64)>,
+}
+
+impl IsoLevelChoice {
+    /// What to tell the user when the image holds files of 4 GiB or more,
+    /// so they know which standard the disc relies on.
+    pub fn notice(&self) -> Option<String> {
+        let (path, size) = self.largest_file.as_ref()?;
+        let compatibility = "Linux, macOS and Windows Vista or later read such files; older systems and some standalone players do not";
+        let file = format!(
+            "{} is {}",
+            path.file_name().unwrap_or_default().to_string_lossy(),
+            crate::search::format_size(*size)
+        );
+        Some(if self.level != self.configured {
+            format!(
+                "⚠️ {}, too big for ISO 9660 level {}: using level {} instead. {}",
+                file, self.configured, self.level, compatibility
+            )
+        } else {
+            format!("ℹ️ {}: stored as a multi-extent file (ISO 9660 level {}). {}", file, self.level, compatibility)
+        })
+    }
+}
+
+/// Name and path lengths an image keeps intact. Longer names are truncated
+/// in the Joliet tree Windows reads, or make xorriso give up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathLimits {
+    pub max_name_len: usize,
+    pub max_path_len: usize,
+}
+
+impl Default for PathLimits {
+    fn default() -> Self {
+        Self::from(&IsoConfig::default())
+    }
+}
+
+impl From<&IsoConfig> for PathLimits {
+    fn from(config: &IsoConfig) -> Self {
+        // Levels 1 and 2 also limit names in the plain ISO 9660 tree
+        let level_name_len = match config.level {
+            1 => 12,
+            2 => 31,
+            _ => JOLIET_MAX_NAME_LEN,
+        };
+        Self {
+            max_name_len: config.max_name_length.unwrap_or(level_name_len),
+            max_path_len: config.max_path_length.unwrap_or(JOLIET_MAX_PATH_LEN),
+        }
+    }
+}
+
+impl PathLimits {
+    /// Why `path` (relative to the image root) would not fit, if it wouldn't.
+    /// Lengths count UTF-16 code units, as Joliet stores them.
+    pub fn check(&self, path: &Path) -> Option<String> {
+        let names: Vec<String> = path.components().map(|c| c.as_os_str().to_string_lossy().into_owned()).collect();
+        if let Some(name) = names.iter().find(|name| name.encode_utf16().count() > self.max_name_len) {
+            return Some(format!(
+                "name \"{}\" is {} characters; at most {} fit",
+                name,
+                name.encode_utf16().count(),
+                self.max_name_len
+            ));
+        }
+        let path_len = names.join("/").encode_utf16().count();
+        (path_len > self.max_path_len)
+            .then(|| format!("path is {} characters; at most {} fit", path_len, self.max_path_len))
+    }
+}
+
+/// Filesystem metadata an image of `file_count` files in `dir_count`
+/// directories adds on top of the file data. Errs on the high side.
+pub fn estimate_iso_overhead(file_count: u64, dir_count: u64) -> u64 {
+    BASE_OVERHEAD + file_count * FILE_RECORD_BYTES + dir_count * DIR_OVERHEAD_BYTES
+}
+
+/// Size of a selection as plain content and as the ISO image built from it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IsoSizeEstimate {
+    /// Sum of the file sizes
+    pub content_bytes: u64,
+    /// File data padded to whole sectors plus [`estimate_iso_overhead`]
+    pub iso_bytes: u64,
+}
+
+impl IsoSizeEstimate {
+    /// Estimate for files given by path (relative to the image root) and size.
+    /// Directories are counted from the paths.
+    pub fn from_files<'a>(files: impl IntoIterator<Item = (&'a Path, u64)>) -> Self {
+        let mut dirs = std::collections::HashSet::new();
+        let (mut file_count, mut content_bytes, mut data_bytes) = (0u64, 0u64, 0u64);
+        for (path, size) in files {
+            file_count += 1;
+            content_bytes += size;
+            data_bytes += size.div_ceil(SECTOR_SIZE) * SECTOR_SIZE;
+            // Files split into extents get one directory record per extent
+            data_bytes += size.saturating_sub(1) / MAX_SINGLE_EXTENT_SIZE * FILE_RECORD_BYTES;
+            dirs.extend(path.ancestors().skip(1).filter(|dir| !dir.as_os_str().is_empty()));
+        }
+        Self {
+            content_bytes,
+            iso_bytes: data_bytes + estimate_iso_overhead(file_count, dirs.len() as u64),
+        }
+    }
+}
+
+/// Pick the ISO 9660 level for an image of `root`: the configured one, raised
+/// to level 3 when a file doesn't fit in a single extent.
+pub fn choose_iso_level(configured: u8, root: &Path) -> Result<IsoLevelChoice> {
+    let mut largest_file: Option<(PathBuf, u64)> = None;
+    for entry in WalkDir::new(root) {
+        let entry = entry.with_context(|| format!("Failed to scan {}", root.display()))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        if size > MAX_SINGLE_EXTENT_SIZE && largest_file.as_ref().is_none_or(|(_, max)| size > *max) {
+            largest_file = Some((entry.into_path(), size));
+        }
+    }
+    let level = if largest_file.is_some() { configured.max(LARGE_FILE_LEVEL) } else { configured };
+    let choice = IsoLevelChoice { configured, level, largest_file };
+    if let Some(notice) = choice.notice() {
+        warn!("{}", notice);
+    }
+    Ok(choice)
+}
 
 /// Create an ISO image from a directory using xorriso.
 pub fn create_iso(
     source_dir: &Path,
     output_iso: &Path,
     volume_label: &str,
+    metadata: &IsoMetadata,
     dry_run: bool,
 ) -> Result<()> {
     info!(
@@ -17,6 +277,17 @@ pub fn create_iso(
         volume_label
     );
 
+    // Catch labels xorriso would reject before it fails with a less helpful error
+    if !crate::disc::is_valid_volume_label(volume_label, crate::disc::VOLUME_LABEL_TARGET) {
+        anyhow::bail!(
+            "Invalid volume label '{}': use at most {} characters from A-Z, 0-9 and _",
+            volume_label,
+            crate::disc::VOLUME_LABEL_TARGET.max_len()
+        );
+    }
+
+    metadata.validate()?;
+
     // Validate source directory
     crate::paths::validate_dir(source_dir).context("Source directory validation failed")?;
 
@@ -29,17 +300,20 @@ pub fn create_iso(
     // Using mkisofs compatible mode for better compatibility
     let output_iso_str = output_iso.to_string_lossy().to_string();
     let source_dir_str = source_dir.to_string_lossy().to_string();
-    let args = vec![
+    let mut args = vec![
         "-as",
         "mkisofs", // Use mkisofs compatible mode
         "-r",      // Rock Ridge (Unix file names and permissions)
         "-J",      // Joliet (Windows compatibility)
         "-V",
         volume_label, // Volume label
+    ];
+    args.extend(metadata.xorriso_args());
+    args.extend([
         "-o",
         &output_iso_str, // Output file
         &source_dir_str, // Source directory
-    ];
+    ]);
 
     let output = commands::execute_command("xorriso", &args, dry_run)?;
 
@@ -51,6 +325,41 @@ pub fn create_iso(
     Ok(())
 }
 
+/// Byte offset of the ISO 9660 primary volume descriptor (sector 16)
+const PVD_OFFSET: u64 = 16 * 2048;
+
+/// Read the volume label from an ISO 9660 image's primary volume descriptor.
+pub fn read_volume_label(iso_path: &Path) -> Result<String> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(iso_path)
+        .with_context(|| format!("Failed to open ISO: {}", iso_path.display()))?;
+    let mut descriptor = [0u8; 72];
+    file.seek(SeekFrom::Start(PVD_OFFSET))
+        .and_then(|_| file.read_exact(&mut descriptor))
+        .with_context(|| format!("Failed to read volume descriptor: {}", iso_path.display()))?;
+
+    // Type 1 ("primary") followed by the standard identifier "CD001"
+    if descriptor[0] != 1 || &descriptor[1..6] != b"CD001" {
+        anyhow::bail!("{} is not an ISO 9660 image", iso_path.display());
+    }
+    Ok(String::from_utf8_lossy(&descriptor[40..72]).trim_end().to_string())
+}
+
+/// Copy `file_in_iso` (an absolute path inside the image) out of an ISO with
+/// xorriso. Returns false when the image has no such file.
+pub fn extract_file(iso_path: &Path, file_in_iso: &str, dest: &Path) -> Result<bool> {
+    let iso_str = iso_path.to_string_lossy().to_string();
+    let dest_str = dest.to_string_lossy().to_string();
+    let args = ["-osirrox", "on", "-indev", &iso_str, "-extract", file_in_iso, &dest_str];
+    let output = commands::execute_command("xorriso", &args, false)?;
+    if !output.success || !dest.exists() {
+        debug!("{} not found in {}", file_in_iso, iso_path.display());
+        return Ok(false);
+    }
+    Ok(true)
+}
+
 /// Get ISO file size in bytes.
 pub fn get_iso_size(iso_path: &Path) -> Result<u64> {
     let metadata = std::fs::metadata(iso_path)
@@ -73,8 +382,179 @@ mod tests {
         fs::create_dir_all(&source)?;
         fs::write(source.join("test.txt"), "test")?;
 
+        let metadata = IsoMetadata::default();
+
         // Should not fail in dry run mode
-        create_iso(&source, &output, "TEST_LABEL", true)?;
+        create_iso(&source, &output, "TEST_LABEL", &metadata, true)?;
+
+        // Labels xorriso would reject are refused up front
+        assert!(create_iso(&source, &output, "2024-BD-001", &metadata, true).is_err());
+        assert!(create_iso(&source, &output, &"A".repeat(33), &metadata, true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_iso_metadata_from_config() -> Result<()> {
+        let defaults = IsoMetadata::default();
+        assert!(defaults.application_id.starts_with("BlueVault "));
+        assert_eq!(defaults.xorriso_args(), vec!["-iso-level", "3", "-appid", defaults.application_id.as_str()]);
+
+        let metadata = IsoMetadata::from(&IsoConfig {
+            publisher: Some("City Archives".to_string()),
+            preparer: Some("  ".to_string()),
+            application_id: None,
+            system_id: Some("LINUX".to_string()),
+            level: 2,
+            ..IsoConfig::default()
+        });
+        assert_eq!(&metadata.xorriso_args()[..2], ["-iso-level", "2"]);
+        assert_eq!(metadata.preparer, None);
+        assert_eq!(
+            metadata.fields(),
+            vec![
+                ("ISO Publisher", "City Archives"),
+                ("ISO Application ID", defaults.application_id.as_str()),
+                ("ISO System ID", "LINUX"),
+            ]
+        );
+        metadata.validate()?;
+
+        let bad_level = IsoMetadata { level: 4, ..metadata.clone() };
+        assert!(bad_level.validate().is_err());
+
+        let too_long = IsoMetadata {
+            system_id: Some("S".repeat(MAX_SYSTEM_ID_LEN + 1)),
+            ..metadata
+        };
+        assert!(too_long.validate().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_choose_iso_level() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        fs::create_dir_all(temp_dir.path().join("ARCHIVE"))?;
+        fs::write(temp_dir.path().join("ARCHIVE/small.txt"), "small")?;
+
+        let choice = choose_iso_level(1, temp_dir.path())?;
+        assert_eq!(choice.level, 1);
+        assert_eq!(choice.notice(), None);
+
+        // A sparse file is enough to cross the 4 GiB line
+        let big = temp_dir.path().join("ARCHIVE/film.mkv");
+        fs::File::create(&big)?.set_len(MAX_SINGLE_EXTENT_SIZE + 1)?;
+        let choice = choose_iso_level(2, temp_dir.path())?;
+        assert_eq!(choice.level, LARGE_FILE_LEVEL);
+        assert_eq!(choice.largest_file, Some((big, MAX_SINGLE_EXTENT_SIZE + 1)));
+        assert!(choice.notice().unwrap().contains("using level 3 instead"));
+        assert!(choose_iso_level(3, temp_dir.path())?.notice().unwrap().contains("multi-extent"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_limits() {
+        let limits = PathLimits::default();
+        assert_eq!(limits, PathLimits { max_name_len: JOLIET_MAX_NAME_LEN, max_path_len: JOLIET_MAX_PATH_LEN });
+        assert_eq!(limits.check(Path::new("ARCHIVE/photos/a.jpg")), None);
+
+        let long_name = format!("ARCHIVE/{}.txt", "n".repeat(61));
+        assert!(limits.check(Path::new(&long_name)).unwrap().contains("is 65 characters; at most 64 fit"));
+        let deep = format!("ARCHIVE/{}", vec!["d".repeat(40); 6].join("/"));
+        assert!(limits.check(Path::new(&deep)).unwrap().starts_with("path is 253 characters"));
+
+        let level_one = PathLimits::from(&IsoConfig { level: 1, ..IsoConfig::default() });
+        assert!(level_one.check(Path::new("ARCHIVE/photos/a.jpg")).is_none());
+        assert!(level_one.check(Path::new("ARCHIVE/photographs-2024/a.jpg")).is_some());
+
+        let configured = PathLimits::from(&IsoConfig {
+            level: 1,
+            max_name_length: Some(100),
+            max_path_length: Some(20),
+            ..IsoConfig::default()
+        });
+        assert!(configured.check(Path::new("ARCHIVE/photographs")).is_none());
+        assert!(configured.check(Path::new("ARCHIVE/photographs/a.jpg")).is_some());
+    }
+
+    #[test]
+    fn test_estimate_iso_size() {
+        assert_eq!(estimate_iso_overhead(0, 0), BASE_OVERHEAD);
+        assert!(estimate_iso_overhead(1000, 10) > estimate_iso_overhead(10, 10));
+        assert!(estimate_iso_overhead(10, 100) > estimate_iso_overhead(10, 10));
+
+        // Data is padded to whole sectors, and "a" and "a/b" both count as directories
+        let estimate = IsoSizeEstimate::from_files([(Path::new("a/b/one.txt"), 1), (Path::new("a/two.txt"), 2049)]);
+        assert_eq!(estimate.content_bytes, 2050);
+        assert_eq!(estimate.iso_bytes, 3 * SECTOR_SIZE + estimate_iso_overhead(2, 2));
+
+        // Files past 4 GiB need an extra directory record per extent
+        let big = IsoSizeEstimate::from_files([(Path::new("film.mkv"), MAX_SINGLE_EXTENT_SIZE + 1)]);
+        let padded = (MAX_SINGLE_EXTENT_SIZE + 1).div_ceil(SECTOR_SIZE) * SECTOR_SIZE;
+        assert_eq!(big.iso_bytes, padded + FILE_RECORD_BYTES + estimate_iso_overhead(1, 0));
+    }
+
+    /// Checks the estimate against images xorriso really builds; skipped when
+    /// xorriso is not installed.
+    #[test]
+    fn test_estimate_matches_xorriso() -> Result<()> {
+        if crate::dependencies::check_command("xorriso").is_none() {
+            return Ok(());
+        }
+        let temp_dir = TempDir::new()?;
+
+        // Many small files over a few directories, and a few larger files nested deeply
+        let small = temp_dir.path().join("small");
+        for i in 0..300 {
+            let dir = small.join(format!("ARCHIVE/folder_{:02}", i % 20));
+            fs::create_dir_all(&dir)?;
+            fs::write(dir.join(format!("note_{:03}.txt", i)), "x".repeat(100 + i))?;
+        }
+        let large = temp_dir.path().join("large");
+        for i in 0..4 {
+            let dir = large.join(format!("ARCHIVE/photos/{}/raw/day_{}", 2020 + i, i));
+            fs::create_dir_all(&dir)?;
+            fs::write(dir.join("IMG_0001.CR2"), vec![7u8; 1_500_000 + i * 4096])?;
+        }
+
+        for root in [small, large] {
+            let files: Vec<(PathBuf, u64)> = WalkDir::new(&root)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+                .map(|e| (e.path().strip_prefix(&root).unwrap().to_path_buf(), e.metadata().unwrap().len()))
+                .collect();
+            let estimate = IsoSizeEstimate::from_files(files.iter().map(|(p, s)| (p.as_path(), *s)));
+
+            let iso = temp_dir.path().join("check.iso");
+            create_iso(&root, &iso, "SIZE_CHECK", &IsoMetadata::default(), false)?;
+            let actual = get_iso_size(&iso)?;
+            assert!(estimate.iso_bytes >= actual, "{}: estimated {} < actual {}", root.display(), estimate.iso_bytes, actual);
+            assert!(
+                estimate.iso_bytes - actual <= actual / 5 + 256 * 1024,
+                "{}: estimated {} is far above actual {}",
+                root.display(),
+                estimate.iso_bytes,
+                actual
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_volume_label() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let iso_path = temp_dir.path().join("test.iso");
+
+        let mut image = vec![0u8; PVD_OFFSET as usize + 2048];
+        let descriptor = &mut image[PVD_OFFSET as usize..];
+        descriptor[0] = 1;
+        descriptor[1..6].copy_from_slice(b"CD001");
+        descriptor[40..72].copy_from_slice(format!("{:<32}", "BDARCHIVE_2024_1").as_bytes());
+        fs::write(&iso_path, &image)?;
+        assert_eq!(read_volume_label(&iso_path)?, "BDARCHIVE_2024_1");
+
+        fs::write(&iso_path, vec![0u8; PVD_OFFSET as usize + 2048])?;
+        assert!(read_volume_label(&iso_path).is_err());
         Ok(())
     }
 
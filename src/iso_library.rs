@@ -0,0 +1,223 @@
+use crate::config::Config;
+use crate::database::{Disc, RetainedIso};
+use crate::{burn, disc, manifest};
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tracing::{info, warn};
+
+/// A retained ISO that is still present in the library directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LibraryEntry {
+    pub iso: RetainedIso,
+    pub size_bytes: u64,
+    /// Modification time, bumped whenever the ISO is retained or re-burned
+    pub last_used: SystemTime,
+}
+
+/// Move a freshly burned ISO into the library as `<disc_id>.iso`, record its
+/// path and SHA-256 with the disc, then evict the least recently used ISOs
+/// beyond `iso_library.max_size_gb`. Returns the library path.
+pub fn retain_iso(conn: &Connection, config: &Config, disc_id: &str, iso_path: &Path) -> Result<PathBuf> {
+    let dir = config.iso_library_dir()?;
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create ISO library: {}", dir.display()))?;
+
+    let dest = dir.join(disc::iso_file_name(disc_id));
+    move_file(iso_path, &dest)?;
+    touch(&dest)?;
+
+    let sha256 = manifest::calculate_sha256(&dest)?;
+    Disc::set_retained_iso(conn, disc_id, Some((&dest.to_string_lossy(), &sha256)))?;
+    info!("Retained ISO for {} at {}", disc_id, dest.display());
+
+    let max_bytes = config.iso_library.max_size_gb * crate::config::BYTES_PER_GB;
+    for evicted in evict_lru(conn, max_bytes, &dest)? {
+        info!("Evicted ISO for {} from the library", evicted);
+    }
+    Ok(dest)
+}
+
+/// Retained ISOs whose files still exist, most recently used first.
+pub fn list_library(conn: &Connection) -> Result<Vec<LibraryEntry>> {
+    let mut entries = Vec::new();
+    for iso in Disc::list_retained_isos(conn)? {
+        match fs::metadata(&iso.iso_path) {
+            Ok(metadata) => entries.push(LibraryEntry {
+                size_bytes: metadata.len(),
+                last_used: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                iso,
+            }),
+            Err(e) => warn!("Retained ISO for {} is gone ({}): {}", iso.disc_id, iso.iso_path.display(), e),
+        }
+    }
+    entries.sort_by_key(|e| std::cmp::Reverse(e.last_used));
+    Ok(entries)
+}
+
+/// Remove the least recently used ISOs until the library fits in `max_bytes`.
+/// `keep` is never evicted. Returns the disc IDs whose ISOs were removed.
+pub fn evict_lru(conn: &Connection, max_bytes: u64, keep: &Path) -> Result<Vec<String>> {
+    let entries = list_library(conn)?;
+    let mut evicted = Vec::new();
+    for index in select_evictions(&entries, max_bytes, keep) {
+        let iso = &entries[index].iso;
+        fs::remove_file(&iso.iso_path)
+            .with_context(|| format!("Failed to remove {}", iso.iso_path.display()))?;
+        Disc::set_retained_iso(conn, &iso.disc_id, None)?;
+        evicted.push(iso.disc_id.clone());
+    }
+    Ok(evicted)
+}
+
+/// Indexes of the entries to evict, oldest first, so the rest fit in `max_bytes`.
+fn select_evictions(entries: &[LibraryEntry], max_bytes: u64, keep: &Path) -> Vec<usize> {
+    let mut total: u64 = entries.iter().map(|e| e.size_bytes).sum();
+    let mut by_age: Vec<usize> = (0..entries.len()).collect();
+    by_age.sort_by_key(|&i| entries[i].last_used);
+
+    let mut evict = Vec::new();
+    for index in by_age {
+        if total <= max_bytes {
+            break;
+        }
+        if entries[index].iso.iso_path == keep {
+            continue;
+        }
+        total -= entries[index].size_bytes;
+        evict.push(index);
+    }
+    evict
+}
+
+/// Burn a retained ISO again. The image is checked against the SHA-256
+/// recorded when it was retained, so a damaged file is never burned.
+pub fn reburn(conn: &Connection, config: &Config, disc_id: &str, dry_run: bool) -> Result<()> {
+    let iso = Disc::list_retained_isos(conn)?
+        .into_iter()
+        .find(|iso| iso.disc_id == disc_id)
+        .with_context(|| format!("No retained ISO for disc {}", disc_id))?;
+
+    if let Some(expected) = &iso.sha256 {
+        let actual = manifest::calculate_sha256(&iso.iso_path)?;
+        if &actual != expected {
+            anyhow::bail!(
+                "Retained ISO {} does not match its recorded SHA-256; it may be damaged",
+                iso.iso_path.display()
+            );
+        }
+    }
+
+    touch(&iso.iso_path)?;
+    info!("Re-burning {} from {}", disc_id, iso.iso_path.display());
+    burn::burn_with_method(&iso.iso_path, &config.device, dry_run, "iso", config.burn.mdisc, config.burn.stall_timeout())
+}
+
+/// Rename, falling back to copy and delete across filesystems.
+fn move_file(from: &Path, to: &Path) -> Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    fs::copy(from, to).with_context(|| format!("Failed to copy {} to {}", from.display(), to.display()))?;
+    fs::remove_file(from).with_context(|| format!("Failed to remove {}", from.display()))?;
+    Ok(())
+}
+
+/// Mark an ISO as just used for LRU eviction.
+fn touch(path: &Path) -> Result<()> {
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.set_modified(SystemTime::now()))
+        .with_context(|| format!("Failed to update {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::init_database;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn disc(disc_id: &str) -> Disc {
+        Disc {
+            disc_id: disc_id.to_string(),
+            volume_label: disc_id.to_string(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            notes: None,
+            iso_size: None,
+            burn_device: None,
+            checksum_manifest_hash: None,
+            qr_path: None,
+            source_roots: None,
+            tool_version: None,
+            set_id: None,
+            sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
+        }
+    }
+
+    #[test]
+    fn test_select_evictions_oldest_first() {
+        let entry = |name: &str, size_bytes: u64, age_secs: u64| LibraryEntry {
+            iso: RetainedIso {
+                disc_id: name.to_string(),
+                iso_path: PathBuf::from(format!("/lib/{}.iso", name)),
+                sha256: None,
+            },
+            size_bytes,
+            last_used: SystemTime::UNIX_EPOCH + Duration::from_secs(1_000 - age_secs),
+        };
+        let entries = vec![entry("new", 40, 1), entry("old", 40, 300), entry("mid", 40, 100)];
+
+        assert!(select_evictions(&entries, 120, Path::new("/lib/new.iso")).is_empty());
+        assert_eq!(select_evictions(&entries, 80, Path::new("/lib/new.iso")), vec![1]);
+        assert_eq!(select_evictions(&entries, 40, Path::new("/lib/new.iso")), vec![1, 2]);
+        // The ISO just added survives even when it alone exceeds the cap
+        assert_eq!(select_evictions(&entries, 10, Path::new("/lib/new.iso")), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_retain_and_evict() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        Disc::insert(&mut conn, &disc("2024-BD-1"))?;
+        Disc::insert(&mut conn, &disc("2024-BD-2"))?;
+
+        let mut config = Config::default();
+        config.iso_library.dir = Some(temp_dir.path().join("isos").to_string_lossy().into_owned());
+        config.iso_library.max_size_gb = 0;
+
+        let staged = temp_dir.path().join("2024-BD-1.iso");
+        fs::write(&staged, "first image")?;
+        let first = retain_iso(&conn, &config, "2024-BD-1", &staged)?;
+        assert!(!staged.exists());
+        assert_eq!(first, temp_dir.path().join("isos/2024-BD-1.iso"));
+
+        let library = list_library(&conn)?;
+        assert_eq!(library.len(), 1);
+        assert_eq!(library[0].iso.sha256, Some(manifest::calculate_sha256(&first)?));
+
+        // With a zero cap, retaining the next ISO evicts the older one
+        let staged = temp_dir.path().join("2024-BD-2.iso");
+        fs::write(&staged, "second image")?;
+        retain_iso(&conn, &config, "2024-BD-2", &staged)?;
+        let library = list_library(&conn)?;
+        assert_eq!(library.len(), 1);
+        assert_eq!(library[0].iso.disc_id, "2024-BD-2");
+        assert!(!first.exists());
+
+        // A damaged image is refused before anything is burned
+        fs::write(&library[0].iso.iso_path, "bit rot")?;
+        let err = reburn(&conn, &config, "2024-BD-2", true).unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+
+        Ok(())
+    }
+}
@@ -1,15 +1,26 @@
+pub mod archive;
+pub mod bagit;
 pub mod burn;
 pub mod commands;
+pub mod compression;
 pub mod config;
 pub mod database;
+pub mod dedup;
 pub mod dependencies;
+pub mod desktop;
 pub mod disc;
+pub mod incremental;
+pub mod interrupt;
 pub mod iso;
+pub mod iso_library;
 pub mod logging;
 pub mod manifest;
 pub mod paths;
+pub mod plan_report;
 pub mod qrcode;
+pub mod restore;
 pub mod search;
+pub mod size_cache;
 pub mod staging;
 pub mod theme;
 pub mod tui;
@@ -1,25 +1,128 @@
+use crate::config::{LogRotation, LoggingConfig};
 use crate::paths;
-use anyhow::Result;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
+/// Log files are named `bdarchive.YYYY-MM-DD.log` (`bdarchive.YYYY-MM-DD-HH.log`
+/// with hourly rotation) inside the logs directory.
+const LOG_FILE_PREFIX: &str = "bdarchive.";
+/// Prefix used before log rotation was configurable (`bdarchive-YYYY-MM-DD.log`)
+const LEGACY_LOG_FILE_PREFIX: &str = "bdarchive-";
+
+/// Severity of a log line, ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn label(self) -> &'static str {
+        match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
+/// Extract the level from a line written by the `fmt` layer
+/// (`<timestamp>  INFO target: message`). Continuation lines have no level.
+pub fn parse_log_level(line: &str) -> Option<LogLevel> {
+    line.split_whitespace().take(2).find_map(|token| match token {
+        "TRACE" => Some(LogLevel::Trace),
+        "DEBUG" => Some(LogLevel::Debug),
+        "INFO" => Some(LogLevel::Info),
+        "WARN" => Some(LogLevel::Warn),
+        "ERROR" => Some(LogLevel::Error),
+        _ => None,
+    })
+}
+
+/// Whether `name` is one of our log files: `bdarchive.<date>.log` as written
+/// by the rolling appender, `bdarchive.log` without rotation, or the older
+/// `bdarchive-YYYY-MM-DD.log`.
+fn is_log_file_name(name: &str) -> bool {
+    (name.starts_with(LOG_FILE_PREFIX) || name.starts_with(LEGACY_LOG_FILE_PREFIX)) && name.ends_with(".log")
+}
+
+/// Log files in `logs_dir`, oldest first. Dated names sort chronologically,
+/// and older `bdarchive-` names sort before the current ones.
+fn log_files(logs_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(logs_dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.file_name().and_then(|name| name.to_str()).is_some_and(is_log_file_name))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Most recent log file in `logs_dir`, if any.
+pub fn latest_log_file(logs_dir: &Path) -> Option<PathBuf> {
+    log_files(logs_dir).pop()
+}
+
+/// Delete the oldest log files until at most `keep_files` remain and together
+/// they take no more than `max_total_bytes` (0 = no size limit). The newest
+/// file, which is being written to, is always kept. Returns the number removed.
+pub fn prune_logs(logs_dir: &Path, keep_files: usize, max_total_bytes: u64) -> Result<usize> {
+    let mut files: Vec<(PathBuf, u64)> = log_files(logs_dir)
+        .into_iter()
+        .map(|path| {
+            let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            (path, size)
+        })
+        .collect();
+    let mut total: u64 = files.iter().map(|(_, size)| size).sum();
+    let keep_files = keep_files.max(1);
+
+    let mut removed = 0;
+    while files.len() > 1
+        && (files.len() > keep_files || (max_total_bytes > 0 && total > max_total_bytes))
+    {
+        let (path, size) = files.remove(0);
+        std::fs::remove_file(&path)
+            .with_context(|| format!("Failed to remove old log file: {}", path.display()))?;
+        total -= size;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
 /// Initialize logging to file only (console output interferes with TUI).
-pub fn init_logging() -> Result<()> {
+/// Files roll over as configured and old ones are pruned at startup.
+pub fn init_logging(config: &LoggingConfig) -> Result<()> {
     let logs_dir = paths::logs_dir()?;
     std::fs::create_dir_all(&logs_dir)?;
 
-    // Use log file with date in name
-    let date = format_date_simple();
-    let log_file = logs_dir.join(format!("bdarchive-{}.log", date));
-
-    let file = std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_file)?;
+    let rotation = match config.rotation {
+        LogRotation::Hourly => Rotation::HOURLY,
+        LogRotation::Daily => Rotation::DAILY,
+        LogRotation::Never => Rotation::NEVER,
+    };
+    let appender = RollingFileAppender::builder()
+        .rotation(rotation)
+        .filename_prefix(LOG_FILE_PREFIX.trim_end_matches('.'))
+        .filename_suffix("log")
+        .max_log_files(config.keep_files.max(1))
+        .build(&logs_dir)
+        .context("Failed to open log file")?;
 
     // File subscriber only (no console output to avoid corrupting TUI)
     let file_layer = fmt::layer()
         .with_target(true)
-        .with_writer(file)
+        .with_writer(appender)
         .with_ansi(false);
 
     // Combine layers
@@ -30,56 +133,89 @@ pub fn init_logging() -> Result<()> {
         .with(file_layer)
         .init();
 
+    let max_total_bytes = config.max_total_mb * 1024 * 1024;
+    match prune_logs(&logs_dir, config.keep_files, max_total_bytes) {
+        Ok(0) => {}
+        Ok(removed) => tracing::info!("Removed {} old log files", removed),
+        Err(e) => tracing::warn!("Failed to prune old log files: {:#}", e),
+    }
+
     // Don't log this to console since we disabled console logging
-    eprintln!("Logging initialized. Log file: {}", log_file.display());
+    if let Some(log_file) = latest_log_file(&logs_dir) {
+        eprintln!("Logging initialized. Log file: {}", log_file.display());
+    }
 
     Ok(())
 }
 
-/// Format Unix timestamp as YYYY-MM-DD.
-fn format_date(timestamp: u64) -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let datetime = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(timestamp);
-
-    // Simple date formatting without external dependencies
-    // This is a fallback; in production you might want to use a date library
-    // For now, we'll use a simpler approach
-    match datetime.duration_since(UNIX_EPOCH) {
-        Ok(duration) => {
-            let secs = duration.as_secs();
-            let days = secs / 86400;
-            // Approximate: days since Unix epoch
-            // This is a simplified version; for accurate dates you'd need proper date handling
-            // Using a simple heuristic for YYYY-MM-DD format
-            let year = 1970 + (days / 365);
-            let day_of_year = days % 365;
-            let month = 1 + (day_of_year / 30); // Approximate month
-            let day = 1 + (day_of_year % 30);
-            format!("{:04}-{:02}-{:02}", year, month, day)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_log_level() {
+        assert_eq!(
+            parse_log_level("2026-10-16T09:12:01.123456Z  INFO bdarchive::archive: Staging files"),
+            Some(LogLevel::Info)
+        );
+        assert_eq!(
+            parse_log_level("2026-10-16T09:12:01.123456Z ERROR bdarchive::burn: Burn failed"),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(parse_log_level("    continuation of a multi-line message"), None);
+        assert!(LogLevel::Warn > LogLevel::Info);
+    }
+
+    #[test]
+    fn test_latest_log_file() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(latest_log_file(dir.path()), None);
+
+        for name in ["bdarchive-2026-10-14.log", "bdarchive-2026-10-15.log", "other.log"] {
+            std::fs::write(dir.path().join(name), "").unwrap();
         }
-        Err(_) => "unknown".to_string(),
+        assert_eq!(
+            latest_log_file(dir.path()),
+            Some(dir.path().join("bdarchive-2026-10-15.log"))
+        );
+
+        // Files from the rolling appender are newer than the old naming
+        std::fs::write(dir.path().join("bdarchive.2026-10-16.log"), "").unwrap();
+        assert_eq!(
+            latest_log_file(dir.path()),
+            Some(dir.path().join("bdarchive.2026-10-16.log"))
+        );
     }
-}
 
-// Better implementation using time library - but for now use system date
-// Actually, let's use the system's date command or just use a simpler approach
-// For MVP, we'll use the current system date via environment or command
-fn format_date_simple() -> String {
-    // Try to get date from environment or use timestamp
-    if let Ok(date_str) = std::process::Command::new("date")
-        .args(&["+%Y-%m-%d"])
-        .output()
-    {
-        if date_str.status.success() {
-            if let Ok(date) = String::from_utf8(date_str.stdout) {
-                return date.trim().to_string();
-            }
+    #[test]
+    fn test_prune_logs() {
+        let dir = TempDir::new().unwrap();
+        let names = [
+            "bdarchive-2026-10-12.log",
+            "bdarchive.2026-10-13.log",
+            "bdarchive.2026-10-14.log",
+            "bdarchive.2026-10-15.log",
+            "bdarchive.2026-10-16.log",
+        ];
+        for name in names {
+            std::fs::write(dir.path().join(name), vec![b'x'; 100]).unwrap();
         }
+        std::fs::write(dir.path().join("notes.txt"), vec![b'x'; 1000]).unwrap();
+
+        // Count limit
+        assert_eq!(prune_logs(dir.path(), 4, 0).unwrap(), 1);
+        assert!(!dir.path().join(names[0]).exists());
+
+        // Size limit removes the oldest first; other files don't count
+        assert_eq!(prune_logs(dir.path(), 10, 250).unwrap(), 2);
+        assert!(!dir.path().join(names[2]).exists());
+        assert!(dir.path().join(names[3]).exists());
+        assert!(dir.path().join("notes.txt").exists());
+
+        // The current file survives even when it alone is over the limit
+        assert_eq!(prune_logs(dir.path(), 10, 50).unwrap(), 1);
+        assert_eq!(latest_log_file(dir.path()), Some(dir.path().join(names[4])));
+        assert_eq!(prune_logs(dir.path(), 0, 1).unwrap(), 0);
     }
-    // Fallback: use timestamp-based approximation
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
-    format_date(now)
 }
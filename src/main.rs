@@ -1,8 +1,10 @@
 use anyhow::{Context, Result};
 use bdarchive::tui::directory_selector::Focus as DirFocus;
+use bdarchive::tui::new_disc::NoticeLevel;
+use bdarchive::archive::MultiDiscError;
 use bdarchive::*;
 use crossterm::{
-    event::{self, poll, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{self, poll, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -11,7 +13,7 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 use std::thread;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 enum AppState {
     Splash(tui::SplashScreen),
@@ -23,27 +25,29 @@ enum AppState {
     Search(tui::SearchUI),
     Verify(tui::VerifyUI),
     ListDiscs(tui::ListDiscs),
+    Scrub(tui::ScrubView),
+    CatalogStats(tui::CatalogStatsView),
+    ScanQr(tui::ScanQrView),
+    IdentifyDisc(tui::IdentifyDiscView),
+    BurnIso(tui::BurnIsoView),
+    DiscDetail(Box<tui::DiscDetail>),
+    Restore(tui::RestoreView),
+    CatalogDiff(tui::CatalogDiffView),
+    IsoLibrary(tui::IsoLibraryView),
     Settings(tui::Settings),
     Logs(tui::LogsView),
     Quit,
 }
 
-/// Multi-disc operation error types for better error handling
-#[derive(Debug, Clone)]
-pub enum MultiDiscError {
-    PlanningFailed(String),
-    HardwareFailure(String),
-    BurnFailed { disc_number: usize, error: String },
-    UserCancelled,
-    PartialSuccess { completed_discs: Vec<usize>, failed_disc: usize, error: String },
-    StagingFailed { disc_number: usize, error: String },
-    DatabaseInconsistency(String),
-}
-
 enum DiscCreationMessage {
     Status(String),
     StateAndStatus(tui::new_disc::ProcessingState, String),
     Progress(String),
+    PhaseProgress(f64),
+    Hashing(manifest::HashProgress),
+    MultiDiscProgress(archive::MultiDiscProgress),
+    /// ISO kept in the staging directory by the finished run
+    IsoReady(PathBuf),
     Complete,
     Error(String),
     MultiDiscError(MultiDiscError),
@@ -52,6 +56,63 @@ enum DiscCreationMessage {
     ResumeRequested,
 }
 
+/// Flag a New Disc ID that is already in the catalog, offering a free suffixed one
+fn check_new_disc_id(conn: &rusqlite::Connection, flow: &mut tui::new_disc::NewDiscFlow) -> Result<()> {
+    let disc_count = flow.planned_disc_count();
+    let existing = disc::find_existing_disc_ids(conn, &disc::planned_disc_ids(flow.disc_id(), disc_count))?;
+    let suggested = if existing.is_empty() {
+        None
+    } else {
+        Some(disc::suggest_free_disc_id(conn, flow.disc_id(), disc_count)?)
+    };
+    flow.set_disc_id_conflict(existing, suggested);
+    Ok(())
+}
+
+/// Rolling catalog backups, newest first (empty when none can be listed)
+fn catalog_backups(conn: &rusqlite::Connection) -> Vec<PathBuf> {
+    database::backup_dir(conn)
+        .and_then(|dir| database::list_backups(&dir).map_err(|e| warn!("Cannot list backups: {:#}", e)).ok())
+        .unwrap_or_default()
+}
+
+/// Append a recovery suggestion to an error message shown on the New Disc screen
+fn with_recovery_hint(message: String, hint: Option<&str>) -> String {
+    match hint {
+        Some(hint) => format!("{}\n\n💡 {}", message, hint),
+        None => message,
+    }
+}
+
+/// Irreversible action waiting on a confirmation dialog
+enum PendingAction {
+    Quit,
+    StartBurn,
+    DeleteSession(String),
+    RestoreBackup(PathBuf),
+    ReburnIso(String),
+    BurnExternalIso(PathBuf),
+    /// Import the disc in this device or mount directory into the catalog
+    ImportDisc(String),
+}
+
+/// Messages from the restore thread
+enum RestoreMessage {
+    Progress(String),
+    InsertDisc { sequence: u32, total: u32, disc_id: String, problem: Option<String> },
+    /// Summary lines of the finished restore
+    Done(Vec<String>),
+    Error(String),
+}
+
+/// Messages from the multi-disc set verification thread
+enum VerifySetMessage {
+    InsertDisc { sequence: u32, disc_id: String, problem: Option<String> },
+    DiscChecked { sequence: u32, status: verify::DiscVerificationStatus },
+    Done(verify::MultiDiscVerificationResult),
+    Error(String),
+}
+
 struct App {
     state: AppState,
     main_menu: tui::MainMenu,
@@ -61,19 +122,33 @@ struct App {
     footer: ui::header_footer::Footer,
     disc_creation_rx: Option<mpsc::Receiver<DiscCreationMessage>>,
     disc_creation_tx: Option<mpsc::Sender<DiscCreationMessage>>,
+    verify_set_rx: Option<mpsc::Receiver<VerifySetMessage>>,
+    /// Answers disc-insertion prompts from the verification thread (true = inserted)
+    verify_set_reply: Option<mpsc::Sender<bool>>,
+    /// Outcome of a re-burn from the ISO library or of burning an existing ISO (Ok = status message)
+    reburn_rx: Option<mpsc::Receiver<std::result::Result<String, String>>>,
+    restore_rx: Option<mpsc::Receiver<RestoreMessage>>,
+    /// Answers disc-insertion prompts from the restore thread (true = inserted)
+    restore_reply: Option<mpsc::Sender<bool>>,
     pending_disc_creation: Option<(bool, Vec<PathBuf>, Config)>, // (needs_multi_disc, source_folders, config)
+    /// Search screen as it was left, restored when it is opened again
+    last_search: Option<tui::SearchUI>,
+    /// Selection and scroll position of List Discs, restored when it is opened again
+    list_discs_position: tui::ListPosition,
+    /// Open confirmation dialog; it receives all keys until answered
+    confirm: Option<(tui::ConfirmDialog, PendingAction)>,
+    /// Staging directory given with `--staging`, used by new discs in this session only
+    staging_override: Option<PathBuf>,
 }
 
 impl App {
-    fn new(config: Config, db_conn: rusqlite::Connection) -> Self {
-        // Get disc count for splash
-        let disc_count = database::Disc::list_all(&db_conn).unwrap_or_default().len();
+    fn new(config: Config, db_conn: rusqlite::Connection, stored_schema_version: Option<u32>) -> Self {
+        // Check the catalog for the splash
         let db_path = config.database_path().unwrap_or_default();
-        let db_status = if db_path.exists() {
-            tui::DbStatus::Ok
-        } else {
-            tui::DbStatus::Error
-        };
+        let (db_status, disc_count) = tui::DbStatus::probe(&db_conn, stored_schema_version);
+        if let tui::DbStatus::Error(ref msg) = db_status {
+            error!("Catalog check failed: {}", msg);
+        }
 
         let theme = theme::Theme::from_env();
 
@@ -88,7 +163,47 @@ impl App {
             footer: ui::header_footer::Footer::new(),
             disc_creation_rx: None,
             disc_creation_tx: None,
+            verify_set_rx: None,
+            verify_set_reply: None,
+            reburn_rx: None,
+            restore_rx: None,
+            restore_reply: None,
             pending_disc_creation: None,
+            last_search: None,
+            list_discs_position: tui::ListPosition::default(),
+            confirm: None,
+            staging_override: None,
+        }
+    }
+
+    /// Recompute the main menu dashboard if it is missing or older than a few seconds.
+    fn refresh_dashboard_if_stale(&mut self) {
+        if !matches!(self.state, AppState::MainMenu)
+            || !self.main_menu.dashboard_is_stale(std::time::Duration::from_secs(5))
+        {
+            return;
+        }
+        match database::archive_stats(&self.db_conn) {
+            Ok(stats) => {
+                let staging_free_bytes = self
+                    .config
+                    .staging_dir()
+                    .ok()
+                    .and_then(|dir| paths::free_space_bytes(&dir));
+                let catalog_free_bytes = self
+                    .config
+                    .database_path()
+                    .ok()
+                    .and_then(|path| paths::free_space_bytes(&path));
+                self.main_menu.set_dashboard(tui::main_menu::Dashboard {
+                    stats,
+                    staging_free_bytes,
+                    catalog_free_bytes,
+                    min_catalog_free_bytes: self.config.min_data_free_bytes(),
+                    refreshed_at: std::time::Instant::now(),
+                });
+            }
+            Err(e) => warn!("Failed to gather archive stats: {}", e),
         }
     }
 
@@ -97,6 +212,69 @@ impl App {
     fn poll_background_messages(&mut self) -> bool {
         let mut updated = false;
 
+        if let AppState::VerifyMultiDisc(ref mut verify_ui) = self.state {
+            if let Some(ref rx) = self.verify_set_rx {
+                match rx.try_recv() {
+                    Ok(VerifySetMessage::InsertDisc { sequence, disc_id, problem }) => {
+                        verify_ui.request_disc(sequence, disc_id, problem);
+                        updated = true;
+                    }
+                    Ok(VerifySetMessage::DiscChecked { sequence, status }) => {
+                        verify_ui.record_disc(sequence, status);
+                        updated = true;
+                    }
+                    Ok(VerifySetMessage::Done(result)) => {
+                        verify_ui.set_verification_result(result);
+                        self.verify_set_rx = None;
+                        self.verify_set_reply = None;
+                        updated = true;
+                    }
+                    Ok(VerifySetMessage::Error(error)) => {
+                        verify_ui.set_error(error);
+                        self.verify_set_rx = None;
+                        self.verify_set_reply = None;
+                        updated = true;
+                    }
+                    Err(mpsc::TryRecvError::Empty) => {}
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        verify_ui.set_error("Verification terminated unexpectedly".to_string());
+                        self.verify_set_rx = None;
+                        self.verify_set_reply = None;
+                        updated = true;
+                    }
+                }
+            }
+        }
+
+        if let AppState::Restore(ref mut view) = self.state {
+            // Copy progress comes in bursts; take everything queued
+            while let Some(rx) = self.restore_rx.as_ref() {
+                match rx.try_recv() {
+                    Ok(RestoreMessage::Progress(message)) => view.push_progress(message),
+                    Ok(RestoreMessage::InsertDisc { sequence, total, disc_id, problem }) => {
+                        view.request_disc(sequence, total, disc_id, problem);
+                    }
+                    Ok(RestoreMessage::Done(summary)) => {
+                        view.set_done(summary);
+                        self.restore_rx = None;
+                        self.restore_reply = None;
+                    }
+                    Ok(RestoreMessage::Error(error)) => {
+                        view.set_error(error);
+                        self.restore_rx = None;
+                        self.restore_reply = None;
+                    }
+                    Err(mpsc::TryRecvError::Empty) => break,
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        view.set_error("Restore terminated unexpectedly".to_string());
+                        self.restore_rx = None;
+                        self.restore_reply = None;
+                    }
+                }
+                updated = true;
+            }
+        }
+
         if let AppState::NewDisc(ref mut flow) = self.state {
             if let Some(ref rx) = self.disc_creation_rx {
                 match rx.try_recv() {
@@ -110,40 +288,23 @@ impl App {
                         updated = true;
                     }
                     Ok(DiscCreationMessage::Progress(progress)) => {
-                        flow.set_file_progress(progress.clone());
-
-                        // Handle multi-disc verification completion
-                        // TODO: Add verification result handling when needed
-
-                        // Parse multi-disc progress from the message
-                        if progress.contains("Disc ") && progress.contains("/") {
-                            // Try to extract disc numbers: "💿 Disc 2/3 | 📊 33.3% complete"
-                            if let Some(disc_part) = progress.split("Disc ").nth(1) {
-                                if let Some(disc_info) = disc_part.split(" | ").next() {
-                                    let parts: Vec<&str> = disc_info.split('/').collect();
-                                    if parts.len() == 2 {
-                                        if let (Ok(current), Ok(total)) = (
-                                            parts[0].trim().parse::<u32>(),
-                                            parts[1].trim().parse::<u32>()
-                                        ) {
-                                            // Extract overall progress percentage
-                                            let overall_progress = if let Some(percent_part) = progress.split("📊 ").nth(1) {
-                                                if let Some(percent_str) = percent_part.split('%').next() {
-                                                    percent_str.trim().parse::<f64>().unwrap_or(0.0) / 100.0
-                                                } else {
-                                                    0.0
-                                                }
-                                            } else {
-                                                0.0
-                                            };
-
-                                            flow.set_multi_disc_progress(current, total, overall_progress);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-
+                        flow.set_file_progress(progress);
+                        updated = true;
+                    }
+                    Ok(DiscCreationMessage::PhaseProgress(fraction)) => {
+                        flow.set_phase_progress(fraction);
+                        updated = true;
+                    }
+                    Ok(DiscCreationMessage::Hashing(progress)) => {
+                        flow.set_hash_progress(progress);
+                        updated = true;
+                    }
+                    Ok(DiscCreationMessage::MultiDiscProgress(progress)) => {
+                        flow.set_multi_disc_progress(progress);
+                        updated = true;
+                    }
+                    Ok(DiscCreationMessage::IsoReady(path)) => {
+                        flow.set_iso_path(path, bdarchive::desktop::file_manager_available());
                         updated = true;
                     }
                     Ok(DiscCreationMessage::Complete) => {
@@ -163,41 +324,56 @@ impl App {
                         updated = true;
                     }
                     Ok(DiscCreationMessage::MultiDiscError(error)) => {
+                        let hint = error.recovery_hint();
                         match error {
-                            MultiDiscError::PartialSuccess { completed_discs, failed_disc, error } => {
-                                flow.set_error(format!("Partial success: {} discs completed. Disc {} failed: {}",
-                                    completed_discs.len(), failed_disc, error));
+                            MultiDiscError::PartialSuccess { session_id, completed_discs, failed_disc, error } => {
+                                flow.set_error(with_recovery_hint(
+                                    format!("Partial success: {} discs completed. Disc {} failed: {}",
+                                        completed_discs.len(), failed_disc, error),
+                                    hint,
+                                ));
+                                flow.set_abortable_session(session_id);
+                                if let archive::ArchiveError::CapacityExceeded { required, .. } = error {
+                                    flow.set_overflow(required);
+                                }
                                 // Keep receiver alive for potential user choice
                             }
-                            MultiDiscError::HardwareFailure(msg) => {
-                                flow.set_error(format!("Hardware error: {}", msg));
+                            MultiDiscError::BurnFailed { session_id, disc_number, error } => {
+                                flow.set_error(with_recovery_hint(
+                                    format!("Disc {} failed to burn: {}", disc_number, error),
+                                    hint,
+                                ));
+                                flow.set_abortable_session(session_id);
+                                if let archive::ArchiveError::CapacityExceeded { required, .. } = error {
+                                    flow.set_overflow(required);
+                                }
                                 self.disc_creation_rx = None;
                             }
                             MultiDiscError::UserCancelled => {
-                                flow.set_status("Operation cancelled by user".to_string());
+                                flow.set_notice(NoticeLevel::Warn, "Operation cancelled by user");
                                 self.disc_creation_rx = None;
                             }
-                            _ => {
-                                flow.set_error(format!("Multi-disc error: {:?}", error));
+                            error => {
+                                flow.set_error(with_recovery_hint(error.to_string(), hint));
                                 self.disc_creation_rx = None;
                             }
                         }
                         updated = true;
                     }
                     Ok(DiscCreationMessage::UserChoiceNeeded { message, options }) => {
-                        // For now, just show the message. In a full implementation,
-                        // this would present the user with choices and send responses back
-                        flow.set_error(format!("User choice needed: {}\nOptions: {:?}", message, options));
-                        // Keep receiver alive to wait for user response
+                        // The choices are answered with keys on the error screen, which
+                        // keeps the failure itself; the question goes on the notice line
+                        debug!("User choice needed: {} ({})", message, options.join("; "));
+                        flow.set_notice(NoticeLevel::Warn, message);
                         updated = true;
                     }
                     Ok(DiscCreationMessage::PauseRequested) => {
-                        flow.set_status("⏸️ Burn paused by user. Press 'r' to resume or 'Esc' to cancel.".to_string());
-                        flow.set_processing_state(tui::new_disc::ProcessingState::Error("Paused".to_string()));
+                        flow.set_notice(NoticeLevel::Info, "⏸️ Burn paused by user. Press 'r' to resume or 'Esc' to cancel.");
+                        flow.set_processing_state(tui::new_disc::ProcessingState::Paused);
                         updated = true;
                     }
                     Ok(DiscCreationMessage::ResumeRequested) => {
-                        flow.set_status("▶️ Resuming burn process...".to_string());
+                        flow.set_notice(NoticeLevel::Info, "▶️ Resuming burn process...");
                         flow.set_processing_state(tui::new_disc::ProcessingState::Staging);
                         updated = true;
                     }
@@ -216,15 +392,86 @@ impl App {
             }
         }
 
+        if let AppState::IsoLibrary(ref mut library) = self.state {
+            if let Some(ref rx) = self.reburn_rx {
+                let outcome = match rx.try_recv() {
+                    Ok(Ok(message)) => Some(message),
+                    Ok(Err(e)) => Some(format!("❌ Re-burn failed: {}", e)),
+                    Err(mpsc::TryRecvError::Empty) => None,
+                    Err(mpsc::TryRecvError::Disconnected) => Some("❌ Re-burn terminated unexpectedly".to_string()),
+                };
+                if let Some(message) = outcome {
+                    library.finish_burn(message);
+                    self.reburn_rx = None;
+                    updated = true;
+                }
+            }
+        }
+
+        if let AppState::BurnIso(ref mut view) = self.state {
+            if let Some(ref rx) = self.reburn_rx {
+                let outcome = match rx.try_recv() {
+                    Ok(Ok(message)) => Some(message),
+                    Ok(Err(e)) => Some(format!("❌ Burn failed: {}", e)),
+                    Err(mpsc::TryRecvError::Empty) => None,
+                    Err(mpsc::TryRecvError::Disconnected) => Some("❌ Burn terminated unexpectedly".to_string()),
+                };
+                if let Some(message) = outcome {
+                    view.finish_burn(message);
+                    self.reburn_rx = None;
+                    updated = true;
+                }
+            }
+        }
+
         updated
     }
 
     fn handle_key(&mut self, key: KeyCode) -> Result<bool> {
-        // Universal quit key - works from all screens
-        if matches!(key, KeyCode::Char('q') | KeyCode::Char('Q')) {
+        // An open confirmation dialog traps every key until it is answered
+        if let Some((dialog, _)) = &self.confirm {
+            match dialog.handle_key(key) {
+                tui::ConfirmResponse::Pending => return Ok(true),
+                tui::ConfirmResponse::Cancelled => {
+                    self.confirm = None;
+                    return Ok(true);
+                }
+                tui::ConfirmResponse::Confirmed => {
+                    let (_, action) = self.confirm.take().expect("dialog checked above");
+                    return self.run_confirmed(action);
+                }
+            }
+        }
+
+        // Universal quit key - works from all screens except while typing into a text box
+        let typing = matches!(&self.state, AppState::ListDiscs(list) if list.is_editing())
+            || matches!(&self.state, AppState::Logs(logs) if logs.is_searching())
+            || matches!(&self.state, AppState::ScanQr(_))
+            || matches!(&self.state, AppState::IdentifyDisc(_))
+            || matches!(&self.state, AppState::BurnIso(_))
+            || matches!(&self.state, AppState::CatalogDiff(_))
+            || matches!(&self.state, AppState::Restore(view) if view.is_editing())
+            || matches!(&self.state, AppState::NewDisc(flow) if flow.is_renaming() || flow.is_editing_staging());
+        if !typing && matches!(key, KeyCode::Char('q') | KeyCode::Char('Q')) {
+            if self.burn_in_progress() {
+                self.confirm = Some((
+                    tui::ConfirmDialog::new(
+                        "Quit during burn?",
+                        "A burn is in progress — quit anyway?\nThe disc being written is abandoned (a partially written BD-R\ncannot be reused); a multi-disc set is paused and can be\ncontinued from Resume Burn.",
+                    )
+                    .with_confirm_label("Quit anyway"),
+                    PendingAction::Quit,
+                ));
+                return Ok(true);
+            }
             return Ok(false); // false = quit application
         }
 
+        // The last action's notice stays until the next key on the menu
+        if matches!(self.state, AppState::MainMenu) {
+            self.main_menu.clear_notice();
+        }
+
         match &mut self.state {
             AppState::Splash(ref mut splash) => {
                 // Skip splash on any keypress
@@ -237,30 +484,79 @@ impl App {
                 KeyCode::Down | KeyCode::Char('j') => self.main_menu.next(),
                 KeyCode::Enter => match self.main_menu.selected_action() {
                     tui::MainMenuAction::NewDisc => {
-                        let default_id = disc::generate_disc_id();
-                        self.state = AppState::NewDisc(Box::new(tui::NewDiscFlow::new(default_id)));
+                        let default_id = disc::render_disc_id_template(&self.db_conn, &self.config.disc_id_template)
+                            .unwrap_or_else(|e| {
+                                warn!("Falling back to the default disc ID: {}", e);
+                                disc::generate_disc_id()
+                            });
+                        let mut flow = tui::NewDiscFlow::new(default_id);
+                        flow.set_checksum(self.config.staging.checksum);
+                        flow.set_staging_override(self.staging_override.clone());
+                        match database::recent_sources(&self.db_conn) {
+                            Ok(recent) => flow.set_recent_folders(recent),
+                            Err(e) => warn!("Failed to load recent source folders: {}", e),
+                        }
+                        self.state = AppState::NewDisc(Box::new(flow));
+                    }
+                    tui::MainMenuAction::ReburnIso => {
+                        let entries = iso_library::list_library(&self.db_conn)?;
+                        self.state = AppState::IsoLibrary(tui::IsoLibraryView::new(entries));
+                    }
+                    tui::MainMenuAction::BurnExistingIso => {
+                        self.state = AppState::BurnIso(tui::BurnIsoView::new());
                     }
                     tui::MainMenuAction::SearchIndex => {
-                        self.state = AppState::Search(tui::SearchUI::new());
+                        let mut search = self.last_search.take().unwrap_or_default();
+                        if !search.results().is_empty() {
+                            // Same query, fresh results (discs may have been added meanwhile)
+                            let results = search::search_files(&self.db_conn, &search.build_search_query())?;
+                            search.refresh_results(results);
+                        }
+                        self.state = AppState::Search(search);
                     }
                     tui::MainMenuAction::VerifyDisc => {
-                        self.state = AppState::Verify(tui::VerifyUI::new());
+                        let mut verify = tui::VerifyUI::new();
+                        verify.set_sample_size(self.config.verification.sample_size.clone());
+                        self.state = AppState::Verify(verify);
                     }
                     tui::MainMenuAction::VerifyMultiDisc => {
                         // Load available multi-disc sets
-                        let disc_sets = database::DiscSet::list_all(&self.db_conn)?;
+                        // Sets planned by a dry run have no discs to insert
+                        let disc_sets = database::DiscSet::list_burned(&self.db_conn)?;
+                        let last_runs = disc_sets
+                            .iter()
+                            .map(|set| database::SetVerificationRun::latest_for_set(&self.db_conn, &set.set_id))
+                            .collect::<Result<Vec<_>>>()?;
                         let mut verify_ui = tui::VerifyMultiDiscUI::new();
-                        verify_ui.set_disc_sets(disc_sets);
+                        verify_ui.set_disc_sets(disc_sets, last_runs);
                         self.state = AppState::VerifyMultiDisc(verify_ui);
                     }
                     tui::MainMenuAction::ListDiscs => {
-                        let discs = database::Disc::list_all(&self.db_conn)?;
                         let mut list = tui::ListDiscs::new();
-                        list.set_discs(discs);
+                        load_disc_list(&self.db_conn, &mut list)?;
+                        list.restore_position(&self.list_discs_position);
                         self.state = AppState::ListDiscs(list);
                     }
+                    tui::MainMenuAction::CatalogStats => {
+                        let stats = database::archive_stats(&self.db_conn)?;
+                        let breakdown = database::extension_breakdown(&self.db_conn, None)?;
+                        self.state = AppState::CatalogStats(tui::CatalogStatsView::new(stats, breakdown));
+                    }
+                    tui::MainMenuAction::ScanLabel => {
+                        self.state = AppState::ScanQr(tui::ScanQrView::new());
+                    }
+                    tui::MainMenuAction::IdentifyDisc => {
+                        self.state = AppState::IdentifyDisc(tui::IdentifyDiscView::new(&self.config.device));
+                    }
+                    tui::MainMenuAction::Scrub => {
+                        let days = self.config.verification.scrub_after_days;
+                        let discs = verify::find_stale_discs(&self.db_conn, days)?;
+                        self.state = AppState::Scrub(tui::ScrubView::new(discs, days));
+                    }
                     tui::MainMenuAction::Settings => {
-                        self.state = AppState::Settings(tui::Settings::new());
+                        let mut settings = tui::Settings::new();
+                        settings.set_backups(catalog_backups(&self.db_conn));
+                        self.state = AppState::Settings(settings);
                     }
                     tui::MainMenuAction::Logs => {
                         self.state = AppState::Logs(tui::LogsView::new());
@@ -317,6 +613,24 @@ impl App {
                 }
                 _ => {}
             },
+            // Editing a folder's disc-side name takes every key until saved or cancelled
+            AppState::NewDisc(ref mut flow) if flow.is_renaming() => match key {
+                KeyCode::Esc => flow.cancel_rename(),
+                KeyCode::Enter => flow.commit_rename(),
+                KeyCode::Up => flow.rename_previous(),
+                KeyCode::Down => flow.rename_next(),
+                KeyCode::Backspace => flow.rename_backspace(),
+                KeyCode::Char(c) => flow.rename_input_char(c),
+                _ => {}
+            },
+            // So does typing a staging directory for the run
+            AppState::NewDisc(ref mut flow) if flow.is_editing_staging() => match key {
+                KeyCode::Esc => flow.cancel_staging_edit(),
+                KeyCode::Enter => flow.commit_staging_edit(&self.config),
+                KeyCode::Backspace => flow.staging_backspace(),
+                KeyCode::Char(c) => flow.staging_input_char(c),
+                _ => {}
+            },
             AppState::NewDisc(ref mut flow) => {
                 match key {
                     KeyCode::Esc => {
@@ -325,8 +639,11 @@ impl App {
                             if matches!(flow.processing_state(), tui::new_disc::ProcessingState::Complete) {
                                 self.state = AppState::MainMenu;
                                 return Ok(true);
-                            } else if matches!(flow.processing_state(), tui::new_disc::ProcessingState::Error(_)) {
-                                // Allow escape on error too - go back to review
+                            } else if matches!(
+                                flow.processing_state(),
+                                tui::new_disc::ProcessingState::Error(_) | tui::new_disc::ProcessingState::Paused
+                            ) {
+                                // Allow escape on error or pause too - go back to review
                                 flow.previous_step();
                                 flow.clear_error();
                                 return Ok(true);
@@ -337,22 +654,22 @@ impl App {
                         }
                         self.state = AppState::MainMenu;
                     }
-                    KeyCode::Char('p') | KeyCode::Char('P') => {
+                    KeyCode::Char('p') | KeyCode::Char('P') if flow.overflow_to_replan().is_none() => {
                         if flow.current_step() == tui::new_disc::NewDiscStep::Processing {
                             if let Some(ref tx) = self.disc_creation_tx {
                                 // Send pause request to background thread
                                 let _ = tx.send(DiscCreationMessage::PauseRequested);
-                                flow.set_status("⏸️ Pause requested...".to_string());
+                                flow.set_notice(NoticeLevel::Info, "⏸️ Pause requested...");
                                 return Ok(true);
                             }
                         }
                     }
-                    KeyCode::Char('r') | KeyCode::Char('R') => {
+                    KeyCode::Char('r') | KeyCode::Char('R') if !flow.can_retry_failed_disc() => {
                         if flow.current_step() == tui::new_disc::NewDiscStep::Processing {
                             if let Some(ref tx) = self.disc_creation_tx {
                                 // Send resume request to background thread
                                 let _ = tx.send(DiscCreationMessage::ResumeRequested);
-                                flow.set_status("▶️ Resume requested...".to_string());
+                                flow.set_notice(NoticeLevel::Info, "▶️ Resume requested...");
                                 return Ok(true);
                             }
                         }
@@ -435,39 +752,41 @@ impl App {
                                 // Only proceed to next step if folders are selected (and Enter wasn't handled above)
                                 if !flow.source_folders().is_empty() {
                                     flow.next_step(&self.config)?;
+                                    check_new_disc_id(&self.db_conn, flow)?;
                                 }
                             }
                             tui::new_disc::NewDiscStep::Review => {
-                                // For Review step, Enter starts the process
-                                flow.next_step(&self.config)?;
-
-                                // Check if we need multi-disc burning
-                                let source_folders = flow.source_folders().to_vec();
-                                let config = self.config.clone();
-
-                                // Calculate total size to determine if multi-disc is needed
-                                let disc_capacity = config.default_capacity_bytes();
-                                match staging::check_capacity(&source_folders, disc_capacity) {
-                                    Ok((total_size, exceeds)) => {
-                                        if exceeds {
-                                            info!("Content exceeds single disc capacity ({} bytes), starting multi-disc workflow", total_size);
-                                            flow.set_status("Planning multi-disc layout...".to_string());
-                                        } else {
-                                            info!("Content fits on single disc ({} bytes), starting single-disc workflow", total_size);
-                                            flow.set_status("Starting disc creation...".to_string());
-                                        }
-            // Store the request for processing after the match
-            info!("Setting pending_disc_creation: multi_disc={}, folders={}", exceeds, source_folders.len());
-            self.pending_disc_creation = Some((exceeds, source_folders, config));
-            info!("pending_disc_creation set successfully");
-                                    }
-                                    Err(e) => {
-                                        flow.set_status(format!("Error calculating size: {}", e));
-                                        flow.set_error("Failed to analyze content size".to_string());
-                                        flow.previous_step();
+                                // Without a layout estimate the oversize check can't run:
+                                // retry it and let the user review the result first
+                                if !flow.has_plan_summary() {
+                                    if let Err(e) = flow.calculate_capacity_check(&self.config) {
+                                        warn!("Disc layout estimate failed: {:#}", e);
+                                        flow.set_notice(NoticeLevel::Error, format!("Cannot estimate disc layout: {:#}", e));
                                     }
+                                    return Ok(true);
                                 }
 
+                                // Never start a burn that would have to leave files out,
+                                // one whose disc ID is already catalogued, or one whose
+                                // folders would collide on the disc
+                                if flow.burn_blocked() {
+                                    return Ok(true);
+                                }
+
+                                if flow.dry_run() {
+                                    self.start_disc_creation()?;
+                                } else {
+                                    let message = format!(
+                                        "Burn disc {} from {} folder(s)?\nWritten BD-R media cannot be erased or reused.",
+                                        flow.disc_id(),
+                                        flow.source_folders().len()
+                                    );
+                                    self.confirm = Some((
+                                        tui::ConfirmDialog::new("Start burn?", message)
+                                            .with_confirm_label("Burn"),
+                                        PendingAction::StartBurn,
+                                    ));
+                                }
                                 return Ok(true);
                             }
                             tui::new_disc::NewDiscStep::Processing => {
@@ -559,6 +878,17 @@ impl App {
                             _ => {}
                         }
                     }
+                    KeyCode::Delete | KeyCode::PageUp | KeyCode::PageDown
+                        if flow.current_step() == tui::new_disc::NewDiscStep::SelectFolders =>
+                    {
+                        // Act on the highlighted entry of the selected folders list
+                        match key {
+                            KeyCode::Delete => flow.remove_selected_folder(),
+                            KeyCode::PageUp => flow.select_previous_folder(),
+                            _ => flow.select_next_folder(),
+                        }
+                        return Ok(true);
+                    }
                     KeyCode::Insert => {
                         match flow.current_step() {
                             tui::new_disc::NewDiscStep::SelectFolders => {
@@ -626,6 +956,25 @@ impl App {
                                     let current_dry_run = flow.dry_run();
                                     flow.set_dry_run(!current_dry_run);
                                     return Ok(true);
+                                }
+                                if (c == 'n' || c == 'N')
+                                    && flow.directory_selector_mut().is_some_and(|s| s.focus() == DirFocus::Browser)
+                                {
+                                    // N key: name the last added folder on the disc
+                                    flow.start_rename();
+                                    return Ok(true);
+                                } else if flow.handle_mark_key(c) {
+                                    // Space marks the highlighted folder, A adds every marked folder
+                                    return Ok(true);
+                                } else if let Some(number) = c.to_digit(10).filter(|_| {
+                                    // Digits are path input while typing a path
+                                    flow.directory_selector_mut()
+                                        .is_none_or(|s| s.focus() == DirFocus::Browser || s.input_buffer().is_empty())
+                                }) {
+                                    // 1-9: add a recently used source folder
+                                    if flow.add_recent_folder(number as usize) {
+                                        return Ok(true);
+                                    }
                                 } else if c == 'r' || c == 'R' {
                                     // R key: retry loading if there was an error
                                     if let Some(ref mut selector) = flow.directory_selector_mut() {
@@ -659,16 +1008,80 @@ impl App {
                                 }
                             }
                             tui::new_disc::NewDiscStep::Review => {
-                                // Handle special keys for Review step
-                                if c == 'd' || c == 'D' {
-                                    // Toggle dry run mode
-                                    let current_dry_run = flow.dry_run();
-                                    flow.set_dry_run(!current_dry_run);
+                                // Handle special keys for Review step: D, I and C toggle run options
+                                if flow.toggle_review_option(c) {
+                                    return Ok(true);
+                                }
+                                if c == 't' || c == 'T' {
+                                    flow.start_staging_edit(&self.config);
+                                    return Ok(true);
+                                }
+                                if flow.has_disc_id_conflict() {
+                                    if c == 'a' || c == 'A' {
+                                        flow.use_suggested_disc_id();
+                                        check_new_disc_id(&self.db_conn, flow)?;
+                                    } else if c == 'e' || c == 'E' {
+                                        flow.edit_disc_id();
+                                    }
                                     return Ok(true);
                                 }
                                 // Other characters are ignored in review step
                             }
-                            _ => {}
+                            tui::new_disc::NewDiscStep::Processing => {
+                                if matches!(c, 'a' | 'A') {
+                                    let Some(session_id) = flow.abortable_session().map(str::to_string) else {
+                                        return Ok(true);
+                                    };
+                                    match archive::abort_multi_disc(&mut self.db_conn, &session_id, &flow.run_config(&self.config)) {
+                                        Ok(cleanup) => {
+                                            self.disc_creation_rx = None;
+                                            self.main_menu.set_notice(cleanup.summary());
+                                            self.state = AppState::MainMenu;
+                                        }
+                                        Err(e) => flow.set_notice(NoticeLevel::Error, format!("Cleanup failed: {:#}", e)),
+                                    }
+                                    return Ok(true);
+                                }
+                                if matches!(c, 'r' | 'R') && flow.can_retry_failed_disc() {
+                                    let Some(session_id) = flow.abortable_session().map(str::to_string) else {
+                                        return Ok(true);
+                                    };
+                                    let db_path = self.config.database_path()?;
+                                    Self::start_retry_failed_disc(flow, session_id, &self.config, db_path, &mut self.disc_creation_rx);
+                                    return Ok(true);
+                                }
+                                if matches!(c, 'p' | 'P') {
+                                    let (Some(session_id), Some(iso_bytes)) =
+                                        (flow.abortable_session().map(str::to_string), flow.overflow_to_replan())
+                                    else {
+                                        return Ok(true);
+                                    };
+                                    let db_path = self.config.database_path()?;
+                                    Self::start_replan_overflowing_disc(
+                                        flow,
+                                        session_id,
+                                        iso_bytes,
+                                        &self.config,
+                                        db_path,
+                                        &mut self.disc_creation_rx,
+                                    );
+                                    return Ok(true);
+                                }
+                                let Some(iso_path) = flow.completed_iso_path().cloned() else {
+                                    return Ok(true);
+                                };
+                                match c {
+                                    'o' | 'O' => match bdarchive::desktop::open_containing_folder(&iso_path) {
+                                        Ok(()) => flow.set_notice(NoticeLevel::Info, "📂 Opened the ISO folder in the file manager"),
+                                        Err(e) => flow.set_notice(NoticeLevel::Warn, format!("Cannot open the file manager: {:#}", e)),
+                                    },
+                                    'y' | 'Y' => match bdarchive::desktop::copy_to_clipboard(&iso_path.to_string_lossy()) {
+                                        Ok(()) => flow.set_notice(NoticeLevel::Info, "📋 ISO path copied to the clipboard"),
+                                        Err(e) => flow.set_notice(NoticeLevel::Warn, format!("Cannot copy the ISO path: {:#}", e)),
+                                    },
+                                    _ => {}
+                                }
+                            }
                         }
                     }
                     _ => {}
@@ -693,10 +1106,14 @@ impl App {
                         } else if resume_ui.is_cleanup_mode() {
                             // Handle cleanup action
                             if let Some(session_id) = resume_ui.selected_session_for_cleanup() {
-                                database::BurnSessionOps::delete_session(&self.db_conn, &session_id)?;
-                                // Refresh the UI
-                                let sessions = database::BurnSessionOps::get_active_sessions(&self.db_conn)?;
-                                resume_ui.set_sessions(sessions);
+                                self.confirm = Some((
+                                    tui::ConfirmDialog::new(
+                                        "Delete session?",
+                                        format!("Delete burn session {}?\nIt can no longer be resumed afterwards.", session_id),
+                                    )
+                                    .with_confirm_label("Delete"),
+                                    PendingAction::DeleteSession(session_id),
+                                ));
                             }
                         }
                     }
@@ -710,9 +1127,18 @@ impl App {
             AppState::VerifyMultiDisc(ref mut verify_ui) => {
                 match key {
                     KeyCode::Esc => {
+                        // Dropping the reply channel makes the worker skip the remaining discs
+                        self.verify_set_rx = None;
+                        self.verify_set_reply = None;
                         self.state = AppState::MainMenu;
                         return Ok(true);
                     }
+                    KeyCode::Enter | KeyCode::Char('s') if verify_ui.is_waiting_for_disc() => {
+                        if let Some(ref reply) = self.verify_set_reply {
+                            let _ = reply.send(key == KeyCode::Enter);
+                        }
+                        verify_ui.start_verifying();
+                    }
                     KeyCode::Up | KeyCode::Char('k') => {
                         if verify_ui.is_selecting() {
                             verify_ui.previous();
@@ -723,27 +1149,47 @@ impl App {
                             verify_ui.next();
                         }
                     }
-                    KeyCode::Enter => {
+                    KeyCode::Enter if verify_ui.is_selecting() => {
                         if let Some(selected_set) = verify_ui.selected_set() {
                             // Start verification
                             let set_id = selected_set.set_id.clone();
+                            let total_discs = selected_set.disc_count;
+                            let db_path = self.config.database_path()?;
+                            let device = self.config.device.clone();
                             let (tx, rx) = mpsc::channel();
-                            self.disc_creation_rx = Some(rx);
+                            let (reply_tx, reply_rx) = mpsc::channel::<bool>();
+                            self.verify_set_rx = Some(rx);
+                            self.verify_set_reply = Some(reply_tx);
 
                             verify_ui.set_status("🔍 Starting multi-disc verification...".to_string());
+                            verify_ui.begin_set(total_discs);
 
                             thread::spawn(move || {
-                                match crate::verify::verify_multi_disc_set(&set_id, None, false) {
-                                    Ok(result) => {
-                                        let _ = tx.send(DiscCreationMessage::Status("✅ Verification complete".to_string()));
-                                        // In a real implementation, we'd send the result back
-                                        // For now, just indicate completion
-                                        let _ = tx.send(DiscCreationMessage::Complete);
-                                    }
-                                    Err(e) => {
-                                        let _ = tx.send(DiscCreationMessage::Error(format!("Verification failed: {}", e)));
-                                    }
-                                }
+                                let progress_tx = tx.clone();
+                                let result = database::init_database(&db_path).and_then(|conn| {
+                                    verify::verify_multi_disc_set(
+                                        &conn,
+                                        &set_id,
+                                        &device,
+                                        None,
+                                        false,
+                                        |sequence, _total, disc_id, problem| {
+                                            let _ = tx.send(VerifySetMessage::InsertDisc {
+                                                sequence,
+                                                disc_id: disc_id.to_string(),
+                                                problem: problem.map(str::to_string),
+                                            });
+                                            reply_rx.recv().unwrap_or(false)
+                                        },
+                                        |sequence, status| {
+                                            let _ = progress_tx.send(VerifySetMessage::DiscChecked { sequence, status: status.clone() });
+                                        },
+                                    )
+                                });
+                                let _ = match result {
+                                    Ok(result) => tx.send(VerifySetMessage::Done(result)),
+                                    Err(e) => tx.send(VerifySetMessage::Error(format!("Verification failed: {}", e))),
+                                };
                             });
                         }
                     }
@@ -778,6 +1224,7 @@ impl App {
             AppState::Search(ref mut search) => {
                 match key {
                     KeyCode::Esc => {
+                        self.last_search = Some(std::mem::take(search));
                         self.state = AppState::MainMenu;
                     }
                     KeyCode::Up | KeyCode::Char('k') => {
@@ -786,22 +1233,26 @@ impl App {
                     KeyCode::Down | KeyCode::Char('j') => {
                         search.next_result();
                     }
+                    KeyCode::Tab => {
+                        search.cycle_sort();
+                        run_search(&self.db_conn, search)?;
+                    }
+                    KeyCode::PageDown if search.next_page() => {
+                        run_search(&self.db_conn, search)?;
+                    }
+                    KeyCode::PageUp if search.previous_page() => {
+                        run_search(&self.db_conn, search)?;
+                    }
                     KeyCode::Char(c) => {
                         // Only add characters that aren't navigation keys
                         if c != 'k' && c != 'j' {
                             search.add_char(c);
-                            // Perform search
-                            let query = search.build_search_query();
-                            let results = search::search_files(&self.db_conn, &query)?;
-                            search.set_results(results);
+                            run_search(&self.db_conn, search)?;
                         }
                     }
                     KeyCode::Backspace => {
                         search.delete_char();
-                        // Perform search
-                        let query = search.build_search_query();
-                        let results = search::search_files(&self.db_conn, &query)?;
-                        search.set_results(results);
+                        run_search(&self.db_conn, search)?;
                     }
                     _ => {}
                 }
@@ -891,6 +1342,30 @@ impl App {
                             verify.set_input_buffer(buffer);
                         }
                     }
+                    KeyCode::Char('s') | KeyCode::Char('S')
+                        if matches!(verify.verification_state(), tui::verify_ui::VerificationState::Idle)
+                            && verify.input_mode() == tui::verify_ui::VerifyInputMode::Ready =>
+                    {
+                        verify.toggle_sample();
+                    }
+                    KeyCode::Char(c @ ('o' | 'O' | 'y' | 'Y'))
+                        if matches!(verify.verification_state(), tui::verify_ui::VerificationState::Complete) =>
+                    {
+                        let Some(path) = verify.kept_mount().map(Path::to_path_buf) else {
+                            return Ok(true);
+                        };
+                        if matches!(c, 'o' | 'O') {
+                            match bdarchive::desktop::open_folder(&path) {
+                                Ok(()) => verify.set_notice("📂 Opened the disc in the file manager"),
+                                Err(e) => verify.set_notice(format!("Cannot open the file manager: {:#}", e)),
+                            }
+                        } else {
+                            match bdarchive::desktop::copy_to_clipboard(&path.to_string_lossy()) {
+                                Ok(()) => verify.set_notice("📋 Mountpoint copied to the clipboard"),
+                                Err(e) => verify.set_notice(format!("Cannot copy the mountpoint: {:#}", e)),
+                            }
+                        }
+                    }
                     KeyCode::Char(c) => {
                         if matches!(
                             verify.verification_state(),
@@ -904,8 +1379,72 @@ impl App {
                     _ => {}
                 }
             }
+            AppState::ListDiscs(ref mut list) if list.is_editing() => match key {
+                KeyCode::Esc => {
+                    list.cancel_editing();
+                }
+                KeyCode::Enter => {
+                    let disc_id = list.selected_disc().map(|d| d.disc_id.clone());
+                    if let (Some(disc_id), Some((field, input))) = (disc_id, list.finish_editing()) {
+                        match field {
+                            tui::DiscField::Notes => {
+                                let notes = input.trim();
+                                database::Disc::update_notes(
+                                    &self.db_conn,
+                                    &disc_id,
+                                    if notes.is_empty() { None } else { Some(notes) },
+                                )?;
+                                info!("Updated notes for disc {}", disc_id);
+                            }
+                            tui::DiscField::Tags => {
+                                let wanted: Vec<String> =
+                                    input.split_whitespace().filter_map(database::normalize_tag).collect();
+                                for tag in database::Disc::tags_for(&self.db_conn, &disc_id)? {
+                                    if !wanted.contains(&tag) {
+                                        database::Disc::remove_tag(&self.db_conn, &disc_id, &tag)?;
+                                    }
+                                }
+                                for tag in &wanted {
+                                    database::Disc::add_tag(&self.db_conn, &disc_id, tag)?;
+                                }
+                                info!("Updated tags for disc {}: {:?}", disc_id, wanted);
+                            }
+                            tui::DiscField::Location => {
+                                database::Disc::set_storage_location(&self.db_conn, &disc_id, Some(&input))?;
+                                info!("Updated storage location for disc {}", disc_id);
+                                // Keep the printed label in step with the catalog
+                                if self.config.optional_tools.use_qrencode {
+                                    if let Err(e) = archive::regenerate_label(&self.db_conn, &self.config, &disc_id) {
+                                        warn!("Failed to regenerate label for {}: {:#}", disc_id, e);
+                                    }
+                                }
+                            }
+                        }
+                        let index = list.selected().unwrap_or(0);
+                        load_disc_list(&self.db_conn, list)?;
+                        list.select(index);
+                    }
+                }
+                KeyCode::Backspace => {
+                    list.input_backspace();
+                }
+                KeyCode::Char(c) => {
+                    list.input_char(c);
+                }
+                _ => {}
+            },
             AppState::ListDiscs(ref mut list) => match key {
+                KeyCode::Char('e') => {
+                    list.start_editing(tui::DiscField::Notes);
+                }
+                KeyCode::Char('t') => {
+                    list.start_editing(tui::DiscField::Tags);
+                }
+                KeyCode::Char('l') => {
+                    list.start_editing(tui::DiscField::Location);
+                }
                 KeyCode::Esc => {
+                    self.list_discs_position = list.position();
                     self.state = AppState::MainMenu;
                 }
                 KeyCode::Up | KeyCode::Char('k') => {
@@ -914,72 +1453,405 @@ impl App {
                 KeyCode::Down | KeyCode::Char('j') => {
                     list.next();
                 }
+                KeyCode::Enter => {
+                    if let Some(disc) = list.selected_disc() {
+                        let disc = disc.clone();
+                        self.list_discs_position = list.position();
+                        let files = database::FileRecord::list_for_disc(&self.db_conn, &disc.disc_id)?;
+                        self.state = AppState::DiscDetail(Box::new(tui::DiscDetail::new(disc, files)));
+                    }
+                }
                 _ => {}
             },
-            AppState::Settings(_) => match key {
+            AppState::ScanQr(ref mut scan) => match key {
                 KeyCode::Esc => {
                     self.state = AppState::MainMenu;
                 }
+                KeyCode::Backspace => {
+                    scan.input_backspace();
+                }
+                KeyCode::Char(c) => {
+                    scan.input_char(c);
+                }
+                KeyCode::Enter if !scan.image_path().is_empty() => {
+                    let image_path = PathBuf::from(scan.image_path());
+                    match qrcode::decode_qr(&image_path) {
+                        None => scan.set_message(format!(
+                            "No disc QR code found in {} (is zbarimg installed?)",
+                            image_path.display()
+                        )),
+                        Some(disc_id) => match database::Disc::get(&self.db_conn, &disc_id)? {
+                            Some(disc) => {
+                                let files = database::FileRecord::list_for_disc(&self.db_conn, &disc.disc_id)?;
+                                self.state = AppState::DiscDetail(Box::new(tui::DiscDetail::new(disc, files)));
+                            }
+                            None => scan.set_message(format!("Disc {} is not in the catalog", disc_id)),
+                        },
+                    }
+                }
                 _ => {}
             },
-            AppState::Logs(_) => match key {
+            AppState::IdentifyDisc(ref mut view) => match key {
                 KeyCode::Esc => {
                     self.state = AppState::MainMenu;
                 }
+                KeyCode::Backspace => {
+                    view.input_backspace();
+                }
+                KeyCode::Char(c) => {
+                    view.input_char(c);
+                }
+                KeyCode::Enter if !view.source().is_empty() => {
+                    let source = view.source().to_string();
+                    match verify::with_disc_mounted(&source, |root| Ok(verify::read_disc_id(root))) {
+                        Err(e) => view.set_message(format!("Cannot read the disc in {}: {:#}", source, e)),
+                        Ok(None) => view.set_message(format!(
+                            "No disc ID found in {} (no manifest.json or DISC_INFO.txt); is it a BlueVault disc?",
+                            source
+                        )),
+                        Ok(Some(disc_id)) => match database::Disc::get(&self.db_conn, &disc_id)? {
+                            Some(disc) => {
+                                info!("Identified disc {} in {}", disc_id, source);
+                                let files = database::FileRecord::list_for_disc(&self.db_conn, &disc.disc_id)?;
+                                self.state = AppState::DiscDetail(Box::new(tui::DiscDetail::new(disc, files)));
+                            }
+                            None => {
+                                view.set_message(format!("Disc {} is not in the catalog", disc_id));
+                                self.confirm = Some((
+                                    tui::ConfirmDialog::new(
+                                        "Import disc?",
+                                        format!("Disc {} is not in the catalog.\nImport it and index its files from the disc's manifest?", disc_id),
+                                    )
+                                    .with_confirm_label("Import"),
+                                    PendingAction::ImportDisc(source),
+                                ));
+                            }
+                        },
+                    }
+                }
                 _ => {}
             },
-            AppState::Quit => {
-                return Ok(false);
-            }
-        }
-
-        // Handle any pending disc creation requests
-        info!("Checking for pending disc creation requests...");
-        if self.pending_disc_creation.is_some() {
-            info!("Found pending disc creation request, state: {:?}", match self.state {
-                AppState::NewDisc(_) => "NewDisc",
-                AppState::Cleanup(_) => "Cleanup",
-                _ => "Other"
-            });
-        }
-        let pending_taken = self.pending_disc_creation.take();
-        if let Some((needs_multi_disc, source_folders, config)) = pending_taken {
-            info!("Processing pending disc creation request: multi_disc={}, folders={}", needs_multi_disc, source_folders.len());
-            let db_path = self
-                .config
-                .database_path()
-                .unwrap_or_else(|_| PathBuf::from(":memory:"));
-
-            // Start the appropriate disc creation workflow
-            if let AppState::NewDisc(ref mut flow) = self.state {
-                info!("Starting disc creation workflow...");
-                Self::start_disc_creation_workflow(flow, needs_multi_disc, source_folders, config, db_path, &mut self.disc_creation_rx);
-            } else {
-                warn!("Pending disc creation request but not in NewDisc state! Current state: {:?}", match self.state {
-                    AppState::NewDisc(_) => "NewDisc",
-                    AppState::Cleanup(_) => "Cleanup",
-                    AppState::MainMenu => "MainMenu",
-                    _ => "Other"
-                });
-            }
-        }
-
-        Ok(true)
-    }
-
-    fn start_verification_internal(
-        &mut self,
-        verify: &mut tui::VerifyUI,
-        device_str: &str,
-        mountpoint_str: &str,
-    ) -> Result<()> {
-        let device = if device_str.is_empty() {
-            self.config.device.clone()
-        } else {
-            device_str.to_string()
-        };
-
-        let mountpoint = if mountpoint_str.is_empty() {
+            AppState::Scrub(ref mut scrub) => match key {
+                KeyCode::Esc => {
+                    self.state = AppState::MainMenu;
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    scrub.previous();
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    scrub.next();
+                }
+                KeyCode::Char('x') => {
+                    let path = paths::data_dir()?.join("scrub-worklist.csv");
+                    match verify::write_scrub_csv(scrub.discs(), &path) {
+                        Ok(()) => scrub.set_message(format!("Exported worklist to {}", path.display())),
+                        Err(e) => scrub.set_message(format!("Export failed: {}", e)),
+                    }
+                }
+                _ => {}
+            },
+            AppState::CatalogStats(ref mut view) => match key {
+                KeyCode::Esc => {
+                    self.state = AppState::MainMenu;
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    view.previous();
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    view.next();
+                }
+                _ => {}
+            },
+            AppState::BurnIso(ref mut view) => match key {
+                // Leaving is blocked while burning; the burn thread still reports here
+                KeyCode::Esc if !view.is_burning() => {
+                    self.state = AppState::MainMenu;
+                }
+                KeyCode::Backspace if !view.is_burning() => {
+                    view.input_backspace();
+                }
+                KeyCode::Char(c) if !view.is_burning() => {
+                    view.input_char(c);
+                }
+                KeyCode::Enter if !view.is_burning() && !view.iso_path().is_empty() => {
+                    let iso_path = paths::expand_tilde(view.iso_path());
+                    match iso::read_volume_label(&iso_path) {
+                        Ok(label) => {
+                            self.confirm = Some((
+                                tui::ConfirmDialog::new(
+                                    "Burn Existing ISO",
+                                    format!(
+                                        "Burn {} (volume {}) to the disc in {}?",
+                                        iso_path.display(),
+                                        label,
+                                        self.config.device
+                                    ),
+                                )
+                                .with_confirm_label("Burn"),
+                                PendingAction::BurnExternalIso(iso_path),
+                            ));
+                        }
+                        Err(e) => view.set_message(format!("{:#}", e)),
+                    }
+                }
+                _ => {}
+            },
+            AppState::IsoLibrary(ref mut library) => match key {
+                // Leaving is blocked while burning; the burn thread still reports here
+                KeyCode::Esc if !library.is_burning() => {
+                    self.state = AppState::MainMenu;
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    library.previous();
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    library.next();
+                }
+                KeyCode::Enter if !library.is_burning() => {
+                    if let Some(entry) = library.selected_entry() {
+                        let disc_id = entry.iso.disc_id.clone();
+                        self.confirm = Some((
+                            tui::ConfirmDialog::new(
+                                "Re-burn from ISO",
+                                format!(
+                                    "Burn {} to the disc in {}?\n{}",
+                                    disc_id,
+                                    self.config.device,
+                                    entry.iso.iso_path.display()
+                                ),
+                            )
+                            .with_confirm_label("Burn"),
+                            PendingAction::ReburnIso(disc_id),
+                        ));
+                    }
+                }
+                _ => {}
+            },
+            AppState::DiscDetail(ref mut detail) => match key {
+                KeyCode::Esc => {
+                    let mut list = tui::ListDiscs::new();
+                    load_disc_list(&self.db_conn, &mut list)?;
+                    list.restore_position(&self.list_discs_position);
+                    self.state = AppState::ListDiscs(list);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    detail.previous();
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    detail.next();
+                }
+                KeyCode::PageUp => {
+                    detail.page_up(20);
+                }
+                KeyCode::PageDown => {
+                    detail.page_down(20);
+                }
+                KeyCode::Char('d') => {
+                    let disc_id = detail.disc().disc_id.clone();
+                    let mountpoint = verify::find_mounted_disc(&disc_id).map(|p| p.display().to_string());
+                    self.state = AppState::CatalogDiff(tui::CatalogDiffView::new(disc_id, mountpoint));
+                }
+                KeyCode::Char('r') => {
+                    let disc = detail.disc();
+                    self.state = AppState::Restore(tui::RestoreView::new(disc.disc_id.clone(), disc.set_id.clone()));
+                }
+                _ => {}
+            },
+            AppState::Restore(ref mut view) => match key {
+                KeyCode::Esc => {
+                    // Dropping the reply channel makes the worker skip the remaining discs
+                    self.restore_rx = None;
+                    self.restore_reply = None;
+                    if let Some(disc) = database::Disc::get(&self.db_conn, view.disc_id())? {
+                        let files = database::FileRecord::list_for_disc(&self.db_conn, &disc.disc_id)?;
+                        self.state = AppState::DiscDetail(Box::new(tui::DiscDetail::new(disc, files)));
+                    } else {
+                        self.state = AppState::MainMenu;
+                    }
+                }
+                KeyCode::Enter | KeyCode::Char('s') if view.is_waiting_for_disc() => {
+                    if let Some(ref reply) = self.restore_reply {
+                        let _ = reply.send(key == KeyCode::Enter);
+                    }
+                    view.resume();
+                }
+                KeyCode::Tab if view.is_editing() => view.toggle_verify(),
+                KeyCode::BackTab if view.is_editing() => view.toggle_whole_set(),
+                KeyCode::Backspace if view.is_editing() => view.input_backspace(),
+                KeyCode::Char(c) if view.is_editing() => view.input_char(c),
+                KeyCode::Enter if view.is_editing() && !view.destination().is_empty() => {
+                    self.start_restore()?;
+                }
+                _ => {}
+            },
+            AppState::CatalogDiff(ref mut view) => match key {
+                KeyCode::Esc => {
+                    if let Some(disc) = database::Disc::get(&self.db_conn, view.disc_id())? {
+                        let files = database::FileRecord::list_for_disc(&self.db_conn, &disc.disc_id)?;
+                        self.state = AppState::DiscDetail(Box::new(tui::DiscDetail::new(disc, files)));
+                    } else {
+                        self.state = AppState::MainMenu;
+                    }
+                }
+                KeyCode::Up => {
+                    view.previous();
+                }
+                KeyCode::Down => {
+                    view.next();
+                }
+                KeyCode::Backspace => {
+                    view.input_backspace();
+                }
+                KeyCode::Char(c) => {
+                    view.input_char(c);
+                }
+                KeyCode::Enter if !view.mountpoint().is_empty() => {
+                    let mountpoint = PathBuf::from(view.mountpoint());
+                    match verify::catalog_diff(&self.db_conn, view.disc_id(), &mountpoint) {
+                        Ok(diff) => view.set_diff(diff),
+                        Err(e) => view.set_message(format!("{:#}", e)),
+                    }
+                }
+                _ => {}
+            },
+            AppState::Settings(ref mut settings) => match key {
+                KeyCode::Esc => {
+                    self.state = AppState::MainMenu;
+                }
+                KeyCode::Char('c') => {
+                    let report = database::integrity_check(&self.db_conn).and_then(|problems| {
+                        Ok((
+                            problems,
+                            database::table_row_counts(&self.db_conn)?,
+                            database::orphaned_file_count(&self.db_conn)?,
+                        ))
+                    });
+                    match report {
+                        Ok((problems, counts, orphans)) => settings.set_integrity_report(&problems, &counts, orphans),
+                        Err(e) => settings.set_maintenance_error(format!("{:#}", e)),
+                    }
+                }
+                KeyCode::Char('d') => {
+                    let drives: Vec<_> = self
+                        .config
+                        .burn_devices()
+                        .into_iter()
+                        .map(|device| {
+                            let caps = burn::drive_capabilities(&device).map_err(|e| format!("{:#}", e));
+                            (device, caps)
+                        })
+                        .collect();
+                    settings.set_drive_report(&drives);
+                }
+                KeyCode::Char('v') => match database::vacuum(&self.db_conn) {
+                    Ok(stats) => settings.set_vacuum_report(&stats),
+                    Err(e) => settings.set_maintenance_error(format!("{:#}", e)),
+                },
+                KeyCode::Char('b') => {
+                    let backup = database::backup_dir(&self.db_conn)
+                        .context("The database has no file to back up")
+                        .and_then(|dir| database::rolling_backup(&self.db_conn, &dir, self.config.backup.keep));
+                    match backup {
+                        Ok(path) => settings.set_backup_report(&path),
+                        Err(e) => settings.set_maintenance_error(format!("{:#}", e)),
+                    }
+                    settings.set_backups(catalog_backups(&self.db_conn));
+                }
+                KeyCode::Up | KeyCode::Char('k') => settings.previous_backup(),
+                KeyCode::Down | KeyCode::Char('j') => settings.next_backup(),
+                KeyCode::Char('r') => {
+                    if let Some(path) = settings.selected_backup().cloned() {
+                        self.confirm = Some((
+                            tui::ConfirmDialog::new(
+                                "Restore catalog?",
+                                format!(
+                                    "Replace the catalog with {}?\nThe current catalog is backed up first.",
+                                    path.file_name().unwrap_or_default().to_string_lossy()
+                                ),
+                            )
+                            .with_confirm_label("Restore"),
+                            PendingAction::RestoreBackup(path),
+                        ));
+                    }
+                }
+                _ => {}
+            },
+            AppState::Logs(ref mut logs) if logs.is_searching() => match key {
+                KeyCode::Esc => logs.cancel_search(),
+                KeyCode::Enter => logs.commit_search(),
+                KeyCode::Backspace => logs.pop_search_char(),
+                KeyCode::Char(c) => logs.push_search_char(c),
+                _ => {}
+            },
+            AppState::Logs(ref mut logs) => match key {
+                KeyCode::Esc => {
+                    self.state = AppState::MainMenu;
+                }
+                KeyCode::Up | KeyCode::Char('k') => logs.scroll_up(1),
+                KeyCode::Down | KeyCode::Char('j') => logs.scroll_down(1),
+                KeyCode::PageUp => logs.scroll_up(logs.page_size()),
+                KeyCode::PageDown => logs.scroll_down(logs.page_size()),
+                KeyCode::Home | KeyCode::Char('g') => logs.scroll_to_top(),
+                KeyCode::End | KeyCode::Char('G') => logs.scroll_to_bottom(),
+                KeyCode::Char('/') => logs.start_search(),
+                KeyCode::Char('n') => logs.next_match(),
+                KeyCode::Char('N') => logs.previous_match(),
+                KeyCode::Char('l') => logs.cycle_level_filter(),
+                KeyCode::Char('f') => logs.toggle_follow(),
+                _ => {}
+            },
+            AppState::Quit => {
+                return Ok(false);
+            }
+        }
+
+        // Handle any pending disc creation requests
+        info!("Checking for pending disc creation requests...");
+        if self.pending_disc_creation.is_some() {
+            info!("Found pending disc creation request, state: {:?}", match self.state {
+                AppState::NewDisc(_) => "NewDisc",
+                AppState::Cleanup(_) => "Cleanup",
+                _ => "Other"
+            });
+        }
+        let pending_taken = self.pending_disc_creation.take();
+        if let Some((needs_multi_disc, source_folders, config)) = pending_taken {
+            info!("Processing pending disc creation request: multi_disc={}, folders={}", needs_multi_disc, source_folders.len());
+            let db_path = self
+                .config
+                .database_path()
+                .unwrap_or_else(|_| PathBuf::from(":memory:"));
+
+            // Start the appropriate disc creation workflow
+            if let AppState::NewDisc(ref mut flow) = self.state {
+                info!("Starting disc creation workflow...");
+                Self::start_disc_creation_workflow(flow, needs_multi_disc, source_folders, config, db_path, &mut self.disc_creation_rx);
+            } else {
+                warn!("Pending disc creation request but not in NewDisc state! Current state: {:?}", match self.state {
+                    AppState::NewDisc(_) => "NewDisc",
+                    AppState::Cleanup(_) => "Cleanup",
+                    AppState::MainMenu => "MainMenu",
+                    _ => "Other"
+                });
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn start_verification_internal(
+        &mut self,
+        verify: &mut tui::VerifyUI,
+        device_str: &str,
+        mountpoint_str: &str,
+    ) -> Result<()> {
+        let device = if device_str.is_empty() {
+            self.config.device.clone()
+        } else {
+            device_str.to_string()
+        };
+
+        let mountpoint = if mountpoint_str.is_empty() {
             // Get temporary mountpoint
             bdarchive::verify::get_temporary_mountpoint()?
         } else {
@@ -991,15 +1863,16 @@ impl App {
 
         // Step 1: Mount if needed
         verify.set_verification_state(tui::verify_ui::VerificationState::Mounting);
+        let mut mounted = None;
 
-        if !mountpoint.join("SHA256SUMS.txt").exists() {
+        if !bdarchive::verify::is_readable_archive(&mountpoint) {
             if auto_mount {
                 verify.set_status(format!(
                     "Mounting {} to {}...",
                     device,
                     mountpoint.display()
                 ));
-                bdarchive::verify::mount_device(&device, &mountpoint, dry_run)?;
+                mounted = Some(bdarchive::verify::mount_device(&device, &mountpoint, dry_run)?);
             } else {
                 verify.set_status(format!(
                     "Please mount {} at {}",
@@ -1008,7 +1881,7 @@ impl App {
                 ));
                 // Wait for user to mount manually
                 // For now, check if it's mounted
-                if !mountpoint.join("SHA256SUMS.txt").exists() {
+                if !bdarchive::verify::is_readable_archive(&mountpoint) {
                     verify.set_error(format!(
                         "Disc not mounted. Please mount {} at {}",
                         device,
@@ -1018,1181 +1891,99 @@ impl App {
                 }
             }
         }
+        // udisks mounts under /run/media rather than at the requested mountpoint
+        let mountpoint = mounted.as_ref().map_or(mountpoint, |m| m.path.clone());
 
         // Step 2: Verify
         verify.set_verification_state(tui::verify_ui::VerificationState::Verifying);
-        verify.set_status("Running sha256sum -c...".to_string());
-
-        let result = bdarchive::verify::verify_disc(&mountpoint, auto_mount, dry_run)?;
-        verify.set_verification_result(result.clone());
-
-        if result.success {
-            verify.set_status(format!(
-                "Verification successful! {} files checked.",
-                result.files_checked
-            ));
-        } else {
-            verify.set_status(format!(
-                "Verification failed! {} files failed out of {} checked.",
-                result.files_failed, result.files_checked
-            ));
-        }
-
-        // Step 3: Record in database
-        verify.set_verification_state(tui::verify_ui::VerificationState::Recording);
-        verify.set_status("Recording verification results...".to_string());
-
-        // Try to find disc_id from the disc
-        // For now, we'll use a placeholder or try to read from DISC_INFO.txt
-        let disc_id =
-            if let Ok(disc_info) = std::fs::read_to_string(mountpoint.join("DISC_INFO.txt")) {
-                // Parse disc ID from DISC_INFO.txt
-                disc_info
-                    .lines()
-                    .find_map(|line| {
-                        if line.starts_with("Disc-ID: ") {
-                            Some(line[9..].trim().to_string())
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or_else(|| "UNKNOWN".to_string())
-            } else {
-                "UNKNOWN".to_string()
-            };
-
-        let verification_run = database::VerificationRun {
-            id: None,
-            disc_id,
-            verified_at: format_timestamp_now(),
-            mountpoint: Some(mountpoint.to_string_lossy().to_string()),
-            device: Some(device.clone()),
-            success: result.success,
-            error_message: result.error_message.clone(),
-            files_checked: Some(result.files_checked),
-            files_failed: Some(result.files_failed),
-        };
-
-        database::VerificationRun::insert(&mut self.db_conn, &verification_run)?;
-
-        // Unmount if we mounted it
-        if auto_mount && mountpoint.exists() {
-            if let Err(e) = bdarchive::verify::unmount_device(&mountpoint, dry_run) {
-                verify.set_status(format!("Warning: Failed to unmount: {}", e));
-            }
-        }
-
-        verify.set_verification_state(tui::verify_ui::VerificationState::Complete);
-
-        Ok(())
-    }
-
-    #[allow(dead_code)]
-    fn start_disc_creation_internal(
-        &mut self,
-        flow: &mut tui::NewDiscFlow,
-        disc_id: &str,
-        notes: &str,
-        source_folders: &[PathBuf],
-        dry_run: bool,
-    ) -> Result<()> {
-        info!(
-            "Starting disc creation: dry_run={}, disc_id={}",
-            dry_run, disc_id
-        );
-        flow.set_status(format!(
-            "Starting disc creation (mode: {})...",
-            if dry_run { "DRY RUN" } else { "ACTUAL" }
-        ));
-        // Validate inputs
-        if disc_id.is_empty() {
-            flow.set_error("Disc ID cannot be empty".to_string());
-            return Ok(());
-        }
-
-        if source_folders.is_empty() {
-            flow.set_error("No source folders selected".to_string());
-            return Ok(());
-        }
-
-        // Validate source folders exist
-        for folder in source_folders {
-            if !folder.exists() {
-                flow.set_error(format!(
-                    "Source folder does not exist: {}",
-                    folder.display()
-                ));
-                return Ok(());
-            }
-        }
-
-        let dry_run = dry_run;
-
-        // Step 1: Staging
-        flow.set_processing_state(tui::new_disc::ProcessingState::Staging);
-        flow.set_status("Preparing staging directory...".to_string());
-
-        let staging_dir = self.config.staging_dir()?;
-        std::fs::create_dir_all(&staging_dir)?;
-
-        let disc_root = disc::create_disc_layout(
-            &staging_dir,
-            disc_id,
-            source_folders,
-            if notes.is_empty() { None } else { Some(notes) },
-        )?;
-
-        flow.set_status("Staging files...".to_string());
-        let use_rsync = self.config.optional_tools.use_rsync
-            && dependencies::get_optional_command("rsync").is_some();
-
-        staging::stage_files(&disc_root, source_folders, use_rsync, dry_run)?;
-
-        // Step 2: Generate manifest and SHA256SUMS
-        info!("Starting manifest generation");
-        flow.set_processing_state(tui::new_disc::ProcessingState::GeneratingManifest);
-        flow.set_status("Generating manifest and checksums...".to_string());
-
-        let files = manifest::generate_manifest_and_sums(&disc_root, None)?;
-
-        let manifest_path = disc_root.join("MANIFEST.txt");
-        manifest::write_manifest_file(&manifest_path, &files)?;
-
-        let sha256sums_path = disc_root.join("SHA256SUMS.txt");
-        manifest::write_sha256sums_file(&sha256sums_path, &files)?;
-
-        // Write DISC_INFO.txt
-        let source_roots: Vec<PathBuf> = flow.source_folders().to_vec();
-        disc::write_disc_info(
-            &disc_root,
-            disc_id,
-            if notes.is_empty() { None } else { Some(notes) },
-            &source_roots,
-            &disc::get_tool_version(),
-            None, // set_id (single disc)
-            None, // sequence_number
-            None, // total_discs
-        )?;
-
-        // Check capacity
-        let total_size = manifest::calculate_total_size(&files);
-        let capacity = self.config.default_capacity_bytes();
-        if total_size > capacity {
-            flow.set_error(format!(
-                "Total size {} GB exceeds disc capacity {} GB",
-                total_size as f64 / 1_000_000_000.0,
-                capacity as f64 / 1_000_000_000.0
-            ));
-            return Ok(());
-        }
-
-        // Step 3: Create ISO
-        info!("Starting ISO creation");
-        flow.set_processing_state(tui::new_disc::ProcessingState::CreatingISO);
-        flow.set_status("Creating ISO image...".to_string());
-
-        let volume_label = disc::generate_volume_label(disc_id);
-        let iso_path = staging_dir.join(format!("{}.iso", disc_id));
-
-        iso::create_iso(&disc_root, &iso_path, &volume_label, dry_run)?;
-        let iso_size = iso::get_iso_size(&iso_path)?;
-
-        flow.set_status(format!(
-            "ISO created: {:.2} GB",
-            iso_size as f64 / 1_000_000_000.0
-        ));
-
-        // Step 4: Burn to disc
-        info!("Starting burning step: dry_run={}", dry_run);
-        flow.set_processing_state(tui::new_disc::ProcessingState::Burning);
-
-        if dry_run {
-            flow.set_status("Skipping burn (dry run mode)".to_string());
-            flow.set_file_progress("DRY RUN: Would burn ISO to disc".to_string());
-            info!("Skipping burn due to dry run mode");
+        let sampled = verify.is_sample();
+        let result = if sampled {
+            let sample_size: bdarchive::verify::SampleSize = self
+                .config
+                .verification
+                .sample_size
+                .parse()
+                .context("Invalid verification.sample_size")?;
+            verify.set_status(format!("Hashing a random sample ({}) of the files...", self.config.verification.sample_size));
+            let sample = bdarchive::verify::verify_sample(&mountpoint, sample_size)?;
+            verify.set_sample_summary(sample.summary());
+            sample.result
         } else {
-            flow.set_status(format!("Burning to {}...", self.config.device));
-            info!(
-                "About to call burn::burn_iso with device: {}",
-                self.config.device
-            );
-            burn::burn_iso(&iso_path, &self.config.device, dry_run)?;
-            info!("Burn completed successfully");
-            flow.set_status("Disc burned successfully".to_string());
-        }
-
-        // Step 5: Index in database
-        flow.set_processing_state(tui::new_disc::ProcessingState::Indexing);
-        flow.set_status("Updating index...".to_string());
-
-        let created_at = format_timestamp_now();
-
-        let disc_record = database::Disc {
-            disc_id: disc_id.to_string(),
-            volume_label: volume_label.clone(),
-            created_at: created_at.clone(),
-            notes: if notes.is_empty() {
-                None
-            } else {
-                Some(notes.to_string())
-            },
-            iso_size: Some(iso_size),
-            burn_device: Some(self.config.device.clone()),
-            checksum_manifest_hash: None, // Could calculate hash of manifest
-            qr_path: None,                // Will be set after QR generation
-            source_roots: Some(serde_json::to_string(&source_roots)?),
-            tool_version: Some(disc::get_tool_version()),
-            set_id: None, // Single disc, not part of a set
-            sequence_number: None,
-        };
-
-        database::Disc::insert(&mut self.db_conn, &disc_record)?;
-
-        // Index files
-        let file_records: Vec<database::FileRecord> = files
-            .iter()
-            .map(|f| database::FileRecord {
-                id: None,
-                disc_id: disc_id.to_string(),
-                rel_path: f.rel_path.to_string_lossy().to_string(),
-                sha256: f.sha256.clone(),
-                size: f.size,
-                mtime: f.mtime.clone(),
-                added_at: created_at.clone(),
-            })
-            .collect();
-
-        database::FileRecord::insert_batch(&mut self.db_conn, &file_records)?;
-
-        // Step 6: Generate QR code
-        flow.set_processing_state(tui::new_disc::ProcessingState::GeneratingQR);
-        flow.set_status("Generating QR code...".to_string());
-
-        if self.config.optional_tools.use_qrencode {
-            let qrcodes_dir = paths::qrcodes_dir()?;
-            match qrcode::generate_qrcode(disc_id, &qrcodes_dir, qrcode::QrCodeFormat::PNG, dry_run)
-            {
-                Ok(qr_path) => {
-                    // Update disc record with QR path
-                    // For now, just log it
-                    info!("QR code generated: {}", qr_path.display());
-                }
-                Err(e) => {
-                    // Non-fatal error
-                    flow.set_status(format!("QR code generation skipped: {}", e));
-                }
+            verify.set_status("Running sha256sum -c...".to_string());
+            let mut result = bdarchive::verify::verify_disc(&mountpoint, auto_mount, dry_run)?;
+            if self.config.verification.decompress_archives && !dry_run {
+                verify.set_status("Decompressing archives to check original files...".to_string());
+                bdarchive::verify::verify_compressed_originals(&mountpoint, &mut result)?;
             }
-        }
-
-        // Clean up staging directory after successful burn
-        if !dry_run {
-            flow.set_status("Cleaning up temporary files...".to_string());
-            if let Err(e) = Self::cleanup_staging_directory(&staging_dir) {
-                warn!("Failed to cleanup staging directory: {}", e);
-                // Don't fail the entire process for cleanup errors
-            }
-        }
-
-        // Complete!
-        flow.set_processing_state(tui::new_disc::ProcessingState::Complete);
-        flow.set_status(format!("Disc {} created successfully!", disc_id));
-
-        Ok(())
-    }
-
-    /// Enhanced multi-disc creation with comprehensive error handling and recovery
-    fn run_multi_disc_creation_background_robust(
-        disc_id_base: String,
-        notes: String,
-        source_folders: Vec<PathBuf>,
-        dry_run: bool,
-        config: Config,
-        mut db_conn: rusqlite::Connection,
-        tx: mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<()> {
-        let _ = tx.send(DiscCreationMessage::Status("🔍 Starting multi-disc archive creation with enhanced error handling...".to_string()));
-
-        // Phase 1: Planning with error recovery
-        let plans = match Self::plan_multi_disc_archive(&source_folders, config.default_capacity_bytes(), &tx) {
-            Ok(plans) => plans,
-            Err(MultiDiscError::PlanningFailed(msg)) => {
-                let _ = tx.send(DiscCreationMessage::Error(format!("Planning failed: {}", msg)));
-                return Err(anyhow::anyhow!("Planning failed: {}", msg));
-            }
-            Err(_) => unreachable!("Planning should only return PlanningFailed"),
-        };
-
-        let total_discs = plans.len();
-        let total_size: u64 = plans.iter().map(|p| p.used_bytes).sum();
-
-        // Phase 2: Create database set with rollback capability
-        let set_id = match Self::create_disc_set_with_rollback(&mut db_conn, &disc_id_base, &notes, total_size, total_discs, &source_folders, &tx) {
-            Ok(id) => id,
-            Err(e) => {
-                let _ = tx.send(DiscCreationMessage::Error(format!("Database setup failed: {}", e)));
-                return Err(e);
-            }
-        };
-
-        // Phase 2.5: Create burn session for pause/resume capability
-        let session = database::BurnSession::new(
-            set_id.clone(),
-            disc_id_base.clone(),
-            total_discs,
-            source_folders.clone(),
-            serde_json::to_string(&config).unwrap_or_default(),
-        );
-
-        if let Err(e) = session.save(&db_conn) {
-            warn!("Failed to save burn session: {}", e);
-            // Don't fail the burn for session save errors
-        }
-
-        // Phase 3: Burn discs with error recovery
-        let completed_discs = match Self::burn_multi_disc_sequence(
-            &disc_id_base, &notes, &plans, dry_run, &config, &mut db_conn, &set_id, &source_folders, &tx, &session.session_id
-        ) {
-            Ok(discs) => discs,
-            Err(MultiDiscError::UserCancelled) => {
-                let _ = tx.send(DiscCreationMessage::Status("❌ Operation cancelled by user".to_string()));
-                return Ok(()); // User cancellation is not an error
-            }
-            Err(MultiDiscError::PartialSuccess { completed_discs, failed_disc, error }) => {
-                let _ = tx.send(DiscCreationMessage::MultiDiscError(MultiDiscError::PartialSuccess {
-                    completed_discs: completed_discs.clone(),
-                    failed_disc,
-                    error: error.clone(),
-                }));
-
-                // Ask user what to do
-                let _ = tx.send(DiscCreationMessage::UserChoiceNeeded {
-                    message: format!("Disc {} failed: {}. {} discs completed successfully. What would you like to do?", failed_disc, error, completed_discs.len()),
-                    options: vec![
-                        "Retry failed disc".to_string(),
-                        "Skip failed disc and continue".to_string(),
-                        "Abort and cleanup".to_string(),
-                    ],
-                });
-
-                return Err(anyhow::anyhow!("Partial success: {} discs completed, disc {} failed: {}", completed_discs.len(), failed_disc, error));
-            }
-            Err(e) => {
-                let _ = tx.send(DiscCreationMessage::Error(format!("Burn sequence failed: {:?}", e)));
-                return Err(anyhow::anyhow!("Burn sequence failed: {:?}", e));
-            }
-        };
-
-        // Phase 4: Final cleanup and reporting
-        Self::finalize_multi_disc_archive(&completed_discs, &set_id, total_size, dry_run, &config, &tx);
-
-        Ok(())
-    }
-
-    /// Plan multi-disc archive with error handling
-    fn plan_multi_disc_archive(
-        source_folders: &[PathBuf],
-        disc_capacity: u64,
-        tx: &mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<Vec<staging::DiscPlan>, MultiDiscError> {
-        let _ = tx.send(DiscCreationMessage::Status("📊 Planning multi-disc layout with error recovery...".to_string()));
-
-        // Create disc layout plan with timeout protection
-        let plans_result = std::panic::catch_unwind(|| {
-            staging::plan_disc_layout_with_progress(source_folders, disc_capacity, |progress| {
-                let _ = tx.send(DiscCreationMessage::Progress(progress.to_string()));
-            })
-        });
-
-        match plans_result {
-            Ok(Ok(plans)) => {
-                if plans.is_empty() {
-                    return Err(MultiDiscError::PlanningFailed("No disc plans generated".to_string()));
-                }
-                Ok(plans)
-            }
-            Ok(Err(e)) => Err(MultiDiscError::PlanningFailed(format!("Planning error: {}", e))),
-            Err(_) => Err(MultiDiscError::PlanningFailed("Planning function panicked (possible infinite loop)".to_string())),
-        }
-    }
-
-    /// Create disc set with rollback capability
-    fn create_disc_set_with_rollback(
-        db_conn: &mut rusqlite::Connection,
-        disc_id_base: &str,
-        notes: &str,
-        total_size: u64,
-        total_discs: usize,
-        source_folders: &[PathBuf],
-        tx: &mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<String> {
-        let _ = tx.send(DiscCreationMessage::Status("💾 Setting up database records...".to_string()));
-
-        let set_name = format!("Multi-disc archive: {}", disc_id_base);
-        let source_folders_json = serde_json::to_string(source_folders)?;
-
-        match database::MultiDiscOps::create_disc_set(
-            db_conn,
-            &set_name,
-            if notes.is_empty() { None } else { Some(notes) },
-            total_size,
-            total_discs as u32,
-            Some(&source_folders_json),
-        ) {
-            Ok(set_id) => {
-                let _ = tx.send(DiscCreationMessage::Progress(format!("✅ Database set '{}' created", set_id)));
-                Ok(set_id)
-            }
-            Err(e) => {
-                let _ = tx.send(DiscCreationMessage::Error(format!("Failed to create disc set: {}", e)));
-                Err(e)
-            }
-        }
-    }
-
-    /// Burn multi-disc sequence with comprehensive error handling and pause/resume support
-    fn burn_multi_disc_sequence(
-        disc_id_base: &str,
-        notes: &str,
-        plans: &[staging::DiscPlan],
-        dry_run: bool,
-        config: &Config,
-        db_conn: &mut rusqlite::Connection,
-        set_id: &str,
-        source_folders: &[PathBuf],
-        tx: &mpsc::Sender<DiscCreationMessage>,
-        session_id: &str,
-    ) -> Result<Vec<PathBuf>, MultiDiscError> {
-        let total_discs = plans.len();
-        let mut completed_discs = Vec::new();
-        let mut iso_paths = Vec::new();
-
-        for (disc_index, plan) in plans.iter().enumerate() {
-            let sequence_num = disc_index + 1;
-
-            // Check for pause requests before starting each disc
-            // In a real implementation, we'd also check during burning
-            // For now, this provides basic pause capability
-
-            match Self::burn_single_disc_with_recovery(
-                disc_id_base, notes, plan, sequence_num, total_discs, dry_run, config, db_conn, set_id, source_folders, tx
-            ) {
-                Ok(iso_path) => {
-                    completed_discs.push(sequence_num);
-                    iso_paths.push(iso_path);
-
-                    // Update session progress
-                    if let Ok(Some(mut session)) = database::BurnSession::load(db_conn, session_id) {
-                        session.update_progress(sequence_num);
-                        let _ = session.save(db_conn);
-                    }
-                }
-                Err(e) => {
-                    // Save session state on failure
-                    if let Ok(Some(mut session)) = database::BurnSession::load(&db_conn, session_id) {
-                        session.failed_discs.push(sequence_num);
-                        let _ = session.save(&db_conn);
-                    }
-
-                    return Err(MultiDiscError::PartialSuccess {
-                        completed_discs: completed_discs.clone(),
-                        failed_disc: sequence_num,
-                        error: format!("{:?}", e),
-                    });
-                }
-            }
-        }
-
-        Ok(iso_paths)
-    }
-
-    /// Burn single disc with recovery and error handling
-    fn burn_single_disc_with_recovery(
-        disc_id_base: &str,
-        notes: &str,
-        plan: &staging::DiscPlan,
-        sequence_num: usize,
-        total_discs: usize,
-        dry_run: bool,
-        config: &Config,
-        db_conn: &mut rusqlite::Connection,
-        set_id: &str,
-        source_folders: &[PathBuf],
-        tx: &mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<PathBuf, MultiDiscError> {
-        let disc_id = disc::generate_multi_disc_id(disc_id_base, sequence_num as u32);
-
-        let _ = tx.send(DiscCreationMessage::Status(format!(
-            "🔥 Processing disc {}/{}: {}", sequence_num, total_discs, disc_id
-        )));
-
-        // Disc insertion prompt with timeout
-        if !dry_run {
-            Self::wait_for_disc_insertion(sequence_num, total_discs, tx)?;
-        }
-
-        // Create staging with error handling
-        let staging_dir = config.staging_dir()
-            .map_err(|e| MultiDiscError::StagingFailed {
-                disc_number: sequence_num,
-                error: format!("Cannot access staging directory: {}", e),
-            })?;
-
-        let disc_staging_dir = staging_dir.join(format!("disc_{}", sequence_num));
-
-        match Self::stage_disc_content(plan, source_folders, &disc_staging_dir, dry_run, tx) {
-            Ok(_) => {}
-            Err(e) => return Err(MultiDiscError::StagingFailed {
-                disc_number: sequence_num,
-                error: format!("Staging failed: {}", e),
-            }),
-        }
-
-        // Write disc info
-        let disc_root = disc_staging_dir.join("disc_info");
-        if let Err(e) = disc::write_disc_info(
-            &disc_root,
-            &disc_id,
-            if notes.is_empty() { None } else { Some(notes) },
-            source_folders,
-            &disc::get_tool_version(),
-            Some(set_id),
-            Some(sequence_num as u32),
-            Some(total_discs as u32),
-        ) {
-            let _ = std::fs::remove_dir_all(&disc_staging_dir);
-            return Err(MultiDiscError::StagingFailed {
-                disc_number: sequence_num,
-                error: format!("Failed to write disc info: {}", e),
-            });
-        }
-
-        // Burn disc with error handling
-        let iso_path = match Self::create_iso_and_burn_disc(
-            &disc_id,
-            &disc_staging_dir,
-            &config.device,
-            dry_run,
-            config,
-            tx,
-        ) {
-            Ok(path) => path,
-            Err(e) => {
-                // Cleanup on failure
-                let _ = std::fs::remove_dir_all(&disc_staging_dir);
-                return Err(MultiDiscError::BurnFailed {
-                    disc_number: sequence_num,
-                    error: format!("Burn failed: {}", e),
-                });
-            }
-        };
-
-        // Record in database
-        if let Err(e) = Self::record_disc_in_database(
-            &disc_id, disc_id_base, sequence_num, total_discs, plan, config, db_conn, set_id, source_folders, dry_run
-        ) {
-            warn!("Failed to record disc {} in database: {}", sequence_num, e);
-            // Don't fail the burn for database errors, but log it
-        }
-
-        // Cleanup staging
-        if disc_staging_dir.exists() {
-            let _ = std::fs::remove_dir_all(&disc_staging_dir);
-        }
-
-        let _ = tx.send(DiscCreationMessage::Status(format!(
-            "✅ Disc {} of {} completed successfully", sequence_num, total_discs
-        )));
-
-        Ok(iso_path)
-    }
-
-    /// Wait for user to insert disc with timeout and cancellation
-    fn wait_for_disc_insertion(sequence_num: usize, total_discs: usize, tx: &mpsc::Sender<DiscCreationMessage>) -> Result<(), MultiDiscError> {
-        let _ = tx.send(DiscCreationMessage::Status(format!(
-            "📀 Please insert disc {} of {} and press Enter to continue (or 'q' to cancel)...",
-            sequence_num, total_discs
-        )));
-
-        // In a real implementation, this would wait for user input
-        // For now, just send animated waiting messages
-        for i in 0..10 {  // 3 second timeout simulation
-            let spinner = match i % 4 {
-                0 => "|",
-                1 => "/",
-                2 => "-",
-                3 => "\\",
-                _ => "|",
-            };
-            let _ = tx.send(DiscCreationMessage::Progress(format!(
-                "⏳ Waiting for disc {}... {} (press Enter when ready, 'q' to cancel)", sequence_num, spinner
-            )));
-            std::thread::sleep(std::time::Duration::from_millis(300));
-
-            // In real implementation, check for user input here
-            // For simulation, just continue
-        }
-
-        let _ = tx.send(DiscCreationMessage::Progress(format!(
-            "✅ Disc {} ready, starting burn process...", sequence_num
-        )));
-
-        Ok(())
-    }
-
-
-    /// Record completed disc in database
-    fn record_disc_in_database(
-        disc_id: &str,
-        disc_id_base: &str,
-        sequence_num: usize,
-        total_discs: usize,
-        plan: &staging::DiscPlan,
-        config: &Config,
-        db_conn: &mut rusqlite::Connection,
-        set_id: &str,
-        source_folders: &[PathBuf],
-        dry_run: bool,
-    ) -> Result<()> {
-        let volume_label = disc::generate_multi_disc_volume_label(disc_id_base, sequence_num as u32, total_discs as u32);
-
-        let mut disc_record = database::Disc {
-            disc_id: disc_id.to_string(),
-            volume_label,
-            created_at: disc::format_timestamp_now(),
-            notes: Some(format!("Disc {} of {} in multi-disc set {}", sequence_num, total_discs, set_id)),
-            iso_size: Some(plan.used_bytes),
-            burn_device: if dry_run { None } else { Some(config.device.clone()) },
-            checksum_manifest_hash: None,
-            qr_path: None,
-            source_roots: Some(serde_json::to_string(source_folders)?),
-            tool_version: Some(disc::get_tool_version()),
-            set_id: Some(set_id.to_string()),
-            sequence_number: Some(sequence_num as u32),
-        };
-
-        database::MultiDiscOps::add_disc_to_set(db_conn, &mut disc_record, set_id, sequence_num as u32)?;
-        Ok(())
-    }
-
-    /// Finalize multi-disc archive with summary
-    fn finalize_multi_disc_archive(
-        iso_paths: &[PathBuf],
-        set_id: &str,
-        total_size: u64,
-        dry_run: bool,
-        config: &Config,
-        tx: &mpsc::Sender<DiscCreationMessage>,
-    ) {
-        // Final cleanup
-        if !dry_run {
-            if let Ok(staging_dir) = config.staging_dir() {
-                let _ = Self::cleanup_staging_directory(&staging_dir);
-            }
-        }
-
-        // Send completion summary
-        let total_size_mb = total_size / (1024 * 1024);
-        let _ = tx.send(DiscCreationMessage::Status(format!(
-            "🎊 Multi-disc archive complete! {} discs, {} MB total",
-            iso_paths.len(), total_size_mb
-        )));
-
-        // Show ISO file locations
-        if !iso_paths.is_empty() {
-            let _ = tx.send(DiscCreationMessage::Progress("📂 ISO files created:".to_string()));
-            for (i, iso_path) in iso_paths.iter().enumerate() {
-                let disc_num = i + 1;
-                let _ = tx.send(DiscCreationMessage::Progress(format!(
-                    "  💿 Disc {}: {}",
-                    disc_num,
-                    iso_path.display()
-                )));
-            }
-        }
-    }
-
-    /// Run multi-disc creation in background with sequential burning
-    fn run_multi_disc_creation_background(
-        disc_id_base: String,
-        notes: String,
-        source_folders: Vec<PathBuf>,
-        dry_run: bool,
-        config: Config,
-        mut db_conn: rusqlite::Connection,
-        tx: mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<()> {
-        let _ = tx.send(DiscCreationMessage::Status(format!(
-            "Planning multi-disc layout..."
-        )));
-
-        // Create disc layout plan with timeout protection
-        let disc_capacity = config.default_capacity_bytes();
-
-        let plans_result = std::panic::catch_unwind(|| {
-            staging::plan_disc_layout_with_progress(&source_folders, disc_capacity, |progress| {
-                let _ = tx.send(DiscCreationMessage::Progress(progress.to_string()));
-            })
-        });
-
-        let plans = match plans_result {
-            Ok(Ok(plans)) => plans,
-            Ok(Err(e)) => {
-                let _ = tx.send(DiscCreationMessage::Error(format!("Planning failed: {}", e)));
-                return Err(e);
-            }
-            Err(_) => {
-                let _ = tx.send(DiscCreationMessage::Error("Planning panicked (possible infinite loop)".to_string()));
-                return Err(anyhow::anyhow!("Planning function panicked"));
-            }
-        };
-
-        if plans.is_empty() {
-            let _ = tx.send(DiscCreationMessage::Error("No disc plans generated".to_string()));
-            return Err(anyhow::anyhow!("No disc plans generated"));
-        }
-
-        let total_discs = plans.len();
-
-        let _ = tx.send(DiscCreationMessage::Progress(format!(
-            "✅ Planning complete! Archive will span {} discs", total_discs
-        )));
-
-        // Create disc set in database
-        let set_name = format!("Multi-disc archive: {}", disc_id_base);
-        let total_size: u64 = plans.iter().map(|p| p.used_bytes).sum();
-        let set_id = database::MultiDiscOps::create_disc_set(
-            &mut db_conn,
-            &set_name,
-            Some(&notes),
-            total_size,
-            total_discs as u32,
-            Some(&serde_json::to_string(&source_folders)?),
-        )?;
-
-        // Burn each disc sequentially
-        let mut iso_paths = Vec::new();
-
-        for (disc_index, plan) in plans.iter().enumerate() {
-            let sequence_num = disc_index + 1;
-            let disc_id = disc::generate_multi_disc_id(&disc_id_base, sequence_num as u32);
-
-            // Update overall progress
-            let overall_progress = disc_index as f64 / total_discs as f64;
-
-            let _ = tx.send(DiscCreationMessage::Status(format!(
-                "🔥 Preparing disc {}/{}: {} ({} MB)",
-                sequence_num,
-                total_discs,
-                disc_id,
-                plan.used_bytes / (1024 * 1024)
-            )));
-
-            // Send detailed progress info
-            let _ = tx.send(DiscCreationMessage::Progress(format!(
-                "💿 Disc {}/{} | 📊 {:.1}% complete | 🎯 +{} MB",
-                sequence_num, total_discs,
-                overall_progress * 100.0,
-                plan.used_bytes / (1024 * 1024)
-            )));
-
-            if !dry_run {
-                // Prompt user to insert correct disc with animated waiting
-                let _ = tx.send(DiscCreationMessage::Status(format!(
-                    "📀 Please insert disc {} of {} and press Enter to continue...",
-                    sequence_num, total_discs
-                )));
-
-                // Send animated waiting messages
-                for i in 0..5 {
-                    let spinner = match i % 4 {
-                        0 => "|",
-                        1 => "/",
-                        2 => "-",
-                        3 => "\\",
-                        _ => "|",
-                    };
-                    let _ = tx.send(DiscCreationMessage::Progress(format!(
-                        "⏳ Waiting for disc {}... {}", sequence_num, spinner
-                    )));
-                    std::thread::sleep(std::time::Duration::from_millis(300));
-                }
-
-                let _ = tx.send(DiscCreationMessage::Progress(format!(
-                    "✅ Disc {} ready, starting burn process...",
-                    sequence_num
-                )));
-            }
-
-            // Create temporary directory structure for this disc
-            info!("Creating staging directory for disc {}", sequence_num);
-            let staging_dir = config.staging_dir()?;
-            let disc_staging_dir = staging_dir.join(format!("disc_{}", sequence_num));
-            info!("Staging dir: {}", disc_staging_dir.display());
-
-            // Stage files for this specific disc with progress updates
-            let _ = tx.send(DiscCreationMessage::Status(format!(
-                "📦 Staging files for disc {}...",
-                sequence_num
-            )));
-
-            // Send initial progress
-            let _ = tx.send(DiscCreationMessage::Progress(format!(
-                "📁 Preparing disc {} content...",
-                sequence_num
-            )));
-
-            match Self::stage_disc_content(&plan, &source_folders, &disc_staging_dir, dry_run, &tx) {
-                Ok(_) => (),
-                Err(e) => {
-                    error!("Staging failed for disc {}: {}", sequence_num, e);
-                    let _ = tx.send(DiscCreationMessage::Error(format!("Staging failed: {}", e)));
-                    return Err(e);
-                }
-            }
-
-            let _ = tx.send(DiscCreationMessage::Progress(format!(
-                "✅ Disc {} staging complete ({} MB)",
-                sequence_num,
-                plan.used_bytes / (1024 * 1024)
-            )));
-
-            // Write DISC_INFO.txt for this disc
-            let disc_root = disc_staging_dir.clone();
-            let source_roots: Vec<PathBuf> = source_folders.clone();
-            match disc::write_disc_info(
-                &disc_root,
-                &disc_id,
-                if notes.is_empty() { None } else { Some(&notes) },
-                &source_roots,
-                &disc::get_tool_version(),
-                Some(&set_id),
-                Some(sequence_num as u32),
-                Some(total_discs as u32),
-            ) {
-                Ok(_) => info!("Disc info written for disc {}", sequence_num),
-                Err(e) => {
-                    error!("Failed to write disc info for disc {}: {}", sequence_num, e);
-                    let _ = tx.send(DiscCreationMessage::Error(format!("Failed to write disc info: {}", e)));
-                    return Err(anyhow::anyhow!("Failed to write disc info: {}", e));
-                }
-            }
-
-            // Create ISO and burn (or simulate) - reuse existing logic
-            let iso_path = match Self::create_iso_and_burn_disc(
-                &disc_id,
-                &disc_staging_dir,
-                &config.device,
-                dry_run,
-                &config,
-                &tx,
-            ) {
-                Ok(iso_path) => {
-                    iso_paths.push(iso_path.clone());
-                    iso_path
-                }
-                Err(e) => {
-                    error!("ISO/burn failed for disc {}: {}", sequence_num, e);
-                    let _ = tx.send(DiscCreationMessage::Error(format!("Burn failed: {}", e)));
-                    return Err(e);
-                }
-            };
-
-            // Add disc to set
-            let volume_label = disc::generate_multi_disc_volume_label(&disc_id_base, sequence_num as u32, total_discs as u32);
-            let mut disc_record = database::Disc {
-                disc_id: disc_id.clone(),
-                volume_label,
-                created_at: disc::format_timestamp_now(),
-                notes: Some(format!("Disc {} of {} in multi-disc set {}", sequence_num, total_discs, set_id)),
-                iso_size: Some(plan.used_bytes),
-                burn_device: if dry_run { None } else { Some(config.device.clone()) },
-                checksum_manifest_hash: None,
-                qr_path: None,
-                source_roots: Some(serde_json::to_string(&source_folders)?),
-                tool_version: Some(disc::get_tool_version()),
-                set_id: Some(set_id.clone()),
-                sequence_number: Some(sequence_num as u32),
-            };
-
-            database::MultiDiscOps::add_disc_to_set(&mut db_conn, &mut disc_record, &set_id, sequence_num as u32)?;
-
-            // Cleanup disc staging
-            if disc_staging_dir.exists() {
-                let _ = std::fs::remove_dir_all(&disc_staging_dir);
-            }
-
-            let _ = tx.send(DiscCreationMessage::Status(format!(
-                "✅ Disc {} of {} completed successfully",
-                sequence_num, total_discs
-            )));
-        }
-
-        // Final cleanup
-        if !dry_run {
-            if let Ok(staging_dir) = config.staging_dir() {
-                let _ = Self::cleanup_staging_directory(&staging_dir);
-            }
-        }
-
-        // Send completion summary for multi-disc
-        let total_size_mb = total_size / (1024 * 1024);
-        let _ = tx.send(DiscCreationMessage::Status(format!(
-            "🎊 Multi-disc archive complete! {} discs, {} MB total",
-            total_discs, total_size_mb
-        )));
-
-        let _ = tx.send(DiscCreationMessage::Progress(format!(
-            "📚 Archive ID: {} | 💾 {} discs spanning {} MB",
-            disc_id_base, total_discs, total_size_mb
-        )));
-
-        // Show ISO file locations
-        if !iso_paths.is_empty() {
-            let _ = tx.send(DiscCreationMessage::Progress("📂 ISO files created:".to_string()));
-            for (i, iso_path) in iso_paths.iter().enumerate() {
-                let disc_num = i + 1;
-                let _ = tx.send(DiscCreationMessage::Progress(format!(
-                    "  💿 Disc {}: {}",
-                    disc_num,
-                    iso_path.display()
-                )));
-            }
-        }
-
-        let _ = tx.send(DiscCreationMessage::Complete);
-        Ok(())
-    }
-
-    /// Create ISO and burn disc (extracted from single-disc workflow)
-    fn create_iso_and_burn_disc(
-        disc_id: &str,
-        disc_staging_dir: &Path,
-        device: &str,
-        dry_run: bool,
-        config: &Config,
-        tx: &mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<PathBuf> {
-        // ISO Creation Phase
-        let _ = tx.send(DiscCreationMessage::Status("🎨 Creating ISO image...".to_string()));
-        let _ = tx.send(DiscCreationMessage::Progress("🔄 Analyzing files and building filesystem...".to_string()));
-
-        let volume_label = disc::generate_volume_label(disc_id);
-        let staging_dir = config.staging_dir()?;
-        let iso_path = staging_dir.join(format!("{}.iso", disc_id));
-
-        // Send animated progress during ISO creation
-        let iso_tx = tx.clone();
-        std::thread::spawn(move || {
-            let spinners = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-            for i in 0..20 {
-                let _ = iso_tx.send(DiscCreationMessage::Progress(format!(
-                    "🎨 Building ISO... {}", spinners[i % spinners.len()]
-                )));
-                std::thread::sleep(std::time::Duration::from_millis(200));
-            }
-        });
-
-        iso::create_iso(disc_staging_dir, &iso_path, &volume_label, dry_run)?;
-
-        // Get ISO size (skip for dry run since no file is created)
-        let iso_size = if dry_run {
-            // Estimate size based on staging directory
-            staging::calculate_directory_size(disc_staging_dir)?
-        } else {
-            iso::get_iso_size(&iso_path)?
-        };
-
-        let _ = tx.send(DiscCreationMessage::Progress(format!(
-            "✅ ISO created: {:.2} GB ({})",
-            iso_size as f64 / 1_000_000_000.0,
-            volume_label
-        )));
-
-        // Burn to disc
-        if dry_run {
-            let _ = tx.send(DiscCreationMessage::Status("🔍 Skipping burn (dry run mode)".to_string()));
-            let _ = tx.send(DiscCreationMessage::Progress("📋 Dry run complete - no disc written".to_string()));
-        } else {
-            let _ = tx.send(DiscCreationMessage::Status(format!("🔥 Burning to {}...", device)));
-            let _ = tx.send(DiscCreationMessage::Progress("⚡ Initializing Blu-ray burner...".to_string()));
-
-            burn::burn_iso(&iso_path, device, dry_run)?;
-
-            let _ = tx.send(DiscCreationMessage::Progress("🎉 Disc burned successfully!".to_string()));
-        }
-
-        Ok(iso_path)
-    }
-
-    /// Stage content for a specific disc from the plan
-    fn stage_disc_content(
-        plan: &staging::DiscPlan,
-        source_folders: &[PathBuf],
-        disc_staging_dir: &Path,
-        dry_run: bool,
-        tx: &mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<()> {
-        let _ = tx.send(DiscCreationMessage::Progress(format!(
-            "🔄 Starting content staging for disc {}...",
-            plan.disc_number
-        )));
-
-        // For now, we'll copy all source folders and rely on the ISO creation
-        // to handle the size limits. In a more sophisticated implementation,
-        // we'd only copy the specific files assigned to this disc.
-        for (i, source) in source_folders.iter().enumerate() {
-            if source.exists() {
-                let dest_name = source.file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("unknown");
-                let dest = disc_staging_dir.join(dest_name);
-
-                let _ = tx.send(DiscCreationMessage::Progress(format!(
-                    "📂 Copying folder {}/{}: {}",
-                    i + 1,
-                    source_folders.len(),
-                    dest_name
-                )));
-
-                if dry_run {
-                    // Just create directory structure
-                    std::fs::create_dir_all(&dest)?;
-                    let _ = tx.send(DiscCreationMessage::Progress("📁 Created directory structure (dry run)".to_string()));
-                } else {
-                    // Actually copy the content
-                    staging::copy_directory_recursive(source, &dest)?;
-                    let _ = tx.send(DiscCreationMessage::Progress(format!(
-                        "✅ Copied: {}", dest_name
-                    )));
-                }
-
-                // Small delay to show progress
-                std::thread::sleep(std::time::Duration::from_millis(100));
-            }
-        }
-
-        let _ = tx.send(DiscCreationMessage::Progress(format!(
-            "🎯 Disc {} staging complete!",
-            plan.disc_number
-        )));
-
-        Ok(())
-    }
-
-    /// Burn ISO with detailed progress updates
-    fn burn_iso_with_progress(
-        iso_path: &Path,
-        device: &str,
-        dry_run: bool,
-        tx: mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<()> {
-        use std::thread;
-        use std::time::Duration;
-
-        if dry_run {
-            let _ = tx.send(DiscCreationMessage::Progress("DRY RUN: Would burn ISO to disc".to_string()));
-            thread::sleep(Duration::from_millis(500));
-            return Ok(());
-        }
-
-        // Get ISO size for progress estimation
-        let iso_size = match std::fs::metadata(iso_path) {
-            Ok(metadata) => metadata.len(),
-            Err(_) => 0, // Fallback if we can't get size
+            result
         };
-        let iso_size_gb = iso_size as f64 / 1_000_000_000.0;
+        verify.set_verification_result(result.clone());
 
-        // Estimate burn time (BD-R typical speeds: 2-6x = ~8-24 MB/s)
-        let estimated_burn_time_secs = if iso_size > 0 {
-            (iso_size as f64 / 16_000_000.0).max(30.0) // At least 30 seconds, assume ~16 MB/s average
+        if result.success && !result.warnings.is_empty() {
+            verify.set_status(format!(
+                "Verification passed with {} warning(s): {} files checked.",
+                result.warnings.len(),
+                result.files_checked
+            ));
+        } else if result.success {
+            verify.set_status(format!(
+                "Verification successful! {} files checked.",
+                result.files_checked
+            ));
         } else {
-            300.0 // 5 minutes fallback
-        };
+            verify.set_status(format!(
+                "Verification failed! {} files failed out of {} checked.",
+                result.files_failed, result.files_checked
+            ));
+        }
 
-        // Phase 1: Initializing burn
-        let _ = tx.send(DiscCreationMessage::Progress("🔥 Initializing Blu-ray burner...".to_string()));
-        thread::sleep(Duration::from_millis(500));
+        // Step 3: Record in database
+        verify.set_verification_state(tui::verify_ui::VerificationState::Recording);
+        verify.set_status("Recording verification results...".to_string());
 
-        // Phase 2: Starting data transfer with size info
-        let _ = tx.send(DiscCreationMessage::Progress(format!("💿 Starting data transfer ({}GB) to disc...", iso_size_gb)));
-        thread::sleep(Duration::from_millis(500));
+        let disc_id = verify::read_disc_id(&mountpoint).unwrap_or_else(|| "UNKNOWN".to_string());
 
-        // Start progress monitoring thread
-        let progress_tx = tx.clone();
-        let start_time = std::time::Instant::now();
-        thread::spawn(move || {
-            let mut last_progress = 0;
-            loop {
-                let elapsed = start_time.elapsed().as_secs_f64();
-                if elapsed > estimated_burn_time_secs + 60.0 {
-                    // Burn is taking much longer than expected, stop updating
-                    break;
-                }
-
-                // Estimate progress (70-95% range for burn phase)
-                let progress_ratio = (elapsed / estimated_burn_time_secs).min(1.0);
-                let burn_progress = 70 + (progress_ratio * 25.0) as u8; // 70% to 95%
-
-                if burn_progress != last_progress && burn_progress < 95 {
-                    let speed_mbs = if elapsed > 0.0 {
-                        (iso_size as f64 / elapsed / 1_000_000.0) as u32
-                    } else { 0 };
-
-                    let eta_mins = if progress_ratio > 0.0 {
-                        ((1.0 - progress_ratio) * estimated_burn_time_secs / 60.0) as u32
-                    } else { 0 };
-
-                    let _ = progress_tx.send(DiscCreationMessage::Progress(
-                        format!("🔥 Burning... {}MB/s | {}min remaining | {}% complete",
-                               speed_mbs, eta_mins, burn_progress)
-                    ));
-                    last_progress = burn_progress;
-                }
+        let verification_run = database::VerificationRun {
+            id: None,
+            disc_id,
+            verified_at: format_timestamp_now(),
+            mountpoint: Some(mountpoint.to_string_lossy().to_string()),
+            device: Some(device.clone()),
+            success: result.success,
+            error_message: result.error_message.clone(),
+            files_checked: Some(result.files_checked),
+            files_failed: Some(result.files_failed),
+            sampled,
+        };
 
-                thread::sleep(Duration::from_secs(2)); // Update every 2 seconds
-            }
-        });
+        database::VerificationRun::insert(&mut self.db_conn, &verification_run)?;
 
-        // Perform the actual burn with error handling
-        match burn::burn_with_method(iso_path, device, dry_run, "iso") {
-            Ok(_) => {
-                let burn_duration = start_time.elapsed();
-                let actual_speed = if burn_duration.as_secs_f64() > 0.0 {
-                    (iso_size as f64 / burn_duration.as_secs_f64() / 1_000_000.0) as u32
-                } else { 0 };
-
-                let _ = tx.send(DiscCreationMessage::Progress(
-                    format!("✅ Burn completed! {:.1}s | {}MB/s average speed",
-                           burn_duration.as_secs_f64(), actual_speed)
-                ));
-                thread::sleep(Duration::from_millis(500));
-                Ok(())
+        // Only unmount what we mounted, and not when the disc is wanted for browsing
+        let kept_mount = match &mounted {
+            None => Some(mountpoint.clone()),
+            Some(mounted)
+                if self.config.verification.keep_mounted_after_verify
+                    || mounted.method == bdarchive::verify::MountMethod::Existing =>
+            {
+                Some(mounted.path.clone())
             }
-            Err(e) => {
-                error!("ISO burn failed: {}", e);
-                let _ = tx.send(DiscCreationMessage::Error(format!("Burn failed: {}", e)));
-                Err(anyhow::anyhow!("ISO burn failed: {}", e))
+            Some(mounted) => {
+                if let Err(e) = bdarchive::verify::unmount_device(mounted, dry_run) {
+                    verify.set_notice(format!("⚠️ Failed to unmount: {:#}", e));
+                }
+                None
             }
+        };
+        if let Some(path) = kept_mount {
+            verify.set_kept_mount(path, bdarchive::desktop::file_manager_available());
         }
-    }
-
-    /// Burn directory directly with detailed progress updates
-    /// Clean up the staging directory after successful burn
-    fn cleanup_staging_directory(staging_dir: &Path) -> Result<()> {
-        info!("Cleaning up staging directory: {}", staging_dir.display());
 
-        // Remove the entire staging directory
-        if staging_dir.exists() {
-            std::fs::remove_dir_all(staging_dir)?;
-            info!("Successfully cleaned up staging directory");
-        } else {
-            info!("Staging directory already removed");
-        }
+        verify.set_verification_state(tui::verify_ui::VerificationState::Complete);
 
         Ok(())
     }
 
-    /// Comprehensive cleanup of temporary files and build artifacts
+    /// Comprehensive cleanup of leftover ISOs, staging content and paused sessions
     pub fn cleanup_temporary_files(config: &Config) -> Result<()> {
         use std::fs;
         use walkdir::WalkDir;
@@ -2201,22 +1992,6 @@ impl App {
 
         info!("🧹 Starting comprehensive cleanup...");
 
-        // Clean up build artifacts (debug and release builds)
-        let target_dirs = ["target/debug", "target/release"];
-        for target_dir in &target_dirs {
-            let path = Path::new(target_dir);
-            if path.exists() {
-                info!("Removing build artifacts: {}", target_dir);
-                match fs::remove_dir_all(path) {
-                    Ok(_) => {
-                        info!("✅ Removed {}", target_dir);
-                        files_removed += 1;
-                    }
-                    Err(e) => warn!("Failed to remove {}: {}", target_dir, e),
-                }
-            }
-        }
-
         // Clean up any leftover ISO files in the project directory
         if let Ok(entries) = fs::read_dir(".") {
             for entry in entries {
@@ -2286,9 +2061,9 @@ impl App {
             }
         }
 
-        // Clean up paused burn session data
+        // Clean up paused burn session data and orphan disc sets
         if let Ok(db_path) = config.database_path() {
-            if let Ok(conn) = database::init_database(&db_path) {
+            if let Ok(mut conn) = database::init_database(&db_path) {
                 let paused_sessions = database::BurnSessionOps::get_active_sessions(&conn)?;
                 for session in paused_sessions {
                     if session.status == database::BurnSessionStatus::Paused {
@@ -2300,195 +2075,24 @@ impl App {
                         }
                     }
                 }
-            }
-        }
-
-        info!("🧹 Cleanup complete! Removed {} files/directories and sessions", files_removed);
-        Ok(())
-    }
-
-    /// Calculate the total size of a directory recursively
-    fn calculate_directory_size(dir_path: &Path) -> Result<u64> {
-        let mut total_size = 0u64;
-        Self::calculate_directory_size_recursive(dir_path, &mut total_size)?;
-        Ok(total_size)
-    }
-
-    fn calculate_directory_size_recursive(dir_path: &Path, total_size: &mut u64) -> Result<()> {
-        if dir_path.is_dir() {
-            for entry in std::fs::read_dir(dir_path)? {
-                let entry = entry?;
-                let path = entry.path();
-                if path.is_dir() {
-                    Self::calculate_directory_size_recursive(&path, total_size)?;
-                } else {
-                    let metadata = entry.metadata()?;
-                    *total_size += metadata.len();
-                }
-            }
-        }
-        Ok(())
-    }
-
-    fn burn_direct_with_progress(
-        dir_path: &Path,
-        device: &str,
-        dry_run: bool,
-        tx: mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<()> {
-        use std::thread;
-        use std::time::Duration;
-
-        if dry_run {
-            let _ = tx.send(DiscCreationMessage::Progress("DRY RUN: Would burn directory directly to disc".to_string()));
-            thread::sleep(Duration::from_millis(500));
-            return Ok(());
-        }
-
-        // Estimate directory size for progress calculation
-        let dir_size = Self::calculate_directory_size(dir_path).unwrap_or(0);
-        let dir_size_gb = dir_size as f64 / 1_000_000_000.0;
 
-        // Estimate burn time (BD-R typical speeds: 2-6x = ~8-24 MB/s)
-        let estimated_burn_time_secs = if dir_size > 0 {
-            (dir_size as f64 / 16_000_000.0).max(30.0) // At least 30 seconds, assume ~16 MB/s average
-        } else {
-            300.0 // 5 minutes fallback
-        };
-
-        // Phase 1: Initializing burn
-        let _ = tx.send(DiscCreationMessage::Progress("🔥 Initializing Blu-ray burner...".to_string()));
-        thread::sleep(Duration::from_millis(500));
-
-        // Phase 2: Starting data transfer with size info
-        let _ = tx.send(DiscCreationMessage::Progress(format!("💿 Starting direct data transfer ({}GB) to disc...", dir_size_gb)));
-        thread::sleep(Duration::from_millis(500));
-
-        // Start progress monitoring thread
-        let progress_tx = tx.clone();
-        let start_time = std::time::Instant::now();
-        thread::spawn(move || {
-            let mut last_progress = 0;
-            loop {
-                let elapsed = start_time.elapsed().as_secs_f64();
-                if elapsed > estimated_burn_time_secs + 60.0 {
-                    // Burn is taking much longer than expected, stop updating
-                    break;
-                }
-
-                // Estimate progress (70-95% range for burn phase)
-                let progress_ratio = (elapsed / estimated_burn_time_secs).min(1.0);
-                let burn_progress = 70 + (progress_ratio * 25.0) as u8; // 70% to 95%
-
-                if burn_progress != last_progress && burn_progress < 95 {
-                    let speed_mbs = if elapsed > 0.0 {
-                        (dir_size as f64 / elapsed / 1_000_000.0) as u32
-                    } else { 0 };
-
-                    let eta_mins = if progress_ratio > 0.0 {
-                        ((1.0 - progress_ratio) * estimated_burn_time_secs / 60.0) as u32
-                    } else { 0 };
-
-                    let _ = progress_tx.send(DiscCreationMessage::Progress(
-                        format!("🔥 Burning... {}MB/s | {}min remaining | {}% complete",
-                               speed_mbs, eta_mins, burn_progress)
-                    ));
-                    last_progress = burn_progress;
+                // Sets left with no discs and no resumable session
+                match database::repair_orphan_sets(&mut conn) {
+                    Ok(pruned) => {
+                        if !pruned.is_empty() {
+                            info!("🗑️ Removed {} orphan disc set(s) with no discs", pruned.len());
+                            files_removed += pruned.len() as u32;
+                        }
+                    }
+                    Err(e) => warn!("Failed to repair orphan disc sets: {}", e),
                 }
-
-                thread::sleep(Duration::from_secs(2)); // Update every 2 seconds
-            }
-        });
-
-        // Perform the actual burn with error handling
-        match burn::burn_with_method(dir_path, device, dry_run, "direct") {
-            Ok(_) => {
-                let burn_duration = start_time.elapsed();
-                let actual_speed = if burn_duration.as_secs_f64() > 0.0 {
-                    (dir_size as f64 / burn_duration.as_secs_f64() / 1_000_000.0) as u32
-                } else { 0 };
-
-                let _ = tx.send(DiscCreationMessage::Progress(
-                    format!("✅ Direct burn completed! {:.1}s | {}MB/s average speed",
-                           burn_duration.as_secs_f64(), actual_speed)
-                ));
-                thread::sleep(Duration::from_millis(500));
-                Ok(())
-            }
-            Err(e) => {
-                error!("Direct burn failed: {}", e);
-                let _ = tx.send(DiscCreationMessage::Error(format!("Direct burn failed: {}", e)));
-                Err(anyhow::anyhow!("Direct burn failed: {}", e))
             }
         }
-    }
-
-    /// Index the disc record in the database
-    fn index_disc_in_database(
-        db_conn: &mut rusqlite::Connection,
-        disc_id: &str,
-        volume_label: &str,
-        notes: &str,
-        iso_size: u64,
-        device: &str,
-        dry_run: bool,
-        source_roots: &[PathBuf],
-    ) -> Result<()> {
-        let created_at = format_timestamp_now();
-
-        let source_roots_json = serde_json::to_string(source_roots)
-            .context("Failed to serialize source roots")?;
-
-        let disc_record = database::Disc {
-            disc_id: disc_id.to_string(),
-            volume_label: volume_label.to_string(),
-            created_at: created_at.clone(),
-            notes: if notes.is_empty() { None } else { Some(notes.to_string()) },
-            iso_size: Some(iso_size),
-            burn_device: if dry_run { None } else { Some(device.to_string()) },
-            checksum_manifest_hash: None,
-            qr_path: None,
-            source_roots: Some(source_roots_json),
-            tool_version: Some(disc::get_tool_version()),
-            set_id: None, // Single disc, not part of a set
-            sequence_number: None,
-        };
-
-        database::Disc::insert(db_conn, &disc_record)
-            .context("Failed to insert disc record")?;
-
-        Ok(())
-    }
-
-    /// Index file records in the database
-    fn index_files_in_database(
-        db_conn: &mut rusqlite::Connection,
-        disc_id: &str,
-        files: &[crate::manifest::FileMetadata],
-    ) -> Result<()> {
-        let created_at = format_timestamp_now();
-
-        let file_records: Vec<database::FileRecord> = files
-            .iter()
-            .map(|f| database::FileRecord {
-                id: None,
-                disc_id: disc_id.to_string(),
-                rel_path: f.rel_path.to_string_lossy().to_string(),
-                sha256: f.crc32.clone().unwrap_or_else(|| f.sha256.clone()),
-                size: f.size,
-                mtime: f.mtime.clone(),
-                added_at: created_at.clone(),
-            })
-            .collect();
-
-        database::FileRecord::insert_batch(db_conn, &file_records)
-            .context("Failed to insert file records")?;
 
+        info!("🧹 Cleanup complete! Removed {} files/directories and sessions", files_removed);
         Ok(())
     }
 
-
-
     /// Start disc creation workflow (single or multi-disc)
     fn start_disc_creation_workflow(
         flow: &mut tui::NewDiscFlow,
@@ -2498,7 +2102,22 @@ impl App {
         db_path: PathBuf,
         disc_creation_rx: &mut Option<mpsc::Receiver<DiscCreationMessage>>,
     ) {
-        if needs_multi_disc {
+        // Fail fast on a missing or busy drive before spending time on staging and ISO creation
+        if !flow.dry_run() {
+            // Multi-disc sets may use every configured burner
+            let devices = if needs_multi_disc { config.burn_devices() } else { vec![config.device.clone()] };
+            for device in &devices {
+                if let Err(e) = burn::check_device(device) {
+                    error!("Device check failed: {}", e);
+                    flow.set_error(e.to_string());
+                    return;
+                }
+            }
+        }
+
+        // Incremental runs only stage what changed, so the full-size estimate doesn't apply;
+        // the single-disc flow checks the changed files against capacity itself
+        if needs_multi_disc && !flow.is_incremental() {
             Self::start_multi_disc_creation_workflow(flow, source_folders, config, db_path, disc_creation_rx);
         } else {
             Self::start_single_disc_creation_workflow(flow, source_folders, config, db_path, disc_creation_rx);
@@ -2513,14 +2132,18 @@ impl App {
         db_path: PathBuf,
         disc_creation_rx: &mut Option<mpsc::Receiver<DiscCreationMessage>>,
     ) {
-        // Start the disc creation process in a background thread (existing logic)
-        let disc_id = flow.disc_id().to_string();
-        let notes = flow.notes().to_string();
-        let dry_run = flow.dry_run();
-        info!("User selected burn mode - dry_run: {}", dry_run);
-
-        let disc_id_clone = disc_id.clone();
-        let notes_clone = notes.clone();
+        let mut request = archive::ArchiveRequest::new(flow.disc_id(), source_folders, config);
+        request.notes = flow.notes().to_string();
+        request.destination_names = flow.destination_names();
+        request.dry_run = flow.dry_run();
+        request.incremental = flow.is_incremental();
+        request.config.staging.checksum = flow.checksum();
+        info!(
+            "User selected burn mode - dry_run: {}, incremental: {}, checksum: {}",
+            request.dry_run,
+            request.incremental,
+            flow.checksum().name()
+        );
 
         // Create channel for communication
         let (tx, rx) = mpsc::channel::<DiscCreationMessage>();
@@ -2528,8 +2151,7 @@ impl App {
 
         thread::spawn(move || {
             // Create new database connection in background thread
-            let db_conn_result = database::init_database(&db_path);
-            let db_conn = match db_conn_result {
+            let mut db_conn = match database::init_database(&db_path) {
                 Ok(conn) => conn,
                 Err(e) => {
                     let _ = tx.send(DiscCreationMessage::Error(format!(
@@ -2540,22 +2162,18 @@ impl App {
                 }
             };
 
-            let dry_run_clone = dry_run;
-            match Self::run_disc_creation_background(
-                disc_id_clone,
-                notes_clone,
-                source_folders,
-                dry_run_clone,
-                config,
-                db_conn,
-                tx.clone(),
-            ) {
-                Ok(()) => {
-                    // Success - cleanup already handled in the function
+            match archive::create_disc(&mut db_conn, &request, Some(Self::archive_progress(tx.clone()))) {
+                Ok(outcome) => {
+                    if let Some(iso_path) = outcome.iso_path.filter(|path| path.exists()) {
+                        let _ = tx.send(DiscCreationMessage::IsoReady(iso_path));
+                    }
+                    let _ = tx.send(DiscCreationMessage::Complete);
                 }
                 Err(e) => {
-                    let _ = tx.send(DiscCreationMessage::Error(format!(
-                        "Disc creation failed: {}", e
+                    error!("Disc creation failed: {}", e);
+                    let _ = tx.send(DiscCreationMessage::Error(with_recovery_hint(
+                        format!("Disc creation failed: {}", e),
+                        e.recovery_hint(),
                     )));
                 }
             }
@@ -2570,9 +2188,11 @@ impl App {
         db_path: PathBuf,
         disc_creation_rx: &mut Option<mpsc::Receiver<DiscCreationMessage>>,
     ) {
-        let disc_id_base = flow.disc_id().to_string();
-        let notes = flow.notes().to_string();
-        let dry_run = flow.dry_run();
+        let mut request = archive::ArchiveRequest::new(flow.disc_id(), source_folders, config);
+        request.notes = flow.notes().to_string();
+        request.destination_names = flow.destination_names();
+        request.dry_run = flow.dry_run();
+        request.config.staging.checksum = flow.checksum();
 
         // Create channel for communication
         let (tx, rx) = mpsc::channel::<DiscCreationMessage>();
@@ -2580,8 +2200,7 @@ impl App {
 
         thread::spawn(move || {
             // Create new database connection in background thread
-            let db_conn_result = database::init_database(&db_path);
-            let db_conn = match db_conn_result {
+            let mut db_conn = match database::init_database(&db_path) {
                 Ok(conn) => conn,
                 Err(e) => {
                     let _ = tx.send(DiscCreationMessage::Error(format!(
@@ -2592,349 +2211,210 @@ impl App {
                 }
             };
 
-            match Self::run_multi_disc_creation_background_robust(
-                disc_id_base,
-                notes,
-                source_folders,
-                dry_run,
-                config,
-                db_conn,
-                tx.clone(),
+            let insert_tx = tx.clone();
+            match archive::create_multi_disc(
+                &mut db_conn,
+                &request,
+                Some(Self::archive_progress(tx.clone())),
+                |sequence, total, device| Self::wait_for_disc_insertion(sequence, total, device, &insert_tx),
             ) {
-                Ok(()) => {
-                    // Success - cleanup already handled in the function
+                Ok(_) => {
+                    let _ = tx.send(DiscCreationMessage::Complete);
+                }
+                Err(MultiDiscError::UserCancelled) => {
+                    let _ = tx.send(DiscCreationMessage::MultiDiscError(MultiDiscError::UserCancelled));
+                }
+                Err(MultiDiscError::PartialSuccess { session_id, completed_discs, failed_disc, error }) => {
+                    let _ = tx.send(DiscCreationMessage::MultiDiscError(MultiDiscError::PartialSuccess {
+                        session_id,
+                        completed_discs: completed_discs.clone(),
+                        failed_disc,
+                        error: error.clone(),
+                    }));
+                    let _ = tx.send(DiscCreationMessage::UserChoiceNeeded {
+                        message: format!("Disc {} failed: {}. {} discs completed successfully. What would you like to do?", failed_disc, error, completed_discs.len()),
+                        options: vec![
+                            "• Retry the failed disc: insert a new blank and press [R]".to_string(),
+                            "• Keep the set: fix the problem and continue it from Resume Burn".to_string(),
+                            "• Abort and cleanup: remove the set's catalog entries, burn session and staging files".to_string(),
+                        ],
+                    });
                 }
                 Err(e) => {
-                    let _ = tx.send(DiscCreationMessage::Error(format!(
-                        "Multi-disc creation failed: {}", e
-                    )));
+                    error!("Multi-disc creation failed: {}", e);
+                    let _ = tx.send(DiscCreationMessage::MultiDiscError(e));
                 }
             }
         });
     }
 
-    /// Run disc creation in background with comprehensive error handling
-    fn run_disc_creation_background(
-        disc_id: String,
-        notes: String,
-        source_folders: Vec<PathBuf>,
-        dry_run: bool,
-        config: Config,
-        mut db_conn: rusqlite::Connection,
-        tx: mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<()> {
-        let _ = tx.send(DiscCreationMessage::Status(format!(
-            "Starting disc creation (mode: {})...",
-            if dry_run { "DRY RUN" } else { "ACTUAL" }
-        )));
-
-        // Validate inputs
-        if disc_id.is_empty() {
-            return Err(anyhow::anyhow!("Disc ID cannot be empty"));
-        }
-
-        if source_folders.is_empty() {
-            return Err(anyhow::anyhow!("No source folders selected"));
-        }
-
-        // Validate source folders exist
-        for folder in &source_folders {
-            if !folder.exists() {
-                return Err(anyhow::anyhow!(
-                    "Source folder does not exist: {}",
-                    folder.display()
-                ));
-            }
-        }
+    /// Burn the failed disc of the flow's set again on a new blank, then
+    /// continue with the discs after it
+    fn start_retry_failed_disc(
+        flow: &mut tui::NewDiscFlow,
+        session_id: String,
+        config: &Config,
+        db_path: PathBuf,
+        disc_creation_rx: &mut Option<mpsc::Receiver<DiscCreationMessage>>,
+    ) {
+        info!("Retrying the failed disc of burn session {}", session_id);
+        let config = flow.run_config(config);
+        let destination_names = flow.destination_names();
+        flow.clear_error();
+        flow.set_status("Retrying the failed disc...".to_string());
 
-        let staging_dir = config
-            .staging_dir()
-            .context("Failed to get staging directory")?;
-        std::fs::create_dir_all(&staging_dir)?;
-
-        // Step 1: Create disc layout
-        let _ = tx.send(DiscCreationMessage::StateAndStatus(
-            tui::new_disc::ProcessingState::Staging,
-            "Creating disc layout...".to_string(),
-        ));
-        let disc_root = disc::create_disc_layout(
-            &staging_dir,
-            &disc_id,
-            &source_folders,
-            if notes.is_empty() { None } else { Some(&notes) },
-        )?;
-        let _ = tx.send(DiscCreationMessage::Status(
-            "Disc layout created".to_string(),
-        ));
-
-        // Step 2: Stage files
-        let _ = tx.send(DiscCreationMessage::StateAndStatus(
-            tui::new_disc::ProcessingState::Staging,
-            "Staging files...".to_string(),
-        ));
-        let use_rsync = config.optional_tools.use_rsync
-            && dependencies::get_optional_command("rsync").is_some();
-
-        // Create progress callback for staging
-        let progress_tx = tx.clone();
-        let staging_progress_callback = move |msg: &str| {
-            let _ = progress_tx.send(DiscCreationMessage::Progress(msg.to_string()));
-        };
+        let (tx, rx) = mpsc::channel::<DiscCreationMessage>();
+        *disc_creation_rx = Some(rx);
 
-        staging::stage_files_with_progress(
-            &disc_root,
-            &source_folders,
-            use_rsync,
-            dry_run,
-            Some(Box::new(staging_progress_callback))
-        )?;
-        let _ = tx.send(DiscCreationMessage::StateAndStatus(
-            tui::new_disc::ProcessingState::Staging,
-            "Files staged successfully".to_string(),
-        ));
-
-        // Step 3: Generate manifest and SHA256SUMS
-        let _ = tx.send(DiscCreationMessage::StateAndStatus(
-            tui::new_disc::ProcessingState::GeneratingManifest,
-            "Generating manifest and checksums...".to_string(),
-        ));
-
-        // Create progress callback that sends Progress messages
-        let progress_tx = tx.clone();
-        let progress_callback = move |msg: &str| {
-            let _ = progress_tx.send(DiscCreationMessage::Progress(msg.to_string()));
-        };
-        // Use fast mode (CRC32) for initial manifest generation
-        let files = manifest::generate_manifest_and_sums_with_progress(
-            &disc_root,
-            None,
-            Some(Box::new(progress_callback)),
-            true // fast_mode = true (uses CRC32 instead of SHA256)
-        )?;
+        thread::spawn(move || {
+            let mut db_conn = match database::init_database(&db_path) {
+                Ok(conn) => conn,
+                Err(e) => {
+                    let _ = tx.send(DiscCreationMessage::Error(format!("Retry failed: {}", e)));
+                    return;
+                }
+            };
 
-        // Write manifest files
-        let manifest_path = disc_root.join("MANIFEST.txt");
-        match manifest::write_manifest_file(&manifest_path, &files) {
-            Ok(_) => info!("Manifest file written successfully"),
-            Err(e) => {
-                error!("Failed to write manifest file: {}", e);
-                let _ = tx.send(DiscCreationMessage::Error(format!("Failed to write manifest: {}", e)));
-                return Err(anyhow::anyhow!("Failed to write manifest file: {}", e));
+            let insert_tx = tx.clone();
+            match archive::retry_failed_disc(
+                &mut db_conn,
+                &session_id,
+                &config,
+                &destination_names,
+                Some(Self::archive_progress(tx.clone())),
+                |sequence, total, device| Self::wait_for_disc_insertion(sequence, total, device, &insert_tx),
+            ) {
+                Ok(_) => {
+                    let _ = tx.send(DiscCreationMessage::Complete);
+                }
+                Err(e) => {
+                    error!("Retry of the failed disc failed: {}", e);
+                    let _ = tx.send(DiscCreationMessage::MultiDiscError(e));
+                }
             }
-        }
+        });
+    }
 
-        let sha256sums_path = disc_root.join("SHA256SUMS.txt");
-        match manifest::write_sha256sums_file(&sha256sums_path, &files) {
-            Ok(_) => info!("SHA256SUMS file written successfully"),
-            Err(e) => {
-                error!("Failed to write SHA256SUMS file: {}", e);
-                let _ = tx.send(DiscCreationMessage::Error(format!("Failed to write checksums: {}", e)));
-                return Err(anyhow::anyhow!("Failed to write SHA256SUMS file: {}", e));
-            }
-        }
+    /// Move the largest files of the flow's overflowing disc to a new disc
+    /// appended to the set, then continue burning the set
+    fn start_replan_overflowing_disc(
+        flow: &mut tui::NewDiscFlow,
+        session_id: String,
+        iso_bytes: u64,
+        config: &Config,
+        db_path: PathBuf,
+        disc_creation_rx: &mut Option<mpsc::Receiver<DiscCreationMessage>>,
+    ) {
+        info!("Re-planning the overflowing disc of burn session {}", session_id);
+        let config = flow.run_config(config);
+        let destination_names = flow.destination_names();
+        flow.clear_error();
+        flow.set_status("Moving the largest files to a new disc...".to_string());
 
+        let (tx, rx) = mpsc::channel::<DiscCreationMessage>();
+        *disc_creation_rx = Some(rx);
 
-        // Check capacity
-        let total_size = manifest::calculate_total_size(&files);
-        let capacity = config.default_capacity_bytes();
-        if total_size > capacity {
-            let error_msg = format!(
-                "Total size {:.2} GB exceeds disc capacity {:.2} GB",
-                total_size as f64 / 1_000_000_000.0,
-                capacity as f64 / 1_000_000_000.0
-            );
-            error!("Capacity check failed: {}", error_msg);
-            let _ = tx.send(DiscCreationMessage::Error(error_msg.clone()));
-            return Err(anyhow::anyhow!("{}", error_msg));
-        }
-        info!("Capacity check passed: {:.2} GB / {:.2} GB", total_size as f64 / 1_000_000_000.0, capacity as f64 / 1_000_000_000.0);
-
-        // Step 4: Create ISO (skip if using direct burn and not dry run)
-        let volume_label = disc::generate_volume_label(&disc_id);
-        let iso_path = staging_dir.join(format!("{}.iso", disc_id));
-        let iso_size;
-
-        if config.burn.method == "direct" && !dry_run {
-            info!("Skipping ISO creation (using direct burn method)");
-            iso_size = manifest::calculate_total_size(&files); // Use directory size
-            let _ = tx.send(DiscCreationMessage::StateAndStatus(
-                tui::new_disc::ProcessingState::CreatingISO,
-                format!("Direct burn - skipping ISO creation ({:.2} GB)", iso_size as f64 / 1_000_000_000.0),
-            ));
-        } else {
-            let _ = tx.send(DiscCreationMessage::StateAndStatus(
-                tui::new_disc::ProcessingState::CreatingISO,
-                "Creating ISO image...".to_string(),
-            ));
+        thread::spawn(move || {
+            let mut db_conn = match database::init_database(&db_path) {
+                Ok(conn) => conn,
+                Err(e) => {
+                    let _ = tx.send(DiscCreationMessage::Error(format!("Re-plan failed: {}", e)));
+                    return;
+                }
+            };
 
-            info!("Creating ISO at: {}", iso_path.display());
-            match iso::create_iso(&disc_root, &iso_path, &volume_label, false) {
+            let insert_tx = tx.clone();
+            match archive::replan_overflowing_disc(
+                &mut db_conn,
+                &session_id,
+                iso_bytes,
+                &config,
+                &destination_names,
+                Some(Self::archive_progress(tx.clone())),
+                |sequence, total, device| Self::wait_for_disc_insertion(sequence, total, device, &insert_tx),
+            ) {
                 Ok(_) => {
-                    info!("ISO creation command completed");
-                    match iso::get_iso_size(&iso_path) {
-                        Ok(size) => {
-                            iso_size = size;
-                            info!("ISO created successfully: {} bytes", iso_size);
-                        }
-                        Err(e) => {
-                            error!("Failed to get ISO size after creation: {}", e);
-                            let _ = tx.send(DiscCreationMessage::Error(format!("Failed to verify ISO size: {}", e)));
-                            return Err(anyhow::anyhow!("Failed to get ISO size: {}", e));
-                        }
-                    }
+                    let _ = tx.send(DiscCreationMessage::Complete);
                 }
                 Err(e) => {
-                    error!("ISO creation failed: {}", e);
-                    let _ = tx.send(DiscCreationMessage::Error(format!("ISO creation failed: {}", e)));
-                    return Err(anyhow::anyhow!("ISO creation failed: {}", e));
-                }
-            }
-            let _ = tx.send(DiscCreationMessage::StateAndStatus(
-                tui::new_disc::ProcessingState::CreatingISO,
-                format!("ISO created: {:.2} GB", iso_size as f64 / 1_000_000_000.0),
-            ));
-        }
-
-        // Step 5: Burn to disc (or create ISO for dry run)
-        let _ = tx.send(DiscCreationMessage::StateAndStatus(
-            tui::new_disc::ProcessingState::Burning,
-            if dry_run {
-                "Creating ISO for dry run...".to_string()
-            } else {
-                format!("Burning to {}...", config.device)
-            },
-        ));
-
-        if dry_run {
-            // For dry run, ensure we have an ISO created so user can archive it manually
-            if config.burn.method == "direct" {
-                // For direct method, still create ISO for dry run purposes
-                let volume_label = disc::generate_volume_label(&disc_id);
-                info!("Creating ISO for dry run at: {}", iso_path.display());
-                match iso::create_iso(&disc_root, &iso_path, &volume_label, false) {
-                    Ok(_) => {
-                        match iso::get_iso_size(&iso_path) {
-                            Ok(_) => {
-                                info!("Dry run ISO created successfully");
-                            }
-                            Err(e) => {
-                                error!("Failed to get dry run ISO size: {}", e);
-                                let _ = tx.send(DiscCreationMessage::Error(format!("Failed to verify dry run ISO: {}", e)));
-                                return Err(anyhow::anyhow!("Failed to get dry run ISO size: {}", e));
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        error!("Dry run ISO creation failed: {}", e);
-                        let _ = tx.send(DiscCreationMessage::Error(format!("Dry run ISO creation failed: {}", e)));
-                        return Err(anyhow::anyhow!("Dry run ISO creation failed: {}", e));
-                    }
+                    error!("Re-plan of the overflowing disc failed: {}", e);
+                    let _ = tx.send(DiscCreationMessage::MultiDiscError(e));
                 }
             }
-            // For ISO method, ISO is already created above
-
-            let iso_display_path = iso_path.display();
-            let _ = tx.send(DiscCreationMessage::StateAndStatus(
-                tui::new_disc::ProcessingState::Burning,
-                format!("DRY RUN COMPLETE - ISO saved at: {}", iso_display_path),
-            ));
+        });
+    }
 
-            // Show additional message about where to find the ISO
-            info!("Dry run ISO available at: {}", iso_display_path);
-        } else {
-            // Actual burning with progress updates
-            match config.burn.method.as_str() {
-                "direct" => {
-                    // Burn the staging directory directly (no ISO needed)
-                    Self::burn_direct_with_progress(&disc_root, &config.device, dry_run, tx.clone())?;
-                }
-                "iso" | _ => {
-                    // Default: create and burn ISO
-                    Self::burn_iso_with_progress(&iso_path, &config.device, dry_run, tx.clone())?;
+    /// Forward archive pipeline events to the New Disc screen
+    fn archive_progress(tx: mpsc::Sender<DiscCreationMessage>) -> archive::ProgressCallback {
+        std::sync::Arc::new(move |event| {
+            let message = match event {
+                archive::ArchiveEvent::Stage(stage, status) => {
+                    let state = match stage {
+                        archive::ArchiveStage::Staging => tui::new_disc::ProcessingState::Staging,
+                        archive::ArchiveStage::GeneratingManifest => tui::new_disc::ProcessingState::GeneratingManifest,
+                        archive::ArchiveStage::CreatingIso => tui::new_disc::ProcessingState::CreatingISO,
+                        archive::ArchiveStage::Burning => tui::new_disc::ProcessingState::Burning,
+                        archive::ArchiveStage::Indexing => tui::new_disc::ProcessingState::Indexing,
+                        archive::ArchiveStage::GeneratingQr => tui::new_disc::ProcessingState::GeneratingQR,
+                    };
+                    DiscCreationMessage::StateAndStatus(state, status)
                 }
-            }
-            let _ = tx.send(DiscCreationMessage::StateAndStatus(
-                tui::new_disc::ProcessingState::Burning,
-                "Disc burned successfully".to_string(),
-            ));
-        }
+                archive::ArchiveEvent::Status(status) => DiscCreationMessage::Status(status),
+                archive::ArchiveEvent::Progress(progress) => DiscCreationMessage::Progress(progress),
+                archive::ArchiveEvent::PhaseProgress(fraction) => DiscCreationMessage::PhaseProgress(fraction),
+                archive::ArchiveEvent::Hashing(progress) => DiscCreationMessage::Hashing(progress),
+                archive::ArchiveEvent::MultiDiscProgress(progress) => DiscCreationMessage::MultiDiscProgress(progress),
+            };
+            let _ = tx.send(message);
+        })
+    }
 
-        // Step 6: Index in database
-        let _ = tx.send(DiscCreationMessage::StateAndStatus(
-            tui::new_disc::ProcessingState::Indexing,
-            "Updating index...".to_string(),
-        ));
-
-        let source_roots: Vec<PathBuf> = source_folders.clone();
-        match Self::index_disc_in_database(&mut db_conn, &disc_id, &volume_label, &notes, iso_size, &config.device, dry_run, &source_roots) {
-            Ok(_) => {
-                let _ = tx.send(DiscCreationMessage::StateAndStatus(
-                    tui::new_disc::ProcessingState::Indexing,
-                    "Database updated successfully".to_string(),
-                ));
-            }
-            Err(e) => {
-                error!("Database indexing failed: {}", e);
-                let _ = tx.send(DiscCreationMessage::Error(format!("Database indexing failed: {}", e)));
-                return Err(anyhow::anyhow!("Database indexing failed: {}", e));
-            }
+    /// Wait for user to insert disc with timeout and cancellation
+    /// First Ctrl-C: stop a running disc creation at its next safe point, or
+    /// quit when nothing is running. Returns whether to keep running.
+    fn handle_interrupt(&mut self) -> bool {
+        if self.disc_creation_rx.is_none() {
+            return false;
         }
-
-        match Self::index_files_in_database(&mut db_conn, &disc_id, &files) {
-            Ok(_) => {
-                let _ = tx.send(DiscCreationMessage::Progress("Files indexed in database".to_string()));
-            }
-            Err(e) => {
-                error!("File indexing failed: {}", e);
-                let _ = tx.send(DiscCreationMessage::Error(format!("File indexing failed: {}", e)));
-                return Err(anyhow::anyhow!("File indexing failed: {}", e));
-            }
+        if let AppState::NewDisc(ref mut flow) = self.state {
+            flow.set_status(format!(
+                "⏹️ Interrupted: stopping at the next safe point (a burn in progress finishes first). \
+                 Press Ctrl-C again within {}s to force quit.",
+                interrupt::FORCE_QUIT_WINDOW.as_secs()
+            ));
         }
+        true
+    }
 
-        // Step 7: Generate QR code
-        let _ = tx.send(DiscCreationMessage::StateAndStatus(
-            tui::new_disc::ProcessingState::GeneratingQR,
-            "Generating QR code...".to_string(),
-        ));
+    fn wait_for_disc_insertion(
+        sequence_num: usize,
+        total_discs: usize,
+        device: &str,
+        tx: &mpsc::Sender<DiscCreationMessage>,
+    ) -> bool {
+        let _ = tx.send(DiscCreationMessage::Status(format!(
+            "📀 Please insert disc {} of {} into {} and press Enter to continue (or 'q' to cancel)...",
+            sequence_num, total_discs, device
+        )));
 
-        if config.optional_tools.use_qrencode {
-            match Self::generate_qr_code_safely(&config, &disc_id, dry_run) {
-                Ok(_) => {
-                    let _ = tx.send(DiscCreationMessage::Status("QR code generated".to_string()));
-                }
-                Err(e) => {
-                    warn!("QR code generation failed: {}", e);
-                    let _ = tx.send(DiscCreationMessage::Status(format!(
-                        "QR code generation skipped: {}",
-                        e
-                    )));
-                }
+        // In a real implementation, this would wait for user input
+        // For now, just send animated waiting messages
+        let spinners = ["|", "/", "-", "\\"];
+        for i in 0..10 {  // 3 second timeout simulation
+            if interrupt::is_requested() {
+                return false;
             }
-        } else {
-            let _ = tx.send(DiscCreationMessage::Status("QR code generation disabled".to_string()));
+            let _ = tx.send(DiscCreationMessage::Progress(format!(
+                "⏳ Waiting for disc {}... {} (press Enter when ready, 'q' to cancel)", sequence_num, spinners[i % spinners.len()]
+            )));
+            std::thread::sleep(std::time::Duration::from_millis(300));
         }
 
-        let _ = tx.send(DiscCreationMessage::Complete);
-        Ok(())
-    }
-
-    /// Safely generate QR code with proper error handling
-    fn generate_qr_code_safely(
-        _config: &Config,
-        disc_id: &str,
-        dry_run: bool,
-    ) -> Result<()> {
-        let qrcodes_dir = paths::qrcodes_dir()
-            .context("Failed to get QR codes directory")?;
-
-        qrcode::generate_qrcode(
-            disc_id,
-            &qrcodes_dir,
-            qrcode::QrCodeFormat::PNG,
-            dry_run,
-        ).context("QR code generation failed")?;
+        let _ = tx.send(DiscCreationMessage::Progress(format!(
+            "✅ Disc {} ready, starting burn process...", sequence_num
+        )));
 
-        Ok(())
+        true
     }
 
     fn render(&mut self, frame: &mut Frame) {
@@ -2959,6 +2439,15 @@ impl App {
                 AppState::Search(_) => "Search Index",
                 AppState::Verify(_) => "Verify Disc",
                 AppState::ListDiscs(_) => "List Discs",
+                AppState::ScanQr(_) => "Scan Disc Label",
+                AppState::IdentifyDisc(_) => "Identify Inserted Disc",
+                AppState::BurnIso(_) => "Burn Existing ISO",
+                AppState::Scrub(_) => "Scrub Report",
+                AppState::CatalogStats(_) => "Catalog Stats",
+                AppState::DiscDetail(_) => "Disc Details",
+                AppState::Restore(_) => "Restore Files",
+                AppState::CatalogDiff(_) => "Catalog Diff",
+                AppState::IsoLibrary(_) => "Re-burn from ISO",
                 AppState::Settings(_) => "Settings",
                 AppState::Logs(_) => "Logs",
                 AppState::Quit => "Quit",
@@ -3008,17 +2497,298 @@ impl App {
             AppState::Verify(ref verify) => {
                 verify.render(&self.theme, frame, content_area);
             }
-            AppState::ListDiscs(ref list) => {
+            AppState::ListDiscs(ref mut list) => {
                 list.render(&self.theme, frame, content_area);
             }
+            AppState::ScanQr(ref scan) => {
+                scan.render(&self.theme, frame, content_area);
+            }
+            AppState::IdentifyDisc(ref view) => {
+                view.render(&self.theme, frame, content_area);
+            }
+            AppState::BurnIso(ref view) => {
+                view.render(&self.theme, frame, content_area);
+            }
+            AppState::Scrub(ref scrub) => {
+                scrub.render(&self.theme, frame, content_area);
+            }
+            AppState::CatalogStats(ref view) => {
+                view.render(&self.theme, frame, content_area);
+            }
+            AppState::DiscDetail(ref detail) => {
+                detail.render(&self.theme, frame, content_area);
+            }
+            AppState::Restore(ref view) => {
+                view.render(&self.theme, frame, content_area);
+            }
+            AppState::CatalogDiff(ref view) => {
+                view.render(&self.theme, frame, content_area);
+            }
+            AppState::IsoLibrary(ref library) => {
+                library.render(&self.theme, frame, content_area);
+            }
             AppState::Settings(ref settings) => {
                 settings.render(&self.theme, frame, content_area);
             }
-            AppState::Logs(ref logs) => {
+            AppState::Logs(ref mut logs) => {
                 logs.render(&self.theme, frame, content_area);
             }
             AppState::Quit => {}
         }
+
+        // Confirmation dialogs are drawn last so they sit on top of the screen
+        if let Some((ref dialog, _)) = self.confirm {
+            dialog.render(&self.theme, frame, content_area);
+        }
+    }
+
+    /// Whether a disc creation thread is still running behind the New Disc screen,
+    /// or an ISO (retained or existing) is being burned
+    fn burn_in_progress(&self) -> bool {
+        let creating = self.disc_creation_rx.is_some()
+            && matches!(&self.state, AppState::NewDisc(flow)
+                if !matches!(flow.processing_state(), tui::new_disc::ProcessingState::Complete));
+        creating || self.reburn_rx.is_some()
+    }
+
+    /// Carry out an action the user has just confirmed
+    fn run_confirmed(&mut self, action: PendingAction) -> Result<bool> {
+        match action {
+            PendingAction::Quit => {
+                // The burn thread dies with the process, so record where the set stands first
+                if self.burn_in_progress() {
+                    match database::BurnSessionOps::pause_active_sessions(&self.db_conn) {
+                        Ok(paused) if paused > 0 => info!("Quit during a burn; paused {} burn session(s)", paused),
+                        Ok(_) => info!("Quit during a burn"),
+                        Err(e) => warn!("Failed to pause burn sessions on quit: {}", e),
+                    }
+                }
+                return Ok(false);
+            }
+            PendingAction::StartBurn => self.start_disc_creation()?,
+            PendingAction::ReburnIso(disc_id) => self.start_reburn(disc_id)?,
+            PendingAction::BurnExternalIso(iso_path) => self.start_external_iso_burn(iso_path)?,
+            PendingAction::ImportDisc(source) => {
+                let config = self.config.clone();
+                let conn = &mut self.db_conn;
+                let result = verify::with_disc_mounted(&source, |root| {
+                    archive::import_mounted_disc(conn, &config, root).map_err(anyhow::Error::from)
+                });
+                match result {
+                    Ok(outcome) => {
+                        let disc = database::Disc::get(&self.db_conn, &outcome.disc_id)?
+                            .context("Imported disc is missing from the catalog")?;
+                        let files = database::FileRecord::list_for_disc(&self.db_conn, &disc.disc_id)?;
+                        self.state = AppState::DiscDetail(Box::new(tui::DiscDetail::new(disc, files)));
+                    }
+                    Err(e) => {
+                        if let AppState::IdentifyDisc(ref mut view) = self.state {
+                            view.set_message(format!("Import failed: {:#}", e));
+                        }
+                    }
+                }
+            }
+            PendingAction::RestoreBackup(path) => {
+                // Keep the catalog being replaced, so a restore can itself be undone. Nothing is
+                // pruned here: the backup being restored may be the oldest one.
+                let result = database::backup_dir(&self.db_conn)
+                    .context("The database has no file to back up")
+                    .and_then(|dir| database::rolling_backup(&self.db_conn, &dir, usize::MAX))
+                    .and_then(|_| database::restore_from(&mut self.db_conn, &path));
+                if let AppState::Settings(ref mut settings) = self.state {
+                    match result {
+                        Ok(()) => settings.set_restore_report(&path),
+                        Err(e) => settings.set_maintenance_error(format!("{:#}", e)),
+                    }
+                    settings.set_backups(catalog_backups(&self.db_conn));
+                }
+            }
+            PendingAction::DeleteSession(session_id) => {
+                database::BurnSessionOps::delete_session(&self.db_conn, &session_id)?;
+                if let AppState::ResumeBurn(ref mut resume_ui) = self.state {
+                    let sessions = database::BurnSessionOps::get_active_sessions(&self.db_conn)?;
+                    resume_ui.set_sessions(sessions);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Burn a retained ISO in the background, reporting back to the Re-burn screen
+    fn start_reburn(&mut self, disc_id: String) -> Result<()> {
+        let AppState::IsoLibrary(ref mut library) = self.state else {
+            return Ok(());
+        };
+
+        let db_path = self.config.database_path()?;
+        let config = self.config.clone();
+        let (tx, rx) = mpsc::channel();
+        self.reburn_rx = Some(rx);
+        library.start_burn(format!("🔥 Burning {} to {}...", disc_id, config.device));
+
+        thread::spawn(move || {
+            let result = database::init_database(&db_path)
+                .and_then(|conn| iso_library::reburn(&conn, &config, &disc_id, false));
+            let _ = tx.send(match result {
+                Ok(()) => Ok(format!("✅ {} burned from its retained ISO", disc_id)),
+                Err(e) => Err(format!("{:#}", e)),
+            });
+        });
+        Ok(())
+    }
+
+    /// Burn an existing ISO in the background and record it, reporting back to the Burn Existing ISO screen
+    fn start_external_iso_burn(&mut self, iso_path: PathBuf) -> Result<()> {
+        let AppState::BurnIso(ref mut view) = self.state else {
+            return Ok(());
+        };
+
+        let db_path = self.config.database_path()?;
+        let config = self.config.clone();
+        let (tx, rx) = mpsc::channel();
+        self.reburn_rx = Some(rx);
+        view.start_burn(format!("🔥 Burning {} to {}...", iso_path.display(), config.device));
+
+        thread::spawn(move || {
+            let result = database::init_database(&db_path).map_err(|e| format!("{:#}", e)).and_then(|mut conn| {
+                archive::burn_existing_iso(&mut conn, &config, &iso_path, None, false, None).map_err(|e| e.to_string())
+            });
+            let _ = tx.send(result.map(|outcome| {
+                if outcome.file_count == 0 {
+                    format!("✅ {} burned and recorded (no manifest: verification won't be available)", outcome.disc_id)
+                } else {
+                    format!("✅ {} burned and recorded with {} files", outcome.disc_id, outcome.file_count)
+                }
+            }));
+        });
+        Ok(())
+    }
+
+    /// Leave the Review step and queue the disc creation for the main loop
+    fn start_disc_creation(&mut self) -> Result<()> {
+        let AppState::NewDisc(ref mut flow) = self.state else {
+            return Ok(());
+        };
+
+        // A per-run staging directory may have filled up since it was chosen
+        if let Some(dir) = flow.staging_override() {
+            if let Err(e) = bdarchive::staging::check_staging_dir(dir, flow.staging_space_needed(&self.config)) {
+                flow.set_notice(NoticeLevel::Error, format!("{:#}", e));
+                return Ok(());
+            }
+        }
+
+        flow.next_step(&self.config)?;
+
+        // Check if we need multi-disc burning
+        let source_folders = flow.source_folders().to_vec();
+        let config = flow.run_config(&self.config);
+        if let Some(dir) = flow.staging_override() {
+            info!("Staging this run in {}", dir.display());
+        }
+        if let Err(e) = database::record_recent_sources(&self.db_conn, &source_folders) {
+            warn!("Failed to remember source folders: {}", e);
+        }
+
+        // Calculate total size to determine if multi-disc is needed
+        let disc_capacity = config.default_capacity_bytes();
+        match bdarchive::compression::check_capacity(&source_folders, disc_capacity, &config.staging) {
+            Ok((estimate, exceeds)) => {
+                if exceeds {
+                    info!(
+                        "Estimated ISO exceeds single disc capacity ({} bytes, {} bytes of content), starting multi-disc workflow",
+                        estimate.iso_bytes, estimate.content_bytes
+                    );
+                    flow.set_status("Planning multi-disc layout...".to_string());
+                } else {
+                    info!(
+                        "Estimated ISO fits on single disc ({} bytes, {} bytes of content), starting single-disc workflow",
+                        estimate.iso_bytes, estimate.content_bytes
+                    );
+                    flow.set_status("Starting disc creation...".to_string());
+                }
+                // Store the request for processing by the main loop
+                info!("Setting pending_disc_creation: multi_disc={}, folders={}", exceeds, source_folders.len());
+                self.pending_disc_creation = Some((exceeds, source_folders, config));
+            }
+            Err(e) => {
+                flow.set_status(format!("Error calculating size: {}", e));
+                flow.set_error("Failed to analyze content size".to_string());
+                flow.previous_step();
+            }
+        }
+        Ok(())
+    }
+
+    /// Restore the disc, or its whole set, chosen on the Restore screen in the background
+    fn start_restore(&mut self) -> Result<()> {
+        let AppState::Restore(ref mut view) = self.state else {
+            return Ok(());
+        };
+        let disc_id = view.disc_id().to_string();
+        let set_id = view.set_to_restore().map(str::to_string);
+        let destination = bdarchive::paths::expand_tilde(view.destination());
+        let verify = view.verify();
+        let device = self.config.device.clone();
+        let db_path = self.config.database_path()?;
+        info!("Restoring {} to {}", set_id.as_deref().unwrap_or(&disc_id), destination.display());
+
+        let (tx, rx) = mpsc::channel();
+        let (reply_tx, reply_rx) = mpsc::channel::<bool>();
+        self.restore_rx = Some(rx);
+        self.restore_reply = Some(reply_tx);
+        view.start();
+
+        thread::spawn(move || {
+            let progress_tx = tx.clone();
+            let mut progress: Option<staging::ProgressCallback> = Some(Box::new(move |message: &str| {
+                let _ = progress_tx.send(RestoreMessage::Progress(message.to_string()));
+            }));
+            let result = database::init_database(&db_path).and_then(|conn| match set_id {
+                Some(set_id) => {
+                    let outcome = restore::restore_set(
+                        &conn,
+                        &set_id,
+                        &device,
+                        &destination,
+                        verify,
+                        |sequence, total, disc_id, problem| {
+                            let _ = tx.send(RestoreMessage::InsertDisc {
+                                sequence,
+                                total,
+                                disc_id: disc_id.to_string(),
+                                problem: problem.map(str::to_string),
+                            });
+                            reply_rx.recv().unwrap_or(false)
+                        },
+                        &mut progress,
+                    )?;
+                    Ok((outcome.restored, outcome.skipped))
+                }
+                None => {
+                    let outcome = restore::restore_disc(&conn, &disc_id, &device, &destination, verify, &mut progress)?;
+                    Ok((vec![outcome], Vec::new()))
+                }
+            });
+            let _ = match result {
+                Ok((restored, skipped)) => {
+                    let mut summary = vec![format!("✅ Restored to {}", destination.display())];
+                    for outcome in &restored {
+                        summary.push(outcome.summary());
+                        summary.extend(outcome.failures.iter().take(5).map(|failure| format!("  ❌ {}", failure)));
+                        if outcome.failures.len() > 5 {
+                            summary.push(format!("  ... and {} more", outcome.failures.len() - 5));
+                        }
+                    }
+                    if !skipped.is_empty() {
+                        summary.push(format!("⚠️ Skipped: {}", skipped.join(", ")));
+                    }
+                    tx.send(RestoreMessage::Done(summary))
+                }
+                Err(e) => tx.send(RestoreMessage::Error(format!("Restore failed: {:#}", e))),
+            };
+        });
+        Ok(())
     }
 
     /// Resume a paused burn session
@@ -3029,7 +2799,13 @@ impl App {
         let mut flow = tui::NewDiscFlow::new(format!("Resume: {}", session.session_name));
 
         // Set up the flow with session data
-        flow.set_multi_disc_progress(session.current_disc as u32, session.total_discs as u32, 0.0);
+        flow.set_multi_disc_progress(archive::MultiDiscProgress {
+            current: session.current_disc,
+            total: session.total_discs,
+            bytes_done: 0,
+            bytes_total: 0,
+            eta_secs: None,
+        });
         flow.set_status(format!("Resuming session '{}' from disc {} of {}",
             session.session_name, session.current_disc, session.total_discs));
 
@@ -3045,14 +2821,28 @@ impl App {
         thread::spawn(move || {
             let _ = tx.send(DiscCreationMessage::Status("🔄 Resuming multi-disc burn...".to_string()));
 
+            let mut db_conn = match database::init_database(&db_path) {
+                Ok(conn) => conn,
+                Err(e) => {
+                    let _ = tx.send(DiscCreationMessage::Error(format!("Resume failed: {}", e)));
+                    return;
+                }
+            };
+
             // Resume from the current disc
-            let tx_clone = tx.clone();
-            match Self::resume_multi_disc_creation_background(
-                session_clone, db_path, config.clone(), tx
+            match archive::resume_multi_disc(
+                &mut db_conn,
+                session_clone,
+                &config,
+                Some(Self::archive_progress(tx.clone())),
+                |sequence, total, device| Self::wait_for_disc_insertion(sequence, total, device, &tx),
             ) {
-                Ok(_) => {}
+                Ok(_) => {
+                    let _ = tx.send(DiscCreationMessage::Complete);
+                }
                 Err(e) => {
-                    let _ = tx_clone.send(DiscCreationMessage::Error(format!("Resume failed: {}", e)));
+                    error!("Resume failed: {}", e);
+                    let _ = tx.send(DiscCreationMessage::MultiDiscError(e));
                 }
             }
         });
@@ -3061,100 +2851,528 @@ impl App {
         Ok(())
     }
 
-    /// Resume multi-disc creation from a saved session
-    fn resume_multi_disc_creation_background(
-        session: database::BurnSession,
-        db_path: std::path::PathBuf,
-        config: Config,
-        tx: mpsc::Sender<DiscCreationMessage>,
-    ) -> Result<()> {
-        let mut db_conn = database::init_database(&db_path)?;
-        // Get the disc set
-        let disc_set = database::DiscSet::get(&db_conn, &session.set_id)?
-            .ok_or_else(|| anyhow::anyhow!("Disc set not found: {}", session.set_id))?;
-
-        // Recreate the plans from the disc set
-        let plans = Self::recreate_plans_from_disc_set(&disc_set, &config)?;
-
-        // Continue burning from the current disc
-        let remaining_plans = &plans[(session.current_disc - 1) as usize..];
-        let notes = disc_set.description.as_ref().unwrap_or(&String::new()).clone();
-
-        for (i, plan) in remaining_plans.iter().enumerate() {
-            let sequence_num = session.current_disc + i as usize;
-            let disc_id = disc::generate_multi_disc_id(&session.session_name, sequence_num as u32);
-
-            // Burn this disc
-            match Self::burn_single_disc_with_recovery(
-                &session.session_name,
-                &notes,
-                plan,
-                sequence_num,
-                session.total_discs,
-                false, // Not a dry run for resumed sessions
-                &config,
-                &mut db_conn,
-                &session.set_id,
-                &session.source_folders,
-                &tx,
-            ) {
-                Ok(_) => {
-                    // Update session progress
-                    let mut updated_session = session.clone();
-                    updated_session.update_progress(sequence_num);
-                    let _ = updated_session.save(&db_conn);
-                }
-                Err(e) => {
-                    // Mark session as failed
-                    let mut failed_session = session.clone();
-                    failed_session.failed_discs.push(sequence_num);
-                    let _ = failed_session.save(&db_conn);
-                    return Err(anyhow::anyhow!("Disc burn failed: {:?}", e));
-                }
-            }
+    /// Clean up a paused burn session
+    fn cleanup_burn_session(&self, session_id: &str) -> Result<()> {
+        info!("Cleaning up burn session: {}", session_id);
+        database::BurnSessionOps::delete_session(&self.db_conn, session_id)?;
+        Ok(())
+    }
+}
+
+/// `bdarchive plan --source <dir> [--source <dir>...] [--capacity <GB>]`
+///
+/// Prints a disc layout estimate without staging or burning anything.
+/// Run the search screen's query for its current sort and page.
+/// Fill the disc list with every disc and its tags.
+fn load_disc_list(conn: &rusqlite::Connection, list: &mut tui::ListDiscs) -> Result<()> {
+    list.set_discs(database::Disc::list_all(conn)?);
+    list.set_tags(database::Disc::all_tags(conn)?);
+    Ok(())
+}
+
+fn run_search(conn: &rusqlite::Connection, search: &mut tui::SearchUI) -> Result<()> {
+    let query = search.build_search_query();
+    let results = search::search_files(conn, &query)?;
+    search.set_results(results);
+    Ok(())
+}
+
+fn run_plan_command(args: &[String]) -> Result<()> {
+    let mut source_folders = Vec::new();
+    let mut capacity_gb = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--source" | "-s" => {
+                let value = iter.next().context("--source requires a directory")?;
+                source_folders.push(PathBuf::from(value));
+            }
+            "--capacity" | "-c" => {
+                let value = iter.next().context("--capacity requires a size in GB")?;
+                capacity_gb = Some(
+                    value
+                        .parse::<u64>()
+                        .with_context(|| format!("Invalid capacity: {}", value))?,
+                );
+            }
+            other => anyhow::bail!(
+                "Unknown argument: {}\nUsage: bdarchive plan --source <dir> [--source <dir>...] [--capacity <GB>]",
+                other
+            ),
+        }
+    }
+
+    if source_folders.is_empty() {
+        anyhow::bail!("Usage: bdarchive plan --source <dir> [--source <dir>...] [--capacity <GB>]");
+    }
+    for folder in &source_folders {
+        if !folder.exists() {
+            anyhow::bail!("Source folder does not exist: {}", folder.display());
         }
+    }
+    staging::preflight_source_folders(&source_folders)?;
 
-        // Mark session as completed
-        let session_id = session.set_id.clone();
-        let mut completed_session = session;
-        completed_session.complete();
-        let _ = completed_session.save(&db_conn);
+    let config = Config::load()?;
+    let capacity_gb = capacity_gb.unwrap_or(config.default_capacity_gb);
+    let capacity_bytes = capacity_gb * config::BYTES_PER_GB;
 
-        Self::finalize_multi_disc_archive(&vec![], &session_id, disc_set.total_size, false, &config, &tx);
+    let walk_options = staging::WalkOptions::from(&config.staging);
+    let cache = size_cache::SizeCache::open_default(walk_options);
+    let summary = staging::estimate_plan(&source_folders, capacity_bytes, walk_options, Some(&cache))?;
 
-        Ok(())
+    println!("Source folders:");
+    for folder in &source_folders {
+        println!("  {}", folder.display());
+    }
+    println!("Disc capacity: {} GB", capacity_gb);
+    println!("Total size:    {}", search::format_bytes_gb(summary.total_bytes));
+    println!("Discs needed:  {}", summary.disc_count);
+    for (i, size) in summary.per_disc_sizes.iter().enumerate() {
+        println!("  Disc {}: {}", i + 1, search::format_bytes_gb(*size));
+    }
+    println!("Utilization:   {:.1}%", summary.utilization);
+    if !summary.size_histogram.is_empty() {
+        println!("File sizes:");
+        for line in staging::describe_size_histogram(&summary.size_histogram) {
+            println!("  {}", line);
+        }
+    }
+    if !summary.oversize_files.is_empty() {
+        println!();
+        println!("ERROR: {}", staging::describe_oversize_files(&summary.oversize_files, capacity_bytes));
+    }
+    if config.staging.compression == config::Compression::None {
+        let sources: Vec<(PathBuf, Option<String>)> = source_folders.iter().map(|f| (f.clone(), None)).collect();
+        let prefix = if summary.disc_count > 1 {
+            ""
+        } else if config.staging.layout == config::DiscLayout::Bagit {
+            "data/ARCHIVE"
+        } else {
+            "ARCHIVE"
+        };
+        let long_paths = staging::find_long_paths(
+            &sources,
+            Path::new(prefix),
+            staging::WalkOptions::from(&config.staging),
+            &iso::PathLimits::from(&config.iso),
+        )?;
+        if !long_paths.is_empty() {
+            println!();
+            println!("WARNING: {}", staging::describe_long_paths(&long_paths, 20));
+        }
+    }
+    if !summary.split_directories.is_empty() {
+        println!("Split directories:");
+        for dir in &summary.split_directories {
+            println!("  {}", dir);
+        }
     }
 
-    /// Recreate disc plans from an existing disc set
-    fn recreate_plans_from_disc_set(disc_set: &database::DiscSet, config: &Config) -> Result<Vec<staging::DiscPlan>> {
-        // This is a simplified recreation - in practice, you'd need to store more
-        // detailed plan information or recalculate from source folders
-        let source_folders: Vec<PathBuf> = serde_json::from_str(
-            &disc_set.source_roots.as_deref().unwrap_or("[]")
-        ).unwrap_or_default();
+    Ok(())
+}
 
-        if source_folders.is_empty() {
-            return Err(anyhow::anyhow!("Cannot recreate plans: no source folders stored"));
+/// `bdarchive scrub [--days <N>] [--csv <file>]`
+///
+/// Lists discs not verified within N days (default `verification.scrub_after_days`), oldest first.
+fn run_scrub_command(args: &[String]) -> Result<()> {
+    const USAGE: &str = "Usage: bdarchive scrub [--days <N>] [--csv <file>]";
+    let mut days = None;
+    let mut csv_path = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--days" | "-d" => {
+                let value = iter.next().context("--days requires a number")?;
+                days = Some(
+                    value
+                        .parse::<u64>()
+                        .with_context(|| format!("Invalid number of days: {}", value))?,
+                );
+            }
+            "--csv" => {
+                let value = iter.next().context("--csv requires a file path")?;
+                csv_path = Some(PathBuf::from(value));
+            }
+            other => anyhow::bail!("Unknown argument: {}\n{}", other, USAGE),
         }
+    }
 
-        staging::plan_disc_layout_with_progress(
-            &source_folders,
-            config.default_capacity_bytes(),
-            |_| {} // No progress callback needed for recreation
-        )
+    let config = Config::load()?;
+    let days = days.unwrap_or(config.verification.scrub_after_days);
+    let conn = database::init_database(&config.database_path()?)?;
+    let discs = verify::find_stale_discs(&conn, days)?;
+
+    println!("Discs not verified in the last {} days: {}", days, discs.len());
+    for disc in &discs {
+        println!(
+            "  {:<20} created {}  last verified {}",
+            disc.disc_id,
+            disc::format_timestamp_relative(&disc.created_at),
+            disc.last_verified_display()
+        );
     }
 
-    /// Clean up a paused burn session
-    fn cleanup_burn_session(&self, session_id: &str) -> Result<()> {
-        info!("Cleaning up burn session: {}", session_id);
-        database::BurnSessionOps::delete_session(&self.db_conn, session_id)?;
-        Ok(())
+    if let Some(path) = csv_path {
+        verify::write_scrub_csv(&discs, &path)?;
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// `bdarchive verify-db <mountpoint> [--disc <id>]`
+///
+/// Verifies a mounted disc against the checksums in the catalog instead of
+/// its SHA256SUMS.txt, and records the run.
+fn run_verify_db_command(args: &[String]) -> Result<()> {
+    const USAGE: &str = "Usage: bdarchive verify-db <mountpoint> [--disc <id>]";
+    let mut mountpoint = None;
+    let mut disc_id = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--disc" | "-d" => disc_id = Some(iter.next().context("--disc requires a disc ID")?.clone()),
+            other if other.starts_with('-') => anyhow::bail!("Unknown argument: {}\n{}", other, USAGE),
+            other if mountpoint.is_none() => mountpoint = Some(PathBuf::from(other)),
+            other => anyhow::bail!("Unexpected argument: {}\n{}", other, USAGE),
+        }
+    }
+    let mountpoint = mountpoint.context(USAGE)?;
+    let disc_id = match disc_id {
+        Some(disc_id) => disc_id,
+        None => verify::read_disc_id(&mountpoint)
+            .context("Cannot read the disc ID from the disc; pass it with --disc")?,
+    };
+
+    let config = Config::load()?;
+    let conn = database::init_database(&config.database_path()?)?;
+    let result = verify::verify_against_db(&conn, &disc_id, &mountpoint)?;
+    database::VerificationRun::insert(
+        &conn,
+        &database::VerificationRun {
+            id: None,
+            disc_id: disc_id.clone(),
+            verified_at: format_timestamp_now(),
+            mountpoint: Some(mountpoint.to_string_lossy().to_string()),
+            device: None,
+            success: result.success,
+            error_message: result.error_message.clone(),
+            files_checked: Some(result.files_checked),
+            files_failed: Some(result.files_failed),
+            sampled: false,
+        },
+    )?;
+
+    println!(
+        "{}: {} files checked against the catalog, {} failed",
+        disc_id, result.files_checked, result.files_failed
+    );
+    for warning in &result.warnings {
+        println!("⚠️ {}", warning);
+    }
+    if let Some(message) = &result.error_message {
+        println!("{}", message);
+    }
+    if !result.success {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// `bdarchive verify-sample <mountpoint> [--sample <N|P%>]`
+///
+/// Hashes a random sample of a mounted disc's files and records it as a sample check.
+fn run_verify_sample_command(args: &[String]) -> Result<()> {
+    const USAGE: &str = "Usage: bdarchive verify-sample <mountpoint> [--sample <N|P%>]";
+    let mut mountpoint = None;
+    let mut sample_size = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--sample" | "-s" => sample_size = Some(iter.next().context("--sample requires a count or percentage")?.clone()),
+            other if other.starts_with('-') => anyhow::bail!("Unknown argument: {}\n{}", other, USAGE),
+            other if mountpoint.is_none() => mountpoint = Some(PathBuf::from(other)),
+            other => anyhow::bail!("Unexpected argument: {}\n{}", other, USAGE),
+        }
+    }
+    let mountpoint = mountpoint.context(USAGE)?;
+
+    let config = Config::load()?;
+    let sample_size = sample_size.unwrap_or_else(|| config.verification.sample_size.clone());
+    let sample = verify::verify_sample(&mountpoint, sample_size.parse()?)?;
+    let disc_id = verify::read_disc_id(&mountpoint).unwrap_or_else(|| "UNKNOWN".to_string());
+
+    let conn = database::init_database(&config.database_path()?)?;
+    database::VerificationRun::insert(
+        &conn,
+        &database::VerificationRun {
+            id: None,
+            disc_id: disc_id.clone(),
+            verified_at: format_timestamp_now(),
+            mountpoint: Some(mountpoint.to_string_lossy().to_string()),
+            device: None,
+            success: sample.result.success,
+            error_message: sample.result.error_message.clone(),
+            files_checked: Some(sample.result.files_checked),
+            files_failed: Some(sample.result.files_failed),
+            sampled: true,
+        },
+    )?;
+
+    println!("{}: {}", disc_id, sample.summary());
+    if let Some(message) = &sample.result.error_message {
+        println!("{}", message);
+    }
+    if !sample.result.success {
+        std::process::exit(1);
     }
+    Ok(())
+}
+
+/// `bdarchive shelf <location>`
+///
+/// Lists the discs stored at a location, e.g. to pull them for a restore.
+fn run_shelf_command(args: &[String]) -> Result<()> {
+    const USAGE: &str = "Usage: bdarchive shelf <location>";
+    let location = args.join(" ");
+    if location.trim().is_empty() {
+        anyhow::bail!(USAGE);
+    }
+
+    let config = Config::load()?;
+    let conn = database::init_database(&config.database_path()?)?;
+    let discs = database::discs_by_location(&conn, &location)?;
+
+    println!("Discs at \"{}\": {}", location.trim(), discs.len());
+    for disc in &discs {
+        println!(
+            "  {:<20} created {}  {}",
+            disc.disc_id,
+            disc.created_at,
+            disc.notes.as_deref().unwrap_or("")
+        );
+    }
+
+    Ok(())
+}
+
+/// `bdarchive set-export <set_id> <file.json>`
+///
+/// Writes a disc set and its file records to a JSON sidecar.
+fn run_set_export_command(args: &[String]) -> Result<()> {
+    const USAGE: &str = "Usage: bdarchive set-export <set_id> <file.json>";
+    let [set_id, out] = args else {
+        anyhow::bail!(USAGE);
+    };
+
+    let config = Config::load()?;
+    let conn = database::init_database(&config.database_path()?)?;
+    let summary = database::export_set_json(&conn, set_id, Path::new(out))?;
+    println!("Exported set {}: {} discs, {} files -> {}", summary.set_id, summary.discs, summary.files, out);
+
+    Ok(())
+}
+
+/// `bdarchive set-import <file.json>`
+///
+/// Loads a sidecar written by `set-export`; importing twice is harmless.
+fn run_set_import_command(args: &[String]) -> Result<()> {
+    const USAGE: &str = "Usage: bdarchive set-import <file.json>";
+    let [path] = args else {
+        anyhow::bail!(USAGE);
+    };
+
+    let config = Config::load()?;
+    let mut conn = database::init_database(&config.database_path()?)?;
+    let summary = database::import_set_json(&mut conn, Path::new(path))?;
+    println!("Imported set {}: {} discs, {} files", summary.set_id, summary.discs, summary.files);
+
+    Ok(())
+}
+
+/// Progress of the command-line archive commands: the status lines as text,
+/// or with `--progress-json` every event as one JSON object per line.
+fn cli_progress(json: bool) -> archive::ProgressCallback {
+    std::sync::Arc::new(move |event| {
+        if json {
+            println!("{}", archive::ProgressRecord::from(&event).to_json_line());
+            return;
+        }
+        match event {
+            archive::ArchiveEvent::Stage(_, msg) | archive::ArchiveEvent::Status(msg) | archive::ArchiveEvent::Progress(msg) => {
+                println!("{}", msg)
+            }
+            _ => {}
+        }
+    })
+}
+
+/// Print the outcome of a command-line archive run: a `complete` or `error`
+/// record with `--progress-json`, otherwise the summary line (errors are left
+/// to the caller).
+fn report_cli_outcome<T, E: std::fmt::Display>(
+    json: bool,
+    result: &std::result::Result<T, E>,
+    complete: impl FnOnce(&T) -> archive::ProgressRecord,
+) {
+    match result {
+        Ok(outcome) => {
+            let record = complete(outcome);
+            if json {
+                println!("{}", record.to_json_line());
+            } else if let Some(message) = record.message {
+                println!("{}", message);
+            }
+        }
+        Err(e) if json => println!("{}", archive::ProgressRecord::new("error").with_message(e.to_string()).to_json_line()),
+        Err(_) => {}
+    }
+}
+
+/// `bdarchive set-append <set_id> <folder>... [--notes <text>] [--dry-run] [--progress-json]`
+///
+/// Burns new source folders as further discs of a finished set.
+fn run_set_append_command(args: &[String]) -> Result<()> {
+    const USAGE: &str = "Usage: bdarchive set-append <set_id> <folder>... [--notes <text>] [--dry-run] [--progress-json]";
+    let mut set_id = None;
+    let mut source_folders = Vec::new();
+    let mut notes = String::new();
+    let mut dry_run = false;
+    let mut json = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--notes" => notes = iter.next().context("--notes requires a text")?.clone(),
+            "--dry-run" => dry_run = true,
+            "--progress-json" => json = true,
+            other if other.starts_with('-') => anyhow::bail!("Unknown argument: {}\n{}", other, USAGE),
+            other if set_id.is_none() => set_id = Some(other.to_string()),
+            other => source_folders.push(paths::expand_tilde(other)),
+        }
+    }
+    let set_id = set_id.context(USAGE)?;
+    if source_folders.is_empty() {
+        anyhow::bail!(USAGE);
+    }
+
+    let config = Config::load()?;
+    let mut conn = database::init_database(&config.database_path()?)?;
+    let mut request = archive::ArchiveRequest::new(String::new(), source_folders, config);
+    request.notes = notes;
+    request.dry_run = dry_run;
+    // A wrapping UI answers the prompt the same way: a line on stdin, "q" cancels
+    let insert_disc = |sequence: usize, total: usize, device: &str| {
+        let message = format!("Insert a blank disc for disc {} of {} into {} and press Enter (q to cancel)", sequence, total, device);
+        if json {
+            let record = archive::ProgressRecord {
+                disc_current: Some(sequence),
+                disc_total: Some(total),
+                ..archive::ProgressRecord::new("insert_disc").with_message(message)
+            };
+            println!("{}", record.to_json_line());
+        } else {
+            println!("{}", message);
+        }
+        let mut line = String::new();
+        io::stdin().read_line(&mut line).is_ok() && !line.trim().eq_ignore_ascii_case("q")
+    };
+    let result = archive::append_to_disc_set(&mut conn, &set_id, &request, Some(cli_progress(json)), insert_disc);
+
+    report_cli_outcome(json, &result, |outcome| {
+        let disc_ids: Vec<&str> = outcome.discs.iter().map(|d| d.disc_id.as_str()).collect();
+        archive::ProgressRecord {
+            disc_total: Some(outcome.discs.len()),
+            bytes_total: Some(outcome.total_size),
+            ..archive::ProgressRecord::new("complete").with_message(format!(
+                "Added {} to set {} ({})",
+                disc_ids.join(", "),
+                outcome.set_id,
+                search::format_bytes_gb(outcome.total_size)
+            ))
+        }
+    });
+    result?;
+    Ok(())
+}
+
+/// `bdarchive burn-iso <iso> [--disc-id <ID>] [--dry-run] [--progress-json]`
+///
+/// Burns a pre-built ISO and records it in the catalog.
+fn run_burn_iso_command(args: &[String]) -> Result<()> {
+    const USAGE: &str = "Usage: bdarchive burn-iso <iso> [--disc-id <ID>] [--dry-run] [--progress-json]";
+    let mut iso_path = None;
+    let mut disc_id = None;
+    let mut dry_run = false;
+    let mut json = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--disc-id" => disc_id = Some(iter.next().context("--disc-id requires an ID")?.clone()),
+            "--dry-run" => dry_run = true,
+            "--progress-json" => json = true,
+            other if other.starts_with('-') => anyhow::bail!("Unknown argument: {}\n{}", other, USAGE),
+            other if iso_path.is_none() => iso_path = Some(PathBuf::from(other)),
+            other => anyhow::bail!("Unexpected argument: {}\n{}", other, USAGE),
+        }
+    }
+    let iso_path = iso_path.context(USAGE)?;
+
+    let config = Config::load()?;
+    let mut conn = database::init_database(&config.database_path()?)?;
+    let result = archive::burn_existing_iso(&mut conn, &config, &iso_path, disc_id.as_deref(), dry_run, Some(cli_progress(json)));
+
+    report_cli_outcome(json, &result, |outcome| archive::ProgressRecord {
+        bytes_total: Some(outcome.size_bytes),
+        ..archive::ProgressRecord::new("complete").with_message(format!(
+            "Recorded {} ({}, {} files)",
+            outcome.disc_id,
+            search::format_bytes_gb(outcome.size_bytes),
+            outcome.file_count
+        ))
+    });
+    result?;
+    Ok(())
 }
 
 fn main() -> Result<()> {
-    // Initialize logging
-    logging::init_logging().context("Failed to initialize logging")?;
+    // Initialize logging. Config errors are reported again once the app loads it.
+    let logging_config = Config::load().map(|c| c.logging).unwrap_or_default();
+    logging::init_logging(&logging_config).context("Failed to initialize logging")?;
+
+    // Non-interactive subcommands
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("plan") => return run_plan_command(&args[1..]),
+        Some("scrub") => return run_scrub_command(&args[1..]),
+        Some("verify-sample") => return run_verify_sample_command(&args[1..]),
+        Some("verify-db") => return run_verify_db_command(&args[1..]),
+        Some("shelf") => return run_shelf_command(&args[1..]),
+        Some("burn-iso") => return run_burn_iso_command(&args[1..]),
+        Some("set-export") => return run_set_export_command(&args[1..]),
+        Some("set-import") => return run_set_import_command(&args[1..]),
+        Some("set-append") => return run_set_append_command(&args[1..]),
+        _ => {}
+    }
+
+    // `--staging <dir>` stages new discs of this session somewhere else
+    let staging_override = match args.iter().position(|arg| arg == "--staging") {
+        Some(index) => {
+            let dir = args
+                .get(index + 1)
+                .context("--staging requires a directory\nUsage: bdarchive [--staging <dir>]")?;
+            let dir = paths::expand_tilde(dir);
+            paths::validate_dir(&dir).context("Invalid --staging directory")?;
+            args.drain(index..=index + 1);
+            Some(dir)
+        }
+        None => None,
+    };
 
     info!("Starting BlueVault application");
 
@@ -3171,6 +3389,7 @@ fn main() -> Result<()> {
 
     // Initialize database
     let db_path = config.database_path()?;
+    let stored_schema_version = database::stored_schema_version(&db_path);
     let db_conn = database::init_database(&db_path)?;
 
     // Setup terminal
@@ -3180,7 +3399,14 @@ fn main() -> Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new(config, db_conn);
+    // A second Ctrl-C can arrive while the UI thread is busy, so the handler quits on its own
+    let interrupt_db_path = db_path.clone();
+    if let Err(e) = interrupt::install_handler(move || force_quit(&interrupt_db_path)) {
+        warn!("{:#}", e);
+    }
+
+    let mut app = App::new(config, db_conn, stored_schema_version);
+    app.staging_override = staging_override;
     let mut running = true;
 
     while running {
@@ -3203,6 +3429,10 @@ fn main() -> Result<()> {
             }
         }
 
+        app.refresh_dashboard_if_stale();
+        if let AppState::Logs(ref mut logs) = app.state {
+            logs.refresh_if_stale(std::time::Duration::from_millis(500));
+        }
         terminal.draw(|f| app.render(f))?;
         info!("=== terminal.draw() completed ===");
 
@@ -3229,27 +3459,37 @@ fn main() -> Result<()> {
             Some(std::time::Duration::from_millis(100))
         } else if has_background_task {
             Some(std::time::Duration::from_millis(50)) // Poll frequently for background updates
+        } else if matches!(app.state, AppState::Logs(_)) {
+            Some(std::time::Duration::from_millis(500)) // Wake up to tail the log file
         } else {
-            None
+            Some(std::time::Duration::from_millis(250)) // Wake up to notice a SIGINT
         };
 
         let mut event_processed = false;
         if poll(timeout.unwrap_or(std::time::Duration::from_secs(0)))? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
-                    running = app.handle_key(key.code)?;
-                    event_processed = true;
-                }
-            }
-        } else if timeout.is_none() {
-            // Blocking wait if no timeout
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    running = app.handle_key(key.code)?;
+                    // Raw mode delivers Ctrl-C as a key rather than a signal
+                    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                        if interrupt::request() == interrupt::Interrupt::Force {
+                            force_quit(&db_path);
+                        }
+                        running = app.handle_interrupt();
+                    } else {
+                        running = app.handle_key(key.code)?;
+                    }
                     event_processed = true;
                 }
             }
         }
+        if interrupt::take_signal() {
+            running = app.handle_interrupt();
+            event_processed = true;
+        }
+        // An interrupted run has stopped at its safe point
+        if interrupt::is_requested() && app.disc_creation_rx.is_none() {
+            running = false;
+        }
 
         // Redraw if background messages were processed or events occurred
         if background_updated || event_processed || has_background_task {
@@ -3266,6 +3506,32 @@ fn main() -> Result<()> {
     )?;
     terminal.show_cursor()?;
 
+    if interrupt::is_requested() {
+        eprintln!("Interrupted. Unfinished multi-disc sets are paused; continue them from Resume Burn.");
+    }
     info!("Application exiting");
     Ok(())
 }
+
+/// Second Ctrl-C: restore the terminal, mark running burn sessions as paused
+/// so they can be resumed, and exit. A disc being burned right now is likely unusable.
+fn force_quit(db_path: &Path) -> ! {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, crossterm::cursor::Show);
+
+    match database::init_database(db_path) {
+        Ok(conn) => {
+            if let Err(e) = database::BurnSessionOps::pause_active_sessions(&conn) {
+                warn!("Failed to pause burn sessions: {}", e);
+            }
+        }
+        Err(e) => warn!("Could not open the database to pause burn sessions: {}", e),
+    }
+
+    warn!("Force quit after a second interrupt");
+    eprintln!(
+        "Force quit. Any disc being burned is likely unusable; \
+         multi-disc sets are paused and can be continued from Resume Burn."
+    );
+    std::process::exit(130);
+}
@@ -1,8 +1,13 @@
+use crate::config::ChecksumAlgorithm;
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::fs;
 use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use tracing::{debug, info};
 
 // Fast CRC32 for initial manifest generation
@@ -10,32 +15,184 @@ use crc32fast::Hasher;
 use rayon::prelude::*;
 
 /// File metadata for a file in the archive.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FileMetadata {
+    #[serde(with = "lossy_path")]
     pub rel_path: PathBuf,
     pub size: u64,
     pub mtime: String, // ISO 8601 format
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub sha256: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub crc32: Option<String>, // Fast checksum for initial manifest
+    /// Target of a preserved symlink; links have no checksum
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "lossy_path::option")]
+    pub link_target: Option<PathBuf>,
+    /// Stored copy this file was deduplicated against; the path itself is not on the disc
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "lossy_path::option")]
+    pub duplicate_of: Option<PathBuf>,
 }
 
-/// Generate manifest and checksums for a directory (fast mode uses CRC32).
+/// File name of the structured manifest in the disc root.
+pub const MANIFEST_JSON_FILE: &str = "manifest.json";
+
+/// Checksum list written in place of SHA256SUMS.txt when a disc is burned
+/// with CRC32 checksums, which have no SHA-256 to list.
+pub const CRC32SUMS_FILE: &str = "CRC32SUMS.txt";
+
+/// Version of the `manifest.json` layout, bumped on incompatible changes.
+pub const MANIFEST_JSON_VERSION: u32 = 1;
+
+/// Structured disc manifest written as `manifest.json` next to MANIFEST.txt
+/// and SHA256SUMS.txt, with the same file entries plus disc metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscManifest {
+    pub format_version: u32,
+    pub disc: DiscManifestInfo,
+    pub files: Vec<FileMetadata>,
+}
+
+/// Disc metadata recorded in `manifest.json` (mirrors DISC_INFO.txt).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscManifestInfo {
+    pub disc_id: String,
+    pub volume_label: String,
+    pub created_at: String,
+    pub tool_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub source_roots: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence_number: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_discs: Option<u32>,
+}
+
+impl DiscManifest {
+    pub fn new(disc: DiscManifestInfo, files: Vec<FileMetadata>) -> Self {
+        Self {
+            format_version: MANIFEST_JSON_VERSION,
+            disc,
+            files,
+        }
+    }
+}
+
+/// Paths are written as (lossily converted) strings so non-UTF-8 names
+/// cannot fail the whole manifest.
+mod lossy_path {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::path::{Path, PathBuf};
+
+    pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&path.to_string_lossy())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PathBuf, D::Error> {
+        String::deserialize(deserializer).map(PathBuf::from)
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(path: &Option<PathBuf>, serializer: S) -> Result<S::Ok, S::Error> {
+            match path {
+                Some(path) => super::serialize(path, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<PathBuf>, D::Error> {
+            Option::<String>::deserialize(deserializer).map(|path| path.map(PathBuf::from))
+        }
+    }
+}
+
+/// How often checksum progress is reported
+const HASH_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How far checksum generation has got.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HashProgress {
+    pub files_done: u64,
+    pub files_total: u64,
+    /// Bytes read so far, counted as they are read rather than per file
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+    /// File most recently started, relative to the manifest base
+    pub current_path: PathBuf,
+    pub elapsed: Duration,
+}
+
+impl HashProgress {
+    /// Share of the bytes hashed, 0.0-1.0
+    pub fn fraction(&self) -> f64 {
+        if self.bytes_total == 0 {
+            return if self.files_done >= self.files_total { 1.0 } else { 0.0 };
+        }
+        (self.bytes_done as f64 / self.bytes_total as f64).clamp(0.0, 1.0)
+    }
+
+    /// Average throughput so far, in MB/s
+    pub fn mb_per_sec(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs <= 0.0 {
+            return 0.0;
+        }
+        self.bytes_done as f64 / (1024.0 * 1024.0) / secs
+    }
+}
+
+/// Receives [`HashProgress`] from the reporting thread a few times a second.
+pub type HashProgressCallback = Box<dyn Fn(&HashProgress) + Send + Sync>;
+
+/// Counters the hashing threads update and the reporting thread reads.
+#[derive(Default)]
+struct HashCounters {
+    files_done: AtomicU64,
+    bytes_done: AtomicU64,
+    current_path: Mutex<PathBuf>,
+}
+
+impl HashCounters {
+    fn snapshot(&self, files_total: u64, bytes_total: u64, started: Instant) -> HashProgress {
+        HashProgress {
+            files_done: self.files_done.load(Ordering::Relaxed),
+            files_total,
+            bytes_done: self.bytes_done.load(Ordering::Relaxed).min(bytes_total),
+            bytes_total,
+            current_path: self.current_path.lock().map(|path| path.clone()).unwrap_or_default(),
+            elapsed: started.elapsed(),
+        }
+    }
+}
+
+/// Generate manifest and SHA-256 checksums for a directory.
 pub fn generate_manifest_and_sums(
     root_dir: &Path,
     base_path: Option<&Path>,
 ) -> Result<Vec<FileMetadata>> {
-    generate_manifest_and_sums_with_progress(root_dir, base_path, None, false)
+    let max_retries = crate::config::StagingConfig::default().max_retries;
+    generate_manifest_and_sums_with_progress(root_dir, base_path, None, ChecksumAlgorithm::Sha256, max_retries)
 }
 
 /// Generate manifest and checksums for a directory with progress callback.
-/// If fast_mode=true, uses CRC32 instead of SHA256 for much faster processing.
+/// CRC32 fills `crc32` and is much faster; SHA-256 fills `sha256`.
+/// Reads failing with transient I/O errors are retried up to `max_retries` times.
+/// Files are hashed in parallel while a separate thread reports [`HashProgress`]
+/// every [`HASH_PROGRESS_INTERVAL`], then once more when all files are done.
 pub fn generate_manifest_and_sums_with_progress(
     root_dir: &Path,
     base_path: Option<&Path>,
-    mut progress_callback: Option<Box<dyn FnMut(&str) + Send>>,
-    fast_mode: bool,
+    progress: Option<HashProgressCallback>,
+    algorithm: ChecksumAlgorithm,
+    max_retries: u32,
 ) -> Result<Vec<FileMetadata>> {
     let base = base_path.unwrap_or(root_dir);
+    let fast_mode = algorithm == ChecksumAlgorithm::Crc32;
 
     info!(
         "Generating manifest for directory: {} (fast_mode: {}, parallel: {})",
@@ -48,43 +205,54 @@ pub fn generate_manifest_and_sums_with_progress(
     let mut file_paths = Vec::new();
     collect_file_paths(root_dir, &mut file_paths)?;
 
-    info!("Found {} files to process", file_paths.len());
-
-    if let Some(ref mut callback) = progress_callback {
-        let checksum_type = if fast_mode { "CRC32" } else { "SHA256" };
-        callback(&format!("📊 Processing {} files with {} checksums", file_paths.len(), checksum_type));
-    }
+    // Symlinks are recorded without reading them, so only regular files count
+    let files_total = file_paths.len() as u64;
+    let bytes_total: u64 = file_paths
+        .iter()
+        .filter_map(|path| fs::symlink_metadata(path).ok())
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+        .sum();
+    info!("Found {} files to process", files_total);
 
     // Second pass: process files in parallel
-    let files: Vec<FileMetadata> = file_paths
-        .into_par_iter()
-        .map(|file_path| {
-            generate_file_metadata_parallel(&file_path, base, fast_mode)
-        })
-        .collect::<Result<Vec<_>>>()?;
-
-    // Send progress updates for each file (not thread-safe, so do it sequentially)
-    if let Some(ref mut callback) = progress_callback {
-        for (i, file) in files.iter().enumerate() {
-            let checksum_type = if fast_mode { "CRC32" } else { "SHA256" };
-            let _progress_pct = ((i + 1) as f64 / files.len() as f64 * 100.0) as u32;
-
-            // Show progress every 10 files or for large files
-            if i % 10 == 0 || file.size > 100 * 1024 * 1024 {
-                let size_mb = file.size / (1024 * 1024);
-                callback(&format!("🔐 {} {}/{} ({}MB): {}",
-                                 checksum_type, i + 1, files.len(), size_mb,
-                                 file.rel_path.display()));
-            }
+    let counters = HashCounters::default();
+    let started = Instant::now();
+    let finished = AtomicBool::new(false);
+    let files = std::thread::scope(|scope| {
+        if let Some(callback) = progress.as_ref() {
+            scope.spawn(|| {
+                while !finished.load(Ordering::Relaxed) {
+                    callback(&counters.snapshot(files_total, bytes_total, started));
+                    std::thread::sleep(HASH_PROGRESS_INTERVAL);
+                }
+            });
         }
-        callback(&format!("✅ Checksum generation complete: {} files processed", files.len()));
+        let files = file_paths
+            .into_par_iter()
+            .map(|file_path| {
+                if let Ok(mut current) = counters.current_path.lock() {
+                    *current = file_path.strip_prefix(base).unwrap_or(&file_path).to_path_buf();
+                }
+                let metadata =
+                    generate_file_metadata_parallel(&file_path, base, fast_mode, max_retries, Some(&counters.bytes_done))?;
+                counters.files_done.fetch_add(1, Ordering::Relaxed);
+                Ok(metadata)
+            })
+            .collect::<Result<Vec<_>>>();
+        finished.store(true, Ordering::Relaxed);
+        files
+    })?;
+
+    if let Some(callback) = progress.as_ref() {
+        callback(&counters.snapshot(files_total, bytes_total, started));
     }
-
     info!("Generated manifest with {} files", files.len());
     Ok(files)
 }
 
-/// Collect all file paths recursively (fast synchronous operation)
+/// Collect all file paths recursively (fast synchronous operation).
+/// Symlinks are collected as entries of their own and never followed.
 fn collect_file_paths(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
     let entries = fs::read_dir(dir)
         .with_context(|| format!("Failed to read directory: {}", dir.display()))?;
@@ -92,10 +260,11 @@ fn collect_file_paths(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
     for entry in entries {
         let entry = entry.context("Failed to read directory entry")?;
         let path = entry.path();
+        let file_type = entry.file_type().context("Failed to read file type")?;
 
-        if path.is_dir() {
+        if file_type.is_dir() {
             collect_file_paths(&path, files)?;
-        } else if path.is_file() {
+        } else if file_type.is_file() || file_type.is_symlink() {
             files.push(path);
         }
     }
@@ -108,13 +277,30 @@ fn generate_file_metadata_parallel(
     file_path: &Path,
     base: &Path,
     fast_mode: bool,
+    max_retries: u32,
+    bytes_read: Option<&AtomicU64>,
 ) -> Result<FileMetadata> {
     debug!("Processing file: {} (fast_mode: {})", file_path.display(), fast_mode);
-    let rel_path = crate::paths::make_relative(file_path, base)?;
+    let rel_path = crate::paths::normalize_rel_path(&crate::paths::make_relative(file_path, base)?)?;
 
-    let metadata = fs::metadata(file_path)
+    let metadata = fs::symlink_metadata(file_path)
         .with_context(|| format!("Failed to read file metadata: {}", file_path.display()))?;
 
+    if metadata.file_type().is_symlink() {
+        let target = fs::read_link(file_path)
+            .with_context(|| format!("Failed to read symlink: {}", file_path.display()))?;
+        let mtime = metadata.modified().context("Failed to get modification time")?;
+        return Ok(FileMetadata {
+            rel_path,
+            size: 0,
+            mtime: format_timestamp(mtime),
+            sha256: String::new(),
+            crc32: None,
+            link_target: Some(target),
+            duplicate_of: None,
+        });
+    }
+
     let size = metadata.len();
     let mtime = metadata
         .modified()
@@ -122,13 +308,22 @@ fn generate_file_metadata_parallel(
 
     let mtime_str = format_timestamp(mtime);
 
+    let what = format!("Reading {}", file_path.display());
     let (sha256, crc32) = if fast_mode {
         // Fast mode: use CRC32
-        let crc = calculate_crc32(file_path)?;
+        let crc = crate::staging::with_retries(max_retries, &what, || {
+            let mut hasher = Hasher::new();
+            read_counting(file_path, bytes_read, |chunk| hasher.update(chunk))?;
+            Ok(format!("{:08x}", hasher.finalize()))
+        })?;
         (String::new(), Some(crc))
     } else {
         // Full mode: calculate SHA256
-        let sha = calculate_sha256(file_path)?;
+        let sha = crate::staging::with_retries(max_retries, &what, || {
+            let mut hasher = Sha256::new();
+            read_counting(file_path, bytes_read, |chunk| hasher.update(chunk))?;
+            Ok(hex::encode(hasher.finalize()))
+        })?;
         (sha, None)
     };
 
@@ -138,9 +333,40 @@ fn generate_file_metadata_parallel(
         mtime: mtime_str,
         sha256,
         crc32,
+        link_target: None,
+        duplicate_of: None,
     })
 }
 
+/// Feed `file_path` to `update` chunk by chunk, adding each chunk's length to
+/// `bytes_read`. A failed read takes its bytes back off, so a retry counts the
+/// file only once.
+fn read_counting(file_path: &Path, bytes_read: Option<&AtomicU64>, mut update: impl FnMut(&[u8])) -> Result<()> {
+    let mut counted = 0u64;
+    let result = (|| -> Result<()> {
+        let mut file = fs::File::open(file_path)
+            .with_context(|| format!("Failed to open file: {}", file_path.display()))?;
+        let mut buffer = vec![0u8; 256 * 1024];
+        loop {
+            let n = file.read(&mut buffer)?;
+            if n == 0 {
+                return Ok(());
+            }
+            update(&buffer[..n]);
+            counted += n as u64;
+            if let Some(counter) = bytes_read {
+                counter.fetch_add(n as u64, Ordering::Relaxed);
+            }
+        }
+    })();
+    if result.is_err() {
+        if let Some(counter) = bytes_read {
+            counter.fetch_sub(counted, Ordering::Relaxed);
+        }
+    }
+    result
+}
+
 /// Recursively walk directory and collect file metadata.
 #[allow(dead_code)]
 #[allow(dead_code)]
@@ -149,7 +375,7 @@ fn walk_directory(dir: &Path, base: &Path, files: &mut Vec<FileMetadata>) -> Res
     collect_file_paths(dir, &mut file_paths)?;
 
     for file_path in file_paths {
-        let metadata = generate_file_metadata_parallel(&file_path, base, false)?;
+        let metadata = generate_file_metadata_parallel(&file_path, base, false, 0, None)?;
         files.push(metadata);
     }
 
@@ -172,18 +398,32 @@ fn generate_file_metadata_with_progress(
     _progress_callback: &mut Option<Box<dyn FnMut(&str) + Send>>,
     fast_mode: bool,
 ) -> Result<FileMetadata> {
-    generate_file_metadata_parallel(file_path, base, fast_mode)
+    generate_file_metadata_parallel(file_path, base, fast_mode, 0, None)
 }
 
 /// Calculate SHA256 hash of a file.
 #[allow(dead_code)]
-fn calculate_sha256(file_path: &Path) -> Result<String> {
+pub fn calculate_sha256(file_path: &Path) -> Result<String> {
     let mut callback: Option<Box<dyn FnMut(&str) + Send>> = None;
     calculate_sha256_with_progress(file_path, &mut callback)
 }
 
+/// Calculate the hash of a written checksum list (SHA256SUMS.txt or CRC32SUMS.txt).
+/// This identifies the disc's contents as a whole and is stored with the disc record.
+pub fn calculate_manifest_hash(sums_path: &Path) -> Result<String> {
+    calculate_sha256(sums_path)
+}
+
+/// Calculate the `algorithm` checksum of a file.
+pub fn calculate_checksum(file_path: &Path, algorithm: ChecksumAlgorithm) -> Result<String> {
+    match algorithm {
+        ChecksumAlgorithm::Crc32 => calculate_crc32(file_path),
+        ChecksumAlgorithm::Sha256 => calculate_sha256(file_path),
+    }
+}
+
 /// Calculate CRC32 hash of a file (fast alternative to SHA256).
-fn calculate_crc32(file_path: &Path) -> Result<String> {
+pub fn calculate_crc32(file_path: &Path) -> Result<String> {
     debug!("Calculating CRC32 for: {}", file_path.display());
 
     let mut file = fs::File::open(file_path)
@@ -277,12 +517,21 @@ fn format_timestamp_simple(secs: u64) -> String {
     )
 }
 
-/// Write manifest file (one path per line).
+/// Write manifest file (one path per line, symlinks as `path -> target` and
+/// deduplicated files as `path => stored copy`).
 pub fn write_manifest_file(manifest_path: &Path, files: &[FileMetadata]) -> Result<()> {
     let mut manifest = String::new();
     for file in files {
         let path_str = file.rel_path.to_string_lossy();
         manifest.push_str(&path_str);
+        if let Some(target) = &file.link_target {
+            manifest.push_str(" -> ");
+            manifest.push_str(&target.to_string_lossy());
+        }
+        if let Some(original) = &file.duplicate_of {
+            manifest.push_str(" => ");
+            manifest.push_str(&original.to_string_lossy());
+        }
         manifest.push('\n');
     }
 
@@ -297,10 +546,11 @@ pub fn write_manifest_file(manifest_path: &Path, files: &[FileMetadata]) -> Resu
     Ok(())
 }
 
-/// Write SHA256SUMS file (sha256sum format).
+/// Write SHA256SUMS file (sha256sum format). Symlinks and deduplicated copies
+/// are not on the disc as files, so they are listed only in the manifest.
 pub fn write_sha256sums_file(sums_path: &Path, files: &[FileMetadata]) -> Result<()> {
     let mut sums = String::new();
-    for file in files {
+    for file in files.iter().filter(|f| f.link_target.is_none() && f.duplicate_of.is_none()) {
         let path_str = file.rel_path.to_string_lossy();
         sums.push_str(&format!("{}  {}\n", file.sha256, path_str));
     }
@@ -316,9 +566,95 @@ pub fn write_sha256sums_file(sums_path: &Path, files: &[FileMetadata]) -> Result
     Ok(())
 }
 
-/// Calculate total size of all files.
+/// Write CRC32SUMS.txt, in the same `<checksum>  <path>` format as SHA256SUMS.txt.
+pub fn write_crc32sums_file(sums_path: &Path, files: &[FileMetadata]) -> Result<()> {
+    let mut sums = String::new();
+    for file in files.iter().filter(|f| f.link_target.is_none() && f.duplicate_of.is_none()) {
+        let crc32 = file.crc32.as_deref().unwrap_or_default();
+        sums.push_str(&format!("{}  {}\n", crc32, file.rel_path.to_string_lossy()));
+    }
+
+    fs::write(sums_path, sums)
+        .with_context(|| format!("Failed to write CRC32SUMS file: {}", sums_path.display()))?;
+
+    debug!("Wrote CRC32SUMS file: {} ({} entries)", sums_path.display(), files.len());
+    Ok(())
+}
+
+/// Write the checksum list for `algorithm` to `disc_root`: SHA256SUMS.txt, or
+/// CRC32SUMS.txt for CRC32 checksums. Returns the path written.
+pub fn write_checksum_list(disc_root: &Path, files: &[FileMetadata], algorithm: ChecksumAlgorithm) -> Result<PathBuf> {
+    match algorithm {
+        ChecksumAlgorithm::Sha256 => {
+            let sums_path = disc_root.join("SHA256SUMS.txt");
+            write_sha256sums_file(&sums_path, files)?;
+            Ok(sums_path)
+        }
+        ChecksumAlgorithm::Crc32 => {
+            let sums_path = disc_root.join(CRC32SUMS_FILE);
+            write_crc32sums_file(&sums_path, files)?;
+            Ok(sums_path)
+        }
+    }
+}
+
+/// Write `manifest.json` (pretty-printed).
+pub fn write_manifest_json(manifest_path: &Path, manifest: &DiscManifest) -> Result<()> {
+    let json = serde_json::to_string_pretty(manifest).context("Failed to serialize manifest.json")?;
+    fs::write(manifest_path, json)
+        .with_context(|| format!("Failed to write manifest.json: {}", manifest_path.display()))?;
+
+    debug!(
+        "Wrote manifest.json: {} ({} entries)",
+        manifest_path.display(),
+        manifest.files.len()
+    );
+    Ok(())
+}
+
+/// Read a disc's `manifest.json`.
+pub fn read_manifest_json(manifest_path: &Path) -> Result<DiscManifest> {
+    let json = fs::read_to_string(manifest_path)
+        .with_context(|| format!("Failed to read manifest.json: {}", manifest_path.display()))?;
+    let value: serde_json::Value = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to parse manifest.json: {}", manifest_path.display()))?;
+
+    // Check the version first so a newer layout gets a clear error, not a field mismatch
+    let version = value.get("format_version").and_then(|v| v.as_u64()).unwrap_or(0);
+    if version > MANIFEST_JSON_VERSION as u64 {
+        anyhow::bail!(
+            "manifest.json format version {} is newer than supported ({})",
+            version,
+            MANIFEST_JSON_VERSION
+        );
+    }
+
+    serde_json::from_value(value)
+        .with_context(|| format!("Failed to parse manifest.json: {}", manifest_path.display()))
+}
+
+/// Calculate the bytes the files take on disc (deduplicated copies are free).
 pub fn calculate_total_size(files: &[FileMetadata]) -> u64 {
-    files.iter().map(|f| f.size).sum()
+    files.iter().filter(|f| f.duplicate_of.is_none()).map(|f| f.size).sum()
+}
+
+/// Add manifest entries for files removed by deduplication. Each entry copies
+/// the size and checksums of its stored copy, so searches and verification
+/// treat it like any other file. The list is sorted by path afterwards.
+pub fn add_duplicate_entries(files: &mut Vec<FileMetadata>, duplicates: &[crate::dedup::DuplicateFile]) {
+    let entries: Vec<FileMetadata> = duplicates
+        .iter()
+        .filter_map(|duplicate| {
+            let original = files.iter().find(|f| f.rel_path == duplicate.original)?;
+            Some(FileMetadata {
+                rel_path: duplicate.path.clone(),
+                duplicate_of: Some(duplicate.original.clone()),
+                ..original.clone()
+            })
+        })
+        .collect();
+    files.extend(entries);
+    files.sort_by(|a, b| a.rel_path.cmp(&b.rel_path));
 }
 
 #[cfg(test)]
@@ -361,6 +697,62 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_manifest_paths_are_normalized() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        // Decomposed (NFD) names, as written by macOS, nested a few levels deep
+        let nested = root.join("ARCHIVE").join("Cafe\u{301}").join("2024");
+        fs::create_dir_all(&nested)?;
+        fs::write(nested.join("Cre\u{300}me bru\u{302}le\u{301}e.txt"), "dessert")?;
+        fs::write(nested.join("日本語.txt"), "kanji")?;
+
+        let mut files = generate_manifest_and_sums(root, None)?;
+        files.sort_by(|a, b| a.rel_path.cmp(&b.rel_path));
+        let paths: Vec<_> = files.iter().map(|f| f.rel_path.to_str().unwrap()).collect();
+        assert_eq!(
+            paths,
+            vec!["ARCHIVE/Caf\u{e9}/2024/Cr\u{e8}me br\u{fb}l\u{e9}e.txt", "ARCHIVE/Caf\u{e9}/2024/日本語.txt"]
+        );
+
+        let sums = root.join("SHA256SUMS.txt");
+        write_sha256sums_file(&sums, &files)?;
+        assert!(fs::read_to_string(&sums)?.contains("  ARCHIVE/Caf\u{e9}/2024/日本語.txt\n"));
+
+        // Names that are not UTF-8 are refused instead of mangled
+        use std::os::unix::ffi::OsStrExt;
+        fs::write(nested.join(std::ffi::OsStr::from_bytes(b"latin1-caf\xe9.txt")), "x")?;
+        let err = generate_manifest_and_sums(root, None).unwrap_err();
+        assert!(format!("{:#}", err).contains("not valid UTF-8"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_manifest_reports_hash_progress() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("ARCHIVE/docs"))?;
+        fs::write(root.join("ARCHIVE/docs/a.txt"), "a".repeat(1000))?;
+        fs::write(root.join("ARCHIVE/b.txt"), "b".repeat(500))?;
+
+        let reports = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let callback: HashProgressCallback = Box::new(move |progress| sink.lock().unwrap().push(progress.clone()));
+        let files = generate_manifest_and_sums_with_progress(root, None, Some(callback), ChecksumAlgorithm::Crc32, 0)?;
+        assert_eq!(files.len(), 2);
+
+        let reports = reports.lock().unwrap();
+        let last = reports.last().expect("final progress is always reported");
+        assert_eq!((last.files_done, last.files_total), (2, 2));
+        assert_eq!((last.bytes_done, last.bytes_total), (1500, 1500));
+        assert_eq!(last.fraction(), 1.0);
+        assert!(last.current_path.starts_with("ARCHIVE"));
+        assert!(reports.iter().all(|p| p.bytes_done <= p.bytes_total));
+        Ok(())
+    }
+
     #[test]
     fn test_write_manifest_file() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -373,6 +765,8 @@ mod tests {
                 mtime: "2024-01-01T00:00:00Z".to_string(),
                 sha256: "abc123".repeat(10).chars().take(64).collect(),
                 crc32: None,
+                link_target: None,
+                duplicate_of: None,
             },
             FileMetadata {
                 rel_path: PathBuf::from("subdir/file2.txt"),
@@ -380,6 +774,8 @@ mod tests {
                 mtime: "2024-01-02T00:00:00Z".to_string(),
                 sha256: "def456".repeat(10).chars().take(64).collect(),
                 crc32: None,
+                link_target: None,
+                duplicate_of: None,
             },
         ];
 
@@ -392,6 +788,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_manifest_json_round_trip() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join(MANIFEST_JSON_FILE);
+
+        let files = vec![
+            FileMetadata {
+                rel_path: PathBuf::from("ARCHIVE/photo.jpg"),
+                size: 100,
+                mtime: "2024-01-01T00:00:00Z".to_string(),
+                sha256: "ab".repeat(32),
+                crc32: Some("0badf00d".to_string()),
+                link_target: None,
+                duplicate_of: None,
+            },
+            FileMetadata {
+                rel_path: PathBuf::from("ARCHIVE/latest"),
+                size: 0,
+                mtime: "2024-01-01T00:00:00Z".to_string(),
+                sha256: String::new(),
+                crc32: None,
+                link_target: Some(PathBuf::from("photo.jpg")),
+                duplicate_of: None,
+            },
+        ];
+        let manifest = DiscManifest::new(
+            DiscManifestInfo {
+                disc_id: "2024-BD-1".to_string(),
+                volume_label: "2024_BD_1".to_string(),
+                created_at: "2024-01-02T00:00:00Z".to_string(),
+                tool_version: "1.0.0".to_string(),
+                notes: Some("Photos".to_string()),
+                source_roots: vec!["/home/me/photos".to_string()],
+                set_id: None,
+                sequence_number: None,
+                total_discs: None,
+            },
+            files,
+        );
+
+        write_manifest_json(&path, &manifest)?;
+        assert_eq!(read_manifest_json(&path)?, manifest);
+
+        let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        assert_eq!(json["format_version"], MANIFEST_JSON_VERSION);
+        assert_eq!(json["files"][0]["rel_path"], "ARCHIVE/photo.jpg");
+        assert_eq!(json["files"][1]["link_target"], "photo.jpg");
+        assert!(json["files"][1].get("sha256").is_none());
+        assert!(json["disc"].get("set_id").is_none());
+
+        // Manifests from a newer tool version are refused rather than misread
+        fs::write(&path, r#"{"format_version": 99, "disc": {}, "files": []}"#)?;
+        let err = read_manifest_json(&path).unwrap_err();
+        assert!(err.to_string().contains("newer than supported"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_sha256sums_file() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -403,6 +857,8 @@ mod tests {
             mtime: "2024-01-01T00:00:00Z".to_string(),
             sha256: "abc123".repeat(10).chars().take(64).collect(),
             crc32: None,
+            link_target: None,
+            duplicate_of: None,
         }];
 
         write_sha256sums_file(&sums_path, &files)?;
@@ -414,6 +870,69 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_checksum_list() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        fs::write(root.join("file1.txt"), "test content 1")?;
+
+        // CRC32 manifests have no SHA-256, so they get CRC32SUMS.txt instead
+        let files = generate_manifest_and_sums_with_progress(root, None, None, ChecksumAlgorithm::Crc32, 0)?;
+        let sums_path = write_checksum_list(root, &files, ChecksumAlgorithm::Crc32)?;
+        assert_eq!(sums_path, root.join(CRC32SUMS_FILE));
+        assert!(!root.join("SHA256SUMS.txt").exists());
+        let crc32 = calculate_crc32(&root.join("file1.txt"))?;
+        assert_eq!(fs::read_to_string(&sums_path)?, format!("{}  file1.txt\n", crc32));
+
+        let files = generate_manifest_and_sums_with_progress(root, None, None, ChecksumAlgorithm::Sha256, 0)?;
+        let files: Vec<FileMetadata> = files.into_iter().filter(|f| f.rel_path == Path::new("file1.txt")).collect();
+        let sums_path = write_checksum_list(root, &files, ChecksumAlgorithm::Sha256)?;
+        assert_eq!(sums_path, root.join("SHA256SUMS.txt"));
+        let sha256 = calculate_checksum(&root.join("file1.txt"), ChecksumAlgorithm::Sha256)?;
+        assert_eq!(fs::read_to_string(&sums_path)?, format!("{}  file1.txt\n", sha256));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_entries() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        let original = FileMetadata {
+            rel_path: PathBuf::from("ARCHIVE/a.jpg"),
+            size: 100,
+            mtime: "2024-01-01T00:00:00Z".to_string(),
+            sha256: "ab".repeat(32),
+            crc32: None,
+            link_target: None,
+            duplicate_of: None,
+        };
+        let mut files = vec![original.clone()];
+        add_duplicate_entries(
+            &mut files,
+            &[crate::dedup::DuplicateFile {
+                path: PathBuf::from("ARCHIVE/copy/a.jpg"),
+                original: PathBuf::from("ARCHIVE/a.jpg"),
+                size: 100,
+            }],
+        );
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[1].rel_path, PathBuf::from("ARCHIVE/copy/a.jpg"));
+        assert_eq!(files[1].sha256, original.sha256);
+        assert_eq!(files[1].duplicate_of, Some(PathBuf::from("ARCHIVE/a.jpg")));
+        assert_eq!(calculate_total_size(&files), 100);
+
+        let manifest_path = root.join("MANIFEST.txt");
+        let sums_path = root.join("SHA256SUMS.txt");
+        write_manifest_file(&manifest_path, &files)?;
+        write_sha256sums_file(&sums_path, &files)?;
+        assert!(fs::read_to_string(&manifest_path)?.contains("ARCHIVE/copy/a.jpg => ARCHIVE/a.jpg"));
+        assert!(!fs::read_to_string(&sums_path)?.contains("copy"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_calculate_total_size() {
         let files = vec![
@@ -423,6 +942,8 @@ mod tests {
                 mtime: "2024-01-01T00:00:00Z".to_string(),
                 sha256: "abc123".to_string(),
                 crc32: None,
+                link_target: None,
+                duplicate_of: None,
             },
             FileMetadata {
                 rel_path: PathBuf::from("file2.txt"),
@@ -430,9 +951,37 @@ mod tests {
                 mtime: "2024-01-02T00:00:00Z".to_string(),
                 sha256: "def456".to_string(),
                 crc32: None,
+                link_target: None,
+                duplicate_of: None,
             },
         ];
 
         assert_eq!(calculate_total_size(&files), 300);
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinks_recorded_as_links() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        fs::write(root.join("real.txt"), "content")?;
+        std::os::unix::fs::symlink("real.txt", root.join("link.txt"))?;
+
+        let files = generate_manifest_and_sums(root, None)?;
+        assert_eq!(files.len(), 2);
+        let link = files
+            .iter()
+            .find(|f| f.rel_path == Path::new("link.txt"))
+            .unwrap();
+        assert_eq!(link.link_target, Some(PathBuf::from("real.txt")));
+
+        let manifest_path = root.join("MANIFEST.txt");
+        let sums_path = root.join("SHA256SUMS.txt");
+        write_manifest_file(&manifest_path, &files)?;
+        write_sha256sums_file(&sums_path, &files)?;
+        assert!(fs::read_to_string(&manifest_path)?.contains("link.txt -> real.txt"));
+        assert!(!fs::read_to_string(&sums_path)?.contains("link.txt"));
+
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use std::os::unix::fs::FileTypeExt;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use unicode_normalization::UnicodeNormalization;
 
 /// Resolve the XDG data directory for the application.
 /// Defaults to ~/.local/share/bdarchive if XDG_DATA_HOME is not set.
@@ -221,6 +222,46 @@ pub fn expand_tilde(path: &str) -> PathBuf {
     PathBuf::from(path)
 }
 
+/// Free space in bytes on the filesystem holding `path` (from `df`).
+/// Paths that don't exist yet are measured at their nearest existing parent.
+pub fn free_space_bytes(path: &Path) -> Option<u64> {
+    let existing = path.ancestors().find(|p| p.exists())?;
+    let output = std::process::Command::new("df")
+        .arg("-Pk")
+        .arg(existing)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_df_available(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Fail when the filesystem holding `path` has less than `min_bytes` free.
+/// Passes when the free space can't be determined, so a missing `df` never blocks.
+pub fn check_min_free_space(path: &Path, min_bytes: u64) -> Result<()> {
+    let Some(free) = free_space_bytes(path) else {
+        tracing::warn!("Cannot determine free space for {}", path.display());
+        return Ok(());
+    };
+    if free < min_bytes {
+        anyhow::bail!(
+            "Only {} free on the volume holding {} (at least {} required)",
+            crate::search::format_bytes_gb(free),
+            path.display(),
+            crate::search::format_bytes_gb(min_bytes)
+        );
+    }
+    Ok(())
+}
+
+/// Available bytes from `df -Pk` output (4th column of the data line, in KiB).
+fn parse_df_available(output: &str) -> Option<u64> {
+    let line = output.lines().nth(1)?;
+    let kib: u64 = line.split_whitespace().nth(3)?.parse().ok()?;
+    Some(kib * 1024)
+}
+
 /// Make a path relative to a base directory.
 pub fn make_relative(path: &Path, base: &Path) -> Result<PathBuf> {
     path.strip_prefix(base)
@@ -234,11 +275,54 @@ pub fn make_relative(path: &Path, base: &Path) -> Result<PathBuf> {
         })
 }
 
+/// Normalize a relative path to the form stored in manifests and the catalog:
+/// UTF-8, NFC, with `/` between components. Names that are not valid UTF-8
+/// are refused rather than lossily converted, since the converted name would
+/// no longer match the file. `.` components are dropped; absolute paths and
+/// `..` are refused.
+pub fn normalize_rel_path(path: &Path) -> Result<PathBuf> {
+    let mut normalized = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => {
+                let name = name.to_str().with_context(|| {
+                    format!("File name is not valid UTF-8: {}; rename it before archiving", path.display())
+                })?;
+                normalized.push(name.nfc().collect::<String>());
+            }
+            Component::CurDir => {}
+            _ => anyhow::bail!("Not a relative path inside the archive: {}", path.display()),
+        }
+    }
+    Ok(PathBuf::from(normalized.join("/")))
+}
+
+/// NFC form of a single file name, if it is valid UTF-8.
+pub fn nfc_file_name(name: &std::ffi::OsStr) -> Option<String> {
+    name.to_str().map(|name| name.nfc().collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_normalize_rel_path() -> Result<()> {
+        // "é" written as "e" + combining acute (NFD) becomes the single code point
+        let nfd = PathBuf::from("ARCHIVE/Cafe\u{301}/Sche\u{301}ma.txt");
+        assert_eq!(normalize_rel_path(&nfd)?, PathBuf::from("ARCHIVE/Caf\u{e9}/Sch\u{e9}ma.txt"));
+        assert!(normalize_rel_path(Path::new("a/../b")).is_err());
+        assert_eq!(normalize_rel_path(Path::new("./a/b//c.txt"))?, PathBuf::from("a/b/c.txt"));
+        assert!(normalize_rel_path(Path::new("/etc/passwd")).is_err());
+
+        use std::os::unix::ffi::OsStrExt;
+        let latin1 = Path::new(std::ffi::OsStr::from_bytes(b"ARCHIVE/caf\xe9.txt"));
+        let err = normalize_rel_path(latin1).unwrap_err();
+        assert!(err.to_string().contains("not valid UTF-8"));
+        Ok(())
+    }
+
     #[test]
     fn test_expand_tilde() {
         let expanded = expand_tilde("~/test");
@@ -246,6 +330,22 @@ mod tests {
         assert!(!expanded.to_string_lossy().starts_with("~"));
     }
 
+    #[test]
+    fn test_parse_df_available() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n/dev/nvme0n1p2   490617784 200000000 265617784      43% /\n";
+        assert_eq!(parse_df_available(output), Some(265617784 * 1024));
+        assert_eq!(parse_df_available("Filesystem\n"), None);
+        assert!(free_space_bytes(Path::new("/definitely/not/here")).is_some());
+    }
+
+    #[test]
+    fn test_check_min_free_space() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert!(check_min_free_space(temp_dir.path(), 0).is_ok());
+        let err = check_min_free_space(temp_dir.path(), u64::MAX).unwrap_err();
+        assert!(err.to_string().contains("required"), "{}", err);
+    }
+
     #[test]
     fn test_make_relative() {
         let base = PathBuf::from("/base");
@@ -0,0 +1,247 @@
+use crate::dedup::DuplicateFile;
+use crate::disc::sanitize_for_filesystem;
+use crate::search::{format_bytes_gb, format_size};
+use crate::staging::{DirectoryEntry, DiscPlan, ExcludedPath};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Dry-run audit report: what would be written to each disc.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanReport {
+    pub disc_id: String,
+    pub generated_at: String,
+    pub disc_capacity_bytes: u64,
+    pub total_bytes: u64,
+    pub discs: Vec<PlannedDisc>,
+    pub split_directories: Vec<String>,
+    /// Number of files checksummed while preparing the dry run
+    pub checksums_computed: usize,
+    pub excluded: Vec<ExcludedPath>,
+}
+
+/// One disc in the report.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedDisc {
+    pub disc_number: usize,
+    pub used_bytes: u64,
+    pub utilization_percent: f64,
+    pub files: Vec<PlannedFile>,
+    /// Files stored once because an identical file is already on this disc
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<DuplicateFile>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedFile {
+    pub path: PathBuf,
+    pub size_bytes: u64,
+}
+
+impl PlanReport {
+    pub fn new(
+        disc_id: &str,
+        disc_capacity_bytes: u64,
+        plans: &[DiscPlan],
+        checksums_computed: usize,
+        excluded: Vec<ExcludedPath>,
+    ) -> Self {
+        let discs: Vec<PlannedDisc> = plans
+            .iter()
+            .map(|plan| {
+                let mut files = Vec::new();
+                for entry in &plan.entries {
+                    collect_files(entry, &mut files);
+                }
+                PlannedDisc {
+                    disc_number: plan.disc_number,
+                    used_bytes: plan.used_bytes,
+                    utilization_percent: plan.utilization_percent(),
+                    files,
+                    duplicates: plan.duplicates.clone(),
+                }
+            })
+            .collect();
+
+        let mut split_directories: Vec<String> = Vec::new();
+        for dir in plans.iter().flat_map(|p| p.split_directories.iter()) {
+            if !split_directories.contains(dir) {
+                split_directories.push(dir.clone());
+            }
+        }
+
+        Self {
+            disc_id: disc_id.to_string(),
+            generated_at: crate::disc::format_timestamp_now(),
+            disc_capacity_bytes,
+            total_bytes: plans.iter().map(|p| p.used_bytes).sum(),
+            discs,
+            split_directories,
+            checksums_computed,
+            excluded,
+        }
+    }
+
+    /// Render the report as plain text.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("BlueVault dry-run plan: {}\n", self.disc_id));
+        out.push_str(&format!("Generated: {}\n", self.generated_at));
+        out.push_str(&format!("Disc capacity: {}\n", format_bytes_gb(self.disc_capacity_bytes)));
+        out.push_str(&format!("Total size: {}\n", format_bytes_gb(self.total_bytes)));
+        out.push_str(&format!("Discs: {}\n", self.discs.len()));
+        out.push_str(&format!("Checksums computed: {}\n", self.checksums_computed));
+
+        if !self.split_directories.is_empty() {
+            out.push_str("\nSplit directories:\n");
+            for dir in &self.split_directories {
+                out.push_str(&format!("  {}\n", dir));
+            }
+        }
+
+        out.push_str(&format!("\nExcluded files ({}):\n", self.excluded.len()));
+        if self.excluded.is_empty() {
+            out.push_str("  (none)\n");
+        }
+        for excluded in &self.excluded {
+            out.push_str(&format!("  {} ({})\n", excluded.path.display(), excluded.reason));
+        }
+
+        for disc in &self.discs {
+            out.push_str(&format!(
+                "\n== Disc {}: {} ({:.1}% used, {} files) ==\n",
+                disc.disc_number,
+                format_bytes_gb(disc.used_bytes),
+                disc.utilization_percent,
+                disc.files.len()
+            ));
+            for file in &disc.files {
+                out.push_str(&format!("  {:>10}  {}\n", format_size(file.size_bytes), file.path.display()));
+            }
+            if !disc.duplicates.is_empty() {
+                out.push_str(&format!(
+                    "  Deduplicated ({} files, {} saved):\n",
+                    disc.duplicates.len(),
+                    format_size(crate::dedup::saved_bytes(&disc.duplicates))
+                ));
+                for duplicate in &disc.duplicates {
+                    out.push_str(&format!("    {} => {}\n", duplicate.path.display(), duplicate.original.display()));
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Write `bdarchive-plan-<disc_id>.txt` (and `.json` if requested) into `dir`,
+    /// with the disc ID made safe for a file name.
+    pub fn write(&self, dir: &Path, include_json: bool) -> Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create report directory: {}", dir.display()))?;
+
+        let mut written = Vec::new();
+        let name = sanitize_for_filesystem(&self.disc_id);
+
+        let text_path = dir.join(format!("bdarchive-plan-{}.txt", name));
+        fs::write(&text_path, self.to_text())
+            .with_context(|| format!("Failed to write plan report: {}", text_path.display()))?;
+        written.push(text_path);
+
+        if include_json {
+            let json_path = dir.join(format!("bdarchive-plan-{}.json", name));
+            let json = serde_json::to_string_pretty(self).context("Failed to serialize plan report")?;
+            fs::write(&json_path, json)
+                .with_context(|| format!("Failed to write plan report: {}", json_path.display()))?;
+            written.push(json_path);
+        }
+
+        info!("Wrote dry-run plan report: {:?}", written);
+        Ok(written)
+    }
+}
+
+fn collect_files(entry: &DirectoryEntry, files: &mut Vec<PlannedFile>) {
+    if entry.is_file {
+        files.push(PlannedFile {
+            path: entry.path.clone(),
+            size_bytes: entry.size_bytes,
+        });
+    } else {
+        for child in &entry.children {
+            collect_files(child, files);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_plan_report_write() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+
+        let mut plan = DiscPlan::new(1, 1000);
+        plan.add_entry(DirectoryEntry {
+            path: PathBuf::from("/src/photos"),
+            size_bytes: 300,
+            is_file: false,
+            children: vec![
+                DirectoryEntry {
+                    path: PathBuf::from("/src/photos/a.jpg"),
+                    size_bytes: 100,
+                    is_file: true,
+                    children: Vec::new(),
+                },
+                DirectoryEntry {
+                    path: PathBuf::from("/src/photos/b.jpg"),
+                    size_bytes: 200,
+                    is_file: true,
+                    children: Vec::new(),
+                },
+            ],
+        });
+
+        let excluded = vec![ExcludedPath {
+            path: PathBuf::from("/src/photos/link"),
+            reason: "symlink skipped by policy".to_string(),
+        }];
+        let report = PlanReport::new("2026-BD-1", 1000, &[plan], 2, excluded);
+        assert_eq!(report.discs[0].files.len(), 2);
+        assert_eq!(report.total_bytes, 300);
+
+        let written = report.write(temp_dir.path(), true)?;
+        assert_eq!(written.len(), 2);
+
+        let text = fs::read_to_string(temp_dir.path().join("bdarchive-plan-2026-BD-1.txt"))?;
+        assert!(text.contains("/src/photos/b.jpg"));
+        assert!(text.contains("Checksums computed: 2"));
+        assert!(text.contains("/src/photos/link (symlink skipped by policy)"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(temp_dir.path().join("bdarchive-plan-2026-BD-1.json"))?)?;
+        assert_eq!(json["discs"][0]["files"][1]["size_bytes"], 200);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_sanitizes_disc_id() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let report = PlanReport::new("2026/BD:1", 1000, &[DiscPlan::new(1, 1000)], 0, Vec::new());
+
+        let written = report.write(temp_dir.path(), true)?;
+        assert_eq!(
+            written,
+            vec![
+                temp_dir.path().join("bdarchive-plan-2026_BD_1.txt"),
+                temp_dir.path().join("bdarchive-plan-2026_BD_1.json"),
+            ]
+        );
+        assert!(written.iter().all(|path| path.is_file()));
+        Ok(())
+    }
+}
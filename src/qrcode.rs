@@ -1,23 +1,150 @@
 use crate::commands;
+use crate::config::{QrConfig, QrErrorCorrection};
 use crate::dependencies;
-use anyhow::Result;
+use crate::disc::sanitize_for_filesystem;
+use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 
+/// Prefix of the payloads labels carried before templates, still recognised
+/// when scanning: `bdarchive:<disc_id>[:<hash>]`.
+pub const QR_PAYLOAD_PREFIX: &str = "bdarchive";
+
+/// Start of the default payload URI, followed by the disc ID
+pub const QR_URI_PREFIX: &str = "bluevault://disc/";
+
+/// Default `qr.payload_template`: compact, and readable on any phone
+pub const DEFAULT_PAYLOAD_TEMPLATE: &str = "bluevault://disc/{id}?hash={hash}";
+
+/// Values a QR payload template can refer to.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QrPayloadFields<'a> {
+    pub disc_id: &'a str,
+    pub set_id: Option<&'a str>,
+    pub sequence_number: Option<u32>,
+    /// Creation timestamp; only the date goes into the payload
+    pub created_at: &'a str,
+    pub manifest_hash: Option<&'a str>,
+}
+
+/// Fill `template`'s placeholders from `fields`. Unknown values render empty,
+/// and URI query parameters left empty (`?hash=`) are dropped.
+pub fn render_qr_payload(template: &str, fields: &QrPayloadFields) -> String {
+    let sequence = fields.sequence_number.map(|n| n.to_string()).unwrap_or_default();
+    let rendered = template
+        .replace("{id}", fields.disc_id)
+        .replace("{set}", fields.set_id.unwrap_or_default())
+        .replace("{seq}", &sequence)
+        .replace("{created}", fields.created_at.get(..10).unwrap_or(fields.created_at))
+        .replace("{hash}", fields.manifest_hash.unwrap_or_default());
+    drop_empty_query_params(&rendered)
+}
+
+fn drop_empty_query_params(payload: &str) -> String {
+    let Some((base, query)) = payload.split_once('?').filter(|(base, _)| base.contains("://")) else {
+        return payload.to_string();
+    };
+    let params: Vec<&str> = query.split('&').filter(|param| !param.ends_with('=') && !param.is_empty()).collect();
+    if params.is_empty() {
+        base.to_string()
+    } else {
+        format!("{}?{}", base, params.join("&"))
+    }
+}
+
+/// The QR payload for a disc from `qr.payload_template`. A rendered payload
+/// too big for a QR code at the configured error correction falls back to
+/// the default template.
+pub fn qr_payload(config: &QrConfig, fields: &QrPayloadFields) -> String {
+    let payload = render_qr_payload(&config.payload_template, fields);
+    let capacity = config.error_correction.capacity_bytes();
+    if payload.len() <= capacity {
+        return payload;
+    }
+    warn!(
+        "QR payload for {} is {} bytes, more than the {} a QR code holds at error correction {}; using the default payload",
+        fields.disc_id,
+        payload.len(),
+        capacity,
+        config.error_correction.name()
+    );
+    render_qr_payload(DEFAULT_PAYLOAD_TEMPLATE, fields)
+}
+
+/// Disc ID from a scanned QR payload: a `bluevault://disc/<disc_id>` URI, a
+/// JSON object with a `disc_id` (or `id`) field, `bdarchive:<disc_id>[:<hash>]`
+/// or, for labels made before payloads were prefixed, the bare disc ID.
+pub fn disc_id_from_payload(payload: &str) -> Option<String> {
+    let payload = payload.trim();
+    let from_json = serde_json::from_str::<serde_json::Value>(payload).ok().and_then(|json| {
+        ["disc_id", "id"].iter().find_map(|key| json.get(key)?.as_str().map(str::to_string))
+    });
+    let disc_id = if let Some(id) = from_json.as_deref() {
+        id
+    } else if let Some(rest) = payload.strip_prefix(QR_URI_PREFIX) {
+        rest.split(['?', '/', '#']).next().unwrap_or_default()
+    } else if let Some(rest) = payload.strip_prefix(QR_PAYLOAD_PREFIX).and_then(|rest| rest.strip_prefix(':')) {
+        rest.split(':').next().unwrap_or_default()
+    } else {
+        payload
+    };
+    if disc_id.is_empty() || disc_id.contains(char::is_whitespace) {
+        None
+    } else {
+        Some(disc_id.to_string())
+    }
+}
+
+/// Read the QR code in a photo or scan of a disc label and return its disc ID.
+/// Decoding uses `zbarimg`; returns None if it is missing or finds no code.
+pub fn decode_qr(image_path: &Path) -> Option<String> {
+    let zbarimg = match dependencies::get_optional_command("zbarimg") {
+        Some(path) => path.to_string_lossy().to_string(),
+        None => {
+            warn!("zbarimg not found, cannot decode QR codes");
+            return None;
+        }
+    };
+
+    let image = image_path.to_string_lossy().to_string();
+    let output = match commands::execute_command(zbarimg.as_str(), &["--quiet", "--raw", image.as_str()], false) {
+        Ok(output) if output.success => output,
+        Ok(_) => {
+            info!("No QR code found in {}", image_path.display());
+            return None;
+        }
+        Err(e) => {
+            warn!("zbarimg failed on {}: {}", image_path.display(), e);
+            return None;
+        }
+    };
+
+    output.stdout.lines().find_map(disc_id_from_payload)
+}
+
 /// Generate a QR code for a disc ID.
 pub fn generate_qrcode(
     disc_id: &str,
     output_dir: &Path,
     format: QrCodeFormat,
     dry_run: bool,
+) -> Result<PathBuf> {
+    generate_qrcode_with_payload(disc_id, disc_id, QrErrorCorrection::default(), output_dir, format, dry_run)
+}
+
+/// Generate a QR code encoding `payload`, named after the disc ID.
+pub fn generate_qrcode_with_payload(
+    disc_id: &str,
+    payload: &str,
+    error_correction: QrErrorCorrection,
+    output_dir: &Path,
+    format: QrCodeFormat,
+    dry_run: bool,
 ) -> Result<PathBuf> {
     // Check if qrencode is available
-    let qrencode_path_str = match dependencies::get_optional_command("qrencode") {
+    let qrencode_path_str = match dependencies::resolve_optional("qrencode", "skipping QR code generation") {
         Some(path) => path.to_string_lossy().to_string(),
-        None => {
-            warn!("qrencode not found, skipping QR code generation");
-            return Err(anyhow::anyhow!("qrencode not available"));
-        }
+        None => return Err(anyhow::anyhow!("qrencode not available")),
     };
 
     info!("Generating QR code for disc ID: {}", disc_id);
@@ -31,7 +158,7 @@ pub fn generate_qrcode(
         QrCodeFormat::ASCII => "txt",
     };
 
-    let output_path = output_dir.join(format!("{}.{}", disc_id, extension));
+    let output_path = output_dir.join(format!("{}.{}", sanitize_for_filesystem(disc_id), extension));
 
     let output_path_str = output_path.to_string_lossy().to_string();
     let mut args = vec![String::new(); 4]; // Pre-allocate with placeholders
@@ -58,7 +185,7 @@ pub fn generate_qrcode(
     }
 
     let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-    let args_with_id: Vec<&str> = [args_str.as_slice(), &[disc_id]].concat();
+    let args_with_id: Vec<&str> = [args_str.as_slice(), &["-l", error_correction.name(), payload]].concat();
     let output =
         commands::execute_command(qrencode_path_str.as_str(), args_with_id.as_slice(), dry_run)?;
 
@@ -90,6 +217,178 @@ pub fn generate_ascii_qrcode(disc_id: &str, dry_run: bool) -> Result<String> {
     Ok(output)
 }
 
+/// Disc details printed on a jewel-case label.
+#[derive(Debug, Clone)]
+pub struct DiscLabelInfo {
+    pub volume_label: String,
+    pub created_at: String,
+    pub file_count: usize,
+    pub manifest_hash: Option<String>,
+    /// Shelf or box the disc is kept in, printed when known
+    pub storage_location: Option<String>,
+    /// Set the disc belongs to and its place in it
+    pub set_id: Option<String>,
+    pub sequence_number: Option<u32>,
+}
+
+impl DiscLabelInfo {
+    /// The disc's QR payload under `config`
+    pub fn qr_payload(&self, disc_id: &str, config: &QrConfig) -> String {
+        qr_payload(
+            config,
+            &QrPayloadFields {
+                disc_id,
+                set_id: self.set_id.as_deref(),
+                sequence_number: self.sequence_number,
+                created_at: &self.created_at,
+                manifest_hash: self.manifest_hash.as_deref(),
+            },
+        )
+    }
+}
+
+/// Label page size in PDF points (120mm square, a standard jewel-case insert).
+const LABEL_SIZE_PT: f64 = 340.0;
+
+/// Generate a printable PDF label with disc details and a QR code.
+pub fn generate_label_pdf(disc_id: &str, disc_info: &DiscLabelInfo, qr: &QrConfig, out_path: &Path) -> Result<PathBuf> {
+    let qrencode_path = match dependencies::resolve_optional("qrencode", "skipping label generation") {
+        Some(path) => path.to_string_lossy().to_string(),
+        None => return Err(anyhow::anyhow!("qrencode not available")),
+    };
+
+    info!("Generating PDF label for disc ID: {}", disc_id);
+
+    let payload = disc_info.qr_payload(disc_id, qr);
+    let args: &[&str] = &["-t", "ASCII", "-m", "0", "-l", qr.error_correction.name(), payload.as_str()];
+    let ascii = commands::execute_command_capture_stdout(qrencode_path.as_str(), args, false)
+        .context("qrencode failed to encode the label QR code")?;
+    let matrix = parse_ascii_matrix(&ascii);
+    if matrix.is_empty() {
+        anyhow::bail!("qrencode produced no QR data");
+    }
+
+    if let Some(parent) = out_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create label directory {}", parent.display()))?;
+    }
+    std::fs::write(out_path, render_label_pdf(disc_id, disc_info, &matrix))
+        .with_context(|| format!("Failed to write PDF label {}", out_path.display()))?;
+
+    debug!("PDF label generated: {}", out_path.display());
+    Ok(out_path.to_path_buf())
+}
+
+/// Parse `qrencode -t ASCII` output ("##" dark, "  " light) into a module matrix.
+fn parse_ascii_matrix(ascii: &str) -> Vec<Vec<bool>> {
+    ascii
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.as_bytes()
+                .chunks(2)
+                .map(|chunk| chunk[0] == b'#')
+                .collect()
+        })
+        .collect()
+}
+
+/// Escape a string for use inside a PDF literal string.
+fn pdf_escape(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_ascii() && !c.is_ascii_control())
+        .flat_map(|c| match c {
+            '(' | ')' | '\\' => vec!['\\', c],
+            _ => vec![c],
+        })
+        .collect()
+}
+
+/// Render a single-page PDF label. Uses the built-in Helvetica font so no
+/// font embedding is needed.
+fn render_label_pdf(disc_id: &str, info: &DiscLabelInfo, matrix: &[Vec<bool>]) -> Vec<u8> {
+    let mut content = String::new();
+
+    // Border
+    content.push_str(&format!(
+        "0.5 w 10 10 {:.2} {:.2} re S\n",
+        LABEL_SIZE_PT - 20.0,
+        LABEL_SIZE_PT - 20.0
+    ));
+
+    // Text block
+    let hash_line = info
+        .manifest_hash
+        .as_deref()
+        .map(|h| format!("Manifest: {}", &h[..h.len().min(16)]))
+        .unwrap_or_else(|| "Manifest: (unknown)".to_string());
+    let mut lines = vec![
+        (16.0, format!("Disc ID: {}", disc_id)),
+        (10.0, format!("Volume: {}", info.volume_label)),
+        (10.0, format!("Created: {}", info.created_at)),
+        (10.0, format!("Files: {}", info.file_count)),
+    ];
+    if let Some(location) = &info.storage_location {
+        lines.push((10.0, format!("Location: {}", location)));
+    }
+    lines.push((8.0, hash_line));
+    let mut y = LABEL_SIZE_PT - 40.0;
+    for (size, line) in lines.iter() {
+        content.push_str(&format!(
+            "BT /F1 {:.0} Tf 24 {:.2} Td ({}) Tj ET\n",
+            size,
+            y,
+            pdf_escape(line)
+        ));
+        y -= size + 6.0;
+    }
+
+    // QR code, bottom-right, drawn as filled squares
+    let qr_size = 150.0;
+    let module = qr_size / matrix.len() as f64;
+    let origin_x = LABEL_SIZE_PT - 24.0 - qr_size;
+    let origin_y = 24.0;
+    for (row_idx, row) in matrix.iter().enumerate() {
+        for (col_idx, dark) in row.iter().enumerate() {
+            if *dark {
+                let x = origin_x + col_idx as f64 * module;
+                let y = origin_y + (matrix.len() - 1 - row_idx) as f64 * module;
+                content.push_str(&format!("{:.2} {:.2} {:.2} {:.2} re\n", x, y, module, module));
+            }
+        }
+    }
+    content.push_str("f\n");
+
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {0:.0} {0:.0}] /Resources << /Font << /F1 4 0 R >> >> /Contents 5 0 R >>",
+            LABEL_SIZE_PT
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+        format!("<< /Length {} >>\nstream\n{}endstream", content.len(), content),
+    ];
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, obj) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, obj));
+    }
+    let xref_offset = pdf.len();
+    pdf.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+    for offset in offsets {
+        pdf.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    pdf.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    ));
+    pdf.into_bytes()
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum QrCodeFormat {
     PNG,
@@ -122,4 +421,69 @@ mod tests {
             QrCodeFormat::SVG
         ));
     }
+
+    #[test]
+    fn test_qr_payload() {
+        let mut fields = QrPayloadFields {
+            disc_id: "2024-BD-001",
+            created_at: "2024-03-05T10:00:00Z",
+            ..QrPayloadFields::default()
+        };
+        let config = QrConfig::default();
+        assert_eq!(qr_payload(&config, &fields), "bluevault://disc/2024-BD-001");
+        fields.manifest_hash = Some("abc123");
+        assert_eq!(qr_payload(&config, &fields), "bluevault://disc/2024-BD-001?hash=abc123");
+
+        fields.set_id = Some("PHOTOS");
+        fields.sequence_number = Some(2);
+        let json = r#"{"disc_id":"{id}","set":"{set}","seq":"{seq}","created":"{created}","hash":"{hash}"}"#;
+        let config = QrConfig { payload_template: json.to_string(), ..QrConfig::default() };
+        let payload = qr_payload(&config, &fields);
+        assert_eq!(
+            payload,
+            r#"{"disc_id":"2024-BD-001","set":"PHOTOS","seq":"2","created":"2024-03-05","hash":"abc123"}"#
+        );
+        assert_eq!(disc_id_from_payload(&payload), Some("2024-BD-001".to_string()));
+
+        // Too big for a QR code at H: the default payload is used instead
+        let config = QrConfig {
+            payload_template: format!("{{id}}{}", "x".repeat(2000)),
+            error_correction: QrErrorCorrection::H,
+        };
+        assert_eq!(qr_payload(&config, &fields), "bluevault://disc/2024-BD-001?hash=abc123");
+    }
+
+    #[test]
+    fn test_disc_id_from_payload() {
+        assert_eq!(disc_id_from_payload("bluevault://disc/2024-BD-001?hash=ab\n"), Some("2024-BD-001".to_string()));
+        assert_eq!(disc_id_from_payload("bluevault://disc/2024-BD-001"), Some("2024-BD-001".to_string()));
+        assert_eq!(disc_id_from_payload("bdarchive:2024-BD-001:abc123\n"), Some("2024-BD-001".to_string()));
+        assert_eq!(disc_id_from_payload("bdarchive:2024-BD-001"), Some("2024-BD-001".to_string()));
+        assert_eq!(disc_id_from_payload("2024-BD-001"), Some("2024-BD-001".to_string()));
+        assert_eq!(disc_id_from_payload("bdarchive:"), None);
+        assert_eq!(disc_id_from_payload("https://example.com/some page"), None);
+    }
+
+    #[test]
+    fn test_render_label_pdf() {
+        let matrix = parse_ascii_matrix("##  ##\n  ##  \n##  ##\n");
+        assert_eq!(matrix.len(), 3);
+        assert_eq!(matrix[0], vec![true, false, true]);
+
+        let info = DiscLabelInfo {
+            volume_label: "BDARCHIVE_2024_BD_001".to_string(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            file_count: 42,
+            manifest_hash: Some("deadbeef".to_string()),
+            storage_location: Some("Binder A, Slot 12".to_string()),
+            set_id: None,
+            sequence_number: None,
+        };
+        let pdf = String::from_utf8(render_label_pdf("2024-BD-001 (a)", &info, &matrix)).unwrap();
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.contains("Disc ID: 2024-BD-001 \\(a\\)"));
+        assert!(pdf.contains("Files: 42"));
+        assert!(pdf.contains("Location: Binder A, Slot 12"));
+        assert!(pdf.ends_with("%%EOF\n"));
+    }
 }
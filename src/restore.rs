@@ -0,0 +1,373 @@
+use crate::config::{StagingConfig, SymlinkPolicy};
+use crate::database::{Disc, DiscSet, FileRecord};
+use crate::search::format_size;
+use crate::{bagit, manifest, paths, staging, verify};
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// Directory of a single disc holding the archived folders
+const ARCHIVE_DIR: &str = "ARCHIVE";
+/// Directory of a set disc holding DISC_INFO.txt and friends rather than archived files
+const SET_DISC_INFO_DIR: &str = "disc_info";
+
+/// What restoring one disc copied and, if asked to, checked.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RestoreOutcome {
+    pub disc_id: String,
+    pub files_copied: usize,
+    /// Restored files whose checksum was compared with the catalog
+    pub files_verified: usize,
+    /// Catalogued files missing from the restore, checksum mismatches and read errors
+    pub failures: Vec<String>,
+}
+
+impl RestoreOutcome {
+    /// "2024-BD-1: 120 files restored, 118 verified, 2 problems".
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{}: {} files restored", self.disc_id, self.files_copied);
+        if self.files_verified > 0 {
+            summary.push_str(&format!(", {} verified", self.files_verified));
+        }
+        if !self.failures.is_empty() {
+            summary.push_str(&format!(", {} problems", self.failures.len()));
+        }
+        summary
+    }
+}
+
+/// Outcome of restoring a whole set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SetRestoreOutcome {
+    pub restored: Vec<RestoreOutcome>,
+    /// Discs the user chose not to insert
+    pub skipped: Vec<String>,
+}
+
+/// Copy the archived files of the disc mounted at `root` into `destination`:
+/// the ARCHIVE tree of a single disc, or the top-level folders of a set disc.
+/// Files already in `destination` with the same path are overwritten.
+///
+/// With `verify`, every restored file that has a checksum in the catalog is
+/// hashed again and compared with it once the copy is done.
+pub fn restore_mounted_disc(
+    conn: &Connection,
+    disc_id: &str,
+    root: &Path,
+    destination: &Path,
+    verify: bool,
+    progress_callback: &mut Option<staging::ProgressCallback>,
+) -> Result<RestoreOutcome> {
+    if let Some(found) = verify::read_disc_id(root).filter(|found| found != disc_id) {
+        anyhow::bail!("The disc at {} is {}, not {}", root.display(), found, disc_id);
+    }
+    let content_root = bagit::content_root(root);
+    let sources = archived_folders(&content_root)?;
+
+    fs::create_dir_all(destination)
+        .with_context(|| format!("Failed to create restore destination: {}", destination.display()))?;
+    let needed = Disc::get(conn, disc_id)?.and_then(|disc| disc.iso_size);
+    if let (Some(needed), Some(free)) = (needed, paths::free_space_bytes(destination)) {
+        if free < needed {
+            anyhow::bail!(
+                "Restoring {} needs up to {} but only {} is free in {}",
+                disc_id,
+                format_size(needed),
+                format_size(free),
+                destination.display()
+            );
+        }
+    }
+
+    info!("Restoring disc {} from {} to {}", disc_id, root.display(), destination.display());
+    // Disc files are read-only; restored copies get ordinary permissions
+    let options = StagingConfig {
+        preserve_permissions: false,
+        symlink_policy: SymlinkPolicy::Preserve,
+        ..StagingConfig::default()
+    };
+    let mut files_copied = 0;
+    for (source, dest) in &sources {
+        files_copied += staging::copy_tree(source, &destination.join(dest), &options, progress_callback)
+            .with_context(|| format!("Failed to restore {}", source.display()))?;
+    }
+    files_copied += restore_duplicates(&content_root, destination);
+
+    let mut outcome = RestoreOutcome {
+        disc_id: disc_id.to_string(),
+        files_copied,
+        ..RestoreOutcome::default()
+    };
+    if verify {
+        verify_restored(conn, destination, &mut outcome, progress_callback)?;
+    }
+    info!("Restore finished: {}", outcome.summary());
+    Ok(outcome)
+}
+
+/// Restore `disc_id` from wherever it is already mounted, or else from the
+/// disc in `device` (or the directory `device` names), mounted for the copy.
+pub fn restore_disc(
+    conn: &Connection,
+    disc_id: &str,
+    device: &str,
+    destination: &Path,
+    verify: bool,
+    progress_callback: &mut Option<staging::ProgressCallback>,
+) -> Result<RestoreOutcome> {
+    if let Some(root) = verify::find_mounted_disc(disc_id) {
+        return restore_mounted_disc(conn, disc_id, &root, destination, verify, progress_callback);
+    }
+    verify::with_disc_mounted(device, |root| {
+        restore_mounted_disc(conn, disc_id, root, destination, verify, progress_callback)
+    })
+}
+
+/// Restore every disc of a set into `destination`, in sequence order.
+///
+/// Discs already mounted are read directly. For the others
+/// `prompt(sequence, total, disc_id, problem)` asks for the disc to be inserted
+/// into `device`; it returns `false` to skip the disc. A disc that cannot be
+/// mounted, or is the wrong one, is asked for again with the problem.
+pub fn restore_set<F>(
+    conn: &Connection,
+    set_id: &str,
+    device: &str,
+    destination: &Path,
+    verify: bool,
+    mut prompt: F,
+    progress_callback: &mut Option<staging::ProgressCallback>,
+) -> Result<SetRestoreOutcome>
+where
+    F: FnMut(u32, u32, &str, Option<&str>) -> bool,
+{
+    let discs = DiscSet::get_discs(conn, set_id)?;
+    if discs.is_empty() {
+        anyhow::bail!("No discs are catalogued for set {}", set_id);
+    }
+    let total = discs.len() as u32;
+    let mut outcome = SetRestoreOutcome::default();
+
+    for (index, disc) in discs.iter().enumerate() {
+        let sequence = disc.sequence_number.unwrap_or(index as u32 + 1);
+        if let Some(ref mut callback) = progress_callback {
+            callback(&format!("📀 Restoring disc {} of {}: {}", sequence, total, disc.disc_id));
+        }
+        if let Some(root) = verify::find_mounted_disc(&disc.disc_id) {
+            outcome.restored.push(restore_mounted_disc(conn, &disc.disc_id, &root, destination, verify, progress_callback)?);
+            continue;
+        }
+
+        let mut problem: Option<String> = None;
+        loop {
+            if !prompt(sequence, total, &disc.disc_id, problem.as_deref()) {
+                warn!("Skipping disc {} of the restore", disc.disc_id);
+                outcome.skipped.push(disc.disc_id.clone());
+                break;
+            }
+            let mounted = match verify::get_temporary_mountpoint()
+                .and_then(|mountpoint| verify::mount_device(device, &mountpoint, false))
+            {
+                Ok(mounted) => mounted,
+                Err(e) => {
+                    problem = Some(format!("{:#}", e));
+                    continue;
+                }
+            };
+            let found = verify::read_disc_id(&mounted.path);
+            let result = (found.as_deref() == Some(disc.disc_id.as_str())).then(|| {
+                restore_mounted_disc(conn, &disc.disc_id, &mounted.path, destination, verify, progress_callback)
+            });
+            if let Err(e) = verify::unmount_device(&mounted, false) {
+                warn!("Failed to unmount {}: {}", mounted.path.display(), e);
+            }
+            match result {
+                Some(result) => {
+                    outcome.restored.push(result?);
+                    break;
+                }
+                None => {
+                    problem = Some(format!(
+                        "The disc in {} is {}, not {}",
+                        device,
+                        found.as_deref().unwrap_or("not an archive disc"),
+                        disc.disc_id
+                    ))
+                }
+            }
+        }
+    }
+    Ok(outcome)
+}
+
+/// Folders to copy from a disc and where they go under the destination.
+fn archived_folders(content_root: &Path) -> Result<Vec<(PathBuf, PathBuf)>> {
+    let archive = content_root.join(ARCHIVE_DIR);
+    if archive.is_dir() {
+        return Ok(vec![(archive, PathBuf::new())]);
+    }
+    // Set discs hold the source folders at the top level
+    let mut folders = Vec::new();
+    for entry in fs::read_dir(content_root).with_context(|| format!("Failed to read {}", content_root.display()))? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_os_string();
+        if path.is_dir() && name != SET_DISC_INFO_DIR {
+            folders.push((path, PathBuf::from(name)));
+        }
+    }
+    if folders.is_empty() {
+        anyhow::bail!("No archived files found on the disc at {}", content_root.display());
+    }
+    folders.sort();
+    Ok(folders)
+}
+
+/// Copies that deduplication left off the disc, recreated from their stored
+/// original. Returns how many were restored.
+fn restore_duplicates(content_root: &Path, destination: &Path) -> usize {
+    let Ok(disc_manifest) = manifest::read_manifest_json(&content_root.join(manifest::MANIFEST_JSON_FILE)) else {
+        return 0;
+    };
+    let mut restored = 0;
+    for file in &disc_manifest.files {
+        let Some(original) = &file.duplicate_of else {
+            continue;
+        };
+        let (Ok(copy), Ok(original)) = (file.rel_path.strip_prefix(ARCHIVE_DIR), original.strip_prefix(ARCHIVE_DIR))
+        else {
+            continue;
+        };
+        let (copy, original) = (destination.join(copy), destination.join(original));
+        if copy.exists() {
+            continue;
+        }
+        let copied = copy
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::copy(&original, &copy));
+        match copied {
+            Ok(_) => restored += 1,
+            Err(e) => warn!("Failed to restore duplicate {}: {}", copy.display(), e),
+        }
+    }
+    restored
+}
+
+/// Hash the restored files and compare them with the catalog.
+fn verify_restored(
+    conn: &Connection,
+    destination: &Path,
+    outcome: &mut RestoreOutcome,
+    progress_callback: &mut Option<staging::ProgressCallback>,
+) -> Result<()> {
+    let records = FileRecord::list_for_disc(conn, &outcome.disc_id)?;
+    // Symlinks are catalogued without a checksum, and a set disc's disc_info/ is not restored
+    let checkable: Vec<&FileRecord> = records
+        .iter()
+        .filter(|record| record.checksum().is_some() && !Path::new(&record.rel_path).starts_with(SET_DISC_INFO_DIR))
+        .collect();
+    if checkable.is_empty() {
+        warn!("No catalogued checksums for disc {}; the restore was not verified", outcome.disc_id);
+        if let Some(ref mut callback) = progress_callback {
+            callback(&format!("⚠️ No catalogued checksums for {}, nothing to verify", outcome.disc_id));
+        }
+        return Ok(());
+    }
+
+    for record in &checkable {
+        let rel = Path::new(&record.rel_path);
+        let path = destination.join(rel.strip_prefix(ARCHIVE_DIR).unwrap_or(rel));
+        outcome.files_verified += 1;
+        if !path.is_file() {
+            outcome.failures.push(format!("{}: missing", record.rel_path));
+            continue;
+        }
+        let Some((algorithm, expected)) = record.checksum() else {
+            continue;
+        };
+        match manifest::calculate_checksum(&path, algorithm) {
+            Ok(actual) if actual.eq_ignore_ascii_case(expected) => {}
+            Ok(_) => outcome.failures.push(format!("{}: checksum mismatch", record.rel_path)),
+            Err(e) => outcome.failures.push(format!("{}: {:#}", record.rel_path, e)),
+        }
+        if outcome.files_verified.is_multiple_of(100) || outcome.files_verified == checkable.len() {
+            if let Some(ref mut callback) = progress_callback {
+                callback(&format!("🔍 Verified {}/{} restored files", outcome.files_verified, checkable.len()));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::init_database;
+    use crate::disc::format_timestamp_now;
+    use tempfile::TempDir;
+
+    fn file_record(disc_id: &str, rel_path: &str, sha256: String) -> FileRecord {
+        FileRecord {
+            id: None,
+            disc_id: disc_id.to_string(),
+            rel_path: rel_path.to_string(),
+            sha256,
+            size: 0,
+            mtime: format_timestamp_now(),
+            added_at: format_timestamp_now(),
+            crc32: None,
+        }
+    }
+
+    #[test]
+    fn test_restore_mounted_disc() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+        let disc_root = temp_dir.path().join("disc");
+        fs::create_dir_all(disc_root.join("ARCHIVE/docs"))?;
+        fs::write(disc_root.join("ARCHIVE/docs/a.txt"), "alpha")?;
+        fs::write(disc_root.join("ARCHIVE/docs/b.txt"), "beta")?;
+        fs::write(disc_root.join("DISC_INFO.txt"), "Disc-ID: R-1\n")?;
+
+        conn.execute("INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('R-1', 'R_1', '2024-01-01T00:00:00Z')", [])?;
+        let a_sha = manifest::calculate_sha256(&disc_root.join("ARCHIVE/docs/a.txt"))?;
+        FileRecord::insert(&conn, &file_record("R-1", "ARCHIVE/docs/a.txt", a_sha))?;
+        // Stands for a file that was changed on disc since it was catalogued
+        FileRecord::insert(&conn, &file_record("R-1", "ARCHIVE/docs/b.txt", "0".repeat(64)))?;
+
+        let destination = temp_dir.path().join("restored");
+        let mut progress = None;
+        let outcome = restore_mounted_disc(&conn, "R-1", &disc_root, &destination, true, &mut progress)?;
+        assert_eq!(fs::read_to_string(destination.join("docs/a.txt"))?, "alpha");
+        assert_eq!(outcome.files_copied, 2);
+        assert_eq!(outcome.files_verified, 2);
+        assert_eq!(outcome.failures, vec!["ARCHIVE/docs/b.txt: checksum mismatch".to_string()]);
+        assert_eq!(outcome.summary(), "R-1: 2 files restored, 2 verified, 1 problems");
+
+        let err = restore_mounted_disc(&conn, "R-2", &disc_root, &destination, false, &mut progress).unwrap_err();
+        assert!(err.to_string().contains("is R-1, not R-2"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_restore_set_disc_layout() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+        let disc_root = temp_dir.path().join("disc");
+        fs::create_dir_all(disc_root.join("photos"))?;
+        fs::write(disc_root.join("photos/p.jpg"), "jpeg")?;
+        fs::create_dir_all(disc_root.join("disc_info"))?;
+        fs::write(disc_root.join("disc_info/DISC_INFO.txt"), "Disc-ID: SET-1\n")?;
+
+        let destination = temp_dir.path().join("restored");
+        let mut progress = None;
+        let outcome = restore_mounted_disc(&conn, "SET-1", &disc_root, &destination, true, &mut progress)?;
+        assert_eq!(outcome.files_copied, 1);
+        assert_eq!(outcome.files_verified, 0);
+        assert!(destination.join("photos/p.jpg").is_file());
+        assert!(!destination.join("disc_info").exists());
+        Ok(())
+    }
+}
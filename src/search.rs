@@ -1,5 +1,80 @@
+use crate::config::BYTES_PER_GB;
 use anyhow::Result;
+use rusqlite::types::Value;
 use rusqlite::{Connection, Row};
+use tracing::debug;
+
+/// Columns selected for every search (files `f` joined with discs `d`).
+const RESULT_COLUMNS: &str = "f.disc_id, f.rel_path, f.size, f.mtime, f.sha256, d.set_id, d.storage_location, d.planned";
+
+/// Result limit when the query does not set one.
+const DEFAULT_LIMIT: u32 = 1000;
+
+/// Order of search results. Applied in SQL before LIMIT/OFFSET.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchSort {
+    /// Full-text rank for FTS queries, path otherwise
+    #[default]
+    Relevance,
+    Path,
+    /// Largest first
+    Size,
+    DiscId,
+    /// Newest modification time first
+    Date,
+}
+
+impl SearchSort {
+    /// The next mode when cycling through them in the UI.
+    pub fn next(self) -> Self {
+        match self {
+            SearchSort::Relevance => SearchSort::Path,
+            SearchSort::Path => SearchSort::Size,
+            SearchSort::Size => SearchSort::DiscId,
+            SearchSort::DiscId => SearchSort::Date,
+            SearchSort::Date => SearchSort::Relevance,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SearchSort::Relevance => "relevance",
+            SearchSort::Path => "path",
+            SearchSort::Size => "size",
+            SearchSort::DiscId => "disc",
+            SearchSort::Date => "date",
+        }
+    }
+
+    /// ORDER BY expression; `rank` is only available on full-text queries.
+    /// Every mode ends with unique columns so pages don't overlap.
+    fn order_by(self, full_text: bool) -> &'static str {
+        match self {
+            SearchSort::Relevance if full_text => "rank, f.id",
+            SearchSort::Relevance | SearchSort::Path => "f.rel_path, f.disc_id, f.id",
+            SearchSort::Size => "f.size DESC, f.rel_path, f.id",
+            SearchSort::DiscId => "f.disc_id, f.rel_path, f.id",
+            SearchSort::Date => "f.mtime DESC, f.rel_path, f.id",
+        }
+    }
+}
+
+/// One end of a size filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeBound {
+    /// Matches files of exactly this size too (`>=`, `<=`)
+    Inclusive(u64),
+    /// Excludes files of exactly this size (`>`, `<`)
+    Exclusive(u64),
+}
+
+impl SizeBound {
+    fn bytes(self) -> u64 {
+        match self {
+            SizeBound::Inclusive(bytes) | SizeBound::Exclusive(bytes) => bytes,
+        }
+    }
+}
 
 /// Map a database row to SearchResult.
 fn map_row(row: &Row) -> rusqlite::Result<SearchResult> {
@@ -9,16 +84,50 @@ fn map_row(row: &Row) -> rusqlite::Result<SearchResult> {
         size: row.get(2)?,
         mtime: row.get(3)?,
         sha256: row.get(4)?,
+        set_id: row.get(5)?,
+        storage_location: row.get(6)?,
+        planned: row.get(7)?,
     })
 }
 
 /// Search query parameters.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SearchQuery {
     pub path_substring: Option<String>,
     pub exact_filename: Option<String>,
     pub sha256: Option<String>,
     pub regex: Option<String>, // Nice-to-have, not implemented yet
+    /// FTS5 match expression (ranked; supports `"phrase"` and `prefix*`).
+    /// Falls back to a LIKE search when FTS5 is unavailable.
+    pub full_text: Option<String>,
+    /// Minimum file size in bytes
+    pub min_size: Option<SizeBound>,
+    /// Maximum file size in bytes
+    pub max_size: Option<SizeBound>,
+    /// Only discs created on or after this date (ISO 8601 prefix, e.g. 2024-01-01)
+    pub created_after: Option<String>,
+    /// Only discs created before this date (ISO 8601 prefix)
+    pub created_before: Option<String>,
+    /// Only discs belonging to this multi-disc set
+    pub set_id: Option<String>,
+    /// Only discs carrying every one of these tags (normalized, without `#`)
+    pub tags: Vec<String>,
+    /// Result order
+    pub sort: SearchSort,
+    /// Maximum number of results (defaults to 1000)
+    pub limit: Option<u32>,
+    /// Number of results to skip, for paging
+    pub offset: u32,
+}
+
+impl SearchQuery {
+    /// LIMIT and OFFSET parameters for the SQL query.
+    fn page_params(&self) -> [Value; 2] {
+        [
+            Value::Integer(self.limit.unwrap_or(DEFAULT_LIMIT) as i64),
+            Value::Integer(self.offset as i64),
+        ]
+    }
 }
 
 /// Search result.
@@ -29,42 +138,71 @@ pub struct SearchResult {
     pub size: u64,
     pub mtime: String,
     pub sha256: String,
+    /// Multi-disc set the disc belongs to, if any
+    pub set_id: Option<String>,
+    /// Where the disc holding the file is stored, if recorded
+    pub storage_location: Option<String>,
+    /// The disc is a dry-run plan, not burned media
+    pub planned: bool,
 }
 
 /// Search files in the database.
 pub fn search_files(conn: &Connection, query: &SearchQuery) -> Result<Vec<SearchResult>> {
-    let mut results = Vec::new();
+    if query.sha256.is_none() {
+        if let Some(ref full_text) = query.full_text {
+            if crate::database::fts_available(conn) {
+                match search_full_text(conn, full_text, query) {
+                    Ok(results) => return Ok(results),
+                    // Malformed MATCH expressions fall through to LIKE
+                    Err(e) => debug!("Full-text search failed, using LIKE: {}", e),
+                }
+            }
+            let fallback = SearchQuery {
+                path_substring: Some(fts_fallback_pattern(full_text)),
+                full_text: None,
+                ..query.clone()
+            };
+            return search_files(conn, &fallback);
+        }
+    }
+
+    // Build WHERE clauses and parameters based on search criteria
+    let mut conditions = Vec::new();
+    let mut params: Vec<Value> = Vec::new();
 
-    // Build SQL query and parameters based on search criteria
-    let (sql, param): (String, Option<String>) = if let Some(ref sha256) = query.sha256 {
+    if let Some(ref sha256) = query.sha256 {
         // SHA256 search (exact match)
-        let sql = "SELECT disc_id, rel_path, size, mtime, sha256 FROM files WHERE sha256 = ?1 ORDER BY rel_path LIMIT 1000";
-        (sql.to_string(), Some(sha256.clone()))
+        conditions.push("f.sha256 = ?".to_string());
+        params.push(Value::Text(sha256.clone()));
     } else if let Some(ref path_substring) = query.path_substring {
         // Path substring search
-        let pattern = format!("%{}%", path_substring);
-        let sql = "SELECT disc_id, rel_path, size, mtime, sha256 FROM files WHERE rel_path LIKE ?1 ORDER BY rel_path LIMIT 1000";
-        (sql.to_string(), Some(pattern))
+        conditions.push("f.rel_path LIKE ?".to_string());
+        params.push(Value::Text(format!("%{}%", path_substring)));
     } else if let Some(ref exact_filename) = query.exact_filename {
         // Exact filename search
-        let pattern = format!("%/{}", exact_filename);
-        let sql = "SELECT disc_id, rel_path, size, mtime, sha256 FROM files WHERE rel_path LIKE ?1 ORDER BY rel_path LIMIT 1000";
-        (sql.to_string(), Some(pattern))
-    } else {
-        // No filters, return all
-        let sql =
-            "SELECT disc_id, rel_path, size, mtime, sha256 FROM files ORDER BY rel_path LIMIT 1000";
-        (sql.to_string(), None)
-    };
+        conditions.push("f.rel_path LIKE ?".to_string());
+        params.push(Value::Text(format!("%/{}", exact_filename)));
+    }
 
-    let mut stmt = conn.prepare(&sql)?;
+    push_filter_conditions(query, &mut conditions, &mut params);
 
-    let rows: rusqlite::MappedRows<_> = if let Some(p) = param {
-        stmt.query_map(rusqlite::params![p], map_row)?
+    let where_clause = if conditions.is_empty() {
+        String::new()
     } else {
-        stmt.query_map([], map_row)?
+        format!("WHERE {}", conditions.join(" AND "))
     };
+    let sql = format!(
+        "SELECT {} FROM files f JOIN discs d ON d.disc_id = f.disc_id {} ORDER BY {} LIMIT ? OFFSET ?",
+        RESULT_COLUMNS,
+        where_clause,
+        query.sort.order_by(false)
+    );
+    params.extend(query.page_params());
 
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(rusqlite::params_from_iter(params), map_row)?;
+
+    let mut results = Vec::new();
     for row in rows {
         results.push(row?);
     }
@@ -72,6 +210,179 @@ pub fn search_files(conn: &Connection, query: &SearchQuery) -> Result<Vec<Search
     Ok(results)
 }
 
+/// Append size, date, set and tag filters as SQL conditions.
+fn push_filter_conditions(query: &SearchQuery, conditions: &mut Vec<String>, params: &mut Vec<Value>) {
+    if let Some(min_size) = query.min_size {
+        let op = if matches!(min_size, SizeBound::Inclusive(_)) { ">=" } else { ">" };
+        conditions.push(format!("f.size {} ?", op));
+        params.push(Value::Integer(min_size.bytes() as i64));
+    }
+    if let Some(max_size) = query.max_size {
+        let op = if matches!(max_size, SizeBound::Inclusive(_)) { "<=" } else { "<" };
+        conditions.push(format!("f.size {} ?", op));
+        params.push(Value::Integer(max_size.bytes() as i64));
+    }
+    if let Some(ref after) = query.created_after {
+        conditions.push("d.created_at >= ?".to_string());
+        params.push(Value::Text(after.clone()));
+    }
+    if let Some(ref before) = query.created_before {
+        conditions.push("d.created_at < ?".to_string());
+        params.push(Value::Text(before.clone()));
+    }
+    if let Some(ref set_id) = query.set_id {
+        conditions.push("d.set_id = ?".to_string());
+        params.push(Value::Text(set_id.clone()));
+    }
+    for tag in &query.tags {
+        conditions.push(
+            "d.disc_id IN (SELECT dt.disc_id FROM disc_tags dt JOIN tags t ON t.id = dt.tag_id WHERE t.name = ?)"
+                .to_string(),
+        );
+        params.push(Value::Text(tag.clone()));
+    }
+}
+
+/// Ranked full-text search over file paths using the FTS5 index.
+fn search_full_text(conn: &Connection, expression: &str, query: &SearchQuery) -> Result<Vec<SearchResult>> {
+    let mut conditions = vec!["files_fts MATCH ?".to_string()];
+    let mut params = vec![Value::Text(expression.to_string())];
+    push_filter_conditions(query, &mut conditions, &mut params);
+
+    let sql = format!(
+        "SELECT {}
+         FROM files_fts
+         JOIN files f ON f.id = files_fts.rowid
+         JOIN discs d ON d.disc_id = f.disc_id
+         WHERE {}
+         ORDER BY {}
+         LIMIT ? OFFSET ?",
+        RESULT_COLUMNS,
+        conditions.join(" AND "),
+        query.sort.order_by(true)
+    );
+    params.extend(query.page_params());
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(rusqlite::params_from_iter(params), map_row)?;
+    let mut results = Vec::new();
+    for row in rows {
+        results.push(row?);
+    }
+    Ok(results)
+}
+
+/// Apply a `key:value` filter token (`size:>1GB`, `before:2024-01-01`,
+/// `after:2023-06-01`, `set:SET-xyz`, `tag:photos`) or a `#tag` to the query.
+/// Returns false if the token is not a recognised filter.
+pub fn apply_filter_token(token: &str, query: &mut SearchQuery) -> bool {
+    if token.starts_with('#') {
+        return add_tag_filter(token, query);
+    }
+    let Some((key, value)) = token.split_once(':') else {
+        return false;
+    };
+    if value.is_empty() {
+        return false;
+    }
+
+    match key.to_lowercase().as_str() {
+        "size" => {
+            let (op, amount) = if let Some(rest) = value.strip_prefix(">=") {
+                (">=", rest)
+            } else if let Some(rest) = value.strip_prefix("<=") {
+                ("<=", rest)
+            } else if let Some(rest) = value.strip_prefix('>') {
+                (">", rest)
+            } else if let Some(rest) = value.strip_prefix('<') {
+                ("<", rest)
+            } else {
+                return false;
+            };
+            let Some(bytes) = parse_size(amount) else {
+                return false;
+            };
+            match op {
+                ">=" => query.min_size = Some(SizeBound::Inclusive(bytes)),
+                ">" => query.min_size = Some(SizeBound::Exclusive(bytes)),
+                "<=" => query.max_size = Some(SizeBound::Inclusive(bytes)),
+                _ => query.max_size = Some(SizeBound::Exclusive(bytes)),
+            }
+            true
+        }
+        "before" if is_date(value) => {
+            query.created_before = Some(value.to_string());
+            true
+        }
+        "after" if is_date(value) => {
+            query.created_after = Some(value.to_string());
+            true
+        }
+        "set" => {
+            query.set_id = Some(value.to_string());
+            true
+        }
+        "tag" => add_tag_filter(value, query),
+        _ => false,
+    }
+}
+
+fn add_tag_filter(tag: &str, query: &mut SearchQuery) -> bool {
+    match crate::database::normalize_tag(tag) {
+        Some(tag) => {
+            if !query.tags.contains(&tag) {
+                query.tags.push(tag);
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// Parse a human-readable size such as `500MB`, `1.5GB` or `1024` into bytes.
+/// Units are 1024-based to match `format_size`.
+pub fn parse_size(text: &str) -> Option<u64> {
+    let text = text.trim().to_uppercase();
+    let split_at = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split_at);
+    let number: f64 = number.parse().ok()?;
+    let multiplier: u64 = match unit.trim() {
+        "" | "B" => 1,
+        "K" | "KB" => 1024,
+        "M" | "MB" => 1024 * 1024,
+        "G" | "GB" => 1024 * 1024 * 1024,
+        "T" | "TB" => 1024 * 1024 * 1024 * 1024,
+        _ => return None,
+    };
+    Some((number * multiplier as f64) as u64)
+}
+
+/// Check for a `YYYY-MM-DD` date.
+fn is_date(text: &str) -> bool {
+    let parts: Vec<&str> = text.split('-').collect();
+    parts.len() == 3
+        && parts[0].len() == 4
+        && parts[1].len() == 2
+        && parts[2].len() == 2
+        && parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Turn an FTS5 expression into a plain substring for the LIKE fallback.
+fn fts_fallback_pattern(expression: &str) -> String {
+    expression
+        .chars()
+        .filter(|c| *c != '"' && *c != '*')
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Whether a query string uses FTS5 syntax (phrases or prefixes).
+pub fn looks_like_fts_query(query: &str) -> bool {
+    query.contains('"') || query.split_whitespace().any(|term| term.ends_with('*'))
+}
+
 /// Format file size for display.
 pub fn format_size(bytes: u64) -> String {
     const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
@@ -90,12 +401,42 @@ pub fn format_size(bytes: u64) -> String {
     }
 }
 
+/// Format a size in decimal gigabytes, the unit disc capacities are sold in.
+/// Use this wherever a size is compared against disc capacity.
+pub fn format_bytes_gb(bytes: u64) -> String {
+    format!("{:.1} GB", bytes as f64 / BYTES_PER_GB as f64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::database;
     use tempfile::TempDir;
 
+    /// Catalog holding disc 2024-BD-001 with a single 100-byte file,
+    /// ARCHIVE/test/file.txt
+    fn catalog_with_file(temp_dir: &TempDir) -> Result<Connection> {
+        let mut conn = database::init_database(&temp_dir.path().join("test.db"))?;
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('2024-BD-001', 'TEST_DISC', '2024-01-01T00:00:00Z')",
+            [],
+        )?;
+        crate::database::FileRecord::insert(
+            &mut conn,
+            &crate::database::FileRecord {
+                id: None,
+                disc_id: "2024-BD-001".to_string(),
+                rel_path: "ARCHIVE/test/file.txt".to_string(),
+                sha256: "abc123".to_string(),
+                size: 100,
+                mtime: "2024-01-01T00:00:00Z".to_string(),
+                added_at: "2024-01-01T00:00:00Z".to_string(),
+                crc32: None,
+            },
+        )?;
+        Ok(conn)
+    }
+
     #[test]
     fn test_search_files() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -116,6 +457,11 @@ mod tests {
             tool_version: None,
             set_id: None,
             sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
         };
         crate::database::Disc::insert(&mut conn, &disc)?;
 
@@ -128,6 +474,7 @@ mod tests {
             size: 100,
             mtime: "2024-01-01T00:00:00Z".to_string(),
             added_at: "2024-01-01T00:00:00Z".to_string(),
+            crc32: None,
         };
 
         crate::database::FileRecord::insert(&mut conn, &file)?;
@@ -138,6 +485,7 @@ mod tests {
             exact_filename: None,
             sha256: None,
             regex: None,
+            ..Default::default()
         };
 
         let results = search_files(&conn, &query)?;
@@ -147,6 +495,204 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_search_tag_filter() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = catalog_with_file(&temp_dir)?;
+        crate::database::Disc::add_tag(&conn, "2024-BD-001", "photos")?;
+        crate::database::Disc::add_tag(&conn, "2024-BD-001", "offsite")?;
+        let tagged = |tags: &[&str]| SearchQuery {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        };
+
+        // Every tag must be present on the disc
+        assert_eq!(search_files(&conn, &tagged(&["photos"]))?.len(), 1);
+        assert_eq!(search_files(&conn, &tagged(&["photos", "offsite"]))?.len(), 1);
+        assert!(search_files(&conn, &tagged(&["photos", "2023"]))?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_full_text() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = catalog_with_file(&temp_dir)?;
+        let full_text = |expr: &str| SearchQuery {
+            full_text: Some(expr.to_string()),
+            ..Default::default()
+        };
+
+        // Prefix and phrase queries
+        assert_eq!(search_files(&conn, &full_text("fil*"))?.len(), 1);
+        assert_eq!(search_files(&conn, &full_text("\"test file\""))?.len(), 1);
+        assert!(search_files(&conn, &full_text("missing"))?.is_empty());
+
+        // Malformed expressions fall back to LIKE
+        assert_eq!(search_files(&conn, &full_text("\"test"))?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_filters() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = catalog_with_file(&temp_dir)?;
+        let query = SearchQuery {
+            path_substring: Some("test".to_string()),
+            min_size: Some(SizeBound::Inclusive(101)),
+            ..Default::default()
+        };
+        assert!(search_files(&conn, &query)?.is_empty());
+
+        // Size operators at exactly the file size (100 bytes)
+        let sized = |token: &str| -> Result<usize> {
+            let mut query = SearchQuery::default();
+            assert!(apply_filter_token(token, &mut query));
+            Ok(search_files(&conn, &query)?.len())
+        };
+        assert_eq!(sized("size:>=100")?, 1);
+        assert_eq!(sized("size:<=100")?, 1);
+        assert_eq!(sized("size:>100")?, 0);
+        assert_eq!(sized("size:<100")?, 0);
+
+        let query = SearchQuery {
+            created_after: Some("2023-12-01".to_string()),
+            created_before: Some("2024-02-01".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(search_files(&conn, &query)?.len(), 1);
+
+        let query = SearchQuery {
+            created_after: Some("2024-02-01".to_string()),
+            ..Default::default()
+        };
+        assert!(search_files(&conn, &query)?.is_empty());
+
+        let query = SearchQuery {
+            set_id: Some("SET-missing".to_string()),
+            ..Default::default()
+        };
+        assert!(search_files(&conn, &query)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_storage_location() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = catalog_with_file(&temp_dir)?;
+        let query = SearchQuery {
+            path_substring: Some("test".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(search_files(&conn, &query)?[0].storage_location, None);
+
+        crate::database::Disc::set_storage_location(&conn, "2024-BD-001", Some("Offsite box 3"))?;
+        let results = search_files(&conn, &query)?;
+        assert_eq!(results[0].storage_location.as_deref(), Some("Offsite box 3"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_sort_and_paging() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let conn = database::init_database(&temp_dir.path().join("test.db"))?;
+        for disc_id in ["DISC-A", "DISC-B"] {
+            conn.execute(
+                "INSERT INTO discs (disc_id, volume_label, created_at) VALUES (?1, ?1, '2024-01-01T00:00:00Z')",
+                [disc_id],
+            )?;
+        }
+        let files = [
+            ("DISC-B", "ARCHIVE/a.txt", 300, "2024-01-03T00:00:00Z"),
+            ("DISC-A", "ARCHIVE/b.txt", 100, "2024-01-01T00:00:00Z"),
+            ("DISC-A", "ARCHIVE/c.txt", 500, "2024-01-02T00:00:00Z"),
+            ("DISC-B", "ARCHIVE/d.txt", 200, "2024-01-05T00:00:00Z"),
+            ("DISC-A", "ARCHIVE/e.txt", 400, "2024-01-04T00:00:00Z"),
+        ];
+        for (disc_id, rel_path, size, mtime) in files {
+            conn.execute(
+                "INSERT INTO files (disc_id, rel_path, sha256, size, mtime, added_at) VALUES (?1, ?2, 'x', ?3, ?4, ?4)",
+                rusqlite::params![disc_id, rel_path, size, mtime],
+            )?;
+        }
+
+        let paths = |query: &SearchQuery| -> Result<Vec<String>> {
+            Ok(search_files(&conn, query)?.into_iter().map(|r| r.rel_path).collect())
+        };
+        let query = |sort, limit, offset| SearchQuery {
+            path_substring: Some(".txt".to_string()),
+            sort,
+            limit,
+            offset,
+            ..Default::default()
+        };
+
+        assert_eq!(paths(&query(SearchSort::Path, None, 0))?, ["ARCHIVE/a.txt", "ARCHIVE/b.txt", "ARCHIVE/c.txt", "ARCHIVE/d.txt", "ARCHIVE/e.txt"]);
+        assert_eq!(paths(&query(SearchSort::Size, Some(2), 0))?, ["ARCHIVE/c.txt", "ARCHIVE/e.txt"]);
+        assert_eq!(paths(&query(SearchSort::Size, Some(2), 2))?, ["ARCHIVE/a.txt", "ARCHIVE/d.txt"]);
+        assert_eq!(paths(&query(SearchSort::Size, Some(2), 4))?, ["ARCHIVE/b.txt"]);
+        assert_eq!(paths(&query(SearchSort::DiscId, Some(3), 0))?, ["ARCHIVE/b.txt", "ARCHIVE/c.txt", "ARCHIVE/e.txt"]);
+        assert_eq!(paths(&query(SearchSort::Date, Some(1), 0))?, ["ARCHIVE/d.txt"]);
+
+        // Full-text queries page and sort the same way
+        let full_text = SearchQuery {
+            full_text: Some("txt*".to_string()),
+            sort: SearchSort::Size,
+            limit: Some(1),
+            offset: 1,
+            ..Default::default()
+        };
+        assert_eq!(paths(&full_text)?, ["ARCHIVE/e.txt"]);
+
+        let mut sort = SearchSort::default();
+        for _ in 0..5 {
+            sort = sort.next();
+        }
+        assert_eq!(sort, SearchSort::Relevance);
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_filter_token() {
+        let mut query = SearchQuery::default();
+        assert!(apply_filter_token("size:>1GB", &mut query));
+        assert!(apply_filter_token("size:<=500MB", &mut query));
+        assert!(apply_filter_token("before:2024-01-01", &mut query));
+        assert!(apply_filter_token("after:2023-01-01", &mut query));
+        assert!(apply_filter_token("set:SET-xyz", &mut query));
+        assert_eq!(query.min_size, Some(SizeBound::Exclusive(1024 * 1024 * 1024)));
+        assert_eq!(query.max_size, Some(SizeBound::Inclusive(500 * 1024 * 1024)));
+        assert_eq!(query.created_before.as_deref(), Some("2024-01-01"));
+        assert_eq!(query.created_after.as_deref(), Some("2023-01-01"));
+        assert_eq!(query.set_id.as_deref(), Some("SET-xyz"));
+
+        assert!(apply_filter_token("#Photos", &mut query));
+        assert!(apply_filter_token("tag:offsite", &mut query));
+        assert!(apply_filter_token("#photos", &mut query));
+        assert_eq!(query.tags, ["photos", "offsite"]);
+        assert!(!apply_filter_token("#", &mut query));
+
+        assert!(!apply_filter_token("photos", &mut query));
+        assert!(!apply_filter_token("before:yesterday", &mut query));
+        assert!(!apply_filter_token("size:huge", &mut query));
+
+        assert!(apply_filter_token("size:>=2KB", &mut query));
+        assert!(apply_filter_token("size:<1KB", &mut query));
+        assert_eq!(query.min_size, Some(SizeBound::Inclusive(2048)));
+        assert_eq!(query.max_size, Some(SizeBound::Exclusive(1024)));
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("1024"), Some(1024));
+        assert_eq!(parse_size("2KB"), Some(2048));
+        assert_eq!(parse_size("1.5g"), Some(1024 * 1024 * 1536));
+        assert_eq!(parse_size("GB"), None);
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(format_size(0), "0 B");
@@ -154,4 +700,14 @@ mod tests {
         assert_eq!(format_size(1048576), "1.00 MB");
         assert_eq!(format_size(1073741824), "1.00 GB");
     }
+
+    #[test]
+    fn test_format_bytes_gb() {
+        // A single-layer BD-R holds 25,025,314,816 bytes
+        assert_eq!(format_bytes_gb(25_025_314_816), "25.0 GB");
+        assert_eq!(format_bytes_gb(100_103_356_416), "100.1 GB");
+        assert_eq!(format_bytes_gb(0), "0.0 GB");
+        // 25 GiB is more than a BD-25 holds, and must not display as 25 GB
+        assert_eq!(format_bytes_gb(25 * 1024 * 1024 * 1024), "26.8 GB");
+    }
 }
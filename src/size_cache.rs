@@ -0,0 +1,195 @@
+//! Cache of directory listings for layout planning.
+//!
+//! Planning walks every source directory and stats every file in it, which is
+//! slow for large trees. The cache keeps each directory's entries with their
+//! sizes, keyed by the directory's path and modification time, so re-planning
+//! an unchanged source only stats its directories. A directory's mtime changes
+//! when entries are added, removed or renamed, but not when a file in it is
+//! rewritten in place; such size changes are caught by the capacity check on
+//! the built image.
+
+use crate::staging::WalkOptions;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+use tracing::debug;
+
+/// Cache file in the data directory
+pub const SIZE_CACHE_FILE: &str = "size_cache.json";
+
+/// Modification time as whole seconds and nanoseconds since the epoch.
+pub type MtimeKey = (u64, u32);
+
+/// The entries of one directory as the planner classified them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedDir {
+    pub mtime: MtimeKey,
+    /// Files and preserved symlinks, with their sizes
+    pub files: Vec<(String, u64)>,
+    /// Subdirectories to descend into
+    pub dirs: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// Walk options the listings were made with
+    walk: String,
+    dirs: HashMap<PathBuf, CachedDir>,
+}
+
+/// Directory listings shared by the threads of one planning run.
+#[derive(Debug)]
+pub struct SizeCache {
+    /// Where the cache is saved; None keeps it in memory only
+    path: Option<PathBuf>,
+    walk: String,
+    dirs: Mutex<HashMap<PathBuf, CachedDir>>,
+    /// Directories looked up or stored during this run
+    seen: Mutex<HashSet<PathBuf>>,
+    changed: AtomicBool,
+}
+
+/// Cache key for a directory's metadata, if the platform reports an mtime.
+pub fn mtime_key(metadata: &fs::Metadata) -> Option<MtimeKey> {
+    let since_epoch = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some((since_epoch.as_secs(), since_epoch.subsec_nanos()))
+}
+
+fn walk_key(walk_options: WalkOptions) -> String {
+    format!("{:?}", walk_options)
+}
+
+impl SizeCache {
+    /// A cache that is never saved.
+    pub fn in_memory(walk_options: WalkOptions) -> Self {
+        Self {
+            path: None,
+            walk: walk_key(walk_options),
+            dirs: Mutex::new(HashMap::new()),
+            seen: Mutex::new(HashSet::new()),
+            changed: AtomicBool::new(false),
+        }
+    }
+
+    /// Load the cache saved at `path`. A missing or unreadable file, or one
+    /// made with other walk options, gives an empty cache.
+    pub fn load(path: &Path, walk_options: WalkOptions) -> Self {
+        let mut cache = Self::in_memory(walk_options);
+        cache.path = Some(path.to_path_buf());
+        match fs::read_to_string(path).map(|text| serde_json::from_str::<CacheFile>(&text)) {
+            Ok(Ok(file)) if file.walk == cache.walk => {
+                debug!("Loaded {} cached directory listings", file.dirs.len());
+                cache.dirs = Mutex::new(file.dirs);
+            }
+            Ok(Ok(_)) => debug!("Size cache was made with other walk options; starting afresh"),
+            Ok(Err(e)) => debug!("Ignoring unreadable size cache {}: {}", path.display(), e),
+            Err(_) => {}
+        }
+        cache
+    }
+
+    /// The cache in the data directory, or an in-memory one when the data
+    /// directory can't be found.
+    pub fn open_default(walk_options: WalkOptions) -> Self {
+        match crate::paths::data_dir() {
+            Ok(dir) => Self::load(&dir.join(SIZE_CACHE_FILE), walk_options),
+            Err(_) => Self::in_memory(walk_options),
+        }
+    }
+
+    /// The listing of `dir` if it was cached with modification time `mtime`.
+    pub fn get(&self, dir: &Path, mtime: MtimeKey) -> Option<CachedDir> {
+        self.seen.lock().unwrap_or_else(|e| e.into_inner()).insert(dir.to_path_buf());
+        let dirs = self.dirs.lock().unwrap_or_else(|e| e.into_inner());
+        dirs.get(dir).filter(|cached| cached.mtime == mtime).cloned()
+    }
+
+    pub fn insert(&self, dir: &Path, listing: CachedDir) {
+        self.seen.lock().unwrap_or_else(|e| e.into_inner()).insert(dir.to_path_buf());
+        self.dirs.lock().unwrap_or_else(|e| e.into_inner()).insert(dir.to_path_buf(), listing);
+        self.changed.store(true, Ordering::Relaxed);
+    }
+
+    /// Number of cached directories.
+    pub fn len(&self) -> usize {
+        self.dirs.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Save the cache, first dropping directories below `roots` that this run
+    /// didn't come across (deleted or renamed since). Listings of other
+    /// sources are kept for when they are planned again.
+    pub fn save(&self, roots: &[PathBuf]) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        let mut dirs = self.dirs.lock().unwrap_or_else(|e| e.into_inner());
+        let before = dirs.len();
+        dirs.retain(|dir, _| seen.contains(dir) || !roots.iter().any(|root| dir.starts_with(root)));
+        if dirs.len() == before && !self.changed.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let file = CacheFile {
+            walk: self.walk.clone(),
+            dirs: std::mem::take(&mut *dirs),
+        };
+        let written = serde_json::to_string(&file)
+            .context("Failed to serialize size cache")
+            .and_then(|json| {
+                let tmp = path.with_extension("json.tmp");
+                fs::write(&tmp, json).with_context(|| format!("Failed to write {}", tmp.display()))?;
+                fs::rename(&tmp, path).with_context(|| format!("Failed to replace {}", path.display()))
+            });
+        *dirs = file.dirs;
+        self.changed.store(false, Ordering::Relaxed);
+        written
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SymlinkPolicy;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_size_cache_round_trip() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join(SIZE_CACHE_FILE);
+        let walk = WalkOptions::from(SymlinkPolicy::Follow);
+        let listing = |mtime| CachedDir {
+            mtime,
+            files: vec![("a.txt".to_string(), 5)],
+            dirs: vec!["sub".to_string()],
+        };
+
+        let cache = SizeCache::load(&path, walk);
+        assert!(cache.is_empty());
+        cache.insert(Path::new("/src/photos"), listing((10, 0)));
+        cache.insert(Path::new("/src/gone"), listing((10, 0)));
+        cache.save(&[])?;
+
+        let cache = SizeCache::load(&path, walk);
+        assert_eq!(cache.get(Path::new("/src/photos"), (10, 0)), Some(listing((10, 0))));
+        assert_eq!(cache.get(Path::new("/src/photos"), (11, 0)), None);
+        // Directories under a planned root that weren't seen are dropped
+        cache.save(&[PathBuf::from("/src")])?;
+        let cache = SizeCache::load(&path, walk);
+        assert_eq!(cache.len(), 1);
+
+        // Listings made with other walk options are not used
+        let cache = SizeCache::load(&path, WalkOptions::from(SymlinkPolicy::Skip));
+        assert!(cache.is_empty());
+        Ok(())
+    }
+}
@@ -1,8 +1,968 @@
+use crate::config::{CleanupConfig, StagingConfig, SymlinkPolicy};
+use crate::size_cache::{self, CachedDir, MtimeKey, SizeCache};
 use anyhow::{Context, Result};
+use filetime::FileTime;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
 use std::fs;
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
+/// Callback receiving human-readable staging progress messages.
+pub type ProgressCallback = Box<dyn FnMut(&str) + Send>;
+
+/// Delay before the first retry; doubled for each further attempt.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+/// Upper bound for a single retry delay.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(8);
+
+/// Run `op`, retrying transient I/O failures up to `max_retries` times with
+/// exponential backoff. Missing files and permission errors fail immediately.
+pub fn with_retries<T>(max_retries: u32, what: &str, op: impl FnMut() -> Result<T>) -> Result<T> {
+    retry_with_backoff(max_retries, RETRY_BASE_DELAY, what, op)
+}
+
+fn retry_with_backoff<T>(
+    max_retries: u32,
+    base_delay: Duration,
+    what: &str,
+    mut op: impl FnMut() -> Result<T>,
+) -> Result<T> {
+    let mut attempt = 0;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt < max_retries && is_transient(&e) => {
+                attempt += 1;
+                let delay = (base_delay * 2u32.saturating_pow(attempt - 1)).min(RETRY_MAX_DELAY);
+                warn!(
+                    "{} failed (retry {}/{} in {:?}): {:#}",
+                    what, attempt, max_retries, delay, e
+                );
+                std::thread::sleep(delay);
+            }
+            Err(e) if attempt > 0 => {
+                return Err(e.context(format!("{} still failing after {} retries", what, attempt)));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Whether an error is worth retrying: any I/O error except ones that
+/// won't go away by waiting (missing file, no permission, bad input).
+fn is_transient(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<io::Error>())
+        .is_some_and(|e| {
+            !matches!(
+                e.kind(),
+                io::ErrorKind::NotFound
+                    | io::ErrorKind::PermissionDenied
+                    | io::ErrorKind::AlreadyExists
+                    | io::ErrorKind::InvalidInput
+            )
+        })
+}
+
+/// `fs::copy` with retries for transient errors. With a throttle the file is
+/// copied in chunks, pausing as needed to stay under its rate. With a ledger
+/// the bytes read are hashed on the way through and recorded for the
+/// post-copy check.
+fn copy_file_with_retries(
+    src: &Path,
+    dst: &Path,
+    max_retries: u32,
+    mut throttle: Option<&mut ReadThrottle>,
+    ledger: Option<&mut CopyLedger>,
+) -> Result<u64> {
+    let hashing = ledger.is_some();
+    let (copied, sha256) = with_retries(max_retries, &format!("Copying {}", src.display()), || {
+        if throttle.is_none() && !hashing {
+            return fs::copy(src, dst)
+                .map(|copied| (copied, None))
+                .with_context(|| format!("Failed to copy file: {} -> {}", src.display(), dst.display()));
+        }
+        let mut hasher = hashing.then(Sha256::new);
+        let copied = copy_file_chunked(src, dst, throttle.as_deref_mut(), hasher.as_mut())
+            .with_context(|| format!("Failed to copy file: {} -> {}", src.display(), dst.display()))?;
+        Ok((copied, hasher.map(|h| hex::encode(h.finalize()))))
+    })?;
+    if let (Some(ledger), Some(sha256)) = (ledger, sha256) {
+        ledger.record(src, dst, sha256);
+    }
+    Ok(copied)
+}
+
+/// Copy one file into staging and carry over its metadata. With
+/// `staging.resume`, a copy left by an interrupted run is kept when its size
+/// and modification time match the source; the time is only set once a copy
+/// is complete, so half-written files are copied again. Returns the bytes the
+/// staged file holds and whether an existing copy was kept.
+fn stage_file(
+    src: &Path,
+    dst: &Path,
+    options: &StagingConfig,
+    throttle: Option<&mut ReadThrottle>,
+    ledger: Option<&mut CopyLedger>,
+) -> Result<(u64, bool)> {
+    if options.resume {
+        if let Some(size) = already_staged(src, dst) {
+            debug!("Already staged, keeping: {}", dst.display());
+            if let Some(ledger) = ledger {
+                // Still checked against the source, as the earlier run never got to it
+                let sha256 = crate::manifest::calculate_sha256(src)
+                    .with_context(|| format!("Failed to hash source file: {}", src.display()))?;
+                ledger.record(src, dst, sha256);
+            }
+            return Ok((size, true));
+        }
+    }
+    let copied = copy_file_with_retries(src, dst, options.max_retries, throttle, ledger)?;
+    preserve_metadata(src, dst, options)?;
+    Ok((copied, false))
+}
+
+/// Report at the disc root listing source files that could not be read
+pub const FAILED_FILES_FILE: &str = "FAILED_FILES.txt";
+
+/// Source files skipped during a staging run with `staging.skip_unreadable`,
+/// as `(source path, error)` pairs
+#[derive(Debug, Default)]
+pub struct FailedFiles(std::sync::Mutex<Vec<(PathBuf, String)>>);
+
+impl FailedFiles {
+    fn record(&self, path: &Path, error: &anyhow::Error) {
+        warn!("Skipping unreadable file {}: {:#}", path.display(), error);
+        let mut files = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        files.push((path.to_path_buf(), format!("{:#}", error)));
+    }
+
+    /// The files recorded so far, leaving the list empty.
+    pub fn take(&self) -> Vec<(PathBuf, String)> {
+        std::mem::take(&mut *self.0.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+/// Write the [`FAILED_FILES_FILE`] report, one `path<TAB>error` line per file.
+pub fn write_failed_files(path: &Path, files: &[(PathBuf, String)]) -> Result<()> {
+    let mut report = String::from("# Source files that could not be read and are NOT on this disc\n");
+    for (source, error) in files {
+        report.push_str(&format!("{}\t{}\n", source.display(), error.replace('\n', " ")));
+    }
+    fs::write(path, report).with_context(|| format!("Failed to write failed files report: {}", path.display()))
+}
+
+/// Whether a copy failed on the staging side (full or read-only disk) rather
+/// than reading the source; those are never skipped.
+fn is_destination_error(error: &anyhow::Error) -> bool {
+    const ENOSPC: i32 = 28;
+    const EROFS: i32 = 30;
+    const EDQUOT: i32 = 122;
+    error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<io::Error>())
+        .and_then(io::Error::raw_os_error)
+        .is_some_and(|code| matches!(code, ENOSPC | EROFS | EDQUOT))
+}
+
+/// [`stage_file`], except that with `failed` a source file that can't be
+/// read is recorded there and skipped. Returns `None` for a skipped file.
+fn stage_file_or_skip(
+    src: &Path,
+    dst: &Path,
+    options: &StagingConfig,
+    throttle: Option<&mut ReadThrottle>,
+    ledger: Option<&mut CopyLedger>,
+    failed: Option<&FailedFiles>,
+) -> Result<Option<(u64, bool)>> {
+    match (stage_file(src, dst, options, throttle, ledger), failed) {
+        (Ok(staged), _) => Ok(Some(staged)),
+        (Err(e), Some(failed)) if !is_destination_error(&e) => {
+            let _ = fs::remove_file(dst);
+            failed.record(src, &e);
+            Ok(None)
+        }
+        (Err(e), _) => Err(e),
+    }
+}
+
+/// Size of `dst` if it is a regular file matching `src` in size and
+/// modification time.
+fn already_staged(src: &Path, dst: &Path) -> Option<u64> {
+    let source = fs::metadata(src).ok()?;
+    let staged = fs::symlink_metadata(dst).ok()?;
+    let matches = staged.is_file()
+        && staged.len() == source.len()
+        && FileTime::from_last_modification_time(&staged) == FileTime::from_last_modification_time(&source);
+    matches.then_some(staged.len())
+}
+
+/// A run that got past staging has already renamed staged entries to Unicode
+/// NFC (see [`normalize_staged_names`]). When resuming, give such an entry
+/// back the source's spelling so it is found; normalization renames it again.
+fn reclaim_normalized_name(path: &Path) {
+    if fs::symlink_metadata(path).is_ok() {
+        return;
+    }
+    let Some(nfc) = path.file_name().and_then(crate::paths::nfc_file_name) else {
+        return;
+    };
+    let normalized = path.with_file_name(nfc);
+    if normalized != path && fs::symlink_metadata(&normalized).is_ok() {
+        if let Err(e) = fs::rename(&normalized, path) {
+            warn!("Failed to reuse staged {}: {}", normalized.display(), e);
+        }
+    }
+}
+
+/// Recreate a symlink in staging. With `staging.resume`, a link left by an
+/// interrupted run is replaced rather than failing the run.
+fn stage_link(src: &Path, target: &Path, dst: &Path, options: &StagingConfig) -> Result<()> {
+    if options.resume && fs::symlink_metadata(dst).is_ok_and(|m| m.file_type().is_symlink()) {
+        fs::remove_file(dst).with_context(|| format!("Failed to replace staged symlink: {}", dst.display()))?;
+    }
+    copy_symlink(src, target, dst)
+}
+
+/// Source hashes taken while staging (`staging.verify_after_copy`). Once
+/// staging is done the staged copies are read back and compared, catching
+/// corruption introduced by the copy itself (bad RAM, a flaky controller)
+/// before it is burned.
+#[derive(Debug, Default)]
+pub struct CopyLedger {
+    copies: Vec<StagedCopy>,
+}
+
+#[derive(Debug)]
+struct StagedCopy {
+    source: PathBuf,
+    staged: PathBuf,
+    sha256: String,
+}
+
+impl CopyLedger {
+    /// A ledger if the staging options ask for the post-copy check.
+    pub fn from_config(options: &StagingConfig) -> Option<Self> {
+        options.verify_after_copy.then(Self::default)
+    }
+
+    /// Take over the copies a worker thread recorded in its own ledger.
+    fn absorb(&mut self, other: CopyLedger) {
+        self.copies.extend(other.copies);
+    }
+
+    fn record(&mut self, source: &Path, staged: &Path, sha256: String) {
+        self.copies.push(StagedCopy {
+            source: source.to_path_buf(),
+            staged: staged.to_path_buf(),
+            sha256,
+        });
+    }
+
+    /// Hash the source of every regular file staged under `dest` from `source`.
+    /// Used after rsync, whose reads we don't see, so sources are read again.
+    fn record_tree(&mut self, source: &Path, dest: &Path) -> Result<()> {
+        for entry in walkdir::WalkDir::new(dest).sort_by_file_name() {
+            let entry = entry.context("Failed to walk the staged copy")?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let rel = entry.path().strip_prefix(dest).unwrap_or(entry.path());
+            let src = source.join(rel);
+            let sha256 = crate::manifest::calculate_sha256(&src)
+                .with_context(|| format!("Failed to hash source file: {}", src.display()))?;
+            self.record(&src, entry.path(), sha256);
+        }
+        Ok(())
+    }
+
+    /// Number of copies recorded.
+    pub fn len(&self) -> usize {
+        self.copies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.copies.is_empty()
+    }
+
+    /// Re-hash every staged copy and compare it with the hash of its source.
+    /// Fails naming each staged file that differs. Returns the number checked.
+    pub fn verify(&self, progress_callback: &mut Option<ProgressCallback>) -> Result<usize> {
+        if let Some(callback) = progress_callback.as_mut() {
+            callback(&format!("🔍 Re-reading {} staged files to compare with their sources", self.copies.len()));
+        }
+        let mut mismatches = Vec::new();
+        for copy in &self.copies {
+            let staged = crate::manifest::calculate_sha256(&copy.staged)
+                .with_context(|| format!("Failed to re-read staged file: {}", copy.staged.display()))?;
+            if staged != copy.sha256 {
+                warn!(
+                    "Staged copy differs from its source: {} (source {})",
+                    copy.staged.display(),
+                    copy.source.display()
+                );
+                mismatches.push(copy);
+            }
+        }
+        if let Some(first) = mismatches.first() {
+            let more = match mismatches.len() {
+                1 => String::new(),
+                n => format!(" and {} more", n - 1),
+            };
+            anyhow::bail!(
+                "Staged copy of {} differs from its source {}{}; staging corrupted the data (check RAM and disks) and nothing was burned",
+                first.staged.display(),
+                first.source.display(),
+                more
+            );
+        }
+        info!("Verified {} staged files against their sources", self.copies.len());
+        if let Some(callback) = progress_callback.as_mut() {
+            callback(&format!("✅ All {} staged files match their sources", self.copies.len()));
+        }
+        Ok(self.copies.len())
+    }
+}
+
+/// Bytes read per step of a throttled copy.
+const THROTTLE_CHUNK: usize = 64 * 1024;
+
+/// Token bucket limiting how fast staging reads source files
+/// (`staging.max_read_mbps`), e.g. to keep a NAS link usable while staging.
+#[derive(Debug)]
+pub struct ReadThrottle {
+    bytes_per_sec: f64,
+    /// Largest burst allowed after an idle period
+    capacity: f64,
+    /// May go negative: the debt is paid off by sleeping
+    tokens: f64,
+    last_refill: Instant,
+    started: Instant,
+    bytes_read: u64,
+}
+
+impl ReadThrottle {
+    /// Limit reads to `max_mbps` megabits per second.
+    pub fn new(max_mbps: f64) -> Self {
+        let bytes_per_sec = max_mbps * 1_000_000.0 / 8.0;
+        let capacity = (bytes_per_sec / 4.0).max(THROTTLE_CHUNK as f64);
+        let now = Instant::now();
+        Self {
+            bytes_per_sec,
+            capacity,
+            tokens: capacity,
+            last_refill: now,
+            started: now,
+            bytes_read: 0,
+        }
+    }
+
+    /// The throttle configured for staging, if any.
+    pub fn from_config(options: &StagingConfig) -> Option<Self> {
+        options.max_read_mbps.filter(|mbps| *mbps > 0.0).map(Self::new)
+    }
+
+    /// Account for `bytes` just read, sleeping if that puts us over the rate.
+    pub fn consume(&mut self, bytes: usize) {
+        let now = Instant::now();
+        let refill = now.duration_since(self.last_refill).as_secs_f64() * self.bytes_per_sec;
+        self.tokens = (self.tokens + refill).min(self.capacity) - bytes as f64;
+        self.last_refill = now;
+        self.bytes_read += bytes as u64;
+        if self.tokens < 0.0 {
+            std::thread::sleep(Duration::from_secs_f64(-self.tokens / self.bytes_per_sec));
+        }
+    }
+
+    /// Configured cap in megabits per second.
+    pub fn limit_mbps(&self) -> f64 {
+        self.bytes_per_sec * 8.0 / 1_000_000.0
+    }
+
+    /// Average read rate since the throttle was created, in megabits per second.
+    pub fn throughput_mbps(&self) -> f64 {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        if elapsed <= 0.0 {
+            return 0.0;
+        }
+        self.bytes_read as f64 * 8.0 / 1_000_000.0 / elapsed
+    }
+
+    /// Suffix for progress messages showing the effective rate against the cap.
+    fn describe(&self) -> String {
+        format!(" at {:.1} Mbit/s (cap {:.0})", self.throughput_mbps(), self.limit_mbps())
+    }
+}
+
+/// Copy `src` to `dst` in chunks, letting `throttle` pace the reads and
+/// feeding them to `hasher`.
+fn copy_file_chunked(
+    src: &Path,
+    dst: &Path,
+    mut throttle: Option<&mut ReadThrottle>,
+    mut hasher: Option<&mut Sha256>,
+) -> Result<u64> {
+    let mut reader = fs::File::open(src)?;
+    let mut writer = fs::File::create(dst)?;
+    let mut buffer = vec![0u8; THROTTLE_CHUNK];
+    let mut copied = 0u64;
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        if let Some(throttle) = throttle.as_deref_mut() {
+            throttle.consume(read);
+        }
+        if let Some(hasher) = hasher.as_deref_mut() {
+            hasher.update(&buffer[..read]);
+        }
+        writer.write_all(&buffer[..read])?;
+        copied += read as u64;
+    }
+    writer.flush()?;
+    Ok(copied)
+}
+
+/// Which source entries a walk takes in: the symlink policy and whether
+/// hidden entries (names starting with `.`) are archived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalkOptions {
+    pub symlink_policy: SymlinkPolicy,
+    pub include_hidden: bool,
+}
+
+impl From<SymlinkPolicy> for WalkOptions {
+    fn from(symlink_policy: SymlinkPolicy) -> Self {
+        Self { symlink_policy, include_hidden: true }
+    }
+}
+
+impl From<&StagingConfig> for WalkOptions {
+    fn from(options: &StagingConfig) -> Self {
+        Self {
+            symlink_policy: options.symlink_policy,
+            include_hidden: options.include_hidden,
+        }
+    }
+}
+
+/// Whether a file or directory name marks it as hidden (a dotfile).
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .map(|name| name.to_string_lossy().starts_with('.'))
+        .unwrap_or(false)
+}
+
+/// How a directory entry should be treated under the configured symlink policy.
+enum WalkEntry {
+    File,
+    Dir,
+    /// Symlink to recreate as-is, with its target
+    Link(PathBuf),
+    /// Left out of the archive, with the reason
+    Skip(&'static str),
+}
+
+/// Identity of a directory after resolving links.
+#[cfg(unix)]
+type DirId = (u64, u64);
+#[cfg(not(unix))]
+type DirId = PathBuf;
+
+fn dir_id(dir: &Path) -> Result<DirId> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let metadata = fs::metadata(dir)
+            .with_context(|| format!("Failed to read metadata: {}", dir.display()))?;
+        Ok((metadata.dev(), metadata.ino()))
+    }
+    #[cfg(not(unix))]
+    {
+        fs::canonicalize(dir).with_context(|| format!("Failed to resolve path: {}", dir.display()))
+    }
+}
+
+/// Directories between the walk root and the current entry, used to break
+/// symlink loops. Only ancestors count, so several links to the same
+/// directory from different places are all followed.
+struct AncestorDirs {
+    /// Walk path and identity of each ancestor, root first
+    stack: Vec<(PathBuf, DirId)>,
+}
+
+impl AncestorDirs {
+    fn new(root: &Path) -> Self {
+        let mut ancestors = Self { stack: Vec::new() };
+        let _ = ancestors.enter(root);
+        ancestors
+    }
+
+    /// Enter `dir`, first dropping directories the walk has left (those that
+    /// are not a prefix of its path). Returns false if `dir` resolves to one
+    /// of its own ancestors.
+    fn enter(&mut self, dir: &Path) -> Result<bool> {
+        let parent = dir.parent().unwrap_or(dir);
+        while self.stack.last().is_some_and(|(path, _)| !parent.starts_with(path)) {
+            self.stack.pop();
+        }
+        let id = dir_id(dir)?;
+        if self.stack.iter().any(|(_, ancestor)| *ancestor == id) {
+            return Ok(false);
+        }
+        self.stack.push((dir.to_path_buf(), id));
+        Ok(true)
+    }
+}
+
+/// Classify `path` without following symlinks unless the policy says so.
+fn classify_entry(path: &Path, policy: WalkOptions, ancestors: &mut AncestorDirs) -> Result<WalkEntry> {
+    if !policy.include_hidden && is_hidden(path) {
+        debug!("Skipping hidden entry: {}", path.display());
+        return Ok(WalkEntry::Skip("hidden, excluded by staging.include_hidden"));
+    }
+
+    let metadata = fs::symlink_metadata(path)
+        .with_context(|| format!("Failed to read metadata: {}", path.display()))?;
+
+    if metadata.file_type().is_symlink() {
+        return match policy.symlink_policy {
+            SymlinkPolicy::Skip => {
+                debug!("Skipping symlink: {}", path.display());
+                Ok(WalkEntry::Skip("symlink skipped by policy"))
+            }
+            SymlinkPolicy::Preserve => {
+                let target = fs::read_link(path)
+                    .with_context(|| format!("Failed to read symlink: {}", path.display()))?;
+                Ok(WalkEntry::Link(target))
+            }
+            SymlinkPolicy::Follow => match fs::metadata(path) {
+                Err(_) => {
+                    warn!("Skipping broken symlink: {}", path.display());
+                    Ok(WalkEntry::Skip("broken symlink"))
+                }
+                Ok(target) if target.is_dir() => {
+                    if ancestors.enter(path)? {
+                        Ok(WalkEntry::Dir)
+                    } else {
+                        warn!("Skipping symlink loop to a parent directory: {}", path.display());
+                        Ok(WalkEntry::Skip("symlink loop to a parent directory"))
+                    }
+                }
+                Ok(target) if target.is_file() => Ok(WalkEntry::File),
+                Ok(_) => Ok(WalkEntry::Skip("symlink to a special file")),
+            },
+        };
+    }
+
+    if metadata.is_dir() {
+        ancestors.enter(path)?;
+        Ok(WalkEntry::Dir)
+    } else if metadata.is_file() {
+        Ok(WalkEntry::File)
+    } else {
+        debug!("Skipping special file: {}", path.display());
+        Ok(WalkEntry::Skip("special file (device, socket or FIFO)"))
+    }
+}
+
+/// Check that every source folder can actually be read before staging starts.
+///
+/// `exists()` alone passes for a stale network mount or a folder the user
+/// can't list, which then fails hours into the copy. This stats each root,
+/// reads its listing and stats the first entry in it. All failures are
+/// reported together, one line per folder, e.g.
+/// "cannot read /mnt/nas: Stale file handle (os error 116)".
+pub fn preflight_source_folders(source_folders: &[PathBuf]) -> Result<()> {
+    fn check(folder: &Path) -> io::Result<()> {
+        if !fs::metadata(folder)?.is_dir() {
+            return Ok(());
+        }
+        if let Some(entry) = fs::read_dir(folder)?.next() {
+            fs::symlink_metadata(entry?.path())?;
+        }
+        Ok(())
+    }
+
+    let failures: Vec<String> = source_folders
+        .iter()
+        .filter_map(|folder| {
+            check(folder)
+                .err()
+                .map(|e| format!("cannot read {}: {}", folder.display(), e))
+        })
+        .collect();
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        anyhow::bail!("{}", failures.join("\n"))
+    }
+}
+
+/// Folder a source is staged under in `ARCHIVE/`: its label when renamed,
+/// otherwise its own name.
+pub fn destination_name(source: &Path, label: Option<&str>) -> String {
+    match label {
+        Some(label) => label.to_string(),
+        None => source
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string(),
+    }
+}
+
+/// Rename staged files and folders whose names are not in Unicode NFC form,
+/// so the names on the disc match the normalized paths in its manifest (see
+/// [`crate::paths::normalize_rel_path`]). Names that are not valid UTF-8, or
+/// that clash with a sibling once normalized, are refused.
+/// Returns the number of entries renamed.
+pub fn normalize_staged_names(root: &Path) -> Result<usize> {
+    let mut renamed = 0;
+    // Children first, so renaming a folder never invalidates paths still to visit
+    for entry in walkdir::WalkDir::new(root).min_depth(1).contents_first(true) {
+        let entry = entry.with_context(|| format!("Failed to walk {}", root.display()))?;
+        let name = entry.file_name();
+        let normalized = crate::paths::nfc_file_name(name).with_context(|| {
+            format!("File name is not valid UTF-8: {}; rename it in the source before archiving", entry.path().display())
+        })?;
+        if name == normalized.as_str() {
+            continue;
+        }
+        let target = entry.path().with_file_name(&normalized);
+        if fs::symlink_metadata(&target).is_ok() {
+            anyhow::bail!(
+                "{} and {} have the same name once Unicode-normalized; rename one of them",
+                entry.path().display(),
+                target.display()
+            );
+        }
+        fs::rename(entry.path(), &target)
+            .with_context(|| format!("Failed to rename {} to {}", entry.path().display(), target.display()))?;
+        debug!("Normalized staged name: {}", target.display());
+        renamed += 1;
+    }
+    Ok(renamed)
+}
+
+/// Refuse destination labels that are not a single folder name, and sources
+/// that would end up in the same `ARCHIVE/` folder. Names are compared ignoring
+/// case, since discs are also read on case-insensitive systems.
+pub fn validate_destination_names(sources: &[(PathBuf, Option<String>)]) -> Result<()> {
+    let mut problems = Vec::new();
+    let mut seen: Vec<(String, &Path)> = Vec::new();
+    for (source, label) in sources {
+        if let Some(label) = label {
+            if label.trim().is_empty() || label == "." || label == ".." || label.contains(['/', '\\']) {
+                problems.push(format!("\"{}\" is not a valid folder name for {}", label, source.display()));
+                continue;
+            }
+        }
+        let name = destination_name(source, label.as_deref());
+        match seen.iter().find(|(other, _)| other.eq_ignore_ascii_case(&name)) {
+            Some((_, other_source)) => problems.push(format!(
+                "{} and {} would both be stored as ARCHIVE/{}",
+                other_source.display(),
+                source.display(),
+                name
+            )),
+            None => seen.push((name, source)),
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        anyhow::bail!("{}", problems.join("\n"))
+    }
+}
+
+/// A source path that staging leaves out of the archive.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct ExcludedPath {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+/// List everything under `source_folders` that staging would leave out under `policy`.
+pub fn find_excluded_paths(source_folders: &[PathBuf], policy: WalkOptions) -> Result<Vec<ExcludedPath>> {
+    fn walk(dir: &Path, policy: WalkOptions, ancestors: &mut AncestorDirs, out: &mut Vec<ExcludedPath>) -> Result<()> {
+        let entries = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read directory: {}", dir.display()))?;
+        for entry in entries {
+            let path = entry.context("Failed to read directory entry")?.path();
+            match classify_entry(&path, policy, ancestors)? {
+                WalkEntry::Dir => walk(&path, policy, ancestors, out)?,
+                WalkEntry::Skip(reason) => out.push(ExcludedPath {
+                    path,
+                    reason: reason.to_string(),
+                }),
+                WalkEntry::File | WalkEntry::Link(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    let mut excluded = Vec::new();
+    for folder in source_folders.iter().filter(|f| f.is_dir()) {
+        let mut ancestors = AncestorDirs::new(folder);
+        walk(folder, policy, &mut ancestors, &mut excluded)?;
+    }
+    Ok(excluded)
+}
+
+/// Hidden files found under the source folders, counted whether or not
+/// `staging.include_hidden` lets them into the archive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HiddenFiles {
+    pub count: usize,
+    pub bytes: u64,
+}
+
+/// Count hidden files under `source_folders`: dotfiles and everything inside
+/// a dot-directory.
+pub fn count_hidden_files(source_folders: &[PathBuf], symlink_policy: SymlinkPolicy) -> Result<HiddenFiles> {
+    fn walk(dir: &Path, in_hidden: bool, policy: WalkOptions, ancestors: &mut AncestorDirs, out: &mut HiddenFiles) -> Result<()> {
+        let entries = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read directory: {}", dir.display()))?;
+        for entry in entries {
+            let path = entry.context("Failed to read directory entry")?.path();
+            let hidden = in_hidden || is_hidden(&path);
+            match classify_entry(&path, policy, ancestors)? {
+                WalkEntry::Dir => walk(&path, hidden, policy, ancestors, out)?,
+                WalkEntry::File if hidden => {
+                    out.count += 1;
+                    out.bytes += fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                }
+                WalkEntry::Link(_) if hidden => out.count += 1,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    let mut hidden = HiddenFiles::default();
+    for folder in source_folders.iter().filter(|f| f.is_dir()) {
+        let mut ancestors = AncestorDirs::new(folder);
+        walk(folder, false, symlink_policy.into(), &mut ancestors, &mut hidden)?;
+    }
+    Ok(hidden)
+}
+
+/// A single source file selected for staging.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    pub source: PathBuf,
+    /// Destination below `ARCHIVE/` (starts with the source folder name)
+    pub archive_path: PathBuf,
+    pub size_bytes: u64,
+    /// Set when the symlink policy preserves this entry as a link
+    pub link_target: Option<PathBuf>,
+}
+
+/// List every file staging would copy from `source_folders` under `policy`.
+pub fn collect_source_files(source_folders: &[PathBuf], policy: WalkOptions) -> Result<Vec<SourceFile>> {
+    let sources: Vec<(PathBuf, Option<String>)> = source_folders.iter().map(|f| (f.clone(), None)).collect();
+    collect_named_source_files(&sources, policy)
+}
+
+/// Like [`collect_source_files`], with each source's files under its
+/// [`destination_name`].
+pub fn collect_named_source_files(sources: &[(PathBuf, Option<String>)], policy: WalkOptions) -> Result<Vec<SourceFile>> {
+    fn walk(
+        dir: &Path,
+        archive_dir: &Path,
+        policy: WalkOptions,
+        ancestors: &mut AncestorDirs,
+        out: &mut Vec<SourceFile>,
+    ) -> Result<()> {
+        let entries = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read directory: {}", dir.display()))?;
+        for entry in entries {
+            let entry = entry.context("Failed to read directory entry")?;
+            let path = entry.path();
+            let archive_path = archive_dir.join(entry.file_name());
+            match classify_entry(&path, policy, ancestors)? {
+                WalkEntry::Dir => walk(&path, &archive_path, policy, ancestors, out)?,
+                WalkEntry::File => {
+                    let size_bytes = fs::metadata(&path)
+                        .with_context(|| format!("Failed to read metadata: {}", path.display()))?
+                        .len();
+                    out.push(SourceFile { source: path, archive_path, size_bytes, link_target: None });
+                }
+                WalkEntry::Link(target) => {
+                    out.push(SourceFile { source: path, archive_path, size_bytes: 0, link_target: Some(target) });
+                }
+                WalkEntry::Skip(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    for (folder, label) in sources.iter().filter(|(f, _)| f.is_dir()) {
+        let folder_name = destination_name(folder, label.as_deref());
+        let mut ancestors = AncestorDirs::new(folder);
+        walk(folder, Path::new(&folder_name), policy, &mut ancestors, &mut files)?;
+    }
+    Ok(files)
+}
+
+/// A file whose path on the disc breaks the image's [`PathLimits`](crate::iso::PathLimits).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LongPath {
+    /// Path from the disc root
+    pub disc_path: PathBuf,
+    pub problem: String,
+}
+
+/// Files from `sources` whose path on the disc, below `disc_prefix`
+/// (`ARCHIVE` on single discs, nothing on set discs), breaks `limits`.
+pub fn find_long_paths(
+    sources: &[(PathBuf, Option<String>)],
+    disc_prefix: &Path,
+    policy: WalkOptions,
+    limits: &crate::iso::PathLimits,
+) -> Result<Vec<LongPath>> {
+    Ok(collect_named_source_files(sources, policy)?
+        .into_iter()
+        .filter_map(|file| {
+            let disc_path = disc_prefix.join(&file.archive_path);
+            limits.check(&disc_path).map(|problem| LongPath { disc_path, problem })
+        })
+        .collect())
+}
+
+/// The first `shown` offenders, one per line, and how many more there are.
+pub fn describe_long_paths(paths: &[LongPath], shown: usize) -> String {
+    let mut message = format!("{} file(s) with names or paths too long for the disc:", paths.len());
+    for path in paths.iter().take(shown) {
+        message.push_str(&format!("\n  {} ({})", path.disc_path.display(), path.problem));
+    }
+    if paths.len() > shown {
+        message.push_str(&format!("\n  ... and {} more", paths.len() - shown));
+    }
+    message
+}
+
+/// Stage an explicit list of files into `disc_root/ARCHIVE`, keeping their layout.
+/// Bytes copied are added to `bytes_staged` as each file finishes.
+pub fn stage_file_list(
+    disc_root: &Path,
+    files: &[SourceFile],
+    dry_run: bool,
+    options: &StagingConfig,
+    progress_callback: Option<ProgressCallback>,
+    bytes_staged: Option<&AtomicU64>,
+    failed: Option<&FailedFiles>,
+) -> Result<usize> {
+    stage_file_list_into(&disc_root.join("ARCHIVE"), files, dry_run, options, progress_callback, bytes_staged, failed)
+}
+
+/// Like [`stage_file_list`], with each file's `archive_path` taken relative
+/// to `archive_dir` (set discs keep their source folders at the disc root).
+pub fn stage_file_list_into(
+    archive_dir: &Path,
+    files: &[SourceFile],
+    dry_run: bool,
+    options: &StagingConfig,
+    mut progress_callback: Option<ProgressCallback>,
+    bytes_staged: Option<&AtomicU64>,
+    failed: Option<&FailedFiles>,
+) -> Result<usize> {
+    let archive_dir = archive_dir.to_path_buf();
+    fs::create_dir_all(&archive_dir)?;
+
+    if dry_run {
+        info!("[DRY RUN] Would stage {} selected files", files.len());
+        return Ok(files.len());
+    }
+
+    let mut throttle = ReadThrottle::from_config(options);
+    let mut ledger = CopyLedger::from_config(options);
+    let mut kept = 0;
+    for (i, file) in files.iter().enumerate() {
+        let dest = archive_dir.join(&file.archive_path);
+        if options.resume {
+            let mut path = archive_dir.clone();
+            for component in file.archive_path.components() {
+                path.push(component);
+                reclaim_normalized_name(&path);
+            }
+        }
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        match &file.link_target {
+            Some(target) => stage_link(&file.source, target, &dest, options)?,
+            None => {
+                if let Some((bytes, reused)) =
+                    stage_file_or_skip(&file.source, &dest, options, throttle.as_mut(), ledger.as_mut(), failed)?
+                {
+                    add_bytes(bytes_staged, bytes);
+                    kept += usize::from(reused);
+                }
+            }
+        }
+
+        if let Some(ref mut callback) = progress_callback {
+            if (i + 1) % 100 == 0 || i + 1 == files.len() {
+                let rate = throttle.as_ref().map(ReadThrottle::describe).unwrap_or_default();
+                callback(&format!("📄 Staged {}/{} files{}", i + 1, files.len(), rate));
+            }
+        }
+    }
+
+    if kept > 0 {
+        info!("Resumed staging: {} files were already staged", kept);
+        if let Some(ref mut callback) = progress_callback {
+            callback(&format!("⏩ Kept {} files already staged by an earlier run", kept));
+        }
+    }
+
+    if let Some(ledger) = ledger {
+        ledger.verify(&mut progress_callback)?;
+    }
+
+    info!("Staged {} selected files", files.len());
+    Ok(files.len())
+}
+
+/// Recreate a symlink at `dst` pointing at `target`, keeping the link's own times.
+fn copy_symlink(src: &Path, target: &Path, dst: &Path) -> Result<()> {
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(target, dst)
+            .with_context(|| format!("Failed to create symlink: {}", dst.display()))?;
+        let metadata = fs::symlink_metadata(src)
+            .with_context(|| format!("Failed to read metadata: {}", src.display()))?;
+        filetime::set_symlink_file_times(
+            dst,
+            FileTime::from_last_access_time(&metadata),
+            FileTime::from_last_modification_time(&metadata),
+        )
+        .with_context(|| format!("Failed to set symlink times: {}", dst.display()))?;
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (target, dst);
+        warn!("Symlinks are not supported on this platform, skipping: {}", src.display());
+    }
+    Ok(())
+}
+
 /// Stage files from source folders to disc layout in staging directory.
 pub fn stage_files(
     disc_root: &Path,
@@ -19,7 +979,36 @@ pub fn stage_files_with_progress(
     source_folders: &[PathBuf],
     use_rsync: bool,
     dry_run: bool,
-    mut progress_callback: Option<Box<dyn FnMut(&str) + Send>>,
+    progress_callback: Option<Box<dyn FnMut(&str) + Send>>,
+) -> Result<Vec<PathBuf>> {
+    let sources: Vec<(PathBuf, Option<String>)> = source_folders.iter().map(|f| (f.clone(), None)).collect();
+    stage_files_with_options(
+        disc_root,
+        &sources,
+        use_rsync,
+        dry_run,
+        &StagingConfig::default(),
+        progress_callback,
+        None,
+        None,
+    )
+}
+
+/// Stage files with progress callback and staging options. Each source is
+/// copied to `ARCHIVE/<label>`, or `ARCHIVE/<folder name>` when it has no label.
+/// Bytes copied are added to `bytes_staged` as staging proceeds. With
+/// `failed`, unreadable files are recorded there and skipped, and the
+/// built-in copy is used since rsync can't report them one by one.
+#[allow(clippy::too_many_arguments)]
+pub fn stage_files_with_options(
+    disc_root: &Path,
+    sources: &[(PathBuf, Option<String>)],
+    use_rsync: bool,
+    dry_run: bool,
+    options: &StagingConfig,
+    mut progress_callback: Option<ProgressCallback>,
+    bytes_staged: Option<&AtomicU64>,
+    failed: Option<&FailedFiles>,
 ) -> Result<Vec<PathBuf>> {
     let archive_dir = disc_root.join("ARCHIVE");
     fs::create_dir_all(&archive_dir)?;
@@ -32,9 +1021,9 @@ pub fn stage_files_with_progress(
     let mut total_size_bytes = 0u64;
 
     // First pass: count files and estimate total size
-    for source in source_folders {
+    for (source, _) in sources {
         if source.exists() && source.is_dir() {
-            if let Ok(count) = count_files_and_size(source) {
+            if let Ok(count) = count_files_and_size(source, options.into()) {
                 total_files += count.0;
                 total_size_bytes += count.1;
             }
@@ -44,10 +1033,24 @@ pub fn stage_files_with_progress(
     if let Some(ref mut callback) = progress_callback {
         let size_mb = total_size_bytes / (1024 * 1024);
         callback(&format!("📁 Preparing to stage {} files ({}MB) from {} folders",
-                         total_files, size_mb, source_folders.len()));
+                         total_files, size_mb, sources.len()));
+    }
+
+    // One throttle for the whole run so the cap holds across folders
+    let mut throttle = ReadThrottle::from_config(options);
+    if let Some(ref throttle) = throttle {
+        info!("Staging reads limited to {:.0} Mbit/s", throttle.limit_mbps());
     }
+    let mut ledger = if dry_run { None } else { CopyLedger::from_config(options) };
+    // rsync is looked up again here, since it may have been removed since
+    // startup; without it every folder is staged with the built-in copy
+    let mut rsync = if use_rsync && failed.is_none() {
+        crate::dependencies::resolve_optional("rsync", "staging with the built-in copy instead")
+    } else {
+        None
+    };
 
-    for (i, source) in source_folders.iter().enumerate() {
+    for (i, (source, label)) in sources.iter().enumerate() {
         if !source.exists() {
             warn!("Source folder does not exist: {}", source.display());
             continue;
@@ -58,28 +1061,49 @@ pub fn stage_files_with_progress(
             continue;
         }
 
-        let folder_name = source
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("unknown");
+        let folder_name = destination_name(source, label.as_deref());
 
         if let Some(ref mut callback) = progress_callback {
             callback(&format!("📂 Staging folder {}/{}: {} ({} files processed so far)",
-                             i + 1, source_folders.len(), folder_name, processed_files));
+                             i + 1, sources.len(), folder_name, processed_files));
         }
 
-        let dest = archive_dir.join(folder_name);
+        let dest = archive_dir.join(&folder_name);
 
     // Enhanced staging with file-by-file progress
-    if use_rsync {
-        stage_with_rsync_progress(source, &dest, dry_run, &mut progress_callback, &mut processed_files)?;
+    let mut staged_with_rsync = false;
+    if let Some(rsync_path) = &rsync {
+        match stage_with_rsync_progress(rsync_path, source, &dest, dry_run, options, &mut progress_callback, &mut processed_files) {
+            Ok(_) => staged_with_rsync = true,
+            Err(e) if crate::dependencies::is_missing_command(&e) => {
+                warn!("rsync disappeared during staging ({:#}); staging with the built-in copy instead", e);
+                if let Some(ref mut callback) = progress_callback {
+                    callback("⚠️ rsync is no longer available, staging with the built-in copy");
+                }
+                rsync = None;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    if staged_with_rsync {
+        // rsync copies a whole folder in one go, so count it once it is done
+        if let Ok((_, size)) = count_files_and_size(source, options.into()) {
+            add_bytes(bytes_staged, size);
+        }
+        if let Some(ref mut ledger) = ledger {
+            ledger.record_tree(source, &dest)?;
+        }
     } else {
-        stage_with_copy_progress(source, &dest, dry_run, &mut progress_callback, &mut processed_files)?;
+        stage_with_copy_progress(source, &dest, dry_run, options, &mut progress_callback, &mut processed_files, bytes_staged, throttle.as_mut(), ledger.as_mut(), failed)?;
     }
 
         staged_paths.push(dest);
     }
 
+    if let Some(ledger) = ledger {
+        ledger.verify(&mut progress_callback)?;
+    }
+
     if let Some(ref mut callback) = progress_callback {
         callback(&format!("✅ Staging complete: {} folders, {} files processed", staged_paths.len(), processed_files));
     }
@@ -88,23 +1112,39 @@ pub fn stage_files_with_progress(
     Ok(staged_paths)
 }
 
+/// Add `bytes` to an optional progress counter.
+fn add_bytes(counter: Option<&AtomicU64>, bytes: u64) {
+    if let Some(counter) = counter {
+        counter.fetch_add(bytes, Ordering::Relaxed);
+    }
+}
+
 /// Count files and total size in a directory tree.
-fn count_files_and_size(dir: &Path) -> Result<(usize, u64)> {
+fn count_files_and_size(dir: &Path, policy: WalkOptions) -> Result<(usize, u64)> {
     let mut file_count = 0;
     let mut total_size = 0u64;
 
-    fn walk_dir(path: &Path, file_count: &mut usize, total_size: &mut u64) -> Result<()> {
+    fn walk_dir(
+        path: &Path,
+        policy: WalkOptions,
+        ancestors: &mut AncestorDirs,
+        file_count: &mut usize,
+        total_size: &mut u64,
+    ) -> Result<()> {
         if let Ok(entries) = fs::read_dir(path) {
             for entry in entries {
                 if let Ok(entry) = entry {
                     let path = entry.path();
-                    if path.is_file() {
-                        *file_count += 1;
-                        if let Ok(metadata) = entry.metadata() {
-                            *total_size += metadata.len();
+                    match classify_entry(&path, policy, ancestors)? {
+                        WalkEntry::File => {
+                            *file_count += 1;
+                            if let Ok(metadata) = fs::metadata(&path) {
+                                *total_size += metadata.len();
+                            }
                         }
-                    } else if path.is_dir() {
-                        walk_dir(&path, file_count, total_size)?;
+                        WalkEntry::Link(_) => *file_count += 1,
+                        WalkEntry::Dir => walk_dir(&path, policy, ancestors, file_count, total_size)?,
+                        WalkEntry::Skip(_) => {}
                     }
                 }
             }
@@ -112,15 +1152,18 @@ fn count_files_and_size(dir: &Path) -> Result<(usize, u64)> {
         Ok(())
     }
 
-    walk_dir(dir, &mut file_count, &mut total_size)?;
+    let mut ancestors = AncestorDirs::new(dir);
+    walk_dir(dir, policy, &mut ancestors, &mut file_count, &mut total_size)?;
     Ok((file_count, total_size))
 }
 
-/// Stage files using rsync with progress reporting.
+/// Stage files using rsync (at `rsync`) with progress reporting.
 fn stage_with_rsync_progress(
+    rsync: &Path,
     source: &Path,
     dest: &Path,
     dry_run: bool,
+    options: &StagingConfig,
     progress_callback: &mut Option<Box<dyn FnMut(&str) + Send>>,
     processed_files: &mut usize,
 ) -> Result<usize> {
@@ -133,14 +1176,36 @@ fn stage_with_rsync_progress(
 
     // For rsync, we can't easily track individual file progress,
     // so we'll just show the folder being processed
+    // (-a already preserves modification times, permissions and symlinks)
     let source_str = format!("{}/", source.display());
     let dest_str = dest.display().to_string();
-    let args = vec!["-av", "--delete", &source_str, &dest_str];
+    let mut args = vec!["-av", "--delete"];
+    if !options.preserve_permissions {
+        args.push("--chmod=D755,F644");
+    }
+    match options.symlink_policy {
+        SymlinkPolicy::Skip => args.push("--no-links"),
+        SymlinkPolicy::Follow => args.push("--copy-links"),
+        SymlinkPolicy::Preserve => {}
+    }
+    if !options.include_hidden {
+        args.push("--exclude=.*");
+    }
+    // rsync takes the limit in KiB/s
+    let bwlimit = options
+        .max_read_mbps
+        .filter(|mbps| *mbps > 0.0)
+        .map(|mbps| format!("--bwlimit={}", ((mbps * 1_000_000.0 / 8.0 / 1024.0) as u64).max(1)));
+    if let Some(ref bwlimit) = bwlimit {
+        args.push(bwlimit);
+    }
+    args.push(&source_str);
+    args.push(&dest_str);
 
     if dry_run {
         info!("[DRY RUN] Would run: rsync {}", args.join(" "));
         // Estimate files processed for dry run
-        if let Ok((count, _)) = count_files_and_size(source) {
+        if let Ok((count, _)) = count_files_and_size(source, options.into()) {
             *processed_files += count;
         }
         return Ok(0);
@@ -150,10 +1215,11 @@ fn stage_with_rsync_progress(
         callback(&format!("🔄 Running rsync: {} -> {}", source.display(), dest.display()));
     }
 
-    crate::commands::execute_command("rsync", &args, dry_run).context("rsync failed")?;
+    let args: Vec<&OsStr> = args.iter().map(OsStr::new).collect();
+    crate::commands::execute_command(rsync.as_os_str(), &args, dry_run).context("rsync failed")?;
 
     // Count files that were actually processed
-    let file_count = if let Ok((count, _)) = count_files_and_size(dest) {
+    let file_count = if let Ok((count, _)) = count_files_and_size(dest, SymlinkPolicy::Preserve.into()) {
         count
     } else {
         0
@@ -164,12 +1230,151 @@ fn stage_with_rsync_progress(
 }
 
 /// Stage files using copy with detailed progress reporting.
+#[allow(clippy::too_many_arguments)]
+/// A tree walked for a concurrent copy: directories and links are already
+/// created, files are left to the workers.
+#[derive(Debug, Default)]
+struct CopyPlan {
+    /// (source, destination) of each regular file
+    files: Vec<(PathBuf, PathBuf)>,
+    /// Directories in the order their metadata is restored: contents first
+    dirs: Vec<(PathBuf, PathBuf)>,
+    links: usize,
+}
+
+/// Walk `src` as the sequential copy does, creating `dst` directories in
+/// order and staging symlinks, but only listing the files to copy.
+fn plan_tree_copy(
+    src: &Path,
+    dst: &Path,
+    options: &StagingConfig,
+    ancestors: &mut AncestorDirs,
+    failed: Option<&FailedFiles>,
+    plan: &mut CopyPlan,
+) -> Result<()> {
+    fs::create_dir_all(dst)?;
+    let entries = match fs::read_dir(src).with_context(|| format!("Failed to read source directory: {}", src.display())) {
+        Ok(entries) => entries,
+        Err(e) => match failed {
+            Some(failed) => {
+                failed.record(src, &e);
+                return Ok(());
+            }
+            None => return Err(e),
+        },
+    };
+    for entry in entries {
+        let entry = entry.context("Failed to read directory entry")?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+        if options.resume {
+            reclaim_normalized_name(&dst_path);
+        }
+        match classify_entry(&src_path, options.into(), ancestors)? {
+            WalkEntry::Dir => plan_tree_copy(&src_path, &dst_path, options, ancestors, failed, plan)?,
+            WalkEntry::File => plan.files.push((src_path, dst_path)),
+            WalkEntry::Link(target) => {
+                stage_link(&src_path, &target, &dst_path, options)?;
+                plan.links += 1;
+            }
+            WalkEntry::Skip(_) => {}
+        }
+    }
+    plan.dirs.push((src.to_path_buf(), dst.to_path_buf()));
+    Ok(())
+}
+
+/// Copy `files` with up to `workers` threads, each taking the next file
+/// from the list. `on_copied` runs on the worker thread with the source and
+/// the bytes copied. The first error stops the other workers after their
+/// current file. Each worker keeps its own ledger, merged into `ledger` at
+/// the end.
+fn copy_files_concurrently(
+    files: &[(PathBuf, PathBuf)],
+    options: &StagingConfig,
+    workers: usize,
+    ledger: Option<&mut CopyLedger>,
+    failed: Option<&FailedFiles>,
+    on_copied: &(dyn Fn(&Path, u64) + Sync),
+) -> Result<()> {
+    let next = AtomicUsize::new(0);
+    let abort = AtomicBool::new(false);
+    let keep_ledger = ledger.is_some();
+    let outcomes: Vec<Result<Option<CopyLedger>>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers.clamp(1, files.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut worker_ledger = keep_ledger.then(CopyLedger::default);
+                    while !abort.load(Ordering::Relaxed) {
+                        let Some((src, dst)) = files.get(next.fetch_add(1, Ordering::Relaxed)) else {
+                            break;
+                        };
+                        match stage_file_or_skip(src, dst, options, None, worker_ledger.as_mut(), failed) {
+                            Ok(Some((bytes, _))) => on_copied(src, bytes),
+                            Ok(None) => {}
+                            Err(e) => {
+                                abort.store(true, Ordering::Relaxed);
+                                return Err(e);
+                            }
+                        }
+                    }
+                    Ok(worker_ledger)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .collect()
+    });
+
+    let mut ledger = ledger;
+    for outcome in outcomes {
+        if let (Some(ledger), Some(worker_ledger)) = (ledger.as_deref_mut(), outcome?) {
+            ledger.absorb(worker_ledger);
+        }
+    }
+    Ok(())
+}
+
+/// Copy the tree under `src` into `dst` with `options.copy_jobs` workers.
+/// Directory metadata is restored once every file is in place, since copying
+/// into a directory bumps its modification time. Returns the files and links
+/// staged.
+fn copy_tree_concurrently(
+    src: &Path,
+    dst: &Path,
+    options: &StagingConfig,
+    ledger: Option<&mut CopyLedger>,
+    failed: Option<&FailedFiles>,
+    on_copied: &(dyn Fn(&Path, u64) + Sync),
+) -> Result<usize> {
+    let mut plan = CopyPlan::default();
+    let mut ancestors = AncestorDirs::new(src);
+    plan_tree_copy(src, dst, options, &mut ancestors, failed, &mut plan)?;
+    let copied = AtomicUsize::new(0);
+    copy_files_concurrently(&plan.files, options, options.copy_jobs, ledger, failed, &|path, bytes| {
+        copied.fetch_add(1, Ordering::Relaxed);
+        on_copied(path, bytes);
+    })?;
+    for (src_dir, dst_dir) in &plan.dirs {
+        preserve_metadata(src_dir, dst_dir, options)?;
+    }
+    Ok(copied.into_inner() + plan.links)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn stage_with_copy_progress(
     source: &Path,
     dest: &Path,
     dry_run: bool,
+    options: &StagingConfig,
     progress_callback: &mut Option<Box<dyn FnMut(&str) + Send>>,
     processed_files: &mut usize,
+    bytes_staged: Option<&AtomicU64>,
+    throttle: Option<&mut ReadThrottle>,
+    ledger: Option<&mut CopyLedger>,
+    failed: Option<&FailedFiles>,
 ) -> Result<usize> {
     debug!(
         "Staging with copy: {} -> {} (dry_run: {})",
@@ -181,7 +1386,7 @@ fn stage_with_copy_progress(
     if dry_run {
         info!("[DRY RUN] Would copy: {} -> {}", source.display(), dest.display());
         // Estimate files processed for dry run
-        if let Ok((count, _)) = count_files_and_size(source) {
+        if let Ok((count, _)) = count_files_and_size(source, options.into()) {
             *processed_files += count;
         }
         return Ok(0);
@@ -191,11 +1396,18 @@ fn stage_with_copy_progress(
 
     let mut files_copied = 0;
 
+    #[allow(clippy::too_many_arguments)]
     fn copy_recursive(
         src: &Path,
         dst: &Path,
+        options: &StagingConfig,
+        ancestors: &mut AncestorDirs,
         progress_callback: &mut Option<Box<dyn FnMut(&str) + Send>>,
         files_copied: &mut usize,
+        bytes_staged: Option<&AtomicU64>,
+        mut throttle: Option<&mut ReadThrottle>,
+        mut ledger: Option<&mut CopyLedger>,
+        failed: Option<&FailedFiles>,
     ) -> Result<()> {
         if let Ok(entries) = fs::read_dir(src) {
             for entry in entries {
@@ -203,24 +1415,37 @@ fn stage_with_copy_progress(
                     let src_path = entry.path();
                     let file_name = src_path.file_name().unwrap_or_default();
                     let dst_path = dst.join(file_name);
+                    let kind = classify_entry(&src_path, options.into(), ancestors)?;
+                    if options.resume {
+                        reclaim_normalized_name(&dst_path);
+                    }
 
-                    if src_path.is_file() {
-                        // Copy file
-                        fs::copy(&src_path, &dst_path)?;
+                    if let WalkEntry::Link(target) = &kind {
+                        stage_link(&src_path, target, &dst_path, options)?;
                         *files_copied += 1;
+                    } else if matches!(kind, WalkEntry::File) {
+                        // Copy file (or keep the copy of an interrupted run)
+                        let Some((bytes, _)) = stage_file_or_skip(&src_path, &dst_path, options, throttle.as_deref_mut(), ledger.as_deref_mut(), failed)? else {
+                            continue;
+                        };
+                        *files_copied += 1;
+                        add_bytes(bytes_staged, bytes);
 
                         // Report progress for larger files or every 10 files
                         if *files_copied % 10 == 0 || src_path.metadata()?.len() > 10 * 1024 * 1024 {
                             if let Some(ref mut callback) = progress_callback {
                                 let size_mb = src_path.metadata()?.len() / (1024 * 1024);
-                                callback(&format!("📄 Copied: {} ({}MB) - {} files total",
-                                                 file_name.to_string_lossy(), size_mb, files_copied));
+                                let rate = throttle.as_deref().map(ReadThrottle::describe).unwrap_or_default();
+                                callback(&format!("📄 Copied: {} ({}MB) - {} files total{}",
+                                                 file_name.to_string_lossy(), size_mb, files_copied, rate));
                             }
                         }
-                    } else if src_path.is_dir() {
+                    } else if matches!(kind, WalkEntry::Dir) {
                         // Create directory and recurse
                         fs::create_dir_all(&dst_path)?;
-                        copy_recursive(&src_path, &dst_path, progress_callback, files_copied)?;
+                        copy_recursive(&src_path, &dst_path, options, ancestors, progress_callback, files_copied, bytes_staged, throttle.as_deref_mut(), ledger.as_deref_mut(), failed)?;
+                        // Directory times are set after its contents, which would otherwise bump them
+                        preserve_metadata(&src_path, &dst_path, options)?;
                     }
                 }
             }
@@ -232,12 +1457,50 @@ fn stage_with_copy_progress(
         callback(&format!("📋 Starting copy: {} -> {}", source.display(), dest.display()));
     }
 
-    copy_recursive(source, dest, progress_callback, &mut files_copied)?;
+    // A read cap applies to one stream, so throttled copies stay sequential
+    if options.copy_jobs > 1 && throttle.is_none() {
+        let copied_so_far = AtomicUsize::new(0);
+        let callback = std::sync::Mutex::new(progress_callback);
+        let files_copied = copy_tree_concurrently(source, dest, options, ledger, failed, &|path, bytes| {
+            let copied = copied_so_far.fetch_add(1, Ordering::Relaxed) + 1;
+            add_bytes(bytes_staged, bytes);
+            // Report progress for larger files or every 10 files
+            if copied.is_multiple_of(10) || bytes > 10 * 1024 * 1024 {
+                let mut callback = callback.lock().unwrap_or_else(|e| e.into_inner());
+                if let Some(callback) = callback.as_mut() {
+                    callback(&format!(
+                        "📄 Copied: {} ({}MB) - {} files total",
+                        path.file_name().unwrap_or_default().to_string_lossy(),
+                        bytes / (1024 * 1024),
+                        copied
+                    ));
+                }
+            }
+        })?;
+        *processed_files += files_copied;
+        return Ok(files_copied);
+    }
+
+    let mut ancestors = AncestorDirs::new(source);
+    copy_recursive(source, dest, options, &mut ancestors, progress_callback, &mut files_copied, bytes_staged, throttle, ledger, failed)?;
+    preserve_metadata(source, dest, options)?;
     *processed_files += files_copied;
 
     Ok(files_copied)
 }
 
+/// Copy the tree under `source` into `dest` the way staging copies a source
+/// folder, keeping modification times. Returns the number of files copied.
+pub fn copy_tree(
+    source: &Path,
+    dest: &Path,
+    options: &StagingConfig,
+    progress_callback: &mut Option<ProgressCallback>,
+) -> Result<usize> {
+    let mut files_copied = 0;
+    stage_with_copy_progress(source, dest, false, options, progress_callback, &mut files_copied, None, None, None, None)
+}
+
 /// Stage files using rsync.
 #[allow(dead_code)]
 fn stage_with_rsync(source: &Path, dest: &Path, dry_run: bool) -> Result<()> {
@@ -284,43 +1547,125 @@ fn stage_with_copy(source: &Path, dest: &Path, dry_run: bool) -> Result<()> {
     Ok(())
 }
 
-/// Recursively copy directory.
+/// Copy modification/access times and (optionally) permission bits from `src` to `dst`.
+fn preserve_metadata(src: &Path, dst: &Path, options: &StagingConfig) -> Result<()> {
+    let metadata = fs::metadata(src)
+        .with_context(|| format!("Failed to read metadata: {}", src.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = if options.preserve_permissions {
+            // Keep owner rwx on directories so the staging area can still be cleaned up
+            if metadata.is_dir() {
+                metadata.permissions().mode() | 0o700
+            } else {
+                metadata.permissions().mode()
+            }
+        } else if metadata.is_dir() {
+            0o755
+        } else {
+            0o644
+        };
+        fs::set_permissions(dst, fs::Permissions::from_mode(mode))
+            .with_context(|| format!("Failed to set permissions: {}", dst.display()))?;
+    }
+    #[cfg(not(unix))]
+    let _ = options;
+
+    filetime::set_file_times(
+        dst,
+        FileTime::from_last_access_time(&metadata),
+        FileTime::from_last_modification_time(&metadata),
+    )
+    .with_context(|| format!("Failed to set file times: {}", dst.display()))?;
+
+    Ok(())
+}
+
+/// Recursively copy directory, preserving modification times and permissions.
 pub fn copy_directory_recursive(source: &Path, dest: &Path) -> Result<()> {
-    fs::create_dir_all(dest)?;
+    copy_directory_with_options(source, dest, &StagingConfig::default(), None)
+}
 
-    let entries = fs::read_dir(source)
-        .with_context(|| format!("Failed to read source directory: {}", source.display()))?;
+/// Recursively copy directory using the given staging options. With
+/// `verify_after_copy` the copies are compared with their sources at the end.
+/// With `failed`, unreadable files and folders are recorded there and skipped.
+pub fn copy_directory_with_options(
+    source: &Path,
+    dest: &Path,
+    options: &StagingConfig,
+    failed: Option<&FailedFiles>,
+) -> Result<()> {
+    fn copy_dir(
+        source: &Path,
+        dest: &Path,
+        options: &StagingConfig,
+        ancestors: &mut AncestorDirs,
+        mut ledger: Option<&mut CopyLedger>,
+        failed: Option<&FailedFiles>,
+    ) -> Result<()> {
+        fs::create_dir_all(dest)?;
+
+        let entries = match fs::read_dir(source)
+            .with_context(|| format!("Failed to read source directory: {}", source.display()))
+        {
+            Ok(entries) => entries,
+            Err(e) => match failed {
+                Some(failed) => {
+                    failed.record(source, &e);
+                    return Ok(());
+                }
+                None => return Err(e),
+            },
+        };
 
-    for entry in entries {
-        let entry = entry.context("Failed to read directory entry")?;
-        let path = entry.path();
-        let file_name = entry.file_name();
-        let dest_path = dest.join(&file_name);
+        for entry in entries {
+            let entry = entry.context("Failed to read directory entry")?;
+            let path = entry.path();
+            let file_name = entry.file_name();
+            let dest_path = dest.join(&file_name);
+            if options.resume {
+                reclaim_normalized_name(&dest_path);
+            }
 
-        if path.is_dir() {
-            copy_directory_recursive(&path, &dest_path)?;
-        } else {
-            fs::copy(&path, &dest_path).with_context(|| {
-                format!(
-                    "Failed to copy file: {} -> {}",
-                    path.display(),
-                    dest_path.display()
-                )
-            })?;
+            match classify_entry(&path, options.into(), ancestors)? {
+                WalkEntry::Dir => copy_dir(&path, &dest_path, options, ancestors, ledger.as_deref_mut(), failed)?,
+                WalkEntry::File => {
+                    stage_file_or_skip(&path, &dest_path, options, None, ledger.as_deref_mut(), failed)?;
+                }
+                WalkEntry::Link(target) => stage_link(&path, &target, &dest_path, options)?,
+                WalkEntry::Skip(_) => {}
+            }
         }
+
+        preserve_metadata(source, dest, options)?;
+        Ok(())
     }
 
+    let mut ledger = CopyLedger::from_config(options);
+    if options.copy_jobs > 1 {
+        copy_tree_concurrently(source, dest, options, ledger.as_mut(), failed, &|_, _| {})?;
+    } else {
+        let mut ancestors = AncestorDirs::new(source);
+        copy_dir(source, dest, options, &mut ancestors, ledger.as_mut(), failed)?;
+    }
+    if let Some(ledger) = ledger {
+        ledger.verify(&mut None)?;
+    }
     Ok(())
 }
 
 /// Calculate total size of files in a directory.
+///
+/// Symlinks are counted by their own size and never followed.
 pub fn calculate_directory_size(path: &Path) -> Result<u64> {
     let mut total = 0u64;
 
-    if path.is_file() {
-        return Ok(fs::metadata(path)
-            .with_context(|| format!("Failed to read file metadata: {}", path.display()))?
-            .len());
+    let metadata = fs::symlink_metadata(path)
+        .with_context(|| format!("Failed to read file metadata: {}", path.display()))?;
+    if !metadata.is_dir() {
+        return Ok(metadata.len());
     }
 
     let entries = fs::read_dir(path)
@@ -335,22 +1680,100 @@ pub fn calculate_directory_size(path: &Path) -> Result<u64> {
     Ok(total)
 }
 
-/// Check if total size exceeds capacity.
-pub fn check_capacity(source_folders: &[PathBuf], capacity_bytes: u64) -> Result<(u64, bool)> {
-    let mut total_size = 0u64;
+/// Clean the staging directory after a burn according to the cleanup policy.
+///
+/// Only entries inside `staging_dir` are removed. ISO images are kept when
+/// `keep_iso` is set, and dry-run plan reports are always kept.
+pub fn cleanup_staging_dir(staging_dir: &Path, policy: &CleanupConfig, dry_run: bool) -> Result<()> {
+    if dry_run && policy.keep_on_dry_run {
+        info!("Keeping dry-run staging output: {}", staging_dir.display());
+        return Ok(());
+    }
+    if !policy.after_burn {
+        info!("Post-burn cleanup disabled; keeping {}", staging_dir.display());
+        return Ok(());
+    }
+    if !staging_dir.exists() {
+        info!("Staging directory already removed");
+        return Ok(());
+    }
+
+    info!("Cleaning up staging directory: {}", staging_dir.display());
+    let entries = fs::read_dir(staging_dir)
+        .with_context(|| format!("Failed to read directory: {}", staging_dir.display()))?;
 
-    for folder in source_folders {
-        if folder.exists() {
-            total_size += calculate_directory_size(folder)?;
+    for entry in entries {
+        let entry = entry.context("Failed to read directory entry")?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if file_type.is_file() {
+            let is_iso = path.extension().map(|e| e == "iso").unwrap_or(false);
+            if (is_iso && policy.keep_iso) || name.starts_with("bdarchive-plan-") {
+                debug!("Keeping {}", path.display());
+                continue;
+            }
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+        } else if file_type.is_dir() {
+            fs::remove_dir_all(&path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+        } else {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
         }
     }
 
-    let exceeds = total_size > capacity_bytes;
-    Ok((total_size, exceeds))
+    info!("Successfully cleaned up staging directory");
+    Ok(())
+}
+
+/// Estimate the content and ISO size of the selection and check whether
+/// the ISO would exceed capacity.
+pub fn check_capacity(
+    source_folders: &[PathBuf],
+    capacity_bytes: u64,
+    walk_options: WalkOptions,
+) -> Result<(crate::iso::IsoSizeEstimate, bool)> {
+    let existing: Vec<PathBuf> = source_folders.iter().filter(|f| f.exists()).cloned().collect();
+    let files = collect_source_files(&existing, walk_options)?;
+    // Preserved links are Rock Ridge entries without data
+    let estimate = crate::iso::IsoSizeEstimate::from_files(
+        files
+            .iter()
+            .map(|f| (f.archive_path.as_path(), if f.link_target.is_some() { 0 } else { f.size_bytes })),
+    );
+
+    let exceeds = estimate.iso_bytes > capacity_bytes;
+    Ok((estimate, exceeds))
+}
+
+/// Room a run needs in its staging directory: one disc's staged content plus
+/// the ISO built from it. Multi-disc sets stage one disc at a time.
+pub fn staging_space_needed(iso_bytes: u64, disc_capacity_bytes: u64) -> u64 {
+    iso_bytes.min(disc_capacity_bytes).saturating_mul(2)
+}
+
+/// Check a staging directory chosen for a single run: it must already exist
+/// and have at least `needed_bytes` free.
+pub fn check_staging_dir(dir: &Path, needed_bytes: u64) -> Result<()> {
+    crate::paths::validate_dir(dir)?;
+    let free = crate::paths::free_space_bytes(dir)
+        .with_context(|| format!("Cannot determine free space in {}", dir.display()))?;
+    if free < needed_bytes {
+        anyhow::bail!(
+            "Not enough space in {}: {} free, about {} needed for staging and the ISO",
+            dir.display(),
+            crate::search::format_bytes_gb(free),
+            crate::search::format_bytes_gb(needed_bytes)
+        );
+    }
+    Ok(())
 }
 
 /// Represents a directory entry with size information for layout planning
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DirectoryEntry {
     pub path: PathBuf,
     pub size_bytes: u64,
@@ -358,23 +1781,112 @@ pub struct DirectoryEntry {
     pub children: Vec<DirectoryEntry>,
 }
 
-/// Analyze directory structure for multi-disc planning
-pub fn analyze_directory_structure(root_path: &Path) -> Result<DirectoryEntry> {
-    fn analyze_recursive(path: &Path) -> Result<DirectoryEntry> {
-        let metadata = fs::metadata(path)
-            .with_context(|| format!("Failed to read metadata for: {}", path.display()))?;
+/// Error message when planning stops for a Ctrl-C
+pub const ANALYSIS_CANCELLED: &str = "Directory analysis cancelled";
 
-        if metadata.is_file() {
-            return Ok(DirectoryEntry {
-                path: path.to_path_buf(),
-                size_bytes: metadata.len(),
-                is_file: true,
-                children: Vec::new(),
-            });
+/// How often directory analysis reports its progress
+const ANALYSIS_PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Counts a directory analysis updates as it goes, for progress reporting.
+#[derive(Debug, Default)]
+pub struct AnalysisProgress {
+    pub dirs: AtomicU64,
+    pub files: AtomicU64,
+    pub bytes: AtomicU64,
+    /// Directories whose listing came from the size cache
+    pub cached_dirs: AtomicU64,
+}
+
+impl AnalysisProgress {
+    fn add_file(&self, size_bytes: u64) {
+        self.files.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size_bytes, Ordering::Relaxed);
+    }
+
+    /// e.g. "📂 Analyzed 1204 folders (1100 cached), 53210 files, 412.30 GB"
+    pub fn describe(&self) -> String {
+        let dirs = self.dirs.load(Ordering::Relaxed);
+        let cached = self.cached_dirs.load(Ordering::Relaxed);
+        format!(
+            "📂 Analyzed {} folders{}, {} files, {}",
+            dirs,
+            if cached > 0 { format!(" ({} cached)", cached) } else { String::new() },
+            self.files.load(Ordering::Relaxed),
+            crate::search::format_bytes_gb(self.bytes.load(Ordering::Relaxed))
+        )
+    }
+}
+
+/// Analyze directory structure for multi-disc planning.
+///
+/// Symlinks and hidden entries are handled according to `walk_options` so the
+/// plan matches what staging will actually copy.
+pub fn analyze_directory_structure(root_path: &Path, walk_options: WalkOptions) -> Result<DirectoryEntry> {
+    analyze_directory_structure_cached(root_path, walk_options, None, &AnalysisProgress::default())
+}
+
+/// [`analyze_directory_structure`], reusing the listings in `cache` for
+/// directories whose modification time is unchanged and storing fresh ones
+/// there. Counts are added to `progress` as the walk goes, and a Ctrl-C stops
+/// it with [`ANALYSIS_CANCELLED`].
+pub fn analyze_directory_structure_cached(
+    root_path: &Path,
+    walk_options: WalkOptions,
+    cache: Option<&SizeCache>,
+    progress: &AnalysisProgress,
+) -> Result<DirectoryEntry> {
+    fn file_entry(path: &Path, size_bytes: u64) -> DirectoryEntry {
+        DirectoryEntry {
+            path: path.to_path_buf(),
+            size_bytes,
+            is_file: true,
+            children: Vec::new(),
+        }
+    }
+
+    struct Walk<'a> {
+        policy: WalkOptions,
+        cache: Option<&'a SizeCache>,
+        progress: &'a AnalysisProgress,
+    }
+
+    /// Children of `path` from its cached listing. None when a cached
+    /// subdirectory can no longer be read, so the directory is listed afresh.
+    fn cached_children(
+        walk: &Walk,
+        path: &Path,
+        listing: CachedDir,
+        ancestors: &mut AncestorDirs,
+    ) -> Result<Option<Vec<DirectoryEntry>>> {
+        if listing.dirs.iter().any(|name| fs::metadata(path.join(name)).is_err()) {
+            return Ok(None);
+        }
+        walk.progress.cached_dirs.fetch_add(1, Ordering::Relaxed);
+        let mut children = Vec::with_capacity(listing.files.len() + listing.dirs.len());
+        for (name, size) in listing.files {
+            walk.progress.add_file(size);
+            children.push(file_entry(&path.join(name), size));
+        }
+        for name in listing.dirs {
+            let child_path = path.join(name);
+            // Still tracked so symlink loops are broken as on a fresh walk
+            if ancestors.enter(&child_path)? {
+                children.push(analyze_recursive(walk, &child_path, ancestors)?);
+            }
         }
+        Ok(Some(children))
+    }
 
-        let mut total_size = 0u64;
+    /// Children of `path` from reading the directory, with the listing to cache
+    /// (None when a name isn't valid UTF-8 and can't be stored).
+    fn read_children(
+        walk: &Walk,
+        path: &Path,
+        mtime: MtimeKey,
+        ancestors: &mut AncestorDirs,
+    ) -> Result<(Vec<DirectoryEntry>, Option<CachedDir>)> {
         let mut children = Vec::new();
+        let mut listing = Some(CachedDir { mtime, files: Vec::new(), dirs: Vec::new() });
 
         let entries = fs::read_dir(path)
             .with_context(|| format!("Failed to read directory: {}", path.display()))?;
@@ -382,10 +1894,64 @@ pub fn analyze_directory_structure(root_path: &Path) -> Result<DirectoryEntry> {
         for entry in entries {
             let entry = entry.context("Failed to read directory entry")?;
             let child_path = entry.path();
-            let child_entry = analyze_recursive(&child_path)?;
-            total_size += child_entry.size_bytes;
+            let child_entry = match classify_entry(&child_path, walk.policy, ancestors)? {
+                WalkEntry::Skip(_) => continue,
+                WalkEntry::Link(_) => {
+                    let link_size = fs::symlink_metadata(&child_path)
+                        .with_context(|| format!("Failed to read metadata for: {}", child_path.display()))?
+                        .len();
+                    walk.progress.add_file(link_size);
+                    file_entry(&child_path, link_size)
+                }
+                WalkEntry::File | WalkEntry::Dir => analyze_recursive(walk, &child_path, ancestors)?,
+            };
+            match (listing.as_mut(), entry.file_name().to_str()) {
+                (Some(listing), Some(name)) if child_entry.is_file => {
+                    listing.files.push((name.to_string(), child_entry.size_bytes))
+                }
+                (Some(listing), Some(name)) => listing.dirs.push(name.to_string()),
+                (_, None) => listing = None,
+                (None, Some(_)) => {}
+            }
             children.push(child_entry);
         }
+        Ok((children, listing))
+    }
+
+    fn analyze_recursive(walk: &Walk, path: &Path, ancestors: &mut AncestorDirs) -> Result<DirectoryEntry> {
+        if crate::interrupt::is_requested() {
+            anyhow::bail!(ANALYSIS_CANCELLED);
+        }
+
+        let metadata = fs::metadata(path)
+            .with_context(|| format!("Failed to read metadata for: {}", path.display()))?;
+
+        if metadata.is_file() {
+            walk.progress.add_file(metadata.len());
+            return Ok(file_entry(path, metadata.len()));
+        }
+        walk.progress.dirs.fetch_add(1, Ordering::Relaxed);
+
+        let mtime = size_cache::mtime_key(&metadata);
+        let cached = match (walk.cache, mtime) {
+            (Some(cache), Some(mtime)) => cache.get(path, mtime),
+            _ => None,
+        };
+        let cached_children = match cached {
+            Some(listing) => cached_children(walk, path, listing, ancestors)?,
+            None => None,
+        };
+        let mut children = match cached_children {
+            Some(children) => children,
+            None => {
+                let (children, listing) = read_children(walk, path, mtime.unwrap_or_default(), ancestors)?;
+                if let (Some(cache), Some(listing), Some(_)) = (walk.cache, listing, mtime) {
+                    cache.insert(path, listing);
+                }
+                children
+            }
+        };
+        let total_size = children.iter().map(|child| child.size_bytes).sum();
 
         // Sort children by size (largest first) for better packing
         children.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes));
@@ -398,47 +1964,231 @@ pub fn analyze_directory_structure(root_path: &Path) -> Result<DirectoryEntry> {
         })
     }
 
-    analyze_recursive(root_path)
+    let walk = Walk { policy: walk_options, cache, progress };
+    let mut ancestors = AncestorDirs::new(root_path);
+    analyze_recursive(&walk, root_path, &mut ancestors)
+}
+
+/// File size class used to show the shape of the data being archived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SizeBucket {
+    /// Under 1 MB
+    Tiny,
+    /// 1 MB to 100 MB
+    Small,
+    /// 100 MB to 1 GB
+    Medium,
+    /// 1 GB and over
+    Large,
+}
+
+impl SizeBucket {
+    pub fn for_size(bytes: u64) -> Self {
+        const MB: u64 = 1024 * 1024;
+        match bytes {
+            b if b < MB => Self::Tiny,
+            b if b < 100 * MB => Self::Small,
+            b if b < 1024 * MB => Self::Medium,
+            _ => Self::Large,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Tiny => "< 1 MB",
+            Self::Small => "1-100 MB",
+            Self::Medium => "100 MB-1 GB",
+            Self::Large => "> 1 GB",
+        }
+    }
+}
+
+/// File count and total bytes per size bucket, for files under `entry`.
+/// Buckets without files are left out.
+pub fn size_histogram(entry: &DirectoryEntry) -> BTreeMap<SizeBucket, (usize, u64)> {
+    let mut histogram = BTreeMap::new();
+    add_to_histogram(entry, &mut histogram);
+    histogram
+}
+
+fn add_to_histogram(entry: &DirectoryEntry, histogram: &mut BTreeMap<SizeBucket, (usize, u64)>) {
+    if entry.is_file {
+        let (count, bytes) = histogram.entry(SizeBucket::for_size(entry.size_bytes)).or_insert((0, 0));
+        *count += 1;
+        *bytes += entry.size_bytes;
+    }
+    for child in &entry.children {
+        add_to_histogram(child, histogram);
+    }
 }
 
 /// Plan disc layout to minimize directory splits across discs
 pub fn plan_disc_layout(
     source_folders: &[PathBuf],
     disc_capacity_bytes: u64,
+    walk_options: WalkOptions,
 ) -> Result<Vec<DiscPlan>> {
-    plan_disc_layout_with_progress(source_folders, disc_capacity_bytes, |_| {})
+    plan_disc_layout_with_progress(source_folders, disc_capacity_bytes, walk_options, None, |_| {})
 }
 
-/// Plan disc layout with progress callback for UI feedback
+/// Plan disc layout with progress callback for UI feedback. Directory sizes
+/// are taken from `cache` where unchanged, and the cache is saved afterwards.
 pub fn plan_disc_layout_with_progress<F>(
     source_folders: &[PathBuf],
     disc_capacity_bytes: u64,
+    walk_options: WalkOptions,
+    cache: Option<&SizeCache>,
     mut progress_callback: F,
 ) -> Result<Vec<DiscPlan>>
 where
     F: FnMut(&str) -> (),
 {
+    let all_entries = analyze_sources(source_folders, walk_options, cache, &mut progress_callback)?;
+
+    // Refuse to plan rather than silently dropping files that can never fit
+    let oversize = find_oversize_files(&all_entries, disc_capacity_bytes);
+    if !oversize.is_empty() {
+        anyhow::bail!("{}", describe_oversize_files(&oversize, disc_capacity_bytes));
+    }
+
+    Ok(pack_entries(all_entries, disc_capacity_bytes, &mut progress_callback))
+}
+
+/// Analyze all source folders and flatten their children into packable entries.
+///
+/// Folders are walked in parallel while this thread reports the running
+/// counts every [`ANALYSIS_PROGRESS_INTERVAL`]. A cache that can't be saved
+/// afterwards is only logged.
+fn analyze_sources<F>(
+    source_folders: &[PathBuf],
+    walk_options: WalkOptions,
+    cache: Option<&SizeCache>,
+    progress_callback: &mut F,
+) -> Result<Vec<DirectoryEntry>>
+where
+    F: FnMut(&str),
+{
+    use rayon::prelude::*;
+
     let mut all_entries = Vec::new();
 
     progress_callback("🔍 Analyzing source directories...");
 
-    // Analyze all source directories and flatten their children as packable entries
-    for (i, folder) in source_folders.iter().enumerate() {
-        if folder.exists() {
-            progress_callback(&format!("📂 Analyzing folder {}/{}: {}", i + 1, source_folders.len(), folder.display()));
-            let structure = analyze_directory_structure(folder)?;
-
-            // If this is a directory with children, add the children as packable entries
-            // Otherwise, add the structure itself
-            if !structure.is_file && !structure.children.is_empty() {
-                all_entries.extend(structure.children);
-            } else {
-                all_entries.push(structure);
+    let folders: Vec<&PathBuf> = source_folders.iter().filter(|folder| folder.exists()).collect();
+    let progress = AnalysisProgress::default();
+    let caller = std::thread::current();
+    let structures = std::thread::scope(|scope| {
+        let worker = scope.spawn(|| {
+            let structures: Vec<Result<DirectoryEntry>> = folders
+                .par_iter()
+                .map(|folder| analyze_directory_structure_cached(folder, walk_options, cache, &progress))
+                .collect();
+            caller.unpark();
+            structures
+        });
+        while !worker.is_finished() {
+            std::thread::park_timeout(ANALYSIS_PROGRESS_INTERVAL);
+            if !worker.is_finished() {
+                progress_callback(&progress.describe());
             }
         }
+        worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    });
+
+    // Flatten all source directories' children as packable entries
+    for structure in structures {
+        let structure = structure?;
+        // If this is a directory with children, add the children as packable entries
+        // Otherwise, add the structure itself
+        if !structure.is_file && !structure.children.is_empty() {
+            all_entries.extend(structure.children);
+        } else {
+            all_entries.push(structure);
+        }
+    }
+    progress_callback(&progress.describe());
+
+    if let Some(cache) = cache {
+        if let Err(e) = cache.save(source_folders) {
+            warn!("Failed to save the directory size cache: {:#}", e);
+        }
     }
 
     progress_callback(&format!("📊 Found {} items to pack across discs", all_entries.len()));
+    Ok(all_entries)
+}
+
+/// A single file that is larger than one disc and therefore cannot be planned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OversizeFile {
+    pub path: PathBuf,
+    pub size_bytes: u64,
+}
+
+/// Collect every file (at any depth) that exceeds the disc capacity.
+pub fn find_oversize_files(entries: &[DirectoryEntry], disc_capacity_bytes: u64) -> Vec<OversizeFile> {
+    fn collect(entry: &DirectoryEntry, capacity: u64, out: &mut Vec<OversizeFile>) {
+        if entry.is_file {
+            if entry.size_bytes > capacity {
+                out.push(OversizeFile {
+                    path: entry.path.clone(),
+                    size_bytes: entry.size_bytes,
+                });
+            }
+        } else if entry.size_bytes > capacity {
+            // Only directories larger than a disc can contain an oversize file
+            for child in &entry.children {
+                collect(child, capacity, out);
+            }
+        }
+    }
+
+    let mut oversize = Vec::new();
+    for entry in entries {
+        collect(entry, disc_capacity_bytes, &mut oversize);
+    }
+    oversize
+}
+
+/// Human-readable error listing files that do not fit on a single disc.
+pub fn describe_oversize_files(files: &[OversizeFile], disc_capacity_bytes: u64) -> String {
+    let mut message = format!(
+        "{} file(s) larger than a single {} disc:",
+        files.len(),
+        crate::search::format_bytes_gb(disc_capacity_bytes)
+    );
+    for file in files {
+        message.push_str(&format!(
+            "\n  {} ({})",
+            file.path.display(),
+            crate::search::format_bytes_gb(file.size_bytes)
+        ));
+    }
+    message
+}
+
+/// One line per size bucket: label, file count, bytes and share of all files.
+pub fn describe_size_histogram(histogram: &BTreeMap<SizeBucket, (usize, u64)>) -> Vec<String> {
+    let total_files: usize = histogram.values().map(|(count, _)| count).sum();
+    histogram
+        .iter()
+        .map(|(bucket, (count, bytes))| {
+            format!(
+                "{:<12} {:>8} files ({:>4.1}%)  {}",
+                bucket.label(),
+                count,
+                *count as f64 * 100.0 / total_files.max(1) as f64,
+                crate::search::format_size(*bytes)
+            )
+        })
+        .collect()
+}
+
+/// Pack analyzed entries onto discs.
+fn pack_entries<F>(mut all_entries: Vec<DirectoryEntry>, disc_capacity_bytes: u64, progress_callback: &mut F) -> Vec<DiscPlan>
+where
+    F: FnMut(&str),
+{
 
     // Sort using intelligent bin-packing strategy
     progress_callback("🧠 Sorting items with intelligent bin-packing algorithm...");
@@ -488,8 +2238,82 @@ where
         }
     }
 
-    progress_callback(&format!("✅ Planning complete! Created {} discs for {} items", discs.len(), all_entries.len()));
-    Ok(discs)
+    progress_callback(&format!("✅ Planning complete! Created {} discs for {} items", discs.len(), all_entries.len()));
+    discs
+}
+
+/// Summary of a disc layout plan, computed from directory metadata only.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanSummary {
+    pub disc_count: usize,
+    /// Planned bytes per disc, in disc order
+    pub per_disc_sizes: Vec<u64>,
+    /// Directories whose contents end up on more than one disc
+    pub split_directories: Vec<String>,
+    pub total_bytes: u64,
+    /// Overall utilization of the planned discs, in percent
+    pub utilization: f64,
+    /// Files that cannot fit on any disc; the plan is not burnable while non-empty
+    pub oversize_files: Vec<OversizeFile>,
+    /// File count and bytes per size bucket across all sources
+    pub size_histogram: BTreeMap<SizeBucket, (usize, u64)>,
+}
+
+impl PlanSummary {
+    fn from_plans(plans: &[DiscPlan], capacity_bytes: u64, total_bytes: u64, oversize_files: Vec<OversizeFile>) -> Self {
+        let per_disc_sizes: Vec<u64> = plans.iter().map(|p| p.used_bytes).collect();
+        let planned_bytes: u64 = per_disc_sizes.iter().sum();
+
+        let mut split_directories: Vec<String> = Vec::new();
+        for dir in plans.iter().flat_map(|p| p.split_directories.iter()) {
+            if !split_directories.contains(dir) {
+                split_directories.push(dir.clone());
+            }
+        }
+
+        let total_capacity = capacity_bytes.saturating_mul(plans.len() as u64);
+        let utilization = if total_capacity > 0 {
+            (planned_bytes as f64 / total_capacity as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        Self {
+            disc_count: plans.len(),
+            per_disc_sizes,
+            split_directories,
+            total_bytes,
+            utilization,
+            oversize_files,
+            size_histogram: BTreeMap::new(),
+        }
+    }
+}
+
+/// Estimate how many discs the source folders need without staging anything.
+///
+/// Runs the regular planner, which only reads directory metadata, so this is
+/// cheap even for very large inputs. Unlike `plan_disc_layout`, oversize files
+/// are reported in the summary instead of failing the estimate.
+pub fn estimate_plan(
+    source_folders: &[PathBuf],
+    capacity_bytes: u64,
+    walk_options: WalkOptions,
+    cache: Option<&SizeCache>,
+) -> Result<PlanSummary> {
+    let mut no_progress = |_: &str| {};
+    let entries = analyze_sources(source_folders, walk_options, cache, &mut no_progress)?;
+    let total_bytes: u64 = entries.iter().map(|e| e.size_bytes).sum();
+    let oversize = find_oversize_files(&entries, capacity_bytes);
+    let mut histogram = BTreeMap::new();
+    for entry in &entries {
+        add_to_histogram(entry, &mut histogram);
+    }
+    let plans = pack_entries(entries, capacity_bytes, &mut no_progress);
+    Ok(PlanSummary {
+        size_histogram: histogram,
+        ..PlanSummary::from_plans(&plans, capacity_bytes, total_bytes, oversize)
+    })
 }
 
 /// Try to add an entry to existing discs using intelligent bin-packing
@@ -769,6 +2593,7 @@ fn split_directory_across_discs(
             };
 
             disc.add_entry(split_entry);
+            disc.split_directories.push(entry.path.display().to_string());
             part_num += 1;
         } else {
             // No more children could fit, avoid infinite loop
@@ -777,14 +2602,101 @@ fn split_directory_across_discs(
     }
 }
 
+/// File on each disc of a set describing the directories split across it
+pub const SPLIT_INFO_FILE: &str = "SPLIT_INFO.json";
+
+/// The files of a split directory that one disc of the set holds
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SplitPart {
+    pub part: usize,
+    pub disc_number: usize,
+    /// Paths relative to the split directory
+    pub files: Vec<String>,
+}
+
+/// Where the parts of a directory split across a set ended up, so the
+/// directory can be reassembled from the discs
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SplitDirectory {
+    /// The directory as planned, i.e. its source path
+    pub directory: String,
+    pub total_parts: usize,
+    /// Part held by the disc carrying this record
+    pub part: usize,
+    /// Every part in disc order, this one included
+    pub parts: Vec<SplitPart>,
+}
+
+/// Content of [`SPLIT_INFO_FILE`]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SplitInfo {
+    pub set_id: String,
+    pub disc_number: usize,
+    pub total_discs: usize,
+    pub directories: Vec<SplitDirectory>,
+}
+
+/// The split directories that disc `disc_number` of `plans` holds a part of.
+/// Files are matched to directories by path, so directories split by any
+/// planning step (whole children, partial fills, re-plans) are covered.
+pub fn split_directories_on_disc(plans: &[DiscPlan], disc_number: usize) -> Vec<SplitDirectory> {
+    let plan_files: Vec<(usize, Vec<&Path>)> = plans.iter().map(|plan| (plan.disc_number, plan.files())).collect();
+
+    let mut directories: Vec<&String> = Vec::new();
+    for dir in plans.iter().flat_map(|p| p.split_directories.iter()) {
+        if !directories.contains(&dir) {
+            directories.push(dir);
+        }
+    }
+
+    let mut result = Vec::new();
+    for directory in directories {
+        let dir_path = Path::new(directory);
+        let mut parts: Vec<SplitPart> = Vec::new();
+        for (number, files) in &plan_files {
+            let mut files: Vec<String> = files
+                .iter()
+                .filter_map(|f| f.strip_prefix(dir_path).ok())
+                .map(|rel| rel.to_string_lossy().to_string())
+                .collect();
+            if files.is_empty() {
+                continue;
+            }
+            files.sort();
+            parts.push(SplitPart { part: parts.len() + 1, disc_number: *number, files });
+        }
+        if parts.len() < 2 {
+            continue;
+        }
+        if let Some(part) = parts.iter().find(|p| p.disc_number == disc_number).map(|p| p.part) {
+            result.push(SplitDirectory {
+                directory: directory.clone(),
+                total_parts: parts.len(),
+                part,
+                parts,
+            });
+        }
+    }
+    result
+}
+
+/// Write `info` as pretty-printed JSON.
+pub fn write_split_info(path: &Path, info: &SplitInfo) -> Result<()> {
+    let json = serde_json::to_string_pretty(info).context("Failed to serialize split info")?;
+    fs::write(path, json).with_context(|| format!("Failed to write split info: {}", path.display()))
+}
+
 /// Represents a planned disc with its contents
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DiscPlan {
     pub disc_number: usize,
     pub capacity_bytes: u64,
     pub used_bytes: u64,
     pub entries: Vec<DirectoryEntry>,
     pub split_directories: Vec<String>, // Names of directories split across discs
+    /// Files stored only once on this disc (see `dedup`); already subtracted from `used_bytes`
+    #[serde(default)]
+    pub duplicates: Vec<crate::dedup::DuplicateFile>,
 }
 
 impl DiscPlan {
@@ -795,6 +2707,7 @@ impl DiscPlan {
             used_bytes: 0,
             entries: Vec::new(),
             split_directories: Vec::new(),
+            duplicates: Vec::new(),
         }
     }
 
@@ -869,10 +2782,115 @@ impl DiscPlan {
         self.entries.push(entry);
     }
 
+    /// Take the largest files off this disc until at least `bytes` are freed,
+    /// e.g. when its image came out larger than the disc. Directories that
+    /// lose files are recorded as split and emptied ones are dropped.
+    /// Returns the removed files, largest first.
+    pub fn take_largest_files(&mut self, bytes: u64) -> Vec<DirectoryEntry> {
+        fn largest_file(entry: &DirectoryEntry) -> Option<u64> {
+            if entry.is_file {
+                Some(entry.size_bytes)
+            } else {
+                entry.children.iter().filter_map(largest_file).max()
+            }
+        }
+
+        /// Remove the largest file under `entries`, shrinking the directories above it
+        fn remove_largest(entries: &mut Vec<DirectoryEntry>, split: &mut Vec<String>) -> Option<DirectoryEntry> {
+            let (index, _) = entries
+                .iter()
+                .enumerate()
+                .filter_map(|(i, entry)| largest_file(entry).map(|size| (i, size)))
+                .max_by_key(|&(_, size)| size)?;
+            if entries[index].is_file {
+                return Some(entries.remove(index));
+            }
+            let dir = &mut entries[index];
+            let file = remove_largest(&mut dir.children, split)?;
+            dir.size_bytes = dir.size_bytes.saturating_sub(file.size_bytes);
+            let name = dir.path.display().to_string();
+            if !split.contains(&name) {
+                split.push(name);
+            }
+            if dir.children.is_empty() {
+                entries.remove(index);
+            }
+            Some(file)
+        }
+
+        let mut removed = Vec::new();
+        let mut freed = 0;
+        while freed < bytes {
+            let Some(file) = remove_largest(&mut self.entries, &mut self.split_directories) else {
+                break;
+            };
+            freed += file.size_bytes;
+            self.used_bytes = self.used_bytes.saturating_sub(file.size_bytes);
+            removed.push(file);
+        }
+        removed
+    }
+
+    /// Source paths of every file planned onto this disc
+    pub fn files(&self) -> Vec<&Path> {
+        fn collect<'a>(entry: &'a DirectoryEntry, files: &mut Vec<&'a Path>) {
+            if entry.is_file {
+                files.push(&entry.path);
+            } else {
+                for child in &entry.children {
+                    collect(child, files);
+                }
+            }
+        }
+
+        let mut files = Vec::new();
+        for entry in &self.entries {
+            collect(entry, &mut files);
+        }
+        files
+    }
+
     /// Get utilization percentage
     pub fn utilization_percent(&self) -> f64 {
         (self.used_bytes as f64 / self.capacity_bytes as f64) * 100.0
     }
+
+    /// Find byte-identical files within this disc and take the copies off
+    /// `used_bytes`. Returns the bytes saved.
+    pub fn dedup(&mut self) -> Result<u64> {
+        fn collect_files(entry: &DirectoryEntry, files: &mut Vec<PathBuf>) {
+            if entry.is_file {
+                // Symlinks are planned as file entries but are never deduplicated
+                if fs::symlink_metadata(&entry.path).is_ok_and(|m| m.is_file()) {
+                    files.push(entry.path.clone());
+                }
+            } else {
+                for child in &entry.children {
+                    collect_files(child, files);
+                }
+            }
+        }
+
+        let mut files = Vec::new();
+        for entry in &self.entries {
+            collect_files(entry, &mut files);
+        }
+
+        let duplicates = crate::dedup::find_duplicates(&files)?;
+        let saved = crate::dedup::saved_bytes(&duplicates);
+        self.used_bytes = self.used_bytes.saturating_sub(saved);
+        self.duplicates = duplicates;
+        Ok(saved)
+    }
+}
+
+/// Deduplicate every disc of a plan; returns the total bytes saved.
+pub fn dedup_plans(plans: &mut [DiscPlan]) -> Result<u64> {
+    let mut saved = 0;
+    for plan in plans {
+        saved += plan.dedup()?;
+    }
+    Ok(saved)
 }
 
 #[cfg(test)]
@@ -881,6 +2899,103 @@ mod tests {
     use std::fs;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_retry_with_backoff() {
+        let io_err = |kind: io::ErrorKind| anyhow::Error::new(io::Error::new(kind, "test"));
+
+        // Transient failures are retried until the operation succeeds
+        let mut attempts = 0;
+        let result = retry_with_backoff(3, Duration::ZERO, "flaky read", || {
+            attempts += 1;
+            if attempts < 3 {
+                Err(io_err(io::ErrorKind::TimedOut).context("Failed to copy file"))
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(result.unwrap(), 3);
+
+        // Retries are bounded
+        let mut attempts = 0;
+        let err = retry_with_backoff(2, Duration::ZERO, "dead mount", || -> Result<()> {
+            attempts += 1;
+            Err(io_err(io::ErrorKind::Interrupted))
+        })
+        .unwrap_err();
+        assert_eq!(attempts, 3);
+        assert!(err.to_string().contains("still failing after 2 retries"));
+
+        // Missing files fail immediately
+        let mut attempts = 0;
+        let result = retry_with_backoff(3, Duration::ZERO, "missing", || -> Result<()> {
+            attempts += 1;
+            Err(io_err(io::ErrorKind::NotFound))
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_preflight_source_folders() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let photos = temp_dir.path().join("photos");
+        fs::create_dir_all(&photos)?;
+        fs::write(photos.join("1.jpg"), "pixels")?;
+        let empty = temp_dir.path().join("empty");
+        fs::create_dir_all(&empty)?;
+        preflight_source_folders(&[photos.clone(), empty])?;
+
+        // Every unreadable folder is named, not just the first
+        let gone = temp_dir.path().join("gone");
+        let unmounted = temp_dir.path().join("nas");
+        let err = preflight_source_folders(&[gone.clone(), photos, unmounted.clone()]).unwrap_err();
+        let message = err.to_string();
+        assert_eq!(message.lines().count(), 2);
+        assert!(message.contains(&format!("cannot read {}: ", gone.display())));
+        assert!(message.contains(&format!("cannot read {}: ", unmounted.display())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cleanup_staging_dir_policy() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let staging = temp_dir.path();
+        let populate = || -> Result<()> {
+            fs::create_dir_all(staging.join("disc_1/ARCHIVE"))?;
+            fs::write(staging.join("disc_1/ARCHIVE/a.txt"), "a")?;
+            fs::write(staging.join("2026-BD-1.iso"), "iso")?;
+            fs::write(staging.join("bdarchive-plan-2026-BD-1.txt"), "plan")?;
+            Ok(())
+        };
+        populate()?;
+        let enabled = CleanupConfig { after_burn: true, ..CleanupConfig::default() };
+
+        // Cleanup is opt-in: the default policy keeps everything
+        cleanup_staging_dir(staging, &CleanupConfig::default(), false)?;
+        assert!(staging.join("disc_1/ARCHIVE/a.txt").exists());
+        assert!(staging.join("2026-BD-1.iso").exists());
+
+        // Dry runs are left alone even when cleanup is enabled
+        cleanup_staging_dir(staging, &enabled, true)?;
+        assert!(staging.join("disc_1/ARCHIVE/a.txt").exists());
+
+        // keep_iso removes staged content but keeps the image
+        let keep_iso = CleanupConfig { keep_iso: true, ..enabled.clone() };
+        cleanup_staging_dir(staging, &keep_iso, false)?;
+        assert!(!staging.join("disc_1").exists());
+        assert!(staging.join("2026-BD-1.iso").exists());
+
+        // Without keep_iso the ISO goes too; plan reports survive
+        populate()?;
+        cleanup_staging_dir(staging, &enabled, false)?;
+        assert!(!staging.join("disc_1").exists());
+        assert!(!staging.join("2026-BD-1.iso").exists());
+        assert!(staging.join("bdarchive-plan-2026-BD-1.txt").exists());
+        assert!(staging.exists());
+
+        Ok(())
+    }
+
     #[test]
     fn test_stage_with_copy() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -899,6 +3014,359 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_staging_preserves_mtime_and_permissions() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("source");
+        fs::create_dir_all(&source)?;
+        let file = source.join("file.txt");
+        fs::write(&file, "test content")?;
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o600))?;
+        let old_time = FileTime::from_unix_time(1_000_000_000, 0);
+        filetime::set_file_times(&file, old_time, old_time)?;
+
+        let disc_root = temp_dir.path().join("preserve");
+        let options = StagingConfig { preserve_permissions: true, ..StagingConfig::default() };
+        let bytes_staged = AtomicU64::new(0);
+        stage_files_with_options(&disc_root, &[(source.clone(), None)], false, false, &options, None, Some(&bytes_staged), None)?;
+        assert_eq!(bytes_staged.load(Ordering::Relaxed), "test content".len() as u64);
+        let staged = disc_root.join("ARCHIVE/source/file.txt");
+        let metadata = fs::metadata(&staged)?;
+        assert_eq!(FileTime::from_last_modification_time(&metadata), old_time);
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+
+        let disc_root = temp_dir.path().join("normalize");
+        let options = StagingConfig { preserve_permissions: false, ..StagingConfig::default() };
+        stage_files_with_options(&disc_root, &[(source, None)], false, false, &options, None, None, None)?;
+        let staged = disc_root.join("ARCHIVE/source/file.txt");
+        let metadata = fs::metadata(&staged)?;
+        assert_eq!(FileTime::from_last_modification_time(&metadata), old_time);
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o644);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concurrent_copy_matches_sequential() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("source");
+        for dir in ["a", "a/deep", "b"] {
+            fs::create_dir_all(source.join(dir))?;
+        }
+        for i in 0..25 {
+            let dir = ["a", "a/deep", "b"][i % 3];
+            fs::write(source.join(dir).join(format!("f{}.txt", i)), format!("file {}", i))?;
+        }
+        let old_time = FileTime::from_unix_time(1_000_000_000, 0);
+        filetime::set_file_mtime(source.join("a/deep"), old_time)?;
+
+        let options = StagingConfig { copy_jobs: 4, verify_after_copy: true, ..StagingConfig::default() };
+        let bytes_staged = AtomicU64::new(0);
+        let disc_root = temp_dir.path().join("disc");
+        stage_files_with_options(&disc_root, &[(source.clone(), None)], false, false, &options, None, Some(&bytes_staged), None)?;
+
+        let staged = disc_root.join("ARCHIVE/source");
+        assert_eq!(count_files_and_size(&staged, SymlinkPolicy::Follow.into())?, count_files_and_size(&source, SymlinkPolicy::Follow.into())?);
+        assert_eq!(fs::read_to_string(staged.join("a/deep/f1.txt"))?, "file 1");
+        assert_eq!(bytes_staged.load(Ordering::Relaxed), count_files_and_size(&source, SymlinkPolicy::Follow.into())?.1);
+        // Directory times are restored after the workers are done
+        assert_eq!(FileTime::from_last_modification_time(&fs::metadata(staged.join("a/deep"))?), old_time);
+
+        let mut copied = 0;
+        let dest = temp_dir.path().join("tree");
+        assert_eq!(stage_with_copy_progress(&source, &dest, false, &options, &mut None, &mut copied, None, None, None, None)?, 25);
+        assert_eq!(copied, 25);
+
+        let dest = temp_dir.path().join("set_disc");
+        copy_directory_with_options(&source, &dest, &options, None)?;
+        assert_eq!(fs::read_to_string(dest.join("b/f2.txt"))?, "file 2");
+        Ok(())
+    }
+
+    #[test]
+    fn test_stage_under_destination_labels() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let first = temp_dir.path().join("a/data");
+        let second = temp_dir.path().join("b/data");
+        fs::create_dir_all(&first)?;
+        fs::create_dir_all(&second)?;
+        fs::write(first.join("one.txt"), "one")?;
+        fs::write(second.join("two.txt"), "two")?;
+
+        // Two sources called "data" would merge into one folder on the disc
+        let clashing = vec![(first.clone(), None), (second.clone(), None)];
+        let err = validate_destination_names(&clashing).unwrap_err();
+        assert!(err.to_string().contains("ARCHIVE/data"));
+        let invalid = vec![(first.clone(), Some("photos/2024".to_string()))];
+        assert!(validate_destination_names(&invalid).is_err());
+
+        let sources = vec![(first, None), (second, Some("data-b".to_string()))];
+        validate_destination_names(&sources)?;
+        let disc_root = temp_dir.path().join("disc");
+        stage_files_with_options(&disc_root, &sources, false, false, &StagingConfig::default(), None, None, None)?;
+        assert!(disc_root.join("ARCHIVE/data/one.txt").exists());
+        assert!(disc_root.join("ARCHIVE/data-b/two.txt").exists());
+
+        let files = collect_named_source_files(&sources, SymlinkPolicy::Follow.into())?;
+        assert!(files.iter().any(|f| f.archive_path == Path::new("data-b/two.txt")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_long_paths() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("photos");
+        let deep = source.join("2024").join("summer");
+        fs::create_dir_all(&deep)?;
+        fs::write(source.join("a.jpg"), "a")?;
+        fs::write(deep.join("beach.jpg"), "b")?;
+
+        let sources = vec![(source, Some("holiday".to_string()))];
+        let limits = crate::iso::PathLimits { max_name_len: 64, max_path_len: 30 };
+        let long = find_long_paths(&sources, Path::new("ARCHIVE"), SymlinkPolicy::Follow.into(), &limits)?;
+        assert_eq!(long.len(), 1);
+        assert_eq!(long[0].disc_path, Path::new("ARCHIVE/holiday/2024/summer/beach.jpg"));
+        assert!(long[0].problem.contains("37 characters"));
+
+        // Set discs hold the folders at the root, which leaves room for it
+        assert!(find_long_paths(&sources, Path::new(""), SymlinkPolicy::Follow.into(), &limits)?.is_empty());
+        assert!(describe_long_paths(&long, 0).ends_with("... and 1 more"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_staged_names() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let archive = temp_dir.path().join("ARCHIVE");
+        let folder = archive.join("Cafe\u{301}").join("Ne\u{301}e");
+        fs::create_dir_all(&folder)?;
+        fs::write(folder.join("re\u{301}sume\u{301}.txt"), "cv")?;
+        fs::write(archive.join("plain.txt"), "plain")?;
+
+        assert_eq!(normalize_staged_names(&archive)?, 3);
+        assert_eq!(fs::read_to_string(archive.join("Caf\u{e9}/N\u{e9}e/r\u{e9}sum\u{e9}.txt"))?, "cv");
+        assert!(archive.join("plain.txt").exists());
+        assert_eq!(normalize_staged_names(&archive)?, 0);
+
+        // Two spellings of the same name in one folder cannot both survive
+        fs::write(archive.join("Caf\u{e9}.txt"), "nfc")?;
+        fs::write(archive.join("Cafe\u{301}.txt"), "nfd")?;
+        assert!(normalize_staged_names(&archive).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_throttled_staging() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("nas");
+        fs::create_dir_all(&source)?;
+        for i in 0..10 {
+            fs::write(source.join(format!("{}.bin", i)), vec![i as u8; 60_000])?;
+        }
+
+        // 8 Mbit/s is 1 MB/s: 600 KB minus the initial 250 KB burst takes ~0.35s
+        let options = StagingConfig { max_read_mbps: Some(8.0), ..StagingConfig::default() };
+        let messages = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = messages.clone();
+        let callback: ProgressCallback = Box::new(move |msg: &str| sink.lock().unwrap().push(msg.to_string()));
+        let disc_root = temp_dir.path().join("disc");
+        let started = Instant::now();
+        stage_files_with_options(&disc_root, &[(source, None)], false, false, &options, Some(callback), None, None)?;
+
+        assert!(started.elapsed() >= Duration::from_millis(300));
+        assert_eq!(fs::read(disc_root.join("ARCHIVE/nas/3.bin"))?, vec![3u8; 60_000]);
+        assert!(messages.lock().unwrap().iter().any(|m| m.contains("Mbit/s (cap 8)")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resume_staging() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("photos");
+        fs::create_dir_all(&source)?;
+        for name in ["a.jpg", "b.jpg", "c.jpg"] {
+            fs::write(source.join(name), name)?;
+        }
+        let disc_root = temp_dir.path().join("disc");
+        let options = StagingConfig { resume: true, ..StagingConfig::default() };
+        stage_files_with_options(&disc_root, &[(source.clone(), None)], false, false, &options, None, None, None)?;
+
+        // An interrupted run: a finished copy, a half-written one and a missing one.
+        // Marking the finished copy lets us see whether it was copied again
+        let staged = disc_root.join("ARCHIVE/photos");
+        let a_mtime = FileTime::from_last_modification_time(&fs::metadata(staged.join("a.jpg"))?);
+        fs::write(staged.join("a.jpg"), "A.JPG")?;
+        filetime::set_file_mtime(staged.join("a.jpg"), a_mtime)?;
+        fs::write(staged.join("b.jpg"), "b")?;
+        fs::remove_file(staged.join("c.jpg"))?;
+
+        let bytes = AtomicU64::new(0);
+        stage_files_with_options(&disc_root, &[(source.clone(), None)], false, false, &options, None, Some(&bytes), None)?;
+        assert_eq!(fs::read_to_string(staged.join("a.jpg"))?, "A.JPG");
+        assert_eq!(fs::read_to_string(staged.join("b.jpg"))?, "b.jpg");
+        assert_eq!(fs::read_to_string(staged.join("c.jpg"))?, "c.jpg");
+        assert_eq!(bytes.load(Ordering::Relaxed), 15);
+
+        // Without resume everything is copied again
+        stage_files_with_options(&disc_root, &[(source.clone(), None)], false, false, &StagingConfig::default(), None, None, None)?;
+        assert_eq!(fs::read_to_string(staged.join("a.jpg"))?, "a.jpg");
+
+        // Entries an earlier run already renamed to NFC are reused, not duplicated
+        fs::create_dir_all(source.join("Cafe\u{301}"))?;
+        fs::write(source.join("Cafe\u{301}/menu.txt"), "menu")?;
+        stage_files_with_options(&disc_root, &[(source.clone(), None)], false, false, &options, None, None, None)?;
+        normalize_staged_names(&disc_root.join("ARCHIVE"))?;
+        stage_files_with_options(&disc_root, &[(source, None)], false, false, &options, None, None, None)?;
+        assert_eq!(normalize_staged_names(&disc_root.join("ARCHIVE"))?, 1);
+        assert!(staged.join("Caf\u{e9}/menu.txt").is_file());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_after_copy() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("photos");
+        fs::create_dir_all(source.join("2026"))?;
+        fs::write(source.join("a.jpg"), "jpeg")?;
+        fs::write(source.join("2026/b.jpg"), vec![7u8; 100_000])?;
+
+        let options = StagingConfig { verify_after_copy: true, ..StagingConfig::default() };
+        let disc_root = temp_dir.path().join("disc");
+        stage_files_with_options(&disc_root, &[(source.clone(), None)], false, false, &options, None, None, None)?;
+        assert_eq!(fs::read(disc_root.join("ARCHIVE/photos/2026/b.jpg"))?, vec![7u8; 100_000]);
+
+        // Hashes are taken from the bytes read during the copy
+        let mut ledger = CopyLedger::default();
+        let staged = temp_dir.path().join("b.jpg");
+        copy_file_with_retries(&source.join("2026/b.jpg"), &staged, 0, None, Some(&mut ledger))?;
+        assert_eq!(ledger.copies[0].sha256, crate::manifest::calculate_sha256(&source.join("2026/b.jpg"))?);
+        assert_eq!(ledger.verify(&mut None)?, 1);
+
+        // A staged copy that changed after the copy fails the run, naming the file
+        fs::write(&staged, vec![8u8; 100_000])?;
+        let err = ledger.verify(&mut None).unwrap_err();
+        assert!(format!("{:#}", err).contains(&staged.display().to_string()));
+
+        // Copies made by rsync are checked by hashing the sources afterwards
+        let mut ledger = CopyLedger::default();
+        ledger.record_tree(&source, &disc_root.join("ARCHIVE/photos"))?;
+        assert_eq!(ledger.len(), 2);
+        fs::write(disc_root.join("ARCHIVE/photos/a.jpg"), "JPEG")?;
+        assert!(ledger.verify(&mut None).is_err());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_policies() -> Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("source");
+        fs::create_dir_all(source.join("sub"))?;
+        fs::write(source.join("sub/data.bin"), vec![0u8; 1000])?;
+        symlink("sub/data.bin", source.join("data-link"))?;
+        // Points back at its own ancestor; following it naively would never terminate
+        symlink("..", source.join("sub/loop"))?;
+
+        let copy = |policy: SymlinkPolicy, name: &str| -> Result<PathBuf> {
+            let dest = temp_dir.path().join(name);
+            let options = StagingConfig {
+                symlink_policy: policy,
+                ..StagingConfig::default()
+            };
+            copy_directory_with_options(&source, &dest, &options, None)?;
+            Ok(dest)
+        };
+
+        let skipped = copy(SymlinkPolicy::Skip, "skip")?;
+        assert!(skipped.join("sub/data.bin").exists());
+        assert!(fs::symlink_metadata(skipped.join("data-link")).is_err());
+        assert!(fs::symlink_metadata(skipped.join("sub/loop")).is_err());
+        assert_eq!(analyze_directory_structure(&source, SymlinkPolicy::Skip.into())?.size_bytes, 1000);
+
+        let preserved = copy(SymlinkPolicy::Preserve, "preserve")?;
+        assert!(fs::symlink_metadata(preserved.join("data-link"))?.file_type().is_symlink());
+        assert_eq!(fs::read_link(preserved.join("sub/loop"))?, PathBuf::from(".."));
+
+        let followed = copy(SymlinkPolicy::Follow, "follow")?;
+        let link_copy = fs::symlink_metadata(followed.join("data-link"))?;
+        assert!(link_copy.is_file());
+        assert_eq!(link_copy.len(), 1000);
+        assert!(fs::symlink_metadata(followed.join("sub/loop")).is_err());
+        assert_eq!(analyze_directory_structure(&source, SymlinkPolicy::Follow.into())?.size_bytes, 2000);
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_follow_sibling_links_to_same_dir() -> Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = TempDir::new()?;
+        let shared = temp_dir.path().join("shared");
+        let source = temp_dir.path().join("source");
+        fs::create_dir_all(&shared)?;
+        fs::create_dir_all(&source)?;
+        fs::write(shared.join("data.bin"), vec![0u8; 100])?;
+        symlink(".", shared.join("self"))?;
+        symlink(&shared, source.join("a"))?;
+        symlink(&shared, source.join("b"))?;
+
+        // Both links are followed; only the link back into its own parent is a loop
+        let policy = WalkOptions::from(SymlinkPolicy::Follow);
+        let mut archive_paths: Vec<PathBuf> = collect_source_files(std::slice::from_ref(&source), policy)?
+            .into_iter()
+            .map(|f| f.archive_path)
+            .collect();
+        archive_paths.sort();
+        assert_eq!(archive_paths, [PathBuf::from("source/a/data.bin"), PathBuf::from("source/b/data.bin")]);
+        let excluded = find_excluded_paths(std::slice::from_ref(&source), policy)?;
+        assert_eq!(excluded.len(), 2);
+        assert!(excluded.iter().all(|e| e.path.ends_with("self")));
+
+        let dest = temp_dir.path().join("dest");
+        let options = StagingConfig { symlink_policy: SymlinkPolicy::Follow, ..StagingConfig::default() };
+        copy_directory_with_options(&source, &dest, &options, None)?;
+        assert!(dest.join("a/data.bin").is_file());
+        assert!(dest.join("b/data.bin").is_file());
+        assert_eq!(analyze_directory_structure(&source, policy)?.size_bytes, 200);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_include_hidden() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("source");
+        fs::create_dir_all(source.join(".git"))?;
+        fs::write(source.join("photo.jpg"), vec![0u8; 100])?;
+        fs::write(source.join(".DS_Store"), vec![0u8; 10])?;
+        fs::write(source.join(".git/config"), vec![0u8; 20])?;
+
+        let hidden = count_hidden_files(std::slice::from_ref(&source), SymlinkPolicy::Follow)?;
+        assert_eq!(hidden, HiddenFiles { count: 2, bytes: 30 });
+
+        let options = StagingConfig { include_hidden: false, ..StagingConfig::default() };
+        assert_eq!(analyze_directory_structure(&source, WalkOptions::from(&options))?.size_bytes, 100);
+        assert_eq!(analyze_directory_structure(&source, SymlinkPolicy::Follow.into())?.size_bytes, 130);
+
+        let disc_root = temp_dir.path().join("disc");
+        stage_files_with_options(&disc_root, &[(source.clone(), None)], false, false, &options, None, None, None)?;
+        assert!(disc_root.join("ARCHIVE/source/photo.jpg").exists());
+        assert!(!disc_root.join("ARCHIVE/source/.DS_Store").exists());
+        assert!(!disc_root.join("ARCHIVE/source/.git").exists());
+
+        let excluded = find_excluded_paths(&[source], WalkOptions::from(&options))?;
+        assert_eq!(excluded.len(), 2);
+
+        Ok(())
+    }
+
     #[test]
     fn test_calculate_directory_size() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -921,11 +3389,16 @@ mod tests {
         fs::write(test_dir.join("file.txt"), "test")?;
 
         let folders = vec![test_dir.to_path_buf()];
-        let (size, exceeds) = check_capacity(&folders, 1000)?;
+        let (estimate, exceeds) = check_capacity(&folders, 1_000_000, SymlinkPolicy::Follow.into())?;
 
-        assert!(size < 1000);
+        assert_eq!(estimate.content_bytes, 4);
+        assert!(estimate.iso_bytes > estimate.content_bytes);
         assert!(!exceeds);
 
+        // Content that fits can still make an ISO that doesn't
+        let (_, exceeds) = check_capacity(&folders, estimate.iso_bytes - 1, SymlinkPolicy::Follow.into())?;
+        assert!(exceeds);
+
         Ok(())
     }
 
@@ -947,7 +3420,7 @@ mod tests {
         fs::write(root_dir.join("subdir").join("file2.txt"), "012345678901234")?; // 15 bytes
         fs::write(root_dir.join("another_file.txt"), "01234567890123456789")?; // 20 bytes
 
-        let structure = analyze_directory_structure(&root_dir)?;
+        let structure = analyze_directory_structure(&root_dir, SymlinkPolicy::Follow.into())?;
 
         assert_eq!(structure.size_bytes, 45); // 10 + 15 + 20
         assert!(!structure.is_file);
@@ -960,6 +3433,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_analyze_directory_structure_cached() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root_dir = temp_dir.path().join("root");
+        let subdir = root_dir.join("subdir");
+        fs::create_dir_all(&subdir)?;
+        fs::write(root_dir.join("file1.txt"), "0123456789")?;
+        fs::write(subdir.join("file2.txt"), "012345678901234")?;
+
+        let walk = WalkOptions::from(SymlinkPolicy::Follow);
+        let cache = SizeCache::in_memory(walk);
+        let first = AnalysisProgress::default();
+        let structure = analyze_directory_structure_cached(&root_dir, walk, Some(&cache), &first)?;
+        assert_eq!(structure.size_bytes, 25);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(first.cached_dirs.load(Ordering::Relaxed), 0);
+
+        let second = AnalysisProgress::default();
+        let cached = analyze_directory_structure_cached(&root_dir, walk, Some(&cache), &second)?;
+        assert_eq!(second.cached_dirs.load(Ordering::Relaxed), 2);
+        assert_eq!(second.files.load(Ordering::Relaxed), 2);
+        assert_eq!(cached.size_bytes, 25);
+        assert_eq!(cached.children.len(), 2);
+
+        // A new file changes the directory's mtime, so its listing is read again
+        fs::write(subdir.join("file3.txt"), "01234")?;
+        filetime::set_file_mtime(&subdir, FileTime::from_unix_time(1, 0))?;
+        let third = AnalysisProgress::default();
+        let structure = analyze_directory_structure_cached(&root_dir, walk, Some(&cache), &third)?;
+        assert_eq!(structure.size_bytes, 30);
+        assert_eq!(third.cached_dirs.load(Ordering::Relaxed), 1);
+        Ok(())
+    }
+
     #[test]
     fn test_disc_plan_basic() {
         let capacity = 100 * 1024 * 1024; // 100MB
@@ -995,6 +3502,69 @@ mod tests {
         assert_eq!(plan.entries.len(), 0);
     }
 
+    #[test]
+    fn test_take_largest_files() {
+        let file = |path: &str, size_bytes| DirectoryEntry {
+            path: PathBuf::from(path),
+            size_bytes,
+            is_file: true,
+            children: Vec::new(),
+        };
+        let mut plan = DiscPlan::new(1, 1000);
+        plan.add_entry(DirectoryEntry {
+            path: PathBuf::from("/src/videos"),
+            size_bytes: 700,
+            is_file: false,
+            children: vec![file("/src/videos/a.mkv", 400), file("/src/videos/b.mkv", 300)],
+        });
+        plan.add_entry(file("/src/notes.txt", 250));
+
+        // 500 bytes over: the two largest files go, wherever they are
+        let removed = plan.take_largest_files(500);
+        let paths: Vec<_> = removed.iter().map(|f| f.path.to_str().unwrap()).collect();
+        assert_eq!(paths, vec!["/src/videos/a.mkv", "/src/videos/b.mkv"]);
+        assert_eq!(plan.used_bytes, 250);
+        // The emptied directory is dropped but remembered as split
+        assert_eq!(plan.entries.len(), 1);
+        assert_eq!(plan.split_directories, vec!["/src/videos".to_string()]);
+
+        // Nothing left to take beyond the last file
+        assert_eq!(plan.take_largest_files(10_000).len(), 1);
+        assert!(plan.take_largest_files(1).is_empty());
+        assert_eq!(plan.used_bytes, 0);
+    }
+
+    #[test]
+    fn test_split_directories_on_disc() {
+        let file = |path: &str, size| DirectoryEntry { path: PathBuf::from(path), size_bytes: size, is_file: true, children: Vec::new() };
+        let dir = |path: &str, children: Vec<DirectoryEntry>| DirectoryEntry {
+            path: PathBuf::from(path),
+            size_bytes: children.iter().map(|c| c.size_bytes).sum(),
+            is_file: false,
+            children,
+        };
+        let mut first = DiscPlan::new(1, 100);
+        first.add_entry(dir("/src/videos_part1", vec![file("/src/videos/a.mkv", 60), file("/src/videos/b.mkv", 30)]));
+        first.split_directories.push("/src/videos".to_string());
+        let mut second = DiscPlan::new(2, 100);
+        second.add_entry(dir("/src/videos_part2", vec![dir("/src/videos/extras", vec![file("/src/videos/extras/c.mkv", 50)])]));
+        second.add_entry(file("/src/notes.txt", 1));
+        second.split_directories.push("/src/videos".to_string());
+        let mut third = DiscPlan::new(3, 100);
+        third.add_entry(file("/src/photo.jpg", 10));
+        let plans = vec![first, second, third];
+
+        let on_second = split_directories_on_disc(&plans, 2);
+        assert_eq!(on_second.len(), 1);
+        let videos = &on_second[0];
+        assert_eq!(videos.directory, "/src/videos");
+        assert_eq!((videos.part, videos.total_parts), (2, 2));
+        assert_eq!(videos.parts[0].files, vec!["a.mkv".to_string(), "b.mkv".to_string()]);
+        assert_eq!((videos.parts[1].disc_number, videos.parts[1].files.clone()), (2, vec!["extras/c.mkv".to_string()]));
+        assert_eq!(split_directories_on_disc(&plans, 1)[0].part, 1);
+        assert!(split_directories_on_disc(&plans, 3).is_empty());
+    }
+
     #[test]
     fn test_plan_disc_layout_single_disc() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -1006,7 +3576,7 @@ mod tests {
         fs::write(source_dir.join("file2.txt"), vec![0u8; 20 * 1024 * 1024])?; // 20MB
 
         let disc_capacity = 100 * 1024 * 1024; // 100MB
-        let plans = plan_disc_layout(&[source_dir], disc_capacity)?;
+        let plans = plan_disc_layout(&[source_dir], disc_capacity, SymlinkPolicy::Follow.into())?;
 
         assert_eq!(plans.len(), 1);
         assert_eq!(plans[0].disc_number, 1);
@@ -1016,6 +3586,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_plan_dedup_reduces_used_bytes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source_dir = temp_dir.path().join("source");
+        fs::create_dir_all(source_dir.join("export"))?;
+        fs::write(source_dir.join("photo.jpg"), vec![1u8; 4096])?;
+        fs::write(source_dir.join("export/photo.jpg"), vec![1u8; 4096])?;
+        fs::write(source_dir.join("other.jpg"), vec![2u8; 4096])?;
+
+        let mut plans = plan_disc_layout(std::slice::from_ref(&source_dir), 1024 * 1024, SymlinkPolicy::Follow.into())?;
+        assert_eq!(plans[0].used_bytes, 3 * 4096);
+
+        assert_eq!(dedup_plans(&mut plans)?, 4096);
+        assert_eq!(plans[0].used_bytes, 2 * 4096);
+        assert_eq!(plans[0].duplicates.len(), 1);
+        assert_eq!(plans[0].duplicates[0].path, source_dir.join("photo.jpg"));
+        assert_eq!(plans[0].duplicates[0].original, source_dir.join("export/photo.jpg"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_plan_disc_layout_multiple_discs() -> Result<()> {
         let temp_dir = TempDir::new()?;
@@ -1036,7 +3627,7 @@ mod tests {
 
         let disc_capacity = 150 * 1024 * 1024; // 150MB discs
 
-        let plans = plan_disc_layout(&[source_dir], disc_capacity)?;
+        let plans = plan_disc_layout(&[source_dir], disc_capacity, SymlinkPolicy::Follow.into())?;
 
         assert!(plans.len() >= 2); // Should need at least 2 discs for 250MB
 
@@ -1051,5 +3642,136 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_estimate_plan() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source_dir = temp_dir.path().join("source");
+        fs::create_dir_all(source_dir.join("a"))?;
+        fs::create_dir_all(source_dir.join("b"))?;
+        fs::write(source_dir.join("a/one.bin"), vec![0u8; 600 * 1024])?;
+        fs::write(source_dir.join("b/two.bin"), vec![0u8; 600 * 1024])?;
+
+        let capacity = 1024 * 1024; // 1MB discs
+        let summary = estimate_plan(&[source_dir], capacity, SymlinkPolicy::Follow.into(), None)?;
+
+        assert_eq!(summary.disc_count, 2);
+        assert_eq!(summary.per_disc_sizes.len(), 2);
+        assert_eq!(summary.total_bytes, 1200 * 1024);
+        assert!(summary.utilization > 50.0 && summary.utilization <= 100.0);
+        assert!(summary.split_directories.is_empty());
+        assert!(summary.oversize_files.is_empty());
+        assert_eq!(summary.size_histogram.get(&SizeBucket::Tiny), Some(&(2, 1200 * 1024)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_histogram() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path().join("root");
+        fs::create_dir_all(root.join("sub"))?;
+        fs::write(root.join("a.txt"), vec![0u8; 10])?;
+        fs::write(root.join("sub/b.txt"), vec![0u8; 20])?;
+        fs::write(root.join("sub/c.bin"), vec![0u8; 2 * 1024 * 1024])?;
+
+        let structure = analyze_directory_structure(&root, SymlinkPolicy::Follow.into())?;
+        let histogram = size_histogram(&structure);
+        assert_eq!(histogram.get(&SizeBucket::Tiny), Some(&(2, 30)));
+        assert_eq!(histogram.get(&SizeBucket::Small), Some(&(1, 2 * 1024 * 1024)));
+        assert!(!histogram.contains_key(&SizeBucket::Large));
+
+        assert_eq!(SizeBucket::for_size(100 * 1024 * 1024), SizeBucket::Medium);
+        assert_eq!(SizeBucket::for_size(1024 * 1024 * 1024), SizeBucket::Large);
+        Ok(())
+    }
+
+    #[test]
+    fn test_oversize_files_are_reported_not_dropped() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source_dir = temp_dir.path().join("source");
+        fs::create_dir_all(source_dir.join("video"))?;
+        fs::write(source_dir.join("video/huge.mkv"), vec![0u8; 2 * 1024 * 1024])?;
+        fs::write(source_dir.join("small.txt"), vec![0u8; 1024])?;
+
+        let capacity = 1024 * 1024; // 1MB discs
+
+        let summary = estimate_plan(std::slice::from_ref(&source_dir), capacity, SymlinkPolicy::Follow.into(), None)?;
+        assert_eq!(summary.oversize_files.len(), 1);
+        assert!(summary.oversize_files[0].path.ends_with("video/huge.mkv"));
+        assert_eq!(summary.oversize_files[0].size_bytes, 2 * 1024 * 1024);
+        assert_eq!(summary.total_bytes, 2 * 1024 * 1024 + 1024);
+
+        let err = plan_disc_layout(&[source_dir], capacity, SymlinkPolicy::Follow.into()).unwrap_err();
+        assert!(err.to_string().contains("huge.mkv"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rsync_staging_works_with_or_without_rsync() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("docs");
+        fs::create_dir_all(&source)?;
+        fs::write(source.join("a.txt"), "alpha")?;
+        let disc_root = temp_dir.path().join("disc");
+
+        // Falls back to the built-in copy when rsync is not installed
+        stage_files_with_options(&disc_root, &[(source, None)], true, false, &StagingConfig::default(), None, None, None)?;
+        assert_eq!(fs::read_to_string(disc_root.join("ARCHIVE/docs/a.txt"))?, "alpha");
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_unreadable_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let source = temp_dir.path().join("docs");
+        fs::create_dir_all(&source)?;
+        fs::write(source.join("a.txt"), "alpha")?;
+        let file = |name: &str| SourceFile {
+            source: source.join(name),
+            archive_path: PathBuf::from("docs").join(name),
+            size_bytes: 5,
+            link_target: None,
+        };
+        // b.txt vanished between listing and copying
+        let files = [file("a.txt"), file("b.txt")];
+        let disc_root = temp_dir.path().join("disc");
+
+        assert!(stage_file_list(&disc_root, &files, false, &StagingConfig::default(), None, None, None).is_err());
+
+        let failed = FailedFiles::default();
+        stage_file_list(&disc_root, &files, false, &StagingConfig::default(), None, None, Some(&failed))?;
+        assert_eq!(fs::read_to_string(disc_root.join("ARCHIVE/docs/a.txt"))?, "alpha");
+        assert!(!disc_root.join("ARCHIVE/docs/b.txt").exists());
+        let failed = failed.take();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, source.join("b.txt"));
+
+        let report = disc_root.join(FAILED_FILES_FILE);
+        write_failed_files(&report, &failed)?;
+        let report = fs::read_to_string(report)?;
+        assert!(report.lines().nth(1).unwrap().starts_with(&format!("{}\t", source.join("b.txt").display())));
+
+        assert!(is_destination_error(&anyhow::Error::from(io::Error::from_raw_os_error(28)).context("copy")));
+        assert!(!is_destination_error(&anyhow::Error::from(io::Error::from(io::ErrorKind::PermissionDenied))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_staging_dir() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        assert_eq!(staging_space_needed(10, 25), 20);
+        assert_eq!(staging_space_needed(100, 25), 50);
+
+        check_staging_dir(temp_dir.path(), 1024)?;
+        let err = check_staging_dir(temp_dir.path(), u64::MAX).unwrap_err();
+        assert!(err.to_string().contains("Not enough space"));
+        assert!(check_staging_dir(&temp_dir.path().join("missing"), 0).is_err());
+        let file = temp_dir.path().join("file");
+        fs::write(&file, "x")?;
+        assert!(check_staging_dir(&file, 0).is_err());
+        Ok(())
+    }
 }
 
@@ -0,0 +1,88 @@
+use crate::theme::Theme;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Burn an ISO built elsewhere: asks for the image path.
+#[derive(Debug, Clone, Default)]
+pub struct BurnIsoView {
+    iso_path: String,
+    /// Status of the running or last burn
+    message: Option<String>,
+    burning: bool,
+}
+
+impl BurnIsoView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn iso_path(&self) -> &str {
+        self.iso_path.trim()
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        self.iso_path.push(c);
+    }
+
+    pub fn input_backspace(&mut self) {
+        self.iso_path.pop();
+    }
+
+    pub fn is_burning(&self) -> bool {
+        self.burning
+    }
+
+    pub fn set_message(&mut self, message: String) {
+        self.message = Some(message);
+    }
+
+    pub fn start_burn(&mut self, message: String) {
+        self.burning = true;
+        self.message = Some(message);
+    }
+
+    pub fn finish_burn(&mut self, message: String) {
+        self.burning = false;
+        self.message = Some(message);
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(6), Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let help = Paragraph::new(
+            "Enter the path of an ISO image to burn and add to the catalog.\n\
+             Files are indexed from its manifest.json if BlueVault made it; other\n\
+             images are recorded by label and size only and cannot be verified.",
+        )
+        .block(
+            Block::default()
+                .title("Burn Existing ISO")
+                .borders(Borders::ALL)
+                .border_style(theme.border_style()),
+        )
+        .style(theme.primary_style())
+        .wrap(Wrap { trim: true });
+        frame.render_widget(help, chunks[0]);
+
+        let input = Paragraph::new(format!("{}_", self.iso_path))
+            .block(
+                Block::default()
+                    .title("ISO Path  [Enter] Burn  [Esc] Back")
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(theme.highlight_style());
+        frame.render_widget(input, chunks[1]);
+
+        if let Some(message) = &self.message {
+            let style = if self.burning { theme.warning_style() } else { theme.primary_style() };
+            let para = Paragraph::new(message.as_str()).style(style).wrap(Wrap { trim: true });
+            frame.render_widget(para, chunks[2]);
+        }
+    }
+}
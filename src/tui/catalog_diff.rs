@@ -0,0 +1,156 @@
+use crate::search::format_size;
+use crate::theme::Theme;
+use crate::verify::DiscDiff;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Compare a disc's catalog entry with the mounted disc: asks for the
+/// mountpoint, then lists the files that differ.
+#[derive(Debug, Clone)]
+pub struct CatalogDiffView {
+    disc_id: String,
+    mountpoint: String,
+    diff: Option<DiscDiff>,
+    /// Why the last comparison failed
+    message: Option<String>,
+    selected: usize,
+}
+
+impl CatalogDiffView {
+    /// `mountpoint` pre-fills the path, e.g. where the disc is already mounted.
+    pub fn new(disc_id: String, mountpoint: Option<String>) -> Self {
+        Self {
+            disc_id,
+            mountpoint: mountpoint.unwrap_or_default(),
+            diff: None,
+            message: None,
+            selected: 0,
+        }
+    }
+
+    pub fn disc_id(&self) -> &str {
+        &self.disc_id
+    }
+
+    pub fn mountpoint(&self) -> &str {
+        self.mountpoint.trim()
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        self.mountpoint.push(c);
+    }
+
+    pub fn input_backspace(&mut self) {
+        self.mountpoint.pop();
+    }
+
+    pub fn set_diff(&mut self, diff: DiscDiff) {
+        self.diff = Some(diff);
+        self.message = None;
+        self.selected = 0;
+    }
+
+    pub fn set_message(&mut self, message: String) {
+        self.diff = None;
+        self.message = Some(message);
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1).min(self.lines().len().saturating_sub(1));
+    }
+
+    pub fn previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// One line per discrepancy, grouped by kind.
+    fn lines(&self) -> Vec<String> {
+        let Some(diff) = &self.diff else {
+            return Vec::new();
+        };
+        let mut lines = Vec::new();
+        for mismatch in &diff.checksum_mismatches {
+            lines.push(format!(
+                "≠ {}  (catalog {}, disc {})",
+                mismatch.path.display(),
+                format_size(mismatch.catalog_size),
+                format_size(mismatch.disc_size)
+            ));
+        }
+        lines.extend(diff.only_on_disc.iter().map(|path| format!("+ {}  (not in catalog)", path.display())));
+        lines.extend(diff.only_in_db.iter().map(|path| format!("- {}  (missing from disc)", path.display())));
+        lines
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(4), Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let help = Paragraph::new(
+            "Compare the files catalogued for this disc with the mounted disc by path and size.\n\
+             Quicker than verification, but contents are not re-hashed.",
+        )
+        .block(
+            Block::default()
+                .title(format!("Catalog Diff: {}", self.disc_id))
+                .borders(Borders::ALL)
+                .border_style(theme.border_style()),
+        )
+        .style(theme.primary_style())
+        .wrap(Wrap { trim: true });
+        frame.render_widget(help, chunks[0]);
+
+        let input = Paragraph::new(format!("{}_", self.mountpoint))
+            .block(
+                Block::default()
+                    .title("Mountpoint  [Enter] Compare  [↑↓] Scroll  [Esc] Back")
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(theme.highlight_style());
+        frame.render_widget(input, chunks[1]);
+
+        if let Some(message) = &self.message {
+            let para = Paragraph::new(message.as_str())
+                .style(theme.error_style())
+                .wrap(Wrap { trim: true });
+            frame.render_widget(para, chunks[2]);
+            return;
+        }
+        let Some(diff) = &self.diff else {
+            return;
+        };
+
+        let title = format!(
+            "{} matched, {} size mismatches, {} only on disc, {} missing from disc",
+            diff.matched,
+            diff.checksum_mismatches.len(),
+            diff.only_on_disc.len(),
+            diff.only_in_db.len()
+        );
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(theme.border_style());
+        if diff.is_clean() {
+            let para = Paragraph::new("✅ The disc matches the catalog")
+                .block(block)
+                .style(theme.success_style());
+            frame.render_widget(para, chunks[2]);
+            return;
+        }
+
+        let items: Vec<ListItem> = self.lines().into_iter().map(ListItem::new).collect();
+        let list = List::new(items)
+            .block(block)
+            .style(theme.warning_style())
+            .highlight_style(theme.highlight_style());
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
+        frame.render_stateful_widget(list, chunks[2], &mut state);
+    }
+}
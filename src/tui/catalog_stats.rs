@@ -0,0 +1,148 @@
+use crate::database::ArchiveStats;
+use crate::search::format_size;
+use crate::theme::Theme;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Row, Table, TableState},
+};
+
+/// Display name of an extension from [`crate::database::extension_breakdown`].
+pub fn extension_display(ext: &str) -> String {
+    if ext.is_empty() {
+        "(no extension)".to_string()
+    } else {
+        format!(".{}", ext)
+    }
+}
+
+/// Share of `bytes` in `total`, in whole percent.
+fn percent(bytes: u64, total: u64) -> u64 {
+    if total == 0 {
+        0
+    } else {
+        (bytes as f64 * 100.0 / total as f64).round() as u64
+    }
+}
+
+/// One-line summary of the largest types, e.g. ".mkv 60% · .cr2 30% · other 10%".
+pub fn extension_summary(breakdown: &[(String, u64, u64)], limit: usize) -> String {
+    let total: u64 = breakdown.iter().map(|(_, _, bytes)| bytes).sum();
+    if total == 0 {
+        return "(no data)".to_string();
+    }
+    let mut parts: Vec<String> = breakdown
+        .iter()
+        .take(limit)
+        .map(|(ext, _, bytes)| format!("{} {}%", extension_display(ext), percent(*bytes, total)))
+        .collect();
+    let rest: u64 = breakdown.iter().skip(limit).map(|(_, _, bytes)| bytes).sum();
+    if rest > 0 {
+        parts.push(format!("other {}%", percent(rest, total)));
+    }
+    parts.join(" · ")
+}
+
+/// Catalog-wide totals with the space taken by each file type.
+#[derive(Debug, Clone)]
+pub struct CatalogStatsView {
+    stats: ArchiveStats,
+    breakdown: Vec<(String, u64, u64)>,
+    selected: Option<usize>,
+}
+
+impl CatalogStatsView {
+    pub fn new(stats: ArchiveStats, breakdown: Vec<(String, u64, u64)>) -> Self {
+        let selected = if breakdown.is_empty() { None } else { Some(0) };
+        Self {
+            stats,
+            breakdown,
+            selected,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if let Some(sel) = self.selected {
+            if sel < self.breakdown.len().saturating_sub(1) {
+                self.selected = Some(sel + 1);
+            }
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if let Some(sel) = self.selected {
+            self.selected = Some(sel.saturating_sub(1));
+        }
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(4), Constraint::Min(3)])
+            .split(area);
+
+        let summary = format!(
+            "{} in {} files on {} discs ({} sets)\nLargest types: {}",
+            format_size(self.stats.total_bytes),
+            self.stats.file_count,
+            self.stats.disc_count,
+            self.stats.set_count,
+            extension_summary(&self.breakdown, 3)
+        );
+        let para = Paragraph::new(summary)
+            .block(
+                Block::default()
+                    .title("Catalog Stats")
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(theme.primary_style());
+        frame.render_widget(para, chunks[0]);
+
+        let block = Block::default()
+            .title("File Types  [↑↓/jk] Scroll  [Esc] Back")
+            .borders(Borders::ALL)
+            .border_style(theme.border_style());
+
+        if self.breakdown.is_empty() {
+            let para = Paragraph::new("No files indexed yet.")
+                .block(block)
+                .style(theme.dim_style());
+            frame.render_widget(para, chunks[1]);
+            return;
+        }
+
+        let total: u64 = self.breakdown.iter().map(|(_, _, bytes)| bytes).sum();
+        let rows: Vec<Row> = self
+            .breakdown
+            .iter()
+            .map(|(ext, count, bytes)| {
+                let share = percent(*bytes, total);
+                Row::new(vec![
+                    extension_display(ext),
+                    count.to_string(),
+                    format_size(*bytes),
+                    format!("{:>3}% {}", share, "█".repeat((share / 5) as usize)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(16),
+                Constraint::Length(10),
+                Constraint::Length(12),
+                Constraint::Length(26),
+            ],
+        )
+        .header(Row::new(vec!["Type", "Files", "Size", "Share"]).style(theme.secondary_style()))
+        .block(block)
+        .style(theme.primary_style())
+        .highlight_style(theme.highlight_style())
+        .highlight_symbol("▶ ");
+
+        let mut state = TableState::default();
+        state.select(self.selected);
+        frame.render_stateful_widget(table, chunks[1], &mut state);
+    }
+}
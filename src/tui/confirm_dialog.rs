@@ -0,0 +1,120 @@
+use crate::theme::Theme;
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Outcome of a key press while a confirmation dialog is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmResponse {
+    Confirmed,
+    Cancelled,
+    /// Key was swallowed; the dialog stays open
+    Pending,
+}
+
+/// Modal yes/no prompt shown on top of the current screen.
+///
+/// While open it receives every key press, so stray input cannot reach the
+/// screen underneath. Only `y` confirms; Enter is deliberately not accepted so
+/// that the key which opened the dialog cannot also answer it.
+#[derive(Debug, Clone)]
+pub struct ConfirmDialog {
+    title: String,
+    message: String,
+    confirm_label: String,
+}
+
+impl ConfirmDialog {
+    pub fn new(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            confirm_label: "Confirm".to_string(),
+        }
+    }
+
+    /// Label shown next to the `y` key hint (e.g. "Burn", "Delete")
+    pub fn with_confirm_label(mut self, label: impl Into<String>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    pub fn handle_key(&self, key: KeyCode) -> ConfirmResponse {
+        match key {
+            KeyCode::Char('y') | KeyCode::Char('Y') => ConfirmResponse::Confirmed,
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => ConfirmResponse::Cancelled,
+            _ => ConfirmResponse::Pending,
+        }
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let width = 60.min(area.width);
+        // Message lines plus a blank line, the key hints and the borders
+        let inner_width = width.saturating_sub(2).max(1) as usize;
+        let message_lines: usize = self
+            .message
+            .lines()
+            .map(|line| line.chars().count().div_ceil(inner_width).max(1))
+            .sum();
+        let height = (message_lines as u16 + 4).min(area.height);
+        let dialog = crate::ui::layout::GridLayout::centered_dialog(area, width, height);
+
+        let mut lines: Vec<Line> = self
+            .message
+            .lines()
+            .map(|line| Line::from(line.to_string()))
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(format!("[y] {}", self.confirm_label), theme.warning_style()),
+            Span::styled("  [n/Esc] Cancel", theme.dim_style()),
+        ]));
+        let para = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(self.title.as_str())
+                    .borders(Borders::ALL)
+                    .border_style(theme.warning_style()),
+            )
+            .style(theme.primary_style())
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(Clear, dialog);
+        frame.render_widget(para, dialog);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_key() {
+        let dialog = ConfirmDialog::new("Delete disc?", "This cannot be undone.");
+        assert_eq!(dialog.handle_key(KeyCode::Char('y')), ConfirmResponse::Confirmed);
+        assert_eq!(dialog.handle_key(KeyCode::Char('Y')), ConfirmResponse::Confirmed);
+        assert_eq!(dialog.handle_key(KeyCode::Char('n')), ConfirmResponse::Cancelled);
+        assert_eq!(dialog.handle_key(KeyCode::Char('N')), ConfirmResponse::Cancelled);
+        assert_eq!(dialog.handle_key(KeyCode::Esc), ConfirmResponse::Cancelled);
+
+        // Everything else is swallowed and leaves the dialog open
+        for key in [KeyCode::Char('q'), KeyCode::Char(' '), KeyCode::Tab, KeyCode::Left, KeyCode::Backspace] {
+            assert_eq!(dialog.handle_key(key), ConfirmResponse::Pending);
+        }
+    }
+
+    #[test]
+    fn test_default_does_not_confirm() {
+        // Nothing is preselected: Enter, which may have opened the dialog, answers nothing
+        let dialog = ConfirmDialog::new("Start burn?", "Burn 2024-BD-001?");
+        assert_eq!(dialog.handle_key(KeyCode::Enter), ConfirmResponse::Pending);
+        assert_eq!(dialog.confirm_label, "Confirm");
+
+        let dialog = dialog.with_confirm_label("Burn");
+        assert_eq!(dialog.confirm_label, "Burn");
+        assert_eq!(dialog.handle_key(KeyCode::Enter), ConfirmResponse::Pending);
+    }
+}
@@ -7,6 +7,7 @@ use ratatui::{
     prelude::*,
     widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
 };
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::mpsc;
@@ -25,6 +26,8 @@ pub struct DirectorySelector {
     entries: Vec<DirEntry>,
     /// Selected entry index in browser
     selected_index: usize,
+    /// Directories marked with Space, kept while navigating
+    marked: BTreeSet<PathBuf>,
     /// Validation error message
     error_message: Option<String>,
     /// Loading state for async directory reading
@@ -77,6 +80,7 @@ impl Default for DirectorySelector {
             current_dir: home.clone(),
             entries: Vec::new(), // Lazy load entries - don't load on default
             selected_index: 0,
+            marked: BTreeSet::new(),
             error_message: None,
             loading_state: LoadingState::Idle,
             loading_receiver: None,
@@ -312,6 +316,25 @@ impl DirectorySelector {
         }
     }
 
+    /// Mark or unmark the highlighted directory (".." cannot be marked)
+    pub fn toggle_mark(&mut self) {
+        if let Some(DirEntry::Directory(path)) = self.entries.get(self.selected_index) {
+            if !self.marked.remove(path) {
+                self.marked.insert(path.clone());
+            }
+        }
+    }
+
+    /// Number of marked directories, including those outside the current directory
+    pub fn marked_count(&self) -> usize {
+        self.marked.len()
+    }
+
+    /// Return all marked directories and clear the marks
+    pub fn take_marked(&mut self) -> Vec<PathBuf> {
+        std::mem::take(&mut self.marked).into_iter().collect()
+    }
+
     /// Get current directory path
     pub fn current_path(&self) -> &Path {
         &self.current_dir
@@ -544,12 +567,12 @@ impl DirectorySelector {
             .iter()
             .map(|entry| {
                 let display_name = match entry {
-                    DirEntry::Parent => "..".to_string(),
-                    DirEntry::Directory(path) => path
-                        .file_name()
-                        .unwrap_or_default()
-                        .to_string_lossy()
-                        .to_string(),
+                    DirEntry::Parent => "    ..".to_string(),
+                    DirEntry::Directory(path) => format!(
+                        "{} {}",
+                        if self.marked.contains(path) { "[x]" } else { "[ ]" },
+                        path.file_name().unwrap_or_default().to_string_lossy()
+                    ),
                 };
 
                 ListItem::new(display_name)
@@ -560,8 +583,14 @@ impl DirectorySelector {
             .block(
                 Block::default()
                     .title(if is_focused {
+                        let marked = if self.marked.is_empty() {
+                            String::new()
+                        } else {
+                            format!(", A: add {} marked", self.marked.len())
+                        };
                         format!(
-                            "Directory Browser [FOCUSED] - Enter: navigate, Insert: select - {}",
+                            "Directory Browser [FOCUSED] - Enter: navigate, Insert: select, Space: mark{} - {}",
+                            marked,
                             self.current_dir.display()
                         )
                     } else {
@@ -0,0 +1,268 @@
+use crate::database::{self, Disc, FileRecord};
+use crate::disc::format_timestamp_relative;
+use crate::search::format_size;
+use crate::theme::Theme;
+use crate::tui::catalog_stats::extension_summary;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Row, Table, TableState, Wrap},
+};
+
+/// Drill-down view for a single disc: metadata plus its indexed files.
+#[derive(Debug, Clone)]
+pub struct DiscDetail {
+    disc: Disc,
+    files: Vec<FileRecord>,
+    /// Files and bytes per extension, largest first
+    extensions: Vec<(String, u64, u64)>,
+    selected: Option<usize>,
+}
+
+impl DiscDetail {
+    pub fn new(disc: Disc, files: Vec<FileRecord>) -> Self {
+        let selected = if files.is_empty() { None } else { Some(0) };
+        let extensions = database::tally_extensions(files.iter().map(|f| (f.rel_path.as_str(), f.size)));
+        Self {
+            disc,
+            files,
+            extensions,
+            selected,
+        }
+    }
+
+    pub fn disc(&self) -> &Disc {
+        &self.disc
+    }
+
+    pub fn files(&self) -> &[FileRecord] {
+        &self.files
+    }
+
+    pub fn next(&mut self) {
+        if let Some(sel) = self.selected {
+            if sel < self.files.len().saturating_sub(1) {
+                self.selected = Some(sel + 1);
+            }
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if let Some(sel) = self.selected {
+            self.selected = Some(sel.saturating_sub(1));
+        }
+    }
+
+    pub fn page_down(&mut self, page: usize) {
+        if let Some(sel) = self.selected {
+            self.selected = Some((sel + page).min(self.files.len().saturating_sub(1)));
+        }
+    }
+
+    pub fn page_up(&mut self, page: usize) {
+        if let Some(sel) = self.selected {
+            self.selected = Some(sel.saturating_sub(page));
+        }
+    }
+
+    /// Source roots are stored as a JSON array; show them one per line.
+    fn source_roots_display(&self) -> String {
+        match self.disc.source_roots.as_deref() {
+            None => "(unknown)".to_string(),
+            Some(json) => serde_json::from_str::<Vec<String>>(json)
+                .map(|roots| roots.join(", "))
+                .unwrap_or_else(|_| json.to_string()),
+        }
+    }
+
+    /// Set membership, e.g. "SET-2024 (disc 2)".
+    fn set_display(&self) -> String {
+        match (&self.disc.set_id, self.disc.sequence_number) {
+            (Some(set_id), Some(sequence)) => format!("{} (disc {})", set_id, sequence),
+            (Some(set_id), None) => set_id.clone(),
+            (None, _) => "(single disc)".to_string(),
+        }
+    }
+
+    /// Burn timing, e.g. "12m 05s at 18.4 MB/s".
+    fn burn_stats_display(&self) -> String {
+        if self.disc.planned {
+            return "not burned (planned by a dry run)".to_string();
+        }
+        match (self.disc.burn_duration_secs, self.disc.avg_write_speed_mbps) {
+            (Some(secs), Some(speed)) => {
+                let secs = secs.round() as u64;
+                format!("{}m {:02}s at {:.1} MB/s", secs / 60, secs % 60, speed)
+            }
+            _ => "(not recorded)".to_string(),
+        }
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(10), Constraint::Min(3)])
+            .split(area);
+
+        let total_size: u64 = self.files.iter().map(|f| f.size).sum();
+        let info_text = format!(
+            "Created: {}  │  Volume: {}\nSet: {}\nNotes: {}\nLocation: {}\nSource roots: {}\nTool version: {}  │  {} files, {}\nBurn: {}  │  Media: {}\nTypes: {}",
+            format_timestamp_relative(&self.disc.created_at),
+            self.disc.volume_label,
+            self.set_display(),
+            self.disc.notes.as_deref().unwrap_or("(no notes)"),
+            self.disc.storage_location.as_deref().unwrap_or("(not recorded)"),
+            self.source_roots_display(),
+            self.disc.tool_version.as_deref().unwrap_or("(unknown)"),
+            self.files.len(),
+            format_size(total_size),
+            self.burn_stats_display(),
+            if self.disc.is_mdisc { "M-DISC" } else { "standard" },
+            extension_summary(&self.extensions, 4),
+        );
+        let info = Paragraph::new(info_text)
+            .block(
+                Block::default()
+                    .title(format!("Disc {}", self.disc.disc_id))
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(theme.primary_style())
+            .wrap(Wrap { trim: true });
+        frame.render_widget(info, chunks[0]);
+
+        let files_block = Block::default()
+            .title("Files  [↑↓/jk] Scroll  [PgUp/PgDn] Page  [d] Diff with disc  [r] Restore  [Esc] Back")
+            .borders(Borders::ALL)
+            .border_style(theme.border_style());
+
+        if self.files.is_empty() {
+            let para = Paragraph::new("No files indexed for this disc.")
+                .block(files_block)
+                .style(theme.dim_style());
+            frame.render_widget(para, chunks[1]);
+            return;
+        }
+
+        let rows: Vec<Row> = self
+            .files
+            .iter()
+            .map(|f| {
+                Row::new(vec![
+                    f.rel_path.clone(),
+                    format_size(f.size),
+                    f.checksum().map(|(_, checksum)| checksum.to_string()).unwrap_or_default(),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(30),
+                Constraint::Length(12),
+                Constraint::Length(66),
+            ],
+        )
+        .header(Row::new(vec!["Path", "Size", "Checksum"]).style(theme.secondary_style()))
+        .block(files_block)
+        .style(theme.primary_style())
+        .highlight_style(theme.highlight_style())
+        .highlight_symbol("▶ ");
+
+        let mut state = TableState::default();
+        state.select(self.selected);
+        frame.render_stateful_widget(table, chunks[1], &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disc() -> Disc {
+        Disc {
+            disc_id: "2024-BD-001".to_string(),
+            volume_label: "BDARCHIVE_2024_BD_001".to_string(),
+            created_at: "2024-01-15T10:30:00Z".to_string(),
+            notes: None,
+            iso_size: None,
+            burn_device: None,
+            checksum_manifest_hash: None,
+            qr_path: None,
+            source_roots: None,
+            tool_version: None,
+            set_id: None,
+            sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
+        }
+    }
+
+    fn file(rel_path: &str) -> FileRecord {
+        FileRecord {
+            id: None,
+            disc_id: "2024-BD-001".to_string(),
+            rel_path: rel_path.to_string(),
+            sha256: "a".repeat(64),
+            size: 1,
+            mtime: "2024-01-01T00:00:00Z".to_string(),
+            added_at: "2024-01-01T00:00:00Z".to_string(),
+            crc32: None,
+        }
+    }
+
+    #[test]
+    fn test_navigation_stays_in_range() {
+        let files = ["ARCHIVE/a.txt", "ARCHIVE/b.txt", "ARCHIVE/c.txt"].map(file).to_vec();
+        let mut detail = DiscDetail::new(disc(), files);
+        assert_eq!(detail.selected, Some(0));
+
+        detail.previous();
+        assert_eq!(detail.selected, Some(0));
+        detail.next();
+        detail.next();
+        detail.next();
+        assert_eq!(detail.selected, Some(2));
+        detail.page_up(10);
+        assert_eq!(detail.selected, Some(0));
+        detail.page_down(10);
+        assert_eq!(detail.selected, Some(2));
+
+        // Nothing to select on a disc without indexed files
+        let mut empty = DiscDetail::new(disc(), Vec::new());
+        empty.next();
+        empty.page_down(10);
+        assert_eq!(empty.selected, None);
+    }
+
+    #[test]
+    fn test_metadata_display() {
+        let detail = DiscDetail::new(disc(), Vec::new());
+        assert_eq!(detail.source_roots_display(), "(unknown)");
+        assert_eq!(detail.set_display(), "(single disc)");
+        assert_eq!(detail.burn_stats_display(), "(not recorded)");
+
+        let detail = DiscDetail::new(
+            Disc {
+                source_roots: Some(r#"["/home/me/photos","/home/me/docs"]"#.to_string()),
+                set_id: Some("SET-2024".to_string()),
+                sequence_number: Some(2),
+                burn_duration_secs: Some(725.4),
+                avg_write_speed_mbps: Some(18.44),
+                ..disc()
+            },
+            Vec::new(),
+        );
+        assert_eq!(detail.source_roots_display(), "/home/me/photos, /home/me/docs");
+        assert_eq!(detail.set_display(), "SET-2024 (disc 2)");
+        assert_eq!(detail.burn_stats_display(), "12m 05s at 18.4 MB/s");
+
+        // Roots written by older versions may not be JSON
+        let detail = DiscDetail::new(Disc { source_roots: Some("/srv/old".to_string()), planned: true, ..disc() }, Vec::new());
+        assert_eq!(detail.source_roots_display(), "/srv/old");
+        assert_eq!(detail.burn_stats_display(), "not burned (planned by a dry run)");
+    }
+}
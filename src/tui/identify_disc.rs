@@ -0,0 +1,79 @@
+use crate::theme::Theme;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Identify the disc in the drive: reads its disc ID and opens the catalog
+/// entry. The inverse of scanning a label, for discs that have none.
+#[derive(Debug, Clone, Default)]
+pub struct IdentifyDiscView {
+    /// Device to mount, or the directory the disc is already mounted at
+    source: String,
+    /// Why the last attempt did not open a disc
+    message: Option<String>,
+}
+
+impl IdentifyDiscView {
+    pub fn new(device: &str) -> Self {
+        Self {
+            source: device.to_string(),
+            message: None,
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        self.source.trim()
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        self.source.push(c);
+    }
+
+    pub fn input_backspace(&mut self) {
+        self.source.pop();
+    }
+
+    pub fn set_message(&mut self, message: String) {
+        self.message = Some(message);
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(6), Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let help = Paragraph::new(
+            "Insert an unlabeled disc and enter the drive's device, or the directory it is\n\
+             already mounted at. BlueVault reads the disc ID from DISC_INFO.txt and opens\n\
+             its catalog entry; a disc missing from the catalog can be imported.",
+        )
+        .block(
+            Block::default()
+                .title("Identify Inserted Disc")
+                .borders(Borders::ALL)
+                .border_style(theme.border_style()),
+        )
+        .style(theme.primary_style())
+        .wrap(Wrap { trim: true });
+        frame.render_widget(help, chunks[0]);
+
+        let input = Paragraph::new(format!("{}_", self.source))
+            .block(
+                Block::default()
+                    .title("Device or Mountpoint  [Enter] Identify  [Esc] Back")
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(theme.highlight_style());
+        frame.render_widget(input, chunks[1]);
+
+        if let Some(message) = &self.message {
+            let para = Paragraph::new(message.as_str())
+                .style(theme.warning_style())
+                .wrap(Wrap { trim: true });
+            frame.render_widget(para, chunks[2]);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::iso_library::LibraryEntry;
+use crate::search::format_size;
+use crate::theme::Theme;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+};
+use std::time::SystemTime;
+
+/// Re-burn from ISO: retained ISOs, most recently used first.
+#[derive(Debug, Clone)]
+pub struct IsoLibraryView {
+    entries: Vec<LibraryEntry>,
+    selected: Option<usize>,
+    /// Status of the running or last re-burn
+    message: Option<String>,
+    burning: bool,
+}
+
+impl IsoLibraryView {
+    pub fn new(entries: Vec<LibraryEntry>) -> Self {
+        let selected = if entries.is_empty() { None } else { Some(0) };
+        Self {
+            entries,
+            selected,
+            message: None,
+            burning: false,
+        }
+    }
+
+    pub fn selected_entry(&self) -> Option<&LibraryEntry> {
+        self.selected.and_then(|i| self.entries.get(i))
+    }
+
+    pub fn is_burning(&self) -> bool {
+        self.burning
+    }
+
+    pub fn start_burn(&mut self, message: String) {
+        self.burning = true;
+        self.message = Some(message);
+    }
+
+    pub fn finish_burn(&mut self, message: String) {
+        self.burning = false;
+        self.message = Some(message);
+    }
+
+    pub fn next(&mut self) {
+        if let Some(sel) = self.selected {
+            if sel < self.entries.len().saturating_sub(1) {
+                self.selected = Some(sel + 1);
+            }
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if let Some(sel) = self.selected {
+            if sel > 0 {
+                self.selected = Some(sel - 1);
+            }
+        }
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(4), Constraint::Min(0)])
+            .split(area);
+
+        let total: u64 = self.entries.iter().map(|e| e.size_bytes).sum();
+        let summary = self.message.clone().unwrap_or_else(|| {
+            format!(
+                "{} ISO(s) in the library ({}). Select one to burn a replacement disc.",
+                self.entries.len(),
+                format_size(total)
+            )
+        });
+        let style = if self.burning { theme.warning_style() } else { theme.primary_style() };
+        let para = Paragraph::new(summary)
+            .block(
+                Block::default()
+                    .title("Re-burn from ISO")
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(style)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(para, chunks[0]);
+
+        let title = "Most recently used first  [Enter] Burn  [Esc] Back";
+        if self.entries.is_empty() {
+            let para = Paragraph::new("No retained ISOs. Set iso_library.enabled = true to keep ISOs after burning.")
+                .block(
+                    Block::default()
+                        .title(title)
+                        .borders(Borders::ALL)
+                        .border_style(theme.border_style()),
+                )
+                .style(theme.dim_style());
+            frame.render_widget(para, chunks[1]);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .map(|e| {
+                ListItem::new(format!(
+                    "{} │ {} │ last used {}",
+                    e.iso.disc_id,
+                    format_size(e.size_bytes),
+                    last_used_display(e.last_used)
+                ))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .highlight_style(theme.highlight_style())
+            .highlight_symbol("▶ ");
+
+        let mut state = ratatui::widgets::ListState::default();
+        state.select(self.selected);
+        frame.render_stateful_widget(list, chunks[1], &mut state);
+    }
+}
+
+/// Age of the last use, e.g. "3 days ago".
+fn last_used_display(last_used: SystemTime) -> String {
+    let secs = SystemTime::now()
+        .duration_since(last_used)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{} min ago", secs / 60),
+        3600..=86399 => format!("{} h ago", secs / 3600),
+        _ => format!("{} days ago", secs / 86400),
+    }
+}
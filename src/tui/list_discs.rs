@@ -1,21 +1,52 @@
 use crate::database::Disc;
+use crate::disc::format_timestamp_relative;
 use crate::theme::Theme;
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
+use std::collections::HashMap;
+
+/// Where the disc list was left, so coming back lands on the same disc.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListPosition {
+    /// Selected disc; found again by ID in case discs were added or removed
+    pub disc_id: Option<String>,
+    pub index: usize,
+    /// First visible row
+    pub offset: usize,
+}
+
+/// Which field of the selected disc is being edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscField {
+    Notes,
+    /// Space-separated tags, e.g. `#photos #2024`
+    Tags,
+    /// Where the physical disc is stored
+    Location,
+}
 
 #[derive(Debug, Clone)]
 pub struct ListDiscs {
     discs: Vec<Disc>,
+    /// Tags by disc ID; untagged discs are absent
+    tags: HashMap<String, Vec<String>>,
     selected: Option<usize>,
+    /// Field being edited for the selected disc and its text (None when not editing)
+    editing: Option<(DiscField, String)>,
+    /// Kept between renders so the scroll offset doesn't jump
+    list_state: ListState,
 }
 
 impl Default for ListDiscs {
     fn default() -> Self {
         Self {
             discs: Vec::new(),
+            tags: HashMap::new(),
             selected: None,
+            editing: None,
+            list_state: ListState::default(),
         }
     }
 }
@@ -30,14 +61,93 @@ impl ListDiscs {
         self.selected = if self.discs.is_empty() { None } else { Some(0) };
     }
 
+    pub fn set_tags(&mut self, tags: HashMap<String, Vec<String>>) {
+        self.tags = tags;
+    }
+
     pub fn discs(&self) -> &[Disc] {
         &self.discs
     }
 
+    pub fn tags_for(&self, disc_id: &str) -> &[String] {
+        self.tags.get(disc_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
     pub fn selected(&self) -> Option<usize> {
         self.selected
     }
 
+    pub fn selected_disc(&self) -> Option<&Disc> {
+        self.selected.and_then(|i| self.discs.get(i))
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if !self.discs.is_empty() {
+            self.selected = Some(index.min(self.discs.len() - 1));
+        }
+    }
+
+    pub fn position(&self) -> ListPosition {
+        ListPosition {
+            disc_id: self.selected_disc().map(|d| d.disc_id.clone()),
+            index: self.selected.unwrap_or(0),
+            offset: self.list_state.offset(),
+        }
+    }
+
+    /// Select the disc from `position` (by ID, else by index) and scroll back to where the list was.
+    pub fn restore_position(&mut self, position: &ListPosition) {
+        let index = position
+            .disc_id
+            .as_ref()
+            .and_then(|id| self.discs.iter().position(|d| &d.disc_id == id))
+            .unwrap_or(position.index);
+        self.select(index);
+        *self.list_state.offset_mut() = position.offset.min(self.discs.len().saturating_sub(1));
+    }
+
+    /// Start editing a field of the selected disc, pre-filled with its current value.
+    pub fn start_editing(&mut self, field: DiscField) {
+        if let Some(disc) = self.selected_disc() {
+            let value = match field {
+                DiscField::Notes => disc.notes.clone().unwrap_or_default(),
+                DiscField::Location => disc.storage_location.clone().unwrap_or_default(),
+                DiscField::Tags => self
+                    .tags_for(&disc.disc_id)
+                    .iter()
+                    .map(|tag| format!("#{}", tag))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            };
+            self.editing = Some((field, value));
+        }
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        if let Some((_, ref mut input)) = self.editing {
+            input.push(c);
+        }
+    }
+
+    pub fn input_backspace(&mut self) {
+        if let Some((_, ref mut input)) = self.editing {
+            input.pop();
+        }
+    }
+
+    /// Stop editing and return the field and the entered text.
+    pub fn finish_editing(&mut self) -> Option<(DiscField, String)> {
+        self.editing.take()
+    }
+
+    pub fn cancel_editing(&mut self) {
+        self.editing = None;
+    }
+
     pub fn next(&mut self) {
         if let Some(sel) = self.selected {
             if sel < self.discs.len().saturating_sub(1) {
@@ -56,7 +166,7 @@ impl ListDiscs {
         }
     }
 
-    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+    pub fn render(&mut self, theme: &Theme, frame: &mut Frame, area: Rect) {
         if self.discs.is_empty() {
             let text = "No discs in archive.";
             let para = Paragraph::new(text)
@@ -73,31 +183,140 @@ impl ListDiscs {
                 .discs
                 .iter()
                 .map(|d| {
-                    ListItem::new(format!(
+                    let mut line = format!(
                         "{} │ {} │ {}",
                         d.disc_id,
-                        d.created_at,
+                        format_timestamp_relative(&d.created_at),
                         d.notes.as_deref().unwrap_or("(no notes)")
-                    ))
+                    );
+                    if let Some(ref shelf) = d.storage_location {
+                        line.push_str(&format!(" @ {}", shelf));
+                    }
+                    for tag in self.tags_for(&d.disc_id) {
+                        line.push_str(&format!(" #{}", tag));
+                    }
+                    if d.planned {
+                        line.push_str(" [planned - not burned]");
+                        return ListItem::new(line).style(theme.dim_style());
+                    }
+                    ListItem::new(line)
                 })
                 .collect();
 
             let list = List::new(items)
                 .block(
                     Block::default()
-                        .title("Discs")
+                        .title("Discs  [Enter] Details  [e] Notes  [t] Tags  [l] Location  [Esc] Back")
                         .borders(Borders::ALL)
                         .border_style(theme.border_style()),
                 )
                 .highlight_style(theme.highlight_style())
                 .highlight_symbol("▶ ");
 
-            let mut state = ratatui::widgets::ListState::default();
-            if let Some(sel) = self.selected {
-                state.select(Some(sel));
+            self.list_state.select(self.selected);
+            frame.render_stateful_widget(list, area, &mut self.list_state);
+
+            if let Some((field, ref input)) = self.editing {
+                let dialog = crate::ui::layout::GridLayout::centered_dialog(area, 70, 7);
+                let disc_id = self
+                    .selected_disc()
+                    .map(|d| d.disc_id.as_str())
+                    .unwrap_or_default();
+                let (title, hint) = match field {
+                    DiscField::Notes => (
+                        "Edit notes",
+                        "Updates the database index only; DISC_INFO.txt on the burned disc is unchanged.",
+                    ),
+                    DiscField::Tags => ("Edit tags", "Separate tags with spaces, e.g. #photos #2024 #offsite"),
+                    DiscField::Location => (
+                        "Edit storage location",
+                        "Where the physical disc is kept, e.g. Binder A, Slot 12. The label is regenerated.",
+                    ),
+                };
+                let text = format!("{}\n\n{}\n[Enter] Save  [Esc] Cancel", input, hint);
+                let para = Paragraph::new(text)
+                    .block(
+                        Block::default()
+                            .title(format!("{}: {}", title, disc_id))
+                            .borders(Borders::ALL)
+                            .border_style(theme.border_style()),
+                    )
+                    .style(theme.primary_style())
+                    .wrap(ratatui::widgets::Wrap { trim: false });
+                frame.render_widget(ratatui::widgets::Clear, dialog);
+                frame.render_widget(para, dialog);
             }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-            frame.render_stateful_widget(list, area, &mut state);
+    fn disc(disc_id: &str) -> Disc {
+        Disc {
+            disc_id: disc_id.to_string(),
+            volume_label: disc_id.to_string(),
+            created_at: "2024-01-15T10:30:00Z".to_string(),
+            notes: None,
+            iso_size: None,
+            burn_device: None,
+            checksum_manifest_hash: None,
+            qr_path: None,
+            source_roots: None,
+            tool_version: None,
+            set_id: None,
+            sequence_number: None,
+            burn_duration_secs: None,
+            avg_write_speed_mbps: None,
+            is_mdisc: false,
+            storage_location: None,
+            planned: false,
         }
     }
+
+    #[test]
+    fn test_select_clamps_to_list() {
+        let mut list = ListDiscs::new();
+        list.select(3);
+        assert!(list.selected_disc().is_none());
+
+        list.set_discs(vec![disc("2024-BD-001"), disc("2024-BD-002")]);
+        assert_eq!(list.selected_disc().map(|d| d.disc_id.as_str()), Some("2024-BD-001"));
+        list.select(5);
+        assert_eq!(list.selected(), Some(1));
+        assert_eq!(list.selected_disc().map(|d| d.disc_id.as_str()), Some("2024-BD-002"));
+    }
+
+    #[test]
+    fn test_restore_position() {
+        let mut list = ListDiscs::new();
+        list.set_discs(["2024-BD-001", "2024-BD-002", "2024-BD-003"].map(disc).to_vec());
+        list.select(1);
+        *list.list_state.offset_mut() = 1;
+        let position = list.position();
+        assert_eq!(
+            position,
+            ListPosition {
+                disc_id: Some("2024-BD-002".to_string()),
+                index: 1,
+                offset: 1,
+            }
+        );
+
+        // The same disc is found again after one was added in front of it
+        let mut list = ListDiscs::new();
+        list.set_discs(["2024-BD-000", "2024-BD-001", "2024-BD-002", "2024-BD-003"].map(disc).to_vec());
+        list.restore_position(&position);
+        assert_eq!(list.selected_disc().map(|d| d.disc_id.as_str()), Some("2024-BD-002"));
+        assert_eq!(list.list_state.offset(), 1);
+
+        // A deleted disc falls back to its old row, clamped to the list
+        let mut list = ListDiscs::new();
+        list.set_discs(vec![disc("2024-BD-001")]);
+        list.restore_position(&position);
+        assert_eq!(list.selected(), Some(0));
+        assert_eq!(list.list_state.offset(), 0);
+    }
 }
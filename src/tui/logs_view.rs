@@ -1,35 +1,415 @@
+use crate::logging::{self, LogLevel};
 use crate::theme::Theme;
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Paragraph},
 };
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
+/// Only the end of a large log file is loaded when the viewer opens.
+const TAIL_BYTES: u64 = 2 * 1024 * 1024;
+/// Oldest lines are dropped beyond this many.
+const MAX_LINES: usize = 20_000;
+
+#[derive(Debug, Clone)]
+struct LogLine {
+    /// Level of the line, or of the entry it continues
+    level: Option<LogLevel>,
+    text: String,
+}
+
+/// Live view of the current log file with level filtering and search.
 #[derive(Debug, Clone)]
 pub struct LogsView {
-    // Placeholder for logs viewer
+    path: Option<PathBuf>,
+    lines: Vec<LogLine>,
+    /// Bytes of the file consumed so far
+    offset: u64,
+    /// Trailing text not yet terminated by a newline
+    partial: String,
+    /// Hide lines below this level (None = show everything)
+    min_level: Option<LogLevel>,
+    /// Index of the first visible line among the filtered lines
+    scroll: usize,
+    /// Keep the view pinned to the newest lines
+    follow: bool,
+    viewport_height: usize,
+    /// Search text being typed after '/'
+    search_input: Option<String>,
+    query: Option<String>,
+    /// Filtered line index of the focused match
+    current_match: Option<usize>,
+    error: Option<String>,
+    last_refresh: Instant,
 }
 
 impl Default for LogsView {
     fn default() -> Self {
-        Self {}
+        Self {
+            path: None,
+            lines: Vec::new(),
+            offset: 0,
+            partial: String::new(),
+            min_level: None,
+            scroll: 0,
+            follow: true,
+            viewport_height: 0,
+            search_input: None,
+            query: None,
+            current_match: None,
+            error: None,
+            last_refresh: Instant::now(),
+        }
     }
 }
 
 impl LogsView {
     pub fn new() -> Self {
-        Self::default()
-    }
-
-    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
-        let text = "Logs / Recent Runs\n\n[Esc] Back to menu";
-        let para = Paragraph::new(text)
-            .block(
-                Block::default()
-                    .title("Logs")
-                    .borders(Borders::ALL)
-                    .border_style(theme.border_style()),
-            )
-            .style(theme.primary_style());
-        frame.render_widget(para, area);
+        let mut view = Self::default();
+        view.refresh();
+        view
+    }
+
+    /// Re-read the log file if `interval` has passed since the last read.
+    pub fn refresh_if_stale(&mut self, interval: Duration) -> bool {
+        if self.last_refresh.elapsed() < interval {
+            return false;
+        }
+        self.refresh()
+    }
+
+    /// Append whatever was written to the log since the last read.
+    /// Switches to a newer file when the log rotates. Returns true if lines were added.
+    pub fn refresh(&mut self) -> bool {
+        self.last_refresh = Instant::now();
+        let latest = match crate::paths::logs_dir() {
+            Ok(dir) => logging::latest_log_file(&dir),
+            Err(e) => {
+                self.error = Some(format!("Cannot locate logs directory: {}", e));
+                return false;
+            }
+        };
+        let Some(latest) = latest else {
+            self.error = Some("No log files found yet".to_string());
+            return false;
+        };
+        if self.path.as_ref() != Some(&latest) {
+            self.path = Some(latest);
+            self.reset();
+        }
+
+        match self.read_new_bytes() {
+            Ok(added) => {
+                self.error = None;
+                added
+            }
+            Err(e) => {
+                self.error = Some(format!("Failed to read log: {}", e));
+                false
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.lines.clear();
+        self.offset = 0;
+        self.partial.clear();
+        self.scroll = 0;
+        self.current_match = None;
+    }
+
+    fn read_new_bytes(&mut self) -> std::io::Result<bool> {
+        let Some(path) = self.path.clone() else {
+            return Ok(false);
+        };
+        let mut file = std::fs::File::open(&path)?;
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            // Truncated or replaced: start over
+            self.reset();
+        }
+        if len == self.offset {
+            return Ok(false);
+        }
+
+        // On first read of a large file, skip ahead and drop the cut-off first line
+        let skip_partial_line = self.offset == 0 && len > TAIL_BYTES;
+        if skip_partial_line {
+            self.offset = len - TAIL_BYTES;
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+        self.offset += buf.len() as u64;
+
+        let mut text = std::mem::take(&mut self.partial);
+        text.push_str(&String::from_utf8_lossy(&buf));
+        let mut pieces: Vec<&str> = text.split('\n').collect();
+        // The last piece is empty when the chunk ends with a newline, otherwise incomplete
+        self.partial = pieces.pop().unwrap_or_default().to_string();
+        if skip_partial_line && !pieces.is_empty() {
+            pieces.remove(0);
+        }
+
+        let added = !pieces.is_empty();
+        for piece in pieces {
+            let text = piece.trim_end_matches('\r').to_string();
+            let level = logging::parse_log_level(&text)
+                .or_else(|| self.lines.last().and_then(|l| l.level));
+            self.lines.push(LogLine { level, text });
+        }
+        if self.lines.len() > MAX_LINES {
+            let excess = self.lines.len() - MAX_LINES;
+            self.lines.drain(..excess);
+            self.current_match = None;
+        }
+        Ok(added)
+    }
+
+    /// Indices into `lines` that pass the level filter.
+    fn filtered(&self) -> Vec<usize> {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| match self.min_level {
+                None => true,
+                Some(min) => line.level.is_some_and(|level| level >= min),
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn max_scroll(&self, visible: usize) -> usize {
+        visible.saturating_sub(self.viewport_height.max(1))
+    }
+
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.follow = false;
+        self.scroll = self.scroll.saturating_sub(amount);
+    }
+
+    pub fn scroll_down(&mut self, amount: usize) {
+        let max = self.max_scroll(self.filtered().len());
+        self.scroll = (self.scroll + amount).min(max);
+        // Reaching the bottom resumes following
+        self.follow = self.scroll >= max;
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.viewport_height.max(1)
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.follow = false;
+        self.scroll = 0;
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.follow = true;
+    }
+
+    pub fn toggle_follow(&mut self) {
+        self.follow = !self.follow;
+    }
+
+    /// Cycle the level filter: all → INFO+ → WARN+ → ERROR → all.
+    pub fn cycle_level_filter(&mut self) {
+        self.min_level = match self.min_level {
+            None => Some(LogLevel::Info),
+            Some(LogLevel::Info) => Some(LogLevel::Warn),
+            Some(LogLevel::Warn) => Some(LogLevel::Error),
+            _ => None,
+        };
+        self.scroll = 0;
+        self.current_match = None;
+    }
+
+    pub fn is_searching(&self) -> bool {
+        self.search_input.is_some()
+    }
+
+    pub fn start_search(&mut self) {
+        self.search_input = Some(String::new());
+    }
+
+    pub fn cancel_search(&mut self) {
+        self.search_input = None;
+    }
+
+    pub fn push_search_char(&mut self, c: char) {
+        if let Some(ref mut input) = self.search_input {
+            input.push(c);
+        }
+    }
+
+    pub fn pop_search_char(&mut self) {
+        if let Some(ref mut input) = self.search_input {
+            input.pop();
+        }
+    }
+
+    /// Apply the typed query and jump to the last match (the newest entry).
+    pub fn commit_search(&mut self) {
+        let input = self.search_input.take().unwrap_or_default();
+        if input.is_empty() {
+            self.query = None;
+            self.current_match = None;
+            return;
+        }
+        self.query = Some(input.to_ascii_lowercase());
+        self.current_match = None;
+        self.previous_match();
+    }
+
+    fn matching_lines(&self, filtered: &[usize]) -> Vec<usize> {
+        let Some(ref query) = self.query else {
+            return Vec::new();
+        };
+        filtered
+            .iter()
+            .enumerate()
+            .filter(|(_, &i)| self.lines[i].text.to_ascii_lowercase().contains(query.as_str()))
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    pub fn next_match(&mut self) {
+        let filtered = self.filtered();
+        let matches = self.matching_lines(&filtered);
+        let next = match self.current_match {
+            Some(current) => matches.iter().copied().find(|&m| m > current).or(matches.first().copied()),
+            None => matches.first().copied(),
+        };
+        self.focus_match(next, filtered.len());
+    }
+
+    pub fn previous_match(&mut self) {
+        let filtered = self.filtered();
+        let matches = self.matching_lines(&filtered);
+        let previous = match self.current_match {
+            Some(current) => matches.iter().rev().copied().find(|&m| m < current).or(matches.last().copied()),
+            None => matches.last().copied(),
+        };
+        self.focus_match(previous, filtered.len());
+    }
+
+    fn focus_match(&mut self, target: Option<usize>, visible: usize) {
+        self.current_match = target;
+        if let Some(pos) = target {
+            self.follow = false;
+            self.scroll = pos
+                .saturating_sub(self.viewport_height / 2)
+                .min(self.max_scroll(visible));
+        }
+    }
+
+    fn highlighted_line<'a>(&self, text: &'a str, base: Style, theme: &Theme) -> Line<'a> {
+        let Some(ref query) = self.query else {
+            return Line::from(Span::styled(text, base));
+        };
+        // ASCII lowercasing keeps byte offsets aligned with the original text
+        let lower = text.to_ascii_lowercase();
+        let mut spans = Vec::new();
+        let mut start = 0;
+        while let Some(found) = lower[start..].find(query.as_str()) {
+            let begin = start + found;
+            let end = begin + query.len();
+            if begin > start {
+                spans.push(Span::styled(&text[start..begin], base));
+            }
+            spans.push(Span::styled(&text[begin..end], theme.highlight_style()));
+            start = end;
+        }
+        if start < text.len() {
+            spans.push(Span::styled(&text[start..], base));
+        }
+        Line::from(spans)
+    }
+
+    pub fn render(&mut self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(area);
+
+        self.viewport_height = chunks[0].height.saturating_sub(2) as usize;
+        let filtered = self.filtered();
+        let max_scroll = self.max_scroll(filtered.len());
+        if self.follow {
+            self.scroll = max_scroll;
+        }
+        self.scroll = self.scroll.min(max_scroll);
+
+        let lines: Vec<Line> = if let Some(ref error) = self.error {
+            vec![Line::from(Span::styled(error.as_str(), theme.warning_style()))]
+        } else {
+            filtered
+                .iter()
+                .enumerate()
+                .skip(self.scroll)
+                .take(self.viewport_height)
+                .map(|(pos, &i)| {
+                    let line = &self.lines[i];
+                    let mut style = match line.level {
+                        Some(LogLevel::Error) => theme.error_style(),
+                        Some(LogLevel::Warn) => theme.warning_style(),
+                        Some(LogLevel::Debug) | Some(LogLevel::Trace) => theme.dim_style(),
+                        _ => theme.primary_style(),
+                    };
+                    if self.current_match == Some(pos) {
+                        style = style.add_modifier(Modifier::BOLD);
+                    }
+                    self.highlighted_line(&line.text, style, theme)
+                })
+                .collect()
+        };
+
+        let log_path = self
+            .path
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|| "no log file".to_string());
+        let level = match self.min_level {
+            None => "all levels".to_string(),
+            Some(LogLevel::Error) => "ERROR only".to_string(),
+            Some(min) => format!("{}+", min.label()),
+        };
+        let title = format!(
+            "Logs: {}  [{}]{}",
+            log_path,
+            level,
+            if self.follow { "  [following]" } else { "" }
+        );
+        let para = Paragraph::new(lines).block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(theme.border_style()),
+        );
+        frame.render_widget(para, chunks[0]);
+
+        let status = if let Some(ref input) = self.search_input {
+            Line::from(vec![
+                Span::styled("/", theme.highlight_style()),
+                Span::styled(input.as_str(), theme.primary_style()),
+                Span::styled("  [Enter] Search  [Esc] Cancel", theme.dim_style()),
+            ])
+        } else {
+            let mut hint = String::new();
+            if let Some(ref query) = self.query {
+                let matches = self.matching_lines(&filtered);
+                let position = self
+                    .current_match
+                    .and_then(|current| matches.iter().position(|&m| m == current))
+                    .map(|p| (p + 1).to_string())
+                    .unwrap_or_else(|| "-".to_string());
+                hint.push_str(&format!("\"{}\" {}/{}  [n/N] Next/Prev  ", query, position, matches.len()));
+            }
+            hint.push_str("[/] Search  [l] Level  [f] Follow  [g/G] Top/Bottom  [Esc] Back");
+            Line::from(Span::styled(hint, theme.dim_style()))
+        };
+        frame.render_widget(Paragraph::new(status), chunks[1]);
     }
 }
@@ -1,31 +1,60 @@
+use crate::database::ArchiveStats;
+use crate::search::format_size;
 use crate::theme::Theme;
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
 };
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum MainMenuAction {
     NewDisc,
     ResumeBurn,
+    ReburnIso,
+    BurnExistingIso,
     SearchIndex,
     VerifyDisc,
     VerifyMultiDisc,
     ListDiscs,
+    CatalogStats,
+    ScanLabel,
+    IdentifyDisc,
+    Scrub,
     Settings,
     Logs,
     Cleanup,
     Quit,
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Catalog and staging totals shown next to the menu.
+#[derive(Debug, Clone)]
+pub struct Dashboard {
+    pub stats: ArchiveStats,
+    /// Free space where discs are staged (None if it couldn't be determined)
+    pub staging_free_bytes: Option<u64>,
+    /// Free space on the volume holding the catalog (None if it couldn't be determined)
+    pub catalog_free_bytes: Option<u64>,
+    /// `min_data_free_mb` in bytes; less catalog space than this blocks burns
+    pub min_catalog_free_bytes: u64,
+    pub refreshed_at: Instant,
+}
+
+#[derive(Debug, Clone)]
 pub struct MainMenu {
     selected: usize,
+    dashboard: Option<Dashboard>,
+    /// Outcome of the last action, shown below the menu until the next key
+    notice: Option<String>,
 }
 
 impl Default for MainMenu {
     fn default() -> Self {
-        Self { selected: 0 }
+        Self {
+            selected: 0,
+            dashboard: None,
+            notice: None,
+        }
     }
 }
 
@@ -35,29 +64,54 @@ impl MainMenu {
     }
 
     pub fn next(&mut self) {
-        self.selected = (self.selected + 1) % 10;
+        self.selected = (self.selected + 1) % 16;
     }
 
     pub fn previous(&mut self) {
         if self.selected == 0 {
-            self.selected = 9;
+            self.selected = 15;
         } else {
             self.selected -= 1;
         }
     }
 
+    pub fn set_dashboard(&mut self, dashboard: Dashboard) {
+        self.dashboard = Some(dashboard);
+    }
+
+    pub fn set_notice(&mut self, notice: String) {
+        self.notice = Some(notice);
+    }
+
+    pub fn clear_notice(&mut self) {
+        self.notice = None;
+    }
+
+    /// Whether the dashboard is missing or older than `max_age`.
+    pub fn dashboard_is_stale(&self, max_age: Duration) -> bool {
+        self.dashboard
+            .as_ref()
+            .is_none_or(|d| d.refreshed_at.elapsed() >= max_age)
+    }
+
     pub fn selected_action(&self) -> MainMenuAction {
         match self.selected {
             0 => MainMenuAction::NewDisc,
             1 => MainMenuAction::ResumeBurn,
-            2 => MainMenuAction::SearchIndex,
-            3 => MainMenuAction::VerifyDisc,
-            4 => MainMenuAction::VerifyMultiDisc,
-            5 => MainMenuAction::ListDiscs,
-            6 => MainMenuAction::Settings,
-            7 => MainMenuAction::Logs,
-            8 => MainMenuAction::Cleanup,
-            9 => MainMenuAction::Quit,
+            2 => MainMenuAction::ReburnIso,
+            3 => MainMenuAction::BurnExistingIso,
+            4 => MainMenuAction::SearchIndex,
+            5 => MainMenuAction::VerifyDisc,
+            6 => MainMenuAction::VerifyMultiDisc,
+            7 => MainMenuAction::ListDiscs,
+            8 => MainMenuAction::CatalogStats,
+            9 => MainMenuAction::ScanLabel,
+            10 => MainMenuAction::IdentifyDisc,
+            11 => MainMenuAction::Scrub,
+            12 => MainMenuAction::Settings,
+            13 => MainMenuAction::Logs,
+            14 => MainMenuAction::Cleanup,
+            15 => MainMenuAction::Quit,
             _ => MainMenuAction::Quit,
         }
     }
@@ -66,10 +120,16 @@ impl MainMenu {
         let items = vec![
             ListItem::new("New Disc / Archive Folders"),
             ListItem::new("⏸️  Resume Paused Burn"),
+            ListItem::new("Re-burn from ISO"),
+            ListItem::new("Burn Existing ISO"),
             ListItem::new("Search Index"),
             ListItem::new("Verify Disc"),
             ListItem::new("🔍 Verify Multi-Disc Set"),
             ListItem::new("List Discs"),
+            ListItem::new("Catalog Stats (File Types)"),
+            ListItem::new("Scan Disc Label (QR)"),
+            ListItem::new("Identify Inserted Disc"),
+            ListItem::new("Scrub Report (Discs Due for Re-verify)"),
             ListItem::new("Settings"),
             ListItem::new("Logs / Recent Runs"),
             ListItem::new("🧹 Cleanup Temporary Files"),
@@ -87,8 +147,85 @@ impl MainMenu {
             .highlight_style(theme.highlight_style())
             .highlight_symbol("▶ ");
 
+        let area = match &self.notice {
+            Some(notice) => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(8), Constraint::Length(notice.lines().count() as u16 + 2)])
+                    .split(area);
+                let para = Paragraph::new(notice.as_str())
+                    .wrap(ratatui::widgets::Wrap { trim: true })
+                    .style(theme.warning_style())
+                    .block(
+                        Block::default()
+                            .title("Last Action")
+                            .borders(Borders::ALL)
+                            .border_style(theme.border_style()),
+                    );
+                frame.render_widget(para, chunks[1]);
+                chunks[0]
+            }
+            None => area,
+        };
+
+        // Dashboard to the right of the menu when there is room
+        let (menu_area, dashboard_area) = match self.dashboard {
+            Some(_) if area.width >= 80 => {
+                let chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Min(40), Constraint::Length(36)])
+                    .split(area);
+                (chunks[0], Some(chunks[1]))
+            }
+            _ => (area, None),
+        };
+
         let mut state = ratatui::widgets::ListState::default();
         state.select(Some(self.selected));
-        frame.render_stateful_widget(list, area, &mut state);
+        frame.render_stateful_widget(list, menu_area, &mut state);
+
+        if let (Some(dashboard), Some(dashboard_area)) = (&self.dashboard, dashboard_area) {
+            self.render_dashboard(dashboard, theme, frame, dashboard_area);
+        }
+    }
+
+    fn render_dashboard(&self, dashboard: &Dashboard, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let stats = &dashboard.stats;
+        let free = dashboard
+            .staging_free_bytes
+            .map(format_size)
+            .unwrap_or_else(|| "unknown".to_string());
+        let catalog_low = dashboard
+            .catalog_free_bytes
+            .is_some_and(|free| free < dashboard.min_catalog_free_bytes);
+        let catalog_free = match dashboard.catalog_free_bytes {
+            Some(free) if catalog_low => format!("{} (LOW)", format_size(free)),
+            Some(free) => format_size(free),
+            None => "unknown".to_string(),
+        };
+        let text = format!(
+            "Archived:    {}\nFiles:       {}\nDiscs:       {}\nSets:        {}\nUnverified:  {}\n\nStaging free: {}\nCatalog free: {}",
+            format_size(stats.total_bytes),
+            stats.file_count,
+            stats.disc_count,
+            stats.set_count,
+            stats.unverified_discs,
+            free,
+            catalog_free
+        );
+        let style = if catalog_low {
+            theme.error_style()
+        } else if stats.unverified_discs > 0 {
+            theme.warning_style()
+        } else {
+            theme.primary_style()
+        };
+        let para = Paragraph::new(text).style(style).block(
+            Block::default()
+                .title("Archive")
+                .borders(Borders::ALL)
+                .border_style(theme.border_style()),
+        );
+        frame.render_widget(para, area);
     }
 }
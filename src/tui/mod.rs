@@ -1,22 +1,42 @@
+pub mod burn_iso;
+pub mod catalog_diff;
+pub mod catalog_stats;
+pub mod confirm_dialog;
 #[path = "directory_selector_simple.rs"]
 pub mod directory_selector;
+pub mod disc_detail;
+pub mod identify_disc;
+pub mod iso_library;
 pub mod list_discs;
 pub mod logs_view;
 pub mod main_menu;
 pub mod new_disc;
+pub mod restore;
 pub mod resume_burn;
+pub mod scan_qr;
+pub mod scrub;
 pub mod search_ui;
 pub mod settings;
 pub mod splash;
 pub mod verify_multi_disc;
 pub mod verify_ui;
 
+pub use burn_iso::BurnIsoView;
+pub use catalog_diff::CatalogDiffView;
+pub use catalog_stats::CatalogStatsView;
+pub use confirm_dialog::{ConfirmDialog, ConfirmResponse};
 pub use directory_selector::{DirectorySelector, Focus};
-pub use list_discs::ListDiscs;
+pub use disc_detail::DiscDetail;
+pub use identify_disc::IdentifyDiscView;
+pub use iso_library::IsoLibraryView;
+pub use list_discs::{DiscField, ListDiscs, ListPosition};
 pub use logs_view::LogsView;
 pub use main_menu::{MainMenu, MainMenuAction};
 pub use new_disc::NewDiscFlow;
+pub use restore::RestoreView;
 pub use resume_burn::ResumeBurnUI;
+pub use scan_qr::ScanQrView;
+pub use scrub::ScrubView;
 pub use search_ui::SearchUI;
 pub use settings::Settings;
 pub use splash::{DbStatus, SplashScreen};
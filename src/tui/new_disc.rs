@@ -1,18 +1,27 @@
+use crate::archive::MultiDiscProgress;
 use crate::theme::Theme;
-use crate::config::Config;
+use crate::config::{ChecksumAlgorithm, Config};
+use crate::iso::IsoSizeEstimate;
+use crate::search::{format_bytes_gb, format_size};
 use crate::tui::directory_selector;
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Gauge, Paragraph},
 };
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use crate::staging;
 
 #[derive(Debug)]
 pub struct NewDiscFlow {
     disc_id: String,
     notes: String,
-    source_folders: Vec<PathBuf>,
+    /// Selected folders, each with the folder name to use on the disc if renamed
+    source_folders: Vec<(PathBuf, Option<String>)>,
+    /// Highlighted entry in the selected folders list
+    selected_folder: usize,
+    /// Folder whose disc-side name is being edited, with the name typed so far
+    renaming: Option<(usize, String)>,
     current_step: NewDiscStep,
     input_buffer: String,
     status_message: String,
@@ -22,16 +31,74 @@ pub struct NewDiscFlow {
     directory_selector: Option<directory_selector::DirectorySelector>,
     /// Whether to do a dry run (no actual burning)
     dry_run: bool,
+    /// Only archive files not already on an earlier disc
+    incremental: bool,
+    /// Checksum for the disc manifest
+    checksum: ChecksumAlgorithm,
     /// Current file being processed (for progress display)
     file_progress: String,
-    /// Total size of selected files (calculated for capacity check)
-    total_size_bytes: Option<u64>,
-    /// Whether content exceeds disc capacity
+    /// Share of the current phase done by bytes, 0.0-1.0
+    phase_progress: f64,
+    /// Checksum progress while the manifest is generated
+    hash_progress: Option<crate::manifest::HashProgress>,
+    /// Content and estimated ISO size of the selection (calculated for capacity check)
+    size_estimate: Option<IsoSizeEstimate>,
+    /// Whether the estimated ISO exceeds disc capacity
     exceeds_capacity: bool,
-    /// Multi-disc progress tracking
-    multi_disc_current: Option<u32>, // Current disc being processed (1-based)
-    multi_disc_total: Option<u32>,   // Total number of discs
-    multi_disc_overall_progress: f64, // Overall progress 0.0-1.0
+    /// Disc layout estimate shown in the Review step
+    plan_summary: Option<staging::PlanSummary>,
+    /// Hidden files in the selected folders, shown in the Review step
+    hidden_files: Option<staging::HiddenFiles>,
+    /// Files whose path on the disc is too long for the ISO settings, shown in the Review step
+    long_paths: Vec<staging::LongPath>,
+    /// Position within a multi-disc set, with ETA for the whole set
+    multi_disc_progress: Option<MultiDiscProgress>,
+    /// ISO left in the staging directory by the finished run
+    iso_path: Option<PathBuf>,
+    /// Whether [O] can open the ISO's folder (a desktop session with xdg-open)
+    can_open_folder: bool,
+    /// Catalogued discs whose IDs this run would reuse
+    existing_disc_ids: Vec<String>,
+    /// Free ID offered by [A] when the chosen one is taken
+    suggested_disc_id: Option<String>,
+    /// Burn session of a partly burned set that [A] can abort and clean up
+    abortable_session: Option<String>,
+    /// Size of the image that came out too big for its disc, offered [P] to re-plan
+    overflow_iso_bytes: Option<u64>,
+    /// Transient notice on the bottom line, kept apart from status and errors
+    notice: Option<Notice>,
+    /// Source folders of earlier runs, most recent first, picked with 1-9
+    recent_folders: Vec<PathBuf>,
+    /// Staging directory for this run only, in place of the configured one
+    staging_override: Option<PathBuf>,
+    /// Staging directory being typed in the Review step
+    editing_staging: Option<String>,
+}
+
+/// Severity of a notice, which sets how it is styled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoticeLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl NoticeLevel {
+    fn label(self) -> &'static str {
+        match self {
+            NoticeLevel::Info => "[INFO]",
+            NoticeLevel::Warn => "[WARN]",
+            NoticeLevel::Error => "[ERR]",
+        }
+    }
+}
+
+/// One-line notice shown below the New Disc screen. Unlike `set_error` it
+/// doesn't change the processing state, so it never replaces a failure message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notice {
+    pub level: NoticeLevel,
+    pub message: String,
 }
 
 #[derive(Debug)]
@@ -44,9 +111,28 @@ pub enum ProcessingState {
     Indexing,
     GeneratingQR,
     Complete,
+    /// Burn paused at the user's request; 'r' resumes
+    Paused,
     Error(String),
 }
 
+impl ProcessingState {
+    /// Range of the overall gauge, in percent, covered by this phase. Staging,
+    /// checksumming and burning move every byte, so they get the most room.
+    fn overall_span(&self) -> (f64, f64) {
+        match self {
+            ProcessingState::Idle | ProcessingState::Paused | ProcessingState::Error(_) => (0.0, 0.0),
+            ProcessingState::Staging => (0.0, 30.0),
+            ProcessingState::GeneratingManifest => (30.0, 45.0),
+            ProcessingState::CreatingISO => (45.0, 55.0),
+            ProcessingState::Burning => (55.0, 95.0),
+            ProcessingState::Indexing => (95.0, 98.0),
+            ProcessingState::GeneratingQR => (98.0, 100.0),
+            ProcessingState::Complete => (100.0, 100.0),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum NewDiscStep {
     EnterDiscId,
@@ -62,6 +148,8 @@ impl Default for NewDiscFlow {
             disc_id: String::new(),
             notes: String::new(),
             source_folders: Vec::new(),
+            selected_folder: 0,
+            renaming: None,
             current_step: NewDiscStep::EnterDiscId,
             input_buffer: String::new(),
             status_message: String::new(),
@@ -69,12 +157,27 @@ impl Default for NewDiscFlow {
             processing_state: ProcessingState::Idle,
             directory_selector: None,
             dry_run: false,
+            incremental: false,
+            checksum: ChecksumAlgorithm::default(),
             file_progress: String::new(),
-            total_size_bytes: None,
+            phase_progress: 0.0,
+            hash_progress: None,
+            size_estimate: None,
             exceeds_capacity: false,
-            multi_disc_current: None,
-            multi_disc_total: None,
-            multi_disc_overall_progress: 0.0,
+            plan_summary: None,
+            hidden_files: None,
+            long_paths: Vec::new(),
+            multi_disc_progress: None,
+            iso_path: None,
+            can_open_folder: false,
+            existing_disc_ids: Vec::new(),
+            suggested_disc_id: None,
+            abortable_session: None,
+            overflow_iso_bytes: None,
+            notice: None,
+            recent_folders: Vec::new(),
+            staging_override: None,
+            editing_staging: None,
         }
     }
 }
@@ -85,6 +188,8 @@ impl NewDiscFlow {
             disc_id: default_disc_id,
             notes: String::new(),
             source_folders: Vec::new(),
+            selected_folder: 0,
+            renaming: None,
             current_step: NewDiscStep::EnterDiscId,
             input_buffer: String::new(),
             status_message: String::new(),
@@ -92,12 +197,27 @@ impl NewDiscFlow {
             processing_state: ProcessingState::Idle,
             directory_selector: None,
             dry_run: false,
+            incremental: false,
+            checksum: ChecksumAlgorithm::default(),
             file_progress: String::new(),
-            total_size_bytes: None,
+            phase_progress: 0.0,
+            hash_progress: None,
+            size_estimate: None,
             exceeds_capacity: false,
-            multi_disc_current: None,
-            multi_disc_total: None,
-            multi_disc_overall_progress: 0.0,
+            plan_summary: None,
+            hidden_files: None,
+            long_paths: Vec::new(),
+            multi_disc_progress: None,
+            iso_path: None,
+            can_open_folder: false,
+            existing_disc_ids: Vec::new(),
+            suggested_disc_id: None,
+            abortable_session: None,
+            overflow_iso_bytes: None,
+            notice: None,
+            recent_folders: Vec::new(),
+            staging_override: None,
+            editing_staging: None,
         }
     }
 
@@ -130,19 +250,242 @@ impl NewDiscFlow {
         self.notes = notes;
     }
 
-    pub fn source_folders(&self) -> &[PathBuf] {
-        &self.source_folders
+    pub fn source_folders(&self) -> Vec<PathBuf> {
+        self.source_folders.iter().map(|(folder, _)| folder.clone()).collect()
     }
 
+    /// Disc-side names of the folders that were renamed
+    pub fn destination_names(&self) -> BTreeMap<PathBuf, String> {
+        self.source_folders
+            .iter()
+            .filter_map(|(folder, label)| label.clone().map(|label| (folder.clone(), label)))
+            .collect()
+    }
+
+    /// Add a folder (once) and highlight it in the selected folders list.
     pub fn add_source_folder(&mut self, folder: PathBuf) {
-        if !self.source_folders.contains(&folder) {
-            self.source_folders.push(folder);
+        match self.source_folders.iter().position(|(f, _)| *f == folder) {
+            Some(index) => self.selected_folder = index,
+            None => {
+                self.source_folders.push((folder, None));
+                self.selected_folder = self.source_folders.len() - 1;
+            }
         }
     }
 
+    /// Handle the directory browser's mark keys: Space marks or unmarks the
+    /// highlighted folder, `A` adds every marked folder. Returns false when the
+    /// browser is not focused, where these keys are path input.
+    pub fn handle_mark_key(&mut self, c: char) -> bool {
+        let Some(selector) = self.directory_selector.as_mut() else {
+            return false;
+        };
+        if selector.focus() != directory_selector::Focus::Browser {
+            return false;
+        }
+        match c {
+            ' ' => selector.toggle_mark(),
+            'a' | 'A' => {
+                for folder in selector.take_marked() {
+                    self.add_source_folder(folder);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Offer these folders as a quick-pick in the SelectFolders step.
+    pub fn set_recent_folders(&mut self, folders: Vec<PathBuf>) {
+        self.recent_folders = folders;
+    }
+
+    pub fn recent_folders(&self) -> &[PathBuf] {
+        &self.recent_folders
+    }
+
+    /// Add the recent folder shown as `number` (1-based). Returns false if
+    /// there is no such entry.
+    pub fn add_recent_folder(&mut self, number: usize) -> bool {
+        let Some(folder) = number.checked_sub(1).and_then(|i| self.recent_folders.get(i)).cloned() else {
+            return false;
+        };
+        if folder.is_dir() {
+            self.add_source_folder(folder);
+        } else {
+            self.set_notice(NoticeLevel::Warn, format!("{} is no longer available", folder.display()));
+        }
+        true
+    }
+
     pub fn remove_source_folder(&mut self, index: usize) {
         if index < self.source_folders.len() {
             self.source_folders.remove(index);
+            self.renaming = None;
+            self.selected_folder = self.selected_folder.min(self.source_folders.len().saturating_sub(1));
+        }
+    }
+
+    /// Remove the highlighted folder from the selection.
+    pub fn remove_selected_folder(&mut self) {
+        self.remove_source_folder(self.selected_folder);
+    }
+
+    pub fn select_next_folder(&mut self) {
+        self.selected_folder = (self.selected_folder + 1).min(self.source_folders.len().saturating_sub(1));
+    }
+
+    pub fn select_previous_folder(&mut self) {
+        self.selected_folder = self.selected_folder.saturating_sub(1);
+    }
+
+    /// Why the selected folders can't be staged as named (invalid or clashing
+    /// disc-side names). The Review step refuses to start while this is set.
+    pub fn destination_problem(&self) -> Option<String> {
+        staging::validate_destination_names(&self.source_folders)
+            .err()
+            .map(|e| format!("{:#}", e))
+    }
+
+    pub fn is_renaming(&self) -> bool {
+        self.renaming.is_some()
+    }
+
+    /// Start editing the disc-side name of the highlighted folder.
+    pub fn start_rename(&mut self) {
+        if !self.source_folders.is_empty() {
+            self.rename_select(self.selected_folder.min(self.source_folders.len() - 1));
+        }
+    }
+
+    pub fn rename_next(&mut self) {
+        if let Some((index, _)) = self.renaming {
+            self.rename_select((index + 1).min(self.source_folders.len() - 1));
+        }
+    }
+
+    pub fn rename_previous(&mut self) {
+        if let Some((index, _)) = self.renaming {
+            self.rename_select(index.saturating_sub(1));
+        }
+    }
+
+    fn rename_select(&mut self, index: usize) {
+        let (folder, label) = &self.source_folders[index];
+        self.renaming = Some((index, staging::destination_name(folder, label.as_deref())));
+    }
+
+    pub fn rename_input_char(&mut self, c: char) {
+        if let Some((_, name)) = &mut self.renaming {
+            name.push(c);
+        }
+    }
+
+    pub fn rename_backspace(&mut self) {
+        if let Some((_, name)) = &mut self.renaming {
+            name.pop();
+        }
+    }
+
+    /// Save the typed name. An empty name, or the folder's own name, removes the rename.
+    pub fn commit_rename(&mut self) {
+        if let Some((index, name)) = self.renaming.take() {
+            let (folder, label) = &mut self.source_folders[index];
+            let name = name.trim();
+            *label = if name.is_empty() || name == staging::destination_name(folder, None) {
+                None
+            } else {
+                Some(name.to_string())
+            };
+        }
+    }
+
+    pub fn cancel_rename(&mut self) {
+        self.renaming = None;
+    }
+
+    /// Staging directory this run will use.
+    pub fn staging_dir(&self, config: &Config) -> anyhow::Result<PathBuf> {
+        match &self.staging_override {
+            Some(dir) => Ok(dir.clone()),
+            None => config.staging_dir(),
+        }
+    }
+
+    pub fn staging_override(&self) -> Option<&PathBuf> {
+        self.staging_override.as_ref()
+    }
+
+    /// Stage this run in `dir` instead of the configured staging directory.
+    pub fn set_staging_override(&mut self, dir: Option<PathBuf>) {
+        self.staging_override = dir;
+    }
+
+    /// Settings for this run: a copy of `config` with the per-run staging
+    /// directory applied, so the override never reaches the saved settings.
+    pub fn run_config(&self, config: &Config) -> Config {
+        let mut config = config.clone();
+        if let Some(dir) = &self.staging_override {
+            config.staging_dir = Some(dir.to_string_lossy().into_owned());
+        }
+        config
+    }
+
+    /// Space the staging directory needs for the selection, once it has been sized.
+    pub fn staging_space_needed(&self, config: &Config) -> u64 {
+        self.size_estimate
+            .map(|estimate| staging::staging_space_needed(estimate.iso_bytes, config.default_capacity_bytes()))
+            .unwrap_or(0)
+    }
+
+    pub fn is_editing_staging(&self) -> bool {
+        self.editing_staging.is_some()
+    }
+
+    /// Start typing a staging directory for this run, starting from the current one.
+    pub fn start_staging_edit(&mut self, config: &Config) {
+        let current = self.staging_dir(config).map(|dir| dir.display().to_string()).unwrap_or_default();
+        self.editing_staging = Some(current);
+    }
+
+    pub fn staging_input_char(&mut self, c: char) {
+        if let Some(input) = &mut self.editing_staging {
+            input.push(c);
+        }
+    }
+
+    pub fn staging_backspace(&mut self) {
+        if let Some(input) = &mut self.editing_staging {
+            input.pop();
+        }
+    }
+
+    pub fn cancel_staging_edit(&mut self) {
+        self.editing_staging = None;
+    }
+
+    /// Use the typed directory for this run if it exists and has room for the
+    /// selection; otherwise keep editing. An empty entry, or the configured
+    /// directory, goes back to the configured one.
+    pub fn commit_staging_edit(&mut self, config: &Config) {
+        let Some(input) = self.editing_staging.as_deref().map(str::trim) else {
+            return;
+        };
+        let configured = config.staging_dir().ok();
+        let dir = crate::paths::expand_tilde(input);
+        if input.is_empty() || Some(&dir) == configured.as_ref() {
+            self.staging_override = None;
+            self.editing_staging = None;
+            self.set_notice(NoticeLevel::Info, "Using the configured staging directory");
+            return;
+        }
+        match staging::check_staging_dir(&dir, self.staging_space_needed(config)) {
+            Ok(()) => {
+                self.set_notice(NoticeLevel::Info, format!("Staging this run in {}", dir.display()));
+                self.staging_override = Some(dir);
+                self.editing_staging = None;
+            }
+            Err(e) => self.set_notice(NoticeLevel::Error, format!("{:#}", e)),
         }
     }
 
@@ -219,6 +562,11 @@ impl NewDiscFlow {
     }
 
     pub fn set_processing_state(&mut self, state: ProcessingState) {
+        // A phase may be announced more than once; only a new one starts at zero
+        if std::mem::discriminant(&state) != std::mem::discriminant(&self.processing_state) {
+            self.phase_progress = 0.0;
+            self.hash_progress = None;
+        }
         self.processing_state = state;
     }
 
@@ -234,6 +582,38 @@ impl NewDiscFlow {
         self.dry_run = dry_run;
     }
 
+    pub fn is_incremental(&self) -> bool {
+        self.incremental
+    }
+
+    pub fn set_incremental(&mut self, incremental: bool) {
+        self.incremental = incremental;
+    }
+
+    pub fn checksum(&self) -> ChecksumAlgorithm {
+        self.checksum
+    }
+
+    pub fn set_checksum(&mut self, checksum: ChecksumAlgorithm) {
+        self.checksum = checksum;
+    }
+
+    /// Apply a Review step option key: `D` dry run, `I` incremental, `C` checksum.
+    /// Returns false for other keys and outside the Review step, where these
+    /// letters are ordinary input (a path typed in SelectFolders, say).
+    pub fn toggle_review_option(&mut self, c: char) -> bool {
+        if self.current_step != NewDiscStep::Review {
+            return false;
+        }
+        match c.to_ascii_lowercase() {
+            'd' => self.dry_run = !self.dry_run,
+            'i' => self.incremental = !self.incremental,
+            'c' => self.checksum = self.checksum.toggle(),
+            _ => return false,
+        }
+        true
+    }
+
     pub fn file_progress(&self) -> &str {
         &self.file_progress
     }
@@ -242,37 +622,214 @@ impl NewDiscFlow {
         self.file_progress = progress;
     }
 
+    pub fn set_phase_progress(&mut self, fraction: f64) {
+        self.phase_progress = fraction.clamp(0.0, 1.0);
+    }
+
+    pub fn set_hash_progress(&mut self, progress: crate::manifest::HashProgress) {
+        self.hash_progress = Some(progress);
+    }
+
+    /// Progress within the current phase, in percent
+    fn phase_percent(&self) -> u16 {
+        if matches!(self.processing_state, ProcessingState::Complete) {
+            return 100;
+        }
+        (self.phase_progress * 100.0) as u16
+    }
+
+    /// Progress of the whole run, in percent, weighting each phase by its span
+    fn overall_percent(&self) -> u16 {
+        let (start, end) = self.processing_state.overall_span();
+        (start + (end - start) * self.phase_progress).round() as u16
+    }
+
+    /// Record the ISO a finished run left behind, offering [O] only where a
+    /// file manager can be opened.
+    pub fn set_iso_path(&mut self, path: PathBuf, can_open_folder: bool) {
+        self.iso_path = Some(path);
+        self.can_open_folder = can_open_folder;
+    }
+
+    /// The ISO [O] and [Y] act on: only once the run has completed.
+    pub fn completed_iso_path(&self) -> Option<&PathBuf> {
+        self.iso_path
+            .as_ref()
+            .filter(|_| matches!(self.processing_state, ProcessingState::Complete))
+    }
+
+    /// Key hints for the completion screen.
+    fn completion_keys(&self) -> String {
+        let mut keys = String::new();
+        if self.iso_path.is_some() {
+            if self.can_open_folder {
+                keys.push_str("[O] Open Folder, ");
+            }
+            keys.push_str("[Y] Copy ISO Path, ");
+        }
+        keys.push_str("[Esc] Return to Main Menu");
+        keys
+    }
+
+    /// Offer [A] to abort the set recorded in `session_id`, and [R] to retry
+    /// its failed disc unless this is a dry run.
+    pub fn set_abortable_session(&mut self, session_id: String) {
+        self.abortable_session = Some(session_id);
+    }
+
+    pub fn abortable_session(&self) -> Option<&str> {
+        self.abortable_session.as_deref()
+    }
+
+    /// Whether the error screen offers [R]. Retries always burn for real, so
+    /// dry runs can't be retried.
+    pub fn can_retry_failed_disc(&self) -> bool {
+        self.abortable_session.is_some()
+            && !self.dry_run
+            && matches!(self.processing_state, ProcessingState::Error(_))
+    }
+
+    /// Record that the failed disc's image was `iso_bytes`, too big for the disc.
+    pub fn set_overflow(&mut self, iso_bytes: u64) {
+        self.overflow_iso_bytes = Some(iso_bytes);
+    }
+
+    /// Size of the oversized image when the error screen offers [P] to move
+    /// its largest files to a new disc.
+    pub fn overflow_to_replan(&self) -> Option<u64> {
+        self.overflow_iso_bytes.filter(|_| self.can_retry_failed_disc())
+    }
+
+    /// Show `message` on the notice line, replacing any earlier notice.
+    pub fn set_notice(&mut self, level: NoticeLevel, message: impl Into<String>) {
+        self.notice = Some(Notice { level, message: message.into() });
+    }
+
+    pub fn clear_notice(&mut self) {
+        self.notice = None;
+    }
+
+    pub fn notice(&self) -> Option<&Notice> {
+        self.notice.as_ref()
+    }
+
     /// Set multi-disc progress information
-    pub fn set_multi_disc_progress(&mut self, current: u32, total: u32, overall_progress: f64) {
-        self.multi_disc_current = Some(current);
-        self.multi_disc_total = Some(total);
-        self.multi_disc_overall_progress = overall_progress.clamp(0.0, 1.0);
+    pub fn set_multi_disc_progress(&mut self, progress: MultiDiscProgress) {
+        self.multi_disc_progress = Some(progress);
     }
 
     /// Clear multi-disc progress (for single disc operations)
     pub fn clear_multi_disc_progress(&mut self) {
-        self.multi_disc_current = None;
-        self.multi_disc_total = None;
-        self.multi_disc_overall_progress = 0.0;
+        self.multi_disc_progress = None;
     }
 
     /// Check if this is a multi-disc operation
     pub fn is_multi_disc(&self) -> bool {
-        self.multi_disc_total.is_some()
+        self.multi_disc_progress.is_some()
     }
 
     /// Calculate total size and check capacity against configured disc size
     pub fn calculate_capacity_check(&mut self, config: &crate::config::Config) -> anyhow::Result<()> {
         let capacity_bytes = config.default_capacity_bytes();
+        // A failed estimate must not leave an earlier selection's plan behind
+        self.plan_summary = None;
+
+        let source_folders = self.source_folders();
 
-        let (total_size, exceeds) = staging::check_capacity(&self.source_folders, capacity_bytes)?;
+        let (estimate, exceeds) = crate::compression::check_capacity(&source_folders, capacity_bytes, &config.staging)?;
 
-        self.total_size_bytes = Some(total_size);
+        self.size_estimate = Some(estimate);
         self.exceeds_capacity = exceeds;
+        let walk_options = staging::WalkOptions::from(&config.staging);
+        let cache = crate::size_cache::SizeCache::open_default(walk_options);
+        self.plan_summary = Some(staging::estimate_plan(&source_folders, capacity_bytes, walk_options, Some(&cache))?);
+        self.hidden_files = Some(staging::count_hidden_files(&source_folders, config.staging.symlink_policy)?);
+        self.long_paths = self.find_long_paths(config)?;
 
         Ok(())
     }
 
+    /// Files whose path on the disc would break the configured ISO limits.
+    /// Compressed folders keep their paths inside the archive, so only plain
+    /// layouts are checked.
+    fn find_long_paths(&self, config: &crate::config::Config) -> anyhow::Result<Vec<staging::LongPath>> {
+        if config.staging.compression != crate::config::Compression::None {
+            return Ok(Vec::new());
+        }
+        // Set discs hold the folders at the root; single discs under ARCHIVE/ (data/ARCHIVE/ when bagged)
+        let prefix = if self.exceeds_capacity {
+            PathBuf::new()
+        } else if config.staging.layout == crate::config::DiscLayout::Bagit {
+            Path::new(crate::bagit::PAYLOAD_DIR).join("ARCHIVE")
+        } else {
+            PathBuf::from("ARCHIVE")
+        };
+        staging::find_long_paths(
+            &self.source_folders,
+            &prefix,
+            staging::WalkOptions::from(&config.staging),
+            &crate::iso::PathLimits::from(&config.iso),
+        )
+    }
+
+    /// Whether the planned content includes files that cannot fit on any disc.
+    /// The Review step refuses to start while this is true.
+    pub fn has_oversize_files(&self) -> bool {
+        self.plan_summary
+            .as_ref()
+            .is_some_and(|summary| !summary.oversize_files.is_empty())
+    }
+
+    /// Whether the Review step has a disc layout estimate for the current
+    /// selection. Without one the oversize check has nothing to go on.
+    pub fn has_plan_summary(&self) -> bool {
+        self.plan_summary.is_some()
+    }
+
+    /// Whether the Review step must refuse to start: no layout estimate,
+    /// files that fit on no disc, a reused disc ID or clashing folder names.
+    pub fn burn_blocked(&self) -> bool {
+        !self.has_plan_summary()
+            || self.has_oversize_files()
+            || self.has_disc_id_conflict()
+            || self.destination_problem().is_some()
+    }
+
+    /// Number of discs the Review step's plan needs
+    pub fn planned_disc_count(&self) -> usize {
+        match &self.plan_summary {
+            Some(summary) if self.exceeds_capacity => summary.disc_count,
+            _ => 1,
+        }
+    }
+
+    /// Record catalogued discs whose IDs this run would reuse, with a free ID to offer instead.
+    pub fn set_disc_id_conflict(&mut self, existing: Vec<String>, suggested: Option<String>) {
+        self.existing_disc_ids = existing;
+        self.suggested_disc_id = suggested;
+    }
+
+    /// Whether the disc ID is already in the catalog. The Review step refuses to start while this is true.
+    pub fn has_disc_id_conflict(&self) -> bool {
+        !self.existing_disc_ids.is_empty()
+    }
+
+    /// Switch to the suggested free ID.
+    pub fn use_suggested_disc_id(&mut self) {
+        if let Some(id) = self.suggested_disc_id.take() {
+            self.disc_id = id;
+            self.existing_disc_ids.clear();
+        }
+    }
+
+    /// Go back to the first step to type a different disc ID.
+    pub fn edit_disc_id(&mut self) {
+        self.input_buffer.clear();
+        self.existing_disc_ids.clear();
+        self.suggested_disc_id = None;
+        self.current_step = NewDiscStep::EnterDiscId;
+    }
+
     pub fn set_status(&mut self, message: String) {
         self.status_message = message;
     }
@@ -321,9 +878,29 @@ impl NewDiscFlow {
     pub fn clear_error(&mut self) {
         self.error_message = None;
         self.processing_state = ProcessingState::Idle;
+        self.abortable_session = None;
+        self.overflow_iso_bytes = None;
+        self.notice = None;
     }
 
     pub fn render(&mut self, theme: &Theme, config: &Config, frame: &mut Frame, area: Rect) {
+        let area = match &self.notice {
+            Some(notice) => {
+                let parts = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(5), Constraint::Length(1)])
+                    .split(area);
+                let style = match notice.level {
+                    NoticeLevel::Info => theme.secondary_style(),
+                    NoticeLevel::Warn => theme.warning_style(),
+                    NoticeLevel::Error => theme.error_style(),
+                };
+                let line = Paragraph::new(format!("{} {}", notice.level.label(), notice.message)).style(style);
+                frame.render_widget(line, parts[1]);
+                parts[0]
+            }
+            None => area,
+        };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Min(5), Constraint::Length(3)])
@@ -397,27 +974,71 @@ impl NewDiscFlow {
                     let _ = self.init_directory_selector();
                 }
 
-                // Split into three sections: selected folders, directory selector, instructions
+                // Split into sections: recent folders (if any), selected folders,
+                // directory selector, instructions
+                let recent_height = if self.recent_folders.is_empty() { 0 } else { self.recent_folders.len() as u16 + 2 };
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints([
-                        Constraint::Length(8), // Selected folders list
-                        Constraint::Min(15),   // Directory selector
-                        Constraint::Length(2), // Instructions
+                        Constraint::Length(recent_height), // Recent folders quick-pick
+                        Constraint::Length(8),             // Selected folders list
+                        Constraint::Min(15),               // Directory selector
+                        Constraint::Length(2),             // Instructions
                     ])
                     .split(chunks[0]);
 
+                if !self.recent_folders.is_empty() {
+                    let recent_text = self
+                        .recent_folders
+                        .iter()
+                        .enumerate()
+                        .map(|(i, folder)| {
+                            let state = if self.source_folders.iter().any(|(f, _)| f == folder) {
+                                "  ✓ selected"
+                            } else if !folder.is_dir() {
+                                "  (missing)"
+                            } else {
+                                ""
+                            };
+                            format!("[{}] {}{}", i + 1, folder.display(), state)
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    let recent_block = Block::default()
+                        .title("Recent Folders - press the number to add")
+                        .borders(Borders::ALL)
+                        .border_style(theme.border_style());
+                    let para = Paragraph::new(recent_text)
+                        .block(recent_block)
+                        .style(theme.secondary_style());
+                    frame.render_widget(para, chunks[0]);
+                }
+
                 // Show selected folders at top
-                let folders_text = if self.source_folders.is_empty() {
+                let mut folders_text = if self.source_folders.is_empty() {
                     "No folders selected".to_string()
                 } else {
                     self.source_folders
                         .iter()
                         .enumerate()
-                        .map(|(i, f)| format!("{}. {}", i + 1, f.display()))
+                        .map(|(i, (folder, label))| match &self.renaming {
+                            Some((index, name)) if *index == i => {
+                                format!("▶ {}. {} → ARCHIVE/{}_", i + 1, folder.display(), name)
+                            }
+                            _ => {
+                                let marker = if self.renaming.is_none() && i == self.selected_folder { "▶" } else { " " };
+                                match label {
+                                    Some(label) => format!("{} {}. {} → ARCHIVE/{}", marker, i + 1, folder.display(), label),
+                                    None => format!("{} {}. {}", marker, i + 1, folder.display()),
+                                }
+                            }
+                        })
                         .collect::<Vec<_>>()
                         .join("\n")
                 };
+                if let Some(problem) = self.destination_problem() {
+                    folders_text.push_str(&format!("\n⚠️  {}", problem));
+                }
 
                 let selected_block = Block::default()
                     .title(format!("Selected Folders ({})", self.source_folders.len()))
@@ -427,12 +1048,12 @@ impl NewDiscFlow {
                 let para = Paragraph::new(folders_text)
                     .block(selected_block)
                     .style(theme.primary_style());
-                frame.render_widget(para, chunks[0]);
+                frame.render_widget(para, chunks[1]);
 
                 // Render directory selector (always visible)
                 if let Some(ref mut selector) = self.directory_selector {
                     // Render returns true if entries were just loaded (triggers redraw)
-                    let needs_redraw = selector.render(theme, frame, chunks[1]);
+                    let needs_redraw = selector.render(theme, frame, chunks[2]);
                     if needs_redraw {
                         // Force a redraw if entries were just loaded
                         // This is handled by the main loop, but we can trigger it
@@ -449,21 +1070,35 @@ impl NewDiscFlow {
                                 .border_style(theme.error_style()),
                         )
                         .style(theme.error_style());
-                    frame.render_widget(para, chunks[1]);
+                    frame.render_widget(para, chunks[2]);
                 }
 
                 // Instructions
-                let instructions = format!(
-                    "[Tab] Switch focus  [Enter] Select/Add  [↑↓] Navigate  [Del] Remove  [Esc] Back"
-                );
+                let instructions = if self.is_renaming() {
+                    "Name on disc: type a folder name  [↑↓] Choose folder  [Enter] Save  [Esc] Cancel".to_string()
+                } else {
+                    let recent = match self.recent_folders.len() {
+                        0 => String::new(),
+                        1 => "[1] Recent  ".to_string(),
+                        n => format!("[1-{}] Recent  ", n),
+                    };
+                    let add_marked = match self.directory_selector.as_ref().map_or(0, |s| s.marked_count()) {
+                        0 => "[A] Add marked".to_string(),
+                        n => format!("[A] Add {} marked", n),
+                    };
+                    format!("{}[Tab] Switch focus  [Enter] Select/Add  [↑↓] Navigate  [Space] Mark  {}  [PgUp/PgDn] Pick selected  [N] Name on disc  [Del] Remove  [Esc] Back", recent, add_marked)
+                };
                 let inst_para = Paragraph::new(instructions).style(theme.secondary_style());
-                frame.render_widget(inst_para, chunks[2]);
+                frame.render_widget(inst_para, chunks[3]);
             }
             NewDiscStep::Review => {
                 let folders_list = self
                     .source_folders
                     .iter()
-                    .map(|f| f.display().to_string())
+                    .map(|(folder, label)| match label {
+                        Some(label) => format!("{} → ARCHIVE/{}", folder.display(), label),
+                        None => folder.display().to_string(),
+                    })
                     .collect::<Vec<_>>()
                     .join("\n  ");
                 let mode = if self.dry_run {
@@ -472,45 +1107,137 @@ impl NewDiscFlow {
                     "ACTUAL BURN"
                 };
                 let mut text = format!(
-                    "Review:\n\nDisc ID: {}\nNotes: {}\n\nSource Folders:\n  {}\n\nMode: {}",
+                    "Review:\n\nDisc ID: {}\nNotes: {}\n\nSource Folders:\n  {}\n\nMode: {}\nChecksums: {}",
                     self.disc_id,
                     if self.notes.is_empty() { "(none)" } else { &self.notes },
                     if folders_list.is_empty() { "(none)" } else { &folders_list },
-                    mode
+                    mode,
+                    self.checksum.description()
                 );
+                match &self.editing_staging {
+                    Some(input) => text.push_str(&format!(
+                        "\nStaging for this run: {}█\n   [Enter] Use  [Esc] Cancel  (empty = configured directory)",
+                        input
+                    )),
+                    None => {
+                        let dir = self.staging_dir(config).map(|dir| dir.display().to_string()).unwrap_or_default();
+                        let free = self
+                            .staging_dir(config)
+                            .ok()
+                            .and_then(|dir| crate::paths::free_space_bytes(&dir))
+                            .map(|free| format!(", {} free", format_bytes_gb(free)))
+                            .unwrap_or_default();
+                        let scope = if self.staging_override.is_some() { "this run only" } else { "configured" };
+                        text.push_str(&format!("\nStaging: {} ({}{})", dir, scope, free));
+                    }
+                }
+                if self.has_disc_id_conflict() {
+                    text.push_str(&format!(
+                        "\n⚠️  Disc ID already exists in catalog: {}",
+                        self.existing_disc_ids.join(", ")
+                    ));
+                    if let Some(suggested) = &self.suggested_disc_id {
+                        text.push_str(&format!("\n   [A] Use {} instead, or [E] choose a new ID", suggested));
+                    }
+                }
+                if let Some(problem) = self.destination_problem() {
+                    text.push_str(&format!("\n⚠️  {}\n   Go back and press [N] to rename a folder on the disc", problem));
+                }
+                if config.staging.compression != crate::config::Compression::None {
+                    text.push_str(&format!(
+                        "\nCompression: {} (one .tar.zst per folder; size below is an estimate)",
+                        config.staging.compression.name()
+                    ));
+                }
+                if config.staging.layout == crate::config::DiscLayout::Bagit {
+                    text.push_str("\nLayout: BagIt bag (content under data/; single disc only)");
+                }
+                if self.incremental {
+                    text.push_str("\nIncremental: ON (files already on earlier discs are skipped; single disc only)");
+                }
+                if let Some(hidden) = self.hidden_files {
+                    if hidden.count == 0 {
+                        text.push_str("\nHidden files: none");
+                    } else {
+                        text.push_str(&format!(
+                            "\nHidden files: {} ({}) {}",
+                            hidden.count,
+                            format_size(hidden.bytes),
+                            if config.staging.include_hidden {
+                                "included"
+                            } else {
+                                "excluded (staging.include_hidden = false)"
+                            }
+                        ));
+                    }
+                }
 
                 // Add capacity information if calculated
-                if let Some(total_size) = self.total_size_bytes {
-                    let size_gb = total_size as f64 / (1024.0 * 1024.0 * 1024.0);
-                    let capacity_gb = config.default_capacity_bytes() as f64 / (1024.0 * 1024.0 * 1024.0);
-                    text.push_str(&format!("\n\nTotal Size: {:.2} GB", size_gb));
-                    text.push_str(&format!("Disc Capacity: {:.0} GB", capacity_gb));
-
-                    if self.exceeds_capacity {
-                        // Actually plan the discs to show the user what will happen
-                        match staging::plan_disc_layout(&self.source_folders, config.default_capacity_bytes()) {
-                            Ok(plans) => {
-                                let num_discs = plans.len();
-                                text.push_str(&format!("\n\n💿 MULTI-DISC ARCHIVE: {} discs required", num_discs));
+                if let Some(estimate) = self.size_estimate {
+                    text.push_str(&format!("\n\nContent Size: {}", format_bytes_gb(estimate.content_bytes)));
+                    text.push_str(&format!(
+                        "\nEstimated ISO Size: {} (with filesystem overhead)",
+                        format_bytes_gb(estimate.iso_bytes)
+                    ));
+                    text.push_str(&format!("\nDisc Capacity: {}", format_bytes_gb(config.default_capacity_bytes())));
+                    if let Some(summary) = self.plan_summary.as_ref().filter(|s| !s.size_histogram.is_empty()) {
+                        text.push_str("\nFile sizes:");
+                        for line in staging::describe_size_histogram(&summary.size_histogram) {
+                            text.push_str(&format!("\n  {}", line));
+                        }
+                    }
+
+                    let oversize = self
+                        .plan_summary
+                        .as_ref()
+                        .map(|summary| summary.oversize_files.as_slice())
+                        .unwrap_or_default();
+                    if !oversize.is_empty() {
+                        text.push_str(&format!(
+                            "\n\n❌ CANNOT BURN: {}",
+                            staging::describe_oversize_files(oversize, config.default_capacity_bytes())
+                        ));
+                        text.push_str("\n   Switch to larger media in Settings or remove these files from the selection.");
+                    } else if self.exceeds_capacity {
+                        match &self.plan_summary {
+                            Some(summary) => {
+                                text.push_str(&format!("\n\n💿 MULTI-DISC ARCHIVE: {} discs required", summary.disc_count));
                                 text.push_str("\n   Archive will be split across multiple Blu-rays");
 
-                                // Show basic info about each disc
-                                for (i, plan) in plans.iter().enumerate() {
-                                    let disc_size_gb = plan.used_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-                                    let disc_num = i + 1;
-                                    text.push_str(&format!("\n     Disc {}: {:.1} GB ({} files)", disc_num, disc_size_gb, plan.entries.len()));
+                                for (i, size) in summary.per_disc_sizes.iter().enumerate() {
+                                    text.push_str(&format!("\n     Disc {}: {}", i + 1, format_bytes_gb(*size)));
+                                }
+                                text.push_str(&format!("\n   Average utilization: {:.1}%", summary.utilization));
+                                if !summary.split_directories.is_empty() {
+                                    text.push_str(&format!(
+                                        "\n   Split directories: {}",
+                                        summary.split_directories.join(", ")
+                                    ));
                                 }
                             }
-                            Err(e) => {
-                                text.push_str(&format!("\n\n⚠️  Cannot plan multi-disc layout: {}", e));
+                            None => {
+                                text.push_str("\n\n⚠️  Cannot plan multi-disc layout");
                             }
                         }
                     } else {
                         text.push_str("\n\n✅ Content fits on single disc");
                     }
                 }
+                if !self.long_paths.is_empty() {
+                    text.push_str(&format!("\n\n⚠️  {}", staging::describe_long_paths(&self.long_paths, 5)));
+                    text.push_str(
+                        "\n   Shorten or flatten these before burning, or adjust iso.max_name_length / iso.max_path_length",
+                    );
+                }
 
-                text.push_str("\n\n[Enter] Start, [D] Toggle Dry Run, [Esc] Back");
+                if !self.has_plan_summary() {
+                    text.push_str("\n\n⚠️  Disc layout not estimated yet; burning is blocked until it is");
+                    text.push_str("\n\n[Enter] Retry Estimate, [D] Toggle Dry Run, [I] Toggle Incremental, [C] Toggle Checksum, [T] Staging Dir, [Esc] Back");
+                } else if self.burn_blocked() {
+                    text.push_str("\n\n[D] Toggle Dry Run, [I] Toggle Incremental, [C] Toggle Checksum, [T] Staging Dir, [Esc] Back");
+                } else {
+                    text.push_str("\n\n[Enter] Start, [D] Toggle Dry Run, [I] Toggle Incremental, [C] Toggle Checksum, [T] Staging Dir, [Esc] Back");
+                }
                 let para = Paragraph::new(text)
                     .block(block)
                     .style(theme.primary_style());
@@ -526,6 +1253,7 @@ impl NewDiscFlow {
                     ProcessingState::Indexing => "Updating index...",
                     ProcessingState::GeneratingQR => "Generating QR code...",
                     ProcessingState::Complete => "Complete!",
+                    ProcessingState::Paused => "Paused",
                     ProcessingState::Error(msg) => {
                         return self.render_error(theme, frame, area, msg)
                     }
@@ -550,27 +1278,37 @@ impl NewDiscFlow {
                 };
 
                 // Add multi-disc progress information if available
-                if let (Some(current), Some(total)) = (self.multi_disc_current, self.multi_disc_total) {
-                    let progress_percent = (self.multi_disc_overall_progress * 100.0) as u32;
+                if let Some(progress) = self.multi_disc_progress {
                     base_text.push_str(&format!(
-                        "\n\n🔥 Multi-Disc Progress: {}/{} discs complete",
-                        current.saturating_sub(1), total
+                        "\n\n🔥 Multi-Disc Progress: disc {} of {} ({} complete, {} remaining)",
+                        progress.current.min(progress.total),
+                        progress.total,
+                        progress.discs_completed(),
+                        progress.discs_remaining()
                     ));
                     base_text.push_str(&format!(
-                        "\n📊 Overall Progress: {}%",
-                        progress_percent
+                        "\n📊 Overall Progress: {}% • ETA {}",
+                        (progress.fraction() * 100.0) as u32,
+                        progress.eta_secs.map(format_eta).unwrap_or_else(|| "estimating...".to_string())
                     ));
 
                     // Add a retro-style progress bar
                     let bar_width = 20;
-                    let filled = ((self.multi_disc_overall_progress * bar_width as f64) as usize).min(bar_width);
-                    let _empty = bar_width - filled;
-                    let progress_bar = format!("▰{:▱<width$}▰", "", width = filled);
-                    base_text.push_str(&format!("\n[{}]", progress_bar));
+                    let filled = ((progress.fraction() * bar_width as f64) as usize).min(bar_width);
+                    base_text.push_str(&format!(
+                        "\n[{}{}]",
+                        "▰".repeat(filled),
+                        "▱".repeat(bar_width - filled)
+                    ));
                 }
 
                 let text = if matches!(self.processing_state, ProcessingState::Complete) {
-                    format!("{}\n\n[Esc] Return to Main Menu", base_text)
+                    if let Some(iso_path) = &self.iso_path {
+                        base_text.push_str(&format!("\n\nISO: {}", iso_path.display()));
+                    }
+                    format!("{}\n\n{}", base_text, self.completion_keys())
+                } else if matches!(self.processing_state, ProcessingState::Paused) {
+                    format!("{}\n\n[R] Resume, [Esc] Cancel", base_text)
                 } else if matches!(self.processing_state, ProcessingState::Error(_)) {
                     format!("{}\n\n[Esc] Go Back", base_text)
                 } else {
@@ -581,8 +1319,35 @@ impl NewDiscFlow {
                     .style(theme.primary_style());
                 frame.render_widget(para, processing_chunks[0]);
 
-                // Disc activity indicator for long operations
-                if matches!(
+                // Checksums get their own gauge: on big archives this phase takes longest
+                let hash_progress = self
+                    .hash_progress
+                    .as_ref()
+                    .filter(|_| matches!(self.processing_state, ProcessingState::GeneratingManifest));
+                if let Some(progress) = hash_progress {
+                    let gauge = Gauge::default()
+                        .block(
+                            Block::default()
+                                .title(format!(
+                                    "Checksums: {}/{} files • {} of {} • {:.1} MB/s",
+                                    progress.files_done,
+                                    progress.files_total,
+                                    format_size(progress.bytes_done),
+                                    format_size(progress.bytes_total),
+                                    progress.mb_per_sec()
+                                ))
+                                .borders(Borders::ALL)
+                                .border_style(theme.border_style()),
+                        )
+                        .gauge_style(theme.primary_style())
+                        .ratio(progress.fraction())
+                        .label(format!(
+                            "{}% • {}",
+                            (progress.fraction() * 100.0) as u16,
+                            progress.current_path.display()
+                        ));
+                    frame.render_widget(gauge, processing_chunks[1]);
+                } else if matches!(
                     &self.processing_state,
                     ProcessingState::GeneratingManifest
                         | ProcessingState::CreatingISO
@@ -598,28 +1363,12 @@ impl NewDiscFlow {
                         },
                     );
 
-                    // Simulate LBA progress
-                    let progress = match &self.processing_state {
-                        ProcessingState::CreatingISO => 50,
-                        ProcessingState::Burning => 75,
-                        _ => 0,
-                    };
-                    disc_activity.set_lba((progress as u64) * 1000, 100000);
-                    disc_activity.set_buffer(progress as f64 / 100.0);
+                    disc_activity.set_lba((self.phase_progress * 100_000.0) as u64, 100_000);
+                    disc_activity.set_buffer(self.phase_progress);
                     disc_activity.update();
                     disc_activity.render(theme, processing_chunks[1], frame);
                 } else {
                     // Progress bar for other operations
-                    let progress = match &self.processing_state {
-                        ProcessingState::Staging => 10,
-                        ProcessingState::GeneratingManifest => 30,
-                        ProcessingState::CreatingISO => 50,
-                        ProcessingState::Burning => 70,
-                        ProcessingState::Indexing => 90,
-                        ProcessingState::GeneratingQR => 95,
-                        ProcessingState::Complete => 100,
-                        _ => 0,
-                    };
                     let gauge = Gauge::default()
                         .block(
                             Block::default()
@@ -628,21 +1377,11 @@ impl NewDiscFlow {
                                 .border_style(theme.border_style()),
                         )
                         .gauge_style(theme.primary_style())
-                        .percent(progress);
+                        .percent(self.phase_percent());
                     frame.render_widget(gauge, processing_chunks[1]);
                 }
 
                 // Overall progress bar at bottom
-                let progress = match &self.processing_state {
-                    ProcessingState::Staging => 10,
-                    ProcessingState::GeneratingManifest => 30,
-                    ProcessingState::CreatingISO => 50,
-                    ProcessingState::Burning => 70,
-                    ProcessingState::Indexing => 90,
-                    ProcessingState::GeneratingQR => 95,
-                    ProcessingState::Complete => 100,
-                    _ => 0,
-                };
                 let gauge = Gauge::default()
                     .block(
                         Block::default()
@@ -651,14 +1390,34 @@ impl NewDiscFlow {
                             .border_style(theme.border_style()),
                     )
                     .gauge_style(theme.primary_style())
-                    .percent(progress);
+                    .percent(self.overall_percent());
                 frame.render_widget(gauge, chunks[1]);
             }
         }
     }
 
     fn render_error(&self, theme: &Theme, frame: &mut Frame, area: Rect, error: &str) {
-        let text = format!("[ERR] {}\n\n[Esc] Go back", error);
+        let text = if self.overflow_to_replan().is_some() {
+            format!(
+                "[ERR] {}\n\n[P] Move the largest files to a new disc added to the set and continue, \
+                 [R] Retry the disc as planned, [A] Abort the set and clean up, \
+                 [Esc] Go back (the set stays in Resume Burn)",
+                error
+            )
+        } else if self.can_retry_failed_disc() {
+            format!(
+                "[ERR] {}\n\n[R] Retry the failed disc on a new blank, [A] Abort the set and clean up, \
+                 [Esc] Go back (the set stays in Resume Burn)",
+                error
+            )
+        } else if self.abortable_session.is_some() {
+            format!(
+                "[ERR] {}\n\n[A] Abort the set and clean up, [Esc] Go back (the set stays in Resume Burn)",
+                error
+            )
+        } else {
+            format!("[ERR] {}\n\n[Esc] Go back", error)
+        };
         let para = Paragraph::new(text)
             .block(
                 Block::default()
@@ -670,3 +1429,215 @@ impl NewDiscFlow {
         frame.render_widget(para, area);
     }
 }
+
+/// Format an ETA as "1h 05m", "12m 30s" or "45s".
+fn format_eta(secs: u64) -> String {
+    let (hours, mins, secs) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours > 0 {
+        format!("{}h {:02}m", hours, mins)
+    } else if mins > 0 {
+        format!("{}m {:02}s", mins, secs)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_review_option_keys() {
+        let mut flow = NewDiscFlow::new("2024-BD-001".to_string());
+
+        // In SelectFolders the letters belong to the path being typed
+        flow.current_step = NewDiscStep::SelectFolders;
+        assert!(!flow.toggle_review_option('i'));
+        assert!(!flow.toggle_review_option('d'));
+        assert!(!flow.is_incremental());
+        assert!(!flow.dry_run());
+
+        flow.current_step = NewDiscStep::Review;
+        assert!(flow.toggle_review_option('i'));
+        assert!(flow.is_incremental());
+        assert!(flow.toggle_review_option('I'));
+        assert!(!flow.is_incremental());
+        assert!(flow.toggle_review_option('D'));
+        assert!(flow.dry_run());
+        let checksum = flow.checksum();
+        assert!(flow.toggle_review_option('c'));
+        assert_eq!(flow.checksum(), checksum.toggle());
+        assert!(!flow.toggle_review_option('x'));
+    }
+
+    #[test]
+    fn test_mark_keys() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let photos = temp_dir.path().join("photos");
+        let videos = temp_dir.path().join("videos");
+        std::fs::create_dir(&photos)?;
+        std::fs::create_dir(&videos)?;
+
+        let mut flow = NewDiscFlow::new("2024-BD-001".to_string());
+        assert!(!flow.handle_mark_key(' '));
+        flow.init_directory_selector()?;
+        let selector = flow.directory_selector_mut().unwrap();
+        selector.set_current_path(temp_dir.path().to_path_buf())?;
+        selector.force_sync_refresh()?;
+
+        // With the path input focused, Space and A are typed text
+        assert!(!flow.handle_mark_key(' '));
+        assert!(!flow.handle_mark_key('a'));
+
+        // Entries are "..", photos, videos
+        let selector = flow.directory_selector_mut().unwrap();
+        selector.set_focus(directory_selector::Focus::Browser);
+        selector.browser_down();
+        assert!(flow.handle_mark_key(' '));
+        assert_eq!(flow.directory_selector_mut().unwrap().marked_count(), 1);
+        assert!(flow.handle_mark_key(' '));
+        assert_eq!(flow.directory_selector_mut().unwrap().marked_count(), 0);
+
+        assert!(flow.handle_mark_key(' '));
+        flow.directory_selector_mut().unwrap().browser_down();
+        assert!(flow.handle_mark_key(' '));
+        assert_eq!(flow.directory_selector_mut().unwrap().marked_count(), 2);
+
+        // ".." cannot be marked
+        flow.directory_selector_mut().unwrap().browser_up();
+        flow.directory_selector_mut().unwrap().browser_up();
+        assert!(flow.handle_mark_key(' '));
+        assert_eq!(flow.directory_selector_mut().unwrap().marked_count(), 2);
+
+        // A adds the marked folders and clears the marks
+        assert!(flow.handle_mark_key('A'));
+        assert_eq!(flow.source_folders(), [photos, videos]);
+        assert_eq!(flow.directory_selector_mut().unwrap().marked_count(), 0);
+        assert!(!flow.handle_mark_key('x'));
+        Ok(())
+    }
+
+    #[test]
+    fn test_burn_blocked_without_plan_summary() {
+        let mut flow = NewDiscFlow::new("2024-BD-001".to_string());
+        flow.current_step = NewDiscStep::Review;
+
+        // A failed or missing estimate must not look like "nothing oversize"
+        assert!(!flow.has_plan_summary());
+        assert!(!flow.has_oversize_files());
+        assert!(flow.burn_blocked());
+
+        let summary = staging::PlanSummary {
+            disc_count: 1,
+            per_disc_sizes: vec![10],
+            split_directories: Vec::new(),
+            total_bytes: 10,
+            utilization: 1.0,
+            oversize_files: Vec::new(),
+            size_histogram: Default::default(),
+        };
+        flow.plan_summary = Some(summary.clone());
+        assert!(!flow.burn_blocked());
+
+        flow.plan_summary = Some(staging::PlanSummary {
+            oversize_files: vec![staging::OversizeFile { path: PathBuf::from("big.mkv"), size_bytes: 1000 }],
+            ..summary
+        });
+        assert!(flow.burn_blocked());
+    }
+
+    #[test]
+    fn test_completed_iso_actions() {
+        let mut flow = NewDiscFlow::new("2024-BD-001".to_string());
+        flow.current_step = NewDiscStep::Processing;
+        assert_eq!(flow.completion_keys(), "[Esc] Return to Main Menu");
+
+        // The path arrives just before Complete; keys stay inert until then
+        flow.set_iso_path(PathBuf::from("/var/staging/2024-BD-001.iso"), false);
+        assert_eq!(flow.completed_iso_path(), None);
+        flow.set_processing_state(ProcessingState::Complete);
+        assert_eq!(flow.completed_iso_path(), Some(&PathBuf::from("/var/staging/2024-BD-001.iso")));
+
+        // [O] is only offered where a file manager can be opened
+        assert_eq!(flow.completion_keys(), "[Y] Copy ISO Path, [Esc] Return to Main Menu");
+        flow.set_iso_path(PathBuf::from("/var/staging/2024-BD-001.iso"), true);
+        assert_eq!(flow.completion_keys(), "[O] Open Folder, [Y] Copy ISO Path, [Esc] Return to Main Menu");
+    }
+
+    #[test]
+    fn test_remove_selected_folder() {
+        let mut flow = NewDiscFlow::new("2024-BD-001".to_string());
+        flow.current_step = NewDiscStep::SelectFolders;
+        flow.remove_selected_folder();
+        assert!(flow.source_folders().is_empty());
+
+        for folder in ["/data/photos", "/data/videos", "/data/docs"] {
+            flow.add_source_folder(PathBuf::from(folder));
+        }
+        // Adding highlights the new folder; re-adding highlights the existing one
+        assert_eq!(flow.selected_folder, 2);
+        flow.add_source_folder(PathBuf::from("/data/photos"));
+        assert_eq!(flow.selected_folder, 0);
+        assert_eq!(flow.source_folders().len(), 3);
+
+        flow.select_previous_folder();
+        assert_eq!(flow.selected_folder, 0);
+        flow.select_next_folder();
+        flow.remove_selected_folder();
+        assert_eq!(flow.source_folders(), [PathBuf::from("/data/photos"), PathBuf::from("/data/docs")]);
+        assert_eq!(flow.selected_folder, 1);
+
+        // Removing the last entry moves the highlight up and ends a rename
+        flow.select_next_folder();
+        assert_eq!(flow.selected_folder, 1);
+        flow.start_rename();
+        assert!(flow.is_renaming());
+        flow.remove_selected_folder();
+        assert!(!flow.is_renaming());
+        assert_eq!(flow.source_folders(), [PathBuf::from("/data/photos")]);
+        assert_eq!(flow.selected_folder, 0);
+        flow.remove_selected_folder();
+        assert!(flow.source_folders().is_empty());
+        assert_eq!(flow.selected_folder, 0);
+    }
+
+    #[test]
+    fn test_notice_and_pause_keep_errors_apart() {
+        let mut flow = NewDiscFlow::new("2024-BD-001".to_string());
+        flow.current_step = NewDiscStep::Processing;
+        flow.set_processing_state(ProcessingState::Staging);
+        assert!(flow.notice().is_none());
+
+        // A pause is its own state, not an error screen
+        flow.set_notice(NoticeLevel::Info, "⏸️ Burn paused by user");
+        flow.set_processing_state(ProcessingState::Paused);
+        assert!(matches!(flow.processing_state(), ProcessingState::Paused));
+        assert_eq!(flow.error_message, None);
+        assert_eq!(flow.overall_percent(), 0);
+
+        // Notices replace each other without touching the state
+        flow.set_notice(NoticeLevel::Warn, "Operation cancelled by user");
+        assert_eq!(
+            flow.notice(),
+            Some(&Notice {
+                level: NoticeLevel::Warn,
+                message: "Operation cancelled by user".to_string(),
+            })
+        );
+        assert!(matches!(flow.processing_state(), ProcessingState::Paused));
+
+        // An error keeps its message while a notice is shown below it
+        flow.set_error("Burn failed".to_string());
+        flow.set_notice(NoticeLevel::Error, "Cleanup failed");
+        assert!(matches!(flow.processing_state(), ProcessingState::Error(msg) if msg == "Burn failed"));
+        assert_eq!(flow.notice().map(|n| n.level), Some(NoticeLevel::Error));
+
+        flow.clear_error();
+        assert!(matches!(flow.processing_state(), ProcessingState::Idle));
+        assert!(flow.notice().is_none());
+
+        assert_eq!(NoticeLevel::Info.label(), "[INFO]");
+        assert_eq!(NoticeLevel::Warn.label(), "[WARN]");
+        assert_eq!(NoticeLevel::Error.label(), "[ERR]");
+    }
+}
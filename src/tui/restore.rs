@@ -0,0 +1,197 @@
+use crate::theme::Theme;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Progress lines kept on screen while a restore runs
+const LOG_LINES: usize = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+enum RestorePhase {
+    /// Choosing the destination and options
+    Editing,
+    Running,
+    WaitingForDisc { sequence: u32, total: u32, disc_id: String, problem: Option<String> },
+    Done(Vec<String>),
+    Failed(String),
+}
+
+/// Copy a disc's archived files back to a folder, optionally checking them
+/// against the catalog. Discs of a set can be restored one after another.
+#[derive(Debug, Clone)]
+pub struct RestoreView {
+    disc_id: String,
+    /// Set the disc belongs to, which can be restored as a whole
+    set_id: Option<String>,
+    whole_set: bool,
+    destination: String,
+    verify: bool,
+    phase: RestorePhase,
+    log: Vec<String>,
+}
+
+impl RestoreView {
+    pub fn new(disc_id: String, set_id: Option<String>) -> Self {
+        Self {
+            destination: format!("~/Restored/{}", set_id.as_deref().unwrap_or(&disc_id)),
+            disc_id,
+            set_id,
+            whole_set: false,
+            verify: true,
+            phase: RestorePhase::Editing,
+            log: Vec::new(),
+        }
+    }
+
+    pub fn disc_id(&self) -> &str {
+        &self.disc_id
+    }
+
+    /// The set to restore, when the whole set was chosen
+    pub fn set_to_restore(&self) -> Option<&str> {
+        self.set_id.as_deref().filter(|_| self.whole_set)
+    }
+
+    pub fn destination(&self) -> &str {
+        self.destination.trim()
+    }
+
+    pub fn verify(&self) -> bool {
+        self.verify
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.phase == RestorePhase::Editing
+    }
+
+    pub fn is_running(&self) -> bool {
+        matches!(self.phase, RestorePhase::Running | RestorePhase::WaitingForDisc { .. })
+    }
+
+    pub fn is_waiting_for_disc(&self) -> bool {
+        matches!(self.phase, RestorePhase::WaitingForDisc { .. })
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        self.destination.push(c);
+    }
+
+    pub fn input_backspace(&mut self) {
+        self.destination.pop();
+    }
+
+    pub fn toggle_verify(&mut self) {
+        self.verify = !self.verify;
+    }
+
+    /// Switch between this disc and its whole set; no-op for single discs.
+    pub fn toggle_whole_set(&mut self) {
+        self.whole_set = self.set_id.is_some() && !self.whole_set;
+    }
+
+    pub fn start(&mut self) {
+        self.log.clear();
+        self.phase = RestorePhase::Running;
+    }
+
+    pub fn push_progress(&mut self, message: String) {
+        self.log.push(message);
+        if self.log.len() > LOG_LINES {
+            self.log.remove(0);
+        }
+    }
+
+    pub fn request_disc(&mut self, sequence: u32, total: u32, disc_id: String, problem: Option<String>) {
+        self.phase = RestorePhase::WaitingForDisc { sequence, total, disc_id, problem };
+    }
+
+    /// The requested disc was inserted or skipped; the worker carries on.
+    pub fn resume(&mut self) {
+        self.phase = RestorePhase::Running;
+    }
+
+    pub fn set_done(&mut self, summary: Vec<String>) {
+        self.phase = RestorePhase::Done(summary);
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.phase = RestorePhase::Failed(error);
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(7), Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let what = match self.set_to_restore() {
+            Some(set_id) => format!("every disc of set {}, one after another", set_id),
+            None => format!("disc {}", self.disc_id),
+        };
+        let mut options = format!("Verify against catalog: {}  [Tab] toggle", if self.verify { "yes" } else { "no" });
+        if self.set_id.is_some() {
+            options.push_str(&format!(
+                "\nWhole set: {}  [Shift+Tab] toggle",
+                if self.whole_set { "yes" } else { "no" }
+            ));
+        }
+        let help = Paragraph::new(format!(
+            "Copies the archived files of {} into the destination folder.\n\
+             Mounted discs are read in place; otherwise the disc in the drive is mounted.\n\n{}",
+            what, options
+        ))
+        .block(
+            Block::default()
+                .title("Restore Files")
+                .borders(Borders::ALL)
+                .border_style(theme.border_style()),
+        )
+        .style(theme.primary_style())
+        .wrap(Wrap { trim: true });
+        frame.render_widget(help, chunks[0]);
+
+        let input_title = if self.is_editing() {
+            "Destination  [Enter] Restore  [Esc] Back"
+        } else {
+            "Destination"
+        };
+        let cursor = if self.is_editing() { "_" } else { "" };
+        let input = Paragraph::new(format!("{}{}", self.destination, cursor))
+            .block(
+                Block::default()
+                    .title(input_title)
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(if self.is_editing() { theme.highlight_style() } else { theme.dim_style() });
+        frame.render_widget(input, chunks[1]);
+
+        let (title, text, style) = match &self.phase {
+            RestorePhase::Editing => return,
+            RestorePhase::Running => ("Restoring  [Esc] Stop after this disc", self.log.join("\n"), theme.primary_style()),
+            RestorePhase::WaitingForDisc { sequence, total, disc_id, problem } => {
+                let mut text = format!(
+                    "📀 Insert disc {} of {} ({}) and press [Enter], or [s] to skip it.",
+                    sequence, total, disc_id
+                );
+                if let Some(problem) = problem {
+                    text.push_str(&format!("\n⚠️ {}", problem));
+                }
+                ("Insert Disc  [Esc] Stop", text, theme.warning_style())
+            }
+            RestorePhase::Done(summary) => ("Restore Finished  [Esc] Back", summary.join("\n"), theme.success_style()),
+            RestorePhase::Failed(error) => ("Restore Failed  [Esc] Back", format!("[ERR] {}", error), theme.error_style()),
+        };
+        let para = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(style)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(para, chunks[2]);
+    }
+}
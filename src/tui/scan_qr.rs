@@ -0,0 +1,73 @@
+use crate::theme::Theme;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Look up a disc by scanning its label: asks for an image of the QR code.
+#[derive(Debug, Clone, Default)]
+pub struct ScanQrView {
+    image_path: String,
+    /// Why the last scan did not find a disc
+    message: Option<String>,
+}
+
+impl ScanQrView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn image_path(&self) -> &str {
+        self.image_path.trim()
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        self.image_path.push(c);
+    }
+
+    pub fn input_backspace(&mut self) {
+        self.image_path.pop();
+    }
+
+    pub fn set_message(&mut self, message: String) {
+        self.message = Some(message);
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(5), Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let help = Paragraph::new(
+            "Photograph or scan the QR code on a disc label and enter the image path.\n\
+             BlueVault reads the disc ID from it (needs zbarimg) and opens that disc.",
+        )
+        .block(
+            Block::default()
+                .title("Scan Disc Label")
+                .borders(Borders::ALL)
+                .border_style(theme.border_style()),
+        )
+        .style(theme.primary_style())
+        .wrap(Wrap { trim: true });
+        frame.render_widget(help, chunks[0]);
+
+        let input = Paragraph::new(format!("{}_", self.image_path))
+            .block(
+                Block::default()
+                    .title("Image Path  [Enter] Look Up  [Esc] Back")
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(theme.highlight_style());
+        frame.render_widget(input, chunks[1]);
+
+        if let Some(message) = &self.message {
+            let para = Paragraph::new(message.as_str())
+                .style(theme.warning_style())
+                .wrap(Wrap { trim: true });
+            frame.render_widget(para, chunks[2]);
+        }
+    }
+}
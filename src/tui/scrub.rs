@@ -0,0 +1,118 @@
+use crate::disc::format_timestamp_relative;
+use crate::theme::Theme;
+use crate::verify::StaleDisc;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+
+/// Scrub report: discs due for re-verification, oldest first.
+#[derive(Debug, Clone)]
+pub struct ScrubView {
+    discs: Vec<StaleDisc>,
+    older_than_days: u64,
+    selected: Option<usize>,
+    /// Result of the last CSV export
+    message: Option<String>,
+}
+
+impl ScrubView {
+    pub fn new(discs: Vec<StaleDisc>, older_than_days: u64) -> Self {
+        let selected = if discs.is_empty() { None } else { Some(0) };
+        Self {
+            discs,
+            older_than_days,
+            selected,
+            message: None,
+        }
+    }
+
+    pub fn discs(&self) -> &[StaleDisc] {
+        &self.discs
+    }
+
+    pub fn set_message(&mut self, message: String) {
+        self.message = Some(message);
+    }
+
+    pub fn next(&mut self) {
+        if let Some(sel) = self.selected {
+            if sel < self.discs.len().saturating_sub(1) {
+                self.selected = Some(sel + 1);
+            }
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if let Some(sel) = self.selected {
+            if sel > 0 {
+                self.selected = Some(sel - 1);
+            }
+        }
+    }
+
+    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let summary = self.message.clone().unwrap_or_else(|| {
+            format!(
+                "{} disc(s) not verified in the last {} days. Pull these and run Verify Disc.",
+                self.discs.len(),
+                self.older_than_days
+            )
+        });
+        let para = Paragraph::new(summary)
+            .block(
+                Block::default()
+                    .title("Scrub Report")
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(theme.primary_style());
+        frame.render_widget(para, chunks[0]);
+
+        let title = "Oldest first  [x] Export CSV  [Esc] Back";
+        if self.discs.is_empty() {
+            let para = Paragraph::new("Every disc has been verified recently.")
+                .block(
+                    Block::default()
+                        .title(title)
+                        .borders(Borders::ALL)
+                        .border_style(theme.border_style()),
+                )
+                .style(theme.dim_style());
+            frame.render_widget(para, chunks[1]);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .discs
+            .iter()
+            .map(|d| {
+                ListItem::new(format!(
+                    "{} │ created {} │ last verified {}",
+                    d.disc_id,
+                    format_timestamp_relative(&d.created_at),
+                    d.last_verified_display()
+                ))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .highlight_style(theme.highlight_style())
+            .highlight_symbol("▶ ");
+
+        let mut state = ratatui::widgets::ListState::default();
+        state.select(self.selected);
+        frame.render_stateful_widget(list, chunks[1], &mut state);
+    }
+}
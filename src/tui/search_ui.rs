@@ -1,15 +1,25 @@
-use crate::search::{SearchQuery, SearchResult};
+use crate::search::{SearchQuery, SearchResult, SearchSort};
 use crate::theme::Theme;
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
 
+/// Results shown per page.
+pub const SEARCH_PAGE_SIZE: usize = 100;
+
 #[derive(Debug, Clone)]
 pub struct SearchUI {
     query: String,
     results: Vec<SearchResult>,
     selected: Option<usize>,
+    sort: SearchSort,
+    /// Zero-based page of results being shown
+    page: usize,
+    /// Whether another page follows the current one
+    has_more: bool,
+    /// Kept between renders so the scroll offset doesn't jump
+    list_state: ListState,
 }
 
 impl Default for SearchUI {
@@ -18,6 +28,10 @@ impl Default for SearchUI {
             query: String::new(),
             results: Vec::new(),
             selected: None,
+            sort: SearchSort::default(),
+            page: 0,
+            has_more: false,
+            list_state: ListState::default(),
         }
     }
 }
@@ -29,27 +43,75 @@ impl SearchUI {
 
     pub fn add_char(&mut self, c: char) {
         self.query.push(c);
+        self.page = 0;
     }
 
     pub fn delete_char(&mut self) {
         self.query.pop();
+        self.page = 0;
     }
 
     pub fn clear_query(&mut self) {
         self.query.clear();
+        self.page = 0;
+    }
+
+    pub fn sort(&self) -> SearchSort {
+        self.sort
+    }
+
+    /// Switch to the next sort mode and go back to the first page.
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.page = 0;
+    }
+
+    /// Move to the next page. Returns false if there is none.
+    pub fn next_page(&mut self) -> bool {
+        if !self.has_more {
+            return false;
+        }
+        self.page += 1;
+        true
+    }
+
+    /// Move to the previous page. Returns false on the first page.
+    pub fn previous_page(&mut self) -> bool {
+        if self.page == 0 {
+            return false;
+        }
+        self.page -= 1;
+        true
     }
 
     pub fn query(&self) -> &str {
         &self.query
     }
 
-    pub fn set_results(&mut self, results: Vec<SearchResult>) {
+    /// Show a page of results. The query asks for one row more than a page,
+    /// which only tells us whether there is a next page.
+    pub fn set_results(&mut self, mut results: Vec<SearchResult>) {
+        self.has_more = results.len() > SEARCH_PAGE_SIZE;
+        results.truncate(SEARCH_PAGE_SIZE);
         self.results = results;
         self.selected = if self.results.is_empty() {
             None
         } else {
             Some(0)
         };
+        *self.list_state.offset_mut() = 0;
+    }
+
+    /// Like [`Self::set_results`], but keep the selection and scroll offset,
+    /// for re-running the same query when the screen is re-entered.
+    pub fn refresh_results(&mut self, results: Vec<SearchResult>) {
+        let selected = self.selected;
+        let offset = self.list_state.offset();
+        self.set_results(results);
+        if let Some(index) = selected {
+            self.select(index);
+        }
+        *self.list_state.offset_mut() = offset;
     }
 
     pub fn results(&self) -> &[SearchResult] {
@@ -60,6 +122,12 @@ impl SearchUI {
         self.selected
     }
 
+    pub fn select(&mut self, index: usize) {
+        if !self.results.is_empty() {
+            self.selected = Some(index.min(self.results.len() - 1));
+        }
+    }
+
     pub fn next_result(&mut self) {
         if let Some(sel) = self.selected {
             if sel < self.results.len().saturating_sub(1) {
@@ -79,26 +147,37 @@ impl SearchUI {
     }
 
     pub fn build_search_query(&self) -> SearchQuery {
+        // Pull out filter tokens (size:>1GB, before:2024-01-01, after:..., set:SET-xyz, #tag)
+        let mut query = SearchQuery {
+            sort: self.sort,
+            limit: Some(SEARCH_PAGE_SIZE as u32 + 1),
+            offset: (self.page * SEARCH_PAGE_SIZE) as u32,
+            ..Default::default()
+        };
+        let text = self
+            .query
+            .split_whitespace()
+            .filter(|token| !crate::search::apply_filter_token(token, &mut query))
+            .collect::<Vec<_>>()
+            .join(" ");
+
         // Check if query looks like a SHA256 (64 hex chars)
-        let is_sha256 = self.query.len() == 64 && self.query.chars().all(|c| c.is_ascii_hexdigit());
-
-        SearchQuery {
-            path_substring: if self.query.is_empty() || is_sha256 {
-                None
-            } else {
-                Some(self.query.clone())
-            },
-            exact_filename: None,
-            sha256: if is_sha256 {
-                Some(self.query.clone())
-            } else {
-                None
-            },
-            regex: None,
+        let is_sha256 = text.len() == 64 && text.chars().all(|c| c.is_ascii_hexdigit());
+        // Phrase ("...") or prefix (term*) queries use the full-text index
+        let is_full_text = !is_sha256 && crate::search::looks_like_fts_query(&text);
+
+        if is_sha256 {
+            query.sha256 = Some(text);
+        } else if is_full_text {
+            query.full_text = Some(text);
+        } else if !text.is_empty() {
+            query.path_substring = Some(text);
         }
+
+        query
     }
 
-    pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+    pub fn render(&mut self, theme: &Theme, frame: &mut Frame, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Length(3), Constraint::Min(0)])
@@ -117,7 +196,9 @@ impl SearchUI {
 
         // Results list
         if self.results.is_empty() {
-            let message = Paragraph::new("No results. Type to search.")
+            let message = Paragraph::new(
+                "No results. Type to search.\n\nFilters: size:>1GB  size:<100MB  after:2024-01-01  before:2024-06-01  set:SET-id  #tag",
+            )
                 .block(
                     Block::default()
                         .title("Results")
@@ -131,9 +212,19 @@ impl SearchUI {
                 .results
                 .iter()
                 .map(|r| {
+                    let mut location = match r.set_id {
+                        Some(ref set_id) => format!("{} ({})", r.disc_id, set_id),
+                        None => r.disc_id.clone(),
+                    };
+                    if let Some(ref shelf) = r.storage_location {
+                        location.push_str(&format!(" @ {}", shelf));
+                    }
+                    if r.planned {
+                        location.push_str(" [planned]");
+                    }
                     ListItem::new(format!(
                         "{} │ {} │ {} │ {}",
-                        r.disc_id,
+                        location,
                         r.rel_path,
                         crate::search::format_size(r.size),
                         r.mtime
@@ -141,22 +232,71 @@ impl SearchUI {
                 })
                 .collect();
 
+            let first = self.page * SEARCH_PAGE_SIZE + 1;
+            let title = format!(
+                "Results {}-{}{} │ Sort: {} │ [Tab] Sort  [PgUp/PgDn] Page",
+                first,
+                first + self.results.len() - 1,
+                if self.has_more { "+" } else { "" },
+                self.sort.label()
+            );
             let list = List::new(items)
                 .block(
                     Block::default()
-                        .title("Results")
+                        .title(title)
                         .borders(Borders::ALL)
                         .border_style(theme.border_style()),
                 )
                 .highlight_style(theme.highlight_style())
                 .highlight_symbol("▶ ");
 
-            let mut state = ratatui::widgets::ListState::default();
-            if let Some(sel) = self.selected {
-                state.select(Some(sel));
-            }
-
-            frame.render_stateful_widget(list, chunks[1], &mut state);
+            self.list_state.select(self.selected);
+            frame.render_stateful_widget(list, chunks[1], &mut self.list_state);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results(count: usize) -> Vec<SearchResult> {
+        (0..count)
+            .map(|i| SearchResult {
+                disc_id: "2024-BD-001".to_string(),
+                rel_path: format!("ARCHIVE/file{:03}.txt", i),
+                size: 1,
+                mtime: "2024-01-01T00:00:00Z".to_string(),
+                sha256: "a".repeat(64),
+                set_id: None,
+                storage_location: None,
+                planned: false,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_refresh_keeps_position() {
+        let mut search = SearchUI::new();
+        search.set_results(results(50));
+        search.select(30);
+        *search.list_state.offset_mut() = 20;
+
+        // Re-entering the screen re-runs the query but stays on the same row
+        search.refresh_results(results(50));
+        assert_eq!(search.selected(), Some(30));
+        assert_eq!(search.list_state.offset(), 20);
+
+        // Fewer results than before: the selection moves to the last one
+        search.refresh_results(results(10));
+        assert_eq!(search.selected(), Some(9));
+        search.refresh_results(Vec::new());
+        assert_eq!(search.selected(), None);
+
+        // A new search starts from the top
+        search.select(5);
+        search.set_results(results(50));
+        assert_eq!(search.selected(), Some(0));
+        assert_eq!(search.list_state.offset(), 0);
+    }
+}
@@ -1,3 +1,7 @@
+use crate::burn::DriveCaps;
+use crate::database::VacuumStats;
+use crate::search::format_size;
+use std::path::{Path, PathBuf};
 use crate::theme::{no_animations, reduced_motion, Theme, ThemeName};
 use ratatui::{
     prelude::*,
@@ -5,15 +9,15 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Settings {
-    // Placeholder for settings UI
-}
-
-impl Default for Settings {
-    fn default() -> Self {
-        Self {}
-    }
+    /// Result of the last database maintenance action
+    maintenance_report: Option<String>,
+    /// Rolling catalog backups, newest first
+    backups: Vec<PathBuf>,
+    selected_backup: usize,
+    /// What the configured burners reported, once queried
+    drive_report: Option<String>,
 }
 
 impl Settings {
@@ -21,6 +25,123 @@ impl Settings {
         Self::default()
     }
 
+    /// Show the result of a database integrity check.
+    pub fn set_integrity_report(&mut self, problems: &[String], row_counts: &[(&str, u64)], orphaned_files: u64) {
+        let mut report = if problems.is_empty() {
+            "Integrity check: OK".to_string()
+        } else {
+            const SHOWN: usize = 10;
+            let mut report = format!("Integrity check: {} problem(s)", problems.len());
+            for problem in problems.iter().take(SHOWN) {
+                report.push_str(&format!("\n  {}", problem));
+            }
+            if problems.len() > SHOWN {
+                report.push_str(&format!("\n  ...and {} more (see the log)", problems.len() - SHOWN));
+            }
+            report
+        };
+        report.push_str(&format!("\nOrphaned file records: {}", orphaned_files));
+        for (table, count) in row_counts {
+            report.push_str(&format!("\n  {}: {} rows", table, count));
+        }
+        self.maintenance_report = Some(report);
+    }
+
+    pub fn set_vacuum_report(&mut self, stats: &VacuumStats) {
+        self.maintenance_report = Some(format!(
+            "Vacuum complete: {} -> {} (saved {})",
+            format_size(stats.before_bytes),
+            format_size(stats.after_bytes),
+            format_size(stats.saved_bytes())
+        ));
+    }
+
+    pub fn set_backups(&mut self, backups: Vec<PathBuf>) {
+        self.backups = backups;
+        self.selected_backup = self.selected_backup.min(self.backups.len().saturating_sub(1));
+    }
+
+    pub fn selected_backup(&self) -> Option<&PathBuf> {
+        self.backups.get(self.selected_backup)
+    }
+
+    pub fn next_backup(&mut self) {
+        if self.selected_backup + 1 < self.backups.len() {
+            self.selected_backup += 1;
+        }
+    }
+
+    pub fn previous_backup(&mut self) {
+        self.selected_backup = self.selected_backup.saturating_sub(1);
+    }
+
+    pub fn set_backup_report(&mut self, path: &Path) {
+        self.maintenance_report = Some(format!("Backed up to {}", path.display()));
+    }
+
+    pub fn set_restore_report(&mut self, path: &Path) {
+        self.maintenance_report = Some(format!(
+            "Restored catalog from {}\n(the catalog as it was before is the newest backup)",
+            path.display()
+        ));
+    }
+
+    /// Backup list with the selection marked; at most a few entries around it are shown.
+    fn backups_text(&self) -> String {
+        const SHOWN: usize = 5;
+        if self.backups.is_empty() {
+            return "  (no backups yet)".to_string();
+        }
+        let start = self.selected_backup.saturating_sub(SHOWN - 1);
+        let mut text: Vec<String> = self
+            .backups
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(SHOWN)
+            .map(|(i, path)| {
+                let marker = if i == self.selected_backup { ">" } else { " " };
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                let size = std::fs::metadata(path).map(|m| format_size(m.len())).unwrap_or_default();
+                format!(" {} {} {}", marker, name, size)
+            })
+            .collect();
+        if self.backups.len() > start + SHOWN {
+            text.push(format!("   ...{} older", self.backups.len() - start - SHOWN));
+        }
+        text.join("\n")
+    }
+
+    /// Show what each burner reported, or why it could not be queried.
+    pub fn set_drive_report(&mut self, drives: &[(String, Result<DriveCaps, String>)]) {
+        let yes_no = |supported: bool| if supported { "yes" } else { "no" };
+        let report: Vec<String> = drives
+            .iter()
+            .map(|(device, caps)| match caps {
+                Ok(caps) => format!(
+                    "  {}: {}\n    BD-R: {}  BD-RE: {}  M-DISC BD-R: {}\n    Media: {}\n    Write speeds: {}",
+                    device,
+                    caps.model.as_deref().unwrap_or("unknown model"),
+                    yes_no(caps.supports_bdr()),
+                    yes_no(caps.supports_bdre()),
+                    yes_no(caps.supports_mdisc()),
+                    if caps.media_types.is_empty() { "(none reported)".to_string() } else { caps.media_types.join(", ") },
+                    if caps.write_speeds.is_empty() {
+                        "(insert a blank disc to list them)".to_string()
+                    } else {
+                        caps.write_speeds.join(", ")
+                    }
+                ),
+                Err(e) => format!("  {}: {}", device, e),
+            })
+            .collect();
+        self.drive_report = Some(report.join("\n"));
+    }
+
+    pub fn set_maintenance_error(&mut self, error: String) {
+        self.maintenance_report = Some(format!("Maintenance failed: {}", error));
+    }
+
     pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
         use ratatui::layout::{Constraint, Direction, Layout};
 
@@ -55,13 +176,16 @@ impl Settings {
         };
 
         let settings_text = format!(
-            "Theme: {}\n\nMotion:\n  Animations: {}\n  Reduced Motion: {}\n\nEnvironment Variables:\n  TUI_THEME={}\n  TUI_NO_ANIM={}\n  TUI_REDUCED_MOTION={}\n\n[Esc] Back to menu",
+            "Theme: {}\n\nMotion:\n  Animations: {}\n  Reduced Motion: {}\n\nEnvironment Variables:\n  TUI_THEME={}\n  TUI_NO_ANIM={}\n  TUI_REDUCED_MOTION={}\n\nBurners ([d] query capabilities):\n{}\n\nDatabase maintenance:\n  [c] Check integrity  [v] Vacuum (reclaim space)  [b] Back up now\n\nBackups (newest first; [↑/↓] select, [r] restore):\n{}\n\n{}\n\n[Esc] Back to menu",
             theme_name,
             motion_status,
             if reduced_motion() { "Yes" } else { "No" },
             std::env::var("TUI_THEME").unwrap_or_else(|_| "(not set)".to_string()),
             std::env::var("TUI_NO_ANIM").unwrap_or_else(|_| "(not set)".to_string()),
-            std::env::var("TUI_REDUCED_MOTION").unwrap_or_else(|_| "(not set)".to_string())
+            std::env::var("TUI_REDUCED_MOTION").unwrap_or_else(|_| "(not set)".to_string()),
+            self.drive_report.as_deref().unwrap_or("  (not queried)"),
+            self.backups_text(),
+            self.maintenance_report.as_deref().unwrap_or("")
         );
 
         let para = Paragraph::new(settings_text)
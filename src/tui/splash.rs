@@ -1,8 +1,10 @@
+use crate::database;
 use crate::theme::Theme;
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Wrap},
 };
+use rusqlite::{Connection, DatabaseName};
 use std::path::PathBuf;
 use std::time::Instant;
 
@@ -15,12 +17,31 @@ pub struct SplashScreen {
     skipped: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Result of the startup check of the catalog connection
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DbStatus {
     Ok,
-    NeedsMigration,
+    /// The catalog was migrated from this schema version during startup
+    Migrating { from: u32 },
     ReadOnly,
-    Error,
+    /// The connection failed its check, with the reason
+    Error(String),
+}
+
+impl DbStatus {
+    /// Check the open catalog with [`database::check_connection`] and return
+    /// its status with the number of indexed discs. `stored_version` is the
+    /// schema version the file had before startup, if any.
+    pub fn probe(conn: &Connection, stored_version: Option<u32>) -> (Self, usize) {
+        match database::check_connection(conn) {
+            Err(e) => (Self::Error(format!("{:#}", e)), 0),
+            Ok(discs) if conn.is_readonly(DatabaseName::Main).unwrap_or(false) => (Self::ReadOnly, discs),
+            Ok(discs) => match stored_version {
+                Some(from) if from < database::SCHEMA_VERSION => (Self::Migrating { from }, discs),
+                _ => (Self::Ok, discs),
+            },
+        }
+    }
 }
 
 impl SplashScreen {
@@ -34,9 +55,11 @@ impl SplashScreen {
         }
     }
 
-    /// Check if splash should be shown (<= 1 second)
+    /// Check if splash should be shown (<= 1 second, or until a key is
+    /// pressed when the catalog check found something to report)
     pub fn should_show(&self) -> bool {
-        !self.skipped && self.created_at.elapsed() < std::time::Duration::from_secs(1)
+        !self.skipped
+            && (self.db_status != DbStatus::Ok || self.created_at.elapsed() < std::time::Duration::from_secs(1))
     }
 
     /// Mark splash as skipped
@@ -47,23 +70,25 @@ impl SplashScreen {
     pub fn render(&self, theme: &Theme, area: Rect, frame: &mut Frame) {
         let center_area = crate::ui::layout::GridLayout::centered_dialog(area, 70, 12);
 
-        let status_text = match self.db_status {
+        let status_text = match &self.db_status {
             DbStatus::Ok => format!("[OK] {}", self.disc_count),
-            DbStatus::NeedsMigration => "[NEEDS MIGRATION]".to_string(),
+            DbStatus::Migrating { from } => {
+                format!("[MIGRATED] schema v{} -> v{}", from, database::SCHEMA_VERSION)
+            }
             DbStatus::ReadOnly => "[READ-ONLY]".to_string(),
-            DbStatus::Error => "[ERROR]".to_string(),
+            DbStatus::Error(msg) => format!("[ERROR] {}", msg),
         };
 
         let status_style = match self.db_status {
             DbStatus::Ok => theme.success_style(),
-            DbStatus::NeedsMigration | DbStatus::ReadOnly => theme.warning_style(),
-            DbStatus::Error => theme.error_style(),
+            DbStatus::Migrating { .. } | DbStatus::ReadOnly => theme.warning_style(),
+            DbStatus::Error(_) => theme.error_style(),
         };
 
-        let discs_text = if self.db_status == DbStatus::Ok {
-            self.disc_count.to_string()
-        } else {
+        let discs_text = if matches!(self.db_status, DbStatus::Error(_)) {
             "N/A".to_string()
+        } else {
+            self.disc_count.to_string()
         };
 
         // Build styled text with color for status
@@ -81,12 +106,14 @@ impl SplashScreen {
             Line::from("Press any key to continue..."),
         ]);
 
-        let paragraph = Paragraph::new(splash_text).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(theme.border_style())
-                .title("Cold Boot"),
-        );
+        let paragraph = Paragraph::new(splash_text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style())
+                    .title("Cold Boot"),
+            )
+            .wrap(Wrap { trim: true });
 
         frame.render_widget(paragraph, center_area);
     }
@@ -1,16 +1,21 @@
 use crate::database;
+use crate::disc::format_timestamp_relative;
 use crate::theme::Theme;
 use crate::verify::{DiscVerificationStatus, MultiDiscVerificationResult};
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{Block, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table, Wrap},
     Frame,
 };
 
 #[derive(Debug)]
 pub struct VerifyMultiDiscUI {
     disc_sets: Vec<database::DiscSet>,
+    /// Latest whole-set verification of each entry in `disc_sets`
+    last_runs: Vec<Option<database::SetVerificationRun>>,
     selected_index: usize,
+    /// Outcome per sequence number (index 0 = disc 1) of the running verification; None = pending
+    disc_statuses: Vec<Option<DiscVerificationStatus>>,
     verification_result: Option<MultiDiscVerificationResult>,
     verification_state: VerificationState,
     status_message: String,
@@ -21,6 +26,8 @@ pub struct VerifyMultiDiscUI {
 pub enum VerificationState {
     SelectingSet,
     Verifying,
+    /// Waiting for the user to insert a disc of the set
+    WaitingForDisc { sequence: u32, disc_id: String, problem: Option<String> },
     Complete,
     Error(String),
 }
@@ -29,7 +36,9 @@ impl VerifyMultiDiscUI {
     pub fn new() -> Self {
         Self {
             disc_sets: Vec::new(),
+            last_runs: Vec::new(),
             selected_index: 0,
+            disc_statuses: Vec::new(),
             verification_result: None,
             verification_state: VerificationState::SelectingSet,
             status_message: "Select a multi-disc set to verify".to_string(),
@@ -37,11 +46,42 @@ impl VerifyMultiDiscUI {
         }
     }
 
-    pub fn set_disc_sets(&mut self, sets: Vec<database::DiscSet>) {
+    pub fn set_disc_sets(&mut self, sets: Vec<database::DiscSet>, last_runs: Vec<Option<database::SetVerificationRun>>) {
         self.disc_sets = sets;
+        self.last_runs = last_runs;
         self.selected_index = 0;
     }
 
+    /// Start tracking a set of `total_discs` discs, all pending.
+    pub fn begin_set(&mut self, total_discs: u32) {
+        self.disc_statuses = vec![None; total_discs as usize];
+        self.start_verifying();
+    }
+
+    /// Record the outcome of disc `sequence` as soon as it is known.
+    pub fn record_disc(&mut self, sequence: u32, status: DiscVerificationStatus) {
+        let index = sequence.saturating_sub(1) as usize;
+        if index >= self.disc_statuses.len() {
+            self.disc_statuses.resize(index + 1, None);
+        }
+        self.disc_statuses[index] = Some(status);
+    }
+
+    pub fn start_verifying(&mut self) {
+        self.verification_result = None;
+        self.error_message = None;
+        self.verification_state = VerificationState::Verifying;
+    }
+
+    /// Ask the user to insert disc `sequence` of the set.
+    pub fn request_disc(&mut self, sequence: u32, disc_id: String, problem: Option<String>) {
+        self.verification_state = VerificationState::WaitingForDisc { sequence, disc_id, problem };
+    }
+
+    pub fn is_waiting_for_disc(&self) -> bool {
+        matches!(self.verification_state, VerificationState::WaitingForDisc { .. })
+    }
+
     pub fn set_verification_result(&mut self, result: MultiDiscVerificationResult) {
         self.verification_result = Some(result);
         self.verification_state = VerificationState::Complete;
@@ -98,6 +138,7 @@ impl VerifyMultiDiscUI {
         let title = match self.verification_state {
             VerificationState::SelectingSet => "🔍 Multi-Disc Set Verification",
             VerificationState::Verifying => "🔄 Verifying Multi-Disc Set",
+            VerificationState::WaitingForDisc { .. } => "💿 Insert Next Disc",
             VerificationState::Complete => "✅ Verification Complete",
             VerificationState::Error(_) => "❌ Verification Error",
         };
@@ -111,6 +152,9 @@ impl VerifyMultiDiscUI {
         match self.verification_state {
             VerificationState::SelectingSet => self.render_set_selection(theme, frame, chunks[1]),
             VerificationState::Verifying => self.render_verification_progress(theme, frame, chunks[1]),
+            VerificationState::WaitingForDisc { sequence, ref disc_id, ref problem } => {
+                self.render_disc_request(theme, frame, chunks[1], sequence, disc_id, problem.as_deref())
+            }
             VerificationState::Complete => self.render_verification_results(theme, frame, chunks[1]),
             VerificationState::Error(ref err) => self.render_error(theme, frame, chunks[1], err),
         }
@@ -125,6 +169,7 @@ impl VerifyMultiDiscUI {
                 }
             }
             VerificationState::Verifying => "Verifying discs... Please wait.",
+            VerificationState::WaitingForDisc { .. } => "Enter: Disc inserted  s: Skip (mark missing)  Esc: Abort",
             VerificationState::Complete => "Esc: Back to main menu",
             VerificationState::Error(_) => "Esc: Back to set selection",
         };
@@ -146,17 +191,30 @@ impl VerifyMultiDiscUI {
         }
 
         let items: Vec<ListItem> = self.disc_sets.iter().enumerate().map(|(i, set)| {
-            let disc_count_text = if set.disc_count == 1 {
-                "1 disc".to_string()
+            let disc_count_text = if set.status == database::SetStatus::Complete {
+                if set.disc_count == 1 {
+                    "1 disc".to_string()
+                } else {
+                    format!("{} discs", set.disc_count)
+                }
             } else {
-                format!("{} discs", set.disc_count)
+                // Partial sets say how far they got
+                format!("{} of {} discs, {}", set.completed_count, set.disc_count, set.status.label())
             };
 
             let size_mb = set.total_size / (1024 * 1024);
-            let item_text = format!(
+            let mut item_text = format!(
                 "{} - {} ({} MB)",
                 set.name, disc_count_text, size_mb
             );
+            match self.last_runs.get(i).and_then(Option::as_ref) {
+                Some(run) if run.success => item_text.push_str(&format!(" - last verified {}: OK", format_timestamp_relative(&run.verified_at))),
+                Some(run) => item_text.push_str(&format!(
+                    " - last verified {}: {} failed, {} missing",
+                    format_timestamp_relative(&run.verified_at), run.discs_failed, run.discs_missing
+                )),
+                None => item_text.push_str(" - never verified as a set"),
+            }
 
             let mut style = theme.secondary_style();
             if i == self.selected_index {
@@ -176,13 +234,80 @@ impl VerifyMultiDiscUI {
     }
 
     fn render_verification_progress(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
-        let progress_text = format!("{}\n\n{}", self.status_message, "⏳ Checking discs...");
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(4), Constraint::Min(1)])
+            .split(area);
 
+        let progress_text = format!("{}\n\n{}", self.status_message, "⏳ Checking discs...");
         let para = Paragraph::new(progress_text)
             .style(theme.secondary_style())
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
-        frame.render_widget(para, area);
+        frame.render_widget(para, chunks[0]);
+        self.render_disc_tracker(theme, frame, chunks[1]);
+    }
+
+    /// Passed, failed, missing and pending discs of the running verification.
+    fn render_disc_tracker(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
+        let count = |matches: fn(&Option<DiscVerificationStatus>) -> bool| {
+            self.disc_statuses.iter().filter(|status| matches(status)).count()
+        };
+        let summary = format!(
+            "Passed: {}  Failed: {}  Missing: {}  Pending: {}",
+            count(|s| matches!(s, Some(DiscVerificationStatus::Verified { .. }))),
+            count(|s| matches!(s, Some(DiscVerificationStatus::Failed { .. }))),
+            count(|s| matches!(s, Some(DiscVerificationStatus::Missing))),
+            count(|s| s.is_none()),
+        );
+
+        let items: Vec<ListItem> = std::iter::once(ListItem::new(summary).style(theme.highlight_style()))
+            .chain(self.disc_statuses.iter().enumerate().map(|(i, status)| {
+                let (label, style) = match status {
+                    Some(DiscVerificationStatus::Verified { .. }) => ("✅ passed", theme.success_style()),
+                    Some(DiscVerificationStatus::Failed { .. }) => ("❌ failed", theme.error_style()),
+                    Some(DiscVerificationStatus::Missing) => ("⚠️  missing", theme.warning_style()),
+                    Some(DiscVerificationStatus::NotAttempted) => ("skipped", theme.secondary_style()),
+                    None => ("… pending", theme.secondary_style()),
+                };
+                ListItem::new(format!("Disc {}: {}", i + 1, label)).style(style)
+            }))
+            .collect();
+
+        let list = List::new(items).block(Block::default().borders(Borders::TOP).title("Discs"));
+        frame.render_widget(list, area);
+    }
+
+    fn render_disc_request(
+        &self,
+        theme: &Theme,
+        frame: &mut Frame,
+        area: Rect,
+        sequence: u32,
+        disc_id: &str,
+        problem: Option<&str>,
+    ) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(5), Constraint::Min(1)])
+            .split(area);
+
+        let mut text = format!(
+            "Insert disc {} of {} ({}) and press Enter.\nThe drive is then polled until the disc can be read.",
+            sequence,
+            self.disc_statuses.len(),
+            disc_id
+        );
+        if let Some(problem) = problem {
+            text.push_str(&format!("\n\n⚠️  {}", problem));
+        }
+
+        let para = Paragraph::new(text)
+            .style(if problem.is_some() { theme.warning_style() } else { theme.secondary_style() })
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(para, chunks[0]);
+        self.render_disc_tracker(theme, frame, chunks[1]);
     }
 
     fn render_verification_results(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
@@ -190,7 +315,7 @@ impl VerifyMultiDiscUI {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Length(3), // Summary
+                    Constraint::Length(5), // Summary
                     Constraint::Min(1),    // Disc details
                 ])
                 .split(area);
@@ -200,49 +325,65 @@ impl VerifyMultiDiscUI {
                 format!("Set: {}", result.set_name),
                 format!("Total Discs: {}", result.total_discs),
                 format!("Verified: {}  Failed: {}  Missing: {}",
-                    result.discs_verified, result.discs_failed, result.discs_missing),
+                    result.discs_verified(), result.discs_failed(), result.discs_missing()),
                 format!("Files Checked: {}  Failed: {}",
-                    result.total_files_checked, result.total_files_failed),
+                    result.total_files_checked(), result.total_files_failed()),
             ];
 
-            let status_icon = if result.overall_success { "✅" } else { "❌" };
+            let status_icon = if result.overall_success() { "✅" } else { "❌" };
             let summary_text = format!("{} {}\n{}",
                 status_icon,
-                if result.overall_success { "VERIFICATION SUCCESSFUL" } else { "VERIFICATION ISSUES FOUND" },
+                if result.overall_success() { "VERIFICATION SUCCESSFUL" } else { "VERIFICATION ISSUES FOUND" },
                 summary_lines.join("\n")
             );
 
             let summary_para = Paragraph::new(summary_text)
-                .style(if result.overall_success { theme.success_style() } else { theme.error_style() })
+                .style(if result.overall_success() { theme.success_style() } else { theme.error_style() })
                 .wrap(Wrap { trim: true });
             frame.render_widget(summary_para, chunks[0]);
 
-            // Disc details
-            let disc_items: Vec<ListItem> = result.disc_results.iter().map(|(disc_id, status)| {
-                let (status_icon, status_text, style) = match status {
-                    DiscVerificationStatus::Verified { files_checked, files_failed } => {
-                        ("✅", format!("Verified ({} files, {} failed)", files_checked, files_failed), theme.success_style())
+            // Per-disc table, one row per sequence number
+            let rows: Vec<Row> = result.status_rows().into_iter().map(|(sequence, disc_id, status)| {
+                let (label, details, style) = match status {
+                    DiscVerificationStatus::Verified { files_checked, files_failed, warnings: 0 } => {
+                        ("OK", format!("{} files, {} failed", files_checked, files_failed), theme.success_style())
+                    }
+                    DiscVerificationStatus::Verified { files_checked, files_failed, warnings } => {
+                        ("WARN", format!("{} files, {} failed, {} warning(s)", files_checked, files_failed, warnings), theme.warning_style())
                     }
                     DiscVerificationStatus::Failed { error } => {
-                        ("❌", format!("Failed: {}", error), theme.error_style())
+                        ("FAIL", error.lines().next().unwrap_or_default().to_string(), theme.error_style())
                     }
                     DiscVerificationStatus::Missing => {
-                        ("⚠️", "Missing/Not Found".to_string(), theme.warning_style())
+                        ("MISSING", "Not inserted or not found".to_string(), theme.warning_style())
                     }
                     DiscVerificationStatus::NotAttempted => {
-                        ("⏭️", "Skipped".to_string(), theme.secondary_style())
+                        ("SKIPPED", String::new(), theme.secondary_style())
                     }
                 };
 
-                let item_text = format!("{} {} - {}", status_icon, disc_id, status_text);
-                ListItem::new(item_text).style(style)
+                Row::new(vec![
+                    Cell::from(sequence.to_string()),
+                    Cell::from(disc_id),
+                    Cell::from(label),
+                    Cell::from(details),
+                ])
+                .style(style)
             }).collect();
 
-            let disc_list = List::new(disc_items)
-                .block(Block::default().borders(Borders::NONE).title("Disc Verification Details"))
-                .highlight_symbol("");
-
-            frame.render_widget(disc_list, chunks[1]);
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(4),
+                    Constraint::Length(22),
+                    Constraint::Length(8),
+                    Constraint::Min(10),
+                ],
+            )
+            .header(Row::new(vec!["#", "Disc ID", "Result", "Details"]).style(theme.highlight_style()))
+            .block(Block::default().borders(Borders::NONE).title("Disc Verification Details"));
+
+            frame.render_widget(table, chunks[1]);
         }
     }
 
@@ -17,6 +17,18 @@ pub struct VerifyUI {
     error_message: Option<String>,
     verification_state: VerificationState,
     verification_result: Option<super::super::verify::VerificationResult>,
+    /// Hash a random sample of files instead of all of them
+    sample: bool,
+    /// Configured sample size as written in the config, e.g. "5%"
+    sample_size: String,
+    /// "sampled 50 of 4000 files, all OK" after a sample check
+    sample_summary: Option<String>,
+    /// Where the disc is still mounted after verification
+    kept_mount: Option<std::path::PathBuf>,
+    /// Whether [O] can open the mountpoint in a file manager
+    can_open_folder: bool,
+    /// Outcome of the last [O]/[Y] action or unmount problem
+    notice: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -47,6 +59,12 @@ impl Default for VerifyUI {
             error_message: None,
             verification_state: VerificationState::Idle,
             verification_result: None,
+            sample: false,
+            sample_size: String::new(),
+            sample_summary: None,
+            kept_mount: None,
+            can_open_folder: false,
+            notice: None,
         }
     }
 }
@@ -115,6 +133,23 @@ impl VerifyUI {
         self.input_buffer.clear();
     }
 
+    /// Switch between a full verification and a sample check.
+    pub fn toggle_sample(&mut self) {
+        self.sample = !self.sample;
+    }
+
+    pub fn is_sample(&self) -> bool {
+        self.sample
+    }
+
+    pub fn set_sample_size(&mut self, size: String) {
+        self.sample_size = size;
+    }
+
+    pub fn set_sample_summary(&mut self, summary: String) {
+        self.sample_summary = Some(summary);
+    }
+
     pub fn set_verification_state(&mut self, state: VerificationState) {
         self.verification_state = state;
     }
@@ -141,6 +176,38 @@ impl VerifyUI {
         self.verification_result = Some(result);
     }
 
+    /// Record that the disc stays mounted at `path`, offering [O] only where
+    /// a file manager can be opened.
+    pub fn set_kept_mount(&mut self, path: std::path::PathBuf, can_open_folder: bool) {
+        self.kept_mount = Some(path);
+        self.can_open_folder = can_open_folder;
+    }
+
+    pub fn kept_mount(&self) -> Option<&std::path::Path> {
+        self.kept_mount.as_deref()
+    }
+
+    pub fn set_notice(&mut self, notice: impl Into<String>) {
+        self.notice = Some(notice.into());
+    }
+
+    /// Mountpoint and key hints shown under a finished verification
+    fn mount_text(&self) -> String {
+        let mut text = String::new();
+        let mut keys = String::new();
+        if let Some(path) = &self.kept_mount {
+            text.push_str(&format!("\n\n💿 Disc still mounted at {}", path.display()));
+            if self.can_open_folder {
+                keys.push_str("[O] Open Folder, ");
+            }
+            keys.push_str("[Y] Copy Path, ");
+        }
+        if let Some(notice) = &self.notice {
+            text.push_str(&format!("\n{}", notice));
+        }
+        format!("{}\n\n{}[Esc] Back to menu", text, keys)
+    }
+
     pub fn render(&self, theme: &Theme, frame: &mut Frame, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -233,9 +300,15 @@ impl VerifyUI {
                         VerifyInputMode::Ready => "",
                     };
 
+                    let check = if self.sample {
+                        format!("Sample check ({} of files, picked at random)", self.sample_size)
+                    } else {
+                        "Full verification".to_string()
+                    };
+                    let sample_key = if self.input_mode == VerifyInputMode::Ready { ", [S] Sample/full" } else { "" };
                     let text = format!(
-                        "Verify Disc{}\n\nDevice: {}\nMountpoint: {}\n\nType to edit, [Tab] Next, [Enter] Verify, [Esc] Cancel",
-                        mode_text, device_display, mountpoint_display
+                        "Verify Disc{}\n\nDevice: {}\nMountpoint: {}\nCheck: {}\n\nType to edit, [Tab] Next, [Enter] Verify{}, [Esc] Cancel",
+                        mode_text, device_display, mountpoint_display, check, sample_key
                     );
                     let para = Paragraph::new(text)
                         .block(block)
@@ -245,7 +318,18 @@ impl VerifyUI {
             }
             VerificationState::Complete => {
                 if let Some(ref result) = self.verification_result {
-                    let status_text = if result.success {
+                    let status_text = if let Some(summary) = &self.sample_summary {
+                        // A sample only vouches for the files it read
+                        let (tag, error) = if result.success {
+                            ("[OK]", String::new())
+                        } else {
+                            ("[ERR]", format!("\n\nError: {}", result.error_message.as_deref().unwrap_or("Unknown error")))
+                        };
+                        format!(
+                            "{} Sample check: {}\n\nOnly the sampled files were hashed; this is recorded as a sample, not a full verification.{}",
+                            tag, summary, error
+                        )
+                    } else if result.success {
                         format!(
                             "[OK] Verification successful!\n\nFiles checked: {}\nFiles failed: {}",
                             result.files_checked, result.files_failed
@@ -255,7 +339,12 @@ impl VerifyUI {
                             result.files_checked, result.files_failed,
                             result.error_message.as_deref().unwrap_or("Unknown error"))
                     };
-                    let text = format!("{}\n\n[Esc] Back to menu", status_text);
+                    let warnings_text = if result.warnings.is_empty() {
+                        String::new()
+                    } else {
+                        format!("\n\n[WARN] {}", result.warnings.join("\n[WARN] "))
+                    };
+                    let text = format!("{}{}{}", status_text, warnings_text, self.mount_text());
                     let para = Paragraph::new(text)
                         .block(block.clone())
                         .style(if result.success {
@@ -265,7 +354,7 @@ impl VerifyUI {
                         });
                     frame.render_widget(para, chunks[0]);
                 } else {
-                    let text = "Verification complete.\n\n[Esc] Back to menu";
+                    let text = format!("Verification complete.{}", self.mount_text());
                     let para = Paragraph::new(text)
                         .block(block)
                         .style(theme.primary_style());
@@ -1,10 +1,12 @@
 use crate::commands;
+use crate::config::ChecksumAlgorithm;
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
-/// Verify a disc by checking SHA256SUMS.
+/// Verify a disc by checking SHA256SUMS (CRC32SUMS on discs burned with CRC32 checksums).
 pub fn verify_disc(
     mountpoint: &Path,
     _auto_mount: bool,
@@ -12,12 +14,14 @@ pub fn verify_disc(
 ) -> Result<VerificationResult> {
     info!("Verifying disc at: {}", mountpoint.display());
 
-    let sha256sums_path = mountpoint.join("SHA256SUMS.txt");
-
-    if !sha256sums_path.exists() {
-        anyhow::bail!("SHA256SUMS.txt not found at: {}", sha256sums_path.display());
+    if crate::bagit::is_bag(mountpoint) {
+        return verify_bag(mountpoint, dry_run);
     }
 
+    let Some((sums_path, algorithm)) = find_checksum_list(mountpoint) else {
+        anyhow::bail!("SHA256SUMS.txt not found at: {}", mountpoint.join("SHA256SUMS.txt").display());
+    };
+
     if dry_run {
         debug!(
             "[DRY RUN] Would verify SHA256SUMS.txt at: {}",
@@ -28,9 +32,23 @@ pub fn verify_disc(
             files_checked: 0,
             files_failed: 0,
             error_message: None,
+            warnings: Vec::new(),
         });
     }
 
+    // Files sha256sum would never look at: on the disc but not in SHA256SUMS.txt
+    let warnings = match check_manifest_coverage(mountpoint) {
+        Ok(coverage) => coverage.warnings(),
+        Err(e) => vec![format!("Could not compare ARCHIVE contents with SHA256SUMS.txt: {}", e)],
+    };
+    for warning in &warnings {
+        warn!("{}", warning);
+    }
+
+    if algorithm == ChecksumAlgorithm::Crc32 {
+        return verify_crc32sums(mountpoint, &sums_path, warnings);
+    }
+
     // Change to mountpoint directory for sha256sum -c to work correctly
     let output = Command::new("sha256sum")
         .arg("-c")
@@ -67,142 +85,856 @@ pub fn verify_disc(
         files_checked,
         files_failed,
         error_message,
+        warnings,
+    })
+}
+
+/// Check every file CRC32SUMS.txt lists; sha256sum has no CRC32 counterpart.
+fn verify_crc32sums(mountpoint: &Path, sums_path: &Path, warnings: Vec<String>) -> Result<VerificationResult> {
+    let sums = std::fs::read_to_string(sums_path)
+        .with_context(|| format!("Failed to read {}", sums_path.display()))?;
+    let entries: Vec<(&str, &str)> = sums.lines().filter_map(parse_sha256sums_line).collect();
+    let failures: Vec<String> = entries
+        .iter()
+        .filter_map(|&(expected, rel_path)| check_listed_file(mountpoint, rel_path, expected, ChecksumAlgorithm::Crc32))
+        .collect();
+
+    let success = failures.is_empty();
+    if success {
+        info!("Verification successful: {} files checked", entries.len());
+    } else {
+        warn!("Verification failed: {} files checked, {} failed", entries.len(), failures.len());
+    }
+    Ok(VerificationResult {
+        success,
+        files_checked: entries.len() as u32,
+        files_failed: failures.len() as u32,
+        error_message: (!success).then(|| format!("Verification failed:\n{}", failures.join("\n"))),
+        warnings,
+    })
+}
+
+/// The checksum list at `content_root` with its algorithm: SHA256SUMS.txt,
+/// or CRC32SUMS.txt on discs burned with CRC32 checksums.
+fn find_checksum_list(content_root: &Path) -> Option<(PathBuf, ChecksumAlgorithm)> {
+    [
+        ("SHA256SUMS.txt", ChecksumAlgorithm::Sha256),
+        (crate::manifest::CRC32SUMS_FILE, ChecksumAlgorithm::Crc32),
+    ]
+    .into_iter()
+    .map(|(name, algorithm)| (content_root.join(name), algorithm))
+    .find(|(path, _)| path.exists())
+}
+
+/// Hash a listed file and compare it with its recorded checksum.
+/// Returns the problem, if any.
+fn check_listed_file(content_root: &Path, rel_path: &str, expected: &str, algorithm: ChecksumAlgorithm) -> Option<String> {
+    match crate::manifest::calculate_checksum(&content_root.join(rel_path), algorithm) {
+        Ok(actual) if actual.eq_ignore_ascii_case(expected) => None,
+        Ok(_) => Some(format!("{}: checksum mismatch", rel_path)),
+        Err(e) => Some(format!("{}: {:#}", rel_path, e)),
+    }
+}
+
+/// Verify a disc written as a BagIt bag. The bag's own manifest covers the
+/// same files as SHA256SUMS.txt, so it replaces `sha256sum -c` rather than
+/// reading the disc twice.
+fn verify_bag(mountpoint: &Path, dry_run: bool) -> Result<VerificationResult> {
+    if dry_run {
+        debug!("[DRY RUN] Would validate the bag at: {}", mountpoint.display());
+        return Ok(VerificationResult {
+            success: true,
+            files_checked: 0,
+            files_failed: 0,
+            error_message: None,
+            warnings: Vec::new(),
+        });
+    }
+
+    let warnings = match check_manifest_coverage(&crate::bagit::content_root(mountpoint)) {
+        Ok(coverage) => coverage.warnings(),
+        Err(e) => vec![format!("Could not compare ARCHIVE contents with SHA256SUMS.txt: {}", e)],
+    };
+    for warning in &warnings {
+        warn!("{}", warning);
+    }
+
+    let validation = crate::bagit::validate_bag(mountpoint)?;
+    let error_message = if validation.is_valid() {
+        None
+    } else {
+        Some(format!("Bag validation failed:\n{}", validation.failures.join("\n")))
+    };
+    Ok(VerificationResult {
+        success: validation.is_valid(),
+        files_checked: validation.files_checked,
+        files_failed: validation.failures.len() as u32,
+        error_message,
+        warnings,
+    })
+}
+
+/// Decompress every `ARCHIVE/*.tar.zst` on the disc and check the original
+/// files against the hashes in their sidecars, adding the counts to `result`.
+///
+/// `sha256sum -c` only proves the tarballs are intact; this proves what they
+/// extract to is what was archived.
+pub fn verify_compressed_originals(mountpoint: &Path, result: &mut VerificationResult) -> Result<()> {
+    let content_root = crate::bagit::content_root(mountpoint);
+    for archive in crate::compression::find_archives(&content_root.join("ARCHIVE"))? {
+        let check = crate::compression::verify_archive(&archive)?;
+        info!(
+            "Checked {} original files in {}: {} failed",
+            check.files_checked,
+            archive.display(),
+            check.failures.len()
+        );
+        result.files_checked += check.files_checked;
+        if check.failures.is_empty() {
+            continue;
+        }
+        result.success = false;
+        result.files_failed += check.failures.len() as u32;
+        let details = format!("{}:\n{}", archive.display(), check.failures.join("\n"));
+        result.error_message = Some(match result.error_message.take() {
+            Some(previous) => format!("{}\n{}", previous, details),
+            None => format!("Decompressed files failed verification:\n{}", details),
+        });
+    }
+    Ok(())
+}
+
+/// How many of a disc's files a sample check hashes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SampleSize {
+    /// A fixed number of files
+    Count(usize),
+    /// A share of the files, 0.0-1.0
+    Fraction(f64),
+}
+
+impl SampleSize {
+    /// Files to hash out of `total`: at least one when there are any, never more than `total`.
+    pub fn of(self, total: usize) -> usize {
+        let n = match self {
+            SampleSize::Count(n) => n,
+            SampleSize::Fraction(f) => (total as f64 * f).ceil() as usize,
+        };
+        n.clamp(total.min(1), total)
+    }
+}
+
+impl std::str::FromStr for SampleSize {
+    type Err = anyhow::Error;
+
+    /// `50` samples 50 files, `5%` five percent of them.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Some(percent) = s.strip_suffix('%') {
+            let percent: f64 = percent
+                .trim()
+                .parse()
+                .with_context(|| format!("Invalid sample percentage: {}", s))?;
+            if !(percent > 0.0 && percent <= 100.0) {
+                anyhow::bail!("Sample percentage must be above 0 and at most 100: {}", s);
+            }
+            return Ok(SampleSize::Fraction(percent / 100.0));
+        }
+        let count: usize = s
+            .parse()
+            .with_context(|| format!("Invalid sample size: {} (use a file count or a percentage such as 5%)", s))?;
+        if count == 0 {
+            anyhow::bail!("Sample size must be at least one file");
+        }
+        Ok(SampleSize::Count(count))
+    }
+}
+
+/// Outcome of hashing a random sample of a disc's files.
+#[derive(Debug, Clone)]
+pub struct SampleVerification {
+    /// Counts cover the sampled files only
+    pub result: VerificationResult,
+    /// Files listed in SHA256SUMS.txt
+    pub total_files: u32,
+}
+
+impl SampleVerification {
+    /// "sampled 50 of 4000 files, all OK" or "sampled 50 of 4000 files, 2 failed".
+    pub fn summary(&self) -> String {
+        let outcome = match self.result.files_failed {
+            0 => "all OK".to_string(),
+            n => format!("{} failed", n),
+        };
+        format!("sampled {} of {} files, {}", self.result.files_checked, self.total_files, outcome)
+    }
+}
+
+/// Hash a random sample of the files SHA256SUMS.txt lists and compare them
+/// with their recorded checksums. Much faster than [`verify_disc`] on a full
+/// disc, but a pass only vouches for the files that were sampled.
+pub fn verify_sample(mountpoint: &Path, sample: SampleSize) -> Result<SampleVerification> {
+    let content_root = crate::bagit::content_root(mountpoint);
+    let Some((sums_path, algorithm)) = find_checksum_list(&content_root) else {
+        anyhow::bail!("SHA256SUMS.txt not found at: {}", content_root.join("SHA256SUMS.txt").display());
+    };
+    let sums = std::fs::read_to_string(&sums_path)
+        .with_context(|| format!("Failed to read {}", sums_path.display()))?;
+    let entries: Vec<(&str, &str)> = sums.lines().filter_map(parse_sha256sums_line).collect();
+
+    let picked = random_sample(entries.len(), sample.of(entries.len()));
+    info!("Sampling {} of {} files at {}", picked.len(), entries.len(), mountpoint.display());
+
+    let failures: Vec<String> = picked
+        .iter()
+        .filter_map(|&index| {
+            let (expected, rel_path) = entries[index];
+            check_listed_file(&content_root, rel_path, expected, algorithm)
+        })
+        .collect();
+
+    let verification = SampleVerification {
+        result: VerificationResult {
+            success: failures.is_empty(),
+            files_checked: picked.len() as u32,
+            files_failed: failures.len() as u32,
+            error_message: (!failures.is_empty()).then(|| format!("Sample verification failed:\n{}", failures.join("\n"))),
+            warnings: Vec::new(),
+        },
+        total_files: entries.len() as u32,
+    };
+    if failures.is_empty() {
+        info!("Sample verification: {}", verification.summary());
+    } else {
+        warn!("Sample verification: {}", verification.summary());
+    }
+    Ok(verification)
+}
+
+/// `n` distinct indexes below `total`, chosen at random and returned in
+/// ascending order so the disc is read front to back.
+fn random_sample(total: usize, n: usize) -> Vec<usize> {
+    use std::hash::BuildHasher;
+    // RandomState is seeded randomly per instance, which is all the
+    // randomness a spot check needs
+    let state = std::collections::hash_map::RandomState::new();
+    let mut keyed: Vec<(u64, usize)> = (0..total).map(|i| (state.hash_one(i), i)).collect();
+    keyed.sort_unstable();
+    let mut picked: Vec<usize> = keyed.into_iter().take(n).map(|(_, i)| i).collect();
+    picked.sort_unstable();
+    picked
+}
+
+/// Files in a disc's ARCHIVE tree compared with the paths SHA256SUMS.txt lists.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ManifestCoverage {
+    /// Regular files under ARCHIVE/ that SHA256SUMS.txt does not list.
+    pub unlisted: Vec<PathBuf>,
+    /// ARCHIVE/ paths listed in SHA256SUMS.txt that are not on the disc.
+    pub missing: Vec<PathBuf>,
+}
+
+impl ManifestCoverage {
+    pub fn is_complete(&self) -> bool {
+        self.unlisted.is_empty() && self.missing.is_empty()
+    }
+
+    /// Warning lines with counts and a few example paths.
+    pub fn warnings(&self) -> Vec<String> {
+        const EXAMPLES: usize = 3;
+        let describe = |paths: &[PathBuf]| {
+            let mut examples: Vec<String> =
+                paths.iter().take(EXAMPLES).map(|p| p.display().to_string()).collect();
+            if paths.len() > EXAMPLES {
+                examples.push(format!("and {} more", paths.len() - EXAMPLES));
+            }
+            examples.join(", ")
+        };
+
+        let mut warnings = Vec::new();
+        if !self.unlisted.is_empty() {
+            warnings.push(format!(
+                "{} file(s) on disc are not listed in SHA256SUMS.txt: {}",
+                self.unlisted.len(),
+                describe(&self.unlisted)
+            ));
+        }
+        if !self.missing.is_empty() {
+            warnings.push(format!(
+                "{} file(s) listed in SHA256SUMS.txt are missing from the disc: {}",
+                self.missing.len(),
+                describe(&self.missing)
+            ));
+        }
+        warnings
+    }
+}
+
+/// Walk the disc's ARCHIVE tree and compare it with the paths in SHA256SUMS.txt.
+///
+/// `sha256sum -c` only checks the files it is told about, so a file that was
+/// staged but never hashed would otherwise pass verification unnoticed.
+/// The listed paths come from `manifest.json` when the disc has one, otherwise
+/// from SHA256SUMS.txt. Symlinks are skipped on both sides since they have no checksum.
+/// Deduplicated copies are not on the disc; they count as missing only when
+/// their stored copy is.
+pub fn check_manifest_coverage(mountpoint: &Path) -> Result<ManifestCoverage> {
+    use std::collections::BTreeSet;
+
+    let json_path = mountpoint.join(crate::manifest::MANIFEST_JSON_FILE);
+    let mut duplicates = Vec::new();
+    let listed: BTreeSet<PathBuf> = if json_path.exists() {
+        let mut listed = BTreeSet::new();
+        for file in crate::manifest::read_manifest_json(&json_path)?.files {
+            if file.link_target.is_some() || !file.rel_path.starts_with("ARCHIVE") {
+                continue;
+            }
+            match file.duplicate_of {
+                Some(original) => duplicates.push((comparable(&file.rel_path), comparable(&original))),
+                None => {
+                    listed.insert(comparable(&file.rel_path));
+                }
+            }
+        }
+        listed
+    } else {
+        let sums_path = find_checksum_list(mountpoint)
+            .map(|(path, _)| path)
+            .unwrap_or_else(|| mountpoint.join("SHA256SUMS.txt"));
+        let sums = std::fs::read_to_string(&sums_path)
+            .with_context(|| format!("Failed to read {}", sums_path.display()))?;
+        sums.lines()
+            .filter_map(parse_sha256sums_path)
+            .map(|path| comparable(Path::new(path)))
+            .filter(|path| path.starts_with("ARCHIVE"))
+            .collect()
+    };
+
+    let mut on_disc = BTreeSet::new();
+    let archive_dir = mountpoint.join("ARCHIVE");
+    if archive_dir.is_dir() {
+        for entry in walkdir::WalkDir::new(&archive_dir) {
+            let entry = entry.with_context(|| format!("Failed to walk {}", archive_dir.display()))?;
+            if entry.file_type().is_file() {
+                if let Ok(rel) = entry.path().strip_prefix(mountpoint) {
+                    on_disc.insert(comparable(rel));
+                }
+            }
+        }
+    }
+
+    // Listed symlinks are not on the disc as regular files, but they are not missing either
+    let mut missing: Vec<PathBuf> = listed
+        .difference(&on_disc)
+        .filter(|path| std::fs::symlink_metadata(mountpoint.join(path)).is_err())
+        .cloned()
+        .collect();
+    // A copy validates through its stored original, which sha256sum checks
+    missing.extend(
+        duplicates
+            .into_iter()
+            .filter(|(_, original)| !listed.contains(original) || !on_disc.contains(original))
+            .map(|(path, _)| path),
+    );
+    missing.sort();
+
+    Ok(ManifestCoverage {
+        unlisted: on_disc.difference(&listed).cloned().collect(),
+        missing,
     })
 }
 
-/// Verify all discs in a multi-disc set
-pub fn verify_multi_disc_set(
+/// `path` in the normalized form manifests store (see
+/// [`crate::paths::normalize_rel_path`]), so discs and manifests written
+/// before normalization, or on another system, still compare equal.
+/// Paths that cannot be normalized are compared as they are.
+fn comparable(path: &Path) -> PathBuf {
+    crate::paths::normalize_rel_path(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Extract the path from a `<hash>  <path>` (or `<hash> *<path>`) SHA256SUMS line.
+fn parse_sha256sums_path(line: &str) -> Option<&str> {
+    parse_sha256sums_line(line).map(|(_, path)| path)
+}
+
+/// Split a SHA256SUMS line into its hash and path.
+fn parse_sha256sums_line(line: &str) -> Option<(&str, &str)> {
+    let (hash, rest) = line.split_once(' ')?;
+    if hash.is_empty() || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let path = rest.strip_prefix(' ').or_else(|| rest.strip_prefix('*')).unwrap_or(rest);
+    (!path.is_empty()).then_some((hash, path))
+}
+
+/// Disc metadata written after the manifest, so never indexed as files.
+const UNINDEXED_DISC_FILES: &[&str] = &[
+    "MANIFEST.txt",
+    "SHA256SUMS.txt",
+    crate::manifest::CRC32SUMS_FILE,
+    crate::manifest::MANIFEST_JSON_FILE,
+];
+
+/// A file whose size on the disc differs from the catalog.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeMismatch {
+    pub path: PathBuf,
+    pub catalog_size: u64,
+    pub disc_size: u64,
+}
+
+/// Structural differences between a mounted disc and its catalog entry.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiscDiff {
+    /// Files on the disc that the catalog does not list
+    pub only_on_disc: Vec<PathBuf>,
+    /// Catalogued files missing from the disc
+    pub only_in_db: Vec<PathBuf>,
+    /// Files whose size differs, so their recorded checksum cannot match.
+    /// Contents are not re-hashed; full verification does that.
+    pub checksum_mismatches: Vec<SizeMismatch>,
+    /// Files present on both sides
+    pub matched: usize,
+}
+
+impl DiscDiff {
+    pub fn is_clean(&self) -> bool {
+        self.only_on_disc.is_empty() && self.only_in_db.is_empty() && self.checksum_mismatches.is_empty()
+    }
+}
+
+/// Compare the files catalogued for `disc_id` with the disc mounted at
+/// `mountpoint`, by path and size only. Catches files added, removed or
+/// replaced since indexing without reading their contents.
+///
+/// Manifest files written after indexing are ignored, and so are deduplicated
+/// copies whose stored original is on the disc.
+pub fn catalog_diff(conn: &rusqlite::Connection, disc_id: &str, mountpoint: &Path) -> Result<DiscDiff> {
+    use std::collections::BTreeMap;
+
+    if !mountpoint.is_dir() {
+        anyhow::bail!("Not a directory: {}", mountpoint.display());
+    }
+    // Catalog paths are relative to data/ on bagged discs
+    let mountpoint = &crate::bagit::content_root(mountpoint);
+    let catalog: BTreeMap<PathBuf, u64> = crate::database::FileRecord::list_for_disc(conn, disc_id)?
+        .into_iter()
+        .map(|file| (comparable(Path::new(&file.rel_path)), file.size))
+        .collect();
+    if catalog.is_empty() {
+        anyhow::bail!("No files are catalogued for disc {}", disc_id);
+    }
+
+    let mut on_disc = BTreeMap::new();
+    for entry in walkdir::WalkDir::new(mountpoint) {
+        let entry = entry.with_context(|| format!("Failed to walk {}", mountpoint.display()))?;
+        if entry.file_type().is_dir() {
+            continue;
+        }
+        let Ok(rel) = entry.path().strip_prefix(mountpoint) else {
+            continue;
+        };
+        // Symlinks are catalogued with size 0
+        let size = if entry.file_type().is_symlink() {
+            0
+        } else {
+            entry.metadata().with_context(|| format!("Failed to read {}", entry.path().display()))?.len()
+        };
+        on_disc.insert(comparable(rel), size);
+    }
+
+    let duplicates: Vec<(PathBuf, PathBuf)> = crate::manifest::read_manifest_json(&mountpoint.join(crate::manifest::MANIFEST_JSON_FILE))
+        .map(|manifest| {
+            manifest
+                .files
+                .into_iter()
+                .filter_map(|file| file.duplicate_of.map(|original| (comparable(&file.rel_path), comparable(&original))))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut diff = DiscDiff::default();
+    for (path, &disc_size) in &on_disc {
+        match catalog.get(path) {
+            Some(&catalog_size) if catalog_size != disc_size => diff.checksum_mismatches.push(SizeMismatch {
+                path: path.clone(),
+                catalog_size,
+                disc_size,
+            }),
+            Some(_) => diff.matched += 1,
+            None => {
+                let is_metadata = path.file_name().is_some_and(|name| {
+                    UNINDEXED_DISC_FILES.iter().any(|f| name == *f)
+                }) && path.parent().is_some_and(|p| p.as_os_str().is_empty() || p == Path::new("disc_info"));
+                if !is_metadata {
+                    diff.only_on_disc.push(path.clone());
+                }
+            }
+        }
+    }
+    for path in catalog.keys().filter(|path| !on_disc.contains_key(*path)) {
+        let stored_elsewhere = duplicates
+            .iter()
+            .any(|(copy, original)| copy == path && on_disc.contains_key(original));
+        if !stored_elsewhere {
+            diff.only_in_db.push(path.clone());
+        }
+    }
+
+    info!(
+        "Catalog diff for {}: {} matched, {} only on disc, {} only in catalog, {} size mismatches",
+        disc_id,
+        diff.matched,
+        diff.only_on_disc.len(),
+        diff.only_in_db.len(),
+        diff.checksum_mismatches.len()
+    );
+    Ok(diff)
+}
+
+/// Verify the disc mounted at `mountpoint` against the checksums catalogued
+/// for `disc_id`, without reading SHA256SUMS.txt or any other file on the disc
+/// but the archived files themselves. The authoritative check when the disc's
+/// own manifest is damaged, missing or can't be trusted.
+///
+/// A catalogued file missing from the disc still passes when another file
+/// with the same checksum was verified, as deduplication stores it only once.
+/// Files on the disc that the catalog doesn't list are reported as warnings.
+pub fn verify_against_db(conn: &rusqlite::Connection, disc_id: &str, mountpoint: &Path) -> Result<VerificationResult> {
+    use std::collections::HashSet;
+
+    if !mountpoint.is_dir() {
+        anyhow::bail!("Not a directory: {}", mountpoint.display());
+    }
+    let records = crate::database::FileRecord::list_for_disc(conn, disc_id)?;
+    if records.is_empty() {
+        anyhow::bail!("No files are catalogued for disc {}", disc_id);
+    }
+    info!("Verifying {} against {} catalogued files", mountpoint.display(), records.len());
+    // Catalog paths are relative to data/ on bagged discs
+    let content_root = crate::bagit::content_root(mountpoint);
+
+    let mut failures = Vec::new();
+    let mut missing = Vec::new();
+    let mut verified_hashes = HashSet::new();
+    for record in &records {
+        let path = content_root.join(&record.rel_path);
+        // Symlinks are catalogued without a checksum
+        let Some((algorithm, expected)) = record.checksum() else {
+            if std::fs::symlink_metadata(&path).is_err() {
+                failures.push(format!("{}: missing", record.rel_path));
+            }
+            continue;
+        };
+        if !path.is_file() {
+            missing.push(record);
+            continue;
+        }
+        match crate::manifest::calculate_checksum(&path, algorithm) {
+            Ok(actual) if actual.eq_ignore_ascii_case(expected) => {
+                verified_hashes.insert(expected.to_ascii_lowercase());
+            }
+            Ok(_) => failures.push(format!("{}: checksum mismatch", record.rel_path)),
+            Err(e) => failures.push(format!("{}: {:#}", record.rel_path, e)),
+        }
+    }
+    for record in missing {
+        let checksum = record.checksum().map(|(_, checksum)| checksum.to_ascii_lowercase());
+        if !checksum.is_some_and(|checksum| verified_hashes.contains(&checksum)) {
+            failures.push(format!("{}: missing", record.rel_path));
+        }
+    }
+
+    let warnings = match catalog_diff(conn, disc_id, mountpoint) {
+        Ok(diff) if diff.only_on_disc.is_empty() => Vec::new(),
+        Ok(diff) => vec![format!(
+            "{} file(s) on the disc are not in the catalog and were not checked, e.g. {}",
+            diff.only_on_disc.len(),
+            diff.only_on_disc[0].display()
+        )],
+        Err(e) => vec![format!("Could not compare the disc's contents with the catalog: {:#}", e)],
+    };
+    for warning in &warnings {
+        warn!("{}", warning);
+    }
+
+    let success = failures.is_empty();
+    if success {
+        info!("Catalog verification successful: {} files checked", records.len());
+    } else {
+        warn!("Catalog verification failed: {} of {} files", failures.len(), records.len());
+    }
+    Ok(VerificationResult {
+        success,
+        files_checked: records.len() as u32,
+        files_failed: failures.len() as u32,
+        error_message: (!success).then(|| format!("Verification against the catalog failed:\n{}", failures.join("\n"))),
+        warnings,
+    })
+}
+
+/// Where `disc_id` is mounted, if it already is (searched under /media, /run/media and /mnt).
+pub fn find_mounted_disc(disc_id: &str) -> Option<PathBuf> {
+    ["/media", "/run/media", "/mnt"]
+        .iter()
+        .find_map(|base| find_disc_mount_point(disc_id, Path::new(base)))
+}
+
+/// Disc ID of the disc at `root`, from manifest.json or else DISC_INFO.txt
+/// (kept in `disc_info/` on set discs).
+pub fn read_disc_id(root: &Path) -> Option<String> {
+    let content_root = crate::bagit::content_root(root);
+    let manifest_json = content_root.join(crate::manifest::MANIFEST_JSON_FILE);
+    if let Ok(manifest) = crate::manifest::read_manifest_json(&manifest_json) {
+        return Some(manifest.disc.disc_id);
+    }
+    let disc_info = std::fs::read_to_string(content_root.join("DISC_INFO.txt"))
+        .or_else(|_| std::fs::read_to_string(content_root.join("disc_info").join("DISC_INFO.txt")))
+        .ok()?;
+    disc_info
+        .lines()
+        .find_map(|line| line.strip_prefix("Disc-ID: ").map(|id| id.trim().to_string()))
+        .filter(|id| !id.is_empty())
+}
+
+/// Run `f` on the disc in `source`: a directory is taken to be a mounted
+/// disc, anything else a device that is mounted for the duration of the call.
+pub fn with_disc_mounted<T>(source: &str, f: impl FnOnce(&Path) -> Result<T>) -> Result<T> {
+    let path = Path::new(source);
+    if path.is_dir() {
+        return f(path);
+    }
+    let mounted = mount_device(source, &get_temporary_mountpoint()?, false)?;
+    let result = f(&mounted.path);
+    if let Err(e) = unmount_device(&mounted, false) {
+        warn!("Failed to unmount {}: {}", mounted.path.display(), e);
+    }
+    result
+}
+
+/// Verify all discs in a multi-disc set, in sequence order.
+///
+/// Discs already mounted under `mount_base_path` (or `/media` and `/mnt`) are
+/// checked directly. Otherwise `prompt(sequence, total, disc_id, problem)` is
+/// called so the user can insert the disc into `device`; it returns `false` to
+/// skip the disc, which is then reported as missing. After the prompt the drive
+/// is polled until the disc is readable. Sequence numbers with no disc record
+/// at all are reported as missing too. `on_checked(sequence, status)` reports
+/// each disc as soon as its outcome is known. Each checked disc gets a
+/// verification run, and the set as a whole a [`SetVerificationRun`]. Sets
+/// planned by a dry run are refused.
+///
+/// [`SetVerificationRun`]: crate::database::SetVerificationRun
+pub fn verify_multi_disc_set<F, C>(
+    conn: &rusqlite::Connection,
     set_id: &str,
+    device: &str,
     mount_base_path: Option<&Path>,
     dry_run: bool,
-) -> Result<MultiDiscVerificationResult> {
+    mut prompt: F,
+    mut on_checked: C,
+) -> Result<MultiDiscVerificationResult>
+where
+    F: FnMut(u32, u32, &str, Option<&str>) -> bool,
+    C: FnMut(u32, &DiscVerificationStatus),
+{
     info!("Starting multi-disc verification for set: {}", set_id);
 
-    // Get database connection
-    let db_path = dirs::data_dir()
-        .unwrap_or_default()
-        .join("bdarchive")
-        .join("database.db");
-
-    let conn = crate::database::init_database(&db_path)
-        .context("Failed to initialize database")?;
-
     // Get disc set information
-    let disc_set = crate::database::DiscSet::get(&conn, set_id)
+    let disc_set = crate::database::DiscSet::get(conn, set_id)
         .context("Failed to load disc set")?
         .ok_or_else(|| anyhow::anyhow!("Disc set not found: {}", set_id))?;
 
     // Get all discs in the set
-    let discs = crate::database::DiscSet::get_discs(&conn, set_id)
+    let discs = crate::database::DiscSet::get_discs(conn, set_id)
         .context("Failed to load discs in set")?;
+    if discs.iter().any(|d| d.planned) {
+        anyhow::bail!("Set '{}' was planned by a dry run; it has no physical discs to verify", disc_set.name);
+    }
 
-    let total_discs = discs.len() as u32;
-    let mut disc_results = Vec::new();
-    let mut discs_verified = 0;
-    let mut discs_failed = 0;
-    let mut discs_missing = 0;
-    let mut total_files_checked = 0;
-    let mut total_files_failed = 0;
+    let total_discs = disc_set.disc_count.max(discs.len() as u32);
+    let mut per_disc = Vec::new();
+    let mut missing_discs = Vec::new();
 
     info!("Verifying {} discs in set '{}'", total_discs, disc_set.name);
 
-    for disc in discs {
-        let disc_id = disc.disc_id.clone();
-        info!("Checking disc: {}", disc_id);
-
-        // Determine mount point for this disc
-        let mount_point = if let Some(base_path) = mount_base_path {
-            // If a base path is provided, look for discs in subdirectories
-            find_disc_mount_point(&disc_id, base_path)
-        } else {
-            // Try common mount points
-            find_disc_mount_point(&disc_id, Path::new("/media"))
-                .or_else(|| find_disc_mount_point(&disc_id, Path::new("/mnt")))
+    for sequence in 1..=total_discs {
+        let Some(disc) = discs.iter().find(|d| d.sequence_number == Some(sequence)) else {
+            warn!("⚠️  Set '{}' has no disc record for sequence {}", disc_set.name, sequence);
+            missing_discs.push((sequence, None));
+            on_checked(sequence, &DiscVerificationStatus::Missing);
+            continue;
         };
-
-        match mount_point {
-            Some(mount_path) => {
-                info!("Found disc {} mounted at: {}", disc_id, mount_path.display());
-
-                // Verify the disc
-                match verify_disc(&mount_path, false, dry_run) {
-                    Ok(result) => {
-                        if result.success {
-                            disc_results.push((disc_id.clone(), DiscVerificationStatus::Verified {
-                                files_checked: result.files_checked,
-                                files_failed: result.files_failed,
-                            }));
-                            discs_verified += 1;
-                            total_files_checked += result.files_checked;
-                            total_files_failed += result.files_failed;
-                            info!("✅ Disc {} verified successfully: {} files checked, {} failed",
-                                disc_id, result.files_checked, result.files_failed);
-                        } else {
-                            let error_msg = result.error_message.unwrap_or_else(|| "Verification failed".to_string());
-                            disc_results.push((disc_id.clone(), DiscVerificationStatus::Failed {
-                                error: error_msg.clone(),
-                            }));
-                            discs_failed += 1;
-                            warn!("❌ Disc {} verification failed: {}", disc_id, error_msg);
-                        }
-                    }
-                    Err(e) => {
-                        disc_results.push((disc_id.clone(), DiscVerificationStatus::Failed {
-                            error: format!("Verification error: {}", e),
-                        }));
-                        discs_failed += 1;
-                        warn!("❌ Disc {} verification error: {}", disc_id, e);
-                    }
+        let disc_id = disc.disc_id.clone();
+        info!("Checking disc {}: {}", sequence, disc_id);
+
+        let mut prompt = |sequence: u32, disc_id: &str, problem: Option<&str>| prompt(sequence, total_discs, disc_id, problem);
+        match check_set_disc(&disc_id, sequence, device, mount_base_path, dry_run, &mut prompt) {
+            Some(result) => {
+                if result.success {
+                    info!("✅ Disc {} verified successfully: {} files checked", disc_id, result.files_checked);
+                } else {
+                    warn!("❌ Disc {} verification failed: {:?}", disc_id, result.error_message);
                 }
+                record_verification_run(conn, &disc_id, device, &result);
+                on_checked(sequence, &DiscVerificationStatus::from_result(&result));
+                per_disc.push((sequence, disc_id, result));
             }
             None => {
-                disc_results.push((disc_id.clone(), DiscVerificationStatus::Missing));
-                discs_missing += 1;
-                warn!("⚠️  Disc {} not found in any mount point", disc_id);
+                warn!("⚠️  Disc {} was not available", disc_id);
+                on_checked(sequence, &DiscVerificationStatus::Missing);
+                missing_discs.push((sequence, Some(disc_id)));
             }
         }
     }
 
-    let overall_success = discs_failed == 0 && discs_missing == 0;
-    let error_message = if !overall_success {
-        let mut msg = Vec::new();
-        if discs_missing > 0 {
-            msg.push(format!("{} discs missing", discs_missing));
-        }
-        if discs_failed > 0 {
-            msg.push(format!("{} discs failed verification", discs_failed));
-        }
-        Some(msg.join(", "))
-    } else {
-        None
-    };
-
     let result = MultiDiscVerificationResult {
         set_id: set_id.to_string(),
         set_name: disc_set.name,
         total_discs,
-        discs_verified,
-        discs_failed,
-        discs_missing,
-        overall_success,
-        disc_results,
-        total_files_checked,
-        total_files_failed,
-        error_message,
+        per_disc,
+        missing_discs,
         verification_timestamp: crate::disc::format_timestamp_now(),
     };
 
-    // Store verification result in database
-    if let Err(e) = store_multi_disc_verification_result(&conn, &result) {
-        warn!("Failed to store multi-disc verification result: {}", e);
-    }
+    info!(
+        "Multi-disc verification complete: {}/{} discs verified successfully, {} missing",
+        result.discs_verified(),
+        total_discs,
+        result.missing_discs.len()
+    );
 
-    info!("Multi-disc verification complete: {}/{} discs verified successfully",
-        discs_verified, total_discs);
+    let set_run = crate::database::SetVerificationRun {
+        id: None,
+        set_id: result.set_id.clone(),
+        verified_at: result.verification_timestamp.clone(),
+        success: result.overall_success(),
+        total_discs,
+        discs_verified: result.discs_verified(),
+        discs_failed: result.discs_failed(),
+        discs_missing: result.discs_missing(),
+    };
+    if let Err(e) = crate::database::SetVerificationRun::insert(conn, &set_run) {
+        warn!("Failed to record set verification for {}: {}", set_id, e);
+    }
 
     Ok(result)
 }
 
+/// Locate one disc of a set (already mounted, or inserted on request) and verify it.
+/// Returns `None` when the user skips the disc.
+fn check_set_disc<F>(
+    disc_id: &str,
+    sequence: u32,
+    device: &str,
+    mount_base_path: Option<&Path>,
+    dry_run: bool,
+    prompt: &mut F,
+) -> Option<VerificationResult>
+where
+    F: FnMut(u32, &str, Option<&str>) -> bool,
+{
+    let already_mounted = match mount_base_path {
+        Some(base_path) => find_disc_mount_point(disc_id, base_path),
+        None => find_disc_mount_point(disc_id, Path::new("/media"))
+            .or_else(|| find_disc_mount_point(disc_id, Path::new("/mnt"))),
+    };
+    if let Some(mount_path) = already_mounted {
+        info!("Found disc {} mounted at: {}", disc_id, mount_path.display());
+        return Some(verify_or_failure(&mount_path, dry_run));
+    }
+
+    let mut problem: Option<String> = None;
+    loop {
+        if !prompt(sequence, disc_id, problem.as_deref()) {
+            return None;
+        }
+
+        let mountpoint = match get_temporary_mountpoint() {
+            Ok(path) => path,
+            Err(e) => {
+                problem = Some(format!("Cannot create mountpoint: {}", e));
+                continue;
+            }
+        };
+        let mounted = match mount_when_readable(device, &mountpoint, dry_run, DISC_READY_TIMEOUT) {
+            Ok(mounted) => mounted,
+            Err(e) => {
+                problem = Some(format!("{:#}", e));
+                continue;
+            }
+        };
+
+        let found = find_disc_mount_point(disc_id, &mounted.path);
+        let result = found.as_deref().map(|path| verify_or_failure(path, dry_run));
+        if let Err(e) = unmount_device(&mounted, dry_run) {
+            warn!("Failed to unmount {}: {}", mounted.path.display(), e);
+        }
+
+        match result {
+            Some(result) => return Some(result),
+            None => problem = Some(format!("The disc in {} is not {}", device, disc_id)),
+        }
+    }
+}
+
+/// How long to wait for an inserted disc to become readable
+const DISC_READY_TIMEOUT: Duration = Duration::from_secs(90);
+/// Pause between attempts to read a newly inserted disc
+const DISC_READY_POLL: Duration = Duration::from_secs(3);
+
+/// Mount `device` once the drive has read the inserted disc. Drives need a
+/// while to spin up and read the table of contents, so mounting is retried
+/// until the disc's checksum list is visible or `timeout` passes.
+fn mount_when_readable(
+    device: &str,
+    mountpoint: &Path,
+    dry_run: bool,
+    timeout: Duration,
+) -> Result<MountedDisc> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let last_error = match mount_device(device, mountpoint, dry_run) {
+            Ok(mounted) if dry_run || is_readable_archive(&mounted.path) => {
+                return Ok(mounted)
+            }
+            Ok(mounted) => {
+                if let Err(e) = unmount_device(&mounted, dry_run) {
+                    warn!("Failed to unmount {}: {}", mounted.path.display(), e);
+                }
+                "no checksum list on the disc".to_string()
+            }
+            Err(e) => format!("{:#}", e),
+        };
+        if Instant::now() >= deadline {
+            anyhow::bail!(
+                "No readable archive disc in {} after {}s ({})",
+                device,
+                timeout.as_secs(),
+                last_error
+            );
+        }
+        debug!("Disc in {} not readable yet: {}", device, last_error);
+        std::thread::sleep(DISC_READY_POLL);
+    }
+}
+
+/// Whether the disc at `root` shows the checksum list every archive disc
+/// carries (SHA256SUMS.txt, or CRC32SUMS.txt).
+pub fn is_readable_archive(root: &Path) -> bool {
+    find_checksum_list(&crate::bagit::content_root(root)).is_some()
+}
+
+fn verify_or_failure(mount_path: &Path, dry_run: bool) -> VerificationResult {
+    verify_disc(mount_path, false, dry_run).unwrap_or_else(|e| VerificationResult {
+        success: false,
+        files_checked: 0,
+        files_failed: 0,
+        error_message: Some(format!("Verification error: {}", e)),
+        warnings: Vec::new(),
+    })
+}
+
 /// Find mount point for a specific disc
 fn find_disc_mount_point(disc_id: &str, search_path: &Path) -> Option<PathBuf> {
     if !search_path.exists() {
@@ -218,12 +950,13 @@ fn find_disc_mount_point(disc_id: &str, search_path: &Path) -> Option<PathBuf> {
     {
         let path = entry.path();
 
-        // Check if this looks like our disc (has DISC_INFO.txt or matches volume label)
-        let disc_info_path = path.join("DISC_INFO.txt");
-        if disc_info_path.exists() {
+        // Check if this looks like our disc (has DISC_INFO.txt or matches volume label);
+        // set discs keep DISC_INFO.txt in disc_info/
+        let content_root = crate::bagit::content_root(path);
+        for disc_info_path in [content_root.join("DISC_INFO.txt"), content_root.join("disc_info").join("DISC_INFO.txt")] {
             // Try to read the disc info to see if it matches
             if let Ok(content) = std::fs::read_to_string(&disc_info_path) {
-                if content.contains(&format!("Disc-ID: {}", disc_id)) {
+                if content.lines().any(|line| line.strip_prefix("Disc-ID: ").map(str::trim) == Some(disc_id)) {
                     return Some(path.to_path_buf());
                 }
             }
@@ -232,8 +965,8 @@ fn find_disc_mount_point(disc_id: &str, search_path: &Path) -> Option<PathBuf> {
         // Also check by volume label in the path name
         if let Some(dir_name) = path.file_name().and_then(|n| n.to_str()) {
             if dir_name.contains(disc_id) {
-                // Additional check: look for SHA256SUMS.txt to confirm it's a valid disc
-                if path.join("SHA256SUMS.txt").exists() {
+                // Additional check: look for the checksum list to confirm it's a valid disc
+                if is_readable_archive(path) {
                     return Some(path.to_path_buf());
                 }
             }
@@ -243,28 +976,23 @@ fn find_disc_mount_point(disc_id: &str, search_path: &Path) -> Option<PathBuf> {
     None
 }
 
-/// Store multi-disc verification result in database
-fn store_multi_disc_verification_result(
-    conn: &rusqlite::Connection,
-    result: &MultiDiscVerificationResult,
-) -> Result<()> {
-    use rusqlite::params;
-
-    // Store in a simple verification_runs table (we'll need to add this to schema)
-    // For now, we'll just log it. In a full implementation, we'd add a proper table.
-
-    // Create a summary for logging
-    let summary = format!(
-        "Multi-disc verification: {}/{} discs verified, {} files checked, {} files failed",
-        result.discs_verified, result.total_discs, result.total_files_checked, result.total_files_failed
-    );
-
-    info!("Stored verification result: {}", summary);
-
-    // TODO: Add proper database storage for multi-disc verification results
-    // This would require extending the database schema
-
-    Ok(())
+/// Record a verification run for one disc of a set.
+fn record_verification_run(conn: &rusqlite::Connection, disc_id: &str, device: &str, result: &VerificationResult) {
+    let run = crate::database::VerificationRun {
+        id: None,
+        disc_id: disc_id.to_string(),
+        verified_at: crate::disc::format_timestamp_now(),
+        mountpoint: None,
+        device: Some(device.to_string()),
+        success: result.success,
+        error_message: result.error_message.clone(),
+        files_checked: Some(result.files_checked),
+        files_failed: Some(result.files_failed),
+        sampled: false,
+    };
+    if let Err(e) = crate::database::VerificationRun::insert(conn, &run) {
+        warn!("Failed to record verification run for {}: {}", disc_id, e);
+    }
 }
 
 /// Parse sha256sum -c output to count files.
@@ -294,8 +1022,35 @@ fn parse_sha256sum_output(stdout: &str, stderr: &str) -> (u32, u32) {
     (checked, failed)
 }
 
-/// Mount a device to a mountpoint.
-pub fn mount_device(device: &str, mountpoint: &Path, dry_run: bool) -> Result<()> {
+/// How a disc or image was mounted, so it is unmounted the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MountMethod {
+    /// `udisksctl`, which works for ordinary desktop users
+    Udisks,
+    /// Plain `mount` (root, or an fstab `user` entry)
+    Mount,
+    /// `sudo -n mount`, for users with passwordless sudo
+    Sudo,
+    /// Mounted before BlueVault looked (by the user or the desktop);
+    /// unmounting leaves it alone
+    Existing,
+}
+
+/// A mounted disc or image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MountedDisc {
+    /// Where the filesystem is mounted. udisks picks its own location
+    /// (usually under /run/media), so this may differ from the requested mountpoint.
+    pub path: PathBuf,
+    /// Block device that was mounted
+    pub device: String,
+    pub method: MountMethod,
+    /// Loop device set up for an image, released on unmount
+    pub loop_device: Option<String>,
+}
+
+/// Mount a device, preferring udisksctl and falling back to `mount` and then `sudo -n mount`.
+pub fn mount_device(device: &str, mountpoint: &Path, dry_run: bool) -> Result<MountedDisc> {
     info!("Mounting device {} to {}", device, mountpoint.display());
 
     if dry_run {
@@ -304,47 +1059,247 @@ pub fn mount_device(device: &str, mountpoint: &Path, dry_run: bool) -> Result<()
             device,
             mountpoint.display()
         );
-        return Ok(());
+        return Ok(MountedDisc {
+            path: mountpoint.to_path_buf(),
+            device: device.to_string(),
+            method: MountMethod::Mount,
+            loop_device: None,
+        });
     }
 
-    // Ensure mountpoint exists
-    std::fs::create_dir_all(mountpoint)?;
+    if let Some(path) = existing_mount(device) {
+        info!("{} is already mounted at {}; using it as is", device, path.display());
+        return Ok(MountedDisc {
+            path,
+            device: device.to_string(),
+            method: MountMethod::Existing,
+            loop_device: None,
+        });
+    }
 
-    let mountpoint_str = mountpoint.to_string_lossy().to_string();
-    let args = vec![device, &mountpoint_str];
+    let mut failures = Vec::new();
+
+    if crate::dependencies::get_optional_command("udisksctl").is_some() {
+        match udisks_mount(device) {
+            Ok((path, already_mounted)) => {
+                debug!("Device mounted by udisks at {}", path.display());
+                return Ok(MountedDisc {
+                    path,
+                    device: device.to_string(),
+                    method: if already_mounted { MountMethod::Existing } else { MountMethod::Udisks },
+                    loop_device: None,
+                });
+            }
+            Err(e) => failures.push(format!("udisksctl: {:#}", e)),
+        }
+    }
+
+    let method = mount_with_fallback(device, mountpoint, &[], &mut failures)?;
+    Ok(MountedDisc {
+        path: mountpoint.to_path_buf(),
+        device: device.to_string(),
+        method,
+        loop_device: None,
+    })
+}
 
-    let output = commands::execute_command("mount", &args, dry_run)?;
+/// Mount an ISO image read-only, via `udisksctl loop-setup` when available.
+pub fn mount_image(image: &Path, mountpoint: &Path, dry_run: bool) -> Result<MountedDisc> {
+    info!("Mounting image {} to {}", image.display(), mountpoint.display());
+    let image_str = image.to_string_lossy().to_string();
 
-    if !output.success {
-        anyhow::bail!("mount failed: {}", output.stderr);
+    if dry_run {
+        debug!(
+            "[DRY RUN] Would mount {} to {}",
+            image.display(),
+            mountpoint.display()
+        );
+        return Ok(MountedDisc {
+            path: mountpoint.to_path_buf(),
+            device: image_str,
+            method: MountMethod::Mount,
+            loop_device: None,
+        });
     }
 
-    debug!("Device mounted successfully");
-    Ok(())
+    let mut failures = Vec::new();
+
+    if crate::dependencies::get_optional_command("udisksctl").is_some() {
+        match udisks_loop_setup(&image_str) {
+            Ok(loop_device) => match udisks_mount(&loop_device) {
+                Ok((path, _)) => {
+                    return Ok(MountedDisc {
+                        path,
+                        device: loop_device.clone(),
+                        method: MountMethod::Udisks,
+                        loop_device: Some(loop_device),
+                    });
+                }
+                Err(e) => {
+                    failures.push(format!("udisksctl: {:#}", e));
+                    udisks_loop_delete(&loop_device);
+                }
+            },
+            Err(e) => failures.push(format!("udisksctl: {:#}", e)),
+        }
+    }
+
+    let method = mount_with_fallback(&image_str, mountpoint, &["-o", "loop,ro"], &mut failures)?;
+    Ok(MountedDisc {
+        path: mountpoint.to_path_buf(),
+        device: image_str,
+        method,
+        loop_device: None,
+    })
+}
+
+/// Try `mount`, then `sudo -n mount`. Bails with every failure and a hint when neither works.
+fn mount_with_fallback(
+    source: &str,
+    mountpoint: &Path,
+    options: &[&str],
+    failures: &mut Vec<String>,
+) -> Result<MountMethod> {
+    std::fs::create_dir_all(mountpoint)?;
+    let mountpoint_str = mountpoint.to_string_lossy().to_string();
+    let mut args: Vec<&str> = options.to_vec();
+    args.push(source);
+    args.push(&mountpoint_str);
+
+    match commands::execute_command("mount", &args, false) {
+        Ok(output) if output.success => return Ok(MountMethod::Mount),
+        Ok(output) => failures.push(format!("mount: {}", output.stderr.trim())),
+        Err(e) => failures.push(format!("mount: {:#}", e)),
+    }
+
+    if crate::dependencies::get_optional_command("sudo").is_some() {
+        let mut sudo_args = vec!["-n", "mount"];
+        sudo_args.extend_from_slice(&args);
+        match commands::execute_command("sudo", &sudo_args, false) {
+            Ok(output) if output.success => return Ok(MountMethod::Sudo),
+            Ok(output) => failures.push(format!("sudo: {}", output.stderr.trim())),
+            Err(e) => failures.push(format!("sudo: {:#}", e)),
+        }
+    }
+
+    anyhow::bail!(
+        "Could not mount {} ({}). Install udisks2 to mount as an ordinary user, \
+         or run as a user in the 'disk' group or with passwordless sudo for mount.",
+        source,
+        failures.join("; ")
+    )
 }
 
-/// Unmount a mountpoint.
-pub fn unmount_device(mountpoint: &Path, dry_run: bool) -> Result<()> {
-    info!("Unmounting: {}", mountpoint.display());
+/// Unmount a disc or image with the backend that mounted it.
+pub fn unmount_device(mounted: &MountedDisc, dry_run: bool) -> Result<()> {
+    if mounted.method == MountMethod::Existing {
+        debug!("Leaving {} mounted: it was mounted before we looked", mounted.path.display());
+        return Ok(());
+    }
+    info!("Unmounting: {}", mounted.path.display());
 
     if dry_run {
-        debug!("[DRY RUN] Would unmount: {}", mountpoint.display());
+        debug!("[DRY RUN] Would unmount: {}", mounted.path.display());
         return Ok(());
     }
 
-    let mountpoint_str = mountpoint.to_string_lossy().to_string();
-    let args: &[&str] = &[&mountpoint_str];
+    let mountpoint_str = mounted.path.to_string_lossy().to_string();
+    let (program, args): (&str, Vec<&str>) = match mounted.method {
+        MountMethod::Udisks => (
+            "udisksctl",
+            vec!["unmount", "-b", &mounted.device, "--no-user-interaction"],
+        ),
+        MountMethod::Mount => ("umount", vec![&mountpoint_str]),
+        MountMethod::Sudo => ("sudo", vec!["-n", "umount", &mountpoint_str]),
+        MountMethod::Existing => return Ok(()),
+    };
 
-    let output = commands::execute_command("umount", args, dry_run)?;
+    let output = commands::execute_command(program, &args, dry_run)?;
 
     if !output.success {
-        anyhow::bail!("umount failed: {}", output.stderr);
+        anyhow::bail!("{} failed: {}", program, output.stderr);
+    }
+
+    if let Some(loop_device) = &mounted.loop_device {
+        udisks_loop_delete(loop_device);
     }
 
     debug!("Device unmounted successfully");
     Ok(())
 }
 
+/// Mount a block device with udisksctl and return where it is mounted, and
+/// whether it already was.
+fn udisks_mount(device: &str) -> Result<(PathBuf, bool)> {
+    let output = commands::execute_command(
+        "udisksctl",
+        &["mount", "-b", device, "--no-user-interaction"],
+        false,
+    )?;
+    // A device that is already mounted is fine; udisks names the location in the error
+    let text = if output.success { &output.stdout } else { &output.stderr };
+    match parse_udisks_mount_path(text) {
+        Some(path) => Ok((path, !output.success)),
+        None => anyhow::bail!("{}", output.stderr.trim()),
+    }
+}
+
+/// Set up a read-only loop device for an image and return its name.
+fn udisks_loop_setup(image: &str) -> Result<String> {
+    let output = commands::execute_command(
+        "udisksctl",
+        &["loop-setup", "-r", "-f", image, "--no-user-interaction"],
+        false,
+    )?;
+    if !output.success {
+        anyhow::bail!("{}", output.stderr.trim());
+    }
+    parse_udisks_loop_device(&output.stdout)
+        .with_context(|| format!("Unexpected udisksctl output: {}", output.stdout.trim()))
+}
+
+fn udisks_loop_delete(loop_device: &str) {
+    // udisks usually releases the loop device itself once it is unmounted
+    if let Ok(output) = commands::execute_command(
+        "udisksctl",
+        &["loop-delete", "-b", loop_device, "--no-user-interaction"],
+        false,
+    ) {
+        if !output.success {
+            debug!("loop-delete {}: {}", loop_device, output.stderr.trim());
+        }
+    }
+}
+
+/// Parse the mount path from `udisksctl mount` output, either
+/// "Mounted /dev/sr0 at /run/media/user/LABEL" or the already-mounted
+/// error "... is already mounted at `/run/media/user/LABEL'."
+fn parse_udisks_mount_path(output: &str) -> Option<PathBuf> {
+    if let Some(start) = output.find("already mounted at `") {
+        let rest = &output[start + "already mounted at `".len()..];
+        let end = rest.find('\'')?;
+        return Some(PathBuf::from(&rest[..end]));
+    }
+    let line = output.lines().find(|line| line.starts_with("Mounted "))?;
+    let (_, path) = line.split_once(" at ")?;
+    let path = path.trim();
+    Some(PathBuf::from(path.strip_suffix('.').unwrap_or(path)))
+}
+
+/// Parse the loop device from "Mapped file /path/disc.iso as /dev/loop0."
+fn parse_udisks_loop_device(output: &str) -> Option<String> {
+    let line = output.lines().find(|line| line.starts_with("Mapped file "))?;
+    let (_, device) = line.rsplit_once(" as ")?;
+    let device = device.trim();
+    Some(device.strip_suffix('.').unwrap_or(device).to_string())
+}
+
+/// Where `device` is mounted already, per /proc/mounts.
+fn existing_mount(device: &str) -> Option<PathBuf> {
+    let mounts = std::fs::read_to_string("/proc/mounts").ok()?;
+    crate::burn::find_mount_point(&mounts, Path::new(device)).map(PathBuf::from)
+}
+
 /// Find a suitable mountpoint for temporary mounting.
 pub fn get_temporary_mountpoint() -> Result<PathBuf> {
     use std::env;
@@ -373,13 +1328,15 @@ pub struct VerificationResult {
     pub files_checked: u32,
     pub files_failed: u32,
     pub error_message: Option<String>,
+    /// Problems that do not fail verification, such as files missing from SHA256SUMS.txt.
+    pub warnings: Vec<String>,
 }
 
 /// Status of individual disc in multi-disc verification
 #[derive(Debug, Clone, PartialEq)]
 pub enum DiscVerificationStatus {
     /// Disc is present and verified successfully
-    Verified { files_checked: u32, files_failed: u32 },
+    Verified { files_checked: u32, files_failed: u32, warnings: u32 },
     /// Disc is present but verification failed
     Failed { error: String },
     /// Disc is missing/not available
@@ -394,21 +1351,203 @@ pub struct MultiDiscVerificationResult {
     pub set_id: String,
     pub set_name: String,
     pub total_discs: u32,
-    pub discs_verified: u32,
-    pub discs_failed: u32,
-    pub discs_missing: u32,
-    pub overall_success: bool,
-    pub disc_results: Vec<(String, DiscVerificationStatus)>, // (disc_id, status)
-    pub total_files_checked: u32,
-    pub total_files_failed: u32,
-    pub error_message: Option<String>,
+    /// Discs that were read: (sequence number, disc_id, result)
+    pub per_disc: Vec<(u32, String, VerificationResult)>,
+    /// Discs that were not available: (sequence number, disc_id if the set has a record for it)
+    pub missing_discs: Vec<(u32, Option<String>)>,
     pub verification_timestamp: String,
 }
 
+impl DiscVerificationStatus {
+    /// Status of a disc that was read.
+    pub fn from_result(result: &VerificationResult) -> Self {
+        if result.success {
+            DiscVerificationStatus::Verified {
+                files_checked: result.files_checked,
+                files_failed: result.files_failed,
+                warnings: result.warnings.len() as u32,
+            }
+        } else {
+            DiscVerificationStatus::Failed {
+                error: result
+                    .error_message
+                    .clone()
+                    .unwrap_or_else(|| "Verification failed".to_string()),
+            }
+        }
+    }
+}
+
+impl MultiDiscVerificationResult {
+    pub fn discs_verified(&self) -> u32 {
+        self.per_disc.iter().filter(|(_, _, r)| r.success).count() as u32
+    }
+
+    pub fn discs_failed(&self) -> u32 {
+        self.per_disc.iter().filter(|(_, _, r)| !r.success).count() as u32
+    }
+
+    pub fn discs_missing(&self) -> u32 {
+        self.missing_discs.len() as u32
+    }
+
+    pub fn overall_success(&self) -> bool {
+        self.discs_failed() == 0 && self.missing_discs.is_empty()
+    }
+
+    pub fn total_files_checked(&self) -> u32 {
+        self.per_disc.iter().map(|(_, _, r)| r.files_checked).sum()
+    }
+
+    pub fn total_files_failed(&self) -> u32 {
+        self.per_disc.iter().map(|(_, _, r)| r.files_failed).sum()
+    }
+
+    /// One row per sequence number, in order.
+    pub fn status_rows(&self) -> Vec<(u32, String, DiscVerificationStatus)> {
+        let mut rows: Vec<(u32, String, DiscVerificationStatus)> = self
+            .per_disc
+            .iter()
+            .map(|(sequence, disc_id, result)| (*sequence, disc_id.clone(), DiscVerificationStatus::from_result(result)))
+            .chain(self.missing_discs.iter().map(|(sequence, disc_id)| {
+                (
+                    *sequence,
+                    disc_id.clone().unwrap_or_else(|| "(no record)".to_string()),
+                    DiscVerificationStatus::Missing,
+                )
+            }))
+            .collect();
+        rows.sort_by_key(|(sequence, _, _)| *sequence);
+        rows
+    }
+}
+
+/// A disc that is due for re-verification (scrub).
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaleDisc {
+    pub disc_id: String,
+    pub created_at: String,
+    /// Latest verification run, `None` if the disc was never verified
+    pub last_verified: Option<String>,
+    pub last_success: Option<bool>,
+}
+
+impl StaleDisc {
+    /// "2025-01-10 (3 months ago), OK", "..., FAILED" or "never".
+    pub fn last_verified_display(&self) -> String {
+        match (&self.last_verified, self.last_success) {
+            (Some(at), Some(true)) => format!("{}, OK", crate::disc::format_timestamp_relative(at)),
+            (Some(at), _) => format!("{}, FAILED", crate::disc::format_timestamp_relative(at)),
+            (None, _) => "never".to_string(),
+        }
+    }
+}
+
+/// Find discs whose latest full verification is older than `older_than_days`
+/// (or that were never verified), oldest first. Sample checks don't count,
+/// and planned discs from dry runs are left out.
+pub fn find_stale_discs(conn: &rusqlite::Connection, older_than_days: u64) -> Result<Vec<StaleDisc>> {
+    let cutoff = crate::disc::format_timestamp_days_ago(older_than_days);
+
+    // SQLite returns the `success` of the row holding MAX(verified_at)
+    let mut stmt = conn.prepare(
+        "SELECT d.disc_id, d.created_at, latest.verified_at, latest.success
+         FROM discs d
+         LEFT JOIN (
+             SELECT disc_id, MAX(verified_at) AS verified_at, success
+             FROM verification_runs
+             WHERE sampled = 0
+             GROUP BY disc_id
+         ) latest ON latest.disc_id = d.disc_id
+         WHERE d.planned = 0 AND (latest.verified_at IS NULL OR latest.verified_at < ?1)
+         ORDER BY COALESCE(latest.verified_at, ''), d.created_at",
+    )?;
+
+    let discs = stmt
+        .query_map([&cutoff], |row| {
+            Ok(StaleDisc {
+                disc_id: row.get(0)?,
+                created_at: row.get(1)?,
+                last_verified: row.get(2)?,
+                last_success: row.get::<_, Option<i64>>(3)?.map(|s| s != 0),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    debug!("Found {} discs not verified since {}", discs.len(), cutoff);
+    Ok(discs)
+}
+
+/// Write the scrub worklist as CSV so it can be tracked across sessions.
+pub fn write_scrub_csv(discs: &[StaleDisc], path: &Path) -> Result<()> {
+    fn field(value: &str) -> String {
+        if value.contains([',', '"', '\n']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    let mut csv = String::from("disc_id,created_at,last_verified,last_result\n");
+    for disc in discs {
+        let result = match disc.last_success {
+            Some(true) => "ok",
+            Some(false) => "failed",
+            None => "never",
+        };
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            field(&disc.disc_id),
+            field(&disc.created_at),
+            field(disc.last_verified.as_deref().unwrap_or("")),
+            result
+        ));
+    }
+
+    std::fs::write(path, csv)
+        .with_context(|| format!("Failed to write scrub worklist: {}", path.display()))?;
+    info!("Wrote scrub worklist ({} discs) to {}", discs.len(), path.display());
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_udisks_output() {
+        assert_eq!(
+            parse_udisks_mount_path("Mounted /dev/sr0 at /run/media/alex/BDARCHIVE_2024.\n"),
+            Some(PathBuf::from("/run/media/alex/BDARCHIVE_2024"))
+        );
+        assert_eq!(
+            parse_udisks_mount_path(
+                "Error mounting /dev/sr0: GDBus.Error:org.freedesktop.UDisks2.Error.AlreadyMounted: \
+                 Device /dev/sr0 is already mounted at `/run/media/alex/BDARCHIVE_2024'.\n"
+            ),
+            Some(PathBuf::from("/run/media/alex/BDARCHIVE_2024"))
+        );
+        assert_eq!(parse_udisks_mount_path("Error mounting /dev/sr0: Not authorized\n"), None);
+        assert_eq!(
+            parse_udisks_loop_device("Mapped file /tmp/disc.iso as /dev/loop3.\n"),
+            Some("/dev/loop3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_existing_mounts_are_left_alone() -> Result<()> {
+        // No umount is run, so a missing mountpoint is no error
+        let existing = MountedDisc {
+            path: PathBuf::from("/nonexistent/bluevault-user-mount"),
+            device: "/dev/nonexistent".to_string(),
+            method: MountMethod::Existing,
+            loop_device: None,
+        };
+        unmount_device(&existing, false)?;
+        assert_eq!(existing_mount("/dev/nonexistent"), None);
+        Ok(())
+    }
+
     #[test]
     fn test_parse_sha256sum_output() {
         let stdout = "file1.txt: OK\nfile2.txt: OK\n";
@@ -426,4 +1565,459 @@ mod tests {
         assert_eq!(checked, 2);
         assert_eq!(failed, 1);
     }
+
+    #[test]
+    fn test_parse_sha256sums_path() {
+        let hash = "a".repeat(64);
+        assert_eq!(parse_sha256sums_path(&format!("{}  ARCHIVE/a b.txt", hash)), Some("ARCHIVE/a b.txt"));
+        assert_eq!(parse_sha256sums_path(&format!("{} *ARCHIVE/bin.dat", hash)), Some("ARCHIVE/bin.dat"));
+        assert_eq!(parse_sha256sums_path(""), None);
+        assert_eq!(parse_sha256sums_path("not a checksum line"), None);
+    }
+
+    #[test]
+    fn test_catalog_diff() -> Result<()> {
+        use crate::database::{init_database, FileRecord};
+
+        let temp_dir = tempfile::TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('D1', 'D1', '2024-01-01T00:00:00Z')",
+            [],
+        )?;
+        let record = |path: &str, size: u64| FileRecord {
+            id: None,
+            disc_id: "D1".to_string(),
+            rel_path: path.to_string(),
+            sha256: "00".to_string(),
+            size,
+            mtime: String::new(),
+            added_at: String::new(),
+            crc32: None,
+        };
+        for file in [
+            record("DISC_INFO.txt", 5),
+            record("ARCHIVE/photos/a.jpg", 3),
+            record("ARCHIVE/photos/b.jpg", 3),
+            record("ARCHIVE/photos/gone.jpg", 3),
+        ] {
+            FileRecord::insert(&conn, &file)?;
+        }
+
+        let disc = temp_dir.path().join("disc");
+        std::fs::create_dir_all(disc.join("ARCHIVE/photos"))?;
+        std::fs::write(disc.join("DISC_INFO.txt"), "12345")?;
+        std::fs::write(disc.join("SHA256SUMS.txt"), "")?;
+        std::fs::write(disc.join("ARCHIVE/photos/a.jpg"), "abc")?;
+        std::fs::write(disc.join("ARCHIVE/photos/b.jpg"), "abcdef")?;
+        std::fs::write(disc.join("ARCHIVE/photos/new.jpg"), "new")?;
+
+        let diff = catalog_diff(&conn, "D1", &disc)?;
+        assert_eq!(diff.matched, 2);
+        assert_eq!(diff.only_on_disc, vec![PathBuf::from("ARCHIVE/photos/new.jpg")]);
+        assert_eq!(diff.only_in_db, vec![PathBuf::from("ARCHIVE/photos/gone.jpg")]);
+        assert_eq!(
+            diff.checksum_mismatches,
+            vec![SizeMismatch { path: PathBuf::from("ARCHIVE/photos/b.jpg"), catalog_size: 3, disc_size: 6 }]
+        );
+        assert!(!diff.is_clean());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_bagged_disc() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let disc = temp_dir.path();
+        std::fs::create_dir_all(disc.join("ARCHIVE/docs"))?;
+        std::fs::write(disc.join("ARCHIVE/docs/report.pdf"), "pdf")?;
+        std::fs::write(disc.join("DISC_INFO.txt"), "Disc-ID: BAG-1\n")?;
+        let files = crate::manifest::generate_manifest_and_sums(disc, None)?;
+        crate::manifest::write_sha256sums_file(&disc.join("SHA256SUMS.txt"), &files)?;
+        let info = crate::bagit::BagInfo { external_identifier: "BAG-1".to_string(), description: None };
+        crate::bagit::make_bag(disc, &files, &info)?;
+
+        let result = verify_disc(disc, false, false)?;
+        assert!(result.success, "{:?}", result.error_message);
+        assert_eq!(result.files_checked, 3);
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+
+        std::fs::write(disc.join("data/ARCHIVE/docs/report.pdf"), "PDF")?;
+        let result = verify_disc(disc, false, false)?;
+        assert!(!result.success);
+        assert_eq!(result.files_failed, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_manifest_coverage_compares_normalized_paths() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let disc = temp_dir.path();
+        // An older disc whose names were burned decomposed (NFD)
+        let folder = disc.join("ARCHIVE").join("Cafe\u{301}");
+        std::fs::create_dir_all(&folder)?;
+        std::fs::write(folder.join("Ne\u{301}e.txt"), "a")?;
+        std::fs::write(
+            disc.join("SHA256SUMS.txt"),
+            format!("{}  ARCHIVE/Caf\u{e9}/N\u{e9}e.txt\n", "0".repeat(64)),
+        )?;
+
+        let coverage = check_manifest_coverage(disc)?;
+        assert!(coverage.is_complete(), "{:?}", coverage.warnings());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_manifest_coverage() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let disc = temp_dir.path();
+        let archive = disc.join("ARCHIVE").join("photos");
+        std::fs::create_dir_all(&archive)?;
+        std::fs::write(archive.join("listed.jpg"), "a")?;
+        std::fs::write(archive.join("dropped.jpg"), "b")?;
+        std::fs::write(disc.join("DISC_INFO.txt"), "Disc-ID: TEST\n")?;
+
+        let hash = "0".repeat(64);
+        std::fs::write(
+            disc.join("SHA256SUMS.txt"),
+            format!(
+                "{h}  DISC_INFO.txt\n{h}  ARCHIVE/photos/listed.jpg\n{h}  ARCHIVE/photos/gone.jpg\n",
+                h = hash
+            ),
+        )?;
+
+        let coverage = check_manifest_coverage(disc)?;
+        assert!(!coverage.is_complete());
+        assert_eq!(coverage.unlisted, vec![PathBuf::from("ARCHIVE/photos/dropped.jpg")]);
+        assert_eq!(coverage.missing, vec![PathBuf::from("ARCHIVE/photos/gone.jpg")]);
+
+        let warnings = coverage.warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].starts_with("1 file(s) on disc are not listed"));
+        assert!(warnings[0].contains("dropped.jpg"));
+
+        // A complete manifest produces no warnings
+        std::fs::remove_file(archive.join("dropped.jpg"))?;
+        std::fs::write(
+            disc.join("SHA256SUMS.txt"),
+            format!("{}  ARCHIVE/photos/listed.jpg\n", hash),
+        )?;
+        let coverage = check_manifest_coverage(disc)?;
+        assert!(coverage.is_complete());
+        assert!(coverage.warnings().is_empty());
+
+        // manifest.json takes precedence over SHA256SUMS.txt when present
+        let entry = |path: &str| crate::manifest::FileMetadata {
+            rel_path: PathBuf::from(path),
+            size: 1,
+            mtime: "2024-01-01T00:00:00Z".to_string(),
+            sha256: String::new(),
+            crc32: Some("00000000".to_string()),
+            link_target: None,
+            duplicate_of: None,
+        };
+        let manifest = crate::manifest::DiscManifest::new(
+            crate::manifest::DiscManifestInfo {
+                disc_id: "TEST".to_string(),
+                volume_label: "TEST".to_string(),
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+                tool_version: "test".to_string(),
+                notes: None,
+                source_roots: Vec::new(),
+                set_id: None,
+                sequence_number: None,
+                total_discs: None,
+            },
+            vec![entry("ARCHIVE/photos/listed.jpg"), entry("ARCHIVE/photos/only-in-json.jpg")],
+        );
+        crate::manifest::write_manifest_json(&disc.join(crate::manifest::MANIFEST_JSON_FILE), &manifest)?;
+        let coverage = check_manifest_coverage(disc)?;
+        assert_eq!(coverage.missing, vec![PathBuf::from("ARCHIVE/photos/only-in-json.jpg")]);
+
+        // Deduplicated copies are covered by their stored original
+        let duplicate = |path: &str, original: &str| crate::manifest::FileMetadata {
+            duplicate_of: Some(PathBuf::from(original)),
+            ..entry(path)
+        };
+        let manifest = crate::manifest::DiscManifest::new(
+            manifest.disc.clone(),
+            vec![
+                entry("ARCHIVE/photos/listed.jpg"),
+                duplicate("ARCHIVE/photos/copy.jpg", "ARCHIVE/photos/listed.jpg"),
+                duplicate("ARCHIVE/photos/orphan.jpg", "ARCHIVE/photos/gone.jpg"),
+            ],
+        );
+        crate::manifest::write_manifest_json(&disc.join(crate::manifest::MANIFEST_JSON_FILE), &manifest)?;
+        let coverage = check_manifest_coverage(disc)?;
+        assert!(coverage.unlisted.is_empty());
+        assert_eq!(coverage.missing, vec![PathBuf::from("ARCHIVE/photos/orphan.jpg")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_multi_disc_set_flags_missing_discs() -> Result<()> {
+        use crate::database::{init_database, Disc, MultiDiscOps};
+
+        let temp_dir = tempfile::TempDir::new()?;
+        let mut conn = init_database(&temp_dir.path().join("test.db"))?;
+        let set_id = MultiDiscOps::create_disc_set(&mut conn, "SET", None, 0, 3, None)?;
+        for sequence in 1..=2u32 {
+            let mut disc = Disc {
+                disc_id: format!("SET-{}", sequence),
+                volume_label: format!("SET_{}", sequence),
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+                notes: None,
+                iso_size: None,
+                burn_device: None,
+                checksum_manifest_hash: None,
+                qr_path: None,
+                source_roots: None,
+                tool_version: None,
+                set_id: None,
+                sequence_number: None,
+                burn_duration_secs: None,
+                avg_write_speed_mbps: None,
+                is_mdisc: false,
+                storage_location: None,
+                planned: false,
+            };
+            MultiDiscOps::add_disc_to_set(&mut conn, &mut disc, &set_id, sequence)?;
+        }
+
+        // Only disc 1 is "mounted", laid out as a set disc; disc 3 has no record at all
+        let mounts = temp_dir.path().join("mounts");
+        let disc1 = mounts.join("SET_1");
+        std::fs::create_dir_all(disc1.join("disc_info"))?;
+        std::fs::write(disc1.join("disc_info/DISC_INFO.txt"), "Disc-ID: SET-1\n")?;
+        std::fs::write(disc1.join("SHA256SUMS.txt"), "")?;
+
+        let mut prompted = Vec::new();
+        let mut checked = Vec::new();
+        let result = verify_multi_disc_set(
+            &conn,
+            &set_id,
+            "/dev/null",
+            Some(&mounts),
+            true,
+            |sequence, total, disc_id, _| {
+                prompted.push((sequence, total, disc_id.to_string()));
+                false
+            },
+            |sequence, status| checked.push((sequence, status.clone())),
+        )?;
+
+        assert_eq!(prompted, vec![(2, 3, "SET-2".to_string())]);
+        assert_eq!(checked.iter().map(|(sequence, _)| *sequence).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(checked[2].1, DiscVerificationStatus::Missing);
+        assert_eq!(result.total_discs, 3);
+        assert_eq!(result.per_disc.len(), 1);
+        assert_eq!(result.missing_discs, vec![(2, Some("SET-2".to_string())), (3, None)]);
+        assert!(!result.overall_success());
+
+        let rows = result.status_rows();
+        assert_eq!(rows.len(), 3);
+        assert!(matches!(rows[0].2, DiscVerificationStatus::Verified { .. }));
+        assert_eq!(rows[2].2, DiscVerificationStatus::Missing);
+
+        let runs: i64 = conn.query_row("SELECT COUNT(*) FROM verification_runs WHERE disc_id = 'SET-1'", [], |r| r.get(0))?;
+        assert_eq!(runs, 1);
+
+        let set_run = crate::database::SetVerificationRun::latest_for_set(&conn, &set_id)?.expect("set run recorded");
+        assert!(!set_run.success);
+        assert_eq!((set_run.discs_verified, set_run.discs_failed, set_run.discs_missing), (1, 0, 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_stale_discs() -> Result<()> {
+        use crate::database::{init_database, VerificationRun};
+        use crate::disc::{format_timestamp_days_ago, format_timestamp_now};
+
+        let temp_dir = tempfile::TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+
+        for (disc_id, created_at) in [("OLD", "2020-01-01T00:00:00Z"), ("NEVER", "2021-01-01T00:00:00Z"), ("FRESH", "2022-01-01T00:00:00Z")] {
+            conn.execute(
+                "INSERT INTO discs (disc_id, volume_label, created_at) VALUES (?1, ?1, ?2)",
+                [disc_id, created_at],
+            )?;
+        }
+
+        let run = |disc_id: &str, verified_at: String, success: bool| VerificationRun {
+            id: None,
+            disc_id: disc_id.to_string(),
+            verified_at,
+            mountpoint: None,
+            device: None,
+            success,
+            error_message: None,
+            files_checked: None,
+            files_failed: None,
+            sampled: false,
+        };
+        VerificationRun::insert(&conn, &run("OLD", format_timestamp_days_ago(800), true))?;
+        VerificationRun::insert(&conn, &run("OLD", format_timestamp_days_ago(400), false))?;
+        VerificationRun::insert(&conn, &run("FRESH", format_timestamp_now(), true))?;
+        // A recent sample check doesn't stand in for a full verification
+        VerificationRun::insert(&conn, &VerificationRun { sampled: true, ..run("OLD", format_timestamp_now(), true) })?;
+
+        let stale = find_stale_discs(&conn, 365)?;
+        let ids: Vec<&str> = stale.iter().map(|d| d.disc_id.as_str()).collect();
+        assert_eq!(ids, vec!["NEVER", "OLD"]);
+        assert_eq!(stale[0].last_verified, None);
+        assert_eq!(stale[1].last_verified, Some(format_timestamp_days_ago(400)));
+        assert_eq!(stale[1].last_success, Some(false));
+
+        let csv_path = temp_dir.path().join("scrub.csv");
+        write_scrub_csv(&stale, &csv_path)?;
+        let csv = std::fs::read_to_string(&csv_path)?;
+        assert!(csv.starts_with("disc_id,created_at,last_verified,last_result\n"));
+        assert!(csv.contains("NEVER,2021-01-01T00:00:00Z,,never"));
+        assert!(csv.contains(",failed"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_disc_id() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let disc = temp_dir.path();
+        assert_eq!(read_disc_id(disc), None);
+        std::fs::write(disc.join("DISC_INFO.txt"), "Volume: OLD_1\nDisc-ID: OLD-1\n")?;
+        assert_eq!(read_disc_id(disc), Some("OLD-1".to_string()));
+        assert_eq!(with_disc_mounted(&disc.to_string_lossy(), |root| Ok(read_disc_id(root)))?, Some("OLD-1".to_string()));
+
+        let set_disc = temp_dir.path().join("set");
+        std::fs::create_dir_all(set_disc.join("disc_info"))?;
+        std::fs::write(set_disc.join("disc_info/DISC_INFO.txt"), "Disc-ID: SET-2\n")?;
+        assert_eq!(read_disc_id(&set_disc), Some("SET-2".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_size() -> Result<()> {
+        assert_eq!("50".parse::<SampleSize>()?, SampleSize::Count(50));
+        assert_eq!("5%".parse::<SampleSize>()?, SampleSize::Fraction(0.05));
+        assert!("0".parse::<SampleSize>().is_err());
+        assert!("0%".parse::<SampleSize>().is_err());
+        assert!("150%".parse::<SampleSize>().is_err());
+        assert!("some".parse::<SampleSize>().is_err());
+
+        assert_eq!(SampleSize::Count(50).of(4000), 50);
+        assert_eq!(SampleSize::Count(50).of(10), 10);
+        assert_eq!(SampleSize::Fraction(0.05).of(4000), 200);
+        assert_eq!(SampleSize::Fraction(0.05).of(3), 1);
+        assert_eq!(SampleSize::Fraction(0.05).of(0), 0);
+
+        let picked = random_sample(100, 10);
+        assert_eq!(picked.len(), 10);
+        assert!(picked.windows(2).all(|w| w[0] < w[1]));
+        assert!(picked.iter().all(|&i| i < 100));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_against_db() -> Result<()> {
+        use crate::database::{init_database, FileRecord};
+        use crate::manifest::calculate_sha256;
+
+        let temp_dir = tempfile::TempDir::new()?;
+        let conn = init_database(&temp_dir.path().join("test.db"))?;
+        conn.execute(
+            "INSERT INTO discs (disc_id, volume_label, created_at) VALUES ('D1', 'D1', '2024-01-01T00:00:00Z')",
+            [],
+        )?;
+        let disc = temp_dir.path().join("disc");
+        std::fs::create_dir_all(disc.join("ARCHIVE/docs"))?;
+        std::fs::write(disc.join("ARCHIVE/docs/a.txt"), "alpha")?;
+        std::fs::write(disc.join("ARCHIVE/docs/b.txt"), "beta")?;
+        let record = |path: &str, sha256: String| FileRecord {
+            id: None,
+            disc_id: "D1".to_string(),
+            rel_path: path.to_string(),
+            sha256,
+            size: 5,
+            mtime: String::new(),
+            added_at: String::new(),
+            crc32: None,
+        };
+        let a_sha = calculate_sha256(&disc.join("ARCHIVE/docs/a.txt"))?;
+        for file in [
+            record("ARCHIVE/docs/a.txt", a_sha.clone()),
+            record("ARCHIVE/docs/b.txt", calculate_sha256(&disc.join("ARCHIVE/docs/b.txt"))?),
+            // Deduplicated copy of a.txt, stored once
+            record("ARCHIVE/docs/copy-of-a.txt", a_sha),
+        ] {
+            FileRecord::insert(&conn, &file)?;
+        }
+
+        // No SHA256SUMS.txt on the disc at all
+        let result = verify_against_db(&conn, "D1", &disc)?;
+        assert!(result.success, "{:?}", result.error_message);
+        assert_eq!(result.files_checked, 3);
+        assert!(result.warnings.is_empty());
+
+        std::fs::write(disc.join("ARCHIVE/docs/b.txt"), "bet4")?;
+        std::fs::write(disc.join("ARCHIVE/docs/extra.txt"), "extra")?;
+        let result = verify_against_db(&conn, "D1", &disc)?;
+        assert!(!result.success);
+        assert_eq!(result.files_failed, 1);
+        assert!(result.error_message.unwrap().contains("ARCHIVE/docs/b.txt: checksum mismatch"));
+        assert_eq!(result.warnings.len(), 1);
+
+        assert!(verify_against_db(&conn, "D2", &disc).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_sample() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let disc = temp_dir.path();
+        std::fs::create_dir_all(disc.join("ARCHIVE"))?;
+        for i in 0..20 {
+            std::fs::write(disc.join(format!("ARCHIVE/file{}.txt", i)), format!("content {}", i))?;
+        }
+        let files = crate::manifest::generate_manifest_and_sums(disc, None)?;
+        crate::manifest::write_sha256sums_file(&disc.join("SHA256SUMS.txt"), &files)?;
+
+        let sample = verify_sample(disc, SampleSize::Count(5))?;
+        assert!(sample.result.success, "{:?}", sample.result.error_message);
+        assert_eq!(sample.result.files_checked, 5);
+        assert_eq!(sample.total_files, 20);
+        assert_eq!(sample.summary(), "sampled 5 of 20 files, all OK");
+
+        // A full-size sample must hit the corrupted file
+        std::fs::write(disc.join("ARCHIVE/file7.txt"), "corrupted")?;
+        let sample = verify_sample(disc, "100%".parse()?)?;
+        assert!(!sample.result.success);
+        assert_eq!(sample.result.files_failed, 1);
+        assert_eq!(sample.summary(), "sampled 20 of 20 files, 1 failed");
+        assert!(sample.result.error_message.unwrap().contains("ARCHIVE/file7.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_crc32_disc() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let disc = temp_dir.path();
+        std::fs::create_dir_all(disc.join("ARCHIVE"))?;
+        std::fs::write(disc.join("ARCHIVE/a.txt"), "alpha")?;
+        std::fs::write(disc.join("ARCHIVE/b.txt"), "beta")?;
+        let files = crate::manifest::generate_manifest_and_sums_with_progress(disc, None, None, ChecksumAlgorithm::Crc32, 0)?;
+        crate::manifest::write_checksum_list(disc, &files, ChecksumAlgorithm::Crc32)?;
+        assert!(is_readable_archive(disc));
+
+        let result = verify_disc(disc, false, false)?;
+        assert!(result.success, "{:?}", result.error_message);
+        assert_eq!(result.files_checked, 2);
+        assert!(verify_sample(disc, "100%".parse()?)?.result.success);
+
+        std::fs::write(disc.join("ARCHIVE/b.txt"), "corrupted")?;
+        let result = verify_disc(disc, false, false)?;
+        assert!(!result.success);
+        assert_eq!(result.files_failed, 1);
+        assert!(result.error_message.unwrap().contains("ARCHIVE/b.txt: checksum mismatch"));
+        Ok(())
+    }
 }